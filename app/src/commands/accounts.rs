@@ -22,11 +22,9 @@ use super::state::AppState;
 use crate::core::accounts::{
     complete_authentication, create_offline_account, poll_device_code, refresh_microsoft_account,
     start_device_code_flow, validate_offline_username, Account, AccountList, AuthProgressEvent,
-    DeviceCodeInfo, PollResult, MSA_CLIENT_ID, skins, SkinVariant,
+    DeviceCodeInfo, PollResult, MSA_CLIENT_ID, skins, SkinVariant, TokenStatus,
 };
 use serde::Serialize;
-use std::collections::HashMap;
-use std::sync::Mutex;
 use tauri::{AppHandle, Emitter, State};
 use tokio::sync::mpsc;
 
@@ -79,18 +77,18 @@ pub struct DeviceCodeInfoResponse {
 
 /// State of a pending device code authentication
 #[derive(Debug, Clone)]
-enum DeviceCodeState {
+pub enum DeviceCodeState {
     /// Waiting for user to complete authentication in browser
     /// (device_code_info, client_id)
     Pending(DeviceCodeInfo, String),
     /// User authenticated, now completing the full auth flow
     Completing,
+    /// User cancelled the login; any in-flight poll must discard its result
+    Cancelled,
 }
 
-// Global state for pending device code authentications
-lazy_static::lazy_static! {
-    static ref PENDING_DEVICE_CODES: Mutex<HashMap<String, DeviceCodeState>> = Mutex::new(HashMap::new());
-}
+/// Error returned to a poll whose login attempt was cancelled
+const LOGIN_CANCELLED: &str = "Login was cancelled";
 
 /// Get all accounts
 #[tauri::command]
@@ -143,12 +141,11 @@ pub async fn add_offline_account(
         return Err(format!("An account with username '{}' already exists", username));
     }
 
-    // Create the account
+    // Create the account; an existing account for the same player is reused
     let account = create_offline_account(&username);
-    let info = AccountInfo::from(&account);
+    let info = AccountInfo::from(account_list.add_or_update(account));
 
-    // Add and save
-    account_list.add(account);
+    // Save
     account_list
         .save(&accounts_file)
         .map_err(|e| e.to_string())?;
@@ -191,7 +188,9 @@ pub async fn start_microsoft_login(
     // Store for polling (include client_id)
     let code_key = device_code.device_code.clone();
     {
-        let mut pending = PENDING_DEVICE_CODES.lock().unwrap();
+        let mut pending = state.pending_logins.lock().unwrap();
+        // Cancelled attempts with no poll left in flight are stale by now
+        pending.retain(|_, attempt| !matches!(attempt, DeviceCodeState::Cancelled));
         pending.insert(code_key.clone(), DeviceCodeState::Pending(device_code.clone(), client_id));
         tracing::info!("Stored device code - key: {} (len: {}), total pending: {}", 
             &code_key[..8.min(code_key.len())], 
@@ -233,12 +232,12 @@ pub async fn poll_microsoft_login(
         &device_code[..8.min(device_code.len())]
     );
     
-    let pending_count = PENDING_DEVICE_CODES.lock().unwrap().len();
+    let pending_count = state.pending_logins.lock().unwrap().len();
     tracing::info!("Total pending device codes: {}", pending_count);
     
     // Get the stored device code state
     let device_code_state = {
-        let pending = PENDING_DEVICE_CODES.lock().unwrap();
+        let pending = state.pending_logins.lock().unwrap();
         let result = pending.get(&device_code).cloned();
         if result.is_none() {
             let available_keys: Vec<String> = pending.keys()
@@ -271,6 +270,10 @@ pub async fn poll_microsoft_login(
             );
             return Ok(None);
         }
+        DeviceCodeState::Cancelled => {
+            state.pending_logins.lock().unwrap().remove(&device_code);
+            return Err(LOGIN_CANCELLED.to_string());
+        }
     };
 
     // Poll for result using the stored client_id
@@ -278,13 +281,28 @@ pub async fn poll_microsoft_login(
         .await
         .map_err(|e| e.to_string())?;
 
+    // The user may have cancelled while the request was in flight
+    {
+        let mut pending = state.pending_logins.lock().unwrap();
+        match pending.get(&device_code) {
+            Some(DeviceCodeState::Pending(..)) => {}
+            // Another poll already got the token and is completing authentication
+            Some(DeviceCodeState::Completing) => return Ok(None),
+            Some(DeviceCodeState::Cancelled) | None => {
+                pending.remove(&device_code);
+                tracing::info!("Discarding poll result for cancelled login");
+                return Err(LOGIN_CANCELLED.to_string());
+            }
+        }
+        
+        if matches!(result, PollResult::Success(_)) {
+            // Mark as completing (instead of removing)
+            pending.insert(device_code.clone(), DeviceCodeState::Completing);
+        }
+    }
+
     match result {
         PollResult::Success(msa_token) => {
-            // Mark as completing (instead of removing)
-            {
-                let mut pending = PENDING_DEVICE_CODES.lock().unwrap();
-                pending.insert(device_code.clone(), DeviceCodeState::Completing);
-            }
 
             // Emit progress events
             let _ = app.emit(
@@ -310,9 +328,13 @@ pub async fn poll_microsoft_login(
             let complete_result = complete_authentication(msa_token, Some(tx)).await;
             
             // Always remove from pending when done (success or failure)
-            {
-                let mut pending = PENDING_DEVICE_CODES.lock().unwrap();
-                pending.remove(&device_code);
+            let attempt = state.pending_logins.lock().unwrap().remove(&device_code);
+            
+            // Microsoft has no endpoint to revoke a device code, so a login cancelled
+            // mid-way is enforced here by dropping the tokens instead of saving an account
+            if !matches!(attempt, Some(DeviceCodeState::Completing)) {
+                tracing::info!("Discarding completed authentication for cancelled login");
+                return Err(LOGIN_CANCELLED.to_string());
             }
             
             // Handle the result
@@ -320,7 +342,6 @@ pub async fn poll_microsoft_login(
 
             // Create account
             let account = Account::new_microsoft_from_data(account_data);
-            let info = AccountInfo::from(&account);
 
             // Save account
             let accounts_file = {
@@ -333,17 +354,8 @@ pub async fn poll_microsoft_login(
             // Mark ownership as verified - Microsoft account verified game ownership
             account_list.set_ownership_verified();
 
-            // Check for duplicate UUID
-            let existing_idx = account_list
-                .accounts
-                .iter()
-                .position(|a| a.uuid == account.uuid);
-            if let Some(idx) = existing_idx {
-                // Update existing account
-                account_list.accounts[idx] = account;
-            } else {
-                account_list.add(account);
-            }
+            // Logging in again as a saved player refreshes that account's tokens
+            let info = AccountInfo::from(account_list.add_or_update(account));
 
             account_list
                 .save(&accounts_file)
@@ -380,7 +392,7 @@ pub async fn poll_microsoft_login(
         PollResult::Declined => {
             // Remove from pending
             {
-                let mut pending = PENDING_DEVICE_CODES.lock().unwrap();
+                let mut pending = state.pending_logins.lock().unwrap();
                 pending.remove(&device_code);
             }
             Err("Authentication was declined".to_string())
@@ -388,7 +400,7 @@ pub async fn poll_microsoft_login(
         PollResult::Expired => {
             // Remove from pending
             {
-                let mut pending = PENDING_DEVICE_CODES.lock().unwrap();
+                let mut pending = state.pending_logins.lock().unwrap();
                 pending.remove(&device_code);
             }
             Err("Device code expired. Please try again.".to_string())
@@ -397,11 +409,19 @@ pub async fn poll_microsoft_login(
 }
 
 /// Cancel a pending Microsoft login
+///
+/// The attempt is flagged rather than removed so that a poll already in flight
+/// sees the cancellation and discards its result instead of adding the account.
 #[tauri::command(rename_all = "camelCase")]
-pub async fn cancel_microsoft_login(device_code: String) -> Result<(), String> {
+pub async fn cancel_microsoft_login(
+    state: State<'_, AppState>,
+    device_code: String,
+) -> Result<(), String> {
     tracing::info!("Cancelling device code: {}", &device_code[..8.min(device_code.len())]);
-    let mut pending = PENDING_DEVICE_CODES.lock().unwrap();
-    pending.remove(&device_code);
+    let mut pending = state.pending_logins.lock().unwrap();
+    if let Some(attempt) = pending.get_mut(&device_code) {
+        *attempt = DeviceCodeState::Cancelled;
+    }
     Ok(())
 }
 
@@ -494,6 +514,32 @@ pub async fn set_active_account(
     Ok(())
 }
 
+/// Persist a user-defined account order; `get_accounts` returns accounts in this order.
+/// `ordered_ids` must contain every account ID exactly once.
+#[tauri::command]
+pub async fn reorder_accounts(
+    state: State<'_, AppState>,
+    ordered_ids: Vec<String>,
+) -> Result<(), String> {
+    let config = state.config.lock().unwrap();
+    let accounts_file = config.accounts_file();
+    drop(config);
+
+    let mut account_list = AccountList::load(&accounts_file).unwrap_or_default();
+    account_list.reorder(&ordered_ids).map_err(|e| e.to_string())?;
+    account_list
+        .save(&accounts_file)
+        .map_err(|e| e.to_string())?;
+
+    // Update state
+    {
+        let mut accounts = state.accounts.lock().unwrap();
+        *accounts = account_list.accounts;
+    }
+
+    Ok(())
+}
+
 /// Remove an account
 #[tauri::command]
 pub async fn remove_account(
@@ -604,6 +650,44 @@ pub async fn has_verified_ownership(state: State<'_, AppState>) -> Result<bool,
     Ok(account_list.is_ownership_verified())
 }
 
+/// Token status of a Microsoft account for the frontend
+#[derive(Debug, Clone, Serialize)]
+pub struct AccountTokenStatus {
+    pub account_id: String,
+    pub username: String,
+    pub status: TokenStatus,
+    pub access_token_expires_at: Option<String>,
+    /// When the refresh token stops working unless it is used before then
+    pub refresh_token_expires_at: Option<String>,
+}
+
+/// Get the token status of every Microsoft account, computed from the stored
+/// expiry timestamps without contacting Microsoft
+#[tauri::command]
+pub async fn get_account_token_status(state: State<'_, AppState>) -> Result<Vec<AccountTokenStatus>, String> {
+    let accounts = state.accounts.lock().unwrap();
+    let now = chrono::Utc::now();
+    
+    Ok(accounts.iter()
+        .filter_map(|account| {
+            let status = account.token_status(now)?;
+            let access_token_expires_at = account.data.as_ref()
+                .and_then(|d| d.minecraft_token.expires_at)
+                .or(account.token_expires_at);
+            let refresh_token_expires_at = account.data.as_ref()
+                .and_then(|d| d.refresh_token_expires_at());
+            
+            Some(AccountTokenStatus {
+                account_id: account.id.clone(),
+                username: account.username.clone(),
+                status,
+                access_token_expires_at: access_token_expires_at.map(|t| t.to_rfc3339()),
+                refresh_token_expires_at: refresh_token_expires_at.map(|t| t.to_rfc3339()),
+            })
+        })
+        .collect())
+}
+
 // =============================================================================
 // Skin Management Commands
 // =============================================================================
@@ -666,24 +750,9 @@ fn get_account_access_token(accounts_file: &std::path::PathBuf, account_id: &str
     Ok(access_token)
 }
 
-/// Get the full player profile including skins and capes
-#[tauri::command(rename_all = "camelCase")]
-pub async fn get_player_profile(
-    state: State<'_, AppState>,
-    account_id: String,
-) -> Result<PlayerProfileResponse, String> {
-    let accounts_file = {
-        let config = state.config.lock().unwrap();
-        config.accounts_file()
-    };
-
-    let access_token = get_account_access_token(&accounts_file, &account_id)?;
-
-    let profile = skins::get_player_profile(&access_token)
-        .await
-        .map_err(|e| e.to_string())?;
-
-    Ok(PlayerProfileResponse {
+/// Convert a fetched profile into the frontend's shape
+fn profile_response(profile: skins::PlayerProfile) -> PlayerProfileResponse {
+    PlayerProfileResponse {
         id: profile.id,
         name: profile.name,
         skins: profile.skins.iter().map(|s| SkinInfoResponse {
@@ -716,7 +785,27 @@ pub async fn get_player_profile(
             alias: c.alias.clone(),
             is_active: true,
         }),
-    })
+    }
+}
+
+/// Get the full player profile including skins and capes
+#[tauri::command(rename_all = "camelCase")]
+pub async fn get_player_profile(
+    state: State<'_, AppState>,
+    account_id: String,
+) -> Result<PlayerProfileResponse, String> {
+    let accounts_file = {
+        let config = state.config.lock().unwrap();
+        config.accounts_file()
+    };
+
+    let access_token = get_account_access_token(&accounts_file, &account_id)?;
+
+    let profile = skins::get_player_profile(&access_token)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(profile_response(profile))
 }
 
 /// Change skin using a URL
@@ -773,6 +862,49 @@ pub async fn upload_skin(
         .map_err(|e| e.to_string())
 }
 
+/// Upload a skin PNG from disk with the classic or slim model, then fetch
+/// the profile again so the new skin shows. Legacy 64x32 skins are
+/// converted to 64x64 first; images of any other size are rejected.
+#[tauri::command(rename_all = "camelCase")]
+pub async fn import_skin_from_file(
+    state: State<'_, AppState>,
+    account_id: String,
+    path: String,
+    slim: bool,
+) -> Result<PlayerProfileResponse, String> {
+    let image_data = std::fs::read(&path)
+        .map_err(|e| format!("Failed to read skin file: {}", e))?;
+    let image_data = skins::prepare_skin_image(&image_data)
+        .map_err(|e| e.to_string())?;
+
+    let accounts_file = {
+        let config = state.config.lock().unwrap();
+        config.accounts_file()
+    };
+
+    let access_token = get_account_access_token(&accounts_file, &account_id)?;
+
+    let variant = if slim { SkinVariant::Slim } else { SkinVariant::Classic };
+    skins::upload_skin(&access_token, &image_data, variant)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let profile = skins::get_player_profile(&access_token)
+        .await
+        .map_err(|e| format!("Skin uploaded, but refreshing the profile failed: {}", e))?;
+
+    // Keep the saved account's skin in step with the upload
+    let mut account_list = AccountList::load(&accounts_file).unwrap_or_default();
+    if let Some(data) = account_list.get_mut(&account_id).and_then(|account| account.data.as_mut()) {
+        data.minecraft_profile.skin = profile.active_skin.clone();
+        if let Err(e) = account_list.save(&accounts_file) {
+            tracing::warn!("Failed to save account after skin upload: {}", e);
+        }
+    }
+
+    Ok(profile_response(profile))
+}
+
 /// Reset skin to default
 #[tauri::command(rename_all = "camelCase")]
 pub async fn reset_skin(
@@ -935,6 +1067,35 @@ pub async fn open_skins_folder(
     Ok(())
 }
 
+/// Merge saved accounts for the same player (same UUID), keeping the one
+/// with the freshest token. Returns how many duplicates were removed.
+#[tauri::command]
+pub async fn deduplicate_accounts(state: State<'_, AppState>) -> Result<usize, String> {
+    let accounts_file = {
+        let config = state.config.lock().unwrap();
+        config.accounts_file()
+    };
+
+    let mut account_list = AccountList::load(&accounts_file).unwrap_or_default();
+    let merged = account_list.deduplicate();
+    if merged == 0 {
+        return Ok(0);
+    }
+
+    account_list
+        .save(&accounts_file)
+        .map_err(|e| e.to_string())?;
+    tracing::info!("Merged {} duplicate accounts", merged);
+
+    // Update state
+    {
+        let mut accounts = state.accounts.lock().unwrap();
+        *accounts = account_list.accounts;
+    }
+
+    Ok(merged)
+}
+
 /// Set an account as the default account
 #[tauri::command(rename_all = "camelCase")]
 pub async fn set_default_account(
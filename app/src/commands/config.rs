@@ -19,8 +19,15 @@
 //! along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use super::state::AppState;
-use crate::core::config::Config;
-use tauri::State;
+use crate::core::config::{migrate_directory, prepare_directory_override, ArgumentProfile, Config, InstanceTemplate, SettingsExport};
+use crate::core::connectivity::{check_services, default_services, ServiceStatus};
+use crate::core::java::memory::{validate_memory, MemoryValidation};
+use crate::core::launch::resolve_java_path;
+use crate::core::minecraft::official::{self, AssetImport, OfficialInstall};
+use crate::core::storage::{storage_info, VolumeInfo};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tauri::{AppHandle, Manager, State};
 
 #[tauri::command]
 pub async fn get_config(state: State<'_, AppState>) -> Result<Config, String> {
@@ -28,19 +35,302 @@ pub async fn get_config(state: State<'_, AppState>) -> Result<Config, String> {
     Ok(config.clone())
 }
 
+/// Save the configuration. Memory is snapped to a valid range first;
+/// returns the memory validation's warnings for the settings page.
 #[tauri::command]
 pub async fn update_config(
     state: State<'_, AppState>,
-    config: Config,
-) -> Result<(), String> {
+    mut config: Config,
+) -> Result<Vec<String>, String> {
+    config.prepare_directory_overrides().map_err(|e| e.to_string())?;
+    
+    let memory = validate_memory(config.memory.min_memory, config.memory.max_memory);
+    for warning in &memory.warnings {
+        tracing::warn!("Global memory settings: {}", warning);
+    }
+    config.memory.min_memory = memory.min_memory;
+    config.memory.max_memory = memory.max_memory;
+    
     // Save to file
     config.save().map_err(|e| e.to_string())?;
     
     let mut app_config = state.config.lock().unwrap();
     *app_config = config;
+    Ok(memory.warnings)
+}
+
+/// Validate a min/max memory pair (MB) against system RAM.
+/// Returns the values snapped to a valid range along with any warnings,
+/// so memory sliders can snap before saving.
+#[tauri::command]
+pub async fn validate_memory_settings(
+    min_memory: u32,
+    max_memory: u32,
+) -> Result<MemoryValidation, String> {
+    Ok(validate_memory(min_memory, max_memory))
+}
+
+/// Get the defaults applied to newly created instances
+#[tauri::command]
+pub async fn get_instance_template(state: State<'_, AppState>) -> Result<InstanceTemplate, String> {
+    let config = state.config.lock().unwrap();
+    Ok(config.instance_template.clone())
+}
+
+/// Replace the new-instance template and save it.
+/// Memory is snapped to a valid range like the global memory settings, and
+/// blank values are cleared. Returns the template as stored.
+#[tauri::command]
+pub async fn set_instance_template(
+    state: State<'_, AppState>,
+    mut template: InstanceTemplate,
+) -> Result<InstanceTemplate, String> {
+    if let (Some(min), Some(max)) = (template.min_memory, template.max_memory) {
+        let memory = validate_memory(min, max);
+        for warning in &memory.warnings {
+            tracing::warn!("Instance template memory: {}", warning);
+        }
+        template.min_memory = Some(memory.min_memory);
+        template.max_memory = Some(memory.max_memory);
+    }
+    
+    template.jvm_args = template.jvm_args.filter(|args| !args.trim().is_empty());
+    template.mod_loader = template.mod_loader
+        .filter(|loader| !matches!(loader.as_str(), "" | "None" | "Vanilla"));
+    template.java_path = template.java_path.filter(|path| !path.as_os_str().is_empty());
+    
+    let mut config = state.config.lock().unwrap().clone();
+    config.instance_template = template.clone();
+    config.save().map_err(|e| e.to_string())?;
+    
+    *state.config.lock().unwrap() = config;
+    Ok(template)
+}
+
+/// Get the saved argument profiles
+#[tauri::command]
+pub async fn get_argument_profiles(state: State<'_, AppState>) -> Result<Vec<ArgumentProfile>, String> {
+    let config = state.config.lock().unwrap();
+    Ok(config.argument_profiles.clone())
+}
+
+/// Create an argument profile, or replace the one with the same name.
+/// Instances using the profile pick up the change on their next launch.
+#[tauri::command]
+pub async fn save_argument_profile(
+    state: State<'_, AppState>,
+    mut profile: ArgumentProfile,
+) -> Result<Vec<ArgumentProfile>, String> {
+    profile.name = profile.name.trim().to_string();
+    if profile.name.is_empty() {
+        return Err("Argument profile name cannot be empty".to_string());
+    }
+    profile.jvm_args = profile.jvm_args.filter(|args| !args.trim().is_empty());
+    profile.game_args = profile.game_args.filter(|args| !args.trim().is_empty());
+    
+    let mut config = state.config.lock().unwrap().clone();
+    match config.argument_profiles.iter_mut().find(|p| p.name == profile.name) {
+        Some(existing) => *existing = profile,
+        None => config.argument_profiles.push(profile),
+    }
+    config.save().map_err(|e| e.to_string())?;
+    
+    let profiles = config.argument_profiles.clone();
+    *state.config.lock().unwrap() = config;
+    Ok(profiles)
+}
+
+/// Delete an argument profile. Instances still assigned to it launch
+/// with their own arguments only.
+#[tauri::command]
+pub async fn delete_argument_profile(
+    state: State<'_, AppState>,
+    name: String,
+) -> Result<Vec<ArgumentProfile>, String> {
+    let mut config = state.config.lock().unwrap().clone();
+    let before = config.argument_profiles.len();
+    config.argument_profiles.retain(|p| p.name != name);
+    if config.argument_profiles.len() == before {
+        return Err(format!("Argument profile '{}' not found", name));
+    }
+    config.save().map_err(|e| e.to_string())?;
+    
+    let profiles = config.argument_profiles.clone();
+    *state.config.lock().unwrap() = config;
+    Ok(profiles)
+}
+
+/// Set the JVM arguments added to every instance's launch, ahead of its
+/// argument profile and its own arguments. Instances can opt out with
+/// `use_global_jvm_args`. Returns the saved arguments.
+#[tauri::command]
+pub async fn set_global_jvm_args(
+    state: State<'_, AppState>,
+    args: String,
+) -> Result<Vec<String>, String> {
+    let mut config = state.config.lock().unwrap().clone();
+    config.java.extra_args = args.split_whitespace().map(String::from).collect();
+    config.save().map_err(|e| e.to_string())?;
+    
+    let saved = config.java.extra_args.clone();
+    *state.config.lock().unwrap() = config;
+    Ok(saved)
+}
+
+/// Change where the shared assets or libraries are stored.
+/// `directory` is "assets" or "libraries"; a `path` of `None` restores the default
+/// location under the data directory. When `migrate` is set, existing files are
+/// moved to the new location. Returns the directory now in use.
+#[tauri::command]
+pub async fn set_shared_directory(
+    state: State<'_, AppState>,
+    directory: String,
+    path: Option<String>,
+    migrate: bool,
+) -> Result<String, String> {
+    let new_override = path
+        .filter(|p| !p.trim().is_empty())
+        .map(|p| PathBuf::from(p.trim()));
+    
+    if let Some(ref dir) = new_override {
+        prepare_directory_override(dir).map_err(|e| e.to_string())?;
+    }
+    
+    if migrate && !state.running_processes.lock().unwrap().is_empty() {
+        return Err("Close all running instances before moving shared files".to_string());
+    }
+    
+    let mut config = state.config.lock().unwrap().clone();
+    let (old_dir, new_dir) = match directory.as_str() {
+        "assets" => {
+            let old_dir = config.assets_dir();
+            config.assets_dir = new_override;
+            (old_dir, config.assets_dir())
+        }
+        "libraries" => {
+            let old_dir = config.libraries_dir();
+            config.libraries_dir = new_override;
+            (old_dir, config.libraries_dir())
+        }
+        _ => return Err(format!("Unknown shared directory: {}", directory)),
+    };
+    
+    if migrate && old_dir != new_dir {
+        tracing::info!("Moving {} from {:?} to {:?}", directory, old_dir, new_dir);
+        migrate_directory(&old_dir, &new_dir)
+            .map_err(|e| format!("Failed to move {}: {}", directory, e))?;
+        let _ = std::fs::remove_dir(&old_dir);
+    }
+    
+    std::fs::create_dir_all(&new_dir).map_err(|e| e.to_string())?;
+    config.save().map_err(|e| e.to_string())?;
+    *state.config.lock().unwrap() = config;
+    
+    Ok(new_dir.to_string_lossy().to_string())
+}
+
+/// Look for an official Minecraft Launcher installation in its default location
+/// and report what it has downloaded. Returns `None` when there isn't one.
+#[tauri::command]
+pub async fn detect_official_minecraft() -> Result<Option<OfficialInstall>, String> {
+    tokio::task::spawn_blocking(|| official::official_minecraft_dir().map(|dir| official::scan_official_install(&dir)))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Link the official launcher's asset store into the shared assets directory,
+/// so existing players don't re-download it. The official installation is only read.
+#[tauri::command]
+pub async fn import_official_assets(state: State<'_, AppState>) -> Result<AssetImport, String> {
+    let official_dir = official::official_minecraft_dir()
+        .ok_or_else(|| "No official Minecraft installation found".to_string())?;
+    let assets_dir = state.config.lock().unwrap().assets_dir();
+    
+    let result = tokio::task::spawn_blocking(move || official::import_official_assets(&official_dir, &assets_dir))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| format!("Failed to import assets: {}", e))?;
+    
+    tracing::info!(
+        "Imported official assets: {} indexes, {} objects ({} already present, {} corrupt)",
+        result.indexes, result.objects, result.existing, result.corrupt
+    );
+    
+    Ok(result)
+}
+
+/// Check that the services the launcher needs can be reached (Mojang,
+/// Minecraft login, Modrinth, CurseForge and the Oxide meta server), so the
+/// frontend can warn before starting something that would fail partway.
+/// Services are probed concurrently with a short timeout.
+#[tauri::command]
+pub async fn check_connectivity(state: State<'_, AppState>) -> Result<Vec<ServiceStatus>, String> {
+    let user_agent = state.config.lock().unwrap().network.user_agent.clone();
+    
+    let statuses = check_services(&default_services(), &user_agent).await;
+    for status in statuses.iter().filter(|s| !s.reachable) {
+        tracing::warn!(
+            "{} is unreachable: {}",
+            status.name,
+            status.error.as_deref().unwrap_or("unknown error")
+        );
+    }
+    
+    Ok(statuses)
+}
+
+/// Export the launcher settings to a portable file for moving to another machine.
+/// API keys and proxy credentials are only written when `include_secrets` is set.
+#[tauri::command]
+pub async fn export_settings(
+    state: State<'_, AppState>,
+    path: String,
+    include_secrets: bool,
+) -> Result<(), String> {
+    let export = {
+        let config = state.config.lock().unwrap();
+        config.to_export(include_secrets).map_err(|e| e.to_string())?
+    };
+    
+    let content = serde_json::to_string_pretty(&export).map_err(|e| e.to_string())?;
+    std::fs::write(&path, content).map_err(|e| format!("Failed to write settings file: {}", e))?;
+    
+    tracing::info!("Exported settings to {} (secrets included: {})", path, include_secrets);
     Ok(())
 }
 
+/// Import launcher settings from a file written by `export_settings`.
+/// With `reset_paths`, this machine's data, instances, assets, libraries, Java
+/// and downloads paths are kept. Returns the imported configuration.
+#[tauri::command]
+pub async fn import_settings(
+    state: State<'_, AppState>,
+    path: String,
+    reset_paths: bool,
+) -> Result<Config, String> {
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read settings file: {}", e))?;
+    let export: SettingsExport = serde_json::from_str(&content)
+        .map_err(|e| format!("Not a valid settings file: {}", e))?;
+    
+    let current = state.config.lock().unwrap().clone();
+    let mut config = Config::from_export(export, &current, reset_paths).map_err(|e| e.to_string())?;
+    
+    config.prepare_directory_overrides().map_err(|e| e.to_string())?;
+    let memory = validate_memory(config.memory.min_memory, config.memory.max_memory);
+    config.memory.min_memory = memory.min_memory;
+    config.memory.max_memory = memory.max_memory;
+    
+    config.save().map_err(|e| e.to_string())?;
+    
+    if config.data_dir != current.data_dir {
+        tracing::info!("Imported settings use a different data directory; restart to apply it");
+    }
+    
+    *state.config.lock().unwrap() = config.clone();
+    Ok(config)
+}
+
 #[tauri::command]
 pub async fn get_logs_directory(state: State<'_, AppState>) -> Result<String, String> {
     let config = state.config.lock().unwrap();
@@ -160,6 +450,74 @@ pub async fn save_window_state(
     Ok(())
 }
 
+// Instance details tab memory
+
+/// Tabs on the instance details view
+const INSTANCE_TABS: &[&str] = &[
+    "log", "version", "mods", "resourcepacks", "shaderpacks",
+    "notes", "worlds", "screenshots", "settings",
+];
+
+/// How long tab changes are held in memory before the config is written
+const TAB_SAVE_DELAY: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// Bumped on every tab change so only the last pending save writes the config
+static TAB_SAVE_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Get the tab to open an instance's details view on: the one last used on
+/// that instance, else the one last used on any instance
+#[tauri::command]
+pub async fn get_instance_tab(
+    state: State<'_, AppState>,
+    instance_id: String,
+) -> Result<Option<String>, String> {
+    let config = state.config.lock().unwrap();
+    
+    Ok(config.ui.instance_tabs.get(&instance_id)
+        .or(config.ui.last_instance_tab.as_ref())
+        .cloned())
+}
+
+/// Remember the tab last opened on an instance's details view. The change is
+/// kept in memory and written out once tab switching settles, rather than
+/// saving the whole config on every click.
+#[tauri::command]
+pub async fn set_instance_tab(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    instance_id: String,
+    tab: String,
+) -> Result<(), String> {
+    if !INSTANCE_TABS.contains(&tab.as_str()) {
+        return Err(format!("Unknown instance tab: {}", tab));
+    }
+    
+    let mut config = state.config.lock().unwrap();
+    if config.ui.instance_tabs.get(&instance_id) == Some(&tab)
+        && config.ui.last_instance_tab.as_ref() == Some(&tab)
+    {
+        return Ok(());
+    }
+    
+    config.ui.instance_tabs.insert(instance_id, tab.clone());
+    config.ui.last_instance_tab = Some(tab);
+    drop(config);
+    
+    let generation = TAB_SAVE_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    tokio::spawn(async move {
+        tokio::time::sleep(TAB_SAVE_DELAY).await;
+        if TAB_SAVE_GENERATION.load(Ordering::SeqCst) != generation {
+            return;
+        }
+        let state = app.state::<AppState>();
+        let config = state.config.lock().unwrap();
+        if let Err(e) = config.save() {
+            tracing::warn!("Failed to save instance tab: {}", e);
+        }
+    });
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn is_window_position_memory_enabled(
     state: State<'_, AppState>,
@@ -173,3 +531,75 @@ pub async fn is_window_position_memory_enabled(
         Ok(config.ui.remember_dialog_window_positions)
     }
 }
+
+/// Whether closing the main window should hide it to the tray
+#[tauri::command]
+pub async fn is_minimize_to_tray_enabled(state: State<'_, AppState>) -> Result<bool, String> {
+    Ok(state.config.lock().unwrap().ui.minimize_to_tray)
+}
+
+/// Free and total space on the volumes holding the data, instances,
+/// libraries and assets folders. Folders on the same volume share an entry.
+#[tauri::command]
+pub async fn get_storage_info(state: State<'_, AppState>) -> Result<Vec<VolumeInfo>, String> {
+    let folders = {
+        let config = state.config.lock().unwrap();
+        [
+            ("data", config.data_dir()),
+            ("instances", config.instances_dir()),
+            ("libraries", config.libraries_dir()),
+            ("assets", config.assets_dir()),
+        ]
+    };
+    
+    tokio::task::spawn_blocking(move || storage_info(&folders))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// A setting that points at something no longer there
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DanglingReference {
+    /// The instance holding the setting; None for the global settings
+    pub instance_id: Option<String>,
+    pub instance_name: Option<String>,
+    /// What the setting refers to; currently always "java"
+    pub kind: String,
+    /// The stored value that no longer resolves
+    pub value: String,
+}
+
+/// Find Java paths, global or on an instance, that point at an installation
+/// that has been deleted or moved, so the UI can offer to reassign or clear
+/// them. Paths are resolved the way a launch does, so a bare `java` found on
+/// PATH is fine. Instances don't store an account of their own, so the
+/// active account is not checked.
+#[tauri::command]
+pub async fn find_dangling_references(state: State<'_, AppState>) -> Result<Vec<DanglingReference>, String> {
+    let global_java = state.config.lock().unwrap().java.custom_path.clone();
+    let instances = state.instances.lock().unwrap().clone();
+    
+    let is_dangling = |java_path: &PathBuf| resolve_java_path(&java_path.to_string_lossy()).is_none();
+    
+    let global = global_java
+        .filter(is_dangling)
+        .map(|java_path| DanglingReference {
+            instance_id: None,
+            instance_name: None,
+            kind: "java".to_string(),
+            value: java_path.to_string_lossy().to_string(),
+        });
+    let per_instance = instances
+        .into_iter()
+        .filter_map(|instance| {
+            let java_path = instance.settings.java_path.filter(is_dangling)?;
+            Some(DanglingReference {
+                instance_id: Some(instance.id),
+                instance_name: Some(instance.name),
+                kind: "java".to_string(),
+                value: java_path.to_string_lossy().to_string(),
+            })
+        });
+    
+    Ok(global.into_iter().chain(per_instance).collect())
+}
@@ -42,6 +42,9 @@ pub struct BlockedMod {
     pub hash_algo: Option<String>,
     /// Expected filename
     pub filename: String,
+    /// Expected file size in bytes (if available)
+    #[serde(default)]
+    pub size: Option<u64>,
     /// CurseForge project ID
     pub project_id: u32,
     /// CurseForge file ID
@@ -84,6 +87,77 @@ impl Default for BlockedModsWatcherState {
     }
 }
 
+/// Event emitted when single-mod downloads are refused and need manual download
+pub const MOD_DOWNLOAD_BLOCKED_EVENT: &str = "mod-download-blocked";
+
+/// Payload of `MOD_DOWNLOAD_BLOCKED_EVENT`
+#[derive(Debug, Clone, Serialize)]
+pub struct ModDownloadBlocked {
+    pub instance_id: String,
+    pub blocked_mods: Vec<BlockedMod>,
+}
+
+/// Build blocked mod info for a single CurseForge file.
+/// `fallback_filename` is used when the file info can't be fetched.
+pub async fn resolve_blocked_mod(
+    client: &CurseForgeClient,
+    project_id: u32,
+    file_id: u32,
+    fallback_filename: Option<&str>,
+    target_folder: &str,
+) -> BlockedMod {
+    // Get project info for name and URL
+    let (name, base_url) = match client.get_mod(project_id).await {
+        Ok(project) => {
+            let url = project.links.website
+                .unwrap_or_else(|| format!("https://www.curseforge.com/minecraft/mc-mods/{}", project.slug));
+            (project.title, url)
+        }
+        Err(_) => (
+            format!("Unknown Mod ({})", project_id),
+            format!("https://www.curseforge.com/minecraft/mc-mods/{}", project_id),
+        ),
+    };
+    
+    // Construct direct file download URL (like Prism does)
+    // This links to the exact file page instead of just the project page
+    let website_url = format!("{}/download/{}", base_url, file_id);
+    
+    let fallback_filename = fallback_filename
+        .map(String::from)
+        .unwrap_or_else(|| format!("{}.jar", file_id));
+    
+    // Get file info for filename, size and hash
+    let (filename, size, hash, hash_algo) = match client.get_file(project_id, file_id).await {
+        Ok(version) => {
+            let file = version.files.first();
+            let filename = file
+                .map(|f| f.filename.clone())
+                .unwrap_or(fallback_filename);
+            
+            // Get SHA1 hash from first file
+            let hash = file.and_then(|f| f.sha1.clone());
+            
+            (filename, file.map(|f| f.size), hash, Some("sha1".to_string()))
+        }
+        Err(_) => (fallback_filename, None, None, None),
+    };
+    
+    BlockedMod {
+        name,
+        website_url,
+        hash,
+        hash_algo,
+        filename,
+        size,
+        project_id,
+        file_id,
+        target_folder: target_folder.to_string(),
+        matched: false,
+        local_path: None,
+    }
+}
+
 /// Resolve blocked mod information from CurseForge API
 /// Takes files that couldn't be downloaded and gets their project info
 pub async fn resolve_blocked_mods(
@@ -113,61 +187,22 @@ pub async fn resolve_blocked_mods(
                 Err(_) => continue,
             };
             
-            // Try to get download URL - if empty or error, it's blocked
+            // An empty download URL means the file is blocked
             let is_blocked = match client.get_download_url(project_id, file_id).await {
                 Ok(url) => url.is_empty(),
-                Err(_) => true,
+                Err(e) => {
+                    tracing::warn!("Couldn't check download URL for {}/{}: {}", project_id, file_id, e);
+                    false
+                }
             };
             
             if !is_blocked {
                 continue;
             }
             
-            // Get project info for name and URL
-            let (name, base_url) = match client.get_mod(project_id).await {
-                Ok(project) => {
-                    let url = project.links.website
-                        .unwrap_or_else(|| format!("https://www.curseforge.com/minecraft/mc-mods/{}", project.slug));
-                    (project.title, url)
-                }
-                Err(_) => (
-                    format!("Unknown Mod ({})", project_id),
-                    format!("https://www.curseforge.com/minecraft/mc-mods/{}", project_id),
-                ),
-            };
-            
-            // Construct direct file download URL (like Prism does)
-            // This links to the exact file page instead of just the project page
-            let website_url = format!("{}/download/{}", base_url, file_id);
-            
-            // Get file info for filename and hash
-            let (filename, hash, hash_algo) = match client.get_file(project_id, file_id).await {
-                Ok(version) => {
-                    let filename = version.files.first()
-                        .map(|f| f.filename.clone())
-                        .unwrap_or_else(|| format!("{}.jar", file_id));
-                    
-                    // Get SHA1 hash from first file
-                    let hash = version.files.first()
-                        .and_then(|f| f.sha1.clone());
-                    
-                    (filename, hash, Some("sha1".to_string()))
-                }
-                Err(_) => (format!("{}.jar", file_id), None, None),
-            };
-            
-            blocked_mods.push(BlockedMod {
-                name,
-                website_url,
-                hash,
-                hash_algo,
-                filename,
-                project_id,
-                file_id,
-                target_folder: target_folder.to_string(),
-                matched: false,
-                local_path: None,
-            });
+            blocked_mods.push(
+                resolve_blocked_mod(&client, project_id, file_id, None, target_folder).await
+            );
         }
     }
     
@@ -354,51 +389,9 @@ pub async fn resolve_blocked_files(
             Err(_) => continue,
         };
         
-        // Get project info for name and URL
-        let (name, base_url) = match client.get_mod(project_id).await {
-            Ok(project) => {
-                let url = project.links.website
-                    .unwrap_or_else(|| format!("https://www.curseforge.com/minecraft/mc-mods/{}", project.slug));
-                (project.title, url)
-            }
-            Err(_) => (
-                format!("Unknown Mod ({})", project_id),
-                format!("https://www.curseforge.com/minecraft/mc-mods/{}", project_id),
-            ),
-        };
-        
-        // Construct direct file download URL (like Prism does)
-        // This links to the exact file page instead of just the project page
-        let website_url = format!("{}/download/{}", base_url, file_id);
-        
-        // Get file info for filename and hash
-        let (filename, hash, hash_algo) = match client.get_file(project_id, file_id).await {
-            Ok(version) => {
-                let filename = version.files.first()
-                    .map(|f| f.filename.clone())
-                    .unwrap_or_else(|| file.filename.clone());
-                
-                // Get SHA1 hash from first file
-                let hash = version.files.first()
-                    .and_then(|f| f.sha1.clone());
-                
-                (filename, hash, Some("sha1".to_string()))
-            }
-            Err(_) => (file.filename.clone(), None, None),
-        };
-        
-        blocked_mods.push(BlockedMod {
-            name,
-            website_url,
-            hash,
-            hash_algo,
-            filename,
-            project_id,
-            file_id,
-            target_folder: target_folder.to_string(),
-            matched: false,
-            local_path: None,
-        });
+        blocked_mods.push(
+            resolve_blocked_mod(&client, project_id, file_id, Some(&file.filename), &target_folder).await
+        );
     }
     
     Ok(blocked_mods)
@@ -586,9 +579,8 @@ pub async fn start_blocked_mods_watcher(
         }));
     }
     
-    // Keep watcher alive - in a real app you'd store this somewhere
-    // For now we'll leak it (not ideal but works for the demo)
-    std::mem::forget(watcher);
+    // Keep the watcher alive until the session is replaced or the profile changes
+    state.blocked_mods_watchers.lock().unwrap().insert(session_id, watcher);
     
     Ok(())
 }
@@ -20,6 +20,7 @@
 
 use crate::commands::state::AppState;
 use crate::core::instance::{ModLoader, ModLoaderType};
+use crate::core::modloaders::{plan_modloader_install, InstallPlan};
 use serde::{Deserialize, Serialize};
 use tauri::State;
 
@@ -192,9 +193,42 @@ pub async fn install_mod_loader(
     
     instance.save().map_err(|e| format!("Failed to save instance: {}", e))?;
     
+    state.invalidate_instance_summary(&instance_id);
+    
     Ok(())
 }
 
+/// Dry run of `install_mod_loader`: resolve the libraries the loader would
+/// download for this instance, with their sizes and any that come from
+/// non-standard Maven repositories, without downloading them.
+#[tauri::command]
+pub async fn plan_mod_loader_install(
+    state: State<'_, AppState>,
+    instance_id: String,
+    loader_type: String,
+    loader_version: String,
+) -> Result<InstallPlan, String> {
+    let minecraft_version = state.instances.lock().unwrap()
+        .iter()
+        .find(|i| i.id == instance_id)
+        .map(|i| i.minecraft_version.clone())
+        .ok_or_else(|| "Instance not found".to_string())?;
+    let libraries_dir = state.config.lock().unwrap().libraries_dir();
+    
+    let loader_type = match loader_type.to_lowercase().as_str() {
+        "forge" => ModLoaderType::Forge,
+        "neoforge" => ModLoaderType::NeoForge,
+        "fabric" => ModLoaderType::Fabric,
+        "quilt" => ModLoaderType::Quilt,
+        "liteloader" => ModLoaderType::LiteLoader,
+        _ => return Err(format!("Unknown loader type: {}", loader_type)),
+    };
+    
+    plan_modloader_install(loader_type, &minecraft_version, &loader_version, &libraries_dir)
+        .await
+        .map_err(|e| format!("Failed to plan {} install: {}", loader_type.name(), e))
+}
+
 #[tauri::command]
 pub async fn move_component_up(
     state: State<'_, AppState>,
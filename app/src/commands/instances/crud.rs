@@ -18,12 +18,90 @@
 //! You should have received a copy of the GNU General Public License
 //! along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use super::{CreateInstanceRequest, InstanceInfo, parse_mod_loader};
 use crate::commands::state::AppState;
 use crate::core::files;
-use crate::core::instance::{setup_instance, Instance};
-use tauri::State;
+use crate::core::instance::{canonical_group_name, canonical_groups, create_game_dirs, portable_demo_lock_pin, setup_instance, Instance, SetupProgress, InstanceList, NameValidation};
+use crate::core::minecraft::world::{self, World, WorldVersion};
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager, State};
+use tokio::sync::mpsc;
+
+/// Event carrying an `InstanceSetupProgress` while a new instance downloads its game files
+pub const INSTANCE_SETUP_PROGRESS_EVENT: &str = "instance-setup-progress";
+/// Event carrying an `InstanceSetupResult` once setup succeeds
+pub const INSTANCE_SETUP_COMPLETE_EVENT: &str = "instance-setup-complete";
+/// Event carrying an `InstanceSetupResult` with the error when setup fails
+pub const INSTANCE_SETUP_FAILED_EVENT: &str = "instance-setup-failed";
+
+/// Progress of a new instance's setup
+#[derive(Debug, Clone, Serialize)]
+pub struct InstanceSetupProgress {
+    pub instance_id: String,
+    /// Current step ("libraries", "assets", "modloader", ...)
+    pub phase: String,
+    /// Bytes downloaded so far in the current step
+    pub current: Option<u64>,
+    /// Bytes to download in the current step
+    pub total: Option<u64>,
+    /// Overall progress from 0 to 100
+    pub percent: f32,
+}
+
+/// How a new instance's setup ended
+#[derive(Debug, Clone, Serialize)]
+pub struct InstanceSetupResult {
+    pub instance_id: String,
+    pub error: Option<String>,
+}
+
+/// Download a new instance's game files in the background, reporting progress
+/// through the instance setup events
+pub(super) fn spawn_instance_setup(app: AppHandle, instance: Instance, data_dir: PathBuf) {
+    // Counted before spawning so a profile switch right after creation sees it
+    let task = app.state::<AppState>().begin_task();
+    tokio::spawn(async move {
+        let _task = task;
+        let (progress_tx, mut progress_rx) = mpsc::channel::<SetupProgress>(100);
+        
+        let forward_app = app.clone();
+        let instance_id = instance.id.clone();
+        let forwarder = tokio::spawn(async move {
+            while let Some(progress) = progress_rx.recv().await {
+                let counts = progress.counts();
+                let _ = forward_app.emit(INSTANCE_SETUP_PROGRESS_EVENT, InstanceSetupProgress {
+                    instance_id: instance_id.clone(),
+                    phase: progress.phase().to_string(),
+                    current: counts.map(|(done, _)| done),
+                    total: counts.map(|(_, total)| total),
+                    percent: progress.fraction() * 100.0,
+                });
+            }
+        });
+        
+        // The sender is dropped when setup returns, which ends the forwarder
+        let result = setup_instance(&instance, &data_dir, Some(progress_tx)).await;
+        let _ = forwarder.await;
+        
+        match result {
+            Ok(()) => {
+                tracing::info!("Instance {} setup complete", instance.id);
+                let _ = app.emit(INSTANCE_SETUP_COMPLETE_EVENT, InstanceSetupResult {
+                    instance_id: instance.id.clone(),
+                    error: None,
+                });
+            }
+            Err(e) => {
+                tracing::error!("Failed to setup instance {}: {}", instance.id, e);
+                let _ = app.emit(INSTANCE_SETUP_FAILED_EVENT, InstanceSetupResult {
+                    instance_id: instance.id.clone(),
+                    error: Some(e.to_string()),
+                });
+            }
+        }
+    });
+}
 
 /// Sanitize a name for use as a directory name.
 /// Allows alphanumeric characters, spaces, hyphens, and underscores.
@@ -43,7 +121,7 @@ fn sanitize_name(name: &str) -> String {
 
 /// Generate a unique folder name for an instance based on user-provided name.
 /// If a folder with the name already exists, appends (1), (2), etc.
-fn generate_folder_name(name: &str, instances_dir: &Path) -> String {
+pub(super) fn generate_folder_name(name: &str, instances_dir: &Path) -> String {
     let base_name = sanitize_name(name);
     
     // Handle empty name edge case
@@ -72,7 +150,16 @@ fn generate_folder_name(name: &str, instances_dir: &Path) -> String {
 #[tauri::command]
 pub async fn get_instances(state: State<'_, AppState>) -> Result<Vec<InstanceInfo>, String> {
     let instances = state.instances.lock().unwrap();
-    Ok(instances.iter().map(InstanceInfo::from).collect())
+    
+    // Show groups that differ only by case as one, under the first-seen spelling
+    let canonical = canonical_groups(instances.iter().filter_map(|i| i.group.as_deref()));
+    Ok(instances.iter()
+        .map(|instance| {
+            let mut info = InstanceInfo::from(instance);
+            info.group = info.group.map(|g| canonical[&g.to_lowercase()].clone());
+            info
+        })
+        .collect())
 }
 
 #[tauri::command]
@@ -91,34 +178,27 @@ pub async fn get_instance_details(
 
 #[tauri::command]
 pub async fn create_instance(
+    app: AppHandle,
     state: State<'_, AppState>,
     request: CreateInstanceRequest,
 ) -> Result<String, String> {
-    let mod_loader = parse_mod_loader(&request.mod_loader_type, request.loader_version.clone());
+    let template = state.config.lock().unwrap().instance_template.clone();
+    
+    let mod_loader_type = if request.mod_loader_type.is_empty() {
+        template.mod_loader.clone().unwrap_or_default()
+    } else {
+        request.mod_loader_type.clone()
+    };
+    let mod_loader = parse_mod_loader(&mod_loader_type, request.loader_version.clone());
     
     // Generate unique folder name from user-provided name
-    let instances_dir = state.data_dir.join("instances");
+    let instances_dir = state.data_dir().join("instances");
     let folder_name = generate_folder_name(&request.name, &instances_dir);
     let instance_path = instances_dir.join(&folder_name);
     
-    // UUID is still used for internal ID (for lookups, events, etc.)
-    let instance_id = uuid::Uuid::new_v4().to_string();
-    
     // Create instance directory structure
-    std::fs::create_dir_all(&instance_path)
-        .map_err(|e| format!("Failed to create instance directory: {}", e))?;
-    
-    let game_dir = instance_path.join(".minecraft");
-    std::fs::create_dir_all(&game_dir)
-        .map_err(|e| format!("Failed to create game directory: {}", e))?;
-    std::fs::create_dir_all(game_dir.join("mods"))
-        .map_err(|e| format!("Failed to create mods directory: {}", e))?;
-    std::fs::create_dir_all(game_dir.join("resourcepacks"))
-        .map_err(|e| format!("Failed to create resourcepacks directory: {}", e))?;
-    std::fs::create_dir_all(game_dir.join("saves"))
-        .map_err(|e| format!("Failed to create saves directory: {}", e))?;
-    std::fs::create_dir_all(game_dir.join("screenshots"))
-        .map_err(|e| format!("Failed to create screenshots directory: {}", e))?;
+    create_game_dirs(&instance_path)
+        .map_err(|e| format!("Failed to create instance directories: {}", e))?;
     
     let mut instance = Instance::new(
         request.name,
@@ -128,10 +208,20 @@ pub async fn create_instance(
     
     instance.mod_loader = mod_loader;
     
+    // Explicit choices win over the instance template
+    instance.settings.min_memory = request.min_memory.or(template.min_memory);
+    instance.settings.max_memory = request.max_memory.or(template.max_memory);
+    instance.settings.jvm_args = request.jvm_args.or(template.jvm_args);
+    instance.settings.java_path = request.java_path.map(PathBuf::from).or(template.java_path);
+    
     // Set group if provided
     if let Some(group) = request.group {
         if !group.is_empty() {
-            instance.group = Some(group);
+            let existing = state.instances.lock().unwrap()
+                .iter()
+                .filter_map(|i| i.group.clone())
+                .collect::<Vec<_>>();
+            instance.group = Some(canonical_group_name(existing.iter().map(String::as_str), &group));
         }
     }
     
@@ -141,8 +231,7 @@ pub async fn create_instance(
     
     // Clone values before moving instance
     let instance_clone = instance.clone();
-    let data_dir_clone = state.data_dir.clone();
-    let id_clone = instance_id.clone();
+    let instance_id = instance.id.clone();
     
     // Add to state
     {
@@ -151,16 +240,100 @@ pub async fn create_instance(
     }
     
     // Setup instance (download files) in background
-    tokio::spawn(async move {
-        match setup_instance(&instance_clone, &data_dir_clone, None).await {
-            Ok(_) => println!("Instance {} setup complete", id_clone),
-            Err(e) => eprintln!("Failed to setup instance {}: {}", id_clone, e),
-        }
-    });
+    spawn_instance_setup(app, instance_clone, state.data_dir());
     
     Ok(instance_id)
 }
 
+/// Result of creating an instance from a world folder
+#[derive(Debug, Clone, Serialize)]
+pub struct WorldInstanceResult {
+    pub instance_id: String,
+    /// Version the world was last played on, used for the instance
+    pub minecraft_version: String,
+    /// Folder name of the world in the new instance's saves
+    pub world_folder: String,
+    pub world_version: WorldVersion,
+    /// Set when the world was played with mods; the instance is vanilla and
+    /// needs the matching loader and mods before the world is opened
+    pub warning: Option<String>,
+}
+
+/// Create a vanilla instance on the version a world was last played on and
+/// import the world into it. `name` defaults to the world's name when empty.
+#[tauri::command]
+pub async fn create_from_world(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    world_path: String,
+    name: String,
+) -> Result<WorldInstanceResult, String> {
+    let mut world_path = PathBuf::from(world_path);
+    if world_path.file_name().is_some_and(|f| f == "level.dat") {
+        world_path.pop();
+    }
+    
+    let world_version = world::read_world_version(&world_path)
+        .map_err(|e| format!("Failed to read the world's level.dat: {}", e))?;
+    let minecraft_version = world_version.name.clone()
+        .ok_or_else(|| "This world doesn't record the version it was played on (worlds from before 1.9 don't)".to_string())?;
+    
+    let name = if name.trim().is_empty() {
+        World::from_path(&world_path)
+            .map(|w| w.name)
+            .unwrap_or_else(|| minecraft_version.clone())
+    } else {
+        name
+    };
+    
+    let warning = world_version.is_modded().then(|| {
+        let brands: Vec<&str> = world_version.brands.iter()
+            .map(String::as_str)
+            .filter(|brand| *brand != "vanilla")
+            .collect();
+        let played_with = if brands.is_empty() {
+            "a modded game".to_string()
+        } else {
+            brands.join(", ")
+        };
+        format!(
+            "This world was played with {}. The instance was created as vanilla {}; add the matching mod loader and mods before opening the world, or modded blocks and items will be lost.",
+            played_with, minecraft_version
+        )
+    });
+    
+    let instance_id = create_instance(app, state.clone(), CreateInstanceRequest {
+        name,
+        minecraft_version: minecraft_version.clone(),
+        mod_loader_type: "Vanilla".to_string(),
+        loader_version: None,
+        group: None,
+        min_memory: None,
+        max_memory: None,
+        jvm_args: None,
+        java_path: None,
+    }).await?;
+    
+    let saves_dir = state.instances.lock().unwrap()
+        .iter()
+        .find(|i| i.id == instance_id)
+        .map(|i| i.game_dir().join("saves"))
+        .ok_or_else(|| "Instance not found".to_string())?;
+    let world_folder = world::import_world(&world_path, &saves_dir)
+        .map_err(|e| format!("Created the instance but failed to import the world: {}", e))?;
+    
+    state.invalidate_instance_summary(&instance_id);
+    tracing::info!("Created instance {} on {} from world {:?}", instance_id, minecraft_version, world_path);
+    
+    Ok(WorldInstanceResult {
+        instance_id,
+        minecraft_version,
+        world_folder,
+        world_version,
+        warning,
+    })
+}
+
 #[tauri::command]
 pub async fn delete_instance(
     state: State<'_, AppState>,
@@ -193,6 +366,14 @@ pub async fn delete_instance(
     
     // Remove from memory
     instances.retain(|i| i.id != instance_id);
+    drop(instances);
+    state.invalidate_instance_summary(&instance_id);
+    
+    // Forget the instance's remembered details tab
+    let mut config = state.config.lock().unwrap();
+    if config.ui.instance_tabs.remove(&instance_id).is_some() {
+        let _ = config.save();
+    }
     
     Ok(())
 }
@@ -235,7 +416,7 @@ pub async fn copy_instance(
         .ok_or_else(|| "Instance not found".to_string())?;
     
     // Generate folder name based on original name with " (Copy)" suffix
-    let instances_dir = state.data_dir.join("instances");
+    let instances_dir = state.data_dir().join("instances");
     let copy_name = format!("{} (Copy)", original.name);
     let folder_name = generate_folder_name(&copy_name, &instances_dir);
     let new_path = instances_dir.join(&folder_name);
@@ -253,6 +434,8 @@ pub async fn copy_instance(
     new_instance.name = copy_name;  // Display name includes (Copy)
     new_instance.path = new_path;
     new_instance.created_at = chrono::Utc::now();
+    // The demo lock PIN was salted for the original's id
+    new_instance.settings.demo_lock_pin = portable_demo_lock_pin(&original.settings, &original.id);
     
     new_instance.save().map_err(|e| format!("Failed to save instance: {}", e))?;
     
@@ -263,6 +446,8 @@ pub async fn copy_instance(
     Ok(new_id)
 }
 
+/// Move an instance to a group, or out of any group with None or an empty name.
+/// A name matching an existing group ignoring case joins that group.
 #[tauri::command]
 pub async fn change_instance_group(
     state: State<'_, AppState>,
@@ -270,9 +455,166 @@ pub async fn change_instance_group(
     group: Option<String>,
 ) -> Result<(), String> {
     let mut instances = state.instances.lock().unwrap();
+    let group = group
+        .map(|g| g.trim().to_string())
+        .filter(|g| !g.is_empty())
+        .map(|g| existing_group_name(&instances, &instance_id, &g));
+    
     if let Some(instance) = instances.iter_mut().find(|i| i.id == instance_id) {
         instance.group = group;
         instance.save().map_err(|e| format!("Failed to save instance: {}", e))?;
     }
     Ok(())
 }
+
+/// Spelling of the group `name` matches ignoring case, among the groups of
+/// instances other than `instance_id`
+fn existing_group_name(instances: &[Instance], instance_id: &str, name: &str) -> String {
+    let existing = instances.iter()
+        .filter(|i| i.id != instance_id)
+        .filter_map(|i| i.group.as_deref());
+    canonical_group_name(existing, name)
+}
+
+/// Groups consolidated by `merge_groups`
+#[derive(Debug, Clone, Serialize)]
+pub struct GroupMerge {
+    /// Spelling kept, the first one seen in the instance list
+    pub group: String,
+    /// Case variants merged into it
+    pub merged: Vec<String>,
+    /// Instances moved from the variants
+    pub instances: usize,
+}
+
+/// Merge groups whose names differ only by case ("Favorites" and
+/// "favorites") into the first-seen spelling. Collapsed state follows the
+/// instances, keeping the surviving group's when it has one. With `dry_run`
+/// set nothing changes and the merges that would happen are returned.
+#[tauri::command]
+pub async fn merge_groups(
+    state: State<'_, AppState>,
+    dry_run: Option<bool>,
+) -> Result<Vec<GroupMerge>, String> {
+    let dry_run = dry_run.unwrap_or(false);
+    let instances_dir = {
+        let config = state.config.lock().unwrap();
+        config.instances_dir()
+    };
+    
+    let merges = {
+        let mut instances = state.instances.lock().unwrap();
+        let canonical = canonical_groups(instances.iter().filter_map(|i| i.group.as_deref()));
+        
+        let mut merges: Vec<GroupMerge> = Vec::new();
+        for instance in instances.iter_mut() {
+            let Some(group) = instance.group.clone() else {
+                continue;
+            };
+            let target = &canonical[&group.to_lowercase()];
+            if *target == group {
+                continue;
+            }
+            
+            if !dry_run {
+                instance.group = Some(target.clone());
+                instance.save().map_err(|e| format!("Failed to save instance: {}", e))?;
+            }
+            
+            let merge = match merges.iter_mut().find(|m| m.group == *target) {
+                Some(merge) => merge,
+                None => {
+                    merges.push(GroupMerge { group: target.clone(), merged: Vec::new(), instances: 0 });
+                    merges.last_mut().unwrap()
+                }
+            };
+            if !merge.merged.contains(&group) {
+                merge.merged.push(group);
+            }
+            merge.instances += 1;
+        }
+        merges
+    };
+    
+    if !dry_run {
+        for merge in &merges {
+            for variant in &merge.merged {
+                if let Err(e) = InstanceList::rename_group_state(&instances_dir, variant, &merge.group) {
+                    tracing::warn!("Failed to migrate collapsed state for group {}: {}", variant, e);
+                }
+            }
+            tracing::info!("Merged groups {:?} into {} ({} instances)", merge.merged, merge.group, merge.instances);
+        }
+    }
+    
+    Ok(merges)
+}
+
+/// Rename a group by moving every instance in it to `new_name`.
+///
+/// Fails if `new_name` is already a group unless `merge` is set, in which case
+/// the instances join that group. The group's collapsed state moves with it.
+/// Returns the number of instances moved.
+#[tauri::command]
+pub async fn rename_group(
+    state: State<'_, AppState>,
+    old_name: String,
+    new_name: String,
+    merge: bool,
+) -> Result<usize, String> {
+    let new_name = new_name.trim().to_string();
+    if new_name.is_empty() {
+        return Err("Group name can't be empty".to_string());
+    }
+    if new_name == old_name {
+        return Ok(0);
+    }
+    
+    let instances_dir = {
+        let config = state.config.lock().unwrap();
+        config.instances_dir()
+    };
+    
+    let moved = {
+        let mut instances = state.instances.lock().unwrap();
+        
+        if !instances.iter().any(|i| i.group.as_deref() == Some(old_name.as_str())) {
+            return Err(format!("Group not found: {}", old_name));
+        }
+        if !merge && instances.iter().any(|i| i.group.as_deref() == Some(new_name.as_str())) {
+            return Err(format!("A group named {} already exists", new_name));
+        }
+        
+        let mut moved = 0;
+        for instance in instances.iter_mut().filter(|i| i.group.as_deref() == Some(old_name.as_str())) {
+            instance.group = Some(new_name.clone());
+            instance.save().map_err(|e| format!("Failed to save instance: {}", e))?;
+            moved += 1;
+        }
+        moved
+    };
+    
+    if let Err(e) = InstanceList::rename_group_state(&instances_dir, &old_name, &new_name) {
+        tracing::warn!("Failed to migrate collapsed state for group {}: {}", old_name, e);
+    }
+    
+    tracing::info!("Renamed group {} to {} ({} instances)", old_name, new_name, moved);
+    
+    Ok(moved)
+}
+
+/// Check a display name before creating an instance.
+///
+/// Instance folders don't depend on the name, but shortcuts and exports do,
+/// and duplicate names are hard to tell apart in the list. Invalid names come
+/// back with a cleaned-up, unique suggestion such as `Name (2)`.
+#[tauri::command]
+pub async fn validate_instance_name(
+    state: State<'_, AppState>,
+    name: String,
+) -> Result<NameValidation, String> {
+    let instances = state.instances.lock().unwrap();
+    let existing: Vec<&str> = instances.iter().map(|i| i.name.as_str()).collect();
+    
+    Ok(crate::core::instance::validate_instance_name(&name, &existing))
+}
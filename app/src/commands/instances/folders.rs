@@ -19,8 +19,22 @@
 //! along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::commands::state::AppState;
+use crate::core::instance::{find_mod_configs, find_mod_file, has_flat_layout, instance_disk_usage, migrate_flat_layout, normalize_instance_layout, split_mod_filename, InstanceDiskUsage};
+use crate::core::modplatform::parse_mod_jar;
+use serde::Serialize;
 use tauri::State;
 
+/// Config files found for a mod
+#[derive(Debug, Clone, Serialize)]
+pub struct ModConfigLookup {
+    /// The mod ID the configs were matched against
+    pub mod_id: String,
+    /// Matching config files and folders
+    pub candidates: Vec<String>,
+    /// Whether the single match was opened
+    pub opened: bool,
+}
+
 #[tauri::command]
 pub async fn open_instance_folder(
     state: State<'_, AppState>,
@@ -84,7 +98,7 @@ pub async fn open_libraries_folder(
 ) -> Result<(), String> {
     // Libraries are stored globally, not per-instance
     let config = state.config.lock().unwrap();
-    let libraries_dir = config.data_dir().join("libraries");
+    let libraries_dir = config.libraries_dir();
     
     // Ensure directory exists
     std::fs::create_dir_all(&libraries_dir)
@@ -95,7 +109,73 @@ pub async fn open_libraries_folder(
     Ok(())
 }
 
-/// Open a folder in the system file explorer
+/// Open the config for one mod.
+///
+/// `mod_id_or_filename` is a mod ID, a jar in the mods folder (whose ID is read
+/// from its metadata) or the name of an entry in `config/`. A single match is
+/// opened; several are returned without opening so the caller can pick one by
+/// name. Most mods only write their config on first launch.
+#[tauri::command]
+pub async fn open_mod_config(
+    state: State<'_, AppState>,
+    instance_id: String,
+    mod_id_or_filename: String,
+) -> Result<ModConfigLookup, String> {
+    let game_dir = {
+        let instances = state.instances.lock().unwrap();
+        instances.iter()
+            .find(|i| i.id == instance_id)
+            .ok_or_else(|| "Instance not found".to_string())?
+            .game_dir()
+    };
+    let config_dir = game_dir.join("config");
+    
+    // An exact config entry, e.g. one picked from an earlier ambiguous lookup
+    let exact = config_dir.join(&mod_id_or_filename);
+    let is_plain_name = std::path::Path::new(&mod_id_or_filename).file_name()
+        .is_some_and(|name| name == mod_id_or_filename.as_str());
+    if is_plain_name && exact.exists() {
+        open_folder(&exact)?;
+        return Ok(ModConfigLookup {
+            mod_id: mod_id_or_filename,
+            candidates: vec![exact.to_string_lossy().to_string()],
+            opened: true,
+        });
+    }
+    
+    let mod_id = match split_mod_filename(&mod_id_or_filename) {
+        Some((jar_name, _, _)) => {
+            let mods_dir = game_dir.join("mods");
+            let file = find_mod_file(&mods_dir, jar_name)
+                .ok_or_else(|| format!("{} isn't in the mods folder", jar_name))?;
+            parse_mod_jar(&mods_dir.join(file))
+                .map(|details| details.mod_id)
+                .ok_or_else(|| format!("Couldn't read the mod ID from {}", jar_name))?
+        }
+        None => mod_id_or_filename,
+    };
+    
+    let candidates = find_mod_configs(&config_dir, &mod_id);
+    if candidates.is_empty() {
+        return Err(format!(
+            "No config found for {}. Most mods create their config the first time the game runs with them.",
+            mod_id
+        ));
+    }
+    
+    let opened = candidates.len() == 1;
+    if opened {
+        open_folder(&candidates[0])?;
+    }
+    
+    Ok(ModConfigLookup {
+        mod_id,
+        candidates: candidates.iter().map(|p| p.to_string_lossy().to_string()).collect(),
+        opened,
+    })
+}
+
+/// Open a folder in the system file explorer, or a file in its default app
 fn open_folder(path: &std::path::Path) -> Result<(), String> {
     #[cfg(target_os = "windows")]
     {
@@ -123,3 +203,92 @@ fn open_folder(path: &std::path::Path) -> Result<(), String> {
     
     Ok(())
 }
+
+/// Layout fixes applied to one instance folder
+#[derive(Debug, Clone, Serialize)]
+pub struct LayoutRepair {
+    pub instance_id: String,
+    pub name: String,
+    pub changes: Vec<String>,
+    pub error: Option<String>,
+    /// Game files sit at the instance root; `migrate_instance_layout` moves them
+    pub flat_layout: bool,
+}
+
+/// Check every instance folder for the expected layout and fix what's
+/// missing. This also runs on startup; the command lets the UI re-run it
+/// after files were moved by hand. Only instances that needed changes,
+/// failed, or have a flat layout to migrate are returned.
+#[tauri::command]
+pub async fn normalize_instance_layouts(
+    state: State<'_, AppState>,
+) -> Result<Vec<LayoutRepair>, String> {
+    let instances: Vec<(String, String, std::path::PathBuf)> = state.instances.lock().unwrap()
+        .iter()
+        .map(|i| (i.id.clone(), i.name.clone(), i.path.clone()))
+        .collect();
+    
+    let mut repairs = Vec::new();
+    for (instance_id, name, path) in instances {
+        let (changes, error) = match normalize_instance_layout(&path) {
+            Ok(changes) => (changes, None),
+            Err(e) => (Vec::new(), Some(e.to_string())),
+        };
+        let flat_layout = has_flat_layout(&path);
+        if !changes.is_empty() || error.is_some() || flat_layout {
+            repairs.push(LayoutRepair { instance_id, name, changes, error, flat_layout });
+        }
+    }
+    
+    Ok(repairs)
+}
+
+/// Move the game files of an instance with a flat layout (mods, saves,
+/// config and so on at the instance root) into its `.minecraft` game
+/// directory, where the game is launched. Returns what was moved; empty
+/// when the instance already has the expected layout.
+#[tauri::command]
+pub async fn migrate_instance_layout(
+    state: State<'_, AppState>,
+    instance_id: String,
+) -> Result<Vec<String>, String> {
+    let path = state.instances.lock().unwrap()
+        .iter()
+        .find(|i| i.id == instance_id)
+        .map(|i| i.path.clone())
+        .ok_or_else(|| "Instance not found".to_string())?;
+    
+    if state.running_processes.lock().unwrap().contains_key(&instance_id) {
+        return Err("Cannot migrate the layout while the instance is running".to_string());
+    }
+    
+    let changes = migrate_flat_layout(&path)
+        .map_err(|e| format!("Failed to migrate instance layout: {}", e))?;
+    for change in &changes {
+        tracing::info!("Migrated layout of instance {}: {}", instance_id, change);
+    }
+    if !changes.is_empty() {
+        state.invalidate_instance_summary(&instance_id);
+    }
+    
+    Ok(changes)
+}
+
+/// How much space an instance folder uses, in bytes, split into mods,
+/// saves, resource packs, shader packs, per-instance libraries and
+/// everything else. The folder is walked off the async runtime.
+#[tauri::command]
+pub async fn get_instance_size_on_disk(
+    state: State<'_, AppState>,
+    instance_id: String,
+) -> Result<InstanceDiskUsage, String> {
+    let instance = state.instances.lock().unwrap()
+        .iter()
+        .find(|i| i.id == instance_id)
+        .cloned()
+        .ok_or_else(|| "Instance not found".to_string())?;
+    
+    tokio::task::spawn_blocking(move || instance_disk_usage(&instance))
+        .await
+        .map_err(|e| e.to_string())
+}
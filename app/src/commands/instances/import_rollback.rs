@@ -0,0 +1,255 @@
+//! Cancelling and rolling back modpack imports.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::commands::state::{AppState, BackgroundTask};
+use crate::core::instance::{ImportPlan, Instance};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tauri::State;
+
+/// Error of an import stopped by `cancel_import`
+pub(super) const IMPORT_CANCELLED: &str = "Import cancelled";
+
+/// Folders pack downloads are installed into
+const DOWNLOAD_DIRS: &[&str] = &["mods", "resourcepacks", "shaderpacks"];
+
+/// Cancellation flag of a running import, registered under the frontend's
+/// import ID until it is dropped
+pub(super) struct ImportToken<'a> {
+    state: &'a AppState,
+    id: Option<String>,
+    cancelled: Arc<AtomicBool>,
+    _task: BackgroundTask,
+}
+
+impl<'a> ImportToken<'a> {
+    pub(super) fn register(state: &'a AppState, import_id: Option<String>) -> Self {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        if let Some(ref id) = import_id {
+            state.import_cancellations.lock().unwrap().insert(id.clone(), cancelled.clone());
+        }
+        Self { state, id: import_id, cancelled, _task: state.begin_task() }
+    }
+    
+    pub(super) fn flag(&self) -> &AtomicBool {
+        &self.cancelled
+    }
+    
+    /// The flag for code that outlives a borrow of the token, like the core importer
+    pub(super) fn shared_flag(&self) -> Arc<AtomicBool> {
+        self.cancelled.clone()
+    }
+    
+    /// Stop here if the import was cancelled
+    pub(super) fn check(&self) -> Result<(), String> {
+        if self.cancelled.load(Ordering::Relaxed) {
+            Err(IMPORT_CANCELLED.to_string())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Drop for ImportToken<'_> {
+    fn drop(&mut self) {
+        if let Some(ref id) = self.id {
+            self.state.import_cancellations.lock().unwrap().remove(id);
+        }
+    }
+}
+
+/// Cancel a running import started with this `import_id`. The import stops
+/// at its next step and rolls back what it changed. Returns false when no
+/// such import is running.
+#[tauri::command]
+pub async fn cancel_import(
+    state: State<'_, AppState>,
+    import_id: String,
+) -> Result<bool, String> {
+    let cancellations = state.import_cancellations.lock().unwrap();
+    let Some(cancelled) = cancellations.get(&import_id) else {
+        return Ok(false);
+    };
+    
+    tracing::info!("Cancelling import {}", import_id);
+    cancelled.store(true, Ordering::Relaxed);
+    Ok(true)
+}
+
+/// Remove everything a failed or cancelled import into a new instance left
+/// behind and describe it in the returned error
+pub(super) fn discard_new_instance(
+    state: &AppState,
+    instance_id: &str,
+    instance_path: &Path,
+    overrides_path: Option<&PathBuf>,
+    error: String,
+) -> String {
+    if let Some(overrides_path) = overrides_path {
+        let _ = std::fs::remove_dir_all(overrides_path);
+    }
+    state.instances.lock().unwrap().retain(|i| i.id != instance_id);
+    state.invalidate_instance_summary(instance_id);
+    
+    if !instance_path.exists() {
+        return error;
+    }
+    match std::fs::remove_dir_all(instance_path) {
+        Ok(()) => {
+            tracing::info!("Rolled back import: removed {}", instance_path.display());
+            format!("{}; the partially imported instance was removed", error)
+        }
+        Err(e) => {
+            tracing::warn!("Failed to remove partial instance {}: {}", instance_path.display(), e);
+            format!("{}; the partial instance at {} could not be removed: {}", error, instance_path.display(), e)
+        }
+    }
+}
+
+/// What an import into an existing instance is about to change, so it can be
+/// put back if the import fails or is cancelled
+pub(super) struct ImportSnapshot {
+    instance: Instance,
+    game_dir: PathBuf,
+    /// Copies of the files the import overwrites
+    backup: tempfile::TempDir,
+    overwritten: Vec<String>,
+    added: Vec<String>,
+    /// Files in the download folders before the import
+    existing_downloads: HashSet<PathBuf>,
+    /// Folders the import could add files to that already existed
+    existing_dirs: HashSet<PathBuf>,
+}
+
+impl ImportSnapshot {
+    pub(super) fn take(instance: &Instance, plan: &ImportPlan) -> Result<Self, String> {
+        let game_dir = instance.game_dir();
+        let backup = tempfile::tempdir()
+            .map_err(|e| format!("Failed to create import backup: {}", e))?;
+        
+        for relative in &plan.overwritten {
+            let target = backup.path().join(relative);
+            if let Some(parent) = target.parent() {
+                std::fs::create_dir_all(parent)
+                    .map_err(|e| format!("Failed to back up {}: {}", relative, e))?;
+            }
+            std::fs::copy(game_dir.join(relative), &target)
+                .map_err(|e| format!("Failed to back up {}: {}", relative, e))?;
+        }
+        
+        let existing_downloads = DOWNLOAD_DIRS.iter()
+            .filter_map(|dir| std::fs::read_dir(game_dir.join(dir)).ok())
+            .flat_map(|entries| entries.flatten().map(|entry| entry.path()))
+            .collect();
+        
+        let existing_dirs = plan.added.iter()
+            .map(String::as_str)
+            .chain(DOWNLOAD_DIRS.iter().copied())
+            .flat_map(|relative| game_dir.join(relative).ancestors().map(Path::to_path_buf).collect::<Vec<_>>())
+            .filter(|dir| dir.starts_with(&game_dir) && dir.is_dir())
+            .collect();
+        
+        Ok(Self {
+            instance: instance.clone(),
+            game_dir,
+            backup,
+            overwritten: plan.overwritten.clone(),
+            added: plan.added.clone(),
+            existing_downloads,
+            existing_dirs,
+        })
+    }
+    
+    /// Put the instance back as it was: restore overwritten files, delete
+    /// added, `created` and downloaded ones along with the folders the import
+    /// made for them, and restore the instance's settings.
+    /// Returns `error` extended with what was rolled back.
+    pub(super) fn restore(
+        self,
+        state: &AppState,
+        created: &[PathBuf],
+        downloaded: &[PathBuf],
+        error: String,
+    ) -> String {
+        let mut failures = Vec::new();
+        
+        let mut restored = 0;
+        for relative in &self.overwritten {
+            match std::fs::copy(self.backup.path().join(relative), self.game_dir.join(relative)) {
+                Ok(_) => restored += 1,
+                Err(e) => failures.push(format!("{} ({})", relative, e)),
+            }
+        }
+        
+        let added = self.added.iter().map(|relative| self.game_dir.join(relative));
+        let new_files = created.iter()
+            .filter(|path| !path.is_dir())
+            .cloned();
+        let new_downloads = downloaded.iter()
+            .filter(|path| !self.existing_downloads.contains(*path))
+            .cloned();
+        let mut removed = 0;
+        let mut new_dirs: Vec<PathBuf> = created.iter()
+            .filter(|path| path.is_dir())
+            .cloned()
+            .collect();
+        for path in added.chain(new_files).chain(new_downloads) {
+            match std::fs::remove_file(&path) {
+                Ok(()) => removed += 1,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => failures.push(format!("{} ({})", path.display(), e)),
+            }
+            new_dirs.extend(path.ancestors().skip(1).map(Path::to_path_buf));
+        }
+        
+        // Folders the import made are removed once empty, deepest first
+        new_dirs.extend(DOWNLOAD_DIRS.iter().map(|dir| self.game_dir.join(dir)));
+        new_dirs.retain(|dir| {
+            dir.starts_with(&self.game_dir) && *dir != self.game_dir && !self.existing_dirs.contains(dir)
+        });
+        new_dirs.sort();
+        new_dirs.dedup();
+        new_dirs.sort_by_key(|dir| std::cmp::Reverse(dir.components().count()));
+        for dir in &new_dirs {
+            let _ = std::fs::remove_dir(dir);
+        }
+        
+        if let Err(e) = self.instance.save() {
+            failures.push(format!("instance settings ({})", e));
+        }
+        let instance_id = self.instance.id.clone();
+        if let Some(existing) = state.instances.lock().unwrap().iter_mut().find(|i| i.id == instance_id) {
+            *existing = self.instance;
+        }
+        state.invalidate_instance_summary(&instance_id);
+        
+        tracing::info!("Rolled back import into {}: restored {} files, removed {}", instance_id, restored, removed);
+        let mut report = format!(
+            "{}; rolled back: restored {} overwritten files and removed {} added files",
+            error, restored, removed
+        );
+        if !failures.is_empty() {
+            report.push_str(&format!(". Could not roll back: {}", failures.join(", ")));
+        }
+        report
+    }
+}
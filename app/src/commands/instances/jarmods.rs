@@ -20,6 +20,7 @@
 
 use crate::commands::state::AppState;
 use crate::core::files;
+use crate::core::instance::{list_jar_mods, save_jar_mod_order, JAR_MODS_DIR};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use tauri::State;
@@ -47,7 +48,7 @@ pub async fn add_jar_mod(
         .ok_or_else(|| "Instance not found".to_string())?;
     
     // Create jar mods directory if it doesn't exist
-    let jar_mods_dir = instance.path.join("jarmods");
+    let jar_mods_dir = instance.path.join(JAR_MODS_DIR);
     fs::create_dir_all(&jar_mods_dir)
         .map_err(|e| format!("Failed to create jarmods directory: {}", e))?;
     
@@ -60,6 +61,16 @@ pub async fn add_jar_mod(
     fs::copy(&source_path, &dest_path)
         .map_err(|e| format!("Failed to copy jar mod: {}", e))?;
     
+    // New jar mods are applied last
+    let file_name = file_name.to_string_lossy().to_string();
+    let mut order: Vec<String> = list_jar_mods(&instance.path)
+        .into_iter()
+        .filter(|name| *name != file_name)
+        .collect();
+    order.push(file_name);
+    save_jar_mod_order(&instance.path, &order)
+        .map_err(|e| format!("Failed to save jar mod order: {}", e))?;
+    
     tracing::info!("Added jar mod: {:?}", dest_path);
     
     Ok(())
@@ -75,24 +86,33 @@ pub async fn get_jar_mods(
         .find(|i| i.id == instance_id)
         .ok_or_else(|| "Instance not found".to_string())?;
     
-    let jar_mods_dir = instance.path.join("jarmods");
+    Ok(list_jar_mods(&instance.path))
+}
+
+/// Set the order jar mods are patched into the Minecraft jar.
+/// Later mods override files from earlier ones.
+#[tauri::command]
+pub async fn reorder_jar_mods(
+    state: State<'_, AppState>,
+    instance_id: String,
+    order: Vec<String>,
+) -> Result<(), String> {
+    let instances = state.instances.lock().unwrap();
+    let instance = instances.iter()
+        .find(|i| i.id == instance_id)
+        .ok_or_else(|| "Instance not found".to_string())?;
     
-    if !jar_mods_dir.exists() {
-        return Ok(Vec::new());
+    let installed = list_jar_mods(&instance.path);
+    if let Some(unknown) = order.iter().find(|name| !installed.contains(name)) {
+        return Err(format!("Jar mod not found: {}", unknown));
     }
     
-    let mut jar_mods = Vec::new();
-    if let Ok(entries) = fs::read_dir(&jar_mods_dir) {
-        for entry in entries.flatten() {
-            if let Some(name) = entry.file_name().to_str() {
-                if name.ends_with(".jar") || name.ends_with(".zip") {
-                    jar_mods.push(name.to_string());
-                }
-            }
-        }
-    }
+    save_jar_mod_order(&instance.path, &order)
+        .map_err(|e| format!("Failed to save jar mod order: {}", e))?;
     
-    Ok(jar_mods)
+    tracing::info!("Reordered jar mods for instance {}", instance_id);
+    
+    Ok(())
 }
 
 #[tauri::command]
@@ -112,7 +132,7 @@ pub async fn remove_jar_mod(
         .find(|i| i.id == instance_id)
         .ok_or_else(|| "Instance not found".to_string())?;
     
-    let jar_path = instance.path.join("jarmods").join(&jar_name);
+    let jar_path = instance.path.join(JAR_MODS_DIR).join(&jar_name);
     
     if jar_path.exists() {
         files::delete_file(&jar_path, use_recycle_bin)
@@ -18,10 +18,14 @@
 //! You should have received a copy of the GNU General Public License
 //! along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use super::log_stream::InstanceLogStream;
 use crate::commands::state::{AppState, RunningProcess};
+use crate::core::instance::mod_set_fingerprint;
+use crate::core::launch::{extract_natives, ExitStatus, ExtractedNatives, LaunchBlocker, LaunchTiming};
 use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
-use tauri::State;
+use tauri::{AppHandle, State};
 
 /// Status information returned by get_instance_status
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -32,6 +36,7 @@ pub struct InstanceStatus {
 
 #[tauri::command]
 pub async fn launch_instance(
+    app: AppHandle,
     state: State<'_, AppState>,
     instance_id: String,
     launch_mode: Option<String>,
@@ -39,20 +44,18 @@ pub async fn launch_instance(
     use crate::core::{
         accounts::{AccountList, AuthSession},
         config::Config,
-        launch::{LaunchContext, steps::create_default_launch_task},
+        launch::{check_account, LaunchContext, steps::create_default_launch_task},
         minecraft::version::LaunchFeatures,
     };
     
-    let mode = launch_mode.as_deref().unwrap_or("normal");
-    
     // Load config first to get accounts file path
     let config = Config::load().unwrap_or_default();
     let accounts_file = config.accounts_file();
     
     // Check ownership verification before allowing launch
     let account_list = AccountList::load(&accounts_file).unwrap_or_default();
-    if !account_list.is_ownership_verified() {
-        return Err("You must sign in with a Microsoft account that owns Minecraft before playing. Go to Accounts to sign in and verify game ownership.".to_string());
+    if let Some(blocker) = check_account(account_list.is_ownership_verified()) {
+        return Err(blocker.message);
     }
     
     // Find instance
@@ -64,14 +67,25 @@ pub async fn launch_instance(
             .clone()
     };
     
+    // A demo-locked instance launches as a demo whatever mode was asked for
+    let mode = if instance.settings.demo_locked {
+        tracing::info!("Instance '{}' is demo-locked", instance.name);
+        "demo"
+    } else {
+        launch_mode.as_deref().unwrap_or("normal")
+    };
+    
     // Determine launch features based on mode and instance settings
     let mut features = LaunchFeatures::normal();
     
-    // Check for custom resolution
-    if instance.settings.window_width.is_some() || instance.settings.window_height.is_some() {
+    // Check for custom resolution; fullscreen ignores the window size
+    let has_window_size = instance.settings.window_width.is_some() || instance.settings.window_height.is_some();
+    if has_window_size && !instance.settings.fullscreen {
         features.has_custom_resolution = true;
     }
     
+    apply_display_options(&app, &instance);
+    
     // Set demo mode feature if launching in demo mode
     if mode == "demo" {
         features.is_demo_user = true;
@@ -101,6 +115,8 @@ pub async fn launch_instance(
         }
     };
     
+    let logging_config = config.logging.clone();
+    
     // Create launch context with features
     let context = LaunchContext::with_features(instance.clone(), auth_session, config, features);
     
@@ -111,7 +127,14 @@ pub async fn launch_instance(
     let _log_receiver = launch_task.take_log_receiver();
     
     // Execute launch task
-    match launch_task.execute().await {
+    let launch_result = launch_task.execute().await;
+    
+    // Keep the step timing, including for failed launches
+    let timing = launch_task.timing().clone();
+    tracing::info!("Launch timing for '{}': {}", instance.name, timing.summary());
+    state.launch_timings.lock().unwrap().insert(instance_id.clone(), timing.clone());
+    
+    match launch_result {
         Ok(_) => {
             tracing::info!("Launch task completed successfully");
         }
@@ -122,19 +145,41 @@ pub async fn launch_instance(
     
     // Get the game process from the launch task
     if let Some(process_arc) = launch_task.take_game_process() {
-        use crate::core::logging::{LogEntry, LogLevel};
+        use crate::core::logging::{GameLogFile, LogEntry, LogLevel};
         
-        let logs = Arc::new(Mutex::new(Vec::new()));
+        let streaming = state.log_subscriptions.lock().unwrap().contains(&instance_id);
+        let logs = Arc::new(InstanceLogStream::new(
+            app.clone(),
+            instance_id.clone(),
+            logging_config.max_buffered_lines,
+            streaming,
+        ));
         let exit_code = Arc::new(Mutex::new(None));
+        let stop_readers = Arc::new(AtomicBool::new(false));
+        
+        // Optional on-disk copy of the game output
+        let game_log = if logging_config.log_game_output_to_file {
+            match GameLogFile::create(
+                &instance.logs_dir(),
+                logging_config.max_files,
+                logging_config.max_file_size_mb,
+            ) {
+                Ok(file) => Some(Arc::new(file)),
+                Err(e) => {
+                    tracing::warn!("Failed to create game log file: {}", e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
         
         // Add initial launcher log entry
-        {
-            let mut log_vec = logs.lock().unwrap();
-            log_vec.push(LogEntry::launcher_info(format!(
-                "Game process started for instance '{}'", 
-                instance.name
-            )));
-        }
+        logs.push(LogEntry::launcher_info(format!(
+            "Game process started for instance '{}'", 
+            instance.name
+        )));
+        logs.push(LogEntry::launcher_info(format!("Launch timing: {}", timing.summary())));
         
         // Try to get stdout/stderr from the child process
         {
@@ -147,32 +192,32 @@ pub async fn launch_instance(
             // Spawn a task to read stdout
             if let Some(stdout) = stdout {
                 let logs_clone = logs.clone();
+                let game_log = game_log.clone();
+                let stop = stop_readers.clone();
                 std::thread::spawn(move || {
-                    use std::io::{BufRead, BufReader};
-                    let reader = BufReader::new(stdout);
-                    for line in reader.lines() {
-                        if let Ok(line) = line {
-                            if let Ok(mut logs) = logs_clone.lock() {
-                                logs.push(LogEntry::game(line));
-                            }
+                    read_output_lines(stdout, &stop, |line| {
+                        let entry = LogEntry::game(line);
+                        if let Some(ref game_log) = game_log {
+                            game_log.write_entry(&entry);
                         }
-                    }
+                        logs_clone.push(entry);
+                    });
                 });
             }
             
             // Spawn a task to read stderr
             if let Some(stderr) = stderr {
                 let logs_clone = logs.clone();
+                let game_log = game_log.clone();
+                let stop = stop_readers.clone();
                 std::thread::spawn(move || {
-                    use std::io::{BufRead, BufReader};
-                    let reader = BufReader::new(stderr);
-                    for line in reader.lines() {
-                        if let Ok(line) = line {
-                            if let Ok(mut logs) = logs_clone.lock() {
-                                logs.push(LogEntry::stderr(line));
-                            }
+                    read_output_lines(stderr, &stop, |line| {
+                        let entry = LogEntry::stderr(line);
+                        if let Some(ref game_log) = game_log {
+                            game_log.write_entry(&entry);
                         }
-                    }
+                        logs_clone.push(entry);
+                    });
                 });
             }
         }
@@ -183,12 +228,25 @@ pub async fn launch_instance(
             let logs_clone = logs.clone();
             let exit_code_clone = exit_code.clone();
             let instance_name = instance.name.clone();
+            let stop = stop_readers.clone();
             
             std::thread::spawn(move || {
                 // Wait for the process to exit
                 loop {
                     std::thread::sleep(std::time::Duration::from_millis(500));
                     
+                    // Tracking was cleared: stop logging but keep polling so
+                    // the child is still reaped when it exits
+                    if stop.load(Ordering::Relaxed) {
+                        if let Ok(mut child) = process_arc_clone.lock() {
+                            if !matches!(child.try_wait(), Ok(None)) {
+                                break;
+                            }
+                            continue;
+                        }
+                        break;
+                    }
+                    
                     if let Ok(mut child) = process_arc_clone.lock() {
                         match child.try_wait() {
                             Ok(Some(status)) => {
@@ -200,29 +258,27 @@ pub async fn launch_instance(
                                 }
                                 
                                 // Add exit log entry
-                                if let Ok(mut log_vec) = logs_clone.lock() {
-                                    match code {
-                                        Some(0) => {
-                                            log_vec.push(LogEntry::launcher_info(format!(
-                                                "Game '{}' exited normally (exit code: 0)",
-                                                instance_name
-                                            )));
-                                        }
-                                        Some(code) => {
-                                            log_vec.push(LogEntry::launcher(
-                                                LogLevel::Error,
-                                                format!(
-                                                    "Game '{}' exited with error code: {}",
-                                                    instance_name, code
-                                                )
-                                            ));
-                                        }
-                                        None => {
-                                            log_vec.push(LogEntry::launcher_warn(format!(
-                                                "Game '{}' exited (signal terminated)",
-                                                instance_name
-                                            )));
-                                        }
+                                match code {
+                                    Some(0) => {
+                                        logs_clone.push(LogEntry::launcher_info(format!(
+                                            "Game '{}' exited normally (exit code: 0)",
+                                            instance_name
+                                        )));
+                                    }
+                                    Some(code) => {
+                                        logs_clone.push(LogEntry::launcher(
+                                            LogLevel::Error,
+                                            format!(
+                                                "Game '{}' exited with error code: {}",
+                                                instance_name, code
+                                            )
+                                        ));
+                                    }
+                                    None => {
+                                        logs_clone.push(LogEntry::launcher_warn(format!(
+                                            "Game '{}' exited (signal terminated)",
+                                            instance_name
+                                        )));
                                     }
                                 }
                                 break;
@@ -232,12 +288,10 @@ pub async fn launch_instance(
                             }
                             Err(e) => {
                                 // Error checking status
-                                if let Ok(mut log_vec) = logs_clone.lock() {
-                                    log_vec.push(LogEntry::launcher_error(format!(
-                                        "Error checking process status: {}",
-                                        e
-                                    )));
-                                }
+                                logs_clone.push(LogEntry::launcher_error(format!(
+                                    "Error checking process status: {}",
+                                    e
+                                )));
                                 break;
                             }
                         }
@@ -254,6 +308,7 @@ pub async fn launch_instance(
             logs,
             launch_time: std::time::Instant::now(),
             exit_code,
+            stop_readers,
         };
         
         let mut processes = state.running_processes.lock().unwrap();
@@ -266,6 +321,7 @@ pub async fn launch_instance(
         let mut instances = state.instances.lock().unwrap();
         if let Some(instance) = instances.iter_mut().find(|i| i.id == instance_id) {
             instance.update_last_played();
+            instance.last_launch_mod_set = Some(mod_set_fingerprint(&instance.mods_dir()));
             if let Err(e) = instance.save() {
                 tracing::error!("Failed to save instance after updating last played: {}", e);
             }
@@ -275,6 +331,97 @@ pub async fn launch_instance(
     Ok(())
 }
 
+/// Write the instance's fullscreen choice and monitor, if set, to its
+/// options.txt. The language is written when it's changed, not here, so a
+/// language picked in-game is kept.
+/// Forward lines from a child's output pipe until it closes or `stop` is set.
+///
+/// The blocking read happens on its own thread so the stop flag is noticed
+/// even while the game prints nothing; that thread exits once the pipe
+/// closes or the receiving side has gone away.
+fn read_output_lines<R>(pipe: R, stop: &AtomicBool, mut on_line: impl FnMut(String))
+where
+    R: std::io::Read + Send + 'static,
+{
+    use std::io::{BufRead, BufReader};
+    use std::sync::mpsc::{self, RecvTimeoutError};
+    
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        let mut reader = BufReader::new(pipe);
+        let mut buf = Vec::new();
+        // Decode lossily so a stray non-UTF-8 line doesn't end the stream
+        while matches!(reader.read_until(b'\n', &mut buf), Ok(n) if n > 0) {
+            let line = String::from_utf8_lossy(&buf)
+                .trim_end_matches(['\r', '\n'])
+                .to_string();
+            buf.clear();
+            if tx.send(line).is_err() {
+                break;
+            }
+        }
+    });
+    
+    while !stop.load(Ordering::Relaxed) {
+        match rx.recv_timeout(std::time::Duration::from_millis(250)) {
+            Ok(line) => on_line(line),
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+}
+
+fn apply_display_options(app: &AppHandle, instance: &crate::core::instance::Instance) {
+    use crate::core::minecraft::options::{display_options, set_options, OPTIONS_FILE};
+    
+    let monitor_size = instance.settings.monitor_index.and_then(|index| {
+        let monitors = app.available_monitors().ok()?;
+        let Some(monitor) = monitors.get(index as usize) else {
+            tracing::warn!("Monitor {} is no longer connected, using the default display", index + 1);
+            return None;
+        };
+        Some((monitor.size().width, monitor.size().height))
+    });
+    
+    let values = display_options(instance.settings.fullscreen, monitor_size);
+    if values.is_empty() {
+        return;
+    }
+    if let Err(e) = set_options(&instance.game_dir().join(OPTIONS_FILE), &values) {
+        tracing::warn!("Failed to write game settings to options.txt: {}", e);
+    }
+}
+
+/// Check whether an instance can be launched without trying.
+///
+/// Returns the issues that would make `launch_instance` fail, most
+/// fundamental first; an empty list means the instance is ready.
+#[tauri::command]
+pub async fn can_launch(
+    state: State<'_, AppState>,
+    instance_id: String,
+) -> Result<Vec<LaunchBlocker>, String> {
+    use crate::core::{accounts::AccountList, config::Config, launch::launch_blockers};
+    
+    let instance = {
+        let instances = state.instances.lock().unwrap();
+        instances.iter()
+            .find(|i| i.id == instance_id)
+            .ok_or_else(|| "Instance not found".to_string())?
+            .clone()
+    };
+    
+    let config = Config::load().unwrap_or_default();
+    let ownership_verified = AccountList::load(&config.accounts_file())
+        .unwrap_or_default()
+        .is_ownership_verified();
+    
+    // Java detection runs `java -version` for each installation
+    tokio::task::spawn_blocking(move || launch_blockers(&instance, &config, ownership_verified))
+        .await
+        .map_err(|e| format!("Failed to check launch readiness: {}", e))
+}
+
 #[tauri::command]
 pub async fn kill_instance(
     state: State<'_, AppState>,
@@ -287,17 +434,54 @@ pub async fn kill_instance(
                 let _ = child.kill();
             }
         }
+        state.last_exits.lock().unwrap().insert(instance_id, ExitStatus::killed());
     }
     Ok(())
 }
 
+/// Stop tracking an instance's process without touching the process itself.
+///
+/// For when the running heuristics get it wrong and the UI is stuck showing
+/// an instance as running. The entry is removed unconditionally and its log
+/// reader threads are told to stop; no play time is recorded.
+#[tauri::command]
+pub async fn clear_running_state(
+    state: State<'_, AppState>,
+    instance_id: String,
+) -> Result<bool, String> {
+    let removed = {
+        let mut processes = state.running_processes.lock().unwrap();
+        processes.remove(&instance_id)
+    };
+    
+    let Some(process_arc) = removed else {
+        return Ok(false);
+    };
+    
+    // Recover the entry even if a reader thread panicked while holding it
+    let process = process_arc.lock().unwrap_or_else(|e| e.into_inner());
+    process.stop_readers.store(true, Ordering::Relaxed);
+    
+    tracing::info!("Cleared running state for instance {}", instance_id);
+    Ok(true)
+}
+
 #[tauri::command]
 pub async fn is_instance_running(
     state: State<'_, AppState>,
     instance_id: String,
 ) -> Result<bool, String> {
-    let status = get_instance_status(state, instance_id).await?;
-    Ok(status.running)
+    // Prune every tracked entry whose child has exited, not just this one,
+    // so stale entries don't linger for instances nobody is polling
+    let tracked: Vec<String> = {
+        let processes = state.running_processes.lock().unwrap();
+        processes.keys().cloned().collect()
+    };
+    for id in tracked.iter().filter(|id| **id != instance_id) {
+        refresh_instance_status(&state, id);
+    }
+    
+    Ok(refresh_instance_status(&state, &instance_id).running)
 }
 
 #[tauri::command]
@@ -305,12 +489,19 @@ pub async fn get_instance_status(
     state: State<'_, AppState>,
     instance_id: String,
 ) -> Result<InstanceStatus, String> {
+    Ok(refresh_instance_status(&state, &instance_id))
+}
+
+/// Check a tracked process and drop its entry once the child has exited,
+/// recording play time on the way out
+pub(crate) fn refresh_instance_status(state: &AppState, instance_id: &str) -> InstanceStatus {
     let mut processes = state.running_processes.lock().unwrap();
     
     // Check if the process exists and whether it's still running
-    let result = if let Some(process_arc) = processes.get(&instance_id) {
-        let process = process_arc.lock().unwrap();
-        let mut child = process.child.lock().unwrap();
+    let result = if let Some(process_arc) = processes.get(instance_id) {
+        // A poisoned lock means a reader thread panicked; the data is still usable
+        let process = process_arc.lock().unwrap_or_else(|e| e.into_inner());
+        let mut child = process.child.lock().unwrap_or_else(|e| e.into_inner());
         
         // Get launch time before checking status
         let launch_time = process.launch_time;
@@ -327,11 +518,12 @@ pub async fn get_instance_status(
                 // Calculate play time
                 let play_time_secs = launch_time.elapsed().as_secs();
                 
+                record_exit(state, instance_id, exit_code, launch_time);
                 Some((false, exit_code, Some(play_time_secs)))
             }
             Ok(None) => {
                 // Process is still running
-                return Ok(InstanceStatus { running: true, exit_code: None });
+                return InstanceStatus { running: true, exit_code: None };
             }
             Err(_) => {
                 // Error checking status - use stored exit code if available
@@ -361,11 +553,39 @@ pub async fn get_instance_status(
             }
         }
         
-        processes.remove(&instance_id);
-        return Ok(InstanceStatus { running, exit_code });
+        processes.remove(instance_id);
+        return InstanceStatus { running, exit_code };
     }
     
-    Ok(InstanceStatus { running: false, exit_code: None })
+    InstanceStatus { running: false, exit_code: None }
+}
+
+/// Classify how a session ended and keep it as the instance's last exit
+fn record_exit(state: &AppState, instance_id: &str, exit_code: Option<i32>, launch_time: std::time::Instant) {
+    let game_dir = state.instances.lock().unwrap()
+        .iter()
+        .find(|i| i.id == instance_id)
+        .map(|i| i.game_dir());
+    let Some(game_dir) = game_dir else {
+        return;
+    };
+    
+    let started_at = std::time::SystemTime::now() - launch_time.elapsed();
+    let status = ExitStatus::classify(exit_code, &game_dir, started_at);
+    tracing::info!("Instance {} exited: {:?}", instance_id, status.reason);
+    state.last_exits.lock().unwrap().insert(instance_id.to_string(), status);
+}
+
+/// How the instance's most recent game session ended: a clean quit, a crash
+/// (with the crash report it left), an error exit without a report, or a
+/// kill from the launcher. `None` until a session has ended.
+#[tauri::command]
+pub async fn get_last_exit_status(
+    state: State<'_, AppState>,
+    instance_id: String,
+) -> Result<Option<ExitStatus>, String> {
+    refresh_instance_status(&state, &instance_id);
+    Ok(state.last_exits.lock().unwrap().get(&instance_id).cloned())
 }
 
 #[tauri::command]
@@ -376,10 +596,63 @@ pub async fn get_instance_logs(
     let processes = state.running_processes.lock().unwrap();
     if let Some(process_arc) = processes.get(&instance_id) {
         if let Ok(process) = process_arc.lock() {
-            if let Ok(logs) = process.logs.lock() {
-                return Ok(logs.clone());
-            }
+            return Ok(process.logs.entries());
         }
     }
     Ok(Vec::new())
 }
+
+/// Step-by-step timing of the instance's most recent launch in this session
+#[tauri::command]
+pub async fn get_launch_timing(
+    state: State<'_, AppState>,
+    instance_id: String,
+) -> Result<Option<LaunchTiming>, String> {
+    Ok(state.launch_timings.lock().unwrap().get(&instance_id).cloned())
+}
+
+/// Clear an instance's natives folder and extract its native libraries again,
+/// without launching, to fix `UnsatisfiedLinkError` and LWJGL crashes after a
+/// Java switch or a damaged natives folder. Reports the jars and libraries
+/// extracted and the platform they were picked for.
+#[tauri::command]
+pub async fn reextract_natives(
+    state: State<'_, AppState>,
+    instance_id: String,
+) -> Result<ExtractedNatives, String> {
+    use crate::core::{
+        accounts::AuthSession,
+        launch::{java_version_at, select_java, LaunchContext},
+    };
+    
+    let instance = state.instances.lock().unwrap()
+        .iter()
+        .find(|i| i.id == instance_id)
+        .cloned()
+        .ok_or_else(|| "Instance not found".to_string())?;
+    
+    // A running game has its natives loaded
+    if state.running_processes.lock().unwrap().contains_key(&instance_id) {
+        return Err("Cannot re-extract natives while the instance is running".to_string());
+    }
+    
+    let config = state.config.lock().unwrap().clone();
+    let mut context = LaunchContext::new(instance, AuthSession::offline("Player"), config);
+    
+    // The Java a launch would use decides how macOS natives are named
+    if let Ok((java_path, _)) = select_java(&context.instance, &context.config) {
+        context.java_version = java_version_at(&java_path).await;
+    }
+    
+    let extracted = extract_natives(&context, |_, _, _| {}).await?;
+    tracing::info!(
+        "Re-extracted {} native libraries from {} jars for {} ({}-bit) in instance {}",
+        extracted.libraries.len(),
+        extracted.jars.len(),
+        extracted.os,
+        extracted.arch,
+        instance_id
+    );
+    
+    Ok(extracted)
+}
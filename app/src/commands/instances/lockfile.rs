@@ -0,0 +1,80 @@
+//! Instance lockfile commands.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::path::PathBuf;
+use tauri::State;
+
+use crate::commands::state::AppState;
+use crate::core::instance::{Instance, Lockfile, LockfileDrift, DEFAULT_LOCK_IGNORE, LOCKFILE_NAME};
+
+fn find_instance(state: &AppState, instance_id: &str) -> Result<Instance, String> {
+    state.instances.lock().unwrap()
+        .iter()
+        .find(|i| i.id == instance_id)
+        .cloned()
+        .ok_or_else(|| "Instance not found".to_string())
+}
+
+/// Record every file in the instance's game directory with its SHA-512 in
+/// `oxide.lock` in the instance folder. `ignore` replaces the default list
+/// of volatile files (logs, worlds, screenshots) left out of the lockfile.
+#[tauri::command]
+pub async fn generate_lockfile(
+    state: State<'_, AppState>,
+    instance_id: String,
+    ignore: Option<Vec<String>>,
+) -> Result<Lockfile, String> {
+    let instance = find_instance(&state, &instance_id)?;
+    let ignore = ignore.unwrap_or_else(|| DEFAULT_LOCK_IGNORE.iter().map(|pattern| pattern.to_string()).collect());
+    
+    let lockfile = tokio::task::spawn_blocking(move || {
+        let lockfile = Lockfile::generate(&instance.game_dir(), &instance.minecraft_version, ignore)?;
+        lockfile.save(&instance.path.join(LOCKFILE_NAME))?;
+        Ok::<_, crate::core::error::OxideError>(lockfile)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+    .map_err(|e| format!("Failed to generate lockfile: {}", e))?;
+    
+    tracing::info!("Wrote lockfile with {} files for instance {}", lockfile.files.len(), instance_id);
+    Ok(lockfile)
+}
+
+/// Compare an instance against a lockfile, by default its own `oxide.lock`,
+/// and report files that are missing, changed or not in the lockfile.
+#[tauri::command]
+pub async fn verify_against_lockfile(
+    state: State<'_, AppState>,
+    instance_id: String,
+    lockfile_path: Option<String>,
+) -> Result<LockfileDrift, String> {
+    let instance = find_instance(&state, &instance_id)?;
+    let lockfile_path = lockfile_path
+        .map(PathBuf::from)
+        .unwrap_or_else(|| instance.path.join(LOCKFILE_NAME));
+    
+    let lockfile = Lockfile::load(&lockfile_path)
+        .map_err(|e| format!("Failed to read lockfile: {}", e))?;
+    
+    tokio::task::spawn_blocking(move || lockfile.verify(&instance.game_dir(), &instance.minecraft_version))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| format!("Failed to verify lockfile: {}", e))
+}
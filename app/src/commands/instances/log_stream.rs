@@ -0,0 +1,180 @@
+//! Streaming a running instance's log to the frontend.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::commands::state::AppState;
+use crate::core::logging::{LogBuffer, LogEntry};
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, State};
+
+/// Event carrying one new log line of a running instance
+pub const INSTANCE_LOG_LINE_EVENT: &str = "instance-log-line";
+
+/// Payload of an `instance-log-line` event
+#[derive(Debug, Clone, Serialize)]
+pub struct InstanceLogLine {
+    pub instance_id: String,
+    /// Position in the instance's log; lines below a snapshot's `next_seq` are already in it
+    pub seq: u64,
+    #[serde(flatten)]
+    pub entry: LogEntry,
+}
+
+/// The buffered log at the time a subscription starts
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct InstanceLogSnapshot {
+    pub entries: Vec<LogEntry>,
+    /// Sequence number of the first line that will be streamed
+    pub next_seq: u64,
+    /// Older lines no longer kept in memory
+    pub dropped: u64,
+}
+
+/// A launched instance with its log already streaming
+#[derive(Debug, Clone, Serialize)]
+pub struct LaunchedConsole {
+    /// The `instance_id` carried by this console's `instance-log-line` events
+    pub channel: String,
+    /// Lines logged before the launch returned
+    pub log: InstanceLogSnapshot,
+}
+
+/// Bounded log of a running instance that pushes new lines to subscribers
+pub struct InstanceLogStream {
+    instance_id: String,
+    app: AppHandle,
+    buffer: Mutex<LogBuffer>,
+    streaming: AtomicBool,
+}
+
+impl InstanceLogStream {
+    pub fn new(app: AppHandle, instance_id: String, capacity: usize, streaming: bool) -> Self {
+        Self {
+            instance_id,
+            app,
+            buffer: Mutex::new(LogBuffer::new(capacity)),
+            streaming: AtomicBool::new(streaming),
+        }
+    }
+    
+    /// Record a log line and emit it when the instance's log is subscribed to
+    pub fn push(&self, entry: LogEntry) {
+        let Ok(mut buffer) = self.buffer.lock() else {
+            return;
+        };
+        
+        // Emitting under the lock keeps events in order with snapshots
+        let line = self.streaming.load(Ordering::Relaxed).then(|| entry.clone());
+        let seq = buffer.push(entry);
+        if let Some(entry) = line {
+            let _ = self.app.emit(INSTANCE_LOG_LINE_EVENT, InstanceLogLine {
+                instance_id: self.instance_id.clone(),
+                seq,
+                entry,
+            });
+        }
+    }
+    
+    /// The buffered entries, oldest first
+    pub fn entries(&self) -> Vec<LogEntry> {
+        self.buffer.lock().map(|buffer| buffer.entries()).unwrap_or_default()
+    }
+    
+    /// Start or stop streaming, returning the buffer as of that moment
+    fn set_streaming(&self, streaming: bool) -> InstanceLogSnapshot {
+        let Ok(buffer) = self.buffer.lock() else {
+            return InstanceLogSnapshot::default();
+        };
+        
+        self.streaming.store(streaming, Ordering::Relaxed);
+        InstanceLogSnapshot {
+            entries: buffer.entries(),
+            next_seq: buffer.next_seq(),
+            dropped: buffer.dropped(),
+        }
+    }
+}
+
+/// Subscribe to an instance's log.
+///
+/// Returns the lines buffered so far; every later line arrives as an
+/// `instance-log-line` event. The subscription also covers later launches
+/// until `unsubscribe_instance_logs` is called.
+#[tauri::command]
+pub async fn subscribe_instance_logs(
+    state: State<'_, AppState>,
+    instance_id: String,
+) -> Result<InstanceLogSnapshot, String> {
+    state.log_subscriptions.lock().unwrap().insert(instance_id.clone());
+    
+    Ok(running_log_stream(&state, &instance_id)
+        .map(|stream| stream.set_streaming(true))
+        .unwrap_or_default())
+}
+
+/// Stop streaming an instance's log lines
+#[tauri::command]
+pub async fn unsubscribe_instance_logs(
+    state: State<'_, AppState>,
+    instance_id: String,
+) -> Result<(), String> {
+    state.log_subscriptions.lock().unwrap().remove(&instance_id);
+    
+    if let Some(stream) = running_log_stream(&state, &instance_id) {
+        stream.set_streaming(false);
+    }
+    Ok(())
+}
+
+/// Launch an instance with its log subscribed from the first line.
+///
+/// The subscription is made before the game starts, so every line is either
+/// in the returned snapshot or streamed as an `instance-log-line` event; start
+/// listening before calling and drop events whose `seq` is below
+/// `log.next_seq`. The subscription is undone if the launch fails and the
+/// log wasn't subscribed before.
+#[tauri::command]
+pub async fn launch_instance_with_console(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    instance_id: String,
+    launch_mode: Option<String>,
+) -> Result<LaunchedConsole, String> {
+    let newly_subscribed = state.log_subscriptions.lock().unwrap().insert(instance_id.clone());
+    
+    if let Err(e) = super::launch::launch_instance(app, state.clone(), instance_id.clone(), launch_mode).await {
+        if newly_subscribed {
+            state.log_subscriptions.lock().unwrap().remove(&instance_id);
+        }
+        return Err(e);
+    }
+    
+    let log = running_log_stream(&state, &instance_id)
+        .map(|stream| stream.set_streaming(true))
+        .unwrap_or_default();
+    Ok(LaunchedConsole { channel: instance_id, log })
+}
+
+fn running_log_stream(state: &AppState, instance_id: &str) -> Option<std::sync::Arc<InstanceLogStream>> {
+    let processes = state.running_processes.lock().unwrap();
+    let process = processes.get(instance_id)?.lock().ok()?;
+    Some(process.logs.clone())
+}
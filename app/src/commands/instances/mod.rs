@@ -20,21 +20,37 @@
 
 mod crud;
 mod launch;
+mod log_stream;
 mod components;
 mod jarmods;
 mod folders;
 mod transfer;
+mod modpack_install;
+mod modpack_update;
+mod import_rollback;
+mod server_pack;
 mod settings;
+mod summary;
+mod recipes;
+mod lockfile;
 pub mod blocked_mods;
 
 // Re-export all commands for registration in main.rs
 pub use crud::*;
 pub use launch::*;
+pub use log_stream::*;
 pub use components::*;
 pub use jarmods::*;
 pub use folders::*;
 pub use transfer::*;
+pub use modpack_install::*;
+pub use modpack_update::*;
+pub use import_rollback::*;
+pub use server_pack::*;
 pub use settings::*;
+pub use summary::*;
+pub use recipes::*;
+pub use lockfile::*;
 pub use blocked_mods::*;
 
 use crate::core::instance::{Instance, ModLoader, ModLoaderType};
@@ -56,6 +72,8 @@ pub struct InstanceInfo {
     pub last_played: Option<String>,
     pub total_played_seconds: u64,
     pub group: Option<String>,
+    /// Launches always run in demo mode
+    pub demo_locked: bool,
 }
 
 impl From<&Instance> for InstanceInfo {
@@ -95,6 +113,7 @@ impl From<&Instance> for InstanceInfo {
             last_played: inst.last_played.map(|dt| dt.to_string()),
             total_played_seconds: inst.total_played_seconds,
             group: inst.group.clone(),
+            demo_locked: inst.settings.demo_locked,
         }
     }
 }
@@ -104,9 +123,19 @@ impl From<&Instance> for InstanceInfo {
 pub struct CreateInstanceRequest {
     pub name: String,
     pub minecraft_version: String,
+    /// Empty to use the instance template's loader
     pub mod_loader_type: String,
     pub loader_version: Option<String>,
     pub group: Option<String>,
+    /// Overrides for the instance template; None keeps the template value
+    #[serde(default)]
+    pub min_memory: Option<u32>,
+    #[serde(default)]
+    pub max_memory: Option<u32>,
+    #[serde(default)]
+    pub jvm_args: Option<String>,
+    #[serde(default)]
+    pub java_path: Option<String>,
 }
 
 /// Instance settings update payload
@@ -116,18 +145,27 @@ pub struct InstanceSettingsUpdate {
     pub java_path: Option<String>,
     pub java_args: Option<String>,
     pub game_args: Option<String>,
+    /// Argument profile name; empty clears the assignment
+    pub argument_profile: Option<String>,
+    pub use_global_jvm_args: Option<bool>,
     pub min_memory: Option<u32>,
     pub max_memory: Option<u32>,
     pub window_width: Option<u32>,
     pub window_height: Option<u32>,
     pub start_maximized: Option<bool>,
     pub fullscreen: Option<bool>,
+    /// Monitor to go fullscreen on; a negative value clears it
+    pub monitor_index: Option<i64>,
+    /// Game language code; empty clears it
+    pub language: Option<String>,
     pub skip_java_compatibility_check: Option<bool>,
     pub close_launcher_on_launch: Option<bool>,
     pub quit_launcher_on_exit: Option<bool>,
     pub prelaunch_command: Option<String>,
     pub postexit_command: Option<String>,
     pub wrapper_command: Option<String>,
+    /// Environment variables for the game process; replaces the whole list
+    pub env_vars: Option<Vec<(String, String)>>,
     // Debug settings
     pub use_java_console: Option<bool>,
     pub disable_create_no_window: Option<bool>,
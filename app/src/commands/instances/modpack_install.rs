@@ -0,0 +1,302 @@
+//! Install a modpack straight from the platform browser.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::commands::modpacks::{get_modpack_details, get_modpack_versions, ModpackVersion};
+use crate::commands::state::AppState;
+use crate::core::download::download_binary_file;
+use crate::core::instance::{import_instance as core_import_instance, FileToDownload, ImportOptions};
+use crate::core::modplatform::curseforge::CurseForgeClient;
+use crate::core::modplatform::types::VersionType;
+use serde::Serialize;
+use std::path::Path;
+use tauri::{AppHandle, Emitter, State};
+
+use super::transfer::{
+    import_instance_from_url, import_mod_loader, BlockedFileInfo, ImportResultInfo, ModpackDownloadProgress,
+};
+
+/// What installing a modpack version involves, for a confirmation screen
+#[derive(Debug, Clone, Serialize)]
+pub struct ModpackInstallPlan {
+    pub name: String,
+    pub version_id: String,
+    pub version_number: String,
+    pub minecraft_version: String,
+    pub mod_loader_type: Option<String>,
+    pub mod_loader_version: Option<String>,
+    /// Mods the pack downloads plus mods shipped in its overrides
+    pub total_mods: usize,
+    /// Files downloaded during install, not counting the pack archive
+    pub total_files: usize,
+    /// Bytes to download: the pack archive plus every file with a known size
+    pub download_size: u64,
+    /// CurseForge files the author doesn't allow third-party downloads for;
+    /// these have to be downloaded by hand during install
+    pub blocked_files: Vec<BlockedFileInfo>,
+    pub warnings: Vec<String>,
+}
+
+/// Create a new instance from a modpack on Modrinth or CurseForge.
+///
+/// Downloads the pack file for `version_id` (or the latest installable
+/// version when none is given) and runs it through the regular modpack
+/// importer, so the pack is tracked for later updates. Progress is reported
+/// through `modpack-download-progress` events.
+#[tauri::command]
+pub async fn install_modpack(
+    state: State<'_, AppState>,
+    app: AppHandle,
+    platform: String,
+    project_id: String,
+    version_id: Option<String>,
+) -> Result<ImportResultInfo, String> {
+    let _task = state.begin_task();
+    emit_phase(&app, "resolving", None);
+    
+    let version = resolve_version(&platform, &project_id, version_id).await?;
+    let url = pack_download_url(&version)?;
+    
+    // Name and icon are cosmetic, so a failed details lookup isn't fatal
+    let details = get_modpack_details(project_id.clone(), platform.clone()).await;
+    let (name, icon_url) = match details {
+        Ok(details) => (Some(details.name), details.icon_url),
+        Err(e) => {
+            tracing::warn!("Failed to fetch details for modpack {}: {}", project_id, e);
+            (None, None)
+        }
+    };
+    
+    tracing::info!("Installing modpack {} version {} from {}", project_id, version.version_number, platform);
+    emit_phase(&app, "downloading", Some(version.filename.clone()));
+    
+    import_instance_from_url(
+        state,
+        app,
+        url,
+        name,
+        icon_url,
+        Some(project_id),
+        Some(version.id),
+        None,
+    ).await
+}
+
+/// Work out what installing a modpack would involve without creating anything.
+///
+/// Downloads and inspects the pack archive for `version_id` (or the latest
+/// installable version), then looks up the size and download permission of
+/// each CurseForge file so the frontend can show the real cost of a big pack
+/// and any manual downloads before `install_modpack` is called.
+#[tauri::command]
+pub async fn plan_modpack_install(
+    state: State<'_, AppState>,
+    platform: String,
+    project_id: String,
+    version_id: Option<String>,
+) -> Result<ModpackInstallPlan, String> {
+    let version = resolve_version(&platform, &project_id, version_id).await?;
+    let url = pack_download_url(&version)?;
+    
+    let (temp_dir, instances_dir) = {
+        let config = state.config.lock().unwrap();
+        (config.data_dir().join("temp"), config.instances_dir())
+    };
+    std::fs::create_dir_all(&temp_dir)
+        .map_err(|e| format!("Failed to create temp directory: {}", e))?;
+    
+    let archive_path = temp_dir.join(format!("plan-{}", version.filename));
+    download_binary_file(&url, &archive_path, None)
+        .await
+        .map_err(|e| format!("Failed to download modpack: {}", e))?;
+    
+    let options = ImportOptions {
+        name_override: None,
+        instances_dir,
+        cancel: None,
+    };
+    let result = core_import_instance(&archive_path, &options, None).await;
+    let _ = std::fs::remove_file(&archive_path);
+    let result = result.map_err(|e| format!("Failed to read modpack: {}", e))?;
+    
+    // Only the overrides' mods folder matters here; nothing is installed
+    let override_mods = match &result.overrides_path {
+        Some(overrides_path) => {
+            let count = count_jars(&overrides_path.join("mods"));
+            let _ = std::fs::remove_dir_all(overrides_path);
+            count
+        }
+        None => 0,
+    };
+    
+    let mod_loader = import_mod_loader(&result);
+    let mut plan = ModpackInstallPlan {
+        name: result.name.clone(),
+        version_id: version.id.clone(),
+        version_number: version.version_number.clone(),
+        minecraft_version: result.minecraft_version.clone(),
+        mod_loader_type: mod_loader.as_ref().map(|m| m.loader_type.name().to_string()),
+        mod_loader_version: mod_loader.as_ref().map(|m| m.version.clone()),
+        total_mods: override_mods,
+        total_files: result.files_to_download.len(),
+        download_size: version.size,
+        blocked_files: Vec::new(),
+        warnings: Vec::new(),
+    };
+    
+    let (curseforge_files, direct_files): (Vec<&FileToDownload>, Vec<&FileToDownload>) = result.files_to_download
+        .iter()
+        .partition(|f| f.platform_info.as_ref().is_some_and(|info| info.platform == "curseforge"));
+    
+    for file in direct_files {
+        plan.download_size += file.size;
+        if file.path.starts_with("mods/") {
+            plan.total_mods += 1;
+        }
+    }
+    
+    if !curseforge_files.is_empty() {
+        plan_curseforge_files(&curseforge_files, &mut plan).await;
+    }
+    
+    Ok(plan)
+}
+
+/// Add CurseForge files' sizes, mod count and blocked downloads to a plan
+async fn plan_curseforge_files(files: &[&FileToDownload], plan: &mut ModpackInstallPlan) {
+    let client = CurseForgeClient::new();
+    if !client.has_api_key() {
+        plan.total_mods += files.len();
+        plan.warnings.push(
+            "CurseForge API key not configured - download size and blocked files are unknown".to_string()
+        );
+        return;
+    }
+    
+    let ids: Vec<(u32, u32)> = files.iter()
+        .filter_map(|f| f.platform_info.as_ref())
+        .filter_map(|info| Some((info.project_id.parse().ok()?, info.file_id.parse().ok()?)))
+        .collect();
+    
+    let project_ids: Vec<u32> = ids.iter()
+        .map(|(project_id, _)| *project_id)
+        .collect::<std::collections::HashSet<_>>()
+        .into_iter()
+        .collect();
+    let class_ids = client.get_mods_class_ids(&project_ids).await.unwrap_or_else(|e| {
+        tracing::warn!("Failed to fetch project class IDs, counting every file as a mod: {}", e);
+        std::collections::HashMap::new()
+    });
+    
+    let lookups = ids.iter().map(|&(project_id, file_id)| {
+        let client = &client;
+        async move { (project_id, file_id, client.get_file(project_id, file_id).await) }
+    });
+    
+    let mut unresolved = 0;
+    for (project_id, file_id, file) in futures::future::join_all(lookups).await {
+        let folder = class_ids.get(&project_id)
+            .map(|&class_id| CurseForgeClient::get_resource_folder(class_id))
+            .unwrap_or("mods");
+        if folder == "mods" {
+            plan.total_mods += 1;
+        }
+        
+        let Some(file) = file.ok().and_then(|version| version.files.into_iter().next()) else {
+            unresolved += 1;
+            continue;
+        };
+        
+        plan.download_size += file.size;
+        // CurseForge returns no download URL when third-party downloads are disabled
+        if file.url.is_empty() {
+            plan.blocked_files.push(BlockedFileInfo {
+                project_id: project_id.to_string(),
+                file_id: file_id.to_string(),
+                filename: file.filename,
+            });
+        }
+    }
+    
+    if unresolved > 0 {
+        plan.warnings.push(format!(
+            "{} CurseForge files couldn't be looked up and aren't included in the download size",
+            unresolved
+        ));
+    }
+}
+
+/// Find the requested version, or the latest installable one
+async fn resolve_version(
+    platform: &str,
+    project_id: &str,
+    version_id: Option<String>,
+) -> Result<ModpackVersion, String> {
+    let versions = get_modpack_versions(project_id.to_string(), platform.to_string(), None, None).await?;
+    match version_id {
+        Some(id) => versions.into_iter()
+            .find(|v| v.id == id)
+            .ok_or_else(|| format!("Version {} not found for modpack {}", id, project_id)),
+        None => latest_installable(versions)
+            .ok_or_else(|| format!("No installable version found for modpack {}", project_id)),
+    }
+}
+
+fn pack_download_url(version: &ModpackVersion) -> Result<String, String> {
+    version.download_url.clone().ok_or_else(|| format!(
+        "{} can't be downloaded automatically; the author has disabled third-party downloads",
+        version.filename
+    ))
+}
+
+/// Number of jar files directly inside a folder
+fn count_jars(dir: &Path) -> usize {
+    std::fs::read_dir(dir)
+        .map(|entries| entries
+            .flatten()
+            .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "jar"))
+            .count())
+        .unwrap_or(0)
+}
+
+/// Pick the newest downloadable version, preferring releases over betas and alphas
+fn latest_installable(versions: Vec<ModpackVersion>) -> Option<ModpackVersion> {
+    let mut installable: Vec<ModpackVersion> = versions.into_iter()
+        .filter(|v| v.download_url.is_some())
+        .collect();
+    installable.sort_by(|a, b| b.date_published.cmp(&a.date_published));
+    
+    let release = installable.iter().position(|v| v.version_type == VersionType::Release);
+    match release {
+        Some(index) => Some(installable.swap_remove(index)),
+        None => installable.into_iter().next(),
+    }
+}
+
+fn emit_phase(app: &AppHandle, phase: &str, current_file: Option<String>) {
+    let _ = app.emit("modpack-download-progress", ModpackDownloadProgress {
+        downloaded: 0,
+        total: 1,
+        bytes_downloaded: 0,
+        speed_bps: 0,
+        current_file,
+        phase: Some(phase.to_string()),
+        total_bytes: None,
+    });
+}
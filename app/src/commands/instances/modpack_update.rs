@@ -0,0 +1,502 @@
+//! In-place modpack update command.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::commands::state::AppState;
+use crate::core::download::{download_binary_file, download_file};
+use crate::core::instance::{
+    file_hash, import_instance as core_import_instance, merge_action, pack_file_key,
+    FileToDownload, ImportOptions, ManagedPack, MergeAction, ModpackPlatform, PackFileIndex,
+};
+use crate::core::modplatform::curseforge::CurseForgeClient;
+use crate::core::modplatform::modrinth::ModrinthClient;
+use crate::core::rustwiz::pinned_mods;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::path::PathBuf;
+use tauri::{AppHandle, State};
+
+use super::transfer::{
+    apply_pack_metadata, download_curseforge_files, pack_override_hashes, BlockedFileInfo,
+};
+
+/// Suffix for the pack's copy of a file the user also changed
+const PACK_COPY_SUFFIX: &str = ".pack-new";
+
+/// A pack file changed both by the user and by the update
+#[derive(Debug, Clone, Serialize)]
+pub struct ModpackUpdateConflict {
+    /// Game-directory relative path (the user's copy is kept here)
+    pub path: String,
+    /// Where the pack's new version was saved for manual merging, if it could be
+    pub pack_copy: Option<String>,
+}
+
+/// Outcome of `update_modpack`
+#[derive(Debug, Clone, Serialize)]
+pub struct ModpackUpdateResult {
+    /// False when the instance was already on the latest pack version
+    pub updated: bool,
+    pub from_version: String,
+    pub to_version: String,
+    /// Pack files added or replaced
+    pub written: Vec<String>,
+    /// Pack files removed because the new version dropped them
+    pub removed: Vec<String>,
+    /// Pack files the user changed and the update left alone
+    pub preserved: Vec<String>,
+    /// Pinned mods the update kept at their current version
+    pub pinned: Vec<String>,
+    pub conflicts: Vec<ModpackUpdateConflict>,
+    pub warnings: Vec<String>,
+    /// Files that need manual download due to CurseForge restrictions
+    pub blocked_files: Vec<BlockedFileInfo>,
+}
+
+/// The newest version of a pack and where to download it
+struct LatestPackVersion {
+    id: String,
+    name: String,
+    url: String,
+}
+
+/// Update a modpack instance to the latest version of its pack.
+///
+/// Files are merged against what the pack originally installed (recorded in
+/// `modpack_files.json`): files the user never touched follow the pack,
+/// user edits are kept, and files the user added are never removed. When
+/// both sides changed a file, the user's copy stays in place, the pack's
+/// version is saved next to it with a `.pack-new` suffix and the file is
+/// reported as a conflict. New files are downloaded before anything in the
+/// instance changes, so a failed download leaves it as it was. Only pack
+/// versions for the instance's Minecraft version and loader are considered.
+#[tauri::command]
+pub async fn update_modpack(
+    state: State<'_, AppState>,
+    app: AppHandle,
+    instance_id: String,
+) -> Result<ModpackUpdateResult, String> {
+    let _task = state.begin_task();
+    let instance = {
+        let instances = state.instances.lock().unwrap();
+        instances.iter()
+            .find(|i| i.id == instance_id)
+            .ok_or_else(|| "Instance not found".to_string())?
+            .clone()
+    };
+    
+    if state.running_processes.lock().unwrap().contains_key(&instance_id) {
+        return Err("Cannot update a modpack while the instance is running".to_string());
+    }
+    
+    let managed_pack = instance.managed_pack.clone()
+        .ok_or_else(|| "This instance was not created from a modpack".to_string())?;
+    if managed_pack.pack_id.is_empty() {
+        return Err(format!(
+            "This instance doesn't record which {:?} project it was installed from. \
+             Reinstall it from the modpack browser to enable updates.",
+            managed_pack.platform
+        ));
+    }
+    
+    let loader = instance.mod_loader.as_ref()
+        .map(|ml| format!("{:?}", ml.loader_type).to_lowercase());
+    let latest = fetch_latest_pack_version(&managed_pack, &instance.minecraft_version, loader.as_deref()).await?;
+    
+    let mut update = ModpackUpdateResult {
+        updated: false,
+        from_version: managed_pack.version_name.clone(),
+        to_version: latest.name.clone(),
+        written: Vec::new(),
+        removed: Vec::new(),
+        preserved: Vec::new(),
+        pinned: Vec::new(),
+        conflicts: Vec::new(),
+        warnings: Vec::new(),
+        blocked_files: Vec::new(),
+    };
+    
+    if latest.id == managed_pack.version_id {
+        return Ok(update);
+    }
+    
+    let (temp_dir, instances_dir, libraries_dir) = {
+        let config = state.config.lock().unwrap();
+        (config.data_dir().join("temp"), config.instances_dir(), config.libraries_dir())
+    };
+    
+    // Download and unpack the new pack version
+    std::fs::create_dir_all(&temp_dir)
+        .map_err(|e| format!("Failed to create temp directory: {}", e))?;
+    let archive_path = temp_dir.join(format!("pack-update-{}.zip", instance_id));
+    
+    tracing::info!("Updating {} from {} to {}", instance.name, update.from_version, update.to_version);
+    
+    let download = if managed_pack.platform == ModpackPlatform::CurseForge {
+        download_binary_file(&latest.url, &archive_path, None).await
+    } else {
+        download_file(&latest.url, &archive_path, None).await
+    };
+    download.map_err(|e| format!("Failed to download pack update: {}", e))?;
+    
+    let options = ImportOptions {
+        name_override: None,
+        instances_dir,
+        cancel: None,
+    };
+    let result = core_import_instance(&archive_path, &options, None).await;
+    let _ = std::fs::remove_file(&archive_path);
+    let result = result.map_err(|e| format!("Failed to read pack update: {}", e))?;
+    
+    let game_dir = instance.game_dir();
+    let base = PackFileIndex::load(&instance.path);
+    let new_overrides = pack_override_hashes(&result);
+    let mut new_index = PackFileIndex {
+        version_id: latest.id.clone(),
+        files: new_overrides.clone(),
+        sources: BTreeMap::new(),
+    };
+    
+    // Paths the new version still provides; anything else the old version installed is dropped
+    let mut kept_paths: HashSet<String> = new_overrides.keys().cloned().collect();
+    
+    // Pinned mods are neither replaced nor removed
+    let pinned = pinned_mods(&instance.mods_dir());
+    let mut pinned_paths: BTreeSet<String> = BTreeSet::new();
+    
+    // Nothing in the game directory changes until every new file is downloaded
+    let mut override_writes: Vec<(String, String)> = Vec::new();
+    let mut to_remove: Vec<String> = Vec::new();
+    
+    // Overrides
+    let overrides_path = result.overrides_path.clone().unwrap_or_default();
+    for (relative, new_hash) in &new_overrides {
+        if pinned.contains_path(relative) {
+            kept_paths.remove(relative);
+            new_index.files.remove(relative);
+            pinned_paths.insert(relative.clone());
+            continue;
+        }
+        
+        let target = game_dir.join(relative);
+        let current = file_hash(&target);
+        let action = merge_action(base.files.get(relative).map(String::as_str), current.as_deref(), Some(new_hash));
+        
+        match action {
+            MergeAction::Write => {
+                override_writes.push((relative.clone(), relative.clone()));
+                update.written.push(relative.clone());
+            }
+            MergeAction::Conflict => {
+                let pack_copy = format!("{}{}", relative, PACK_COPY_SUFFIX);
+                override_writes.push((relative.clone(), pack_copy.clone()));
+                update.conflicts.push(ModpackUpdateConflict {
+                    path: relative.clone(),
+                    pack_copy: Some(pack_copy),
+                });
+            }
+            MergeAction::Keep if current.is_some() && current.as_ref() != Some(new_hash) => {
+                update.preserved.push(relative.clone());
+            }
+            MergeAction::Keep | MergeAction::Delete => {}
+        }
+    }
+    
+    // Downloads
+    let mut to_fetch: Vec<FileToDownload> = Vec::new();
+    for file in &result.files_to_download {
+        let key = pack_file_key(file);
+        
+        let pinned_jar = pack_file_project(file)
+            .and_then(|project| pinned.project_jar(&project))
+            .map(|jar| format!("mods/{}", jar));
+        if let Some(jar) = pinned_jar {
+            pinned_paths.insert(jar);
+            continue;
+        }
+        if pinned.contains_path(&file.path) {
+            pinned_paths.insert(file.path.clone());
+            continue;
+        }
+        
+        // CurseForge files are identified by project/file ID; the same file needs no download
+        let is_curseforge = file.platform_info.as_ref().is_some_and(|info| info.platform == "curseforge");
+        if is_curseforge {
+            match base.sources.get(&key) {
+                Some(relative) => {
+                    kept_paths.insert(relative.clone());
+                    if let Some(hash) = base.files.get(relative) {
+                        new_index.files.insert(relative.clone(), hash.clone());
+                    }
+                    new_index.sources.insert(key, relative.clone());
+                }
+                None => to_fetch.push(file.clone()),
+            }
+            continue;
+        }
+        
+        let target = game_dir.join(&file.path);
+        let current = file_hash(&target);
+        let new_hash = file.hash_sha1.clone().unwrap_or_default();
+        kept_paths.insert(file.path.clone());
+        new_index.files.insert(file.path.clone(), new_hash.clone());
+        new_index.sources.insert(key, file.path.clone());
+        
+        match merge_action(base.files.get(&file.path).map(String::as_str), current.as_deref(), Some(&new_hash)) {
+            MergeAction::Write => {
+                to_fetch.push(file.clone());
+                update.written.push(file.path.clone());
+            }
+            MergeAction::Conflict => update.conflicts.push(ModpackUpdateConflict {
+                path: file.path.clone(),
+                pack_copy: None,
+            }),
+            MergeAction::Keep if current.is_some() && current.as_deref() != Some(new_hash.as_str()) => {
+                update.preserved.push(file.path.clone());
+            }
+            MergeAction::Keep | MergeAction::Delete => {}
+        }
+    }
+    
+    // Files the new version dropped; user-added files are never in the base index
+    for (relative, base_hash) in &base.files {
+        if kept_paths.contains(relative) {
+            continue;
+        }
+        
+        // Keep tracking the pinned copy so unpinning lets a later update replace it
+        if pinned.contains_path(relative) {
+            new_index.files.insert(relative.clone(), base_hash.clone());
+            for (key, source) in &base.sources {
+                if source == relative {
+                    new_index.sources.insert(key.clone(), source.clone());
+                }
+            }
+            pinned_paths.insert(relative.clone());
+            continue;
+        }
+        
+        let target = game_dir.join(relative);
+        let current = file_hash(&target);
+        match merge_action(Some(base_hash), current.as_deref(), None) {
+            MergeAction::Delete => to_remove.push(relative.clone()),
+            MergeAction::Conflict => update.conflicts.push(ModpackUpdateConflict {
+                path: relative.clone(),
+                pack_copy: None,
+            }),
+            MergeAction::Keep | MergeAction::Write => {}
+        }
+    }
+    
+    // Download into a staging folder first, so a failed download leaves the instance as it was
+    let staging_dir = temp_dir.join(format!("pack-update-{}", instance_id));
+    let _ = std::fs::remove_dir_all(&staging_dir);
+    let cleanup = || {
+        if let Some(ref overrides_path) = result.overrides_path {
+            let _ = std::fs::remove_dir_all(overrides_path);
+        }
+        let _ = std::fs::remove_dir_all(&staging_dir);
+    };
+    
+    let mut staged = Vec::new();
+    if !to_fetch.is_empty() {
+        tracing::info!("Downloading {} updated modpack files...", to_fetch.len());
+        let dl_result = download_curseforge_files(&to_fetch, &staging_dir, Some(&app), None).await;
+        if !dl_result.warnings.is_empty() {
+            cleanup();
+            return Err(format!(
+                "The pack update couldn't be downloaded, so nothing was changed: {}",
+                dl_result.warnings.join("; ")
+            ));
+        }
+        staged = dl_result.installed;
+        update.blocked_files = dl_result.blocked_files;
+    }
+    
+    // Everything is here; apply the update
+    for (key, path) in staged {
+        let Ok(relative) = path.strip_prefix(&staging_dir).map(|p| p.to_path_buf()) else {
+            continue;
+        };
+        let target = game_dir.join(&relative);
+        if let Err(e) = move_pack_file(&path, &target) {
+            cleanup();
+            return Err(e);
+        }
+        if key.starts_with("curseforge:") {
+            update.written.push(relative.to_string_lossy().replace('\\', "/"));
+        }
+        new_index.record_download(&game_dir, key, &target);
+    }
+    for (source, dest) in override_writes {
+        if let Err(e) = copy_pack_file(&overrides_path.join(&source), &game_dir.join(&dest)) {
+            // A pack copy for a conflict is a convenience; the file itself isn't
+            let conflict = update.conflicts.iter_mut()
+                .find(|c| c.pack_copy.as_deref() == Some(dest.as_str()));
+            match conflict {
+                Some(conflict) => conflict.pack_copy = None,
+                None => {
+                    cleanup();
+                    return Err(e);
+                }
+            }
+        }
+    }
+    cleanup();
+    for relative in to_remove {
+        std::fs::remove_file(game_dir.join(&relative))
+            .map_err(|e| format!("Failed to remove {}: {}", relative, e))?;
+        update.removed.push(relative);
+    }
+    
+    update.pinned = pinned_paths.into_iter().collect();
+    
+    let new_pack = ManagedPack {
+        version_id: latest.id,
+        version_name: latest.name,
+        ..managed_pack
+    };
+    let instance = apply_pack_metadata(instance, &result, Some(new_pack), &libraries_dir).await?;
+    
+    if let Err(e) = new_index.save(&instance.path) {
+        update.warnings.push(format!("Failed to record modpack files: {}", e));
+    }
+    
+    {
+        let mut instances = state.instances.lock().unwrap();
+        if let Some(existing) = instances.iter_mut().find(|i| i.id == instance_id) {
+            *existing = instance;
+        }
+    }
+    state.invalidate_instance_summary(&instance_id);
+    
+    update.updated = true;
+    tracing::info!(
+        "Updated modpack instance {}: {} written, {} removed, {} preserved, {} conflicts",
+        instance_id, update.written.len(), update.removed.len(), update.preserved.len(), update.conflicts.len()
+    );
+    
+    Ok(update)
+}
+
+/// Look up the newest version of a pack on its platform that still targets
+/// the instance's Minecraft version and mod loader
+async fn fetch_latest_pack_version(
+    pack: &ManagedPack,
+    minecraft_version: &str,
+    loader: Option<&str>,
+) -> Result<LatestPackVersion, String> {
+    match pack.platform {
+        ModpackPlatform::Modrinth => {
+            let game_versions = [minecraft_version.to_string()];
+            let loaders = loader.map(|loader| vec![loader.to_string()]);
+            let versions = ModrinthClient::new()
+                .get_versions(&pack.pack_id, Some(&game_versions), loaders.as_deref())
+                .await
+                .map_err(|e| format!("Failed to fetch pack versions: {}", e))?;
+            
+            let latest = versions.into_iter()
+                .max_by_key(|v| v.date_published)
+                .ok_or_else(|| format!("The pack has no versions for Minecraft {}", minecraft_version))?;
+            let file = latest.files.iter()
+                .find(|f| f.primary)
+                .or_else(|| latest.files.first())
+                .ok_or_else(|| "The latest pack version has no files".to_string())?;
+            
+            Ok(LatestPackVersion {
+                url: file.url.clone(),
+                id: latest.id,
+                name: latest.version_number,
+            })
+        }
+        ModpackPlatform::CurseForge => {
+            let client = CurseForgeClient::new();
+            if !client.has_api_key() {
+                return Err("CurseForge API key not configured".to_string());
+            }
+            
+            let project_id: u32 = pack.pack_id.parse()
+                .map_err(|_| format!("Invalid CurseForge project ID: {}", pack.pack_id))?;
+            let latest = client.get_files(project_id, Some(minecraft_version), loader)
+                .await
+                .map_err(|e| format!("Failed to fetch pack versions: {}", e))?
+                .into_iter()
+                .max_by_key(|v| v.date_published)
+                .ok_or_else(|| format!("The pack has no versions for Minecraft {}", minecraft_version))?;
+            
+            let file_id: u32 = latest.id.parse()
+                .map_err(|_| format!("Invalid CurseForge file ID: {}", latest.id))?;
+            let url = client.get_download_url(project_id, file_id)
+                .await
+                .map_err(|e| format!("Failed to get download URL: {}", e))?;
+            if url.is_empty() {
+                return Err(format!(
+                    "{} {} must be downloaded manually from CurseForge",
+                    pack.pack_name, latest.name
+                ));
+            }
+            
+            Ok(LatestPackVersion {
+                id: latest.id,
+                name: latest.name,
+                url,
+            })
+        }
+        other => Err(format!("Updating {:?} modpacks is not supported", other)),
+    }
+}
+
+/// The Modrinth or CurseForge project a pack file belongs to. Modrinth packs
+/// only list download URLs, so the project ID is read from the CDN path.
+fn pack_file_project(file: &FileToDownload) -> Option<String> {
+    if let Some(ref info) = file.platform_info {
+        return Some(info.project_id.clone());
+    }
+    file.urls.iter().find_map(|url| {
+        url.strip_prefix("https://cdn.modrinth.com/data/")
+            .and_then(|rest| rest.split('/').next())
+            .filter(|id| !id.is_empty())
+            .map(str::to_string)
+    })
+}
+
+/// Move a downloaded file from the staging folder into the game directory
+fn move_pack_file(source: &std::path::Path, target: &PathBuf) -> Result<(), String> {
+    if let Some(parent) = target.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    if std::fs::rename(source, target).is_err() {
+        // The temp folder may be on another drive
+        copy_pack_file(source, target)?;
+        let _ = std::fs::remove_file(source);
+    }
+    Ok(())
+}
+
+/// Copy a file from the extracted pack into the game directory
+fn copy_pack_file(source: &std::path::Path, target: &PathBuf) -> Result<(), String> {
+    if let Some(parent) = target.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    std::fs::copy(source, target)
+        .map_err(|e| format!("Failed to write {}: {}", target.display(), e))?;
+    Ok(())
+}
@@ -0,0 +1,131 @@
+//! Library-wide export and import of instance recipes.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::path::PathBuf;
+use serde::Serialize;
+use tauri::{AppHandle, State};
+
+use super::crud::{generate_folder_name, spawn_instance_setup};
+use crate::commands::state::AppState;
+use crate::core::instance::{
+    create_game_dirs, read_recipe_manifest, restore_recipe_mods, write_recipe_manifest,
+    Instance, InstanceRecipe, RecipeModRestore,
+};
+
+/// Outcome of importing one instance from a recipe manifest
+#[derive(Debug, Clone, Serialize)]
+pub struct RecipeImportResult {
+    pub name: String,
+    /// ID of the created instance; None if it couldn't be created
+    pub instance_id: Option<String>,
+    pub mods_restored: usize,
+    /// Mods that failed to download, with the reason
+    pub mods_failed: Vec<String>,
+    /// Mods the recipe couldn't include because they have no platform metadata
+    pub untracked_mods: Vec<String>,
+    pub error: Option<String>,
+}
+
+/// Write every instance's recipe (version, loader, settings and tracked
+/// mods, but no files) to a single JSON manifest. Returns the number of
+/// instances exported.
+#[tauri::command]
+pub async fn export_all_recipes(
+    state: State<'_, AppState>,
+    output_path: String,
+) -> Result<usize, String> {
+    let instances = state.instances.lock().unwrap().clone();
+    
+    let manifest = write_recipe_manifest(&PathBuf::from(&output_path), &instances)
+        .map_err(|e| format!("Failed to export recipes: {}", e))?;
+    
+    tracing::info!("Exported {} instance recipes to {}", manifest.instances.len(), output_path);
+    Ok(manifest.instances.len())
+}
+
+/// Recreate every instance in a recipe manifest and download its mods again.
+///
+/// Each recipe becomes a new instance; existing instances are never
+/// touched. Game files are set up in the background as with a new instance.
+#[tauri::command]
+pub async fn import_all_recipes(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    input_path: String,
+) -> Result<Vec<RecipeImportResult>, String> {
+    let manifest = read_recipe_manifest(&PathBuf::from(&input_path))
+        .map_err(|e| format!("Failed to read recipes: {}", e))?;
+    
+    let mut results = Vec::new();
+    for recipe in &manifest.instances {
+        let mut result = RecipeImportResult {
+            name: recipe.name.clone(),
+            instance_id: None,
+            mods_restored: 0,
+            mods_failed: Vec::new(),
+            untracked_mods: recipe.untracked_mods.clone(),
+            error: None,
+        };
+        
+        match create_from_recipe(&app, &state, recipe).await {
+            Ok((instance_id, restore)) => {
+                result.instance_id = Some(instance_id);
+                result.mods_restored = restore.restored.len();
+                result.mods_failed = restore.failed;
+            }
+            Err(e) => {
+                tracing::warn!("Failed to import recipe {}: {}", recipe.name, e);
+                result.error = Some(e);
+            }
+        }
+        
+        results.push(result);
+    }
+    
+    Ok(results)
+}
+
+/// Create one instance from a recipe and download its mods
+async fn create_from_recipe(
+    app: &AppHandle,
+    state: &AppState,
+    recipe: &InstanceRecipe,
+) -> Result<(String, RecipeModRestore), String> {
+    let instances_dir = state.data_dir().join("instances");
+    let folder_name = generate_folder_name(&recipe.name, &instances_dir);
+    let instance_path = instances_dir.join(&folder_name);
+    
+    create_game_dirs(&instance_path)
+        .map_err(|e| format!("Failed to create instance directories: {}", e))?;
+    
+    let mut instance = Instance::new(recipe.name.clone(), instance_path, recipe.minecraft_version.clone());
+    recipe.apply_to(&mut instance);
+    instance.save().map_err(|e| format!("Failed to save instance: {}", e))?;
+    
+    let restore = restore_recipe_mods(&instance.mods_dir(), &recipe.mods).await;
+    
+    let instance_id = instance.id.clone();
+    let data_dir = state.data_dir();
+    state.instances.lock().unwrap().push(instance.clone());
+    
+    spawn_instance_setup(app.clone(), instance, data_dir);
+    
+    Ok((instance_id, restore))
+}
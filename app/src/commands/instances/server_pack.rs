@@ -0,0 +1,211 @@
+//! Server pack generation command.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::commands::state::AppState;
+use crate::core::download::download_file;
+use crate::core::instance::{
+    copy_server_mods, write_start_scripts, Instance, ModLoaderType, ServerLaunch, SERVER_CONFIG_DIRS,
+};
+use crate::core::minecraft::version::{fetch_version_data, fetch_version_manifest};
+use crate::core::modloaders::forge::ForgeInstaller;
+use crate::core::modloaders::neoforge::NeoForgeInstaller;
+use crate::core::modloaders::quilt::QUILT_INSTALLER_URL;
+use crate::core::modloaders::{fabric, ModloaderProfile};
+use serde::Serialize;
+use std::path::PathBuf;
+use tauri::State;
+
+/// Outcome of `generate_server_pack`
+#[derive(Debug, Clone, Serialize)]
+pub struct ServerPackResult {
+    pub output_dir: String,
+    /// Mod files copied into the server pack
+    pub included_mods: Vec<String>,
+    /// Mods left out because their metadata marks them client-only
+    pub client_only_mods: Vec<String>,
+    /// Included mods that don't declare a side; the first to check if the
+    /// server fails to start
+    pub unknown_side_mods: Vec<String>,
+    /// Server jar or loader installer placed in the pack
+    pub server_file: String,
+    pub warnings: Vec<String>,
+}
+
+/// Generate a server pack matching an instance.
+///
+/// Copies server-safe mods and configs into `output_dir` (which must be empty
+/// or not exist yet), downloads the server jar or loader installer, and writes
+/// `start.sh`/`start.bat` using the instance's memory settings. Loader
+/// installers run on the first start. The EULA still has to be accepted by
+/// whoever runs the server.
+#[tauri::command]
+pub async fn generate_server_pack(
+    state: State<'_, AppState>,
+    instance_id: String,
+    output_dir: String,
+) -> Result<ServerPackResult, String> {
+    let (instance, default_min, default_max) = {
+        let instances = state.instances.lock().unwrap();
+        let instance = instances.iter()
+            .find(|i| i.id == instance_id)
+            .ok_or_else(|| "Instance not found".to_string())?
+            .clone();
+        let config = state.config.lock().unwrap();
+        (instance, config.memory.min_memory, config.memory.max_memory)
+    };
+    
+    let output = PathBuf::from(&output_dir);
+    if output.exists() && std::fs::read_dir(&output).map(|mut e| e.next().is_some()).unwrap_or(true) {
+        return Err("The output folder must be empty".to_string());
+    }
+    std::fs::create_dir_all(&output)
+        .map_err(|e| format!("Failed to create output folder: {}", e))?;
+    
+    let mut warnings = Vec::new();
+    
+    // Server jar or loader installer
+    let (url, launch) = server_download(&instance).await?;
+    let server_file = match &launch {
+        ServerLaunch::Jar(file) => file.clone(),
+        ServerLaunch::ForgeInstaller { installer, .. } => installer.clone(),
+        ServerLaunch::QuiltInstaller { installer, .. } => installer.clone(),
+    };
+    download_file(&url, &output.join(&server_file), None)
+        .await
+        .map_err(|e| format!("Failed to download {}: {}", server_file, e))?;
+    
+    // Mods
+    let selection = copy_server_mods(&instance.mods_dir(), &output.join("mods"))
+        .map_err(|e| format!("Failed to copy mods: {}", e))?;
+    if !selection.client_only.is_empty() {
+        warnings.push(format!(
+            "Left out {} client-only mod(s): {}",
+            selection.client_only.len(),
+            selection.client_only.join(", ")
+        ));
+    }
+    
+    // Configs
+    let game_dir = instance.game_dir();
+    for dir in SERVER_CONFIG_DIRS {
+        let source = game_dir.join(dir);
+        if source.is_dir() {
+            copy_dir(&source, &output.join(dir))
+                .map_err(|e| format!("Failed to copy {}: {}", dir, e))?;
+        }
+    }
+    
+    // Start scripts
+    let min_memory = instance.settings.min_memory.unwrap_or(default_min);
+    let max_memory = instance.settings.max_memory.unwrap_or(default_max);
+    write_start_scripts(&output, &launch, min_memory, max_memory)
+        .map_err(|e| format!("Failed to write start scripts: {}", e))?;
+    
+    tracing::info!(
+        "Generated server pack for {} in {:?} ({} mods, {} client-only left out)",
+        instance.name, output, selection.included.len(), selection.client_only.len()
+    );
+    
+    Ok(ServerPackResult {
+        output_dir,
+        included_mods: selection.included,
+        client_only_mods: selection.client_only,
+        unknown_side_mods: selection.unknown_side,
+        server_file,
+        warnings,
+    })
+}
+
+/// Work out what to download for the instance's loader and how to start it
+async fn server_download(instance: &Instance) -> Result<(String, ServerLaunch), String> {
+    let minecraft_version = &instance.minecraft_version;
+    
+    let Some(ref loader) = instance.mod_loader else {
+        let manifest = fetch_version_manifest()
+            .await
+            .map_err(|e| format!("Failed to fetch version manifest: {}", e))?;
+        let version_info = manifest.get_version(minecraft_version)
+            .ok_or_else(|| format!("Version {} not found", minecraft_version))?;
+        let version_data = fetch_version_data(version_info)
+            .await
+            .map_err(|e| format!("Failed to fetch version data: {}", e))?;
+        let server = version_data.downloads.server
+            .ok_or_else(|| format!("Minecraft {} has no server download", minecraft_version))?;
+        
+        return Ok((server.url, ServerLaunch::Jar("server.jar".to_string())));
+    };
+    
+    // Prefer the installed profile's version over the configured one (which may be "latest")
+    let loader_version = ModloaderProfile::load(&instance.path.join("modloader_profile.json"))
+        .ok()
+        .map(|profile| profile.version)
+        .filter(|v| !v.is_empty())
+        .unwrap_or_else(|| loader.version.clone());
+    
+    match loader.loader_type {
+        ModLoaderType::Fabric => {
+            let url = fabric::get_server_launcher_url(minecraft_version, &loader_version)
+                .await
+                .map_err(|e| format!("Failed to resolve Fabric server launcher: {}", e))?;
+            Ok((url, ServerLaunch::Jar("fabric-server-launch.jar".to_string())))
+        }
+        ModLoaderType::Quilt => Ok((
+            QUILT_INSTALLER_URL.to_string(),
+            ServerLaunch::QuiltInstaller {
+                installer: "quilt-installer.jar".to_string(),
+                minecraft_version: minecraft_version.clone(),
+                loader_version,
+            },
+        )),
+        ModLoaderType::Forge => Ok((
+            ForgeInstaller::new().get_installer_url(minecraft_version, &loader_version),
+            ServerLaunch::ForgeInstaller {
+                installer: "forge-installer.jar".to_string(),
+                jar_prefix: "forge-".to_string(),
+            },
+        )),
+        ModLoaderType::NeoForge => Ok((
+            NeoForgeInstaller::new().get_installer_url(&loader_version),
+            ServerLaunch::ForgeInstaller {
+                installer: "neoforge-installer.jar".to_string(),
+                jar_prefix: "neoforge-".to_string(),
+            },
+        )),
+        other => Err(format!("{} has no server version", other.name())),
+    }
+}
+
+/// Copy a directory recursively
+fn copy_dir(src: &PathBuf, dst: &PathBuf) -> std::io::Result<()> {
+    std::fs::create_dir_all(dst)?;
+    
+    for entry in std::fs::read_dir(src)? {
+        let entry = entry?;
+        let dst_path = dst.join(entry.file_name());
+        
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &dst_path)?;
+        } else {
+            std::fs::copy(entry.path(), &dst_path)?;
+        }
+    }
+    
+    Ok(())
+}
@@ -20,9 +20,20 @@
 
 use super::InstanceSettingsUpdate;
 use crate::commands::state::AppState;
+use crate::core::instance::{list_jar_mods, set_demo_lock, share_folder, Instance, supports_jar_mods, unshare_folder, SharedFolder, PATCHED_JAR_NAME};
+use crate::core::accounts::AuthSession;
+use crate::core::java::gc_flags::apply_aikars_flags;
+use crate::core::java::memory::validate_memory;
+use crate::core::launch::steps::LaunchGameStep;
+use crate::core::launch::{classpath_entries, wrapper_reads_token_file, ClasspathEntry, EffectiveSettings, LaunchContext, RuntimeConfig};
+use crate::core::minecraft::local_versions::load_version_json;
+use crate::core::config::{Config, MemoryConfig};
+use crate::core::minecraft::version::{fetch_version_data, fetch_version_manifest, supports_demo, VersionData};
+use crate::core::modloaders::ModloaderProfile;
+use crate::core::minecraft::options::{is_known_language, language_for_version, normalize_language, reset_options, set_options, ResetOptionsFile, OPTIONS_FILE};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
-use tauri::State;
+use tauri::{AppHandle, State};
 
 /// Instance settings returned to frontend
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -32,10 +43,14 @@ pub struct InstanceSettingsResponse {
     pub memory_max_mb: u32,
     pub java_args: String,
     pub game_args: String,
+    pub argument_profile: Option<String>,
+    pub use_global_jvm_args: bool,
     pub window_width: u32,
     pub window_height: u32,
     pub start_maximized: bool,
     pub fullscreen: bool,
+    pub monitor_index: Option<u32>,
+    pub language: Option<String>,
     pub console_mode: String,
     pub pre_launch_hook: Option<String>,
     pub post_exit_hook: Option<String>,
@@ -44,10 +59,17 @@ pub struct InstanceSettingsResponse {
     pub game_dir_override: Option<String>,
     pub skip_java_compatibility_check: bool,
     pub wrapper_command: Option<String>,
+    pub env_vars: Vec<(String, String)>,
     // Debug settings
     pub use_java_console: bool,
     pub disable_create_no_window: bool,
     pub log_launch_command: bool,
+    // Shared folders
+    pub shared_screenshots: bool,
+    pub shared_saves: bool,
+    // Demo lock
+    pub demo_locked: bool,
+    pub demo_lock_has_pin: bool,
 }
 
 #[tauri::command]
@@ -69,10 +91,14 @@ pub async fn get_instance_settings(
         memory_max_mb: instance.settings.max_memory.unwrap_or(config.memory.max_memory),
         java_args: instance.settings.jvm_args.clone().unwrap_or_default(),
         game_args: instance.settings.game_args.clone().unwrap_or_default(),
+        argument_profile: instance.settings.argument_profile.clone(),
+        use_global_jvm_args: instance.settings.use_global_jvm_args,
         window_width: instance.settings.window_width.unwrap_or(config.minecraft.window_width),
         window_height: instance.settings.window_height.unwrap_or(config.minecraft.window_height),
         start_maximized: instance.settings.fullscreen,
         fullscreen: instance.settings.fullscreen,
+        monitor_index: instance.settings.monitor_index,
+        language: instance.settings.language.clone(),
         console_mode: "on_error".to_string(), // Default
         pre_launch_hook: instance.settings.pre_launch_command.clone(),
         post_exit_hook: instance.settings.post_exit_command.clone(),
@@ -81,19 +107,85 @@ pub async fn get_instance_settings(
         game_dir_override: None, // Not available in current InstanceSettings struct
         skip_java_compatibility_check: instance.settings.skip_java_compatibility_check,
         wrapper_command: instance.settings.wrapper_command.clone(),
+        env_vars: instance.settings.env_vars.clone(),
         // Debug settings
         use_java_console: instance.settings.use_java_console,
         disable_create_no_window: instance.settings.disable_create_no_window,
         log_launch_command: instance.settings.log_launch_command,
+        shared_screenshots: instance.settings.shared_screenshots,
+        shared_saves: instance.settings.shared_saves,
+        demo_locked: instance.settings.demo_locked,
+        demo_lock_has_pin: instance.settings.demo_lock_pin.is_some(),
     })
 }
 
+/// Correct an instance's memory overrides, validating the effective values
+/// with the global settings standing in for unset overrides. Returns the
+/// validation's warnings.
+fn validate_instance_memory(instance: &mut Instance, global: &MemoryConfig) -> Vec<String> {
+    let min = instance.settings.min_memory.unwrap_or(global.min_memory);
+    let max = instance.settings.max_memory.unwrap_or(global.max_memory);
+    let memory = validate_memory(min, max);
+    
+    for warning in &memory.warnings {
+        tracing::warn!("Memory settings for '{}': {}", instance.name, warning);
+    }
+    if memory.min_memory != min {
+        instance.settings.min_memory = Some(memory.min_memory);
+    }
+    if memory.max_memory != max {
+        instance.settings.max_memory = Some(memory.max_memory);
+    }
+    memory.warnings
+}
+
+/// Warnings about instance settings that were saved
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct InstanceSettingsSaved {
+    /// Set for a new language code the game doesn't ship with
+    pub language_warning: Option<String>,
+    /// Why the memory settings were corrected or may cause problems
+    pub memory_warnings: Vec<String>,
+}
+
+/// Apply the settings that are set. Returns warnings for a new language
+/// code the game doesn't ship with and for the memory settings.
 #[tauri::command]
 pub async fn update_instance_settings(
+    app: AppHandle,
     state: State<'_, AppState>,
     instance_id: String,
     settings: InstanceSettingsUpdate,
-) -> Result<(), String> {
+) -> Result<InstanceSettingsSaved, String> {
+    // Validate the monitor before changing anything; skipped when displays can't be listed
+    let monitor_index = settings.monitor_index.map(|monitor| u32::try_from(monitor).ok());
+    if let Some(Some(index)) = monitor_index {
+        if let Ok(monitors) = app.available_monitors() {
+            if index as usize >= monitors.len() {
+                return Err(format!(
+                    "Monitor {} isn't connected ({} displays found)",
+                    index + 1,
+                    monitors.len()
+                ));
+            }
+        }
+    }
+    
+    let language = match settings.language {
+        Some(ref language) => Some(parse_language(language)?),
+        None => None,
+    };
+    
+    if let Some(ref env_vars) = settings.env_vars {
+        validate_env_vars(env_vars)?;
+    }
+    
+    if let Some(ref profile) = settings.argument_profile {
+        if !profile.is_empty() && state.config.lock().unwrap().argument_profile(profile).is_none() {
+            return Err(format!("Argument profile '{}' not found", profile));
+        }
+    }
+    
     let mut instances = state.instances.lock().unwrap();
     let instance = instances.iter_mut()
         .find(|i| i.id == instance_id)
@@ -116,21 +208,44 @@ pub async fn update_instance_settings(
     if let Some(game_args) = settings.game_args {
         instance.settings.game_args = if game_args.is_empty() { None } else { Some(game_args) };
     }
+    if let Some(profile) = settings.argument_profile {
+        instance.settings.argument_profile = if profile.is_empty() { None } else { Some(profile) };
+    }
+    if let Some(use_global) = settings.use_global_jvm_args {
+        instance.settings.use_global_jvm_args = use_global;
+    }
     if let Some(min) = settings.min_memory {
         instance.settings.min_memory = Some(min);
     }
     if let Some(max) = settings.max_memory {
         instance.settings.max_memory = Some(max);
     }
+    let mut memory_warnings = Vec::new();
+    if instance.settings.min_memory.is_some() || instance.settings.max_memory.is_some() {
+        let global = state.config.lock().unwrap().memory.clone();
+        memory_warnings = validate_instance_memory(instance, &global);
+    }
     if let Some(width) = settings.window_width {
         instance.settings.window_width = Some(width);
     }
     if let Some(height) = settings.window_height {
         instance.settings.window_height = Some(height);
     }
+    let left_fullscreen = settings.fullscreen == Some(false) && instance.settings.fullscreen;
     if let Some(fullscreen) = settings.fullscreen {
         instance.settings.fullscreen = fullscreen;
     }
+    if let Some(monitor_index) = monitor_index {
+        instance.settings.monitor_index = monitor_index;
+    }
+    // Only a changed language is written out, so other saves leave options.txt alone
+    let language_warning = match language {
+        Some((language, warning)) if language != instance.settings.language => {
+            instance.settings.language = language;
+            Some(warning)
+        }
+        _ => None,
+    };
     if let Some(skip) = settings.skip_java_compatibility_check {
         instance.settings.skip_java_compatibility_check = skip;
     }
@@ -149,6 +264,9 @@ pub async fn update_instance_settings(
     if let Some(cmd) = settings.wrapper_command {
         instance.settings.wrapper_command = if cmd.is_empty() { None } else { Some(cmd) };
     }
+    if let Some(env_vars) = settings.env_vars {
+        instance.settings.env_vars = env_vars;
+    }
     
     // Debug settings
     if let Some(use_console) = settings.use_java_console {
@@ -166,6 +284,468 @@ pub async fn update_instance_settings(
     drop(instances);
     
     instance_clone.save().map_err(|e| e.to_string())?;
+    if left_fullscreen {
+        leave_fullscreen(&instance_clone)?;
+    }
+    
+    let Some(language_warning) = language_warning else {
+        return Ok(InstanceSettingsSaved { language_warning: None, memory_warnings });
+    };
+    apply_language(&instance_clone)?;
+    
+    Ok(InstanceSettingsSaved { language_warning, memory_warnings })
+}
+
+/// Check environment variables can be set on the game process: names must
+/// be non-empty without `=`, and neither names nor values may contain NUL
+fn validate_env_vars(vars: &[(String, String)]) -> Result<(), String> {
+    for (name, value) in vars {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("Environment variable names can't be empty".to_string());
+        }
+        if name.contains('=') {
+            return Err(format!("Environment variable name '{}' can't contain '='", name));
+        }
+        if name.contains('\0') || value.contains('\0') {
+            return Err(format!("Environment variable '{}' can't contain NUL characters", name.replace('\0', "")));
+        }
+    }
+    Ok(())
+}
+
+/// Check a language code from the frontend. Empty clears the language;
+/// codes the game doesn't ship with are allowed, since mods can add
+/// languages, and come back with a warning.
+fn parse_language(language: &str) -> Result<(Option<String>, Option<String>), String> {
+    if language.trim().is_empty() {
+        return Ok((None, None));
+    }
+    let code = normalize_language(language)
+        .ok_or_else(|| format!("'{}' is not a valid language code", language.trim()))?;
+    let warning = (!is_known_language(&code)).then(|| format!(
+        "'{}' is not one of Minecraft's languages; it will only work if a mod or resource pack adds it",
+        code
+    ));
+    Ok((Some(code), warning))
+}
+
+/// Write the instance's language to its options.txt, in the form its
+/// Minecraft version reads
+fn apply_language(instance: &Instance) -> Result<(), String> {
+    let Some(ref language) = instance.settings.language else {
+        return Ok(());
+    };
+    let lang = language_for_version(language, &instance.minecraft_version);
+    set_options(&instance.game_dir().join(OPTIONS_FILE), &[("lang", lang)])
+        .map_err(|e| format!("Failed to write language to options.txt: {}", e))
+}
+
+/// Turn fullscreen off in the instance's options.txt. Launches only write
+/// `fullscreen:true`, so this runs when the setting is switched off.
+fn leave_fullscreen(instance: &Instance) -> Result<(), String> {
+    set_options(&instance.game_dir().join(OPTIONS_FILE), &[("fullscreen", "false".to_string())])
+        .map_err(|e| format!("Failed to write fullscreen to options.txt: {}", e))
+}
+
+/// Set the game language for an instance and write it to its options.txt.
+/// An empty code clears it, leaving options.txt as it is. Returns a warning
+/// for a code the game doesn't ship with.
+#[tauri::command]
+pub async fn set_instance_language(
+    state: State<'_, AppState>,
+    instance_id: String,
+    language: String,
+) -> Result<Option<String>, String> {
+    let (language, warning) = parse_language(&language)?;
+    
+    let instance = {
+        let mut instances = state.instances.lock().unwrap();
+        let instance = instances.iter_mut()
+            .find(|i| i.id == instance_id)
+            .ok_or_else(|| "Instance not found".to_string())?;
+        instance.settings.language = language;
+        instance.clone()
+    };
+    
+    instance.save().map_err(|e| e.to_string())?;
+    apply_language(&instance)?;
+    
+    Ok(warning)
+}
+
+/// Put Aikar's G1 garbage collector flags, tuned to the instance's
+/// effective maximum memory, into its JVM arguments. Conflicting GC flags
+/// are replaced and other arguments kept; the result is saved as the
+/// instance's ordinary JVM arguments, so it stays editable. Returns them.
+#[tauri::command]
+pub async fn apply_aikars_gc_flags(
+    state: State<'_, AppState>,
+    instance_id: String,
+) -> Result<String, String> {
+    let global_max_memory = state.config.lock().unwrap().memory.max_memory;
+    
+    let instance = {
+        let mut instances = state.instances.lock().unwrap();
+        let instance = instances.iter_mut()
+            .find(|i| i.id == instance_id)
+            .ok_or_else(|| "Instance not found".to_string())?;
+        
+        let max_memory = instance.settings.max_memory.unwrap_or(global_max_memory);
+        let existing = instance.settings.jvm_args.clone().unwrap_or_default();
+        instance.settings.jvm_args = Some(apply_aikars_flags(&existing, max_memory));
+        instance.clone()
+    };
+    
+    instance.save().map_err(|e| e.to_string())?;
+    tracing::info!("Applied Aikar's flags to instance {}", instance.name);
+    
+    Ok(instance.settings.jvm_args.unwrap_or_default())
+}
+
+/// Get the settings a launch of an instance will use, after applying the
+/// instance's overrides to the global defaults. Each value says whether it
+/// came from the instance, the global settings, or neither.
+#[tauri::command]
+pub async fn get_effective_settings(
+    state: State<'_, AppState>,
+    instance_id: String,
+) -> Result<EffectiveSettings, String> {
+    let instance = state.instances.lock().unwrap()
+        .iter()
+        .find(|i| i.id == instance_id)
+        .cloned()
+        .ok_or_else(|| "Instance not found".to_string())?;
+    let config = state.config.lock().unwrap().clone();
+    
+    let context = LaunchContext::new(instance, AuthSession::offline("Player"), config);
+    Ok(EffectiveSettings::from_context(&context))
+}
+
+/// Get the Java executable and version, memory and full JVM argument list a
+/// launch of an instance would use right now, resolved the same way a launch
+/// resolves them. Values say whether they're inherited or set on the instance.
+#[tauri::command]
+pub async fn get_instance_runtime_config(
+    state: State<'_, AppState>,
+    instance_id: String,
+) -> Result<RuntimeConfig, String> {
+    let instance = state.instances.lock().unwrap()
+        .iter()
+        .find(|i| i.id == instance_id)
+        .cloned()
+        .ok_or_else(|| "Instance not found".to_string())?;
+    let config = state.config.lock().unwrap().clone();
+    
+    let context = LaunchContext::new(instance, AuthSession::offline("Player"), config);
+    Ok(RuntimeConfig::resolve(&context).await)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LaunchArgumentsPreview {
+    /// Argument profile applied, if any
+    pub argument_profile: Option<String>,
+    /// Memory flags, version and loader JVM arguments, classpath, then
+    /// global, profile and instance JVM arguments
+    pub jvm_args: Vec<String>,
+    /// The game's main class, or the launch wrapper's entry point
+    pub main_class: String,
+    /// Version and loader game arguments, window flags, then profile and
+    /// instance game arguments (after the wrapper's own arguments if wrapped)
+    pub game_args: Vec<String>,
+    /// A signed-in launch hands the access token to the wrapper in a file
+    /// rather than putting it on the command line
+    pub access_token_out_of_band: bool,
+}
+
+/// Preview the arguments an instance would launch with under an argument profile.
+/// `profile` of None uses the instance's assigned profile; an empty name
+/// previews it without one. The arguments are built the same way as at
+/// launch, with an offline session standing in for the account;
+/// `access_token_out_of_band` says how a signed-in token will be passed.
+#[tauri::command]
+pub async fn preview_launch_arguments(
+    state: State<'_, AppState>,
+    instance_id: String,
+    profile: Option<String>,
+) -> Result<LaunchArgumentsPreview, String> {
+    let mut instance = state.instances.lock().unwrap()
+        .iter()
+        .find(|i| i.id == instance_id)
+        .cloned()
+        .ok_or_else(|| "Instance not found".to_string())?;
+    let config = state.config.lock().unwrap().clone();
+    
+    if let Some(profile) = profile {
+        if !profile.is_empty() && config.argument_profile(&profile).is_none() {
+            return Err(format!("Argument profile '{}' not found", profile));
+        }
+        instance.settings.argument_profile = if profile.is_empty() { None } else { Some(profile) };
+    }
+    
+    let argument_profile = instance.settings.argument_profile.clone();
+    let access_token_out_of_band = config.minecraft.access_token_out_of_band
+        && wrapper_reads_token_file(&config.data_dir);
+    let mut context = LaunchContext::new(instance, AuthSession::offline("Player"), config);
+    
+    let launch_args = LaunchGameStep::new().preview_arguments(&mut context).await?;
+    
+    Ok(LaunchArgumentsPreview {
+        argument_profile,
+        jvm_args: launch_args.jvm_args,
+        main_class: launch_args.main_class,
+        game_args: launch_args.game_args,
+        access_token_out_of_band,
+    })
+}
+
+/// Settings applied to every instance in a group; `None` leaves a setting
+/// as each instance has it, and an empty string clears the override
+#[derive(Debug, Clone, Deserialize)]
+pub struct GroupSettingsUpdate {
+    pub java_path: Option<String>,
+    pub java_args: Option<String>,
+    pub min_memory: Option<u32>,
+    pub max_memory: Option<u32>,
+    pub window_width: Option<u32>,
+    pub window_height: Option<u32>,
+    pub fullscreen: Option<bool>,
+}
+
+/// Outcome of applying group settings to one instance
+#[derive(Debug, Clone, Serialize)]
+pub struct GroupSettingsResult {
+    pub instance_id: String,
+    pub name: String,
+    pub error: Option<String>,
+}
+
+/// Apply memory, JVM argument, Java and window settings to every instance in
+/// a group (matched ignoring case), saving each. Returns how each one went.
+#[tauri::command]
+pub async fn apply_settings_to_group(
+    state: State<'_, AppState>,
+    group: String,
+    settings: GroupSettingsUpdate,
+) -> Result<Vec<GroupSettingsResult>, String> {
+    let group = group.trim().to_lowercase();
+    let global_memory = state.config.lock().unwrap().memory.clone();
+    
+    let updated: Vec<(Instance, bool)> = {
+        let mut instances = state.instances.lock().unwrap();
+        instances.iter_mut()
+            .filter(|i| i.group.as_ref().is_some_and(|g| g.to_lowercase() == group))
+            .map(|instance| {
+                if let Some(ref java_path) = settings.java_path {
+                    instance.settings.java_path = (!java_path.is_empty()).then(|| PathBuf::from(java_path));
+                }
+                if let Some(ref java_args) = settings.java_args {
+                    instance.settings.jvm_args = (!java_args.is_empty()).then(|| java_args.clone());
+                }
+                if let Some(min) = settings.min_memory {
+                    instance.settings.min_memory = Some(min);
+                }
+                if let Some(max) = settings.max_memory {
+                    instance.settings.max_memory = Some(max);
+                }
+                if settings.min_memory.is_some() || settings.max_memory.is_some() {
+                    validate_instance_memory(instance, &global_memory);
+                }
+                if let Some(width) = settings.window_width {
+                    instance.settings.window_width = Some(width);
+                }
+                if let Some(height) = settings.window_height {
+                    instance.settings.window_height = Some(height);
+                }
+                let left_fullscreen = settings.fullscreen == Some(false) && instance.settings.fullscreen;
+                if let Some(fullscreen) = settings.fullscreen {
+                    instance.settings.fullscreen = fullscreen;
+                }
+                (instance.clone(), left_fullscreen)
+            })
+            .collect()
+    };
+    
+    if updated.is_empty() {
+        return Err("No instances in that group".to_string());
+    }
+    
+    let results = updated.into_iter()
+        .map(|(instance, left_fullscreen)| {
+            let error = match instance.save() {
+                Err(e) => Some(format!("Failed to save instance: {}", e)),
+                Ok(()) if left_fullscreen => leave_fullscreen(&instance).err(),
+                Ok(()) => None,
+            };
+            state.invalidate_instance_summary(&instance.id);
+            GroupSettingsResult {
+                instance_id: instance.id,
+                name: instance.name,
+                error,
+            }
+        })
+        .collect();
+    
+    Ok(results)
+}
+
+/// Read a version's data from the meta folder, fetching it when it isn't there yet
+async fn load_version_data(config: &Config, version: &str) -> Result<VersionData, String> {
+    if let Some(data) = load_version_json(&config.meta_dir(), version) {
+        return Ok(data);
+    }
+    
+    let manifest = fetch_version_manifest().await
+        .map_err(|e| format!("Failed to fetch version manifest: {}", e))?;
+    let version_info = manifest.get_version(version)
+        .ok_or_else(|| format!("Version {} not found", version))?;
+    fetch_version_data(version_info).await
+        .map_err(|e| format!("Failed to fetch version data: {}", e))
+}
+
+/// Get the classpath a launch of the instance would use, in order, with
+/// each jar's source and whether it's on disk. Missing libraries are left
+/// off at launch. When the instance needs the legacy launch wrapper, its jar
+/// is put in front of these at launch.
+#[tauri::command]
+pub async fn get_classpath(
+    state: State<'_, AppState>,
+    instance_id: String,
+) -> Result<Vec<ClasspathEntry>, String> {
+    let instance = state.instances.lock().unwrap()
+        .iter()
+        .find(|i| i.id == instance_id)
+        .cloned()
+        .ok_or_else(|| "Instance not found".to_string())?;
+    let config = state.config.lock().unwrap().clone();
+    
+    let version = instance.minecraft_version.clone();
+    let version_data = load_version_data(&config, &version).await?;
+    let modloader_profile = ModloaderProfile::load(&instance.path.join("modloader_profile.json")).ok();
+    
+    // Jar mods replace the client jar with the patched one built at launch
+    let patched_jar = (!list_jar_mods(&instance.path).is_empty() && supports_jar_mods(&version))
+        .then(|| instance.path.join("patches").join(PATCHED_JAR_NAME));
+    
+    let mut context = LaunchContext::new(instance, AuthSession::offline("Player"), config);
+    context.client_jar = patched_jar;
+    
+    Ok(classpath_entries(&context, &version_data, modloader_profile.as_ref()))
+}
+
+/// Reset an instance's game settings so the game regenerates its defaults,
+/// for when a bad setting stops it from starting. options.txt is moved to a
+/// `.bak` backup; `include_mod_options` does the same for OptiFine's and
+/// shader mods' settings. Returns the files that were reset.
+#[tauri::command]
+pub async fn reset_game_options(
+    state: State<'_, AppState>,
+    instance_id: String,
+    include_mod_options: Option<bool>,
+) -> Result<Vec<ResetOptionsFile>, String> {
+    let game_dir = state.instances.lock().unwrap()
+        .iter()
+        .find(|i| i.id == instance_id)
+        .map(|i| i.game_dir())
+        .ok_or_else(|| "Instance not found".to_string())?;
+    
+    // The game saves its options on exit, which would undo the reset
+    if state.running_processes.lock().unwrap().contains_key(&instance_id) {
+        return Err("Cannot reset game options while the instance is running".to_string());
+    }
+    
+    let reset = reset_options(&game_dir, include_mod_options.unwrap_or(false))
+        .map_err(|e| format!("Failed to reset game options: {}", e))?;
+    tracing::info!("Reset {} game option files for instance {}", reset.len(), instance_id);
+    
+    Ok(reset)
+}
+
+/// Link an instance's screenshots or saves folder to the shared folder
+/// configured in `Config.files`, or turn it back into a regular folder.
+///
+/// Enabling moves the folder's contents into the shared folder; disabling
+/// moves those same files back. Returns the names of the files moved.
+#[tauri::command]
+pub async fn set_shared_folder(
+    state: State<'_, AppState>,
+    instance_id: String,
+    folder: SharedFolder,
+    enabled: bool,
+) -> Result<Vec<String>, String> {
+    let shared_dir = {
+        let config = state.config.lock().unwrap();
+        match folder {
+            SharedFolder::Screenshots => config.shared_screenshots_dir(),
+            SharedFolder::Saves => config.shared_saves_dir(),
+        }
+    };
+    
+    let mut instances = state.instances.lock().unwrap();
+    let instance = instances.iter_mut()
+        .find(|i| i.id == instance_id)
+        .ok_or_else(|| "Instance not found".to_string())?;
+    
+    let game_dir = instance.game_dir();
+    let moved = if enabled {
+        share_folder(&game_dir, folder, &shared_dir)
+    } else {
+        unshare_folder(&game_dir, folder, &shared_dir)
+    }
+    .map_err(|e| e.to_string())?;
+    
+    match folder {
+        SharedFolder::Screenshots => instance.settings.shared_screenshots = enabled,
+        SharedFolder::Saves => instance.settings.shared_saves = enabled,
+    }
+    instance.save().map_err(|e| format!("Failed to save instance: {}", e))?;
+    
+    drop(instances);
+    state.invalidate_instance_summary(&instance_id);
+    
+    Ok(moved)
+}
+
+/// Lock an instance to demo mode, so it launches as a demo whatever account
+/// or launch mode is picked, or lift the lock. Locking with `new_pin` makes
+/// that PIN required, as `pin`, to change the lock again. Only versions
+/// that can be told to run as a demo (1.13+) can be locked.
+#[tauri::command]
+pub async fn set_instance_demo_lock(
+    state: State<'_, AppState>,
+    instance_id: String,
+    locked: bool,
+    pin: Option<String>,
+    new_pin: Option<String>,
+) -> Result<(), String> {
+    let instance = state.instances.lock().unwrap()
+        .iter()
+        .find(|i| i.id == instance_id)
+        .cloned()
+        .ok_or_else(|| "Instance not found".to_string())?;
+    
+    if locked && !instance.settings.demo_locked {
+        let config = state.config.lock().unwrap().clone();
+        let version_data = load_version_data(&config, &instance.minecraft_version).await?;
+        if !supports_demo(&version_data) {
+            return Err(format!(
+                "Minecraft {} doesn't support demo mode; demo lock needs 1.13 or newer",
+                instance.minecraft_version
+            ));
+        }
+    }
+    
+    let mut instances = state.instances.lock().unwrap();
+    let instance = instances.iter_mut()
+        .find(|i| i.id == instance_id)
+        .ok_or_else(|| "Instance not found".to_string())?;
+    
+    set_demo_lock(&mut instance.settings, &instance_id, locked, pin.as_deref(), new_pin.as_deref())
+        .map_err(|e| e.to_string())?;
+    instance.save().map_err(|e| format!("Failed to save instance: {}", e))?;
+    
+    drop(instances);
+    state.invalidate_instance_summary(&instance_id);
     
     Ok(())
 }
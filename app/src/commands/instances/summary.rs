@@ -0,0 +1,275 @@
+//! Instance overview summary command.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::commands::state::AppState;
+use crate::core::instance::{encode_png, mod_set_fingerprint, render_card, Instance, InstanceCard};
+use crate::core::minecraft::world;
+use crate::core::modloaders::ModloaderProfile;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use tauri::State;
+
+/// Aggregated content counts for the instance overview card
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstanceSummary {
+    pub enabled_mods: usize,
+    pub disabled_mods: usize,
+    pub resource_packs: usize,
+    pub shader_packs: usize,
+    pub worlds: usize,
+    pub mod_loader: String,
+    pub mod_loader_version: Option<String>,
+}
+
+/// The instance's enabled mod set now and when it was last launched
+#[derive(Debug, Clone, Serialize)]
+pub struct ModSetFingerprint {
+    pub current: String,
+    pub last_launch: Option<String>,
+    /// True when the mods differ from the last launch, or it was never launched
+    pub changed: bool,
+}
+
+/// A cached summary along with the directory state it was computed from
+pub struct CachedInstanceSummary {
+    summary: InstanceSummary,
+    fingerprint: Vec<Option<SystemTime>>,
+}
+
+/// Get the content summary for an instance.
+///
+/// Results are cached per instance and dropped whenever a known mutation
+/// (mod toggle/delete/install, pack or world changes, loader changes)
+/// calls `AppState::invalidate_instance_summary`. The modification times of
+/// the scanned folders are also compared so changes made outside the
+/// launcher are picked up.
+#[tauri::command]
+pub async fn get_instance_summary(
+    state: State<'_, AppState>,
+    instance_id: String,
+) -> Result<InstanceSummary, String> {
+    let instance = {
+        let instances = state.instances.lock().unwrap();
+        instances.iter()
+            .find(|i| i.id == instance_id)
+            .ok_or_else(|| "Instance not found".to_string())?
+            .clone()
+    };
+
+    Ok(cached_summary(&state, &instance))
+}
+
+/// The instance's summary from the cache, computing and caching it when
+/// missing or when its folders changed since
+pub(crate) fn cached_summary(state: &AppState, instance: &Instance) -> InstanceSummary {
+    let fingerprint = content_fingerprint(instance);
+
+    {
+        let cache = state.instance_summaries.lock().unwrap();
+        if let Some(cached) = cache.get(&instance.id) {
+            if cached.fingerprint == fingerprint {
+                return cached.summary.clone();
+            }
+        }
+    }
+
+    let summary = compute_summary(instance);
+
+    let mut cache = state.instance_summaries.lock().unwrap();
+    cache.insert(instance.id.clone(), CachedInstanceSummary {
+        summary: summary.clone(),
+        fingerprint,
+    });
+
+    summary
+}
+
+/// Fingerprint the instance's enabled mods (names and contents) and compare it
+/// with the one recorded at the last launch, so callers can decide whether
+/// compatibility checks need to run again.
+#[tauri::command]
+pub async fn get_mod_set_fingerprint(
+    state: State<'_, AppState>,
+    instance_id: String,
+) -> Result<ModSetFingerprint, String> {
+    let (mods_dir, last_launch) = {
+        let instances = state.instances.lock().unwrap();
+        let instance = instances.iter()
+            .find(|i| i.id == instance_id)
+            .ok_or_else(|| "Instance not found".to_string())?;
+        (instance.mods_dir(), instance.last_launch_mod_set.clone())
+    };
+
+    // Hashing new or changed jars reads them from disk
+    let current = tokio::task::spawn_blocking(move || mod_set_fingerprint(&mods_dir))
+        .await
+        .map_err(|e| format!("Failed to fingerprint mods: {}", e))?;
+    Ok(ModSetFingerprint {
+        changed: last_launch.as_ref() != Some(&current),
+        current,
+        last_launch,
+    })
+}
+
+/// Render a shareable PNG card showing the instance's icon, name, Minecraft
+/// version, loader and mod count. The PNG is returned base64 encoded and is
+/// also written to `output_path` when one is given.
+#[tauri::command]
+pub async fn render_instance_card(
+    state: State<'_, AppState>,
+    instance_id: String,
+    output_path: Option<String>,
+) -> Result<String, String> {
+    let instance = {
+        let instances = state.instances.lock().unwrap();
+        instances.iter()
+            .find(|i| i.id == instance_id)
+            .ok_or_else(|| "Instance not found".to_string())?
+            .clone()
+    };
+
+    let png = tokio::task::spawn_blocking(move || {
+        let (enabled_mods, _) = count_mods(&instance.mods_dir());
+        let (mod_loader, mod_loader_version) = resolve_loader(&instance);
+        let icon = icon_file(&instance);
+        let card = InstanceCard {
+            name: &instance.name,
+            minecraft_version: &instance.minecraft_version,
+            loader: mod_loader_version.map(|version| format!("{} {}", mod_loader, version)),
+            mod_count: enabled_mods,
+            icon: icon.as_deref(),
+        };
+        encode_png(&render_card(&card))
+    })
+    .await
+    .map_err(|e| format!("Failed to render instance card: {}", e))?
+    .map_err(|e| e.to_string())?;
+
+    if let Some(output_path) = output_path {
+        std::fs::write(&output_path, &png)
+            .map_err(|e| format!("Failed to save instance card: {}", e))?;
+    }
+
+    use base64::{Engine as _, engine::general_purpose::STANDARD};
+    Ok(STANDARD.encode(&png))
+}
+
+/// The instance's custom icon file, if it has one
+fn icon_file(instance: &Instance) -> Option<PathBuf> {
+    let path = match instance.icon.strip_prefix("custom:") {
+        Some(filename) => instance.path.join(filename),
+        None => PathBuf::from(&instance.icon),
+    };
+    path.is_file().then_some(path)
+}
+
+/// Paths whose state feeds into the summary
+fn content_paths(instance: &Instance) -> [PathBuf; 6] {
+    [
+        instance.mods_dir(),
+        instance.resourcepacks_dir(),
+        instance.shaderpacks_dir(),
+        instance.saves_dir(),
+        instance.config_path(),
+        instance.path.join("modloader_profile.json"),
+    ]
+}
+
+/// Modification times of the content folders, instance config and loader profile.
+/// Adding, removing or renaming an entry updates its parent folder's mtime.
+fn content_fingerprint(instance: &Instance) -> Vec<Option<SystemTime>> {
+    content_paths(instance)
+        .iter()
+        .map(|path| std::fs::metadata(path).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+/// Compute the summary from disk
+fn compute_summary(instance: &Instance) -> InstanceSummary {
+    let (enabled_mods, disabled_mods) = count_mods(&instance.mods_dir());
+    let (mod_loader, mod_loader_version) = resolve_loader(instance);
+
+    InstanceSummary {
+        enabled_mods,
+        disabled_mods,
+        resource_packs: count_packs(&instance.resourcepacks_dir()),
+        shader_packs: count_packs(&instance.shaderpacks_dir()),
+        worlds: world::count_worlds(&instance.saves_dir()),
+        mod_loader,
+        mod_loader_version,
+    }
+}
+
+/// Count enabled and disabled mod JARs, matching `get_installed_mods`
+fn count_mods(mods_dir: &Path) -> (usize, usize) {
+    let mut enabled = 0;
+    let mut disabled = 0;
+
+    if let Ok(entries) = std::fs::read_dir(mods_dir) {
+        for entry in entries.flatten() {
+            if !entry.path().is_file() {
+                continue;
+            }
+
+            let filename = entry.file_name().to_string_lossy().to_string();
+            if filename.ends_with(".jar") {
+                enabled += 1;
+            } else if filename.ends_with(".jar.disabled") {
+                disabled += 1;
+            }
+        }
+    }
+
+    (enabled, disabled)
+}
+
+/// Count resource or shader packs, matching the pack listing commands
+fn count_packs(packs_dir: &Path) -> usize {
+    let Ok(entries) = std::fs::read_dir(packs_dir) else {
+        return 0;
+    };
+
+    entries
+        .flatten()
+        .filter(|entry| {
+            let filename = entry.file_name().to_string_lossy().to_string();
+            !filename.starts_with('.')
+                && (entry.path().is_dir() || filename.to_lowercase().ends_with(".zip"))
+        })
+        .count()
+}
+
+/// Resolve the loader name and version, preferring the installed profile
+/// over the configured version (which may be "latest")
+fn resolve_loader(instance: &Instance) -> (String, Option<String>) {
+    let Some(ref loader) = instance.mod_loader else {
+        return ("Vanilla".to_string(), None);
+    };
+
+    let profile_path = instance.path.join("modloader_profile.json");
+    let version = ModloaderProfile::load(&profile_path)
+        .ok()
+        .map(|profile| profile.version)
+        .filter(|v| !v.is_empty())
+        .unwrap_or_else(|| loader.version.clone());
+
+    (loader.loader_type.name().to_string(), Some(version))
+}
@@ -24,17 +24,23 @@ use crate::core::instance::{
     export_instance as core_export_instance, ExportOptions,
     import_instance as core_import_instance, detect_import_type, ImportOptions, ImportType,
     ModLoader, ModLoaderType, ManagedPack, ModpackPlatform, Instance,
-    install_modloader_for_instance, FileToDownload,
+    install_modloader_for_instance, FileToDownload, ImportResult, ImportPlan, plan_import,
+    PackFileIndex, hash_tree, pack_file_key,
 };
 use crate::core::modplatform::curseforge::CurseForgeClient;
-use crate::core::download::download_file;
+use crate::core::modplatform::modrinth::ModrinthClient;
+use crate::core::download::{compute_sha1, download_binary_file, download_file, fetch_total_size};
+use crate::core::error::OxideError;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
-use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::Instant;
 use tauri::{State, AppHandle, Emitter};
 
+use super::import_rollback::{discard_new_instance, ImportSnapshot, ImportToken, IMPORT_CANCELLED};
+
 // =============================================================================
 // Export Types
 // =============================================================================
@@ -106,6 +112,8 @@ pub struct ModpackDownloadProgress {
     pub bytes_downloaded: u64,
     /// Current download speed in bytes per second
     pub speed_bps: u64,
+    /// Total bytes to download, when every file's size is known up front
+    pub total_bytes: Option<u64>,
     /// Name of the file currently being downloaded (if any)
     pub current_file: Option<String>,
     /// Current phase: "preparing", "resolving", "downloading"
@@ -263,6 +271,7 @@ pub async fn detect_import_format(
         ImportType::Technic => ("technic", "Technic"),
         ImportType::ATLauncher => ("atlauncher", "ATLauncher"),
         ImportType::FTBApp => ("ftbapp", "FTB App"),
+        ImportType::InstallerJar => ("jar", "Installer Jar"),
         ImportType::Unknown => ("unknown", "Unknown Format"),
     };
     
@@ -278,7 +287,10 @@ pub async fn import_instance_from_file(
     app: AppHandle,
     archive_path: String,
     name_override: Option<String>,
+    import_id: Option<String>,
 ) -> Result<ImportResultInfo, String> {
+    let token = ImportToken::register(&state, import_id);
+    
     // Get instances_dir without holding mutex across await
     let instances_dir = {
         let config = state.config.lock().unwrap();
@@ -289,164 +301,162 @@ pub async fn import_instance_from_file(
     let options = ImportOptions {
         name_override: name_override.clone(),
         instances_dir: instances_dir.clone(),
+        cancel: Some(token.shared_flag()),
     };
     
-    let result = core_import_instance(&path, &options, None)
-        .await
-        .map_err(|e| format!("Import failed: {}", e))?;
+    let result = match core_import_instance(&path, &options, None).await {
+        Ok(result) => result,
+        Err(e) => {
+            token.check()?;
+            return Err(format!("Import failed: {}", e));
+        }
+    };
+    let archive_sha1 = compute_sha1(&path).ok();
+    if let Err(e) = token.check() {
+        if let Some(ref overrides_path) = result.overrides_path {
+            let _ = std::fs::remove_dir_all(overrides_path);
+        }
+        return Err(e);
+    }
     
     // Create the actual instance
     let new_id = uuid::Uuid::new_v4().to_string();
     let instance_path = instances_dir.join(&new_id);
     let game_dir = instance_path.join(".minecraft");
     
-    // Create directories
-    std::fs::create_dir_all(&game_dir)
-        .map_err(|e| format!("Failed to create instance directory: {}", e))?;
-    
-    // Move overrides to game directory
-    if let Some(overrides_path) = &result.overrides_path {
-        if overrides_path.exists() {
-            // Copy all files from temp to game_dir
-            copy_dir_all(overrides_path, &game_dir)
-                .map_err(|e| format!("Failed to copy overrides: {}", e))?;
-            
-            // Clean up temp
-            let _ = std::fs::remove_dir_all(overrides_path);
+    // Anything left behind by a failure or cancellation from here on is removed
+    let overrides_path = result.overrides_path.clone();
+    let imported = async {
+        // Create directories
+        std::fs::create_dir_all(&game_dir)
+            .map_err(|e| format!("Failed to create instance directory: {}", e))?;
+        
+        // Remember the pack's override files for later updates
+        let override_hashes = pack_override_hashes(&result);
+        
+        // Move overrides to game directory
+        if let Some(overrides_path) = &result.overrides_path {
+            if overrides_path.exists() {
+                // Copy all files from temp to game_dir
+                copy_dir_all(overrides_path, &game_dir, &mut Vec::new())
+                    .map_err(|e| format!("Failed to copy overrides: {}", e))?;
+                
+                // Clean up temp
+                let _ = std::fs::remove_dir_all(overrides_path);
+            }
         }
-    }
-    
-    // Create mod loader
-    let mod_loader = result.mod_loader.as_ref().map(|(loader_type, version)| {
-        let lt = match loader_type.as_str() {
-            "forge" => ModLoaderType::Forge,
-            "neoforge" => ModLoaderType::NeoForge,
-            "fabric" => ModLoaderType::Fabric,
-            "quilt" => ModLoaderType::Quilt,
-            "liteloader" => ModLoaderType::LiteLoader,
-            _ => ModLoaderType::Fabric,
+        token.check()?;
+        
+        // Create mod loader
+        let mod_loader = import_mod_loader(&result);
+        
+        // Create managed pack
+        let mut managed_pack = import_managed_pack(&result);
+        identify_pack(&mut managed_pack, archive_sha1, None, None).await;
+        
+        // Create instance settings
+        let settings = crate::core::instance::InstanceSettings {
+            jvm_args: result.settings.jvm_args.clone(),
+            game_args: result.settings.game_args.clone(),
+            min_memory: result.settings.min_memory,
+            max_memory: result.settings.max_memory,
+            window_width: result.settings.window_width,
+            window_height: result.settings.window_height,
+            fullscreen: result.settings.fullscreen,
+            ..Default::default()
         };
-        ModLoader {
-            loader_type: lt,
-            version: version.clone(),
-        }
-    });
-    
-    // Create managed pack
-    let managed_pack = result.managed_pack.as_ref().map(|mp| {
-        let platform = match mp.platform.as_str() {
-            "modrinth" => ModpackPlatform::Modrinth,
-            "curseforge" => ModpackPlatform::CurseForge,
-            "atlauncher" => ModpackPlatform::ATLauncher,
-            "technic" => ModpackPlatform::Technic,
-            "ftb" => ModpackPlatform::FTB,
-            _ => ModpackPlatform::Modrinth,
+        
+        // Determine icon
+        let icon = result.icon.as_ref().map(|i| {
+            match i {
+                crate::core::instance::OxideIcon::Default { name } => name.clone(),
+                crate::core::instance::OxideIcon::Custom { filename, .. } => format!("custom:{}", filename),
+            }
+        }).unwrap_or_else(|| "grass".to_string());
+        
+        // Create the instance
+        let instance = Instance::new(
+            result.name.clone(),
+            instance_path.clone(),
+            result.minecraft_version.clone(),
+        );
+        
+        // Create a modified instance with all our settings
+        let instance = Instance {
+            id: new_id.clone(),
+            mod_loader: mod_loader.clone(),
+            managed_pack,
+            settings,
+            icon,
+            notes: result.notes.clone(),
+            total_played_seconds: result.playtime,
+            ..instance
         };
-        ManagedPack {
-            platform,
-            pack_id: mp.pack_id.clone(),
-            pack_name: mp.pack_name.clone(),
-            version_id: mp.version_id.clone(),
-            version_name: mp.version_name.clone(),
+        
+        // Save the instance
+        instance.save()
+            .map_err(|e| format!("Failed to save instance: {}", e))?;
+        
+        // Install modloader if present
+        if instance.mod_loader.is_some() {
+            tracing::info!("Installing modloader for imported instance...");
+            
+            // Get libraries directory (scope to ensure mutex is dropped before await)
+            let libraries_dir = {
+                let config = state.config.lock().unwrap();
+                config.libraries_dir()
+            };
+            
+            install_modloader_for_instance(&instance, &libraries_dir)
+                .await
+                .map_err(|e| format!("Failed to install modloader: {}", e))?;
+            
+            tracing::info!("Modloader installed successfully");
         }
-    });
-    
-    // Create instance settings
-    let settings = crate::core::instance::InstanceSettings {
-        jvm_args: result.settings.jvm_args.clone(),
-        game_args: result.settings.game_args.clone(),
-        min_memory: result.settings.min_memory,
-        max_memory: result.settings.max_memory,
-        window_width: result.settings.window_width,
-        window_height: result.settings.window_height,
-        fullscreen: result.settings.fullscreen,
-        ..Default::default()
-    };
-    
-    // Determine icon
-    let icon = result.icon.as_ref().map(|i| {
-        match i {
-            crate::core::instance::OxideIcon::Default { name } => name.clone(),
-            crate::core::instance::OxideIcon::Custom { filename, .. } => format!("custom:{}", filename),
+        token.check()?;
+        
+        // Add to state (using block to ensure lock is dropped before async ops)
+        {
+            let mut instances = state.instances.lock().unwrap();
+            instances.push(instance.clone());
         }
-    }).unwrap_or_else(|| "grass".to_string());
-    
-    // Create the instance
-    let instance = Instance::new(
-        result.name.clone(),
-        instance_path.clone(),
-        result.minecraft_version.clone(),
-    );
-    
-    // Create a modified instance with all our settings
-    let instance = Instance {
-        id: new_id.clone(),
-        mod_loader: mod_loader.clone(),
-        managed_pack,
-        settings,
-        icon,
-        notes: result.notes.clone(),
-        total_played_seconds: result.playtime,
-        ..instance
-    };
-    
-    // Save the instance
-    instance.save()
-        .map_err(|e| format!("Failed to save instance: {}", e))?;
-    
-    // Install modloader if present
-    if instance.mod_loader.is_some() {
-        tracing::info!("Installing modloader for imported instance...");
         
-        // Get libraries directory (scope to ensure mutex is dropped before await)
-        let libraries_dir = {
-            let config = state.config.lock().unwrap();
-            config.libraries_dir()
+        // Download files that need API resolution (CurseForge modpacks)
+        let (download_warnings, blocked_files) = if !result.files_to_download.is_empty() {
+            tracing::info!("Downloading {} modpack files...", result.files_to_download.len());
+            // Pass game_dir and let the function determine correct subdirectory for each file
+            let dl_result = download_curseforge_files(&result.files_to_download, &game_dir, Some(&app), Some(token.flag())).await;
+            token.check()?;
+            record_pack_files(&instance, &result, override_hashes, &dl_result.installed);
+            (dl_result.warnings, dl_result.blocked_files)
+        } else {
+            record_pack_files(&instance, &result, override_hashes, &[]);
+            (Vec::new(), Vec::new())
         };
         
-        install_modloader_for_instance(&instance, &libraries_dir)
-            .await
-            .map_err(|e| format!("Failed to install modloader: {}", e))?;
+        // Prepare warnings
+        let mut warnings = result.warnings.clone();
+        if !download_warnings.is_empty() {
+            warnings.extend(download_warnings);
+        }
         
-        tracing::info!("Modloader installed successfully");
-    }
-    
-    // Add to state (using block to ensure lock is dropped before async ops)
-    {
-        let mut instances = state.instances.lock().unwrap();
-        instances.push(instance.clone());
-    }
-    
-    // Download files that need API resolution (CurseForge modpacks)
-    let (download_warnings, blocked_files) = if !result.files_to_download.is_empty() {
-        tracing::info!("Downloading {} modpack files...", result.files_to_download.len());
-        // Pass game_dir and let the function determine correct subdirectory for each file
-        let dl_result = download_curseforge_files(&result.files_to_download, &game_dir, Some(&app)).await;
-        (dl_result.warnings, dl_result.blocked_files)
-    } else {
-        (Vec::new(), Vec::new())
-    };
-    
-    // Prepare warnings
-    let mut warnings = Vec::new();
-    if !download_warnings.is_empty() {
-        warnings.extend(download_warnings);
-    }
-    
-    let result_info = ImportResultInfo {
-        instance_id: new_id,
-        name: result.name,
-        minecraft_version: result.minecraft_version,
-        mod_loader_type: mod_loader.as_ref().map(|m| m.loader_type.name().to_string()),
-        mod_loader_version: mod_loader.as_ref().map(|m| m.version.clone()),
-        files_to_download: result.files_to_download.len(),
-        warnings,
-        blocked_files,
-    };
-    
-    tracing::info!("Imported instance: {}", result_info.name);
+        let result_info = ImportResultInfo {
+            instance_id: new_id.clone(),
+            name: result.name,
+            minecraft_version: result.minecraft_version,
+            mod_loader_type: mod_loader.as_ref().map(|m| m.loader_type.name().to_string()),
+            mod_loader_version: mod_loader.as_ref().map(|m| m.version.clone()),
+            files_to_download: result.files_to_download.len(),
+            warnings,
+            blocked_files,
+        };
+        
+        tracing::info!("Imported instance: {}", result_info.name);
+        
+        Ok::<_, String>(result_info)
+    }.await;
     
-    Ok(result_info)
+    imported.map_err(|e| discard_new_instance(&state, &new_id, &instance_path, overrides_path.as_ref(), e))
 }
 
 /// Import an instance from a URL (downloads first, then imports)
@@ -457,7 +467,12 @@ pub async fn import_instance_from_url(
     url: String,
     name_override: Option<String>,
     icon_url: Option<String>,
+    pack_id: Option<String>,
+    pack_version_id: Option<String>,
+    import_id: Option<String>,
 ) -> Result<ImportResultInfo, String> {
+    let token = ImportToken::register(&state, import_id);
+    
     // Get temp and instances directories
     let (temp_dir, instances_dir) = {
         let config = state.config.lock().unwrap();
@@ -509,47 +524,461 @@ pub async fn import_instance_from_url(
     let options = ImportOptions {
         name_override: name_override.clone(),
         instances_dir: instances_dir.clone(),
+        cancel: Some(token.shared_flag()),
     };
     
-    let result = core_import_instance(&download_path, &options, None)
-        .await
-        .map_err(|e| format!("Import failed: {}", e))?;
+    let result = match core_import_instance(&download_path, &options, None).await {
+        Ok(result) => result,
+        Err(e) => {
+            if let Err(cancelled) = token.check() {
+                let _ = std::fs::remove_file(&download_path);
+                return Err(cancelled);
+            }
+            return Err(format!("Import failed: {}", e));
+        }
+    };
+    let archive_sha1 = compute_sha1(&download_path).ok();
     
     // Clean up downloaded file
     let _ = std::fs::remove_file(&download_path);
+    if let Err(e) = token.check() {
+        if let Some(ref overrides_path) = result.overrides_path {
+            let _ = std::fs::remove_dir_all(overrides_path);
+        }
+        return Err(e);
+    }
     
     // Create the actual instance (same logic as import_instance_from_file)
     let new_id = uuid::Uuid::new_v4().to_string();
     let instance_path = instances_dir.join(&new_id);
     let game_dir = instance_path.join(".minecraft");
     
-    std::fs::create_dir_all(&game_dir)
-        .map_err(|e| format!("Failed to create instance directory: {}", e))?;
-    
-    // Download and save icon if provided
-    let downloaded_icon = if let Some(ref icon_url_str) = icon_url {
-        match download_icon(&client, icon_url_str, &instance_path).await {
-            Ok(icon_filename) => Some(format!("custom:{}", icon_filename)),
-            Err(e) => {
-                tracing::warn!("Failed to download icon: {}", e);
-                None
+    // Anything left behind by a failure or cancellation from here on is removed
+    let overrides_path = result.overrides_path.clone();
+    let imported = async {
+        std::fs::create_dir_all(&game_dir)
+            .map_err(|e| format!("Failed to create instance directory: {}", e))?;
+        
+        // Download and save icon if provided
+        let downloaded_icon = if let Some(ref icon_url_str) = icon_url {
+            match download_icon(&client, icon_url_str, &instance_path).await {
+                Ok(icon_filename) => Some(format!("custom:{}", icon_filename)),
+                Err(e) => {
+                    tracing::warn!("Failed to download icon: {}", e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+        
+        // Remember the pack's override files for later updates
+        let override_hashes = pack_override_hashes(&result);
+        
+        // Move overrides to game directory
+        if let Some(overrides_path) = &result.overrides_path {
+            if overrides_path.exists() {
+                copy_dir_all(overrides_path, &game_dir, &mut Vec::new())
+                    .map_err(|e| format!("Failed to copy overrides: {}", e))?;
+                let _ = std::fs::remove_dir_all(overrides_path);
             }
         }
-    } else {
-        None
+        token.check()?;
+        
+        // Create mod loader
+        let mod_loader = import_mod_loader(&result);
+        
+        // Create managed pack
+        let mut managed_pack = import_managed_pack(&result);
+        identify_pack(&mut managed_pack, archive_sha1, pack_id, pack_version_id).await;
+        
+        // Create instance settings
+        let settings = crate::core::instance::InstanceSettings {
+            jvm_args: result.settings.jvm_args.clone(),
+            game_args: result.settings.game_args.clone(),
+            min_memory: result.settings.min_memory,
+            max_memory: result.settings.max_memory,
+            window_width: result.settings.window_width,
+            window_height: result.settings.window_height,
+            fullscreen: result.settings.fullscreen,
+            ..Default::default()
+        };
+        
+        // Determine icon - prefer downloaded icon, then result icon, then default
+        let icon = downloaded_icon
+            .or_else(|| result.icon.as_ref().map(|i| {
+                match i {
+                    crate::core::instance::OxideIcon::Default { name } => name.clone(),
+                    crate::core::instance::OxideIcon::Custom { filename, .. } => format!("custom:{}", filename),
+                }
+            }))
+            .unwrap_or_else(|| "grass".to_string());
+        
+        // Create the instance
+        let instance = Instance::new(
+            result.name.clone(),
+            instance_path.clone(),
+            result.minecraft_version.clone(),
+        );
+        
+        let instance = Instance {
+            id: new_id.clone(),
+            mod_loader: mod_loader.clone(),
+            managed_pack,
+            settings,
+            icon,
+            notes: result.notes.clone(),
+            total_played_seconds: result.playtime,
+            ..instance
+        };
+        
+        // Save the instance
+        instance.save()
+            .map_err(|e| format!("Failed to save instance: {}", e))?;
+        
+        // Install modloader if present
+        if instance.mod_loader.is_some() {
+            tracing::info!("Installing modloader for imported instance...");
+            
+            let libraries_dir = {
+                let config = state.config.lock().unwrap();
+                config.libraries_dir()
+            };
+            
+            install_modloader_for_instance(&instance, &libraries_dir)
+                .await
+                .map_err(|e| format!("Failed to install modloader: {}", e))?;
+            
+            tracing::info!("Modloader installed successfully");
+        }
+        token.check()?;
+        
+        // Add to state (using block to ensure lock is dropped before async ops)
+        {
+            let mut instances = state.instances.lock().unwrap();
+            instances.push(instance.clone());
+        }
+        
+        // Download files that need API resolution (CurseForge modpacks)
+        let (download_warnings, blocked_files) = if !result.files_to_download.is_empty() {
+            tracing::info!("Downloading {} modpack files from URL import...", result.files_to_download.len());
+            // Pass game_dir and let the function determine correct subdirectory for each file
+            let dl_result = download_curseforge_files(&result.files_to_download, &game_dir, Some(&app), Some(token.flag())).await;
+            token.check()?;
+            record_pack_files(&instance, &result, override_hashes, &dl_result.installed);
+            (dl_result.warnings, dl_result.blocked_files)
+        } else {
+            record_pack_files(&instance, &result, override_hashes, &[]);
+            (Vec::new(), Vec::new())
+        };
+        
+        // Prepare warnings
+        let mut warnings = result.warnings.clone();
+        if !download_warnings.is_empty() {
+            warnings.extend(download_warnings);
+        }
+        
+        let result_info = ImportResultInfo {
+            instance_id: new_id.clone(),
+            name: result.name,
+            minecraft_version: result.minecraft_version,
+            mod_loader_type: mod_loader.as_ref().map(|m| m.loader_type.name().to_string()),
+            mod_loader_version: mod_loader.as_ref().map(|m| m.version.clone()),
+            files_to_download: result.files_to_download.len(),
+            warnings,
+            blocked_files,
+        };
+        
+        tracing::info!("Imported instance from URL: {}", result_info.name);
+        
+        Ok::<_, String>(result_info)
+    }.await;
+    
+    imported.map_err(|e| discard_new_instance(&state, &new_id, &instance_path, overrides_path.as_ref(), e))
+}
+
+/// Result of importing a pack into an existing instance
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportIntoInstanceResult {
+    /// Files the import adds, overwrites or leaves alone
+    pub plan: ImportPlan,
+    /// False for dry runs
+    pub applied: bool,
+    pub minecraft_version: String,
+    pub mod_loader_type: Option<String>,
+    pub mod_loader_version: Option<String>,
+    pub warnings: Vec<String>,
+    /// Files that need manual download due to CurseForge restrictions
+    pub blocked_files: Vec<BlockedFileInfo>,
+}
+
+/// Import a modpack archive (.mrpack, CurseForge zip, ...) into an existing instance.
+///
+/// With `dry_run` set nothing is changed: the archive is only inspected and the
+/// plan of added, overwritten and unchanged files is returned so the frontend
+/// can ask for confirmation before the user's configs or worlds are replaced.
+/// Call again with `dry_run` unset to apply it.
+#[tauri::command]
+pub async fn import_into_instance(
+    state: State<'_, AppState>,
+    app: AppHandle,
+    instance_id: String,
+    archive_path: String,
+    dry_run: bool,
+    import_id: Option<String>,
+) -> Result<ImportIntoInstanceResult, String> {
+    let token = ImportToken::register(&state, import_id);
+    
+    let instance = {
+        let instances = state.instances.lock().unwrap();
+        instances.iter()
+            .find(|i| i.id == instance_id)
+            .ok_or_else(|| "Instance not found".to_string())?
+            .clone()
     };
     
-    // Move overrides to game directory
-    if let Some(overrides_path) = &result.overrides_path {
-        if overrides_path.exists() {
-            copy_dir_all(overrides_path, &game_dir)
-                .map_err(|e| format!("Failed to copy overrides: {}", e))?;
+    if !dry_run && state.running_processes.lock().unwrap().contains_key(&instance_id) {
+        return Err("Cannot import into an instance while it is running".to_string());
+    }
+    
+    let (instances_dir, libraries_dir) = {
+        let config = state.config.lock().unwrap();
+        (config.instances_dir(), config.libraries_dir())
+    };
+    
+    let options = ImportOptions {
+        name_override: None,
+        instances_dir,
+        cancel: Some(token.shared_flag()),
+    };
+    
+    let archive_path = PathBuf::from(archive_path);
+    let result = match core_import_instance(&archive_path, &options, None).await {
+        Ok(result) => result,
+        Err(e) => {
+            token.check()?;
+            return Err(format!("Import failed: {}", e));
+        }
+    };
+    
+    let game_dir = instance.game_dir();
+    let plan = plan_import(&result, &game_dir);
+    
+    // Nothing is applied on a dry run (or a failed plan), so drop the extracted overrides
+    if dry_run || plan.is_err() {
+        if let Some(overrides_path) = &result.overrides_path {
             let _ = std::fs::remove_dir_all(overrides_path);
         }
     }
     
-    // Create mod loader
-    let mod_loader = result.mod_loader.as_ref().map(|(loader_type, version)| {
+    let plan = plan.map_err(|e| format!("Failed to compare pack with instance: {}", e))?;
+    let mod_loader = import_mod_loader(&result);
+    
+    let mut info = ImportIntoInstanceResult {
+        plan,
+        applied: false,
+        minecraft_version: result.minecraft_version.clone(),
+        mod_loader_type: mod_loader.as_ref().map(|m| m.loader_type.name().to_string()),
+        mod_loader_version: mod_loader.as_ref().map(|m| m.version.clone()),
+        warnings: result.warnings.clone(),
+        blocked_files: Vec::new(),
+    };
+    
+    if dry_run {
+        return Ok(info);
+    }
+    
+    // Keep what the import replaces so a failure or cancellation can be undone
+    let snapshot = match ImportSnapshot::take(&instance, &info.plan) {
+        Ok(snapshot) => snapshot,
+        Err(e) => {
+            if let Some(overrides_path) = &result.overrides_path {
+                let _ = std::fs::remove_dir_all(overrides_path);
+            }
+            return Err(e);
+        }
+    };
+    let mut created = Vec::new();
+    let mut installed = Vec::new();
+    
+    let applied = async {
+        token.check()?;
+        let override_hashes = pack_override_hashes(&result);
+        
+        // Copy overrides over the game directory
+        if let Some(overrides_path) = &result.overrides_path {
+            if overrides_path.exists() {
+                let copied = copy_dir_all(overrides_path, &game_dir, &mut created)
+                    .map_err(|e| format!("Failed to copy overrides: {}", e));
+                let _ = std::fs::remove_dir_all(overrides_path);
+                copied?;
+            }
+        }
+        token.check()?;
+        
+        // Skip downloads that already match the instance's copy
+        let files: Vec<FileToDownload> = result.files_to_download.iter()
+            .filter(|f| !info.plan.unchanged.contains(&f.path))
+            .cloned()
+            .collect();
+        
+        if !files.is_empty() {
+            tracing::info!("Downloading {} modpack files into {}...", files.len(), instance.name);
+            let dl_result = download_curseforge_files(&files, &game_dir, Some(&app), Some(token.flag())).await;
+            info.warnings.extend(dl_result.warnings);
+            info.blocked_files = dl_result.blocked_files;
+            installed = dl_result.installed;
+        }
+        token.check()?;
+        
+        let mut managed_pack = import_managed_pack(&result);
+        identify_pack(&mut managed_pack, compute_sha1(&archive_path).ok(), None, None).await;
+        
+        let instance = apply_pack_metadata(instance.clone(), &result, managed_pack, &libraries_dir).await?;
+        record_pack_files(&instance, &result, override_hashes, &installed);
+        Ok::<_, String>(instance)
+    }.await;
+    
+    let instance = match applied {
+        Ok(instance) => instance,
+        Err(e) => {
+            let downloaded: Vec<PathBuf> = installed.into_iter().map(|(_, path)| path).collect();
+            return Err(snapshot.restore(&state, &created, &downloaded, e));
+        }
+    };
+    
+    {
+        let mut instances = state.instances.lock().unwrap();
+        if let Some(existing) = instances.iter_mut().find(|i| i.id == instance_id) {
+            *existing = instance;
+        }
+    }
+    state.invalidate_instance_summary(&instance_id);
+    
+    info.applied = true;
+    tracing::info!("Imported pack into instance {}", instance_id);
+    
+    Ok(info)
+}
+
+/// Switch an instance to the pack's Minecraft version, loader and pack
+/// version, reinstalling the loader when it changed
+pub(super) async fn apply_pack_metadata(
+    mut instance: Instance,
+    result: &ImportResult,
+    managed_pack: Option<ManagedPack>,
+    libraries_dir: &std::path::Path,
+) -> Result<Instance, String> {
+    let mod_loader = import_mod_loader(result);
+    let loader_key = |loader: &Option<ModLoader>| {
+        loader.as_ref().map(|m| (m.loader_type.name().to_string(), m.version.clone()))
+    };
+    let needs_loader_install = mod_loader.is_some()
+        && (instance.minecraft_version != result.minecraft_version
+            || loader_key(&instance.mod_loader) != loader_key(&mod_loader));
+    
+    instance.minecraft_version = result.minecraft_version.clone();
+    instance.mod_loader = mod_loader;
+    if managed_pack.is_some() {
+        instance.managed_pack = managed_pack;
+    }
+    
+    instance.save()
+        .map_err(|e| format!("Failed to save instance: {}", e))?;
+    
+    if needs_loader_install {
+        install_modloader_for_instance(&instance, libraries_dir)
+            .await
+            .map_err(|e| format!("Failed to install modloader: {}", e))?;
+    }
+    
+    Ok(instance)
+}
+
+/// Fill in the pack's platform IDs so it can be updated later.
+///
+/// IDs passed by the frontend (installs from the platform browser) win;
+/// otherwise Modrinth packs are looked up by the archive's hash.
+pub(super) async fn identify_pack(
+    managed_pack: &mut Option<ManagedPack>,
+    archive_sha1: Option<String>,
+    pack_id: Option<String>,
+    pack_version_id: Option<String>,
+) {
+    let Some(pack) = managed_pack.as_mut() else {
+        return;
+    };
+    
+    if let Some(pack_id) = pack_id {
+        pack.pack_id = pack_id;
+        if let Some(version_id) = pack_version_id {
+            pack.version_id = version_id;
+        }
+        return;
+    }
+    
+    if pack.platform != ModpackPlatform::Modrinth {
+        return;
+    }
+    let Some(sha1) = archive_sha1 else {
+        return;
+    };
+    
+    match ModrinthClient::new().get_version_from_hash(&sha1).await {
+        Ok(version) => {
+            pack.pack_id = version.project_id;
+            pack.version_id = version.id;
+            pack.version_name = version.version_number;
+        }
+        Err(e) => tracing::debug!("Couldn't identify pack on Modrinth: {}", e),
+    }
+}
+
+/// Hashes of the pack's extracted override files
+pub(super) fn pack_override_hashes(result: &ImportResult) -> BTreeMap<String, String> {
+    let Some(ref overrides_path) = result.overrides_path else {
+        return BTreeMap::new();
+    };
+    
+    hash_tree(overrides_path).unwrap_or_else(|e| {
+        tracing::warn!("Failed to hash pack overrides: {}", e);
+        BTreeMap::new()
+    })
+}
+
+/// Record the files the pack installed, the base for later in-place updates
+pub(super) fn record_pack_files(
+    instance: &Instance,
+    result: &ImportResult,
+    override_hashes: BTreeMap<String, String>,
+    installed: &[(String, PathBuf)],
+) {
+    let game_dir = instance.game_dir();
+    let mut index = PackFileIndex {
+        version_id: instance.managed_pack.as_ref().map(|p| p.version_id.clone()).unwrap_or_default(),
+        files: override_hashes,
+        sources: BTreeMap::new(),
+    };
+    
+    // Files that were already in place and skipped count as installed too
+    for file in &result.files_to_download {
+        let path = game_dir.join(&file.path);
+        if file.platform_info.is_none() && path.is_file() {
+            index.record_download(&game_dir, pack_file_key(file), &path);
+        }
+    }
+    for (key, path) in installed {
+        index.record_download(&game_dir, key.clone(), path);
+    }
+    
+    if let Err(e) = index.save(&instance.path) {
+        tracing::warn!("Failed to record modpack files: {}", e);
+    }
+}
+
+/// Map the imported loader tuple to an instance mod loader
+pub(super) fn import_mod_loader(result: &ImportResult) -> Option<ModLoader> {
+    result.mod_loader.as_ref().map(|(loader_type, version)| {
         let lt = match loader_type.as_str() {
             "forge" => ModLoaderType::Forge,
             "neoforge" => ModLoaderType::NeoForge,
@@ -562,10 +991,12 @@ pub async fn import_instance_from_url(
             loader_type: lt,
             version: version.clone(),
         }
-    });
-    
-    // Create managed pack
-    let managed_pack = result.managed_pack.as_ref().map(|mp| {
+    })
+}
+
+/// Map the imported pack info to the instance's managed pack
+fn import_managed_pack(result: &ImportResult) -> Option<ManagedPack> {
+    result.managed_pack.as_ref().map(|mp| {
         let platform = match mp.platform.as_str() {
             "modrinth" => ModpackPlatform::Modrinth,
             "curseforge" => ModpackPlatform::CurseForge,
@@ -581,110 +1012,15 @@ pub async fn import_instance_from_url(
             version_id: mp.version_id.clone(),
             version_name: mp.version_name.clone(),
         }
-    });
-    
-    // Create instance settings
-    let settings = crate::core::instance::InstanceSettings {
-        jvm_args: result.settings.jvm_args.clone(),
-        game_args: result.settings.game_args.clone(),
-        min_memory: result.settings.min_memory,
-        max_memory: result.settings.max_memory,
-        window_width: result.settings.window_width,
-        window_height: result.settings.window_height,
-        fullscreen: result.settings.fullscreen,
-        ..Default::default()
-    };
-    
-    // Determine icon - prefer downloaded icon, then result icon, then default
-    let icon = downloaded_icon
-        .or_else(|| result.icon.as_ref().map(|i| {
-            match i {
-                crate::core::instance::OxideIcon::Default { name } => name.clone(),
-                crate::core::instance::OxideIcon::Custom { filename, .. } => format!("custom:{}", filename),
-            }
-        }))
-        .unwrap_or_else(|| "grass".to_string());
-    
-    // Create the instance
-    let instance = Instance::new(
-        result.name.clone(),
-        instance_path.clone(),
-        result.minecraft_version.clone(),
-    );
-    
-    let instance = Instance {
-        id: new_id.clone(),
-        mod_loader: mod_loader.clone(),
-        managed_pack,
-        settings,
-        icon,
-        notes: result.notes.clone(),
-        total_played_seconds: result.playtime,
-        ..instance
-    };
-    
-    // Save the instance
-    instance.save()
-        .map_err(|e| format!("Failed to save instance: {}", e))?;
-    
-    // Install modloader if present
-    if instance.mod_loader.is_some() {
-        tracing::info!("Installing modloader for imported instance...");
-        
-        let libraries_dir = {
-            let config = state.config.lock().unwrap();
-            config.libraries_dir()
-        };
-        
-        install_modloader_for_instance(&instance, &libraries_dir)
-            .await
-            .map_err(|e| format!("Failed to install modloader: {}", e))?;
-        
-        tracing::info!("Modloader installed successfully");
-    }
-    
-    // Add to state (using block to ensure lock is dropped before async ops)
-    {
-        let mut instances = state.instances.lock().unwrap();
-        instances.push(instance.clone());
-    }
-    
-    // Download files that need API resolution (CurseForge modpacks)
-    let (download_warnings, blocked_files) = if !result.files_to_download.is_empty() {
-        tracing::info!("Downloading {} modpack files from URL import...", result.files_to_download.len());
-        // Pass game_dir and let the function determine correct subdirectory for each file
-        let dl_result = download_curseforge_files(&result.files_to_download, &game_dir, Some(&app)).await;
-        (dl_result.warnings, dl_result.blocked_files)
-    } else {
-        (Vec::new(), Vec::new())
-    };
-    
-    // Prepare warnings
-    let mut warnings = Vec::new();
-    if !download_warnings.is_empty() {
-        warnings.extend(download_warnings);
-    }
-    
-    let result_info = ImportResultInfo {
-        instance_id: new_id,
-        name: result.name,
-        minecraft_version: result.minecraft_version,
-        mod_loader_type: mod_loader.as_ref().map(|m| m.loader_type.name().to_string()),
-        mod_loader_version: mod_loader.as_ref().map(|m| m.version.clone()),
-        files_to_download: result.files_to_download.len(),
-        warnings,
-        blocked_files,
-    };
-    
-    tracing::info!("Imported instance from URL: {}", result_info.name);
-    
-    Ok(result_info)
+    })
 }
 
-/// Copy directory recursively
-fn copy_dir_all(src: &PathBuf, dst: &PathBuf) -> std::io::Result<()> {
+/// Copy directory recursively. Files and directories that didn't exist
+/// before are pushed to `created`, parents before their contents.
+fn copy_dir_all(src: &PathBuf, dst: &PathBuf, created: &mut Vec<PathBuf>) -> std::io::Result<()> {
     if !dst.exists() {
         std::fs::create_dir_all(dst)?;
+        created.push(dst.clone());
     }
     
     for entry in std::fs::read_dir(src)? {
@@ -694,9 +1030,13 @@ fn copy_dir_all(src: &PathBuf, dst: &PathBuf) -> std::io::Result<()> {
         let dst_path = dst.join(entry.file_name());
         
         if ty.is_dir() {
-            copy_dir_all(&src_path, &dst_path)?;
+            copy_dir_all(&src_path, &dst_path, created)?;
         } else {
+            let is_new = !dst_path.exists();
             std::fs::copy(&src_path, &dst_path)?;
+            if is_new {
+                created.push(dst_path);
+            }
         }
     }
     
@@ -704,27 +1044,31 @@ fn copy_dir_all(src: &PathBuf, dst: &PathBuf) -> std::io::Result<()> {
 }
 
 /// Result of downloading CurseForge files
-struct CurseForgeDownloadResult {
-    warnings: Vec<String>,
-    blocked_files: Vec<BlockedFileInfo>,
+pub(super) struct CurseForgeDownloadResult {
+    pub warnings: Vec<String>,
+    pub blocked_files: Vec<BlockedFileInfo>,
+    /// Pack file key (see `pack_file_key`) and install path of each downloaded file
+    pub installed: Vec<(String, PathBuf)>,
 }
 
 /// Download files from CurseForge API
 /// Returns warnings and info about blocked files that need manual download
 /// game_dir is the .minecraft directory, files will be placed in appropriate subdirectories
 /// based on their class_id from the API (mods/, resourcepacks/, shaderpacks/)
-async fn download_curseforge_files(
+pub(super) async fn download_curseforge_files(
     files: &[FileToDownload],
     game_dir: &PathBuf,
     app: Option<&AppHandle>,
+    cancel: Option<&AtomicBool>,
 ) -> CurseForgeDownloadResult {
     let mut warnings = Vec::new();
     let mut blocked_files = Vec::new();
+    let mut installed = Vec::new();
     let client = CurseForgeClient::new();
     
     if !client.has_api_key() {
         warnings.push("CurseForge API key not configured - cannot download modpack files".to_string());
-        return CurseForgeDownloadResult { warnings, blocked_files };
+        return CurseForgeDownloadResult { warnings, blocked_files, installed };
     }
     
     // Emit preparing phase progress
@@ -734,6 +1078,7 @@ async fn download_curseforge_files(
             total: files.len(),
             bytes_downloaded: 0,
             speed_bps: 0,
+            total_bytes: None,
             current_file: None,
             phase: Some("preparing".to_string()),
         });
@@ -757,6 +1102,7 @@ async fn download_curseforge_files(
                 total: files.len(),
                 bytes_downloaded: 0,
                 speed_bps: 0,
+                total_bytes: None,
                 current_file: Some(format!("Resolving {} projects...", cf_project_ids.len())),
                 phase: Some("resolving".to_string()),
             });
@@ -777,13 +1123,19 @@ async fn download_curseforge_files(
     };
     
     // Phase 1: Resolve all download URLs in parallel
-    // Collect tasks: (url, dest_path, is_blocked, blocked_info)
+    // Collect tasks: (url, dest_path, blocked_info, manual_fallback, known_size)
+    // manual_fallback is used when a CurseForge URL serves the manual-download page instead of the file
     let resolve_futures: Vec<_> = files.iter().map(|file| {
         let game_dir = game_dir.clone();
         let file = file.clone();
         let project_class_ids = project_class_ids.clone();
         
         async move {
+            // Nothing more is resolved once the import is cancelled
+            if cancel.is_some_and(|cancel| cancel.load(Ordering::Relaxed)) {
+                return Ok(None);
+            }
+            
             // Create client inside async block since CurseForgeClient isn't Clone
             let client = CurseForgeClient::new();
             
@@ -810,7 +1162,7 @@ async fn download_curseforge_files(
                             .file_name()
                             .and_then(|n| n.to_str())
                             .unwrap_or(&file.path);
-                        return Ok(Some((file.urls[0].clone(), target_dir.join(filename), None)));
+                        return Ok(Some((file.urls[0].clone(), target_dir.join(filename), None, None, (file.size > 0).then_some(file.size))));
                     }
                     return Ok(None);
                 }
@@ -837,33 +1189,34 @@ async fn download_curseforge_files(
                     return Err(format!("Failed to create directory {}: {}", target_dir.display(), e));
                 }
                 
+                // The file's name and size come from its metadata, so the total
+                // download size doesn't need a HEAD request per file
+                let (filename, size) = match client.get_file(project_id, file_id).await {
+                    Ok(file_info) => file_info.files.first()
+                        .map(|f| (f.filename.clone(), (f.size > 0).then_some(f.size)))
+                        .unwrap_or_else(|| (format!("{}.jar", file_id), None)),
+                    Err(_) => (format!("{}.jar", file_id), None),
+                };
+                
                 // Get download URL
                 match client.get_download_url(project_id, file_id).await {
                     Ok(download_url) if !download_url.is_empty() => {
-                        // Get filename
-                        let filename = match client.get_file(project_id, file_id).await {
-                            Ok(file_info) => file_info.files.first()
-                                .map(|f| f.filename.clone())
-                                .unwrap_or_else(|| format!("{}.jar", file_id)),
-                            Err(_) => format!("{}.jar", file_id),
+                        let fallback = BlockedFileInfo {
+                            project_id: project_id.to_string(),
+                            file_id: file_id.to_string(),
+                            filename: filename.clone(),
                         };
-                        Ok(Some((download_url, target_dir.join(&filename), None)))
+                        Ok(Some((download_url, target_dir.join(&filename), None, Some(fallback), size)))
                     }
-                    Ok(_) | Err(_) => {
-                        // Blocked or error - get filename for blocked_files
-                        let filename = match client.get_file(project_id, file_id).await {
-                            Ok(file_info) => file_info.files.first()
-                                .map(|f| f.filename.clone())
-                                .unwrap_or_else(|| format!("{}.jar", file_id)),
-                            Err(_) => format!("{}.jar", file_id),
-                        };
-                        
+                    Err(e) => Err(format!("Failed to get download URL for {}: {}", filename, e)),
+                    Ok(_) => {
+                        // Distribution disabled, must be downloaded manually
                         let blocked_info = BlockedFileInfo {
                             project_id: project_id.to_string(),
                             file_id: file_id.to_string(),
                             filename,
                         };
-                        Ok(Some(("".to_string(), PathBuf::new(), Some(blocked_info))))
+                        Ok(Some(("".to_string(), PathBuf::new(), Some(blocked_info), None, None)))
                     }
                 }
             } else if !file.urls.is_empty() {
@@ -887,7 +1240,7 @@ async fn download_curseforge_files(
                     .file_name()
                     .and_then(|n| n.to_str())
                     .unwrap_or(&file.path);
-                Ok(Some((file.urls[0].clone(), target_dir.join(filename), None)))
+                Ok(Some((file.urls[0].clone(), target_dir.join(filename), None, None, (file.size > 0).then_some(file.size))))
             } else {
                 Ok(None)
             }
@@ -896,17 +1249,25 @@ async fn download_curseforge_files(
     
     // Run URL resolution in parallel (limited concurrency)
     let resolved: Vec<_> = futures::future::join_all(resolve_futures).await;
+    if cancel.is_some_and(|cancel| cancel.load(Ordering::Relaxed)) {
+        return CurseForgeDownloadResult { warnings, blocked_files, installed };
+    }
     
     // Collect download tasks and blocked files
     let mut download_tasks = Vec::new();
+    let mut task_keys = Vec::new();
+    let mut task_sizes = Vec::new();
     
-    for result in resolved {
+    // join_all keeps input order, so results line up with `files`
+    for (file, result) in files.iter().zip(resolved) {
         match result {
-            Ok(Some((url, dest, blocked_info))) => {
+            Ok(Some((url, dest, blocked_info, manual_fallback, size))) => {
                 if let Some(info) = blocked_info {
                     blocked_files.push(info);
                 } else if !url.is_empty() {
-                    download_tasks.push((url, dest));
+                    task_keys.push((pack_file_key(file), dest.clone()));
+                    task_sizes.push((url.clone(), size));
+                    download_tasks.push((url, dest, manual_fallback));
                 }
             }
             Ok(None) => {}
@@ -920,6 +1281,12 @@ async fn download_curseforge_files(
     let max_concurrent = config.network.max_concurrent_downloads;
     let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(max_concurrent));
     
+    // Size up the download before it starts so progress can be shown in bytes
+    let expected_bytes = fetch_total_size(&task_sizes, max_concurrent, cancel).await;
+    if cancel.is_some_and(|cancel| cancel.load(Ordering::Relaxed)) {
+        return CurseForgeDownloadResult { warnings, blocked_files, installed };
+    }
+    
     // Progress tracking
     let total_files = download_tasks.len();
     let downloaded_count = Arc::new(AtomicUsize::new(0));
@@ -933,12 +1300,13 @@ async fn download_curseforge_files(
             total: total_files,
             bytes_downloaded: 0,
             speed_bps: 0,
+            total_bytes: expected_bytes,
             current_file: None,
             phase: Some("downloading".to_string()),
         });
     }
     
-    let download_futures: Vec<_> = download_tasks.into_iter().map(|(url, dest)| {
+    let download_futures: Vec<_> = download_tasks.into_iter().map(|(url, dest, manual_fallback)| {
         let sem = semaphore.clone();
         let downloaded_count = downloaded_count.clone();
         let bytes_downloaded = bytes_downloaded.clone();
@@ -947,12 +1315,22 @@ async fn download_curseforge_files(
         
         async move {
             let _permit = sem.acquire().await.unwrap();
+            if cancel.is_some_and(|cancel| cancel.load(Ordering::Relaxed)) {
+                return Err(IMPORT_CANCELLED.to_string());
+            }
             let filename = dest.file_name()
                 .and_then(|n| n.to_str())
                 .unwrap_or("unknown")
                 .to_string();
             
-            match download_file(&url, &dest, None).await {
+            // CurseForge files must be real binaries; anything else goes to manual download
+            let result = if manual_fallback.is_some() {
+                download_binary_file(&url, &dest, None).await
+            } else {
+                download_file(&url, &dest, None).await
+            };
+            
+            match result {
                 Ok(_) => {
                     // Update counters
                     let new_count = downloaded_count.fetch_add(1, Ordering::SeqCst) + 1;
@@ -974,13 +1352,18 @@ async fn download_curseforge_files(
                             total,
                             bytes_downloaded: total_bytes,
                             speed_bps: speed,
+                            total_bytes: expected_bytes,
                             current_file: Some(filename.clone()),
                             phase: Some("downloading".to_string()),
                         });
                     }
                     
                     tracing::debug!("Downloaded: {}", dest.display());
-                    Ok(())
+                    Ok(None)
+                }
+                Err(OxideError::DownloadBlocked(reason)) if manual_fallback.is_some() => {
+                    tracing::warn!("CurseForge download blocked for {}: {}", filename, reason);
+                    Ok(manual_fallback)
                 }
                 Err(e) => Err(format!("Failed to download {}: {}", dest.display(), e)),
             }
@@ -990,9 +1373,13 @@ async fn download_curseforge_files(
     let download_results: Vec<_> = futures::future::join_all(download_futures).await;
     
     let mut downloaded = 0;
-    for result in download_results {
+    for (key, result) in task_keys.into_iter().zip(download_results) {
         match result {
-            Ok(_) => downloaded += 1,
+            Ok(None) => {
+                downloaded += 1;
+                installed.push(key);
+            }
+            Ok(Some(info)) => blocked_files.push(info),
             Err(e) => warnings.push(e),
         }
     }
@@ -1017,10 +1404,11 @@ async fn download_curseforge_files(
             total: total_files,
             bytes_downloaded: total_bytes,
             speed_bps: speed,
+            total_bytes: expected_bytes,
             current_file: None,
             phase: Some("downloading".to_string()),
         });
     }
     
-    CurseForgeDownloadResult { warnings, blocked_files }
+    CurseForgeDownloadResult { warnings, blocked_files, installed }
 }
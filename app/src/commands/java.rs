@@ -18,6 +18,9 @@
 //! You should have received a copy of the GNU General Public License
 //! along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use super::state::AppState;
+use crate::core::java::{permissions, validate_memory_for_arch, JavaRequirement, MemoryValidation};
+use std::path::Path;
 use serde::{Deserialize, Serialize};
 use tauri::Emitter;
 use tokio::sync::mpsc;
@@ -60,12 +63,17 @@ pub struct AvailableJavaInfo {
     pub is_lts: bool,
 }
 
-/// Detect all Java installations on the system
+/// Detect all Java installations on the system. The last detection is
+/// reused until Java is installed or removed, or `refresh` is set.
 #[tauri::command]
-pub async fn detect_java() -> Result<Vec<JavaInstallationInfo>, String> {
-    use crate::core::java::detection::detect_java_installations;
+pub async fn detect_java(refresh: Option<bool>) -> Result<Vec<JavaInstallationInfo>, String> {
+    use crate::core::java::detection::{cached_java_installations, detect_java_installations};
     
-    let installations = detect_java_installations();
+    let installations = if refresh.unwrap_or(false) {
+        detect_java_installations()
+    } else {
+        cached_java_installations()
+    };
     
     Ok(installations.into_iter().map(JavaInstallationInfo::from).collect())
 }
@@ -88,6 +96,12 @@ pub fn get_required_java(minecraft_version: String) -> u32 {
     crate::core::java::detection::get_required_java_version(&minecraft_version)
 }
 
+/// Get the range of Java major versions that can run a Minecraft version
+#[tauri::command]
+pub fn get_java_requirement(minecraft_version: String) -> JavaRequirement {
+    crate::core::java::detection::get_java_requirement(&minecraft_version)
+}
+
 /// Validate a Java installation
 #[tauri::command]
 pub async fn validate_java(java_path: String) -> Result<JavaInstallationInfo, String> {
@@ -187,6 +201,91 @@ pub async fn download_java(
     Ok(JavaInstallationInfo::from(installation))
 }
 
+/// Managed Java installations that fail validation, such as ones left
+/// half-extracted by an interrupted download
+#[tauri::command]
+pub async fn check_managed_java() -> Result<Vec<crate::core::java::download::BrokenManagedJava>, String> {
+    use crate::core::java::download::find_broken_managed_java;
+    
+    find_broken_managed_java().await.map_err(|e| e.to_string())
+}
+
+/// Download a managed Java again and remove the installs it replaces.
+/// `major_or_id` is a major version (every managed Java of that version is
+/// replaced), a managed folder name, or a path inside the managed folder.
+/// The download is extracted to a staging folder and swapped in once it
+/// succeeds, so a failed repair leaves the existing installs in place.
+#[tauri::command]
+pub async fn repair_managed_java(
+    major_or_id: String,
+    app: tauri::AppHandle,
+) -> Result<JavaInstallationInfo, String> {
+    use crate::core::java::download::{delete_managed_java_dir, get_java_install_dir, list_managed_java};
+    
+    let install_dir = get_java_install_dir().map_err(|e| e.to_string())?;
+    let managed = list_managed_java(&install_dir);
+    
+    let (major, targets): (Option<u32>, Vec<_>) = match major_or_id.trim().parse::<u32>() {
+        Ok(major) => (Some(major), managed.into_iter().filter(|java| java.major == Some(major)).collect()),
+        Err(_) => {
+            let id = Path::new(major_or_id.trim());
+            let targets: Vec<_> = managed.into_iter()
+                .filter(|java| java.name == major_or_id.trim() || id.starts_with(&java.dir))
+                .collect();
+            (targets.first().and_then(|java| java.major), targets)
+        }
+    };
+    let major = major.ok_or_else(|| format!("No managed Java matches {}", major_or_id))?;
+    
+    tracing::info!("Re-downloading Java {} to replace {} managed installs", major, targets.len());
+    let installation = download_java(major, app).await?;
+    
+    // The download replaced its own folder; remove the other broken copies
+    let new_path = Path::new(&installation.path);
+    for java in targets.iter().filter(|java| !new_path.starts_with(&java.dir)) {
+        delete_managed_java_dir(&java.dir).await.map_err(|e| e.to_string())?;
+    }
+    
+    Ok(installation)
+}
+
+/// Whether the OxideLaunch wrapper JAR is available
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LaunchWrapperStatus {
+    pub found: bool,
+    /// Where the wrapper was found, if anywhere
+    pub path: Option<String>,
+    /// Where `install_launch_wrapper` places it
+    pub install_path: String,
+}
+
+/// Check whether the OxideLaunch wrapper JAR needed for legacy/modded launches is present
+#[tauri::command]
+pub async fn check_launch_wrapper(
+    state: tauri::State<'_, AppState>,
+) -> Result<LaunchWrapperStatus, String> {
+    use crate::core::launch::{find_launch_wrapper, wrapper_install_path};
+    
+    let data_dir = state.config.lock().unwrap().data_dir();
+    let path = find_launch_wrapper(&data_dir);
+    
+    Ok(LaunchWrapperStatus {
+        found: path.is_some(),
+        path: path.map(|p| p.to_string_lossy().to_string()),
+        install_path: wrapper_install_path(&data_dir).to_string_lossy().to_string(),
+    })
+}
+
+/// Install the OxideLaunch wrapper JAR into `<data>/bin` from the launcher bundle
+#[tauri::command]
+pub async fn install_launch_wrapper(state: tauri::State<'_, AppState>) -> Result<String, String> {
+    use crate::core::launch::install_launch_wrapper as do_install;
+    
+    let data_dir = state.config.lock().unwrap().data_dir();
+    let path = do_install(&data_dir).map_err(|e| e.to_string())?;
+    Ok(path.to_string_lossy().to_string())
+}
+
 /// Get the path to the managed Java directory
 #[tauri::command]
 pub fn get_java_install_dir() -> Result<String, String> {
@@ -212,6 +311,15 @@ pub async fn delete_java(java_path: String) -> Result<(), String> {
         .await
         .map_err(|e| e.to_string())
 }
+
+/// Re-apply the executable bit to a Java installation's binaries.
+/// Returns the files that were fixed; a no-op off Unix.
+#[tauri::command]
+pub async fn repair_java_permissions(java_path: String) -> Result<Vec<String>, String> {
+    let fixed = permissions::repair_java_permissions(Path::new(&java_path)).map_err(|e| e.to_string())?;
+    Ok(fixed.into_iter().map(|p| p.to_string_lossy().to_string()).collect())
+}
+
 /// Java compatibility check result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JavaCompatibilityResult {
@@ -235,10 +343,12 @@ pub fn check_java_compatibility(
     java_major_version: u32,
     minecraft_version: String,
 ) -> JavaCompatibilityResult {
-    let required = crate::core::java::detection::get_required_java_version(&minecraft_version);
-    let (min_compatible, max_compatible) = get_java_compat_range(required);
+    let requirement = crate::core::java::detection::get_java_requirement(&minecraft_version);
+    let required = requirement.recommended_major;
+    let min_compatible = requirement.min_major;
+    let max_compatible = requirement.max_major;
     
-    let compatible = java_major_version >= min_compatible && java_major_version <= max_compatible;
+    let compatible = requirement.contains(java_major_version);
     
     let message = if compatible {
         format!("Java {} is compatible with Minecraft {}", java_major_version, minecraft_version)
@@ -264,15 +374,46 @@ pub fn check_java_compatibility(
     }
 }
 
-/// Get the compatible Java version range (min, max) for a required version
-fn get_java_compat_range(required_major: u32) -> (u32, u32) {
-    match required_major {
-        8 => (8, 8),
-        16 => (16, 17),
-        17 => (17, 21),
-        21 => (21, 25),
-        _ => (required_major, required_major + 4),
+/// Check whether an instance's memory settings fit the Java it would launch with.
+///
+/// Resolves Java the way the launch does (instance path, global path, then
+/// auto-detection) and reports a 32-bit JVM paired with a heap it can't address.
+/// The returned values are what the launch will actually use.
+#[tauri::command]
+pub async fn check_java_memory(
+    state: tauri::State<'_, AppState>,
+    instance_id: String,
+) -> Result<MemoryValidation, String> {
+    use crate::core::java::checker::JavaChecker;
+    use crate::core::java::detection::find_java_for_minecraft;
+    
+    let (instance, config) = {
+        let instances = state.instances.lock().unwrap();
+        let instance = instances.iter()
+            .find(|i| i.id == instance_id)
+            .ok_or_else(|| "Instance not found".to_string())?
+            .clone();
+        (instance, state.config.lock().unwrap().clone())
+    };
+    
+    let min_memory = instance.settings.min_memory.unwrap_or(config.memory.min_memory);
+    let max_memory = instance.settings.max_memory.unwrap_or(config.memory.max_memory);
+    
+    let java_path = instance.settings.java_path.clone()
+        .or_else(|| config.java.custom_path.clone())
+        .or_else(|| find_java_for_minecraft(&instance.minecraft_version).map(|java| java.path));
+    
+    // Without a Java to inspect there is nothing to compare against
+    let Some(java_path) = java_path else {
+        return Ok(validate_memory_for_arch(true, min_memory, max_memory));
+    };
+    
+    let result = JavaChecker::new(java_path).check().await;
+    if !result.valid {
+        return Err(result.error.unwrap_or_else(|| "Java check failed".to_string()));
     }
+    
+    Ok(validate_memory_for_arch(result.is_64bit, min_memory, max_memory))
 }
 
 /// Find the best Java for an instance, with option to auto-download
@@ -32,6 +32,9 @@ pub mod screenshots;
 pub mod shortcuts;
 pub mod rustwiz;
 pub mod news;
+pub mod profiles;
+pub mod servers;
+pub mod startup;
 
 // Re-export state types for use in main.rs
 pub use state::AppState;
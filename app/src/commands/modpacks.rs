@@ -68,7 +68,8 @@ pub struct ModpackVersion {
     pub size: u64,
     pub downloads: u64,
     pub date_published: String,
-    pub version_type: String,
+    /// Serialized as "Release", "Beta" or "Alpha"
+    pub version_type: VersionType,
     pub platform: String,
 }
 
@@ -647,7 +648,7 @@ async fn get_modrinth_modpack_versions(
             size: primary_file.map(|f| f.size).unwrap_or(0),
             downloads: v.downloads,
             date_published: v.date_published.to_rfc3339(),
-            version_type: format!("{:?}", v.version_type),
+            version_type: v.version_type,
             platform: "Modrinth".to_string(),
         }
     }).collect())
@@ -689,7 +690,7 @@ async fn get_curseforge_modpack_versions(
             size: primary_file.map(|f| f.size).unwrap_or(0),
             downloads: v.downloads,
             date_published: v.date_published.to_rfc3339(),
-            version_type: format!("{:?}", v.version_type),
+            version_type: v.version_type,
             platform: "CurseForge".to_string(),
         }
     }).collect())
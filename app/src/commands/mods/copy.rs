@@ -0,0 +1,70 @@
+//! Copying mods between instances.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::commands::state::AppState;
+use crate::core::instance::{copy_mods, Instance, ModCopyResult, ModTarget};
+use tauri::State;
+
+/// Copy mods from one instance into another.
+///
+/// `filenames` picks the jars to copy; None copies all of them. Jars come
+/// with their metadata and keep their enabled state. Mods the destination
+/// already has are skipped, and mods that may not match the destination's
+/// loader or game version are copied with a warning.
+#[tauri::command]
+pub async fn copy_mods_from_instance(
+    state: State<'_, AppState>,
+    source_id: String,
+    dest_id: String,
+    filenames: Option<Vec<String>>,
+) -> Result<ModCopyResult, String> {
+    if source_id == dest_id {
+        return Err("Source and destination are the same instance".to_string());
+    }
+    
+    let ((source_dir, source), (dest_dir, dest)) = {
+        let instances = state.instances.lock().unwrap();
+        let find = |id: &str| {
+            instances.iter()
+                .find(|i| i.id == id)
+                .map(|instance| (instance.mods_dir(), mod_target(instance)))
+                .ok_or_else(|| "Instance not found".to_string())
+        };
+        (find(&source_id)?, find(&dest_id)?)
+    };
+    
+    let result = copy_mods(&source_dir, &dest_dir, filenames.as_deref(), &source, &dest)
+        .map_err(|e| format!("Failed to copy mods: {}", e))?;
+    
+    if !result.copied.is_empty() {
+        tracing::info!("Copied {} mods from {} to {}", result.copied.len(), source_id, dest_id);
+        state.invalidate_instance_summary(&dest_id);
+    }
+    
+    Ok(result)
+}
+
+fn mod_target(instance: &Instance) -> ModTarget {
+    ModTarget {
+        minecraft_version: instance.minecraft_version.clone(),
+        loader: instance.mod_loader.as_ref()
+            .map(|ml| format!("{:?}", ml.loader_type).to_lowercase()),
+    }
+}
@@ -18,8 +18,10 @@
 //! You should have received a copy of the GNU General Public License
 //! along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::commands::instances::{resolve_blocked_mod, ModDownloadBlocked, MOD_DOWNLOAD_BLOCKED_EVENT};
 use crate::commands::state::AppState;
-use crate::core::download::download_file;
+use crate::core::download::{download_binary_file, download_file, fetch_content_length};
+use crate::core::error::OxideError;
 use crate::core::modplatform::{
     curseforge::CurseForgeClient, 
     modrinth::ModrinthClient,
@@ -30,7 +32,7 @@ use crate::core::rustwiz::{
 };
 use tauri::{State, Emitter, AppHandle};
 use std::sync::Arc;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use tokio::sync::Semaphore;
 
 // =============================================================================
@@ -44,7 +46,7 @@ use tokio::sync::Semaphore;
 /// - Modpack export to .mrpack or CurseForge formats
 /// 
 /// Metadata is stored in mods/.index/<slug>.pw.toml following Prism Launcher's approach.
-fn create_mod_metadata(
+pub(super) fn create_mod_metadata(
     mods_dir: &Path,
     filename: &str,
     name: &str,
@@ -110,6 +112,58 @@ fn create_mod_metadata(
     }
 }
 
+/// Download a CurseForge mod file.
+///
+/// Distribution-disabled files come back as a 403 or an HTML page instead of a JAR.
+/// Those are routed into the blocked mods workflow through `MOD_DOWNLOAD_BLOCKED_EVENT`
+/// so the user can supply the file manually, and nothing is written to the mods folder.
+async fn download_curseforge_mod_file(
+    app: &AppHandle,
+    instance_id: &str,
+    client: &CurseForgeClient,
+    mod_id: u32,
+    file_id: u32,
+    download_url: &str,
+    file_path: &PathBuf,
+) -> Result<(), String> {
+    let result = if download_url.is_empty() {
+        Err(OxideError::DownloadBlocked("no download URL provided".to_string()))
+    } else {
+        download_binary_file(download_url, file_path, None).await
+    };
+    
+    match result {
+        Ok(()) => Ok(()),
+        Err(OxideError::DownloadBlocked(reason)) => {
+            tracing::warn!("CurseForge download blocked for {}/{}: {}", mod_id, file_id, reason);
+            
+            let filename = file_path.file_name().and_then(|n| n.to_str());
+            let blocked = resolve_blocked_mod(client, mod_id, file_id, filename, "mods").await;
+            let message = format!(
+                "{} ({}) must be downloaded manually from CurseForge",
+                blocked.name, blocked.filename
+            );
+            let _ = app.emit(MOD_DOWNLOAD_BLOCKED_EVENT, ModDownloadBlocked {
+                instance_id: instance_id.to_string(),
+                blocked_mods: vec![blocked],
+            });
+            
+            Err(message)
+        }
+        Err(e) => Err(format!("Failed to download mod: {}", e)),
+    }
+}
+
+/// Get a file's size before downloading it, for a determinate progress bar.
+/// Returns None when the server doesn't report a length.
+#[tauri::command]
+pub async fn get_download_size(url: String) -> Result<Option<u64>, String> {
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        return Err(format!("Not an HTTP URL: {}", url));
+    }
+    Ok(fetch_content_length(&url).await)
+}
+
 /// Progress tracking for batch downloads
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct ModDownloadProgress {
@@ -128,6 +182,7 @@ pub struct ModDownloadRequest {
 
 #[tauri::command]
 pub async fn download_mod(
+    app: AppHandle,
     state: State<'_, AppState>,
     instance_id: String,
     mod_id: String,
@@ -182,9 +237,9 @@ pub async fn download_mod(
             
             let file = &version.files[0];
             
+            let file_id: u32 = version.id.parse()
+                .map_err(|_| "Invalid file ID".to_string())?;
             let download_url = if file.url.is_empty() {
-                let file_id: u32 = version.id.parse()
-                    .map_err(|_| "Invalid file ID".to_string())?;
                 client.get_download_url(mod_id_num, file_id)
                     .await
                     .map_err(|e| format!("Failed to get download URL: {}", e))?
@@ -194,9 +249,7 @@ pub async fn download_mod(
             
             let file_path = mods_dir.join(&file.filename);
             
-            download_file(&download_url, &file_path, None)
-                .await
-                .map_err(|e| format!("Failed to download mod: {}", e))?;
+            download_curseforge_mod_file(&app, &instance_id, &client, mod_id_num, file_id, &download_url, &file_path).await?;
             
             // Create RustWiz metadata with icon_url and description
             let hash = rustwiz::compute_file_hash(&file_path, HashFormat::Sha512)
@@ -273,11 +326,14 @@ pub async fn download_mod(
         }
     }
     
+    state.invalidate_instance_summary(&instance_id);
+    
     Ok(())
 }
 
 #[tauri::command]
 pub async fn download_mod_version(
+    app: AppHandle,
     state: State<'_, AppState>,
     instance_id: String,
     mod_id: String,
@@ -336,9 +392,7 @@ pub async fn download_mod_version(
             
             let file_path = mods_dir.join(&file.filename);
             
-            download_file(&download_url, &file_path, None)
-                .await
-                .map_err(|e| format!("Failed to download mod: {}", e))?;
+            download_curseforge_mod_file(&app, &instance_id, &client, mod_id_num, file_id, &download_url, &file_path).await?;
             
             // Create RustWiz metadata with icon_url and description
             let hash = rustwiz::compute_file_hash(&file_path, HashFormat::Sha512)
@@ -411,6 +465,8 @@ pub async fn download_mod_version(
         }
     }
     
+    state.invalidate_instance_summary(&instance_id);
+    
     Ok(())
 }
 
@@ -459,11 +515,14 @@ pub async fn download_mods_batch(
         let mc_version = Arc::clone(&mc_version);
         let loader_name = Arc::clone(&loader_name);
         let app_handle = app.clone();
+        let instance_id = instance_id.clone();
         
         let handle = tokio::spawn(async move {
             let _permit = semaphore.acquire().await.map_err(|e| e.to_string())?;
             
             let result = download_single_mod(
+                &app_handle,
+                &instance_id,
                 &mods_dir,
                 &mod_req.mod_id,
                 &mod_req.version_id,
@@ -489,6 +548,7 @@ pub async fn download_mods_batch(
     
     // Wait for all downloads to complete
     let results: Vec<_> = futures::future::join_all(handles).await;
+    state.invalidate_instance_summary(&instance_id);
     
     // Check for errors
     let errors: Vec<String> = results.into_iter()
@@ -508,6 +568,8 @@ pub async fn download_mods_batch(
 
 /// Internal function to download a single mod
 async fn download_single_mod(
+    app: &AppHandle,
+    instance_id: &str,
     mods_dir: &std::path::Path,
     mod_id: &str,
     version_id: &str,
@@ -552,9 +614,7 @@ async fn download_single_mod(
             
             let file_path = mods_dir.join(&file.filename);
             
-            download_file(&download_url, &file_path, None)
-                .await
-                .map_err(|e| format!("Failed to download mod: {}", e))?;
+            download_curseforge_mod_file(app, instance_id, &client, mod_id_num, file_id, &download_url, &file_path).await?;
             
             // Create RustWiz metadata with icon_url and description
             let hash = rustwiz::compute_file_hash(&file_path, HashFormat::Sha512)
@@ -654,6 +714,8 @@ pub async fn add_local_mod(
     
     std::fs::copy(source, dest).map_err(|e| format!("Failed to copy mod: {}", e))?;
     
+    state.invalidate_instance_summary(&instance_id);
+    
     Ok(())
 }
 
@@ -682,5 +744,7 @@ pub async fn add_local_mod_from_bytes(
     std::fs::write(dest, bytes)
         .map_err(|e| format!("Failed to write mod file: {}", e))?;
     
+    state.invalidate_instance_summary(&instance_id);
+    
     Ok(())
 }
@@ -20,6 +20,8 @@
 
 use crate::commands::state::AppState;
 use crate::core::files;
+use crate::core::modplatform::{mod_side, ModSide};
+use crate::core::instance::{clean_mod_metadata as clean_mods_dir_metadata, find_duplicate_mods as find_duplicates_in_dir, find_mod_file, repair_mod_files as repair_mods_dir, scan_mod_files, split_mod_filename, DuplicateMods, ModFileRepair, DISABLED_SUFFIX};
 use crate::core::rustwiz::{self, parser::read_mod_toml};
 use super::types::*;
 use tauri::State;
@@ -70,12 +72,13 @@ pub async fn get_installed_mods(
             }
             
             // Only process .jar files (enabled or disabled)
-            if !filename.ends_with(".jar") && !filename.ends_with(".jar.disabled") {
+            let Some((base, enabled, suffixes)) = split_mod_filename(&filename) else {
                 continue;
+            };
+            if suffixes > 1 {
+                tracing::warn!("Mod file {} has repeated .disabled suffixes; run repair_mod_files to fix it", filename);
             }
-            
-            let enabled = !filename.ends_with(".disabled");
-            let base_filename = filename.trim_end_matches(".disabled").to_string();
+            let base_filename = base.to_string();
             
             let file_meta = entry.metadata().ok();
             let size = file_meta.as_ref().map(|m| m.len()).unwrap_or(0);
@@ -105,6 +108,8 @@ pub async fn get_installed_mods(
                 None
             };
             
+            let pinned = pw_toml_metadata.as_ref().is_some_and(|m| m.packwiz.pin);
+            
             // Priority: pw.toml > .metadata.json > JAR parsing
             let (name, version, provider, icon_url, homepage, issues_url, source_url) = if let Some(ref pw_meta) = pw_toml_metadata {
                 // Extract provider from update section
@@ -132,11 +137,7 @@ pub async fn get_installed_mods(
                 // Try to parse mod metadata from JAR file
                 use crate::core::modplatform::mod_parser::{parse_mod_jar, extract_mod_icon};
                 
-                let jar_path = if enabled {
-                    mods_dir.join(&base_filename)
-                } else {
-                    mods_dir.join(format!("{}.disabled", base_filename))
-                };
+                let jar_path = path.clone();
                 
                 if let Some(jar_details) = parse_mod_jar(&jar_path) {
                     tracing::debug!(
@@ -213,6 +214,7 @@ pub async fn get_installed_mods(
                 homepage,
                 issues_url,
                 source_url,
+                pinned,
             });
         }
     }
@@ -235,24 +237,158 @@ pub async fn toggle_mod(
         .ok_or_else(|| "Instance not found".to_string())?;
     
     let mods_dir = instance.mods_dir();
-    let current_path = if enabled {
-        mods_dir.join(format!("{}.disabled", filename))
-    } else {
-        mods_dir.join(&filename)
-    };
-    
     let new_path = if enabled {
         mods_dir.join(&filename)
     } else {
-        mods_dir.join(format!("{}.disabled", filename))
+        mods_dir.join(format!("{}{}", filename, DISABLED_SUFFIX))
     };
     
-    std::fs::rename(current_path, new_path)
+    // Already in the requested state
+    if new_path.is_file() {
+        return Ok(());
+    }
+    
+    // Whatever file currently backs the mod, including ones with repeated suffixes
+    let current = find_mod_file(&mods_dir, &filename)
+        .ok_or_else(|| format!("Mod file not found: {}", filename))?;
+    
+    std::fs::rename(mods_dir.join(current), new_path)
         .map_err(|e| format!("Failed to toggle mod: {}", e))?;
     
+    state.invalidate_instance_summary(&instance_id);
+    
+    Ok(())
+}
+
+/// Pin a mod at its current version, or unpin it. Pinned mods are skipped by
+/// `check_mod_updates` and left in place by `update_modpack`. Only mods with
+/// RustWiz metadata can be pinned.
+#[tauri::command]
+pub async fn set_mod_pinned(
+    state: State<'_, AppState>,
+    instance_id: String,
+    filename: String,
+    pinned: bool,
+) -> Result<(), String> {
+    let mods_dir = {
+        let instances = state.instances.lock().unwrap();
+        instances.iter()
+            .find(|i| i.id == instance_id)
+            .ok_or_else(|| "Instance not found".to_string())?
+            .mods_dir()
+    };
+    
+    let base = split_mod_filename(&filename)
+        .map(|(base, _, _)| base.to_string())
+        .unwrap_or(filename);
+    let changed = rustwiz::set_mod_pinned(&mods_dir, &base, pinned).map_err(|e| e.to_string())?;
+    if !changed {
+        return Ok(());
+    }
+    
+    tracing::info!("{} {} in instance {}", if pinned { "Pinned" } else { "Unpinned" }, base, instance_id);
+    state.invalidate_instance_summary(&instance_id);
+    
     Ok(())
 }
 
+/// Find and fix mod files left in an inconsistent state: jars with repeated
+/// `.disabled` suffixes, jars present both enabled and disabled, and
+/// `.metadata.json` files whose jar is gone. With `dry_run` only the issues
+/// are reported.
+#[tauri::command]
+pub async fn repair_mod_files(
+    state: State<'_, AppState>,
+    instance_id: String,
+    dry_run: bool,
+) -> Result<ModFileRepair, String> {
+    let use_recycle_bin = {
+        let config = state.config.lock().unwrap();
+        config.files.use_recycle_bin
+    };
+    
+    let mods_dir = {
+        let instances = state.instances.lock().unwrap();
+        instances.iter()
+            .find(|i| i.id == instance_id)
+            .ok_or_else(|| "Instance not found".to_string())?
+            .mods_dir()
+    };
+    
+    if dry_run {
+        return Ok(ModFileRepair {
+            issues: scan_mod_files(&mods_dir),
+            ..Default::default()
+        });
+    }
+    
+    let repair = repair_mods_dir(&mods_dir, use_recycle_bin)
+        .map_err(|e| format!("Failed to repair mod files: {}", e))?;
+    
+    if !repair.fixed.is_empty() {
+        tracing::info!("Repaired mod files for {}: {:?}", instance_id, repair.fixed);
+        state.invalidate_instance_summary(&instance_id);
+    }
+    
+    Ok(repair)
+}
+
+/// Remove `.metadata.json` files whose jar is gone (enabled or disabled) and
+/// rename ones saved under the disabled filename. `get_installed_mods` never
+/// lists orphans, but they clutter the mods folder. Returns how many files
+/// were removed.
+#[tauri::command]
+pub async fn clean_mod_metadata(
+    state: State<'_, AppState>,
+    instance_id: String,
+) -> Result<usize, String> {
+    let use_recycle_bin = {
+        let config = state.config.lock().unwrap();
+        config.files.use_recycle_bin
+    };
+    
+    let mods_dir = {
+        let instances = state.instances.lock().unwrap();
+        instances.iter()
+            .find(|i| i.id == instance_id)
+            .ok_or_else(|| "Instance not found".to_string())?
+            .mods_dir()
+    };
+    
+    let cleanup = clean_mods_dir_metadata(&mods_dir, use_recycle_bin)
+        .map_err(|e| format!("Failed to clean mod metadata: {}", e))?;
+    
+    if !cleanup.removed.is_empty() || !cleanup.renamed.is_empty() {
+        tracing::info!(
+            "Cleaned mod metadata for {}: removed {:?}, renamed {:?}",
+            instance_id, cleanup.removed, cleanup.renamed
+        );
+    }
+    
+    Ok(cleanup.removed.len())
+}
+
+/// Find mods installed more than once under different filenames, grouped by
+/// the mod ID in each jar's metadata. Disabled jars are included; a disabled
+/// copy identical to its enabled twin isn't reported.
+#[tauri::command]
+pub async fn find_duplicate_mods(
+    state: State<'_, AppState>,
+    instance_id: String,
+) -> Result<Vec<DuplicateMods>, String> {
+    let mods_dir = {
+        let instances = state.instances.lock().unwrap();
+        instances.iter()
+            .find(|i| i.id == instance_id)
+            .ok_or_else(|| "Instance not found".to_string())?
+            .mods_dir()
+    };
+    
+    tokio::task::spawn_blocking(move || find_duplicates_in_dir(&mods_dir))
+        .await
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub async fn delete_mod(
     state: State<'_, AppState>,
@@ -288,9 +424,32 @@ pub async fn delete_mod(
         let _ = files::delete_file(&potential_toml, use_recycle_bin);
     }
     
+    state.invalidate_instance_summary(&instance_id);
+    
     Ok(())
 }
 
+/// Which side a mod runs on, read from its jar metadata
+#[tauri::command]
+pub async fn get_mod_side(
+    state: State<'_, AppState>,
+    instance_id: String,
+    filename: String,
+) -> Result<ModSide, String> {
+    let mods_dir = {
+        let instances = state.instances.lock().unwrap();
+        let instance = instances.iter()
+            .find(|i| i.id == instance_id)
+            .ok_or_else(|| "Instance not found".to_string())?;
+        instance.mods_dir()
+    };
+    
+    let current = find_mod_file(&mods_dir, &filename)
+        .ok_or_else(|| format!("Mod file not found: {}", filename))?;
+    
+    Ok(mod_side(&mods_dir.join(current)))
+}
+
 #[tauri::command]
 pub async fn delete_mods(
     state: State<'_, AppState>,
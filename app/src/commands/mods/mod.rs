@@ -22,8 +22,16 @@ pub mod types;
 pub mod search;
 pub mod download;
 pub mod listing;
+pub mod suggestions;
+pub mod copy;
+pub mod scan;
+pub mod verify;
 
 // Re-export all commands - using wildcard to include __cmd__ symbols for tauri
 pub use search::*;
 pub use download::*;
 pub use listing::*;
+pub use suggestions::*;
+pub use copy::*;
+pub use scan::*;
+pub use verify::*;
@@ -0,0 +1,106 @@
+//! Scanning mods against known-bad file hashes.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::commands::state::AppState;
+use crate::core::config::NetworkConfig;
+use crate::core::download::http_client;
+use crate::core::instance::{scan_instance_files, Blocklist, ScanReport};
+use tauri::State;
+
+/// Load the configured blocklist sources, returning a warning for each one
+/// that couldn't be loaded
+async fn configured_blocklist(url: Option<String>, file: Option<std::path::PathBuf>, network: &NetworkConfig) -> (Blocklist, Vec<String>) {
+    let mut blocklist = Blocklist::bundled();
+    let mut warnings = Vec::new();
+    
+    if let Some(path) = file {
+        match std::fs::read_to_string(&path).map_err(|e| e.to_string())
+            .and_then(|json| Blocklist::parse(&json).map_err(|e| e.to_string()))
+        {
+            Ok(list) => blocklist.extend(list),
+            Err(e) => warnings.push(format!("Couldn't load blocklist {}: {}", path.display(), e)),
+        }
+    }
+    
+    if let Some(url) = url {
+        let fetched = async {
+            let client = http_client(network).map_err(|e| e.to_string())?;
+            let response = client.get(&url).send().await
+                .and_then(|response| response.error_for_status())
+                .map_err(|e| e.to_string())?;
+            let json = response.text().await.map_err(|e| e.to_string())?;
+            Blocklist::parse(&json).map_err(|e| e.to_string())
+        };
+        match fetched.await {
+            Ok(list) => blocklist.extend(list),
+            Err(e) => warnings.push(format!("Couldn't fetch blocklist {}: {}", url, e)),
+        }
+    }
+    
+    (blocklist, warnings)
+}
+
+/// Check an instance's mods, coremods and jar mods against a blocklist of
+/// known-malicious file hashes (the bundled list plus the configured URL or
+/// file). This is known-bad hash detection only, not an antivirus scan: a
+/// file that isn't reported is only known not to be on the list. With no
+/// hashes loaded the report says nothing was checked (`checked: false`).
+#[tauri::command]
+pub async fn scan_mods_for_malware(
+    state: State<'_, AppState>,
+    instance_id: String,
+) -> Result<ScanReport, String> {
+    let instance = state.instances.lock().unwrap()
+        .iter()
+        .find(|i| i.id == instance_id)
+        .cloned()
+        .ok_or_else(|| "Instance not found".to_string())?;
+    let (mods_config, network) = {
+        let config = state.config.lock().unwrap();
+        (config.mods.clone(), config.network.clone())
+    };
+    
+    let (blocklist, source_warnings) = configured_blocklist(
+        mods_config.hash_blocklist_url,
+        mods_config.hash_blocklist_file,
+        &network,
+    ).await;
+    let allowlist = mods_config.hash_allowlist;
+    
+    let scan = tokio::task::spawn_blocking(move || {
+        scan_instance_files(&instance.path, &instance.game_dir(), &blocklist, &allowlist)
+    });
+    let mut report = scan.await.map_err(|e| e.to_string())?;
+    report.warnings.splice(0..0, source_warnings);
+    
+    for found in &report.matches {
+        tracing::warn!("Known-bad file in instance {}: {} ({})", instance_id, found.file, found.name);
+    }
+    if !report.checked {
+        tracing::warn!("Mod scan for instance {} skipped: the blocklist is empty", instance_id);
+        return Ok(report);
+    }
+    tracing::info!(
+        "Scanned {} files in instance {} against {} known-bad hashes: {} matches",
+        report.scanned, instance_id, report.blocklist_entries, report.matches.len()
+    );
+    
+    Ok(report)
+}
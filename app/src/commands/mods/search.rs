@@ -278,6 +278,37 @@ pub async fn get_mod_details(
     }
 }
 
+/// Convert a platform version into the response sent to the frontend
+fn version_response(v: ProjectVersion) -> ModVersionResponse {
+    ModVersionResponse {
+        id: v.id,
+        version_number: v.version_number,
+        name: v.name,
+        game_versions: v.game_versions,
+        loaders: v.loaders,
+        date_published: v.date_published.to_rfc3339(),
+        downloads: v.downloads,
+        files: v.files.into_iter().map(|f| ModFileResponse {
+            filename: f.filename,
+            url: f.url,
+            size: f.size,
+            primary: f.primary,
+        }).collect(),
+        dependencies: v.dependencies.into_iter()
+            .filter(|d| d.project_id.is_some())
+            .map(|d| ModDependencyResponse {
+                project_id: d.project_id.unwrap_or_default(),
+                dependency_type: match d.dependency_type {
+                    DependencyType::Required => "required".to_string(),
+                    DependencyType::Optional => "optional".to_string(),
+                    DependencyType::Incompatible => "incompatible".to_string(),
+                    DependencyType::Embedded => "embedded".to_string(),
+                    DependencyType::Unknown => "unknown".to_string(),
+                },
+            }).collect(),
+    }
+}
+
 #[tauri::command]
 pub async fn get_mod_versions(
     mod_id: String,
@@ -307,33 +338,7 @@ pub async fn get_mod_versions(
                 loaders.first().map(|s| s.as_str()),
             ).await.map_err(|e| format!("Failed to get mod versions: {}", e))?;
             
-            Ok(versions.into_iter().map(|v| ModVersionResponse {
-                id: v.id,
-                version_number: v.version_number,
-                name: v.name,
-                game_versions: v.game_versions,
-                loaders: v.loaders,
-                date_published: v.date_published.to_rfc3339(),
-                downloads: v.downloads,
-                files: v.files.into_iter().map(|f| ModFileResponse {
-                    filename: f.filename,
-                    url: f.url,
-                    size: f.size,
-                    primary: f.primary,
-                }).collect(),
-                dependencies: v.dependencies.into_iter()
-                    .filter(|d| d.project_id.is_some())
-                    .map(|d| ModDependencyResponse {
-                        project_id: d.project_id.unwrap_or_default(),
-                        dependency_type: match d.dependency_type {
-                            crate::core::modplatform::types::DependencyType::Required => "required".to_string(),
-                            crate::core::modplatform::types::DependencyType::Optional => "optional".to_string(),
-                            crate::core::modplatform::types::DependencyType::Incompatible => "incompatible".to_string(),
-                            crate::core::modplatform::types::DependencyType::Embedded => "embedded".to_string(),
-                            crate::core::modplatform::types::DependencyType::Unknown => "unknown".to_string(),
-                        },
-                    }).collect(),
-            }).collect())
+            Ok(versions.into_iter().map(version_response).collect())
         },
         _ => {
             let client = ModrinthClient::new();
@@ -344,79 +349,86 @@ pub async fn get_mod_versions(
                 if loaders.is_empty() { None } else { Some(&loaders) },
             ).await.map_err(|e| format!("Failed to get mod versions: {}", e))?;
             
-            Ok(versions.into_iter().map(|v| ModVersionResponse {
-                id: v.id,
-                version_number: v.version_number,
-                name: v.name,
-                game_versions: v.game_versions,
-                loaders: v.loaders,
-                date_published: v.date_published.to_rfc3339(),
-                downloads: v.downloads,
-                files: v.files.into_iter().map(|f| ModFileResponse {
-                    filename: f.filename,
-                    url: f.url,
-                    size: f.size,
-                    primary: f.primary,
-                }).collect(),
-                dependencies: v.dependencies.into_iter()
-                    .filter(|d| d.project_id.is_some())
-                    .map(|d| ModDependencyResponse {
-                        project_id: d.project_id.unwrap_or_default(),
-                        dependency_type: match d.dependency_type {
-                            crate::core::modplatform::types::DependencyType::Required => "required".to_string(),
-                            crate::core::modplatform::types::DependencyType::Optional => "optional".to_string(),
-                            crate::core::modplatform::types::DependencyType::Incompatible => "incompatible".to_string(),
-                            crate::core::modplatform::types::DependencyType::Embedded => "embedded".to_string(),
-                            crate::core::modplatform::types::DependencyType::Unknown => "unknown".to_string(),
-                        },
-                    }).collect(),
-            }).collect())
+            Ok(versions.into_iter().map(version_response).collect())
         }
     }
 }
 
+/// Every version of a project, unfiltered by game version or loader, one page
+/// at a time (newest first). `limit` defaults to 50; CurseForge pages hold at
+/// most 50 files.
 #[tauri::command]
-pub async fn get_mod_categories(
+pub async fn get_all_mod_versions(
     platform: String,
-) -> Result<Vec<String>, String> {
-    match platform.to_lowercase().as_str() {
+    mod_id: String,
+    offset: Option<u32>,
+    limit: Option<u32>,
+) -> Result<ModVersionPage, String> {
+    let offset = offset.unwrap_or(0);
+    let limit = limit.unwrap_or(50).clamp(1, 100);
+    
+    let page = match platform.to_lowercase().as_str() {
         "curseforge" => {
-            Ok(vec![
-                "Adventure".to_string(),
-                "Magic".to_string(),
-                "Tech".to_string(),
-                "Storage".to_string(),
-                "Library".to_string(),
-                "Utility".to_string(),
-                "World Gen".to_string(),
-                "Cosmetic".to_string(),
-                "Food".to_string(),
-                "Armor".to_string(),
-                "Mobs".to_string(),
-            ])
+            let client = CurseForgeClient::new();
+            if !client.has_api_key() {
+                return Err("CurseForge API key not configured".to_string());
+            }
+            
+            let mod_id_num: u32 = mod_id.parse()
+                .map_err(|_| "Invalid CurseForge mod ID".to_string())?;
+            
+            client.get_files_page(mod_id_num, offset, limit).await
+        },
+        _ => ModrinthClient::new().get_versions_page(&mod_id, offset, limit).await,
+    }.map_err(|e| format!("Failed to get mod versions: {}", e))?;
+    
+    Ok(ModVersionPage {
+        versions: page.versions.into_iter().map(version_response).collect(),
+        total: page.total,
+        offset: page.offset,
+        limit: page.limit,
+    })
+}
+
+/// Get the categories a platform offers for a project type, for filtering
+/// searches. The names returned are the ones `search_mods_detailed` accepts.
+#[tauri::command]
+pub async fn get_categories(
+    platform: String,
+    resource_type: ResourceType,
+) -> Result<Vec<PlatformCategory>, String> {
+    let categories = match platform.to_lowercase().as_str() {
+        "curseforge" => {
+            let client = CurseForgeClient::new();
+            if !client.has_api_key() {
+                return Err("CurseForge API key not configured. Please add your API key in settings.".to_string());
+            }
+            
+            client.get_categories(resource_type)
+                .await
+                .map_err(|e| format!("Failed to get CurseForge categories: {}", e))?
         },
         _ => {
-            Ok(vec![
-                "adventure".to_string(),
-                "cursed".to_string(),
-                "decoration".to_string(),
-                "economy".to_string(),
-                "equipment".to_string(),
-                "food".to_string(),
-                "game-mechanics".to_string(),
-                "library".to_string(),
-                "magic".to_string(),
-                "management".to_string(),
-                "minigame".to_string(),
-                "mobs".to_string(),
-                "optimization".to_string(),
-                "social".to_string(),
-                "storage".to_string(),
-                "technology".to_string(),
-                "transportation".to_string(),
-                "utility".to_string(),
-                "worldgen".to_string(),
-            ])
+            // Data packs share the mod categories on Modrinth
+            let project_type = match resource_type {
+                ResourceType::Modpack => "modpack",
+                ResourceType::ResourcePack => "resourcepack",
+                ResourceType::ShaderPack => "shader",
+                ResourceType::Mod | ResourceType::DataPack => "mod",
+            };
+            
+            ModrinthClient::new().get_categories()
+                .await
+                .map_err(|e| format!("Failed to get Modrinth categories: {}", e))?
+                .into_iter()
+                .filter(|c| c.project_type == project_type)
+                .collect()
         }
-    }
+    };
+    
+    Ok(categories.into_iter().map(|c| PlatformCategory {
+        name: c.name,
+        icon: c.icon,
+        project_type: c.project_type,
+    }).collect())
 }
@@ -0,0 +1,141 @@
+//! Essential mod suggestions for an instance's loader.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::download::download_mod;
+use crate::commands::state::AppState;
+use crate::core::config::SuggestedMod;
+use crate::core::modplatform::suggestions_for_loader;
+use crate::core::rustwiz::parser::{find_mod_tomls, read_mod_toml};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::path::Path;
+use tauri::{AppHandle, State};
+
+/// A suggested mod and whether the instance already has it
+#[derive(Debug, Clone, Serialize)]
+pub struct SuggestedModInfo {
+    #[serde(flatten)]
+    pub suggestion: SuggestedMod,
+    pub installed: bool,
+}
+
+/// Outcome of installing suggested mods
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SuggestedModsInstallResult {
+    /// Names of the mods that were installed
+    pub installed: Vec<String>,
+    /// "Name: error" for each mod that failed
+    pub errors: Vec<String>,
+}
+
+/// Get the essential mod suggestions for an instance's loader.
+///
+/// These are only suggestions; nothing is installed until
+/// `install_suggested_mods` is called. Vanilla instances get none.
+#[tauri::command]
+pub async fn get_suggested_mods(
+    state: State<'_, AppState>,
+    instance_id: String,
+) -> Result<Vec<SuggestedModInfo>, String> {
+    let (loader, game_dir, suggestions) = instance_suggestions(&state, &instance_id)?;
+    let Some(loader) = loader else {
+        return Ok(Vec::new());
+    };
+    
+    let installed = installed_modrinth_ids(&game_dir);
+    
+    Ok(suggestions_for_loader(&suggestions, &loader)
+        .into_iter()
+        .map(|suggestion| SuggestedModInfo {
+            installed: installed.contains(&suggestion.project_id),
+            suggestion: suggestion.clone(),
+        })
+        .collect())
+}
+
+/// Install suggested mods through the regular Modrinth download path.
+///
+/// `project_ids` limits the install to those suggestions; when omitted every
+/// suggestion for the loader is installed. Mods already present are skipped.
+#[tauri::command]
+pub async fn install_suggested_mods(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    instance_id: String,
+    project_ids: Option<Vec<String>>,
+) -> Result<SuggestedModsInstallResult, String> {
+    let (loader, game_dir, suggestions) = instance_suggestions(&state, &instance_id)?;
+    let loader = loader.ok_or_else(|| "Vanilla instances have no suggested mods".to_string())?;
+    
+    let installed = installed_modrinth_ids(&game_dir);
+    let mut result = SuggestedModsInstallResult::default();
+    
+    for suggestion in suggestions_for_loader(&suggestions, &loader) {
+        let selected = project_ids.as_ref()
+            .is_none_or(|ids| ids.contains(&suggestion.project_id));
+        if !selected || installed.contains(&suggestion.project_id) {
+            continue;
+        }
+        
+        match download_mod(
+            app.clone(),
+            state.clone(),
+            instance_id.clone(),
+            suggestion.project_id.clone(),
+            Some("modrinth".to_string()),
+        ).await {
+            Ok(()) => result.installed.push(suggestion.name.clone()),
+            Err(e) => result.errors.push(format!("{}: {}", suggestion.name, e)),
+        }
+    }
+    
+    Ok(result)
+}
+
+/// The instance's loader (as a Modrinth loader name), game directory and suggestion list
+fn instance_suggestions(
+    state: &State<'_, AppState>,
+    instance_id: &str,
+) -> Result<(Option<String>, std::path::PathBuf, Vec<SuggestedMod>), String> {
+    let (loader, game_dir) = {
+        let instances = state.instances.lock().unwrap();
+        let instance = instances.iter()
+            .find(|i| i.id == instance_id)
+            .ok_or_else(|| "Instance not found".to_string())?;
+        
+        let loader = instance.mod_loader.as_ref()
+            .map(|ml| format!("{:?}", ml.loader_type).to_lowercase());
+        (loader, instance.game_dir())
+    };
+    
+    let suggestions = state.config.lock().unwrap().suggested_mods();
+    Ok((loader, game_dir, suggestions))
+}
+
+/// Modrinth project IDs recorded in the instance's mod metadata
+fn installed_modrinth_ids(game_dir: &Path) -> HashSet<String> {
+    find_mod_tomls(game_dir)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|path| read_mod_toml(path).ok())
+        .filter_map(|toml| toml.packwiz.update?.modrinth)
+        .map(|update| update.mod_id)
+        .collect()
+}
@@ -57,6 +57,8 @@ pub struct InstalledMod {
     pub homepage: Option<String>,
     pub issues_url: Option<String>,
     pub source_url: Option<String>,
+    /// Held at its current version by `set_mod_pinned`
+    pub pinned: bool,
 }
 
 /// Enhanced mod search result with more details
@@ -125,6 +127,16 @@ pub struct ModVersionResponse {
     pub dependencies: Vec<ModDependencyResponse>,
 }
 
+/// One page of a project's full version list
+#[derive(Debug, Clone, Serialize)]
+pub struct ModVersionPage {
+    pub versions: Vec<ModVersionResponse>,
+    /// Versions the project has in total
+    pub total: u32,
+    pub offset: u32,
+    pub limit: u32,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct ModFileResponse {
     pub filename: String,
@@ -139,6 +151,14 @@ pub struct ModDependencyResponse {
     pub dependency_type: String,
 }
 
+/// A browsable category on a mod platform
+#[derive(Debug, Clone, Serialize)]
+pub struct PlatformCategory {
+    pub name: String,
+    pub icon: String,
+    pub project_type: String,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct ModSearchResponse {
     pub mods: Vec<ModSearchResultDetailed>,
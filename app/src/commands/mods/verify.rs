@@ -0,0 +1,220 @@
+//! Verifying installed mods against their stored hashes.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::commands::state::AppState;
+use crate::core::download::download_binary_file;
+use crate::core::modplatform::modrinth::ModrinthClient;
+use crate::core::modplatform::types::ProjectVersion;
+use crate::core::rustwiz::{self, check_mod_files, HashCheck, HashFormat, ModFileCheck};
+use super::download::create_mod_metadata;
+use serde::Serialize;
+use tauri::State;
+
+/// What verification found for one mod
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModVerifyStatus {
+    /// Matches its stored hash
+    Intact,
+    /// Differs from its stored hash and wasn't replaced
+    Corrupt,
+    /// Differed from its stored hash and was downloaded again
+    Redownloaded,
+    /// Had no stored hash; identified on Modrinth and metadata was written
+    Identified,
+    /// Had no stored hash and couldn't be identified
+    Unverified,
+    /// Couldn't be read to hash it
+    Unreadable,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ModVerifyResult {
+    /// File name on disk, including any `.disabled` suffix
+    pub filename: String,
+    pub status: ModVerifyStatus,
+    pub detail: Option<String>,
+}
+
+/// Download a mod again from its recorded source, replacing the local file
+/// only when the new download matches the stored hash
+async fn redownload_mod(mods_dir: &Path, check: &ModFileCheck) -> Result<(), String> {
+    let Some((_, toml)) = &check.metadata else {
+        return Err("No recorded source".to_string());
+    };
+    let download = &toml.packwiz.download;
+    if download.url.is_empty() {
+        return Err("No recorded download URL".to_string());
+    }
+    
+    let partial = mods_dir.join(format!("{}.part", check.base));
+    download_binary_file(&download.url, &partial, None)
+        .await
+        .map_err(|e| e.to_string())?;
+    
+    let hash = rustwiz::compute_file_hash(&partial, download.hash_format).map_err(|e| e.to_string())?;
+    if !hash.eq_ignore_ascii_case(&download.hash) {
+        let _ = std::fs::remove_file(&partial);
+        return Err("The download doesn't match the stored hash either".to_string());
+    }
+    
+    std::fs::rename(&partial, mods_dir.join(&check.file)).map_err(|e| e.to_string())
+}
+
+/// Write `.pw.toml` metadata for a mod found on Modrinth by its SHA1
+fn identify_mod(mods_dir: &Path, check: &ModFileCheck, sha1: &str, version: &ProjectVersion) -> Result<String, String> {
+    let path = mods_dir.join(&check.file);
+    let file = version.files.iter()
+        .find(|f| f.sha1.as_deref().is_some_and(|h| h.eq_ignore_ascii_case(sha1)))
+        .or_else(|| version.files.iter().find(|f| f.primary))
+        .or(version.files.first());
+    let url = file.map(|f| f.url.clone()).unwrap_or_default();
+    let hash = rustwiz::compute_file_hash(&path, HashFormat::Sha512).map_err(|e| e.to_string())?;
+    
+    // Metadata without a hash gets replaced rather than left beside the new file
+    if let Some((old_path, _)) = &check.metadata {
+        let _ = rustwiz::delete_mod_toml(old_path);
+    }
+    
+    create_mod_metadata(
+        mods_dir,
+        &check.base,
+        &version.name,
+        &url,
+        &hash,
+        HashFormat::Sha512,
+        "modrinth",
+        &version.project_id,
+        &version.id,
+        None,
+        None,
+        Some(version.game_versions.clone()),
+        Some(version.loaders.clone()),
+    );
+    
+    Ok(version.name.clone())
+}
+
+/// Recompute the hash of every mod with stored metadata and compare it with
+/// the recorded one. With `redownload`, mismatched mods are fetched again from
+/// their recorded URL. Mods without a stored hash are looked up on Modrinth and
+/// get metadata written when found.
+#[tauri::command]
+pub async fn verify_mods(
+    state: State<'_, AppState>,
+    instance_id: String,
+    redownload: Option<bool>,
+) -> Result<Vec<ModVerifyResult>, String> {
+    let mods_dir = state.instances.lock().unwrap()
+        .iter()
+        .find(|i| i.id == instance_id)
+        .map(|i| i.mods_dir())
+        .ok_or_else(|| "Instance not found".to_string())?;
+    let redownload = redownload.unwrap_or(false);
+    
+    if redownload && state.running_processes.lock().unwrap().contains_key(&instance_id) {
+        return Err("Cannot replace mods while the instance is running".to_string());
+    }
+    
+    let checks = {
+        let mods_dir = mods_dir.clone();
+        tokio::task::spawn_blocking(move || check_mod_files(&mods_dir))
+            .await
+            .map_err(|e| e.to_string())?
+            .map_err(|e| format!("Failed to check mods: {}", e))?
+    };
+    
+    // Mods without a stored hash are looked up on Modrinth in one request
+    let unidentified: Vec<(usize, Result<String, String>)> = checks.iter()
+        .enumerate()
+        .filter(|(_, check)| check.check == HashCheck::NoHash)
+        .map(|(i, check)| {
+            let sha1 = rustwiz::compute_file_hash(&mods_dir.join(&check.file), HashFormat::Sha1)
+                .map_err(|e| format!("Couldn't read the file: {}", e));
+            (i, sha1)
+        })
+        .collect();
+    let hashes: Vec<String> = unidentified.iter()
+        .filter_map(|(_, sha1)| sha1.as_ref().ok().map(|sha1| sha1.to_ascii_lowercase()))
+        .collect();
+    let found = ModrinthClient::new().get_versions_from_hashes(&hashes)
+        .await
+        .map_err(|e| format!("Couldn't identify on Modrinth: {}", e));
+    let mut sha1s: HashMap<usize, Result<String, String>> = unidentified.into_iter().collect();
+    
+    let mut results = Vec::with_capacity(checks.len());
+    let mut changed = false;
+    
+    for (i, check) in checks.iter().enumerate() {
+        let (status, detail) = match &check.check {
+            HashCheck::Intact => (ModVerifyStatus::Intact, None),
+            HashCheck::Mismatch { expected, actual } if !redownload => {
+                tracing::warn!("Mod {} doesn't match its stored hash (expected {}, got {})", check.file, expected, actual);
+                (ModVerifyStatus::Corrupt, None)
+            }
+            HashCheck::Mismatch { .. } => match redownload_mod(&mods_dir, check).await {
+                Ok(()) => {
+                    changed = true;
+                    (ModVerifyStatus::Redownloaded, None)
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to re-download {}: {}", check.file, e);
+                    (ModVerifyStatus::Corrupt, Some(e))
+                }
+            },
+            HashCheck::Unreadable(e) => (ModVerifyStatus::Unreadable, Some(e.clone())),
+            HashCheck::NoHash => {
+                let identified = sha1s.remove(&i)
+                    .unwrap_or_else(|| Err("Couldn't read the file".to_string()))
+                    .and_then(|sha1| {
+                        let versions = found.as_ref().map_err(Clone::clone)?;
+                        let version = versions.get(&sha1.to_ascii_lowercase())
+                            .ok_or_else(|| "Not found on Modrinth".to_string())?;
+                        identify_mod(&mods_dir, check, &sha1, version)
+                    });
+                match identified {
+                    Ok(name) => {
+                        changed = true;
+                        (ModVerifyStatus::Identified, Some(name))
+                    }
+                    Err(e) => (ModVerifyStatus::Unverified, Some(e)),
+                }
+            }
+        };
+        
+        results.push(ModVerifyResult {
+            filename: check.file.clone(),
+            status,
+            detail,
+        });
+    }
+    
+    if changed {
+        state.invalidate_instance_summary(&instance_id);
+    }
+    
+    let corrupt = results.iter().filter(|r| matches!(r.status, ModVerifyStatus::Corrupt)).count();
+    tracing::info!("Verified {} mods in instance {}: {} corrupt", results.len(), instance_id, corrupt);
+    
+    Ok(results)
+}
@@ -0,0 +1,80 @@
+//! Data profile Tauri commands.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::instances::refresh_instance_status;
+use super::state::AppState;
+use crate::core::profiles;
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, State};
+
+/// Event emitted with the new `ProfileList` after the active profile changes;
+/// windows reload their instances, accounts and settings
+pub const PROFILE_SWITCHED_EVENT: &str = "profile-switched";
+
+/// Available data profiles and the active one
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileList {
+    pub active: String,
+    pub profiles: Vec<String>,
+}
+
+fn profile_list() -> ProfileList {
+    ProfileList {
+        active: profiles::active_profile_name(),
+        profiles: profiles::list_profiles(),
+    }
+}
+
+/// List the data profiles. "default" is the launcher's original data.
+#[tauri::command]
+pub async fn list_profiles() -> Result<ProfileList, String> {
+    Ok(profile_list())
+}
+
+/// Switch to the profile `name`, creating it if it doesn't exist, and reload
+/// config, instances and accounts from its directory
+#[tauri::command]
+pub async fn switch_profile(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    name: String,
+) -> Result<ProfileList, String> {
+    let name = name.trim().to_string();
+    if name == profiles::active_profile_name() {
+        return Ok(profile_list());
+    }
+    
+    // Drop entries for games that have already exited before checking
+    let tracked: Vec<String> = state.running_processes.lock().unwrap().keys().cloned().collect();
+    if tracked.iter().any(|id| refresh_instance_status(&state, id).running) {
+        return Err("Cannot switch profiles while a game is running".to_string());
+    }
+    if state.has_active_tasks() {
+        return Err("Cannot switch profiles while an instance is being set up or imported".to_string());
+    }
+    
+    profiles::set_active_profile(&name).map_err(|e| e.to_string())?;
+    state.reload_profile();
+    tracing::info!("Switched to profile {}", name);
+    
+    let list = profile_list();
+    let _ = app.emit(PROFILE_SWITCHED_EVENT, &list);
+    Ok(list)
+}
@@ -48,7 +48,10 @@ pub async fn download_resource_pack_version(
     std::fs::create_dir_all(&resourcepacks_dir)
         .map_err(|e| format!("Failed to create directory: {}", e))?;
 
-    download_resource(&resourcepacks_dir, resource_id, version_id, platform).await
+    download_resource(&resourcepacks_dir, resource_id, version_id, platform).await?;
+    state.invalidate_instance_summary(&instance_id);
+
+    Ok(())
 }
 
 /// Download a shader pack version
@@ -73,7 +76,10 @@ pub async fn download_shader_pack_version(
     std::fs::create_dir_all(&shaderpacks_dir)
         .map_err(|e| format!("Failed to create directory: {}", e))?;
 
-    download_resource(&shaderpacks_dir, resource_id, version_id, platform).await
+    download_resource(&shaderpacks_dir, resource_id, version_id, platform).await?;
+    state.invalidate_instance_summary(&instance_id);
+
+    Ok(())
 }
 
 /// Internal function to download a resource
@@ -182,6 +188,8 @@ pub async fn add_local_resource_pack(
 
     std::fs::copy(source, dest).map_err(|e| format!("Failed to copy file: {}", e))?;
 
+    state.invalidate_instance_summary(&instance_id);
+
     Ok(())
 }
 
@@ -215,6 +223,8 @@ pub async fn add_local_shader_pack(
 
     std::fs::copy(source, dest).map_err(|e| format!("Failed to copy file: {}", e))?;
 
+    state.invalidate_instance_summary(&instance_id);
+
     Ok(())
 }
 
@@ -249,6 +259,8 @@ pub async fn add_local_resource_pack_from_bytes(
 
     std::fs::write(dest, bytes).map_err(|e| format!("Failed to write resource pack file: {}", e))?;
 
+    state.invalidate_instance_summary(&instance_id);
+
     Ok(())
 }
 
@@ -283,6 +295,8 @@ pub async fn add_local_shader_pack_from_bytes(
 
     std::fs::write(dest, bytes).map_err(|e| format!("Failed to write shader pack file: {}", e))?;
 
+    state.invalidate_instance_summary(&instance_id);
+
     Ok(())
 }
 
@@ -307,14 +321,17 @@ pub async fn download_resource_packs_batch(
     std::fs::create_dir_all(&resourcepacks_dir)
         .map_err(|e| format!("Failed to create directory: {}", e))?;
 
-    download_resources_batch_internal(
+    let result = download_resources_batch_internal(
         app,
-        state,
+        state.clone(),
         resourcepacks_dir,
         resources,
         "resource-download-progress",
     )
-    .await
+    .await;
+    state.invalidate_instance_summary(&instance_id);
+
+    result
 }
 
 /// Batch download multiple shader packs in parallel
@@ -338,14 +355,17 @@ pub async fn download_shader_packs_batch(
     std::fs::create_dir_all(&shaderpacks_dir)
         .map_err(|e| format!("Failed to create directory: {}", e))?;
 
-    download_resources_batch_internal(
+    let result = download_resources_batch_internal(
         app,
-        state,
+        state.clone(),
         shaderpacks_dir,
         resources,
         "resource-download-progress",
     )
-    .await
+    .await;
+    state.invalidate_instance_summary(&instance_id);
+
+    result
 }
 
 /// Internal function to batch download resources in parallel
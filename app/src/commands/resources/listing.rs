@@ -18,13 +18,17 @@
 //! You should have received a copy of the GNU General Public License
 //! along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use super::types::{ResourcePackInfo, ShaderPackInfo};
+use super::types::{ActiveShaderPack, ResourcePackInfo, ShaderPackInfo};
 use crate::commands::state::AppState;
 use crate::commands::utils::format_file_size;
+use crate::core::minecraft::shaders::{self, active_shader_pack, detect_shader_loader};
 use std::io::Read;
 use std::path::Path;
 use tauri::State;
 
+/// Reported when an instance has no mod that loads shader packs
+const NO_SHADER_LOADER: &str = "No shader loader installed. Install Iris, Oculus or OptiFine to use shader packs.";
+
 /// Extract pack.png and pack.mcmeta from a resource pack
 fn extract_pack_metadata(pack_path: &Path, cache_dir: &Path) -> (Option<String>, Option<String>) {
     let mut icon_path = None;
@@ -215,6 +219,8 @@ pub async fn delete_resource_pack(
         std::fs::remove_file(&pack_path).map_err(|e| e.to_string())?;
     }
     
+    state.invalidate_instance_summary(&instance_id);
+    
     Ok(())
 }
 
@@ -237,6 +243,8 @@ pub async fn list_shader_packs(
         return Ok(Vec::new());
     }
     
+    let active = detect_shader_loader(&instance.mods_dir())
+        .and_then(|loader| active_shader_pack(&instance.game_dir(), loader));
     let mut packs = Vec::new();
     
     if let Ok(entries) = std::fs::read_dir(&shaderpacks_dir) {
@@ -264,6 +272,7 @@ pub async fn list_shader_packs(
                     filename: filename.clone(),
                     name: filename.trim_end_matches(".zip").to_string(),
                     size: format_file_size(size),
+                    active: active.as_deref() == Some(filename.as_str()),
                 });
             }
         }
@@ -273,6 +282,76 @@ pub async fn list_shader_packs(
     Ok(packs)
 }
 
+/// Get the instance's shader loader (Iris, Oculus or OptiFine) and the
+/// shader pack its settings have active
+#[tauri::command]
+pub async fn get_active_shader_pack(
+    state: State<'_, AppState>,
+    instance_id: String,
+) -> Result<ActiveShaderPack, String> {
+    let instance = {
+        let instances = state.instances.lock().unwrap();
+        instances.iter()
+            .find(|i| i.id == instance_id)
+            .ok_or_else(|| "Instance not found".to_string())?
+            .clone()
+    };
+    
+    let loader = detect_shader_loader(&instance.mods_dir());
+    Ok(ActiveShaderPack {
+        loader,
+        pack: loader.and_then(|loader| active_shader_pack(&instance.game_dir(), loader)),
+        message: loader.is_none().then(|| NO_SHADER_LOADER.to_string()),
+    })
+}
+
+/// Make a shader pack the active one, or turn shaders off with None, in the
+/// settings of the instance's shader loader. Shaders are picked by those
+/// settings rather than by which files are present. Nothing changes when
+/// neither Iris, Oculus nor OptiFine is installed; `message` says so.
+#[tauri::command]
+pub async fn set_active_shader_pack(
+    state: State<'_, AppState>,
+    instance_id: String,
+    filename: Option<String>,
+) -> Result<ActiveShaderPack, String> {
+    let instance = {
+        let instances = state.instances.lock().unwrap();
+        instances.iter()
+            .find(|i| i.id == instance_id)
+            .ok_or_else(|| "Instance not found".to_string())?
+            .clone()
+    };
+    
+    let Some(loader) = detect_shader_loader(&instance.mods_dir()) else {
+        return Ok(ActiveShaderPack {
+            loader: None,
+            pack: None,
+            message: Some(NO_SHADER_LOADER.to_string()),
+        });
+    };
+    
+    let game_dir = instance.game_dir();
+    if let Some(filename) = &filename {
+        if !game_dir.join("shaderpacks").join(filename).exists() {
+            return Err(format!("Shader pack '{}' not found", filename));
+        }
+    }
+    
+    shaders::set_active_shader_pack(&game_dir, loader, filename.as_deref())
+        .map_err(|e| format!("Failed to update {} settings: {}", loader.name(), e))?;
+    tracing::info!(
+        "Set active shader pack for instance {} to {:?} ({})",
+        instance_id, filename, loader.name()
+    );
+    
+    Ok(ActiveShaderPack {
+        loader: Some(loader),
+        pack: filename,
+        message: None,
+    })
+}
+
 /// Delete a shader pack
 #[tauri::command]
 pub async fn delete_shader_pack(
@@ -300,6 +379,8 @@ pub async fn delete_shader_pack(
         std::fs::remove_file(&pack_path).map_err(|e| e.to_string())?;
     }
     
+    state.invalidate_instance_summary(&instance_id);
+    
     Ok(())
 }
 
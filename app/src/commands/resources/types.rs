@@ -18,6 +18,7 @@
 //! You should have received a copy of the GNU General Public License
 //! along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::core::minecraft::shaders::ShaderLoader;
 use serde::{Deserialize, Serialize};
 
 /// Resource pack information
@@ -38,6 +39,19 @@ pub struct ShaderPackInfo {
     pub filename: String,
     pub name: String,
     pub size: String,
+    /// Whether the shader loader's settings name this pack as the active one
+    pub active: bool,
+}
+
+/// The instance's shader loader and the pack it has active
+#[derive(Debug, Clone, Serialize)]
+pub struct ActiveShaderPack {
+    /// Iris, Oculus or OptiFine; None when none is installed
+    pub loader: Option<ShaderLoader>,
+    /// File or folder name in `shaderpacks`; None when shaders are off
+    pub pack: Option<String>,
+    /// Why nothing was changed, when no shader loader is installed
+    pub message: Option<String>,
 }
 
 /// Search result for resource browsing
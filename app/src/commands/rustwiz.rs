@@ -251,7 +251,8 @@ pub async fn check_mod_updates(
         let loader = instance.mod_loader.as_ref()
             .map(|ml| format!("{:?}", ml.loader_type).to_lowercase());
         
-        (instance.path.clone(), instance.minecraft_version.clone(), loader)
+        // Mod metadata lives in the game directory's mods/.index, next to the jars
+        (instance.game_dir(), instance.minecraft_version.clone(), loader)
     }; // Lock is released here when scope ends
     
     rustwiz::check_instance_updates_with_info(
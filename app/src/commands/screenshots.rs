@@ -20,6 +20,7 @@
 
 use super::state::AppState;
 use super::utils::format_file_size;
+use crate::core::minecraft::captures::{self, PruneResult};
 use serde::{Deserialize, Serialize};
 use tauri::State;
 
@@ -109,6 +110,34 @@ pub async fn delete_screenshot(
     std::fs::remove_file(&screenshot_path).map_err(|e| e.to_string())
 }
 
+/// Delete an instance's screenshots taken before `date` (`YYYY-MM-DD` or an
+/// RFC 3339 timestamp). Returns how many were removed and the space freed.
+#[tauri::command]
+pub async fn delete_screenshots_before(
+    state: State<'_, AppState>,
+    instance_id: String,
+    date: String,
+) -> Result<PruneResult, String> {
+    let cutoff = captures::parse_cutoff(&date)
+        .ok_or_else(|| format!("Invalid date '{}'; expected YYYY-MM-DD", date))?;
+    let use_recycle_bin = state.config.lock().unwrap().files.use_recycle_bin;
+    
+    let instance = {
+        let instances = state.instances.lock().unwrap();
+        instances.iter()
+            .find(|i| i.id == instance_id)
+            .ok_or_else(|| "Instance not found".to_string())?
+            .clone()
+    };
+    
+    let screenshots_dir = instance.game_dir().join("screenshots");
+    let result = captures::prune_before(&screenshots_dir, "png", cutoff, use_recycle_bin)
+        .map_err(|e| e.to_string())?;
+    state.invalidate_instance_summary(&instance_id);
+    
+    Ok(result)
+}
+
 /// Open screenshots folder in file explorer
 #[tauri::command]
 pub async fn open_screenshots_folder(
@@ -0,0 +1,109 @@
+//! Server list Tauri commands.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::state::AppState;
+use crate::core::minecraft::blocked_servers::{BlockedServers, BLOCKED_SERVERS_CACHE};
+use serde::Serialize;
+use tauri::State;
+
+/// Summary of the cached blocked-servers list
+#[derive(Debug, Clone, Serialize)]
+pub struct BlockedServerList {
+    /// SHA-1 hashes of blocked addresses and wildcard patterns
+    pub hashes: Vec<String>,
+    pub fetched_at: String,
+}
+
+/// Whether a server address is on the blocked-servers list
+#[derive(Debug, Clone, Serialize)]
+pub struct BlockedServerCheck {
+    pub address: String,
+    pub blocked: bool,
+    /// The entry that blocks it, e.g. `*.example.com`
+    pub matched: Option<String>,
+}
+
+/// Load the blocked-servers list, fetching it when the cache is missing,
+/// stale or `refresh` is set. A stale cache is used if fetching fails.
+async fn load_blocked_servers(state: &AppState, refresh: bool) -> Result<BlockedServers, String> {
+    let (network, cache_path) = {
+        let config = state.config.lock().unwrap();
+        (config.network.clone(), config.cache_dir().join(BLOCKED_SERVERS_CACHE))
+    };
+    
+    if !network.check_blocked_servers {
+        return Err("Checking blocked servers is turned off in the network settings".to_string());
+    }
+    
+    let cached = BlockedServers::load(&cache_path);
+    if let Some(ref list) = cached {
+        if !refresh && !list.is_stale() {
+            return Ok(list.clone());
+        }
+    }
+    
+    match BlockedServers::fetch(&network).await {
+        Ok(list) => {
+            if let Err(e) = list.save(&cache_path) {
+                tracing::warn!("Failed to cache blocked servers list: {}", e);
+            }
+            Ok(list)
+        }
+        Err(e) => {
+            tracing::warn!("Failed to fetch blocked servers list: {}", e);
+            cached.ok_or_else(|| format!("Failed to fetch blocked servers list: {}", e))
+        }
+    }
+}
+
+/// Get Mojang's blocked-servers hash list. Needs `check_blocked_servers`
+/// turned on; the list is cached for a day unless `refresh` is set.
+#[tauri::command]
+pub async fn get_blocked_servers(
+    state: State<'_, AppState>,
+    refresh: Option<bool>,
+) -> Result<BlockedServerList, String> {
+    let list = load_blocked_servers(&state, refresh.unwrap_or(false)).await?;
+    
+    let mut hashes: Vec<String> = list.hashes.into_iter().collect();
+    hashes.sort();
+    
+    Ok(BlockedServerList {
+        hashes,
+        fetched_at: list.fetched_at.to_rfc3339(),
+    })
+}
+
+/// Check a server address against the blocked-servers list, including the
+/// wildcard entries covering its parent domains or IP range
+#[tauri::command]
+pub async fn check_server_blocked(
+    state: State<'_, AppState>,
+    address: String,
+) -> Result<BlockedServerCheck, String> {
+    let list = load_blocked_servers(&state, false).await?;
+    let matched = list.blocked_by(&address);
+    
+    Ok(BlockedServerCheck {
+        address,
+        blocked: matched.is_some(),
+        matched,
+    })
+}
@@ -0,0 +1,72 @@
+//! Startup warm-up Tauri commands.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::instances::cached_summary;
+use super::state::AppState;
+use super::versions::warm_version_caches;
+use crate::core::java::cached_java_installations;
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager};
+
+/// What the startup warm-up managed to load, sent with `startup-ready`
+#[derive(Debug, Clone, Serialize)]
+pub struct WarmUpReport {
+    /// Whether the version lists could be fetched; false when offline
+    pub versions_fetched: bool,
+    pub java_installations: usize,
+    pub instance_summaries: usize,
+}
+
+/// Fill the version list, Java detection and instance summary caches so the
+/// first search or create doesn't wait on them. Failures are logged and
+/// skipped, so it finishes offline too.
+pub async fn warm_up(app: AppHandle) -> WarmUpReport {
+    let versions = warm_version_caches();
+    
+    let java = tokio::task::spawn_blocking(|| cached_java_installations().len());
+    
+    let summaries_app = app.clone();
+    let summaries = tokio::task::spawn_blocking(move || {
+        let state = summaries_app.state::<AppState>();
+        let instances = state.instances.lock().unwrap().clone();
+        for instance in &instances {
+            cached_summary(&state, instance);
+        }
+        instances.len()
+    });
+    
+    let (versions_fetched, java, summaries) = tokio::join!(versions, java, summaries);
+    
+    let report = WarmUpReport {
+        versions_fetched,
+        java_installations: java.unwrap_or(0),
+        instance_summaries: summaries.unwrap_or(0),
+    };
+    tracing::info!("Startup warm-up finished: {:?}", report);
+    let _ = app.emit("startup-ready", &report);
+    
+    report
+}
+
+/// Run the startup warm-up now, emitting `startup-ready` when it's done
+#[tauri::command]
+pub async fn warm_up_caches(app: AppHandle) -> Result<WarmUpReport, String> {
+    Ok(warm_up(app).await)
+}
@@ -18,15 +18,18 @@
 //! You should have received a copy of the GNU General Public License
 //! along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::commands::accounts::DeviceCodeState;
+use crate::commands::instances::{CachedInstanceSummary, InstanceLogStream};
 use crate::core::{
     accounts::{Account, AccountList},
     config::Config,
     instance::{Instance, InstanceList},
-    logging::LogEntry,
+    launch::{ExitStatus, LaunchTiming},
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 use std::process::Child;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
@@ -34,12 +37,14 @@ use std::time::Instant;
 pub struct RunningProcess {
     /// The child process - wrapped in Arc<Mutex<>> since we got it from the launch task
     pub child: Arc<Mutex<Child>>,
-    /// Structured log entries from the game process
-    pub logs: Arc<Mutex<Vec<LogEntry>>>,
+    /// Structured log entries from the game process, newest lines only
+    pub logs: Arc<InstanceLogStream>,
     /// Time when the game was launched
     pub launch_time: Instant,
     /// Exit code when process exits (None if still running or not checked yet)
     pub exit_code: Arc<Mutex<Option<i32>>>,
+    /// Set to make the log reader and exit monitor threads stop early
+    pub stop_readers: Arc<AtomicBool>,
 }
 
 /// Application state shared across all commands
@@ -47,60 +52,143 @@ pub struct AppState {
     pub instances: Mutex<Vec<Instance>>,
     pub accounts: Mutex<Vec<Account>>,
     pub config: Mutex<Config>,
-    pub data_dir: PathBuf,
+    /// The active profile's data directory
+    pub data_dir: Mutex<PathBuf>,
     pub running_processes: Mutex<HashMap<String, Arc<Mutex<RunningProcess>>>>,
+    /// Cached overview summaries keyed by instance ID
+    pub instance_summaries: Mutex<HashMap<String, CachedInstanceSummary>>,
+    /// Microsoft device code logins in progress, keyed by device code
+    pub pending_logins: Mutex<HashMap<String, DeviceCodeState>>,
+    /// Instances whose log lines are streamed to the frontend
+    pub log_subscriptions: Mutex<HashSet<String>>,
+    /// Step timing of each instance's most recent launch
+    pub launch_timings: Mutex<HashMap<String, LaunchTiming>>,
+    /// How each instance's most recent game session ended
+    pub last_exits: Mutex<HashMap<String, ExitStatus>>,
+    /// Downloads folder watchers for blocked mods, keyed by session ID.
+    /// Dropping a watcher stops it.
+    pub blocked_mods_watchers: Mutex<HashMap<String, notify::RecommendedWatcher>>,
+    /// Cancellation flags of running imports, keyed by the frontend's import ID
+    pub import_cancellations: Mutex<HashMap<String, Arc<AtomicBool>>>,
+    /// Instance setups and imports in progress, counted by `BackgroundTask` guards
+    pub active_tasks: Arc<AtomicUsize>,
+}
+
+/// Marks an instance setup or import as in progress until dropped, so the
+/// profile isn't switched out from under it
+pub struct BackgroundTask(Arc<AtomicUsize>);
+
+impl Drop for BackgroundTask {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
 }
 
 impl AppState {
     pub fn new() -> Self {
-        // Load configuration from disk (or create default)
-        let config = Config::load().unwrap_or_else(|e| {
-            tracing::warn!("Failed to load config, using defaults: {}", e);
-            Config::default()
-        });
-        
-        // Get data directory from config
-        let data_dir = config.data_dir();
-        
-        // Ensure data directory exists
-        if let Err(e) = std::fs::create_dir_all(&data_dir) {
-            tracing::error!("Failed to create data directory: {}", e);
-        }
-        
-        // Load instances from disk
-        let instances_dir = config.instances_dir();
-        let instances = match InstanceList::load(&instances_dir) {
-            Ok(list) => {
-                tracing::info!("Loaded {} instances from {:?}", list.instances.len(), instances_dir);
-                list.instances
-            }
-            Err(e) => {
-                tracing::warn!("Failed to load instances: {}", e);
-                Vec::new()
-            }
-        };
-        
-        // Load accounts from disk
-        let accounts_file = config.accounts_file();
-        let accounts = match AccountList::load(&accounts_file) {
-            Ok(list) => {
-                tracing::info!("Loaded {} accounts from {:?}", list.accounts.len(), accounts_file);
-                list.accounts
-            }
-            Err(e) => {
-                tracing::warn!("Failed to load accounts: {}", e);
-                Vec::new()
-            }
-        };
+        let (config, data_dir, instances, accounts) = load_profile();
         
         Self {
             instances: Mutex::new(instances),
             accounts: Mutex::new(accounts),
             config: Mutex::new(config),
-            data_dir,
+            data_dir: Mutex::new(data_dir),
             running_processes: Mutex::new(HashMap::new()),
+            instance_summaries: Mutex::new(HashMap::new()),
+            pending_logins: Mutex::new(HashMap::new()),
+            log_subscriptions: Mutex::new(HashSet::new()),
+            launch_timings: Mutex::new(HashMap::new()),
+            last_exits: Mutex::new(HashMap::new()),
+            blocked_mods_watchers: Mutex::new(HashMap::new()),
+            import_cancellations: Mutex::new(HashMap::new()),
+            active_tasks: Arc::new(AtomicUsize::new(0)),
         }
     }
+    
+    /// The active profile's data directory
+    pub fn data_dir(&self) -> PathBuf {
+        self.data_dir.lock().unwrap().clone()
+    }
+    
+    /// Reload config, instances and accounts after the active profile changed,
+    /// stopping watchers and dropping everything cached for the old profile.
+    /// Callers make sure no game is running first.
+    pub fn reload_profile(&self) {
+        self.blocked_mods_watchers.lock().unwrap().clear();
+        self.log_subscriptions.lock().unwrap().clear();
+        self.pending_logins.lock().unwrap().clear();
+        self.instance_summaries.lock().unwrap().clear();
+        self.launch_timings.lock().unwrap().clear();
+        self.last_exits.lock().unwrap().clear();
+        
+        let (config, data_dir, instances, accounts) = load_profile();
+        *self.instances.lock().unwrap() = instances;
+        *self.accounts.lock().unwrap() = accounts;
+        *self.config.lock().unwrap() = config;
+        *self.data_dir.lock().unwrap() = data_dir;
+    }
+    
+    /// Count a setup or import as running until the returned guard is dropped
+    pub fn begin_task(&self) -> BackgroundTask {
+        self.active_tasks.fetch_add(1, Ordering::SeqCst);
+        BackgroundTask(self.active_tasks.clone())
+    }
+    
+    /// Whether any instance setup or import is still running
+    pub fn has_active_tasks(&self) -> bool {
+        self.active_tasks.load(Ordering::SeqCst) > 0
+    }
+    
+    /// Drop the cached overview summary for an instance after its content changes
+    pub fn invalidate_instance_summary(&self, instance_id: &str) {
+        self.instance_summaries.lock().unwrap().remove(instance_id);
+    }
+}
+
+/// Load the active profile's config, instances and accounts, creating its
+/// data directory if needed
+fn load_profile() -> (Config, PathBuf, Vec<Instance>, Vec<Account>) {
+    // Load configuration from disk (or create default)
+    let config = Config::load().unwrap_or_else(|e| {
+        tracing::warn!("Failed to load config, using defaults: {}", e);
+        Config::default()
+    });
+    
+    // Get data directory from config
+    let data_dir = config.data_dir();
+    
+    // Ensure data directory exists
+    if let Err(e) = std::fs::create_dir_all(&data_dir) {
+        tracing::error!("Failed to create data directory: {}", e);
+    }
+    
+    // Load instances from disk
+    let instances_dir = config.instances_dir();
+    let instances = match InstanceList::load(&instances_dir) {
+        Ok(list) => {
+            tracing::info!("Loaded {} instances from {:?}", list.instances.len(), instances_dir);
+            list.instances
+        }
+        Err(e) => {
+            tracing::warn!("Failed to load instances: {}", e);
+            Vec::new()
+        }
+    };
+    
+    // Load accounts from disk
+    let accounts_file = config.accounts_file();
+    let accounts = match AccountList::load(&accounts_file) {
+        Ok(list) => {
+            tracing::info!("Loaded {} accounts from {:?}", list.accounts.len(), accounts_file);
+            list.accounts
+        }
+        Err(e) => {
+            tracing::warn!("Failed to load accounts: {}", e);
+            Vec::new()
+        }
+    };
+    
+    (config, data_dir, instances, accounts)
 }
 
 impl Default for AppState {
@@ -21,8 +21,21 @@
 //! You should have received a copy of the GNU General Public License
 //! along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::core::meta::MetaClient;
+use crate::commands::state::AppState;
+use crate::core::meta::{filter_minecraft_versions, uids, MetaClient, VersionEntry};
+use crate::core::minecraft::local_versions::{local_versions, LocalVersion};
+use crate::core::minecraft::version::{fetch_version_manifest, LatestVersions};
+use crate::core::modloaders::{fabric, forge};
+use crate::core::modloaders::version_range::{self, LoaderRecommendation, LoaderRequirement};
+use crate::core::modplatform::mod_parser::parse_mod_jar;
+use futures::StreamExt;
+use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::State;
 
 /// Minecraft version information
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -39,10 +52,367 @@ pub struct LoaderVersionInfo {
     pub recommended: bool,
 }
 
+/// Fabric or Quilt loader versions that run on a Minecraft version
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotLoaderVersions {
+    pub minecraft_version: String,
+    pub snapshot: bool,
+    pub versions: Vec<LoaderVersionInfo>,
+    /// Build to preselect: the newest one for snapshots
+    pub recommended: Option<String>,
+    /// Why `versions` is empty
+    pub reason: Option<String>,
+}
+
+/// One loader version in a changelog range
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoaderChangelogEntry {
+    pub version: String,
+    pub release_time: String,
+    /// Promoted as the loader's recommended build
+    pub recommended: bool,
+    pub prerelease: bool,
+    /// Where the loader publishes this version's changes, if it does
+    pub changelog_url: Option<String>,
+    /// This version's changes, read from the published changelog. None when
+    /// the loader publishes none or it couldn't be fetched; `changelog_url`
+    /// still links it.
+    pub changes: Option<String>,
+}
+
+/// The loader versions between an installed version and an upgrade target
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoaderChangelog {
+    pub loader: String,
+    pub from_version: String,
+    pub to_version: String,
+    /// Whether `to_version` is older than `from_version`
+    pub downgrade: bool,
+    /// Versions after `from_version` up to and including `to_version`, in
+    /// the order they're passed (oldest first for an upgrade)
+    pub versions: Vec<LoaderChangelogEntry>,
+}
+
+/// How long fetched version lists and the latest-version block are reused
+const LOADER_INDEX_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// A fetched loader version list and when it was fetched
+type CachedVersions = (Instant, Vec<VersionEntry>);
+
+/// Version lists keyed by meta package UID
+static LOADER_INDEX_CACHE: Lazy<Mutex<HashMap<&'static str, CachedVersions>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// The `latest` block of Mojang's version manifest and when it was fetched
+static LATEST_VERSIONS_CACHE: Lazy<Mutex<Option<(Instant, LatestVersions)>>> =
+    Lazy::new(|| Mutex::new(None));
+
+/// Fetch every version of a meta package, reusing a recent result when available
+async fn cached_package_versions(client: &MetaClient, uid: &'static str) -> Option<Vec<VersionEntry>> {
+    {
+        let cache = LOADER_INDEX_CACHE.lock().unwrap();
+        if let Some((fetched_at, versions)) = cache.get(uid) {
+            if fetched_at.elapsed() < LOADER_INDEX_TTL {
+                return Some(versions.clone());
+            }
+        }
+    }
+    
+    match client.get_loader_versions(uid).await {
+        Ok(versions) => {
+            let mut cache = LOADER_INDEX_CACHE.lock().unwrap();
+            cache.insert(uid, (Instant::now(), versions.clone()));
+            Some(versions)
+        }
+        Err(e) => {
+            tracing::warn!("Failed to fetch {} versions: {}", uid, e);
+            // Offline: a stale list beats none
+            let cache = LOADER_INDEX_CACHE.lock().unwrap();
+            cache.get(uid).map(|(_, versions)| versions.clone())
+        }
+    }
+}
+
+/// Get the latest release and snapshot IDs from Mojang's manifest.
+/// Falls back to a stale result, then to the newest entries in the meta
+/// version list, when the manifest can't be fetched.
+async fn cached_latest_versions(client: &MetaClient) -> Option<LatestVersions> {
+    {
+        let cache = LATEST_VERSIONS_CACHE.lock().unwrap();
+        if let Some((fetched_at, latest)) = cache.as_ref() {
+            if fetched_at.elapsed() < LOADER_INDEX_TTL {
+                return Some(latest.clone());
+            }
+        }
+    }
+    
+    match fetch_version_manifest().await {
+        Ok(manifest) => {
+            let mut cache = LATEST_VERSIONS_CACHE.lock().unwrap();
+            *cache = Some((Instant::now(), manifest.latest.clone()));
+            return Some(manifest.latest);
+        }
+        Err(e) => tracing::warn!("Failed to fetch version manifest: {}", e),
+    }
+    
+    if let Some((_, latest)) = LATEST_VERSIONS_CACHE.lock().unwrap().as_ref() {
+        return Some(latest.clone());
+    }
+    
+    let versions = cached_package_versions(client, uids::MINECRAFT).await?;
+    let newest = |version_type: &str| {
+        versions.iter()
+            .filter(|v| v.version_type.as_deref().unwrap_or("release") == version_type)
+            .max_by(|a, b| a.release_time.cmp(&b.release_time))
+            .map(|v| v.version.clone())
+    };
+    let release = newest("release")?;
+    
+    Some(LatestVersions {
+        snapshot: newest("snapshot").unwrap_or_else(|| release.clone()),
+        release,
+    })
+}
+
+/// Fill the Minecraft version list and latest-version caches ahead of the
+/// first search. Returns whether both could be loaded.
+pub(crate) async fn warm_version_caches() -> bool {
+    let client = MetaClient::default();
+    let (versions, latest) = tokio::join!(
+        cached_package_versions(&client, uids::MINECRAFT),
+        cached_latest_versions(&client),
+    );
+    versions.is_some() && latest.is_some()
+}
+
+/// Build version info for an ID, using the meta list for its type and release time
+async fn minecraft_version_info(client: &MetaClient, id: String, fallback_type: &str) -> MinecraftVersionInfo {
+    let entry = cached_package_versions(client, uids::MINECRAFT)
+        .await
+        .and_then(|versions| versions.into_iter().find(|v| v.version == id));
+    
+    match entry {
+        Some(entry) => MinecraftVersionInfo {
+            id,
+            version_type: entry.version_type.unwrap_or_else(|| "release".to_string()),
+            release_time: entry.release_time,
+        },
+        None => MinecraftVersionInfo {
+            id,
+            version_type: fallback_type.to_string(),
+            release_time: String::new(),
+        },
+    }
+}
+
+/// Where a loader's stable builds are read from
+enum LoaderStability {
+    /// Fabric's meta marks each loader build `stable`
+    Flagged(HashSet<String>),
+    /// Forge promotes a recommended build once a Minecraft version leaves beta
+    ForgePromotions { has_recommended: bool },
+    /// No published flag (Quilt, NeoForge, LiteLoader, or the lookup failed):
+    /// read the pre-release tag in the version string
+    VersionTag,
+}
+
+impl LoaderStability {
+    /// Look up how a loader marks stable builds for a Minecraft version
+    async fn fetch(loader: &str, minecraft_version: &str) -> Self {
+        match loader {
+            "fabric" => match fabric::get_fabric_versions(minecraft_version).await {
+                Ok(versions) => LoaderStability::Flagged(
+                    versions.into_iter().filter(|v| v.stable).map(|v| v.version).collect()
+                ),
+                Err(e) => {
+                    tracing::warn!("Couldn't fetch Fabric stable flags, using version tags: {}", e);
+                    LoaderStability::VersionTag
+                }
+            },
+            "forge" => match forge::get_forge_promotions(minecraft_version).await {
+                Ok(promotions) => LoaderStability::ForgePromotions {
+                    has_recommended: promotions.recommended.is_some(),
+                },
+                Err(e) => {
+                    tracing::warn!("Couldn't fetch Forge promotions, using version tags: {}", e);
+                    LoaderStability::VersionTag
+                }
+            },
+            _ => LoaderStability::VersionTag,
+        }
+    }
+    
+    fn is_prerelease(&self, version: &VersionEntry) -> bool {
+        match self {
+            LoaderStability::Flagged(stable) => !stable.contains(&version.version),
+            LoaderStability::ForgePromotions { has_recommended } => !has_recommended && !version.recommended,
+            LoaderStability::VersionTag => version.is_prerelease(),
+        }
+    }
+}
+
+/// Drop non-stable loader builds unless pre-releases were asked for.
+/// Keeps the full list when it holds no stable build, so a Minecraft
+/// version that only has betas still offers something to pick.
+fn filter_prerelease(
+    versions: Vec<VersionEntry>,
+    stability: &LoaderStability,
+    include_prerelease: Option<bool>,
+) -> Vec<VersionEntry> {
+    if include_prerelease.unwrap_or(false) || versions.iter().all(|v| stability.is_prerelease(v)) {
+        return versions;
+    }
+    
+    versions.into_iter().filter(|v| !stability.is_prerelease(v)).collect()
+}
+
+/// Whether the meta version list marks a Minecraft version as a snapshot
+async fn is_snapshot(client: &MetaClient, minecraft_version: &str) -> bool {
+    cached_package_versions(client, uids::MINECRAFT)
+        .await
+        .and_then(|versions| versions.into_iter().find(|v| v.version == minecraft_version))
+        .is_some_and(|v| v.version_type.as_deref() == Some("snapshot"))
+}
+
+/// Whether Fabric intermediary mappings exist for a Minecraft version,
+/// or `None` when the intermediary list can't be fetched
+async fn has_intermediary(client: &MetaClient, minecraft_version: &str) -> Option<bool> {
+    let versions = cached_package_versions(client, uids::FABRIC_INTERMEDIARY).await?;
+    Some(versions.iter().any(|v| v.version == minecraft_version))
+}
+
+/// Narrow Fabric/Quilt loader builds for a Minecraft version.
+/// Loader builds are MC-agnostic, so with `include_snapshots` set a snapshot
+/// keeps its pre-release builds too (new snapshots often need the newest beta
+/// loader), and gets nothing when it has no intermediary mappings yet.
+async fn intermediary_loader_versions(
+    client: &MetaClient,
+    versions: Vec<VersionEntry>,
+    loader: &str,
+    minecraft_version: &str,
+    include_prerelease: Option<bool>,
+    include_snapshots: Option<bool>,
+) -> Vec<VersionEntry> {
+    if !include_snapshots.unwrap_or(false) || !is_snapshot(client, minecraft_version).await {
+        let stability = LoaderStability::fetch(loader, minecraft_version).await;
+        return filter_prerelease(versions, &stability, include_prerelease);
+    }
+    
+    if has_intermediary(client, minecraft_version).await == Some(false) {
+        return Vec::new();
+    }
+    versions
+}
+
 // ============================================================================
 // Tauri Commands
 // ============================================================================
 
+/// Get the mod loaders that have at least one version for a Minecraft version.
+/// Returns loader names as used by `CreateInstanceRequest::mod_loader_type`.
+/// Loaders whose version list can't be fetched are left out.
+#[tauri::command]
+pub async fn get_supported_loaders(minecraft_version: String) -> Result<Vec<String>, String> {
+    let client = MetaClient::default();
+    
+    let (forge, neoforge, liteloader, intermediary, fabric, quilt) = tokio::join!(
+        cached_package_versions(&client, uids::FORGE),
+        cached_package_versions(&client, uids::NEOFORGE),
+        cached_package_versions(&client, uids::LITELOADER),
+        cached_package_versions(&client, uids::FABRIC_INTERMEDIARY),
+        cached_package_versions(&client, uids::FABRIC_LOADER),
+        cached_package_versions(&client, uids::QUILT_LOADER),
+    );
+    
+    let has_version_for = |versions: &Option<Vec<VersionEntry>>| {
+        versions.as_ref().is_some_and(|versions| {
+            versions.iter().any(|v| v.is_compatible_with(&minecraft_version))
+        })
+    };
+    
+    // Fabric and Quilt loader versions are MC-agnostic; support depends on intermediary mappings
+    let has_intermediary = intermediary.as_ref().is_some_and(|versions| {
+        versions.iter().any(|v| v.version == minecraft_version)
+    });
+    let has_any = |versions: &Option<Vec<VersionEntry>>| {
+        versions.as_ref().is_some_and(|versions| !versions.is_empty())
+    };
+    
+    let supported = [
+        ("Forge", has_version_for(&forge)),
+        ("NeoForge", has_version_for(&neoforge)),
+        ("Fabric", has_intermediary && has_any(&fabric)),
+        ("Quilt", has_intermediary && has_any(&quilt)),
+        ("LiteLoader", has_version_for(&liteloader)),
+    ];
+    
+    Ok(supported
+        .into_iter()
+        .filter(|(_, available)| *available)
+        .map(|(name, _)| name.to_string())
+        .collect())
+}
+
+/// Get the latest Minecraft release
+#[tauri::command]
+pub async fn get_latest_release() -> Result<MinecraftVersionInfo, String> {
+    let client = MetaClient::default();
+    let latest = cached_latest_versions(&client)
+        .await
+        .ok_or_else(|| "Failed to determine the latest Minecraft release".to_string())?;
+    
+    Ok(minecraft_version_info(&client, latest.release, "release").await)
+}
+
+/// Get the latest Minecraft snapshot (may be the latest release when no snapshot is newer)
+#[tauri::command]
+pub async fn get_latest_snapshot() -> Result<MinecraftVersionInfo, String> {
+    let client = MetaClient::default();
+    let latest = cached_latest_versions(&client)
+        .await
+        .ok_or_else(|| "Failed to determine the latest Minecraft snapshot".to_string())?;
+    
+    Ok(minecraft_version_info(&client, latest.snapshot, "snapshot").await)
+}
+
+/// Get the `count` most recent Minecraft releases, newest first,
+/// for a compact "stable releases only" picker
+#[tauri::command]
+pub async fn get_recent_releases(count: usize) -> Result<Vec<MinecraftVersionInfo>, String> {
+    let client = MetaClient::default();
+    let mut releases: Vec<VersionEntry> = cached_package_versions(&client, uids::MINECRAFT)
+        .await
+        .ok_or_else(|| "Failed to fetch Minecraft versions".to_string())?
+        .into_iter()
+        .filter(|v| v.version_type.as_deref().unwrap_or("release") == "release")
+        .collect();
+    
+    releases.sort_by(|a, b| b.release_time.cmp(&a.release_time));
+    
+    Ok(releases
+        .into_iter()
+        .take(count)
+        .map(|v| MinecraftVersionInfo {
+            id: v.version,
+            version_type: "release".to_string(),
+            release_time: v.release_time,
+        })
+        .collect())
+}
+
+/// List the Minecraft versions with files in the launcher's version store.
+/// `complete` versions have their JSON, client jar and asset index, so new
+/// instances of them don't need to download those again.
+#[tauri::command]
+pub async fn get_downloaded_versions(state: State<'_, AppState>) -> Result<Vec<LocalVersion>, String> {
+    let (meta_dir, assets_dir) = {
+        let config = state.config.lock().unwrap();
+        (config.meta_dir(), config.assets_dir())
+    };
+    
+    Ok(local_versions(&meta_dir, &assets_dir))
+}
+
 #[tauri::command]
 pub async fn get_minecraft_versions(
     show_releases: bool,
@@ -52,10 +422,10 @@ pub async fn get_minecraft_versions(
     show_experimental: bool,
 ) -> Result<Vec<MinecraftVersionInfo>, String> {
     let client = MetaClient::default();
-    let versions = client
-        .get_minecraft_versions_filtered(show_releases, show_snapshots, show_betas, show_alphas, show_experimental)
+    let versions = cached_package_versions(&client, uids::MINECRAFT)
         .await
-        .map_err(|e| format!("Failed to fetch Minecraft versions: {}", e))?;
+        .ok_or_else(|| "Failed to fetch Minecraft versions".to_string())?;
+    let versions = filter_minecraft_versions(versions, show_releases, show_snapshots, show_betas, show_alphas, show_experimental);
 
     Ok(versions
         .into_iter()
@@ -68,12 +438,17 @@ pub async fn get_minecraft_versions(
 }
 
 #[tauri::command]
-pub async fn get_forge_versions(minecraft_version: String) -> Result<Vec<LoaderVersionInfo>, String> {
+pub async fn get_forge_versions(
+    minecraft_version: String,
+    include_prerelease: Option<bool>,
+) -> Result<Vec<LoaderVersionInfo>, String> {
     let client = MetaClient::default();
     let versions = client
         .get_forge_versions(&minecraft_version)
         .await
         .map_err(|e| format!("Failed to fetch Forge versions: {}", e))?;
+    let stability = LoaderStability::fetch("forge", &minecraft_version).await;
+    let versions = filter_prerelease(versions, &stability, include_prerelease);
 
     Ok(versions
         .into_iter()
@@ -85,12 +460,17 @@ pub async fn get_forge_versions(minecraft_version: String) -> Result<Vec<LoaderV
 }
 
 #[tauri::command]
-pub async fn get_fabric_versions(minecraft_version: String) -> Result<Vec<LoaderVersionInfo>, String> {
+pub async fn get_fabric_versions(
+    minecraft_version: String,
+    include_prerelease: Option<bool>,
+    include_snapshots: Option<bool>,
+) -> Result<Vec<LoaderVersionInfo>, String> {
     let client = MetaClient::default();
     let versions = client
         .get_fabric_versions(&minecraft_version)
         .await
         .map_err(|e| format!("Failed to fetch Fabric versions: {}", e))?;
+    let versions = intermediary_loader_versions(&client, versions, "fabric", &minecraft_version, include_prerelease, include_snapshots).await;
 
     Ok(versions
         .into_iter()
@@ -102,12 +482,17 @@ pub async fn get_fabric_versions(minecraft_version: String) -> Result<Vec<Loader
 }
 
 #[tauri::command]
-pub async fn get_quilt_versions(minecraft_version: String) -> Result<Vec<LoaderVersionInfo>, String> {
+pub async fn get_quilt_versions(
+    minecraft_version: String,
+    include_prerelease: Option<bool>,
+    include_snapshots: Option<bool>,
+) -> Result<Vec<LoaderVersionInfo>, String> {
     let client = MetaClient::default();
     let versions = client
         .get_quilt_versions(&minecraft_version)
         .await
         .map_err(|e| format!("Failed to fetch Quilt versions: {}", e))?;
+    let versions = intermediary_loader_versions(&client, versions, "quilt", &minecraft_version, include_prerelease, include_snapshots).await;
 
     // Quilt versions are all beta (recommended=false), so treat first as recommended
     let mut result: Vec<LoaderVersionInfo> = versions
@@ -138,12 +523,17 @@ pub async fn get_quilt_versions(minecraft_version: String) -> Result<Vec<LoaderV
 }
 
 #[tauri::command]
-pub async fn get_neoforge_versions(minecraft_version: String) -> Result<Vec<LoaderVersionInfo>, String> {
+pub async fn get_neoforge_versions(
+    minecraft_version: String,
+    include_prerelease: Option<bool>,
+) -> Result<Vec<LoaderVersionInfo>, String> {
     let client = MetaClient::default();
     let versions = client
         .get_neoforge_versions(&minecraft_version)
         .await
         .map_err(|e| format!("Failed to fetch NeoForge versions: {}", e))?;
+    let stability = LoaderStability::fetch("neoforge", &minecraft_version).await;
+    let versions = filter_prerelease(versions, &stability, include_prerelease);
 
     Ok(versions
         .into_iter()
@@ -155,12 +545,17 @@ pub async fn get_neoforge_versions(minecraft_version: String) -> Result<Vec<Load
 }
 
 #[tauri::command]
-pub async fn get_liteloader_versions(minecraft_version: String) -> Result<Vec<LoaderVersionInfo>, String> {
+pub async fn get_liteloader_versions(
+    minecraft_version: String,
+    include_prerelease: Option<bool>,
+) -> Result<Vec<LoaderVersionInfo>, String> {
     let client = MetaClient::default();
     let versions = client
         .get_liteloader_versions(&minecraft_version)
         .await
         .map_err(|e| format!("Failed to fetch LiteLoader versions: {}", e))?;
+    let stability = LoaderStability::fetch("liteloader", &minecraft_version).await;
+    let versions = filter_prerelease(versions, &stability, include_prerelease);
 
     Ok(versions
         .into_iter()
@@ -172,3 +567,311 @@ pub async fn get_liteloader_versions(minecraft_version: String) -> Result<Vec<Lo
         })
         .collect())
 }
+
+/// Find the Fabric or Quilt loader builds that run on a Minecraft version,
+/// snapshots included. When there are none, `reason` says why, so a snapshot
+/// that can't be modded yet isn't shown as a blank list.
+#[tauri::command]
+pub async fn detect_snapshot_loader(
+    minecraft_version: String,
+    loader: String,
+) -> Result<SnapshotLoaderVersions, String> {
+    let loader = loader.to_lowercase();
+    let label = match loader.as_str() {
+        "fabric" => "Fabric",
+        "quilt" => "Quilt",
+        _ => return Err(format!("Unsupported mod loader: {}", loader)),
+    };
+    
+    let client = MetaClient::default();
+    let snapshot = is_snapshot(&client, &minecraft_version).await;
+    
+    if has_intermediary(&client, &minecraft_version).await == Some(false) {
+        let reason = format!(
+            "Minecraft {} has no intermediary mappings yet, so {} can't run on it until they're published",
+            minecraft_version, label
+        );
+        return Ok(SnapshotLoaderVersions {
+            minecraft_version,
+            snapshot,
+            versions: Vec::new(),
+            recommended: None,
+            reason: Some(reason),
+        });
+    }
+    
+    let versions = if loader == "fabric" {
+        get_fabric_versions(minecraft_version.clone(), None, Some(true)).await?
+    } else {
+        get_quilt_versions(minecraft_version.clone(), None, Some(true)).await?
+    };
+    
+    // A snapshot usually needs the newest build, not the last recommended one
+    let recommended = if snapshot {
+        versions.first()
+    } else {
+        versions.iter().find(|v| v.recommended).or(versions.first())
+    }
+    .map(|v| v.version.clone());
+    let reason = versions.is_empty()
+        .then(|| format!("No {} loader versions are available", label));
+    
+    Ok(SnapshotLoaderVersions {
+        minecraft_version,
+        snapshot,
+        versions,
+        recommended,
+        reason,
+    })
+}
+
+/// Published changelog for a loader version. LiteLoader has none.
+fn loader_changelog_url(loader: &str, minecraft_version: &str, version: &str) -> Option<String> {
+    match loader {
+        "forge" => Some(format!(
+            "https://maven.minecraftforge.net/net/minecraftforge/forge/{0}-{1}/forge-{0}-{1}-changelog.txt",
+            minecraft_version, version
+        )),
+        "neoforge" => Some(format!(
+            "https://maven.neoforged.net/releases/net/neoforged/neoforge/{0}/neoforge-{0}-changelog.txt",
+            version
+        )),
+        "fabric" => Some(format!("https://github.com/FabricMC/fabric-loader/releases/tag/{}", version)),
+        "quilt" => Some(format!("https://github.com/QuiltMC/quilt-loader/releases/tag/{}", version)),
+        _ => None,
+    }
+}
+
+/// Pick one version's changes out of a Forge or NeoForge changelog, which
+/// lists every build as ` - <version> <change>` lines (NeoForge quotes the
+/// version in backticks), continued on indented lines
+fn changelog_entries(changelog: &str, version: &str) -> Option<String> {
+    let quoted = format!("`{}`", version);
+    let mut entries: Vec<String> = Vec::new();
+    let mut in_entry = false;
+    
+    for line in changelog.lines() {
+        let trimmed = line.trim();
+        if let Some(item) = trimmed.strip_prefix("- ").map(str::trim_start) {
+            let change = item.strip_prefix(quoted.as_str()).or_else(|| item.strip_prefix(version));
+            in_entry = match change {
+                // A following character rules out 47.2.2 matching 47.2.20
+                Some(change) if change.is_empty() || change.starts_with(char::is_whitespace) => {
+                    entries.push(change.trim().to_string());
+                    true
+                }
+                _ => false,
+            };
+        } else if in_entry && !trimmed.is_empty() && line.starts_with(char::is_whitespace) {
+            if let Some(entry) = entries.last_mut() {
+                entry.push('\n');
+                entry.push_str(trimmed);
+            }
+        } else {
+            in_entry = false;
+        }
+    }
+    
+    (!entries.is_empty()).then(|| entries.join("\n"))
+}
+
+/// Release notes of a Fabric or Quilt loader version from its GitHub release
+async fn github_release_notes(client: &reqwest::Client, repo: &str, version: &str) -> Option<String> {
+    #[derive(Deserialize)]
+    struct Release {
+        body: Option<String>,
+    }
+    
+    let url = format!("https://api.github.com/repos/{}/releases/tags/{}", repo, version);
+    let response = client.get(&url).send().await.ok()?;
+    if !response.status().is_success() {
+        return None;
+    }
+    response.json::<Release>().await.ok()?.body
+        .map(|body| body.trim().to_string())
+        .filter(|body| !body.is_empty())
+}
+
+/// Fetch the changes of each version in a changelog range, keyed by version.
+/// Forge and NeoForge publish one cumulative changelog per build, so only
+/// the newest build's is fetched; Fabric and Quilt have a release per version.
+async fn fetch_loader_changes(
+    loader: &str,
+    minecraft_version: &str,
+    newest: &str,
+    versions: &[String],
+) -> HashMap<String, String> {
+    let network = crate::core::config::Config::load().unwrap_or_default().network;
+    let client = match crate::core::download::http_client(&network) {
+        Ok(client) => client,
+        Err(e) => {
+            tracing::warn!("Failed to create HTTP client for the {} changelog: {}", loader, e);
+            return HashMap::new();
+        }
+    };
+    
+    match loader {
+        "forge" | "neoforge" => {
+            let Some(url) = loader_changelog_url(loader, minecraft_version, newest) else {
+                return HashMap::new();
+            };
+            let changelog = match client.get(&url).send().await.and_then(|r| r.error_for_status()) {
+                Ok(response) => response.text().await.unwrap_or_default(),
+                Err(e) => {
+                    tracing::warn!("Failed to fetch {} changelog: {}", loader, e);
+                    return HashMap::new();
+                }
+            };
+            versions.iter()
+                .filter_map(|version| Some((version.clone(), changelog_entries(&changelog, version)?)))
+                .collect()
+        }
+        "fabric" | "quilt" => {
+            let repo = if loader == "fabric" { "FabricMC/fabric-loader" } else { "QuiltMC/quilt-loader" };
+            futures::stream::iter(versions.iter().cloned())
+                .map(|version| {
+                    let client = client.clone();
+                    async move {
+                        let notes = github_release_notes(&client, repo, &version).await;
+                        notes.map(|notes| (version, notes))
+                    }
+                })
+                .buffer_unordered(4)
+                .filter_map(|entry| async move { entry })
+                .collect()
+                .await
+        }
+        _ => HashMap::new(),
+    }
+}
+
+/// List the loader versions between `from_version` and `to_version`, so an
+/// upgrade can be judged before it's made. Each entry notes whether it was
+/// promoted to recommended and carries its changes from the loader's
+/// published changelog, linked by `changelog_url`; for loaders that publish
+/// none, the version list is the changelog.
+#[tauri::command]
+pub async fn get_loader_changelog_range(
+    loader_type: String,
+    mc_version: String,
+    from_version: String,
+    to_version: String,
+) -> Result<LoaderChangelog, String> {
+    let loader = loader_type.to_lowercase();
+    let client = MetaClient::default();
+    let versions = match loader.as_str() {
+        "forge" => client.get_forge_versions(&mc_version).await,
+        "neoforge" => client.get_neoforge_versions(&mc_version).await,
+        "fabric" => client.get_fabric_versions(&mc_version).await,
+        "quilt" => client.get_quilt_versions(&mc_version).await,
+        "liteloader" => client.get_liteloader_versions(&mc_version).await,
+        _ => return Err(format!("Unsupported mod loader: {}", loader_type)),
+    }
+    .map_err(|e| format!("Failed to fetch {} versions: {}", loader_type, e))?;
+    
+    if !versions.iter().any(|v| v.version == to_version) {
+        return Err(format!("{} {} not found for Minecraft {}", loader_type, to_version, mc_version));
+    }
+    
+    let stability = LoaderStability::fetch(&loader, &mc_version).await;
+    let downgrade = version_range::compare_versions(&to_version, &from_version).is_lt();
+    let mut in_range: Vec<VersionEntry> = versions
+        .into_iter()
+        .filter(|v| version_range::in_version_range(&v.version, &from_version, &to_version))
+        .collect();
+    in_range.sort_by(|a, b| version_range::compare_versions(&a.version, &b.version));
+    if downgrade {
+        in_range.reverse();
+    }
+    
+    let newest = if downgrade { &from_version } else { &to_version };
+    let range_versions: Vec<String> = in_range.iter().map(|v| v.version.clone()).collect();
+    let mut changes = fetch_loader_changes(&loader, &mc_version, newest, &range_versions).await;
+    
+    Ok(LoaderChangelog {
+        versions: in_range
+            .into_iter()
+            .map(|v| LoaderChangelogEntry {
+                changelog_url: loader_changelog_url(&loader, &mc_version, &v.version),
+                changes: changes.remove(&v.version),
+                prerelease: stability.is_prerelease(&v),
+                recommended: v.recommended,
+                release_time: v.release_time,
+                version: v.version,
+            })
+            .collect(),
+        loader: loader_type,
+        from_version,
+        to_version,
+        downgrade,
+    })
+}
+
+/// Recommend a loader version for a set of mod jars.
+///
+/// Reads the loader version range each mod declares and picks the version
+/// that satisfies all of them, preferring the loader's recommended build.
+/// When no single version works, `conflicts` names the mods that disagree.
+/// Mods built for a different loader, or without a declared range, are
+/// ignored.
+#[tauri::command]
+pub async fn recommend_loader_version(
+    minecraft_version: String,
+    loader: String,
+    mod_paths: Vec<String>,
+) -> Result<LoaderRecommendation, String> {
+    let loader = loader.to_lowercase();
+    let dependency_id = match loader.as_str() {
+        "fabric" => "fabricloader",
+        "quilt" => "quilt_loader",
+        "forge" => "forge",
+        "neoforge" => "neoforge",
+        _ => return Err(format!("Unsupported mod loader: {}", loader)),
+    };
+    
+    let requirements: Vec<LoaderRequirement> = mod_paths
+        .iter()
+        .filter_map(|path| parse_mod_jar(Path::new(path)))
+        .filter(|details| details.loader_dependency.as_deref() == Some(dependency_id))
+        .filter_map(|details| {
+            let range = details.loader_version_range?;
+            let mod_name = if details.name.is_empty() { details.mod_id } else { details.name };
+            Some(LoaderRequirement { mod_name, range })
+        })
+        .collect();
+    
+    let client = MetaClient::default();
+    let versions = match loader.as_str() {
+        "fabric" => client.get_fabric_versions(&minecraft_version).await,
+        "quilt" => client.get_quilt_versions(&minecraft_version).await,
+        "forge" => client.get_forge_versions(&minecraft_version).await,
+        _ => client.get_neoforge_versions(&minecraft_version).await,
+    }
+    .map_err(|e| format!("Failed to fetch {} versions: {}", loader, e))?;
+    
+    let versions: Vec<(String, bool)> = versions
+        .into_iter()
+        .map(|v| (v.version, v.recommended))
+        .collect();
+    
+    Ok(version_range::recommend_loader_version(&versions, &requirements))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn reads_one_version_from_forge_and_neoforge_changelogs() {
+        let forge = "47.2\n====\n - 47.2.20 Fix the thing (#123)\n          and its follow-up\n - 47.2.2 Older fix\n - 47.2.20 Second change\n";
+        assert_eq!(
+            changelog_entries(forge, "47.2.20").as_deref(),
+            Some("Fix the thing (#123)\nand its follow-up\nSecond change")
+        );
+        assert_eq!(changelog_entries(forge, "47.2.2").as_deref(), Some("Older fix"));
+        assert_eq!(changelog_entries(forge, "47.2.1"), None);
+        
+        let neoforge = "20.4.237\n========\n - `20.4.237` Fix crash on startup\n";
+        assert_eq!(changelog_entries(neoforge, "20.4.237").as_deref(), Some("Fix crash on startup"));
+    }
+}
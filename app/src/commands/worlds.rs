@@ -19,7 +19,8 @@
 //! along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use super::state::AppState;
-use crate::core::minecraft::world;
+use crate::core::minecraft::captures::{self, PruneResult};
+use crate::core::minecraft::world::{self, WorldBackup, WorldExport};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use tauri::State;
@@ -95,17 +96,144 @@ pub async fn delete_world(
     
     let saves_dir = instance.game_dir().join("saves");
     world::delete_world(&saves_dir, &folder_name, use_recycle_bin)
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string())?;
+    state.invalidate_instance_summary(&instance_id);
+    
+    Ok(())
+}
+
+/// Delete the world backups in the `backups` folder, Minecraft's and the
+/// launcher's, made before `date` (`YYYY-MM-DD` or an RFC 3339 timestamp).
+/// Returns how many were removed and the space freed.
+#[tauri::command]
+pub async fn delete_world_backups_before(
+    state: State<'_, AppState>,
+    instance_id: String,
+    date: String,
+) -> Result<PruneResult, String> {
+    let cutoff = captures::parse_cutoff(&date)
+        .ok_or_else(|| format!("Invalid date '{}'; expected YYYY-MM-DD", date))?;
+    let use_recycle_bin = state.config.lock().unwrap().files.use_recycle_bin;
+    
+    let instance = {
+        let instances = state.instances.lock().unwrap();
+        instances.iter()
+            .find(|i| i.id == instance_id)
+            .ok_or_else(|| "Instance not found".to_string())?
+            .clone()
+    };
+    
+    let backups_dir = instance.game_dir().join("backups");
+    let result = world::prune_world_backups(&backups_dir, cutoff, use_recycle_bin)
+        .map_err(|e| e.to_string())?;
+    state.invalidate_instance_summary(&instance_id);
+    
+    Ok(result)
 }
 
-/// Export a world to a ZIP file
+/// Back up a world to `backups/<world>/<timestamp>.zip` in the game folder
+#[tauri::command]
+pub async fn backup_world(
+    state: State<'_, AppState>,
+    instance_id: String,
+    folder_name: String,
+) -> Result<WorldBackup, String> {
+    let instance = {
+        let instances = state.instances.lock().unwrap();
+        instances.iter()
+            .find(|i| i.id == instance_id)
+            .ok_or_else(|| "Instance not found".to_string())?
+            .clone()
+    };
+    
+    let game_dir = instance.game_dir();
+    let backup = tokio::task::spawn_blocking(move || {
+        world::backup_world(&game_dir.join("saves"), &folder_name, &game_dir.join("backups"))
+    })
+    .await
+    .map_err(|e| e.to_string())?
+    .map_err(|e| format!("Failed to back up world: {}", e))?;
+    state.invalidate_instance_summary(&instance_id);
+    
+    Ok(backup)
+}
+
+/// List the backups of a world, or of every world when `folder_name` is
+/// None (including deleted ones), newest first
+#[tauri::command]
+pub async fn list_world_backups(
+    state: State<'_, AppState>,
+    instance_id: String,
+    folder_name: Option<String>,
+) -> Result<Vec<WorldBackup>, String> {
+    let instance = {
+        let instances = state.instances.lock().unwrap();
+        instances.iter()
+            .find(|i| i.id == instance_id)
+            .ok_or_else(|| "Instance not found".to_string())?
+            .clone()
+    };
+    
+    let backups_dir = instance.game_dir().join("backups");
+    Ok(tokio::task::spawn_blocking(move || world::list_world_backups(&backups_dir))
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .filter(|backup| folder_name.as_ref().is_none_or(|name| &backup.world == name))
+        .collect())
+}
+
+/// Restore a world backup (by its listed file name) into saves. Unless
+/// `overwrite` is set, a world that still exists is kept and the backup is
+/// restored under a new folder name. Overwriting is refused while the
+/// instance is running. Returns the folder name used.
+#[tauri::command]
+pub async fn restore_world_backup(
+    state: State<'_, AppState>,
+    instance_id: String,
+    file_name: String,
+    overwrite: bool,
+) -> Result<String, String> {
+    let use_recycle_bin = state.config.lock().unwrap().files.use_recycle_bin;
+    
+    let instance = {
+        let instances = state.instances.lock().unwrap();
+        instances.iter()
+            .find(|i| i.id == instance_id)
+            .ok_or_else(|| "Instance not found".to_string())?
+            .clone()
+    };
+    
+    if overwrite && state.running_processes.lock().unwrap().contains_key(&instance_id) {
+        return Err("Cannot replace a world while the instance is running".to_string());
+    }
+    
+    let game_dir = instance.game_dir();
+    let backup_path = world::backup_path(&game_dir.join("backups"), &file_name)
+        .ok_or_else(|| format!("Backup '{}' not found", file_name))?;
+    
+    let restored = tokio::task::spawn_blocking(move || {
+        world::restore_world_backup(&backup_path, &game_dir.join("saves"), overwrite, use_recycle_bin)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+    .map_err(|e| format!("Failed to restore backup: {}", e))?;
+    state.invalidate_instance_summary(&instance_id);
+    
+    Ok(restored)
+}
+
+/// Export a world to a ZIP file with a README of its seed, game rules and
+/// data packs. `include_global_datapacks` copies the global data packs the
+/// world has enabled into the archive's world folder.
 #[tauri::command]
 pub async fn export_world(
     state: State<'_, AppState>,
     instance_id: String,
     folder_name: String,
     output_path: String,
-) -> Result<(), String> {
+    include_global_datapacks: Option<bool>,
+) -> Result<WorldExport, String> {
     let instance = {
         let instances = state.instances.lock().unwrap();
         instances.iter()
@@ -114,10 +242,12 @@ pub async fn export_world(
             .clone()
     };
     
-    let saves_dir = instance.game_dir().join("saves");
+    let game_dir = instance.game_dir();
+    let saves_dir = game_dir.join("saves");
     let output = PathBuf::from(output_path);
+    let global_datapacks_dir = include_global_datapacks.unwrap_or(false).then_some(game_dir.as_path());
     
-    world::export_world(&saves_dir, &folder_name, &output)
+    world::export_world(&saves_dir, &folder_name, &output, global_datapacks_dir)
         .map_err(|e| e.to_string())
 }
 
@@ -139,7 +269,10 @@ pub async fn copy_world(
     
     let saves_dir = instance.game_dir().join("saves");
     world::copy_world(&saves_dir, &folder_name, &new_name)
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string())?;
+    state.invalidate_instance_summary(&instance_id);
+    
+    Ok(())
 }
 
 /// Get world icon as base64
@@ -23,7 +23,7 @@
 use std::path::PathBuf;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use crate::core::error::Result;
+use crate::core::error::{OxideError, Result};
 use super::Account;
 
 /// Stored accounts file format (for JSON serialization)
@@ -202,6 +202,25 @@ impl AccountList {
         }
     }
 
+    /// Put the accounts in the order given by `ordered_ids`.
+    ///
+    /// The IDs must name every stored account exactly once, so an order sent
+    /// from a stale list can't drop or duplicate accounts.
+    pub fn reorder(&mut self, ordered_ids: &[String]) -> Result<()> {
+        let mut stored: Vec<&str> = self.accounts.iter().map(|a| a.id.as_str()).collect();
+        let mut requested: Vec<&str> = ordered_ids.iter().map(String::as_str).collect();
+        stored.sort_unstable();
+        requested.sort_unstable();
+        if stored != requested {
+            return Err(OxideError::Other(
+                "The account order doesn't match the saved accounts; reload the list and try again".into(),
+            ));
+        }
+        
+        self.accounts.sort_by_key(|a| ordered_ids.iter().position(|id| *id == a.id));
+        Ok(())
+    }
+
     /// Update an existing account
     pub fn update(&mut self, account: Account) {
         if let Some(existing) = self.get_mut(&account.id) {
@@ -225,6 +244,61 @@ impl AccountList {
             .collect()
     }
 
+    /// Find an account by Minecraft UUID, ignoring dashes and case
+    pub fn position_by_uuid(&self, uuid: &str) -> Option<usize> {
+        let uuid = normalize_uuid(uuid);
+        self.accounts.iter().position(|a| normalize_uuid(&a.uuid) == uuid)
+    }
+
+    /// Add an account, or if one with the same UUID already exists, give it
+    /// the new account's profile and tokens while keeping its ID, position
+    /// and active state. Returns the stored account.
+    pub fn add_or_update(&mut self, account: Account) -> &Account {
+        match self.position_by_uuid(&account.uuid) {
+            Some(idx) => {
+                let existing = &mut self.accounts[idx];
+                *existing = Account {
+                    id: existing.id.clone(),
+                    is_active: existing.is_active,
+                    added_at: existing.added_at,
+                    last_used: existing.last_used.or(account.last_used),
+                    ..account
+                };
+                &self.accounts[idx]
+            }
+            None => {
+                self.add(account);
+                self.accounts.last().unwrap()
+            }
+        }
+    }
+
+    /// Merge accounts that share a Minecraft UUID. Each group keeps the account
+    /// with the freshest token, in the position of the group's first account,
+    /// and stays active if any of its accounts was. Returns how many accounts
+    /// were removed.
+    pub fn deduplicate(&mut self) -> usize {
+        let before = self.accounts.len();
+        let mut merged: Vec<Account> = Vec::with_capacity(before);
+        
+        for account in self.accounts.drain(..) {
+            let uuid = normalize_uuid(&account.uuid);
+            let Some(kept) = merged.iter_mut().find(|a| normalize_uuid(&a.uuid) == uuid) else {
+                merged.push(account);
+                continue;
+            };
+            
+            let is_active = kept.is_active || account.is_active;
+            if freshness(&account) > freshness(kept) {
+                *kept = account;
+            }
+            kept.is_active = is_active;
+        }
+        
+        self.accounts = merged;
+        before - self.accounts.len()
+    }
+
     /// Check if an account with the given username exists
     pub fn has_username(&self, username: &str) -> bool {
         self.accounts.iter().any(|a| a.username == username)
@@ -246,8 +320,95 @@ impl AccountList {
     }
 }
 
+/// Minecraft UUIDs are stored both with and without dashes
+fn normalize_uuid(uuid: &str) -> String {
+    uuid.replace('-', "").to_lowercase()
+}
+
+/// Sort key for picking which duplicate to keep: signed-in accounts first,
+/// then the latest token expiry, then the most recently used
+fn freshness(account: &Account) -> (bool, Option<DateTime<Utc>>, Option<DateTime<Utc>>) {
+    let token_expiry = account.data.as_ref()
+        .and_then(|d| d.minecraft_token.expires_at.or(d.minecraft_token.issued_at))
+        .or(account.token_expires_at);
+    (account.is_online(), token_expiry, account.last_used)
+}
+
 impl Default for AccountList {
     fn default() -> Self {
         Self::new()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn list_of(names: &[&str]) -> AccountList {
+        let mut list = AccountList::new();
+        for name in names {
+            list.add(Account::new_offline(name.to_string()));
+        }
+        list
+    }
+    
+    #[test]
+    fn test_reorder_follows_given_ids() {
+        let mut list = list_of(&["Alex", "Steve", "Notch"]);
+        let ids: Vec<String> = list.iter().rev().map(|a| a.id.clone()).collect();
+        
+        list.reorder(&ids).unwrap();
+        let names: Vec<&str> = list.iter().map(|a| a.username.as_str()).collect();
+        assert_eq!(names, vec!["Notch", "Steve", "Alex"]);
+    }
+    
+    #[test]
+    fn test_reorder_rejects_mismatched_ids() {
+        let mut list = list_of(&["Alex", "Steve"]);
+        let first = list.accounts[0].id.clone();
+        
+        assert!(list.reorder(std::slice::from_ref(&first)).is_err());
+        assert!(list.reorder(&[first.clone(), first]).is_err());
+        assert_eq!(list.len(), 2);
+    }
+    
+    fn microsoft(uuid: &str, expires_in_hours: i64) -> Account {
+        Account::new_microsoft(
+            "Steve".to_string(),
+            uuid.to_string(),
+            "token".to_string(),
+            "refresh".to_string(),
+            Utc::now() + chrono::Duration::hours(expires_in_hours),
+        )
+    }
+    
+    #[test]
+    fn test_deduplicate_keeps_freshest_and_active() {
+        let mut list = list_of(&["Alex"]);
+        let mut stale = microsoft("069a79f4-44e9-4726-a5be-fca90e38aaf5", 1);
+        stale.is_active = true;
+        let fresh = microsoft("069a79f444e94726a5befca90e38aaf5", 24);
+        let fresh_id = fresh.id.clone();
+        list.accounts[0].is_active = false;
+        list.add(stale);
+        list.add(fresh);
+        
+        assert_eq!(list.deduplicate(), 1);
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.accounts[1].id, fresh_id);
+        assert!(list.accounts[1].is_active);
+        assert_eq!(list.deduplicate(), 0);
+    }
+    
+    #[test]
+    fn test_add_or_update_keeps_existing_identity() {
+        let mut list = AccountList::new();
+        list.add(microsoft("069a79f444e94726a5befca90e38aaf5", 1));
+        let original_id = list.accounts[0].id.clone();
+        
+        let stored = list.add_or_update(microsoft("069a79f4-44e9-4726-a5be-fca90e38aaf5", 24));
+        assert_eq!(stored.id, original_id);
+        assert!(stored.is_active);
+        assert_eq!(list.len(), 1);
+    }
+}
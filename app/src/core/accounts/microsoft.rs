@@ -45,7 +45,7 @@ pub const MSA_CLIENT_ID: &str = "1f8c7ebd-3140-4b03-830a-dd0e5ec3218f";
 use crate::core::error::{OxideError, Result};
 use super::{
     Account, AccountData, AuthProgressEvent, CapeInfo, DeviceCodeInfo, MinecraftEntitlement,
-    MinecraftProfile, SkinInfo, SkinVariant, Token,
+    MinecraftProfile, SkinInfo, SkinVariant, Token, REFRESH_TOKEN_KEY,
 };
 
 // =============================================================================
@@ -329,7 +329,8 @@ pub async fn complete_authentication(
     account_data.msa_token = Token::new(msa_token.access_token.clone())
         .with_expiry(msa_expires);
     if let Some(ref rt) = msa_token.refresh_token {
-        account_data.msa_token.extra.insert("refresh_token".to_string(), rt.clone());
+        account_data.msa_token.extra.insert(REFRESH_TOKEN_KEY.to_string(), rt.clone());
+        account_data.set_refresh_token_issued_at(Utc::now());
     }
     
     // Step 1: Xbox Live User Authentication
@@ -460,6 +461,9 @@ pub async fn refresh_microsoft_account(
         step: "refresh_msa".to_string(),
     }).await;
     
+    // Microsoft normally rotates the refresh token; if it didn't, the old one keeps its age
+    let rotated = ms_token.refresh_token.is_some();
+    
     // Complete the rest of the authentication flow
     let msa_token = MsaToken {
         access_token: ms_token.access_token,
@@ -467,7 +471,14 @@ pub async fn refresh_microsoft_account(
         expires_in: ms_token.expires_in,
     };
     
-    complete_authentication(msa_token, progress_tx).await
+    let mut account_data = complete_authentication(msa_token, progress_tx).await?;
+    if !rotated {
+        if let Some(issued_at) = account.data.as_ref().and_then(|d| d.refresh_token_issued_at()) {
+            account_data.set_refresh_token_issued_at(issued_at);
+        }
+    }
+    
+    Ok(account_data)
 }
 
 /// Login with Microsoft account using device code flow (legacy wrapper)
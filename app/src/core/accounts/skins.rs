@@ -29,7 +29,7 @@ use super::{CapeInfo, SkinInfo, SkinVariant};
 // =============================================================================
 
 /// Minecraft services base URL
-const MC_SERVICES_URL: &str = "https://api.minecraftservices.com";
+pub(crate) const MC_SERVICES_URL: &str = "https://api.minecraftservices.com";
 
 /// Session server for fetching other players' skins
 const SESSION_SERVER_URL: &str = "https://sessionserver.mojang.com";
@@ -501,6 +501,60 @@ pub fn validate_skin_image(image_data: &[u8]) -> Result<()> {
     Ok(())
 }
 
+/// Validate a skin and convert a legacy 64x32 one to the 64x64 layout the
+/// skin API expects. 64x64 skins are returned unchanged.
+pub fn prepare_skin_image(image_data: &[u8]) -> Result<Vec<u8>> {
+    validate_skin_image(image_data)?;
+    
+    let skin = image::load_from_memory_with_format(image_data, image::ImageFormat::Png)
+        .map_err(|e| OxideError::Other(format!("Failed to read skin image: {}", e)))?
+        .to_rgba8();
+    if skin.height() == 64 {
+        return Ok(image_data.to_vec());
+    }
+    
+    let converted = convert_legacy_skin(&skin);
+    let mut png = std::io::Cursor::new(Vec::new());
+    converted.write_to(&mut png, image::ImageFormat::Png)
+        .map_err(|e| OxideError::Other(format!("Failed to encode skin image: {}", e)))?;
+    Ok(png.into_inner())
+}
+
+/// Expand a 64x32 skin to 64x64 the way the game does: the left leg and
+/// left arm, which legacy skins don't have, are mirrored from the right ones
+fn convert_legacy_skin(legacy: &image::RgbaImage) -> image::RgbaImage {
+    let mut skin = image::RgbaImage::new(64, 64);
+    image::imageops::replace(&mut skin, legacy, 0, 0);
+    
+    // (source x, source y, offset x, offset y, width, height), mirrored horizontally
+    const MIRRORED: [(u32, u32, i32, i32, u32, u32); 12] = [
+        (4, 16, 16, 32, 4, 4),
+        (8, 16, 16, 32, 4, 4),
+        (0, 20, 24, 32, 4, 12),
+        (4, 20, 16, 32, 4, 12),
+        (8, 20, 8, 32, 4, 12),
+        (12, 20, 16, 32, 4, 12),
+        (44, 16, -8, 32, 4, 4),
+        (48, 16, -8, 32, 4, 4),
+        (40, 20, 0, 32, 4, 12),
+        (44, 20, -8, 32, 4, 12),
+        (48, 20, -16, 32, 4, 12),
+        (52, 20, -8, 32, 4, 12),
+    ];
+    for (x, y, dx, dy, width, height) in MIRRORED {
+        for row in 0..height {
+            for col in 0..width {
+                let pixel = *legacy.get_pixel(x + col, y + row);
+                let target_x = (x as i32 + dx) as u32 + (width - 1 - col);
+                let target_y = (y as i32 + dy) as u32 + row;
+                skin.put_pixel(target_x, target_y, pixel);
+            }
+        }
+    }
+    
+    skin
+}
+
 /// Get the skins folder path for caching downloaded skins
 pub fn get_skins_folder(data_dir: &std::path::Path) -> std::path::PathBuf {
     data_dir.join("skins")
@@ -517,3 +571,28 @@ pub async fn cache_skin(data_dir: &std::path::Path, uuid: &str, image_data: &[u8
     
     Ok(skin_path)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn legacy_skins_are_expanded_with_mirrored_limbs() {
+        let mut legacy = image::RgbaImage::new(64, 32);
+        // Right leg front, leftmost column
+        legacy.put_pixel(4, 20, image::Rgba([255, 0, 0, 255]));
+        legacy.put_pixel(0, 0, image::Rgba([0, 255, 0, 255]));
+        let mut png = std::io::Cursor::new(Vec::new());
+        legacy.write_to(&mut png, image::ImageFormat::Png).unwrap();
+        
+        let converted = prepare_skin_image(&png.into_inner()).unwrap();
+        validate_skin_image(&converted).unwrap();
+        let skin = image::load_from_memory(&converted).unwrap().to_rgba8();
+        assert_eq!(skin.dimensions(), (64, 64));
+        assert_eq!(skin.get_pixel(0, 0), &image::Rgba([0, 255, 0, 255]));
+        // Left leg front starts at (20, 52) and is mirrored
+        assert_eq!(skin.get_pixel(23, 52), &image::Rgba([255, 0, 0, 255]));
+        
+        assert!(prepare_skin_image(b"not a png").is_err());
+    }
+}
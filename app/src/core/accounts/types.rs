@@ -76,6 +76,27 @@ impl Token {
     }
 }
 
+/// Key in `msa_token.extra` holding the refresh token
+pub const REFRESH_TOKEN_KEY: &str = "refresh_token";
+
+/// Key in `msa_token.extra` holding when the refresh token was issued (RFC 3339)
+pub const REFRESH_TOKEN_ISSUED_KEY: &str = "refresh_token_issued_at";
+
+/// How long Microsoft keeps an unused refresh token valid
+pub const REFRESH_TOKEN_LIFETIME_DAYS: i64 = 90;
+
+/// Whether a Microsoft account's stored tokens can still be used
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenStatus {
+    /// The Minecraft access token is still valid
+    Valid,
+    /// The access token expired but can be renewed with the refresh token
+    Expired,
+    /// The refresh token is missing or too old; the user has to log in again
+    NeedsRelogin,
+}
+
 /// Minecraft profile information
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct MinecraftProfile {
@@ -203,6 +224,44 @@ impl AccountData {
     pub fn profile_name(&self) -> &str {
         &self.minecraft_profile.name
     }
+    
+    /// When the refresh token was issued. Accounts added before this was
+    /// recorded fall back to when the MSA token was issued.
+    pub fn refresh_token_issued_at(&self) -> Option<DateTime<Utc>> {
+        self.msa_token.extra.get(REFRESH_TOKEN_ISSUED_KEY)
+            .and_then(|v| DateTime::parse_from_rfc3339(v).ok())
+            .map(|v| v.with_timezone(&Utc))
+            .or(self.msa_token.issued_at)
+    }
+    
+    /// Record when the refresh token was issued
+    pub fn set_refresh_token_issued_at(&mut self, issued_at: DateTime<Utc>) {
+        self.msa_token.extra.insert(REFRESH_TOKEN_ISSUED_KEY.to_string(), issued_at.to_rfc3339());
+    }
+    
+    /// When the refresh token stops working if it isn't used
+    pub fn refresh_token_expires_at(&self) -> Option<DateTime<Utc>> {
+        self.refresh_token_issued_at()
+            .map(|issued| issued + chrono::Duration::days(REFRESH_TOKEN_LIFETIME_DAYS))
+    }
+    
+    /// Token status at `now`
+    pub fn token_status(&self, now: DateTime<Utc>) -> TokenStatus {
+        let has_refresh_token = self.msa_token.extra.get(REFRESH_TOKEN_KEY)
+            .is_some_and(|t| !t.is_empty());
+        let refresh_stale = self.refresh_token_expires_at().is_some_and(|expires| now >= expires);
+        
+        let access_valid = !self.minecraft_token.token.is_empty()
+            && self.minecraft_token.expires_at.is_none_or(|expires| now < expires);
+        
+        if access_valid {
+            TokenStatus::Valid
+        } else if has_refresh_token && !refresh_stale {
+            TokenStatus::Expired
+        } else {
+            TokenStatus::NeedsRelogin
+        }
+    }
 }
 
 /// A Minecraft account
@@ -261,7 +320,7 @@ impl Account {
             username: data.minecraft_profile.name.clone(),
             uuid: data.minecraft_profile.id.clone(),
             access_token: Some(data.minecraft_token.token.clone()),
-            refresh_token: Some(data.msa_token.extra.get("refresh_token").cloned().unwrap_or_default()),
+            refresh_token: Some(data.msa_token.extra.get(REFRESH_TOKEN_KEY).cloned().unwrap_or_default()),
             token_expires_at: data.minecraft_token.expires_at,
             is_active: false,
             skin: data.minecraft_profile.skin.as_ref().map(|s| SkinData {
@@ -349,6 +408,28 @@ impl Account {
         }
     }
 
+    /// Token status at `now`, or None for offline accounts
+    pub fn token_status(&self, now: DateTime<Utc>) -> Option<TokenStatus> {
+        if !self.is_online() {
+            return None;
+        }
+        
+        if let Some(ref data) = self.data {
+            return Some(data.token_status(now));
+        }
+        
+        // Legacy accounts only recorded the access token expiry
+        let access_valid = self.token_expires_at.is_some_and(|expires| now < expires);
+        let has_refresh_token = self.refresh_token.as_ref().is_some_and(|t| !t.is_empty());
+        Some(if access_valid {
+            TokenStatus::Valid
+        } else if has_refresh_token {
+            TokenStatus::Expired
+        } else {
+            TokenStatus::NeedsRelogin
+        })
+    }
+
     /// Check if this is an online account
     pub fn is_online(&self) -> bool {
         matches!(self.account_type, AccountType::Microsoft)
@@ -392,7 +473,7 @@ impl Account {
     /// Get the MSA refresh token
     pub fn get_refresh_token(&self) -> Option<String> {
         if let Some(ref data) = self.data {
-            if let Some(rt) = data.msa_token.extra.get("refresh_token") {
+            if let Some(rt) = data.msa_token.extra.get(REFRESH_TOKEN_KEY) {
                 return Some(rt.clone());
             }
         }
@@ -405,7 +486,7 @@ impl Account {
         self.uuid = data.minecraft_profile.id.clone();
         self.access_token = Some(data.minecraft_token.token.clone());
         self.token_expires_at = data.minecraft_token.expires_at;
-        if let Some(rt) = data.msa_token.extra.get("refresh_token") {
+        if let Some(rt) = data.msa_token.extra.get(REFRESH_TOKEN_KEY) {
             self.refresh_token = Some(rt.clone());
         }
         self.data = Some(data);
@@ -630,3 +711,46 @@ pub enum AuthProgressEvent {
     },
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    
+    fn account_data(now: DateTime<Utc>, access_expires: DateTime<Utc>, refresh_issued: DateTime<Utc>) -> AccountData {
+        let mut data = AccountData {
+            minecraft_token: Token::new("access".to_string()).with_expiry(access_expires),
+            msa_token: Token::new("msa".to_string()).with_extra(REFRESH_TOKEN_KEY, "refresh"),
+            ..Default::default()
+        };
+        data.msa_token.issued_at = Some(now);
+        data.set_refresh_token_issued_at(refresh_issued);
+        data
+    }
+    
+    #[test]
+    fn test_token_status_transitions() {
+        let now = Utc::now();
+        
+        let valid = account_data(now, now + Duration::hours(1), now);
+        assert_eq!(valid.token_status(now), TokenStatus::Valid);
+        
+        let expired = account_data(now, now - Duration::hours(1), now - Duration::days(10));
+        assert_eq!(expired.token_status(now), TokenStatus::Expired);
+        
+        let stale = account_data(now, now - Duration::hours(1), now - Duration::days(REFRESH_TOKEN_LIFETIME_DAYS + 1));
+        assert_eq!(stale.token_status(now), TokenStatus::NeedsRelogin);
+    }
+    
+    #[test]
+    fn test_missing_refresh_token_needs_relogin() {
+        let now = Utc::now();
+        let mut data = account_data(now, now - Duration::hours(1), now);
+        data.msa_token.extra.remove(REFRESH_TOKEN_KEY);
+        assert_eq!(data.token_status(now), TokenStatus::NeedsRelogin);
+    }
+    
+    #[test]
+    fn test_offline_account_has_no_token_status() {
+        assert!(Account::new_offline("Steve".to_string()).token_status(Utc::now()).is_none());
+    }
+}
@@ -20,8 +20,27 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::PathBuf;
-use crate::core::error::Result;
+use std::path::{Path, PathBuf};
+use crate::core::error::{OxideError, Result};
+use crate::core::profiles;
+
+/// Current version of the settings export format
+pub const SETTINGS_EXPORT_VERSION: u32 = 1;
+
+/// Portable launcher settings file, used to move settings to another machine
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SettingsExport {
+    /// Export format version, checked and migrated on import
+    pub format_version: u32,
+    /// Launcher version that wrote the export
+    pub launcher_version: String,
+    /// RFC 3339 timestamp of the export
+    pub exported_at: String,
+    /// Whether API keys and proxy credentials were included
+    pub includes_secrets: bool,
+    /// The serialized `Config`
+    pub config: serde_json::Value,
+}
 
 /// Application configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -34,6 +53,14 @@ pub struct Config {
     #[serde(default)]
     pub instances_dir: Option<PathBuf>,
 
+    /// Directory where shared game assets are stored (defaults to `data_dir/assets`)
+    #[serde(default)]
+    pub assets_dir: Option<PathBuf>,
+
+    /// Directory where shared libraries are stored (defaults to `data_dir/libraries`)
+    #[serde(default)]
+    pub libraries_dir: Option<PathBuf>,
+
     /// Theme name (dark, light, system)
     #[serde(default = "default_theme")]
     pub theme: String,
@@ -77,6 +104,18 @@ pub struct Config {
     /// File management settings
     #[serde(default)]
     pub files: FilesConfig,
+    
+    /// Mod management settings
+    #[serde(default)]
+    pub mods: ModsConfig,
+    
+    /// Defaults for newly created instances
+    #[serde(default)]
+    pub instance_template: InstanceTemplate,
+    
+    /// Named argument sets instances can be assigned
+    #[serde(default)]
+    pub argument_profiles: Vec<ArgumentProfile>,
 }
 
 impl Default for Config {
@@ -84,6 +123,8 @@ impl Default for Config {
         Self {
             data_dir: default_data_dir(),
             instances_dir: None,
+            assets_dir: None,
+            libraries_dir: None,
             theme: default_theme(),
             java: JavaConfig::default(),
             network: NetworkConfig::default(),
@@ -95,6 +136,9 @@ impl Default for Config {
             api_keys: ApiKeys::default(),
             debug: DebugConfig::default(),
             files: FilesConfig::default(),
+            mods: ModsConfig::default(),
+            instance_template: InstanceTemplate::default(),
+            argument_profiles: Vec::new(),
         }
     }
 }
@@ -156,12 +200,45 @@ impl Config {
 
     /// Get the assets directory
     pub fn assets_dir(&self) -> PathBuf {
-        self.data_dir.join("assets")
+        self.assets_dir
+            .clone()
+            .unwrap_or_else(|| self.data_dir.join("assets"))
+    }
+
+    /// Get the shared screenshots directory
+    pub fn shared_screenshots_dir(&self) -> PathBuf {
+        self.files.shared_screenshots_dir
+            .clone()
+            .unwrap_or_else(|| self.data_dir.join("shared").join("screenshots"))
+    }
+
+    /// Get the shared saves directory
+    pub fn shared_saves_dir(&self) -> PathBuf {
+        self.files.shared_saves_dir
+            .clone()
+            .unwrap_or_else(|| self.data_dir.join("shared").join("saves"))
+    }
+
+    /// Get the essential mod suggestions, falling back to the built-in list
+    pub fn suggested_mods(&self) -> Vec<SuggestedMod> {
+        self.mods.suggested_mods
+            .clone()
+            .unwrap_or_else(default_suggested_mods)
     }
 
     /// Get the libraries directory
     pub fn libraries_dir(&self) -> PathBuf {
-        self.data_dir.join("libraries")
+        self.libraries_dir
+            .clone()
+            .unwrap_or_else(|| self.data_dir.join("libraries"))
+    }
+
+    /// Validate the assets/libraries directory overrides, creating them if missing
+    pub fn prepare_directory_overrides(&self) -> Result<()> {
+        for path in [&self.assets_dir, &self.libraries_dir].into_iter().flatten() {
+            prepare_directory_override(path)?;
+        }
+        Ok(())
     }
 
     /// Get the meta directory (for version manifests, etc.)
@@ -204,10 +281,80 @@ impl Config {
             })
     }
 
+    /// Find an argument profile by name
+    pub fn argument_profile(&self, name: &str) -> Option<&ArgumentProfile> {
+        self.argument_profiles.iter().find(|p| p.name == name)
+    }
+
     /// Set the theme name
     pub fn set_theme(&mut self, theme: &str) {
         self.theme = theme.to_string();
     }
+
+    /// Build a portable settings export, optionally leaving out secrets
+    pub fn to_export(&self, include_secrets: bool) -> Result<SettingsExport> {
+        let mut config = self.clone();
+        if !include_secrets {
+            config.api_keys = ApiKeys::default();
+            if let Some(ref mut proxy) = config.network.proxy {
+                proxy.username = None;
+                proxy.password = None;
+            }
+        }
+
+        Ok(SettingsExport {
+            format_version: SETTINGS_EXPORT_VERSION,
+            launcher_version: env!("CARGO_PKG_VERSION").to_string(),
+            exported_at: chrono::Utc::now().to_rfc3339(),
+            includes_secrets: include_secrets,
+            config: serde_json::to_value(&config)?,
+        })
+    }
+
+    /// Restore a configuration from a settings export.
+    /// Secrets missing from the export are kept from `current`, and with
+    /// `reset_paths` the machine-specific directories are kept from `current` too.
+    pub fn from_export(export: SettingsExport, current: &Config, reset_paths: bool) -> Result<Config> {
+        let value = migrate_settings_export(export.format_version, export.config)?;
+        let mut config: Config = serde_json::from_value(value)?;
+
+        if !export.includes_secrets {
+            config.api_keys = current.api_keys.clone();
+            if let (Some(proxy), Some(current_proxy)) = (config.network.proxy.as_mut(), current.network.proxy.as_ref()) {
+                proxy.username = current_proxy.username.clone();
+                proxy.password = current_proxy.password.clone();
+            }
+        }
+
+        if reset_paths {
+            config.data_dir = current.data_dir.clone();
+            config.instances_dir = current.instances_dir.clone();
+            config.assets_dir = current.assets_dir.clone();
+            config.libraries_dir = current.libraries_dir.clone();
+            config.java.custom_path = current.java.custom_path.clone();
+            config.network.downloads_dir = current.network.downloads_dir.clone();
+            config.files.shared_screenshots_dir = current.files.shared_screenshots_dir.clone();
+            config.files.shared_saves_dir = current.files.shared_saves_dir.clone();
+            config.mods.hash_blocklist_file = current.mods.hash_blocklist_file.clone();
+            config.instance_template.java_path = current.instance_template.java_path.clone();
+        }
+
+        Ok(config)
+    }
+}
+
+/// Upgrade an exported config to the current export format.
+/// Fields added since the export are filled by serde defaults; structural
+/// changes to `Config` get an upgrade step here keyed by the old version.
+fn migrate_settings_export(format_version: u32, config: serde_json::Value) -> Result<serde_json::Value> {
+    match format_version {
+        SETTINGS_EXPORT_VERSION => Ok(config),
+        v if v > SETTINGS_EXPORT_VERSION => Err(OxideError::Config(format!(
+            "Settings file format {} was made by a newer launcher (supported: {})",
+            v, SETTINGS_EXPORT_VERSION
+        ))),
+        v => Err(OxideError::Config(format!("Unknown settings file format: {}", v))),
+    }
 }
 
 /// Java runtime configuration
@@ -280,6 +427,10 @@ pub struct NetworkConfig {
     /// Whether to watch downloads directory recursively
     #[serde(default)]
     pub downloads_dir_watch_recursive: bool,
+
+    /// Fetch Mojang's blocked-servers list to warn about blocked saved servers
+    #[serde(default)]
+    pub check_blocked_servers: bool,
 }
 
 impl Default for NetworkConfig {
@@ -292,6 +443,7 @@ impl Default for NetworkConfig {
             user_agent: default_user_agent(),
             downloads_dir: None,
             downloads_dir_watch_recursive: false,
+            check_blocked_servers: false,
         }
     }
 }
@@ -394,6 +546,27 @@ pub struct UiConfig {
     /// Open instance details view after installing a modpack
     #[serde(default)]
     pub open_instance_after_install: bool,
+
+    /// Hide to the tray instead of quitting when the main window is closed
+    #[serde(default)]
+    pub minimize_to_tray: bool,
+
+    /// Keep the main window hidden at startup until the tray icon is clicked
+    #[serde(default)]
+    pub start_minimized: bool,
+
+    /// Prefetch version lists, detect Java and load instance summaries in
+    /// the background at startup
+    #[serde(default)]
+    pub warm_up_on_startup: bool,
+
+    /// Tab last opened on any instance's details view
+    #[serde(default)]
+    pub last_instance_tab: Option<String>,
+
+    /// Tab last opened on each instance's details view (keyed by instance ID)
+    #[serde(default)]
+    pub instance_tabs: HashMap<String, String>,
 }
 
 impl Default for UiConfig {
@@ -414,6 +587,11 @@ impl Default for UiConfig {
             main_window_state: WindowState::default(),
             dialog_window_states: HashMap::new(),
             open_instance_after_install: false,
+            minimize_to_tray: false,
+            start_minimized: false,
+            warm_up_on_startup: false,
+            last_instance_tab: None,
+            instance_tabs: HashMap::new(),
         }
     }
 }
@@ -489,6 +667,11 @@ pub struct MinecraftConfig {
     /// Show game time in instance list
     #[serde(default = "default_true")]
     pub show_game_time: bool,
+
+    /// Hand the access token to the OxideLaunch wrapper in a file instead of
+    /// on the command line, where other users can see it in process listings
+    #[serde(default)]
+    pub access_token_out_of_band: bool,
 }
 
 impl Default for MinecraftConfig {
@@ -503,6 +686,7 @@ impl Default for MinecraftConfig {
             show_console_on_error: true,
             record_game_time: true,
             show_game_time: true,
+            access_token_out_of_band: false,
         }
     }
 }
@@ -550,6 +734,14 @@ pub struct LoggingConfig {
     /// Number of rotated log files to keep
     #[serde(default = "default_log_files")]
     pub max_files: u32,
+
+    /// Also write game stdout/stderr to the instance's logs folder as it streams
+    #[serde(default)]
+    pub log_game_output_to_file: bool,
+
+    /// Lines of game output kept in memory per running instance
+    #[serde(default = "default_log_buffer_lines")]
+    pub max_buffered_lines: usize,
 }
 
 impl Default for LoggingConfig {
@@ -558,6 +750,8 @@ impl Default for LoggingConfig {
             debug_to_file: false,
             max_file_size_mb: default_log_size(),
             max_files: default_log_files(),
+            log_game_output_to_file: false,
+            max_buffered_lines: default_log_buffer_lines(),
         }
     }
 }
@@ -588,6 +782,48 @@ pub struct DebugConfig {
     /// Pause before launch (for attaching debuggers)
     #[serde(default)]
     pub pause_before_launch: bool,
+    
+    /// Which optional launch steps run
+    #[serde(default)]
+    pub launch_steps: LaunchStepOptions,
+}
+
+/// Toggles for the optional steps of the default launch task.
+/// Steps that others depend on are re-enabled at launch when skipping them
+/// would leave later steps without what they need.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LaunchStepOptions {
+    /// Log instance, Java and launch details before launching
+    #[serde(default = "default_true")]
+    pub print_instance_info: bool,
+    
+    /// Create the game, mods and natives folders
+    #[serde(default = "default_true")]
+    pub create_game_folders: bool,
+    
+    /// Find and validate the Java executable
+    #[serde(default = "default_true")]
+    pub check_java: bool,
+    
+    /// Check the Java version is compatible with the Minecraft version
+    #[serde(default = "default_true")]
+    pub verify_java: bool,
+    
+    /// Extract native libraries before launch
+    #[serde(default = "default_true")]
+    pub extract_natives: bool,
+}
+
+impl Default for LaunchStepOptions {
+    fn default() -> Self {
+        Self {
+            print_instance_info: true,
+            create_game_folders: true,
+            check_java: true,
+            verify_java: true,
+            extract_natives: true,
+        }
+    }
 }
 
 impl Default for DebugConfig {
@@ -599,6 +835,7 @@ impl Default for DebugConfig {
             verbose_logging: false,
             keep_natives_after_launch: false,
             pause_before_launch: false,
+            launch_steps: LaunchStepOptions::default(),
         }
     }
 }
@@ -611,29 +848,252 @@ pub struct FilesConfig {
     /// When disabled, files are permanently deleted and cannot be recovered.
     #[serde(default = "default_true")]
     pub use_recycle_bin: bool,
+
+    /// Folder instances with shared screenshots link to (defaults to `data_dir/shared/screenshots`)
+    #[serde(default)]
+    pub shared_screenshots_dir: Option<PathBuf>,
+
+    /// Folder instances with shared saves link to (defaults to `data_dir/shared/saves`)
+    #[serde(default)]
+    pub shared_saves_dir: Option<PathBuf>,
+
+    /// Free space (MB) kept in reserve on top of what extracting natives needs
+    /// before a launch is allowed to start
+    #[serde(default = "default_disk_space_margin_mb")]
+    pub disk_space_margin_mb: u64,
 }
 
 impl Default for FilesConfig {
     fn default() -> Self {
         Self {
             use_recycle_bin: true,
+            shared_screenshots_dir: None,
+            shared_saves_dir: None,
+            disk_space_margin_mb: default_disk_space_margin_mb(),
+        }
+    }
+}
+
+/// Mod management configuration
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ModsConfig {
+    /// Replaces the built-in essential mod suggestions when set
+    #[serde(default)]
+    pub suggested_mods: Option<Vec<SuggestedMod>>,
+
+    /// URL of a known-bad hash blocklist added to the bundled one by mod scans
+    #[serde(default)]
+    pub hash_blocklist_url: Option<String>,
+
+    /// Local known-bad hash blocklist added to the bundled one by mod scans
+    #[serde(default)]
+    pub hash_blocklist_file: Option<PathBuf>,
+
+    /// Hashes mod scans never report, for files wrongly on a blocklist
+    #[serde(default)]
+    pub hash_allowlist: Vec<String>,
+}
+
+/// A Modrinth project commonly paired with a mod loader
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SuggestedMod {
+    /// Modrinth project ID
+    pub project_id: String,
+    /// Display name
+    pub name: String,
+    /// Why the mod is suggested, shown next to it
+    pub reason: String,
+    /// Loaders the suggestion applies to, as Modrinth loader names
+    pub loaders: Vec<String>,
+}
+
+impl SuggestedMod {
+    fn new(project_id: &str, name: &str, reason: &str, loaders: &[&str]) -> Self {
+        Self {
+            project_id: project_id.to_string(),
+            name: name.to_string(),
+            reason: reason.to_string(),
+            loaders: loaders.iter().map(|l| l.to_string()).collect(),
         }
     }
+    
+    /// Whether the suggestion applies to a loader (case-insensitive)
+    pub fn supports_loader(&self, loader: &str) -> bool {
+        self.loaders.iter().any(|l| l.eq_ignore_ascii_case(loader))
+    }
+}
+
+/// The built-in suggestion list, used unless `Config.mods.suggested_mods` overrides it
+pub fn default_suggested_mods() -> Vec<SuggestedMod> {
+    vec![
+        SuggestedMod::new(
+            "P7dR8mSH",
+            "Fabric API",
+            "Required by most Fabric mods",
+            &["fabric"],
+        ),
+        SuggestedMod::new(
+            "qvIfYCYJ",
+            "QFAPI / QSL",
+            "Required by most Quilt mods and provides Fabric API for Fabric mods",
+            &["quilt"],
+        ),
+        SuggestedMod::new(
+            "mOgUt4GM",
+            "Mod Menu",
+            "Adds an in-game mod list with access to mod settings",
+            &["fabric", "quilt"],
+        ),
+        SuggestedMod::new(
+            "AANobbMI",
+            "Sodium",
+            "Rendering performance",
+            &["fabric", "quilt", "neoforge"],
+        ),
+        SuggestedMod::new(
+            "gvQqBUqZ",
+            "Lithium",
+            "Game logic and server performance",
+            &["fabric", "quilt", "neoforge"],
+        ),
+        SuggestedMod::new(
+            "uXXizFIs",
+            "FerriteCore",
+            "Lower memory usage",
+            &["fabric", "quilt", "forge", "neoforge"],
+        ),
+        SuggestedMod::new(
+            "nmDcB62a",
+            "ModernFix",
+            "Faster startup and lower memory usage",
+            &["fabric", "quilt", "forge", "neoforge"],
+        ),
+    ]
+}
+
+/// Settings applied to every new instance unless the creation request
+/// overrides them
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct InstanceTemplate {
+    /// Minimum memory (MB); None uses the launcher-wide setting
+    #[serde(default)]
+    pub min_memory: Option<u32>,
+    
+    /// Maximum memory (MB); None uses the launcher-wide setting
+    #[serde(default)]
+    pub max_memory: Option<u32>,
+    
+    /// Extra JVM arguments
+    #[serde(default)]
+    pub jvm_args: Option<String>,
+    
+    /// Mod loader preselected when creating an instance ("Fabric", "Forge", ...)
+    #[serde(default)]
+    pub mod_loader: Option<String>,
+    
+    /// Java installation new instances use instead of automatic selection
+    #[serde(default)]
+    pub java_path: Option<PathBuf>,
+}
+
+/// A named set of JVM and game arguments shared between instances
+/// (e.g. "Aikar's flags"). Editing a profile changes every instance using it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArgumentProfile {
+    /// Profile name, unique among profiles
+    pub name: String,
+    
+    /// JVM arguments
+    #[serde(default)]
+    pub jvm_args: Option<String>,
+    
+    /// Game arguments
+    #[serde(default)]
+    pub game_args: Option<String>,
 }
 
 // Default value functions
 
+/// Check that a directory override is usable and create it if it doesn't exist
+pub fn prepare_directory_override(path: &Path) -> Result<()> {
+    if !path.is_absolute() {
+        return Err(OxideError::Config(format!(
+            "Directory must be an absolute path: {}", path.display()
+        )));
+    }
+    
+    if path.exists() && !path.is_dir() {
+        return Err(OxideError::Config(format!(
+            "Path exists but is not a directory: {}", path.display()
+        )));
+    }
+    
+    std::fs::create_dir_all(path).map_err(|e| OxideError::Config(format!(
+        "Failed to create directory {}: {}", path.display(), e
+    )))?;
+    
+    // Make sure we can actually write there before pointing the launcher at it
+    let probe = path.join(".oxide-write-test");
+    std::fs::write(&probe, b"").map_err(|e| OxideError::Config(format!(
+        "Directory is not writable {}: {}", path.display(), e
+    )))?;
+    let _ = std::fs::remove_file(&probe);
+    
+    Ok(())
+}
+
+/// Move the contents of one directory into another.
+/// Entries are renamed where possible and copied when the target is on another drive.
+/// Files that already exist in the target are kept and the source copy is dropped.
+pub fn migrate_directory(from: &Path, to: &Path) -> Result<()> {
+    if !from.is_dir() || from == to {
+        return Ok(());
+    }
+    
+    if to.starts_with(from) {
+        return Err(OxideError::Config(format!(
+            "Cannot move {} into one of its own subfolders", from.display()
+        )));
+    }
+    
+    std::fs::create_dir_all(to)?;
+    
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        let src = entry.path();
+        let dest = to.join(entry.file_name());
+        
+        if src.is_dir() {
+            if !dest.exists() && std::fs::rename(&src, &dest).is_ok() {
+                continue;
+            }
+            migrate_directory(&src, &dest)?;
+            std::fs::remove_dir_all(&src)?;
+        } else if dest.exists() {
+            std::fs::remove_file(&src)?;
+        } else if std::fs::rename(&src, &dest).is_err() {
+            std::fs::copy(&src, &dest)?;
+            std::fs::remove_file(&src)?;
+        }
+    }
+    
+    Ok(())
+}
+
+/// The active profile's default data directory
 fn default_data_dir() -> PathBuf {
-    dirs::data_dir()
-        .unwrap_or_else(|| PathBuf::from("."))
-        .join("OxideLauncher")
+    match profiles::active_profile() {
+        Some(name) => profiles::profile_dir(&name),
+        None => profiles::launcher_data_dir(),
+    }
 }
 
+/// The active profile's config file
 fn config_file_path() -> PathBuf {
-    dirs::config_dir()
-        .unwrap_or_else(|| PathBuf::from("."))
-        .join("OxideLauncher")
-        .join("config.json")
+    match profiles::active_profile() {
+        Some(name) => profiles::profile_dir(&name).join("config.json"),
+        None => profiles::launcher_config_dir().join("config.json"),
+    }
 }
 
 fn default_theme() -> String {
@@ -644,6 +1104,10 @@ fn default_true() -> bool {
     true
 }
 
+fn default_disk_space_margin_mb() -> u64 {
+    256
+}
+
 fn default_max_downloads() -> usize {
     6
 }
@@ -680,6 +1144,10 @@ fn default_log_files() -> u32 {
     5
 }
 
+fn default_log_buffer_lines() -> usize {
+    20_000
+}
+
 fn default_permgen() -> u32 {
     256
 }
@@ -707,3 +1175,90 @@ fn default_game_width() -> u32 {
 fn default_game_height() -> u32 {
     480
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_without_secrets_keeps_current_secrets_on_import() {
+        let config = Config {
+            theme: "dark".to_string(),
+            api_keys: ApiKeys {
+                curseforge_api_key: Some("exported-key".to_string()),
+                ..ApiKeys::default()
+            },
+            ..Config::default()
+        };
+
+        let export = config.to_export(false).unwrap();
+        assert!(export.config["api_keys"]["curseforge_api_key"].is_null());
+
+        let current = Config {
+            api_keys: ApiKeys {
+                curseforge_api_key: Some("local-key".to_string()),
+                ..ApiKeys::default()
+            },
+            ..Config::default()
+        };
+
+        let imported = Config::from_export(export, &current, false).unwrap();
+        assert_eq!(imported.theme, "dark");
+        assert_eq!(imported.api_keys.curseforge_api_key.as_deref(), Some("local-key"));
+    }
+
+    #[test]
+    fn test_export_without_secrets_keeps_current_proxy_credentials() {
+        let proxy = |username: Option<&str>, password: Option<&str>| ProxyConfig {
+            proxy_type: ProxyType::Http,
+            host: "proxy.example".to_string(),
+            port: 8080,
+            username: username.map(str::to_string),
+            password: password.map(str::to_string),
+        };
+        let mut config = Config::default();
+        config.network.proxy = Some(proxy(Some("exported-user"), Some("exported-pass")));
+
+        let export = config.to_export(false).unwrap();
+        assert!(export.config["network"]["proxy"]["password"].is_null());
+
+        let mut current = Config::default();
+        current.network.proxy = Some(proxy(Some("local-user"), Some("local-pass")));
+
+        let imported = Config::from_export(export, &current, false).unwrap();
+        let imported_proxy = imported.network.proxy.unwrap();
+        assert_eq!(imported_proxy.host, "proxy.example");
+        assert_eq!(imported_proxy.username.as_deref(), Some("local-user"));
+        assert_eq!(imported_proxy.password.as_deref(), Some("local-pass"));
+    }
+
+    #[test]
+    fn test_import_resets_machine_paths() {
+        let mut config = Config {
+            data_dir: PathBuf::from("/old/machine/data"),
+            assets_dir: Some(PathBuf::from("/old/machine/assets")),
+            ..Config::default()
+        };
+        config.files.shared_screenshots_dir = Some(PathBuf::from("/old/machine/screenshots"));
+        config.files.shared_saves_dir = Some(PathBuf::from("/old/machine/saves"));
+
+        let export = config.to_export(true).unwrap();
+        let current = Config::default();
+
+        let imported = Config::from_export(export.clone(), &current, true).unwrap();
+        assert_eq!(imported.data_dir, current.data_dir);
+        assert_eq!(imported.assets_dir, None);
+        assert_eq!(imported.files.shared_screenshots_dir, None);
+        assert_eq!(imported.files.shared_saves_dir, None);
+
+        let kept = Config::from_export(export, &current, false).unwrap();
+        assert_eq!(kept.data_dir, PathBuf::from("/old/machine/data"));
+    }
+
+    #[test]
+    fn test_import_rejects_newer_format() {
+        let mut export = Config::default().to_export(true).unwrap();
+        export.format_version = SETTINGS_EXPORT_VERSION + 1;
+        assert!(Config::from_export(export, &Config::default(), false).is_err());
+    }
+}
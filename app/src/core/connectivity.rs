@@ -0,0 +1,165 @@
+//! Reachability checks for the online services the launcher depends on.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::core::accounts::skins::MC_SERVICES_URL;
+use crate::core::meta::MetaClient;
+use crate::core::minecraft::version::VERSION_MANIFEST_URL;
+use crate::core::modplatform::curseforge::CURSEFORGE_API_URL;
+use crate::core::modplatform::modrinth::MODRINTH_API_URL;
+
+/// How long a probe waits before calling a service unreachable
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// An online service to probe
+#[derive(Debug, Clone)]
+pub struct Service {
+    /// Stable identifier the frontend keys on
+    pub id: &'static str,
+    /// Display name
+    pub name: &'static str,
+    pub url: String,
+}
+
+/// The services the launcher talks to: Mojang for versions and game files,
+/// Minecraft services for login, the mod platforms, and the launcher's own
+/// metadata server for mod loader versions
+pub fn default_services() -> Vec<Service> {
+    vec![
+        Service { id: "mojang", name: "Mojang", url: VERSION_MANIFEST_URL.to_string() },
+        Service { id: "minecraft_services", name: "Minecraft login", url: MC_SERVICES_URL.to_string() },
+        Service { id: "modrinth", name: "Modrinth", url: MODRINTH_API_URL.to_string() },
+        Service { id: "curseforge", name: "CurseForge", url: CURSEFORGE_API_URL.to_string() },
+        Service {
+            id: "meta",
+            name: "Oxide meta server",
+            url: format!("{}/index.json", MetaClient::default().base_url()),
+        },
+    ]
+}
+
+/// Result of probing one service
+#[derive(Debug, Clone, Serialize)]
+pub struct ServiceStatus {
+    pub id: String,
+    pub name: String,
+    pub url: String,
+    /// The service answered without a server error. Client errors count as
+    /// reachable, since some endpoints refuse requests without an API key.
+    pub reachable: bool,
+    /// HTTP status, if the service answered
+    pub status: Option<u16>,
+    /// Time until the response headers arrived
+    pub latency_ms: Option<u64>,
+    /// Why the service is unreachable
+    pub error: Option<String>,
+}
+
+/// Probe one service with a GET request
+async fn probe(client: &reqwest::Client, service: &Service) -> ServiceStatus {
+    let started = Instant::now();
+    let response = client.get(&service.url).send().await;
+    let latency_ms = started.elapsed().as_millis() as u64;
+    
+    let (reachable, status, latency_ms, error) = match response {
+        Ok(response) if response.status().is_server_error() => (
+            false,
+            Some(response.status().as_u16()),
+            Some(latency_ms),
+            Some(format!("Server error {}", response.status())),
+        ),
+        Ok(response) => (true, Some(response.status().as_u16()), Some(latency_ms), None),
+        Err(e) if e.is_timeout() => (false, None, None, Some(format!(
+            "No response within {} seconds", PROBE_TIMEOUT.as_secs()
+        ))),
+        Err(e) => (false, None, None, Some(e.to_string())),
+    };
+    
+    ServiceStatus {
+        id: service.id.to_string(),
+        name: service.name.to_string(),
+        url: service.url.clone(),
+        reachable,
+        status,
+        latency_ms,
+        error,
+    }
+}
+
+/// Probe services concurrently, returning their statuses in the given order
+pub async fn check_services(services: &[Service], user_agent: &str) -> Vec<ServiceStatus> {
+    let client = reqwest::Client::builder()
+        .user_agent(user_agent)
+        .timeout(PROBE_TIMEOUT)
+        .build()
+        .unwrap_or_else(|_| reqwest::Client::new());
+    
+    futures::future::join_all(services.iter().map(|service| probe(&client, service))).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    
+    /// Answer a single request on a local port with the given status line
+    async fn serve_once(status: &'static str) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0u8; 1024];
+            let _ = socket.read(&mut buf).await;
+            let response = format!("HTTP/1.1 {}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n", status);
+            let _ = socket.write_all(response.as_bytes()).await;
+        });
+        
+        format!("http://{}/", addr)
+    }
+    
+    #[tokio::test]
+    async fn classifies_responses_and_failures() {
+        // Bound then dropped, so nothing is listening there
+        let closed = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap().local_addr().unwrap();
+        let services = vec![
+            Service { id: "up", name: "Up", url: serve_once("200 OK").await },
+            Service { id: "keyed", name: "Keyed", url: serve_once("403 Forbidden").await },
+            Service { id: "down", name: "Down", url: serve_once("503 Service Unavailable").await },
+            Service { id: "closed", name: "Closed", url: format!("http://{}/", closed) },
+        ];
+        
+        let statuses = check_services(&services, "OxideLauncher/test").await;
+        let summary: Vec<(&str, bool, Option<u16>)> = statuses.iter()
+            .map(|s| (s.id.as_str(), s.reachable, s.status))
+            .collect();
+        assert_eq!(summary, vec![
+            ("up", true, Some(200)),
+            ("keyed", true, Some(403)),
+            ("down", false, Some(503)),
+            ("closed", false, None),
+        ]);
+        assert!(statuses[0].latency_ms.is_some());
+        assert!(statuses[3].error.is_some());
+    }
+}
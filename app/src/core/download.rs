@@ -20,20 +20,46 @@
 
 #![allow(dead_code)] // Download types will be used as features are completed
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 use tokio::sync::mpsc;
 use futures::StreamExt;
+use crate::core::config::{NetworkConfig, ProxyType};
 use crate::core::error::{OxideError, Result};
 
 /// Download progress event
 #[derive(Debug, Clone)]
 pub enum DownloadProgress {
     Started { url: String, total_size: Option<u64> },
-    Progress { url: String, downloaded: u64, total: Option<u64> },
+    Progress { url: String, dest: PathBuf, downloaded: u64, total: Option<u64> },
     Completed { url: String },
     Failed { url: String, error: String },
-    Retrying { url: String, attempt: u32, max_retries: u32, error: String },
+    Retrying { url: String, dest: PathBuf, attempt: u32, max_retries: u32, error: String },
+}
+
+/// Build an HTTP client from the network settings: request timeout, user
+/// agent and the configured HTTP or SOCKS5 proxy
+pub fn http_client(network: &NetworkConfig) -> Result<reqwest::Client> {
+    let mut builder = reqwest::Client::builder()
+        .timeout(Duration::from_secs(network.timeout_seconds))
+        .user_agent(network.user_agent.as_str());
+    
+    if let Some(proxy) = &network.proxy {
+        let scheme = match proxy.proxy_type {
+            ProxyType::Http => "http",
+            // socks5h resolves hostnames through the proxy as well
+            ProxyType::Socks5 => "socks5h",
+        };
+        let mut proxy_config = reqwest::Proxy::all(format!("{}://{}:{}", scheme, proxy.host, proxy.port))
+            .map_err(|e| OxideError::Config(format!("Invalid proxy: {}", e)))?;
+        if let Some(username) = proxy.username.as_deref().filter(|u| !u.is_empty()) {
+            proxy_config = proxy_config.basic_auth(username, proxy.password.as_deref().unwrap_or(""));
+        }
+        builder = builder.proxy(proxy_config);
+    }
+    
+    Ok(builder.build()?)
 }
 
 /// Download options
@@ -67,12 +93,39 @@ pub async fn download_file_with_options(
     dest: &PathBuf,
     progress_tx: Option<mpsc::Sender<DownloadProgress>>,
     options: DownloadOptions,
+) -> Result<()> {
+    download_with_retries(url, dest, progress_tx, options, false, None).await
+}
+
+/// Download a file that must be binary (mod JARs, pack archives).
+///
+/// Some hosts (notably CurseForge for distribution-disabled projects) answer with
+/// a 403 or an HTML page instead of the file. Those responses are rejected with
+/// `OxideError::DownloadBlocked` before anything is written to `dest`.
+pub async fn download_binary_file(
+    url: &str,
+    dest: &PathBuf,
+    progress_tx: Option<mpsc::Sender<DownloadProgress>>,
+) -> Result<()> {
+    download_with_retries(url, dest, progress_tx, DownloadOptions::default(), true, None).await
+}
+
+/// Retry loop shared by the download entry points
+async fn download_with_retries(
+    url: &str,
+    dest: &PathBuf,
+    progress_tx: Option<mpsc::Sender<DownloadProgress>>,
+    options: DownloadOptions,
+    require_binary: bool,
+    expected_sha1: Option<&str>,
 ) -> Result<()> {
     let mut last_error = None;
     
     for attempt in 0..=options.retries {
-        match download_file_inner(url, dest, progress_tx.clone(), options.timeout_seconds).await {
+        match download_file_inner(url, dest, progress_tx.clone(), options.timeout_seconds, require_binary, expected_sha1).await {
             Ok(()) => return Ok(()),
+            // Retrying won't turn a blocked page into the file
+            Err(e @ OxideError::DownloadBlocked(_)) => return Err(e),
             Err(e) => {
                 last_error = Some(e.to_string());
                 
@@ -81,6 +134,7 @@ pub async fn download_file_with_options(
                     if let Some(tx) = &progress_tx {
                         let _ = tx.send(DownloadProgress::Retrying {
                             url: url.to_string(),
+                            dest: dest.clone(),
                             attempt: attempt + 1,
                             max_retries: options.retries,
                             error: last_error.clone().unwrap_or_default(),
@@ -111,12 +165,19 @@ pub async fn download_file_with_options(
     )))
 }
 
-/// Internal download function (single attempt)
+/// Internal download function (single attempt).
+///
+/// Writes to `<dest>.part` and only renames it into place once the download
+/// is complete and, when `expected_sha1` is given, its hash matches. The
+/// partial file is removed on any failure, so `dest` is either the previous
+/// file or a complete, verified one.
 async fn download_file_inner(
     url: &str,
     dest: &PathBuf,
     progress_tx: Option<mpsc::Sender<DownloadProgress>>,
     timeout_seconds: u64,
+    require_binary: bool,
+    expected_sha1: Option<&str>,
 ) -> Result<()> {
     // Ensure parent directory exists
     if let Some(parent) = dest.parent() {
@@ -129,6 +190,23 @@ async fn download_file_inner(
     
     let response = client.get(url).send().await?;
     
+    if require_binary {
+        if response.status() == reqwest::StatusCode::FORBIDDEN {
+            return Err(OxideError::DownloadBlocked(format!("HTTP 403 for {}", url)));
+        }
+        
+        let is_html = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.to_ascii_lowercase().contains("text/html"))
+            .unwrap_or(false);
+        
+        if is_html {
+            return Err(OxideError::DownloadBlocked(format!("HTML page returned for {}", url)));
+        }
+    }
+    
     if !response.status().is_success() {
         return Err(OxideError::Download(format!(
             "HTTP error {}: {}",
@@ -146,35 +224,143 @@ async fn download_file_inner(
         }).await;
     }
 
-    let mut file = tokio::fs::File::create(dest).await?;
+    let mut stream = Box::pin(response.bytes_stream().peekable());
+    
+    // Servers don't always label HTML correctly, so sniff the body before creating the file
+    if require_binary {
+        if let Some(Ok(first)) = stream.as_mut().peek().await {
+            if looks_like_html(first) {
+                return Err(OxideError::DownloadBlocked(format!("HTML page returned for {}", url)));
+            }
+        }
+    }
+    
+    let part_path = part_file_path(dest);
+    let written = write_stream(url, dest, &mut stream, &part_path, total_size, &progress_tx).await;
+    let verified = written.and_then(|actual_hash| match expected_sha1 {
+        Some(expected) if !actual_hash.eq_ignore_ascii_case(expected) => Err(OxideError::Download(format!(
+            "SHA1 mismatch for {}: expected {}, got {}. The download is corrupt and was discarded",
+            url, expected, actual_hash
+        ))),
+        _ => Ok(()),
+    });
+    if let Err(e) = verified.and_then(|()| std::fs::rename(&part_path, dest).map_err(Into::into)) {
+        let _ = std::fs::remove_file(&part_path);
+        return Err(e);
+    }
+
+    if let Some(tx) = &progress_tx {
+        let _ = tx.send(DownloadProgress::Completed {
+            url: url.to_string(),
+        }).await;
+    }
+
+    Ok(())
+}
+
+/// Temporary path a download is written to before it's moved into place.
+/// Unique per call, so two downloads of the same file never write into each
+/// other's partial file.
+fn part_file_path(dest: &Path) -> PathBuf {
+    let mut name = dest.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".{}.part", &uuid::Uuid::new_v4().simple().to_string()[..8]));
+    dest.with_file_name(name)
+}
+
+/// Write a response body to `path`, returning the SHA1 of what was written
+async fn write_stream<S>(
+    url: &str,
+    dest: &Path,
+    stream: &mut S,
+    path: &PathBuf,
+    total_size: Option<u64>,
+    progress_tx: &Option<mpsc::Sender<DownloadProgress>>,
+) -> Result<String>
+where
+    S: futures::Stream<Item = reqwest::Result<bytes::Bytes>> + Unpin,
+{
+    use sha1::{Sha1, Digest};
+    
+    let mut file = tokio::fs::File::create(path).await?;
+    let mut hasher = Sha1::new();
     let mut downloaded: u64 = 0;
-    let mut stream = response.bytes_stream();
     
     while let Some(chunk) = stream.next().await {
         let chunk = chunk?;
         tokio::io::AsyncWriteExt::write_all(&mut file, &chunk).await?;
+        hasher.update(&chunk);
         
         downloaded += chunk.len() as u64;
         
-        if let Some(tx) = &progress_tx {
+        if let Some(tx) = progress_tx {
             let _ = tx.send(DownloadProgress::Progress {
                 url: url.to_string(),
+                dest: dest.to_path_buf(),
                 downloaded,
                 total: total_size,
             }).await;
         }
     }
+    
+    // tokio writes in the background; make sure the file is complete before
+    // it's renamed into place
+    tokio::io::AsyncWriteExt::flush(&mut file).await?;
+    
+    Ok(format!("{:x}", hasher.finalize()))
+}
 
-    if let Some(tx) = &progress_tx {
-        let _ = tx.send(DownloadProgress::Completed {
-            url: url.to_string(),
-        }).await;
+/// Look up a file's size without downloading it, from the `Content-Length`
+/// of a HEAD request (redirects are followed). None when the server doesn't
+/// report one, so callers can fall back to indeterminate progress.
+pub async fn fetch_content_length(url: &str) -> Option<u64> {
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(DownloadOptions::default().timeout_seconds))
+        .build()
+        .ok()?;
+    
+    let response = client.head(url).send().await.ok()?;
+    if !response.status().is_success() {
+        return None;
     }
+    
+    // reqwest sizes a HEAD response by its (empty) body, so read the header itself
+    response.headers()
+        .get(reqwest::header::CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .filter(|&len| len > 0)
+}
 
-    Ok(())
+/// Total size of a set of downloads given as (url, known size) pairs.
+/// Unknown sizes are looked up with HEAD requests, up to `max_concurrent`
+/// at a time, and skipped once `cancel` is set. None if any file's size
+/// can't be determined.
+pub async fn fetch_total_size(
+    files: &[(String, Option<u64>)],
+    max_concurrent: usize,
+    cancel: Option<&AtomicBool>,
+) -> Option<u64> {
+    // Owned items keep the stream's futures Send for any lifetime, which
+    // Tauri's command futures require
+    let sizes: Vec<Option<u64>> = futures::stream::iter(files.to_vec())
+        .map(|(url, size)| async move {
+            match size {
+                Some(size) => Some(size),
+                // Probes still queued when the caller cancels are skipped
+                None if cancel.is_some_and(|cancel| cancel.load(Ordering::Relaxed)) => None,
+                None => fetch_content_length(&url).await,
+            }
+        })
+        .buffer_unordered(max_concurrent.max(1))
+        .collect()
+        .await;
+    
+    sizes.into_iter().sum()
 }
 
-/// Download a file and verify its SHA1 hash
+/// Download a file and verify its SHA1 hash as it's written. On a mismatch
+/// the download is retried, and if it never matches an error is returned
+/// and nothing is left at `dest`.
 pub async fn download_file_verified(
     url: &str,
     dest: &PathBuf,
@@ -192,22 +378,21 @@ pub async fn download_file_verified_with_options(
     progress_tx: Option<mpsc::Sender<DownloadProgress>>,
     options: DownloadOptions,
 ) -> Result<()> {
-    download_file_with_options(url, dest, progress_tx.clone(), options).await?;
-    
-    // Verify hash
-    if !expected_sha1.is_empty() {
-        let actual_hash = compute_sha1(dest)?;
-        if actual_hash != expected_sha1 {
-            // Delete the file if hash doesn't match
-            let _ = std::fs::remove_file(dest);
-            return Err(OxideError::Download(format!(
-                "SHA1 mismatch for {}: expected {}, got {}",
-                url, expected_sha1, actual_hash
-            )));
-        }
-    }
+    let expected_sha1 = Some(expected_sha1).filter(|sha1| !sha1.is_empty());
+    download_with_retries(url, dest, progress_tx, options, false, expected_sha1).await
+}
+
+/// Check whether the start of a response body is an HTML document
+pub fn looks_like_html(bytes: &[u8]) -> bool {
+    let bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes);
+    let start = bytes.iter().position(|b| !b.is_ascii_whitespace()).unwrap_or(bytes.len());
+    let head: Vec<u8> = bytes[start..]
+        .iter()
+        .take(15)
+        .map(|b| b.to_ascii_lowercase())
+        .collect();
     
-    Ok(())
+    head.starts_with(b"<!doctype html") || head.starts_with(b"<html")
 }
 
 /// Compute SHA1 hash of a file
@@ -303,3 +488,106 @@ impl DownloadTask {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    
+    /// Serve a single canned HTTP response on a local port and return its URL
+    async fn serve_once(content_type: &'static str, body: &'static str) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0u8; 1024];
+            let _ = socket.read(&mut buf).await;
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                content_type,
+                body.len(),
+                body
+            );
+            let _ = socket.write_all(response.as_bytes()).await;
+        });
+        
+        format!("http://{}/mod.jar", addr)
+    }
+    
+    #[test]
+    fn test_looks_like_html() {
+        assert!(looks_like_html(b"<!DOCTYPE html><html></html>"));
+        assert!(looks_like_html(b"\n  <html lang=\"en\">"));
+        assert!(looks_like_html(b"\xEF\xBB\xBF<!doctype HTML>"));
+        assert!(!looks_like_html(b"PK\x03\x04"));
+        assert!(!looks_like_html(b""));
+    }
+    
+    #[tokio::test]
+    async fn test_html_response_is_never_written_as_jar() {
+        let dir = tempfile::tempdir().unwrap();
+        
+        // Labelled as HTML
+        let dest = dir.path().join("labelled.jar");
+        let url = serve_once("text/html; charset=utf-8", "<html><body>Download</body></html>").await;
+        let result = download_binary_file(&url, &dest, None).await;
+        assert!(matches!(result, Err(OxideError::DownloadBlocked(_))));
+        assert!(!dest.exists());
+        
+        // HTML body behind a binary content type
+        let dest = dir.path().join("mislabelled.jar");
+        let url = serve_once("application/java-archive", "<!DOCTYPE html><html></html>").await;
+        let result = download_binary_file(&url, &dest, None).await;
+        assert!(matches!(result, Err(OxideError::DownloadBlocked(_))));
+        assert!(!dest.exists());
+    }
+    
+    #[tokio::test]
+    async fn test_binary_response_is_written() {
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("real.jar");
+        let url = serve_once("application/java-archive", "PK\x03\x04jar-bytes").await;
+        
+        download_binary_file(&url, &dest, None).await.unwrap();
+        assert_eq!(std::fs::read(&dest).unwrap(), b"PK\x03\x04jar-bytes");
+    }
+    
+    #[tokio::test]
+    async fn test_sha1_mismatch_leaves_no_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("library.jar");
+        let url = serve_once("application/java-archive", "corrupted").await;
+        let options = DownloadOptions { timeout_seconds: 5, retries: 0 };
+        
+        let result = download_file_verified_with_options(&url, &dest, "0000000000000000000000000000000000000000", None, options).await;
+        assert!(result.unwrap_err().to_string().contains("SHA1 mismatch"));
+        assert!(!dest.exists());
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+    
+    #[tokio::test]
+    async fn test_sha1_match_is_moved_into_place() {
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("library.jar");
+        let url = serve_once("application/java-archive", "library").await;
+        let options = DownloadOptions { timeout_seconds: 5, retries: 0 };
+        
+        let expected = {
+            use sha1::{Sha1, Digest};
+            format!("{:x}", Sha1::digest(b"library"))
+        };
+        download_file_verified_with_options(&url, &dest, &expected, None, options).await.unwrap();
+        assert_eq!(std::fs::read(&dest).unwrap(), b"library");
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+    
+    #[test]
+    fn test_part_files_are_unique_per_download() {
+        let dest = PathBuf::from("/objects/ab/abcdef");
+        let (a, b) = (part_file_path(&dest), part_file_path(&dest));
+        assert_ne!(a, b);
+        assert_eq!(a.parent(), dest.parent());
+        assert!(a.to_string_lossy().ends_with(".part"));
+    }
+}
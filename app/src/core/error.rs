@@ -36,6 +36,9 @@ pub enum OxideError {
     #[error("Download error: {0}")]
     Download(String),
 
+    #[error("Download blocked: {0}")]
+    DownloadBlocked(String),
+
     #[error("Authentication error: {0}")]
     Auth(String),
 
@@ -0,0 +1,271 @@
+//! Shareable instance summary card rendering.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::io::Cursor;
+use std::path::Path;
+
+use image::imageops::{self, FilterType};
+use image::{ImageFormat, Rgba, RgbaImage};
+
+use crate::core::error::{OxideError, Result};
+
+/// Card dimensions in pixels
+pub const CARD_WIDTH: u32 = 640;
+pub const CARD_HEIGHT: u32 = 200;
+
+const ICON_SIZE: u32 = 128;
+const PADDING: u32 = 36;
+const TEXT_X: u32 = PADDING + ICON_SIZE + 32;
+
+/// Glyphs are 5x7 pixels with one column of spacing
+const GLYPH_WIDTH: u32 = 5;
+const GLYPH_HEIGHT: u32 = 7;
+const GLYPH_ADVANCE: u32 = GLYPH_WIDTH + 1;
+
+const BACKGROUND: Rgba<u8> = Rgba([30, 30, 36, 255]);
+const ACCENT: Rgba<u8> = Rgba([232, 98, 44, 255]);
+const TEXT: Rgba<u8> = Rgba([240, 240, 240, 255]);
+const TEXT_DIM: Rgba<u8> = Rgba([150, 150, 160, 255]);
+
+/// What goes on an instance card
+#[derive(Debug, Clone)]
+pub struct InstanceCard<'a> {
+    pub name: &'a str,
+    pub minecraft_version: &'a str,
+    /// Loader name and version, e.g. "Fabric 0.16.5"; `None` for vanilla
+    pub loader: Option<String>,
+    pub mod_count: usize,
+    /// Instance icon file; a built-in icon is drawn when missing or unreadable
+    pub icon: Option<&'a Path>,
+}
+
+/// Draw the card. Text uses a built-in pixel font, so it needs no font files;
+/// other scripts are transliterated to ASCII first ("Café" becomes "Cafe") and
+/// anything left over is drawn as `?`.
+pub fn render_card(card: &InstanceCard) -> RgbaImage {
+    let mut image = RgbaImage::from_pixel(CARD_WIDTH, CARD_HEIGHT, BACKGROUND);
+    fill_rect(&mut image, 0, 0, 8, CARD_HEIGHT, ACCENT);
+    
+    let icon = card.icon
+        .and_then(|path| image::open(path).ok())
+        .map(|icon| {
+            // Pixel-art icons stay crisp; larger images are smoothed
+            let filter = if icon.width() < ICON_SIZE { FilterType::Nearest } else { FilterType::Triangle };
+            icon.resize_exact(ICON_SIZE, ICON_SIZE, filter).to_rgba8()
+        })
+        .unwrap_or_else(default_icon);
+    let icon_y = (CARD_HEIGHT - ICON_SIZE) / 2;
+    imageops::overlay(&mut image, &icon, PADDING as i64, icon_y as i64);
+    
+    let max_width = CARD_WIDTH - TEXT_X - PADDING;
+    let mut y = icon_y;
+    let name = transliterate(card.name);
+    y += draw_text(&mut image, &fit_text(&name, 4, max_width), TEXT_X, y, 4, TEXT) + 14;
+    let version = format!("Minecraft {}", transliterate(card.minecraft_version));
+    y += draw_text(&mut image, &fit_text(&version, 2, max_width), TEXT_X, y, 2, TEXT) + 8;
+    let loader = card.loader.as_deref().map(transliterate).unwrap_or_else(|| "Vanilla".to_string());
+    y += draw_text(&mut image, &fit_text(&loader, 2, max_width), TEXT_X, y, 2, TEXT) + 8;
+    let mods = match card.mod_count {
+        1 => "1 mod".to_string(),
+        count => format!("{} mods", count),
+    };
+    draw_text(&mut image, &mods, TEXT_X, y, 2, TEXT);
+    
+    let footer = "Oxide Launcher";
+    let footer_x = CARD_WIDTH - PADDING - text_width(footer, 1);
+    draw_text(&mut image, footer, footer_x, CARD_HEIGHT - 16, 1, TEXT_DIM);
+    
+    image
+}
+
+/// Encode a rendered card as PNG
+pub fn encode_png(image: &RgbaImage) -> Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    image.write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
+        .map_err(|e| OxideError::Other(format!("Failed to encode card: {}", e)))?;
+    Ok(bytes)
+}
+
+/// Spell text with ASCII so the pixel font can draw it, keeping `?` for
+/// characters with no transliteration
+fn transliterate(text: &str) -> String {
+    deunicode::deunicode_with_tofu(text, "?").trim().to_string()
+}
+
+/// Width of a line of text at the given scale
+fn text_width(text: &str, scale: u32) -> u32 {
+    let chars = text.chars().count() as u32;
+    (chars * GLYPH_ADVANCE).saturating_sub(1) * scale
+}
+
+/// Shorten text with ".." so it fits in `max_width`
+fn fit_text(text: &str, scale: u32, max_width: u32) -> String {
+    if text_width(text, scale) <= max_width {
+        return text.to_string();
+    }
+    let max_chars = ((max_width / scale + 1) / GLYPH_ADVANCE) as usize;
+    let kept: String = text.chars().take(max_chars.saturating_sub(2)).collect();
+    format!("{}..", kept.trim_end())
+}
+
+/// Draw a line of text and return its height
+fn draw_text(image: &mut RgbaImage, text: &str, x: u32, y: u32, scale: u32, color: Rgba<u8>) -> u32 {
+    for (index, c) in text.chars().enumerate() {
+        let glyph_x = x + index as u32 * GLYPH_ADVANCE * scale;
+        for (row, bits) in glyph(c).iter().enumerate() {
+            for col in 0..GLYPH_WIDTH {
+                if bits & (1 << (GLYPH_WIDTH - 1 - col)) != 0 {
+                    fill_rect(image, glyph_x + col * scale, y + row as u32 * scale, scale, scale, color);
+                }
+            }
+        }
+    }
+    GLYPH_HEIGHT * scale
+}
+
+/// Fill a rectangle, clipped to the image
+fn fill_rect(image: &mut RgbaImage, x: u32, y: u32, width: u32, height: u32, color: Rgba<u8>) {
+    for py in y..(y + height).min(image.height()) {
+        for px in x..(x + width).min(image.width()) {
+            image.put_pixel(px, py, color);
+        }
+    }
+}
+
+/// A grass block, drawn when the instance has no icon
+fn default_icon() -> RgbaImage {
+    const GRASS: [Rgba<u8>; 2] = [Rgba([95, 159, 53, 255]), Rgba([77, 134, 41, 255])];
+    const DIRT: [Rgba<u8>; 2] = [Rgba([134, 96, 67, 255]), Rgba([110, 78, 54, 255])];
+    // Which rows are grass, and where the darker pixels go
+    const PATTERN: [u8; 8] = [
+        0b0010_0100, 0b1000_0001, 0b0100_1010, 0b0001_0000,
+        0b1000_0100, 0b0010_0001, 0b0100_1000, 0b0001_0010,
+    ];
+    
+    let mut small = RgbaImage::new(8, 8);
+    for (y, bits) in PATTERN.iter().enumerate() {
+        for x in 0..8u32 {
+            // Grass hangs a little further down on alternate columns
+            let palette = if y < 2 || (y == 2 && x % 3 == 0) { GRASS } else { DIRT };
+            let shade = (bits >> (7 - x)) & 1;
+            small.put_pixel(x, y as u32, palette[shade as usize]);
+        }
+    }
+    imageops::resize(&small, ICON_SIZE, ICON_SIZE, FilterType::Nearest)
+}
+
+/// Rows of a 5x7 glyph, most significant bit on the left. Letters are drawn
+/// in upper case.
+fn glyph(c: char) -> [u8; 7] {
+    match c.to_ascii_uppercase() {
+        'A' => [0b01110, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001],
+        'B' => [0b11110, 0b10001, 0b10001, 0b11110, 0b10001, 0b10001, 0b11110],
+        'C' => [0b01110, 0b10001, 0b10000, 0b10000, 0b10000, 0b10001, 0b01110],
+        'D' => [0b11110, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b11110],
+        'E' => [0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b11111],
+        'F' => [0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b10000],
+        'G' => [0b01110, 0b10001, 0b10000, 0b10111, 0b10001, 0b10001, 0b01111],
+        'H' => [0b10001, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001],
+        'I' => [0b01110, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110],
+        'J' => [0b00111, 0b00010, 0b00010, 0b00010, 0b00010, 0b10010, 0b01100],
+        'K' => [0b10001, 0b10010, 0b10100, 0b11000, 0b10100, 0b10010, 0b10001],
+        'L' => [0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b11111],
+        'M' => [0b10001, 0b11011, 0b10101, 0b10101, 0b10001, 0b10001, 0b10001],
+        'N' => [0b10001, 0b10001, 0b11001, 0b10101, 0b10011, 0b10001, 0b10001],
+        'O' => [0b01110, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110],
+        'P' => [0b11110, 0b10001, 0b10001, 0b11110, 0b10000, 0b10000, 0b10000],
+        'Q' => [0b01110, 0b10001, 0b10001, 0b10001, 0b10101, 0b10010, 0b01101],
+        'R' => [0b11110, 0b10001, 0b10001, 0b11110, 0b10100, 0b10010, 0b10001],
+        'S' => [0b01111, 0b10000, 0b10000, 0b01110, 0b00001, 0b00001, 0b11110],
+        'T' => [0b11111, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100],
+        'U' => [0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110],
+        'V' => [0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01010, 0b00100],
+        'W' => [0b10001, 0b10001, 0b10001, 0b10101, 0b10101, 0b10101, 0b01010],
+        'X' => [0b10001, 0b10001, 0b01010, 0b00100, 0b01010, 0b10001, 0b10001],
+        'Y' => [0b10001, 0b10001, 0b10001, 0b01010, 0b00100, 0b00100, 0b00100],
+        'Z' => [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b11111],
+        '0' => [0b01110, 0b10001, 0b10011, 0b10101, 0b11001, 0b10001, 0b01110],
+        '1' => [0b00100, 0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110],
+        '2' => [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b01000, 0b11111],
+        '3' => [0b11111, 0b00010, 0b00100, 0b00010, 0b00001, 0b10001, 0b01110],
+        '4' => [0b00010, 0b00110, 0b01010, 0b10010, 0b11111, 0b00010, 0b00010],
+        '5' => [0b11111, 0b10000, 0b11110, 0b00001, 0b00001, 0b10001, 0b01110],
+        '6' => [0b00110, 0b01000, 0b10000, 0b11110, 0b10001, 0b10001, 0b01110],
+        '7' => [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b01000, 0b01000],
+        '8' => [0b01110, 0b10001, 0b10001, 0b01110, 0b10001, 0b10001, 0b01110],
+        '9' => [0b01110, 0b10001, 0b10001, 0b01111, 0b00001, 0b00010, 0b01100],
+        ' ' => [0; 7],
+        '.' => [0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b01100, 0b01100],
+        ',' => [0b00000, 0b00000, 0b00000, 0b00000, 0b01100, 0b00100, 0b01000],
+        '-' => [0b00000, 0b00000, 0b00000, 0b11111, 0b00000, 0b00000, 0b00000],
+        '_' => [0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b11111],
+        '+' => [0b00000, 0b00100, 0b00100, 0b11111, 0b00100, 0b00100, 0b00000],
+        ':' => [0b00000, 0b01100, 0b01100, 0b00000, 0b01100, 0b01100, 0b00000],
+        '/' => [0b00000, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b00000],
+        '(' => [0b00010, 0b00100, 0b01000, 0b01000, 0b01000, 0b00100, 0b00010],
+        ')' => [0b01000, 0b00100, 0b00010, 0b00010, 0b00010, 0b00100, 0b01000],
+        '\'' => [0b00100, 0b00100, 0b01000, 0b00000, 0b00000, 0b00000, 0b00000],
+        '!' => [0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00000, 0b00100],
+        '&' => [0b01100, 0b10010, 0b10100, 0b01000, 0b10101, 0b10010, 0b01101],
+        _ => [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b00000, 0b00100],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn renders_card_with_default_icon() {
+        let card = InstanceCard {
+            name: "A very long modpack name that will not fit",
+            minecraft_version: "1.21.1",
+            loader: Some("Fabric 0.16.5".to_string()),
+            mod_count: 42,
+            icon: Some(Path::new("/nonexistent/icon.png")),
+        };
+        let image = render_card(&card);
+        
+        assert_eq!(image.dimensions(), (CARD_WIDTH, CARD_HEIGHT));
+        assert_eq!(*image.get_pixel(2, 2), ACCENT);
+        // Top of the fallback grass block
+        let icon_y = (CARD_HEIGHT - ICON_SIZE) / 2;
+        assert_ne!(*image.get_pixel(PADDING + 1, icon_y + 1), BACKGROUND);
+        assert!(image.pixels().any(|p| *p == TEXT));
+        
+        let png = encode_png(&image).unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+    }
+    
+    #[test]
+    fn long_text_is_shortened_to_fit() {
+        let fitted = fit_text("A very long modpack name that will not fit", 4, 400);
+        assert!(fitted.ends_with(".."));
+        assert!(text_width(&fitted, 4) <= 400);
+        assert_eq!(fit_text("Short", 4, 400), "Short");
+    }
+    
+    #[test]
+    fn non_ascii_text_is_transliterated() {
+        assert_eq!(transliterate("Café Créatif"), "Cafe Creatif");
+        assert!(transliterate("Über Pack").is_ascii());
+        assert!(transliterate("日本のパック").is_ascii());
+    }
+}
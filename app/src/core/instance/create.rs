@@ -22,7 +22,7 @@
 
 use std::path::PathBuf;
 use crate::core::error::{OxideError, Result};
-use super::{Instance, InstanceConfig};
+use super::{create_game_dirs, Instance, InstanceConfig};
 
 /// Create a new instance
 pub async fn create_instance(
@@ -52,11 +52,7 @@ pub async fn create_instance(
     std::fs::create_dir_all(&instance_path)?;
 
     // Create the basic directory structure
-    let game_dir = instance_path.join(".minecraft");
-    std::fs::create_dir_all(&game_dir)?;
-    std::fs::create_dir_all(game_dir.join("mods"))?;
-    std::fs::create_dir_all(game_dir.join("resourcepacks"))?;
-    std::fs::create_dir_all(game_dir.join("saves"))?;
+    create_game_dirs(&instance_path)?;
 
     // Create the instance
     let mut instance = Instance::new(
@@ -0,0 +1,131 @@
+//! Demo lock: forcing an instance to launch in demo mode.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::InstanceSettings;
+use crate::core::error::{OxideError, Result};
+use sha2::{Digest, Sha256};
+
+/// Hash a demo lock PIN with a salt
+fn hash_pin(salt: &str, pin: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(salt.as_bytes());
+    hasher.update(b":");
+    hasher.update(pin.as_bytes());
+    hex::encode(hasher.finalize())
+}
+
+/// Store a PIN as `salt:hash` with a random salt, so the lock keeps working
+/// when its settings are copied to another instance
+fn store_pin(pin: &str) -> String {
+    let salt = uuid::Uuid::new_v4().simple().to_string();
+    format!("{}:{}", salt, hash_pin(&salt, pin))
+}
+
+/// The stored PIN with its salt spelled out, for settings copied to another
+/// instance. Locks set before the salt was stored were salted with the id of
+/// the instance they were set on.
+pub fn portable_demo_lock_pin(settings: &InstanceSettings, instance_id: &str) -> Option<String> {
+    let stored = settings.demo_lock_pin.as_ref()?;
+    if stored.contains(':') {
+        Some(stored.clone())
+    } else {
+        Some(format!("{}:{}", instance_id, stored))
+    }
+}
+
+/// Whether `pin` lifts the demo lock. A lock without a PIN accepts anything.
+/// Locks set before the salt was stored were salted with the instance id.
+pub fn demo_pin_matches(settings: &InstanceSettings, instance_id: &str, pin: Option<&str>) -> bool {
+    let Some(stored) = &settings.demo_lock_pin else {
+        return true;
+    };
+    let (salt, hash) = stored.split_once(':').unwrap_or((instance_id, stored));
+    pin.is_some_and(|pin| hash_pin(salt, pin) == hash)
+}
+
+/// Lock or unlock demo mode. Changing a lock that has a PIN needs that PIN;
+/// locking with a non-empty `pin` makes it required to change the lock again.
+pub fn set_demo_lock(
+    settings: &mut InstanceSettings,
+    instance_id: &str,
+    locked: bool,
+    current_pin: Option<&str>,
+    new_pin: Option<&str>,
+) -> Result<()> {
+    if settings.demo_locked && !demo_pin_matches(settings, instance_id, current_pin) {
+        return Err(OxideError::Other("Incorrect PIN for the demo lock".to_string()));
+    }
+    
+    settings.demo_locked = locked;
+    settings.demo_lock_pin = new_pin
+        .map(str::trim)
+        .filter(|pin| locked && !pin.is_empty())
+        .map(store_pin);
+    
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn pin_protects_the_lock() {
+        let mut settings = InstanceSettings::default();
+        set_demo_lock(&mut settings, "inst", true, None, Some("1234")).unwrap();
+        assert!(settings.demo_locked);
+        assert_ne!(settings.demo_lock_pin.as_deref(), Some("1234"));
+        
+        assert!(set_demo_lock(&mut settings, "inst", false, None, None).is_err());
+        assert!(set_demo_lock(&mut settings, "inst", false, Some("0000"), None).is_err());
+        assert!(settings.demo_locked);
+        
+        // The salt is stored with the hash, so a copied instance keeps the PIN
+        assert!(demo_pin_matches(&settings, "copy", Some("1234")));
+        
+        set_demo_lock(&mut settings, "inst", false, Some("1234"), None).unwrap();
+        assert!(!settings.demo_locked);
+        assert!(settings.demo_lock_pin.is_none());
+    }
+    
+    #[test]
+    fn locks_salted_with_the_instance_id_still_open() {
+        let mut settings = InstanceSettings::default();
+        settings.demo_locked = true;
+        settings.demo_lock_pin = Some(hash_pin("inst", "1234"));
+        
+        assert!(demo_pin_matches(&settings, "inst", Some("1234")));
+        assert!(!demo_pin_matches(&settings, "inst", Some("0000")));
+        
+        settings.demo_lock_pin = portable_demo_lock_pin(&settings, "inst");
+        assert!(demo_pin_matches(&settings, "copy", Some("1234")));
+    }
+    
+    #[test]
+    fn lock_without_pin_toggles_freely() {
+        let mut settings = InstanceSettings::default();
+        set_demo_lock(&mut settings, "inst", true, None, Some("  ")).unwrap();
+        assert!(settings.demo_locked);
+        assert!(settings.demo_lock_pin.is_none());
+        
+        set_demo_lock(&mut settings, "inst", false, None, None).unwrap();
+        assert!(!settings.demo_locked);
+    }
+}
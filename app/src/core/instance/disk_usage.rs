@@ -0,0 +1,126 @@
+//! Disk usage of an instance folder.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use serde::Serialize;
+use std::path::Path;
+
+use super::Instance;
+
+/// Space an instance takes up on disk, in bytes. The categories add up to
+/// `total`; anything not in one of the named folders counts as `other`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct InstanceDiskUsage {
+    pub total: u64,
+    pub mods: u64,
+    pub saves: u64,
+    pub resourcepacks: u64,
+    pub shaderpacks: u64,
+    /// Libraries kept in the instance folder rather than the shared library store
+    pub libraries: u64,
+    pub other: u64,
+}
+
+impl InstanceDiskUsage {
+    fn add(&mut self, category: Option<&str>, size: u64) {
+        let bucket = match category {
+            Some("mods") => &mut self.mods,
+            Some("saves") => &mut self.saves,
+            Some("resourcepacks") => &mut self.resourcepacks,
+            Some("shaderpacks") => &mut self.shaderpacks,
+            Some("libraries") => &mut self.libraries,
+            _ => &mut self.other,
+        };
+        *bucket += size;
+        self.total += size;
+    }
+}
+
+/// Add up the files in an instance folder by category. Symlinks aren't
+/// followed, so shared folders count toward the launcher's shared storage
+/// rather than every instance that links them. Unreadable entries are skipped.
+pub fn instance_disk_usage(instance: &Instance) -> InstanceDiskUsage {
+    let game_dir = instance.game_dir();
+    let mut usage = InstanceDiskUsage::default();
+    
+    for entry in walkdir::WalkDir::new(&instance.path).follow_links(false).into_iter().flatten() {
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        
+        let category = match entry.path().strip_prefix(&game_dir) {
+            Ok(relative) => first_component(relative),
+            Err(_) => entry.path().strip_prefix(&instance.path).ok().and_then(first_component)
+                .filter(|folder| *folder == "libraries"),
+        };
+        usage.add(category, metadata.len());
+    }
+    
+    usage
+}
+
+/// The top-level folder a relative path is in, if it's in one
+fn first_component(relative: &Path) -> Option<&str> {
+    let mut components = relative.components();
+    let first = components.next()?;
+    components.next()?;
+    first.as_os_str().to_str()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(path: &Path, size: usize) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, vec![0u8; size]).unwrap();
+    }
+
+    #[test]
+    fn categories_add_up_to_the_total() {
+        let dir = TempDir::new().unwrap();
+        let instance = Instance::new("Test".to_string(), dir.path().to_path_buf(), "1.20.1".to_string());
+        let game_dir = instance.game_dir();
+        
+        write(&game_dir.join("mods").join("sodium.jar"), 100);
+        write(&game_dir.join("saves").join("World").join("level.dat"), 50);
+        write(&game_dir.join("resourcepacks").join("pack.zip"), 30);
+        write(&game_dir.join("shaderpacks").join("shader.zip"), 20);
+        write(&dir.path().join("libraries").join("lib.jar"), 10);
+        write(&game_dir.join("options.txt"), 5);
+        write(&dir.path().join("instance.json"), 3);
+        // A file named like a folder isn't in that folder
+        write(&game_dir.join("mods.txt"), 2);
+        
+        let usage = instance_disk_usage(&instance);
+        assert_eq!(usage, InstanceDiskUsage {
+            total: 220,
+            mods: 100,
+            saves: 50,
+            resourcepacks: 30,
+            shaderpacks: 20,
+            libraries: 10,
+            other: 10,
+        });
+    }
+}
@@ -22,13 +22,15 @@ use std::path::{Path, PathBuf};
 use std::io::Read;
 use std::fs::{self, File};
 use zip::ZipArchive;
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
 use crate::core::error::Result;
 use super::transfer::{
     ImportType, OxideManifest, OxideIcon, ModrinthIndex, CurseForgeManifest, 
     PrismInstanceConfig, PrismPackJson, ImportResult, FileToDownload, PlatformFileInfo,
-    OxideInstanceSettings, OxideManagedPack, FTBInstanceJson,
+    OxideInstanceSettings, OxideManagedPack, FTBInstanceJson, ATLauncherInstanceJson, ATLauncherMod,
 };
 
 /// Progress callback type that's Send + Sync
@@ -42,19 +44,53 @@ pub struct ImportOptions {
     
     /// Target instances directory
     pub instances_dir: PathBuf,
+    
+    /// Set to stop the import; checked between extracted files
+    pub cancel: Option<Arc<AtomicBool>>,
+}
+
+impl ImportOptions {
+    fn is_cancelled(&self) -> bool {
+        self.cancel.as_ref().is_some_and(|cancel| cancel.load(Ordering::Relaxed))
+    }
+    
+    /// Stop here if the import was cancelled
+    fn check_cancelled(&self) -> Result<()> {
+        if self.is_cancelled() {
+            Err("Import cancelled".into())
+        } else {
+            Ok(())
+        }
+    }
 }
 
 /// Detect the type of import file
 pub fn detect_import_type(archive_path: &Path) -> Result<ImportType> {
     let file = File::open(archive_path)?;
-    let archive = ZipArchive::new(file)?;
+    let mut archive = ZipArchive::new(file)?;
     
     // Collect file names
     let file_list: Vec<String> = (0..archive.len())
         .filter_map(|i| archive.name_for_index(i).map(|s| s.to_string()))
         .collect();
     
-    Ok(ImportType::detect(&file_list))
+    // ATLauncher has no marker file of its own; only instance.json's contents tell
+    let import_type = ImportType::detect(&file_list);
+    let has_own_manifest = matches!(
+        import_type,
+        ImportType::OxideLauncher | ImportType::Modrinth | ImportType::FTBApp
+    );
+    if !has_own_manifest && find_atlauncher_instance_json(&mut archive, &file_list).is_some() {
+        return Ok(ImportType::ATLauncher);
+    }
+    
+    let is_jar = archive_path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("jar"))
+        || file_list.iter().any(|f| f == "META-INF/MANIFEST.MF");
+    if import_type == ImportType::Unknown && is_jar {
+        return Ok(ImportType::InstallerJar);
+    }
+    
+    Ok(import_type)
 }
 
 /// Import an instance from any supported format
@@ -68,12 +104,13 @@ pub async fn import_instance(
     }
     
     let import_type = detect_import_type(archive_path)?;
+    options.check_cancelled()?;
     
     if let Some(ref cb) = progress_callback {
         cb(0.1, &format!("Detected {} format", format_name(&import_type)));
     }
     
-    match import_type {
+    let result = match import_type {
         ImportType::OxideLauncher => import_oxide(archive_path, options, progress_callback).await,
         ImportType::Modrinth => import_modrinth(archive_path, options, progress_callback).await,
         ImportType::CurseForge => import_curseforge(archive_path, options, progress_callback).await,
@@ -81,8 +118,16 @@ pub async fn import_instance(
         ImportType::Technic => import_technic(archive_path, options, progress_callback).await,
         ImportType::ATLauncher => import_atlauncher(archive_path, options, progress_callback).await,
         ImportType::FTBApp => import_ftb_app(archive_path, options, progress_callback).await,
+        ImportType::InstallerJar => import_installer_jar(archive_path, options, progress_callback).await,
         ImportType::Unknown => Err("Unknown archive format".into()),
+    };
+    
+    // A cancelled import leaves nothing extracted behind
+    if options.is_cancelled() {
+        let _ = fs::remove_dir_all(options.instances_dir.join("_temp_import"));
+        options.check_cancelled()?;
     }
+    result
 }
 
 fn format_name(import_type: &ImportType) -> &'static str {
@@ -94,10 +139,83 @@ fn format_name(import_type: &ImportType) -> &'static str {
         ImportType::Technic => "Technic",
         ImportType::ATLauncher => "ATLauncher",
         ImportType::FTBApp => "FTB App",
+        ImportType::InstallerJar => "installer jar",
         ImportType::Unknown => "Unknown",
     }
 }
 
+/// Import the pack bundled inside an installer jar.
+///
+/// Some packs ship as a runnable jar that carries an `.mrpack` or a zip in
+/// one of the supported formats. That archive is imported as usual, so its
+/// mods are downloaded like any other pack's. Jars without one are rejected
+/// with an explanation of what they are instead of being treated as mods.
+async fn import_installer_jar(
+    archive_path: &Path,
+    options: &ImportOptions,
+    progress_callback: Option<ProgressCallback>,
+) -> Result<ImportResult> {
+    if let Some(ref cb) = progress_callback {
+        cb(0.15, "Looking for a bundled pack...");
+    }
+    
+    let file = File::open(archive_path)?;
+    let mut archive = ZipArchive::new(file)?;
+    let file_list: Vec<String> = (0..archive.len())
+        .filter_map(|i| archive.name_for_index(i).map(|s| s.to_string()))
+        .collect();
+    
+    // Keep the entry's file name so formats that name the instance after it still work
+    let temp_dir = tempfile::tempdir()?;
+    let mut embedded = None;
+    for entry in file_list.iter().filter(|f| is_embedded_pack_candidate(f)) {
+        let file_name = Path::new(entry).file_name().unwrap_or_default();
+        let extracted = temp_dir.path().join(file_name);
+        std::io::copy(&mut archive.by_name(entry)?, &mut File::create(&extracted)?)?;
+        
+        let embedded_type = detect_import_type(&extracted).unwrap_or(ImportType::Unknown);
+        if !matches!(embedded_type, ImportType::Unknown | ImportType::InstallerJar) {
+            tracing::info!("Found {} pack {} inside installer jar", format_name(&embedded_type), entry);
+            embedded = Some(extracted);
+            break;
+        }
+    }
+    drop(archive);
+    
+    match embedded {
+        Some(pack) => Box::pin(import_instance(&pack, options, progress_callback)).await,
+        None => Err(installer_jar_hint(&file_list).into()),
+    }
+}
+
+/// Jar entries that may be a bundled pack archive
+fn is_embedded_pack_candidate(entry: &str) -> bool {
+    let lower = entry.to_lowercase();
+    !lower.starts_with("meta-inf/") && (lower.ends_with(".mrpack") || lower.ends_with(".zip"))
+}
+
+/// Explain why a jar without a bundled pack can't be imported
+fn installer_jar_hint(file_list: &[String]) -> String {
+    let has = |name: &str| file_list.iter().any(|f| f == name);
+    let has_prefix = |prefix: &str| file_list.iter().any(|f| f.starts_with(prefix));
+    
+    if has("install_profile.json") {
+        "This is a Forge or NeoForge installer, not a modpack. \
+         Create an instance with that loader instead.".to_string()
+    } else if has_prefix("net/fabricmc/installer/") || has_prefix("org/quiltmc/installer/") {
+        "This is the Fabric or Quilt installer, not a modpack. \
+         Create an instance with that loader instead.".to_string()
+    } else if has("fabric.mod.json") || has("quilt.mod.json") || has("META-INF/mods.toml")
+        || has("META-INF/neoforge.mods.toml") || has("mcmod.info")
+    {
+        "This jar is a mod, not a modpack. Add it to an instance from its Mods tab.".to_string()
+    } else {
+        "This jar looks like a modpack installer in a format that can't be imported. \
+         Create an empty instance with the pack's Minecraft version and mod loader, \
+         then run the installer and point it at that instance's game folder.".to_string()
+    }
+}
+
 /// Import from OxideLauncher format
 async fn import_oxide(
     archive_path: &Path,
@@ -133,6 +251,7 @@ async fn import_oxide(
     // Extract data files
     let total_files = archive.len();
     for i in 0..archive.len() {
+        options.check_cancelled()?;
         let mut file = archive.by_index(i)?;
         let name_raw = file.name().to_string();
         
@@ -188,6 +307,7 @@ async fn import_oxide(
         notes: manifest.instance.notes,
         managed_pack: manifest.instance.managed_pack,
         settings: manifest.instance.settings,
+        warnings: Vec::new(),
     })
 }
 
@@ -260,7 +380,7 @@ async fn import_modrinth(
     let temp_dir = options.instances_dir.join("_temp_import");
     fs::create_dir_all(&temp_dir)?;
     
-    extract_overrides(&mut archive, &temp_dir, &["overrides/", "client-overrides/"])?;
+    extract_overrides(&mut archive, &temp_dir, options, &["overrides/", "client-overrides/"])?;
     
     // Create managed pack info
     let managed_pack = Some(OxideManagedPack {
@@ -286,6 +406,7 @@ async fn import_modrinth(
         notes: index.summary.unwrap_or_default(),
         managed_pack,
         settings: OxideInstanceSettings::default(),
+        warnings: Vec::new(),
     })
 }
 
@@ -353,7 +474,7 @@ async fn import_curseforge(
     fs::create_dir_all(&temp_dir)?;
     
     let override_folder = format!("{}/", manifest.overrides);
-    extract_overrides(&mut archive, &temp_dir, &[&override_folder])?;
+    extract_overrides(&mut archive, &temp_dir, options, &[&override_folder])?;
     
     let managed_pack = Some(OxideManagedPack {
         platform: "curseforge".to_string(),
@@ -378,6 +499,7 @@ async fn import_curseforge(
         notes: String::new(),
         managed_pack,
         settings: OxideInstanceSettings::default(),
+        warnings: Vec::new(),
     })
 }
 
@@ -455,7 +577,7 @@ async fn import_prism(
     let temp_dir = options.instances_dir.join("_temp_import");
     fs::create_dir_all(&temp_dir)?;
     
-    extract_prism_minecraft(&mut archive, &temp_dir)?;
+    extract_prism_minecraft(&mut archive, &temp_dir, options)?;
     
     // Parse playtime (Prism stores in seconds already)
     let playtime = config.total_time_played;
@@ -495,6 +617,7 @@ async fn import_prism(
         notes: config.notes.clone(),
         managed_pack,
         settings: OxideInstanceSettings::default(),
+        warnings: Vec::new(),
     })
 }
 
@@ -502,9 +625,11 @@ async fn import_prism(
 fn extract_overrides(
     archive: &mut ZipArchive<File>,
     target_dir: &Path,
+    options: &ImportOptions,
     prefixes: &[&str],
 ) -> Result<()> {
     for i in 0..archive.len() {
+        options.check_cancelled()?;
         let mut file = archive.by_index(i)?;
         let name = file.name().to_string();
         
@@ -539,11 +664,12 @@ fn extract_overrides(
 }
 
 /// Extract Prism's .minecraft folder contents
-fn extract_prism_minecraft(archive: &mut ZipArchive<File>, target_dir: &Path) -> Result<()> {
+fn extract_prism_minecraft(archive: &mut ZipArchive<File>, target_dir: &Path, options: &ImportOptions) -> Result<()> {
     // Prism exports have .minecraft/ at the root
     let prefixes = [".minecraft/", "minecraft/"];
     
     for i in 0..archive.len() {
+        options.check_cancelled()?;
         let mut file = archive.by_index(i)?;
         let name = file.name().to_string();
         
@@ -646,6 +772,7 @@ async fn import_technic(
     // Technic packs have a flat structure with bin/, mods/, config/, etc.
     let total_files = archive.len();
     for i in 0..archive.len() {
+        options.check_cancelled()?;
         let mut file = archive.by_index(i)?;
         let name_raw = file.name().to_string();
         
@@ -742,113 +869,185 @@ async fn import_technic(
             version_name: String::new(),
         }),
         settings: OxideInstanceSettings::default(),
+        warnings: Vec::new(),
     })
 }
 
-/// Import from ATLauncher format
-/// ATLauncher packs can be exported as zip files containing configs and mod references
+/// Find an ATLauncher instance.json in an archive and return its path.
+/// FTB App instances also have an instance.json; ATLauncher's is told apart
+/// by its `launcher` block.
+fn find_atlauncher_instance_json(archive: &mut ZipArchive<File>, file_list: &[String]) -> Option<String> {
+    let candidate = file_list.iter()
+        .filter(|f| f.as_str() == "instance.json" || f.ends_with("/instance.json"))
+        .min_by_key(|f| f.matches('/').count())?;
+    
+    let mut content = String::new();
+    archive.by_name(candidate).ok()?.read_to_string(&mut content).ok()?;
+    let json: serde_json::Value = serde_json::from_str(&content).ok()?;
+    json.get("launcher")
+        .filter(|launcher| launcher.is_object())
+        .map(|_| candidate.clone())
+}
+
+/// Build the downloads for ATLauncher mods whose files aren't in the export.
+/// Mods with a Modrinth version download directly; CurseForge mods go through
+/// API resolution, where ones that can't be redistributed end up as blocked
+/// files. Returns the downloads and a warning for each mod with no source.
+fn atlauncher_downloads(mods: &[ATLauncherMod], extracted_dir: &Path) -> (Vec<FileToDownload>, Vec<String>) {
+    let mut files = Vec::new();
+    let mut warnings = Vec::new();
+    
+    for atl_mod in mods.iter().filter(|m| !m.disabled) {
+        let Some(folder) = atl_mod.target_folder() else {
+            continue;
+        };
+        let path = format!("{}/{}", folder, atl_mod.file);
+        if extracted_dir.join(&path).is_file() {
+            continue;
+        }
+        
+        let modrinth_file = atl_mod.modrinth_version.as_ref().and_then(|version| {
+            version.files.iter()
+                .find(|f| f.primary)
+                .or_else(|| version.files.first())
+                .map(|file| (version, file))
+        });
+        
+        if let Some((version, file)) = modrinth_file {
+            files.push(FileToDownload {
+                path,
+                urls: vec![file.url.clone()],
+                size: file.size,
+                hash_sha1: file.hashes.get("sha1").cloned(),
+                hash_sha512: file.hashes.get("sha512").cloned(),
+                platform_info: Some(PlatformFileInfo {
+                    platform: "modrinth".to_string(),
+                    project_id: version.project_id.clone(),
+                    file_id: version.id.clone(),
+                }),
+            });
+        } else if let (Some(project_id), Some(file_id)) = (atl_mod.curse_forge_project_id, atl_mod.curse_forge_file_id) {
+            files.push(FileToDownload {
+                path,
+                urls: Vec::new(),
+                size: 0,
+                hash_sha1: None,
+                hash_sha512: None,
+                platform_info: Some(PlatformFileInfo {
+                    platform: "curseforge".to_string(),
+                    project_id: project_id.to_string(),
+                    file_id: file_id.to_string(),
+                }),
+            });
+        } else {
+            warnings.push(format!(
+                "{} ({}) isn't included in the export and has no known download; add it manually",
+                atl_mod.name, path
+            ));
+        }
+    }
+    
+    (files, warnings)
+}
+
+/// Import from ATLauncher format.
+/// ATLauncher instances are exported as a zip of the instance folder, with an
+/// instance.json describing the Minecraft version, loader and mods.
 async fn import_atlauncher(
     archive_path: &Path,
     options: &ImportOptions,
     progress_callback: Option<ProgressCallback>,
 ) -> Result<ImportResult> {
     if let Some(ref cb) = progress_callback {
-        cb(0.15, "Reading ATLauncher pack...");
+        cb(0.15, "Reading ATLauncher instance...");
     }
     
     let file = File::open(archive_path)?;
     let mut archive = ZipArchive::new(file)?;
     
-    // Create temporary extraction path
-    let temp_dir = options.instances_dir.join("_temp_import");
-    fs::create_dir_all(&temp_dir)?;
-    
-    // ATLauncher exported packs typically have:
-    // - instance.json or pack.json with metadata
-    // - mods/ folder with mod jars or mod info
-    // - config/ folder with configuration
-    
-    // Try to find instance metadata
-    let mut instance_name = "ATLauncher Pack".to_string();
-    let mut minecraft_version = "1.20.1".to_string();
-    let mut mod_loader: Option<(String, String)> = None;
-    
-    // Look for instance.json or similar metadata files
     let file_list: Vec<String> = (0..archive.len())
         .filter_map(|i| archive.name_for_index(i).map(|s| s.to_string()))
         .collect();
     
-    // Check for various ATLauncher metadata files
-    for file_name in &file_list {
-        if file_name.ends_with("instance.json") || file_name == "instance.json" {
-            if let Ok(mut file) = archive.by_name(file_name) {
-                let mut content = String::new();
-                if file.read_to_string(&mut content).is_ok() {
-                    if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
-                        // Extract basic info
-                        if let Some(name) = json.get("launcher").and_then(|l| l.get("name")).and_then(|n| n.as_str()) {
-                            instance_name = name.to_string();
-                        }
-                        if let Some(mc) = json.get("minecraft").and_then(|m| m.as_str()) {
-                            minecraft_version = mc.to_string();
-                        }
-                        if let Some(loader) = json.get("loader") {
-                            if let Some(loader_type) = loader.get("type").and_then(|t| t.as_str()) {
-                                if let Some(loader_ver) = loader.get("version").and_then(|v| v.as_str()) {
-                                    mod_loader = Some((loader_type.to_lowercase(), loader_ver.to_string()));
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-            break;
-        }
-    }
+    let instance_json_path = find_atlauncher_instance_json(&mut archive, &file_list)
+        .ok_or("No ATLauncher instance.json found")?;
+    let instance: ATLauncherInstanceJson = {
+        let mut content = String::new();
+        archive.by_name(&instance_json_path)?.read_to_string(&mut content)?;
+        serde_json::from_str(&content)?
+    };
     
-    // Reopen archive for extraction
-    let file = File::open(archive_path)?;
-    let mut archive = ZipArchive::new(file)?;
+    // Files sit next to instance.json, which may be inside the instance's own folder
+    let root_prefix = instance_json_path.trim_end_matches("instance.json").to_string();
+    
+    let temp_dir = options.instances_dir.join("_temp_import");
+    fs::create_dir_all(&temp_dir)?;
     
     if let Some(ref cb) = progress_callback {
         cb(0.3, "Extracting files...");
     }
     
-    // Extract all files to temp directory
     let total_files = archive.len();
     for i in 0..total_files {
+        options.check_cancelled()?;
         let mut file = archive.by_index(i)?;
-        let name = file.name().to_string();
+        let Some(enclosed) = file.enclosed_name() else {
+            continue;
+        };
+        let name = enclosed.to_string_lossy().replace('\\', "/");
+        let Some(relative) = name.strip_prefix(&root_prefix) else {
+            continue;
+        };
+        if relative.is_empty() || relative == "instance.json" {
+            continue;
+        }
         
+        let out_path = temp_dir.join(relative);
         if file.is_dir() {
-            let dir_path = temp_dir.join(&name);
-            fs::create_dir_all(&dir_path)?;
+            fs::create_dir_all(&out_path)?;
         } else {
-            let file_path = temp_dir.join(&name);
-            if let Some(parent) = file_path.parent() {
+            if let Some(parent) = out_path.parent() {
                 fs::create_dir_all(parent)?;
             }
-            let mut outfile = File::create(&file_path)?;
+            let mut outfile = File::create(&out_path)?;
             std::io::copy(&mut file, &mut outfile)?;
         }
         
         if let Some(ref cb) = progress_callback {
-            let progress = 0.3 + (0.6 * (i as f32 / total_files as f32));
-            cb(progress, &format!("Extracting {}", name));
+            let progress = 0.3 + (0.5 * (i as f32 / total_files as f32));
+            cb(progress, &format!("Extracting {}", relative));
         }
     }
     
-    let name = options.name_override.clone().unwrap_or(instance_name);
+    if let Some(ref cb) = progress_callback {
+        cb(0.85, "Resolving mods...");
+    }
+    
+    let launcher = instance.launcher;
+    let (files_to_download, warnings) = atlauncher_downloads(&launcher.mods, &temp_dir);
+    let mod_loader = launcher.loader_version.as_ref()
+        .map(|loader| (loader.loader_type.to_lowercase(), loader.version.clone()));
+    
+    let settings = OxideInstanceSettings {
+        jvm_args: launcher.java_arguments.clone().filter(|args| !args.trim().is_empty()),
+        min_memory: launcher.initial_memory,
+        max_memory: launcher.maximum_memory,
+        ..Default::default()
+    };
+    
+    let pack_name = launcher.pack.clone().unwrap_or_else(|| launcher.name.clone());
+    let pack_version = launcher.version.clone().unwrap_or_default();
+    let name = options.name_override.clone().unwrap_or(launcher.name);
     
     if let Some(ref cb) = progress_callback {
         cb(1.0, "Import complete!");
     }
     
     Ok(ImportResult {
-        name: name.clone(),
-        minecraft_version,
+        name,
+        minecraft_version: instance.id,
         mod_loader,
-        files_to_download: Vec::new(),
+        files_to_download,
         overrides_path: Some(temp_dir),
         icon: None,
         playtime: 0,
@@ -856,11 +1055,12 @@ async fn import_atlauncher(
         managed_pack: Some(OxideManagedPack {
             platform: "atlauncher".to_string(),
             pack_id: String::new(),
-            pack_name: name,
+            pack_name,
             version_id: String::new(),
-            version_name: String::new(),
+            version_name: pack_version,
         }),
-        settings: OxideInstanceSettings::default(),
+        settings,
+        warnings,
     })
 }
 
@@ -930,6 +1130,7 @@ async fn import_ftb_app(
     // Extract all files to temp directory
     let total_files = archive.len();
     for i in 0..total_files {
+        options.check_cancelled()?;
         let mut file = archive.by_index(i)?;
         let file_name = file.name().to_string();
         
@@ -990,5 +1191,248 @@ async fn import_ftb_app(
             version_name: ftb_instance.version,
         }),
         settings,
+        warnings: Vec::new(),
     })
 }
+
+// =============================================================================
+// Import Into Existing Instance
+// =============================================================================
+
+/// Files a pack import would touch in an existing instance
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ImportPlan {
+    /// Pack files that don't exist in the instance yet
+    pub added: Vec<String>,
+    
+    /// Existing files the pack would replace with different content
+    pub overwritten: Vec<String>,
+    
+    /// Existing files identical to the pack's copy, left alone
+    pub unchanged: Vec<String>,
+    
+    /// Total bytes to download for files with a known size
+    pub download_size: u64,
+    
+    /// Platform files (CurseForge) whose name and size are only known after API resolution
+    pub unresolved_files: usize,
+}
+
+/// Compare an import result against an existing game directory without changing anything.
+///
+/// Override files are compared byte-for-byte, downloads by their SHA1 when the
+/// pack provides one. Downloads that already match are counted as unchanged
+/// and not included in `download_size`.
+pub fn plan_import(result: &ImportResult, game_dir: &Path) -> Result<ImportPlan> {
+    let mut plan = ImportPlan::default();
+    
+    if let Some(ref overrides_path) = result.overrides_path {
+        if overrides_path.exists() {
+            for entry in walkdir::WalkDir::new(overrides_path).sort_by_file_name() {
+                let entry = entry.map_err(|e| std::io::Error::other(e.to_string()))?;
+                if !entry.file_type().is_file() {
+                    continue;
+                }
+                
+                let relative = entry.path().strip_prefix(overrides_path).unwrap_or(entry.path());
+                let target = game_dir.join(relative);
+                let display = relative.to_string_lossy().replace('\\', "/");
+                
+                if !target.is_file() {
+                    plan.added.push(display);
+                } else if files_identical(entry.path(), &target)? {
+                    plan.unchanged.push(display);
+                } else {
+                    plan.overwritten.push(display);
+                }
+            }
+        }
+    }
+    
+    for file in &result.files_to_download {
+        let is_platform_file = file.platform_info.as_ref()
+            .is_some_and(|info| info.platform == "curseforge");
+        if is_platform_file {
+            plan.unresolved_files += 1;
+            continue;
+        }
+        
+        let target = game_dir.join(&file.path);
+        if !target.is_file() {
+            plan.download_size += file.size;
+            plan.added.push(file.path.clone());
+            continue;
+        }
+        
+        let matches = match file.hash_sha1 {
+            Some(ref expected) => crate::core::download::compute_sha1(&target)
+                .map(|actual| actual.eq_ignore_ascii_case(expected))
+                .unwrap_or(false),
+            None => false,
+        };
+        
+        if matches {
+            plan.unchanged.push(file.path.clone());
+        } else {
+            plan.download_size += file.size;
+            plan.overwritten.push(file.path.clone());
+        }
+    }
+    
+    Ok(plan)
+}
+
+/// Check whether two files have the same contents
+pub(super) fn files_identical(a: &Path, b: &Path) -> Result<bool> {
+    if fs::metadata(a)?.len() != fs::metadata(b)?.len() {
+        return Ok(false);
+    }
+    
+    Ok(fs::read(a)? == fs::read(b)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn write_jar(path: &Path, entries: &[(&str, &[u8])]) {
+        let mut zip = zip::ZipWriter::new(File::create(path).unwrap());
+        for (name, data) in entries {
+            zip.start_file(*name, zip::write::SimpleFileOptions::default()).unwrap();
+            std::io::Write::write_all(&mut zip, data).unwrap();
+        }
+        zip.finish().unwrap();
+    }
+    
+    #[test]
+    fn detects_and_explains_installer_jars() {
+        let dir = tempfile::tempdir().unwrap();
+        let forge = dir.path().join("forge-installer.jar");
+        write_jar(&forge, &[("META-INF/MANIFEST.MF", b""), ("install_profile.json", b"{}")]);
+        let mod_jar = dir.path().join("sodium.jar");
+        write_jar(&mod_jar, &[("fabric.mod.json", b"{}")]);
+        
+        assert_eq!(detect_import_type(&forge).unwrap(), ImportType::InstallerJar);
+        assert_eq!(detect_import_type(&mod_jar).unwrap(), ImportType::InstallerJar);
+        assert!(installer_jar_hint(&["install_profile.json".to_string()]).contains("Forge"));
+        assert!(installer_jar_hint(&["fabric.mod.json".to_string()]).contains("is a mod"));
+        assert!(is_embedded_pack_candidate("packs/MyPack.mrpack"));
+        assert!(!is_embedded_pack_candidate("META-INF/jarjar/lib.zip"));
+    }
+    
+    fn empty_result(overrides_path: Option<PathBuf>, files_to_download: Vec<FileToDownload>) -> ImportResult {
+        ImportResult {
+            name: "Pack".to_string(),
+            minecraft_version: "1.20.1".to_string(),
+            mod_loader: None,
+            files_to_download,
+            overrides_path,
+            icon: None,
+            playtime: 0,
+            notes: String::new(),
+            managed_pack: None,
+            settings: OxideInstanceSettings::default(),
+            warnings: Vec::new(),
+        }
+    }
+    
+    #[test]
+    fn plan_classifies_override_files() {
+        let overrides = tempfile::tempdir().unwrap();
+        let game_dir = tempfile::tempdir().unwrap();
+        
+        fs::create_dir_all(overrides.path().join("config")).unwrap();
+        fs::write(overrides.path().join("config/new.toml"), "a = 1").unwrap();
+        fs::write(overrides.path().join("config/same.toml"), "b = 2").unwrap();
+        fs::write(overrides.path().join("options.txt"), "fov:90").unwrap();
+        
+        fs::create_dir_all(game_dir.path().join("config")).unwrap();
+        fs::write(game_dir.path().join("config/same.toml"), "b = 2").unwrap();
+        fs::write(game_dir.path().join("options.txt"), "fov:70").unwrap();
+        
+        let result = empty_result(Some(overrides.path().to_path_buf()), Vec::new());
+        let plan = plan_import(&result, game_dir.path()).unwrap();
+        
+        assert_eq!(plan.added, vec!["config/new.toml"]);
+        assert_eq!(plan.unchanged, vec!["config/same.toml"]);
+        assert_eq!(plan.overwritten, vec!["options.txt"]);
+    }
+    
+    #[test]
+    fn plan_counts_downloads() {
+        let game_dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(game_dir.path().join("mods")).unwrap();
+        fs::write(game_dir.path().join("mods/kept.jar"), "kept").unwrap();
+        fs::write(game_dir.path().join("mods/old.jar"), "old").unwrap();
+        
+        let kept_sha1 = crate::core::download::compute_sha1(&game_dir.path().join("mods/kept.jar")).unwrap();
+        let download = |path: &str, size: u64, sha1: Option<String>| FileToDownload {
+            path: path.to_string(),
+            urls: vec!["https://example.com/file.jar".to_string()],
+            size,
+            hash_sha1: sha1,
+            hash_sha512: None,
+            platform_info: None,
+        };
+        
+        let files = vec![
+            download("mods/kept.jar", 4, Some(kept_sha1)),
+            download("mods/old.jar", 100, Some("0".repeat(40))),
+            download("mods/new.jar", 50, None),
+            FileToDownload {
+                platform_info: Some(PlatformFileInfo {
+                    platform: "curseforge".to_string(),
+                    project_id: "1".to_string(),
+                    file_id: "2".to_string(),
+                }),
+                ..download("mods/cf_1_2.jar", 0, None)
+            },
+        ];
+        
+        let plan = plan_import(&empty_result(None, files), game_dir.path()).unwrap();
+        
+        assert_eq!(plan.unchanged, vec!["mods/kept.jar"]);
+        assert_eq!(plan.overwritten, vec!["mods/old.jar"]);
+        assert_eq!(plan.added, vec!["mods/new.jar"]);
+        assert_eq!(plan.download_size, 150);
+        assert_eq!(plan.unresolved_files, 1);
+    }
+    
+    #[test]
+    fn atlauncher_mods_resolve_to_downloads() {
+        let instance: ATLauncherInstanceJson = serde_json::from_str(r#"{
+            "id": "1.20.1",
+            "launcher": {
+                "name": "My Pack",
+                "loaderVersion": { "version": "0.15.0", "type": "Fabric" },
+                "mods": [
+                    { "name": "Bundled", "file": "bundled.jar", "type": "mods" },
+                    { "name": "Sodium", "file": "sodium.jar", "type": "mods",
+                      "modrinthVersion": { "id": "v1", "projectId": "p1", "files": [
+                          { "url": "https://cdn.modrinth.com/sodium.jar", "filename": "sodium.jar",
+                            "primary": true, "size": 10, "hashes": { "sha1": "abc" } }
+                      ] } },
+                    { "name": "JEI", "file": "jei.jar", "type": "mods",
+                      "curseForgeProjectId": 238222, "curseForgeFileId": 4712866 },
+                    { "name": "Local", "file": "local.jar", "type": "mods" },
+                    { "name": "Off", "file": "off.jar", "type": "mods", "disabled": true }
+                ]
+            }
+        }"#).unwrap();
+        
+        let extracted = tempfile::tempdir().unwrap();
+        fs::create_dir_all(extracted.path().join("mods")).unwrap();
+        fs::write(extracted.path().join("mods/bundled.jar"), "jar").unwrap();
+        
+        let (files, warnings) = atlauncher_downloads(&instance.launcher.mods, extracted.path());
+        
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].path, "mods/sodium.jar");
+        assert_eq!(files[0].urls, vec!["https://cdn.modrinth.com/sodium.jar"]);
+        assert_eq!(files[0].hash_sha1.as_deref(), Some("abc"));
+        assert!(files[1].urls.is_empty());
+        assert_eq!(files[1].platform_info.as_ref().unwrap().project_id, "238222");
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("local.jar"));
+    }
+}
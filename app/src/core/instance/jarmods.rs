@@ -0,0 +1,212 @@
+//! Jar mods: ordering and patching them into the Minecraft jar.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::collections::HashSet;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use zip::write::ZipWriter;
+use zip::ZipArchive;
+
+use crate::core::error::Result;
+
+/// Folder inside the instance directory holding jar mods
+pub const JAR_MODS_DIR: &str = "jarmods";
+
+/// File inside the jar mods folder recording the application order
+const ORDER_FILE: &str = "order.json";
+
+/// Patched client jar written to the instance's patches folder
+pub const PATCHED_JAR_NAME: &str = "jarmods-patched.jar";
+
+/// Get the instance's jar mods in application order.
+///
+/// Mods listed in the order file come first, in that order; any others
+/// (e.g. copied in by hand) follow alphabetically.
+pub fn list_jar_mods(instance_path: &Path) -> Vec<String> {
+    let dir = instance_path.join(JAR_MODS_DIR);
+    
+    let mut available: Vec<String> = fs::read_dir(&dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|entry| entry.file_name().to_str().map(|s| s.to_string()))
+                .filter(|name| name.ends_with(".jar") || name.ends_with(".zip"))
+                .collect()
+        })
+        .unwrap_or_default();
+    available.sort();
+    
+    let order: Vec<String> = fs::read_to_string(dir.join(ORDER_FILE))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default();
+    
+    let mut result: Vec<String> = order.into_iter()
+        .filter(|name| available.contains(name))
+        .collect();
+    let ordered: HashSet<String> = result.iter().cloned().collect();
+    result.extend(available.into_iter().filter(|name| !ordered.contains(name)));
+    
+    result
+}
+
+/// Save the jar mod application order
+pub fn save_jar_mod_order(instance_path: &Path, order: &[String]) -> Result<()> {
+    let dir = instance_path.join(JAR_MODS_DIR);
+    fs::create_dir_all(&dir)?;
+    fs::write(dir.join(ORDER_FILE), serde_json::to_string_pretty(order)?)?;
+    Ok(())
+}
+
+/// Whether a Minecraft version loads jar mods patched into its client jar.
+///
+/// This is the pre-1.13 workflow (alpha/beta and release 1.0-1.12.x, plus
+/// snapshots from before 2018); newer versions have no use for it.
+pub fn supports_jar_mods(minecraft_version: &str) -> bool {
+    // Alpha, beta, classic and infdev versions
+    if minecraft_version.starts_with(['a', 'b', 'c']) || minecraft_version.starts_with("inf-") {
+        return true;
+    }
+    
+    // Weekly snapshots like 17w43a
+    if let Some((year, _)) = minecraft_version.split_once('w') {
+        if let Ok(year) = year.parse::<u32>() {
+            return year < 18;
+        }
+    }
+    
+    let mut parts = minecraft_version.split(['.', '-', ' ']);
+    match (parts.next().and_then(|p| p.parse::<u32>().ok()), parts.next().and_then(|p| p.parse::<u32>().ok())) {
+        (Some(1), Some(minor)) => minor < 13,
+        _ => false,
+    }
+}
+
+/// Build a patched client jar by overlaying jar mods onto the base jar.
+///
+/// Mods are applied in the given order, so a later mod's files replace an
+/// earlier one's, and any mod's files replace the base jar's. The base jar's
+/// `META-INF` is dropped since its signatures would no longer match.
+pub fn build_patched_jar(base_jar: &Path, jar_mods: &[PathBuf], output: &Path) -> Result<()> {
+    if let Some(parent) = output.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    
+    // Write to a temp file so a failed build never leaves a half-written jar behind
+    let temp_output = output.with_extension("jar.tmp");
+    let mut writer = ZipWriter::new(File::create(&temp_output)?);
+    let mut written: HashSet<String> = HashSet::new();
+    
+    // Highest priority first: the last mod wins, the base jar fills in the rest
+    let sources = jar_mods.iter().rev().map(|p| (p.as_path(), false))
+        .chain(std::iter::once((base_jar, true)));
+    
+    for (source, is_base) in sources {
+        let mut archive = ZipArchive::new(File::open(source)?)?;
+        
+        for i in 0..archive.len() {
+            let entry = archive.by_index_raw(i)?;
+            let name = entry.name().to_string();
+            
+            if is_base && name.starts_with("META-INF/") {
+                continue;
+            }
+            if !written.insert(name) {
+                continue;
+            }
+            
+            writer.raw_copy_file(entry)?;
+        }
+    }
+    
+    writer.finish()?;
+    fs::rename(&temp_output, output)?;
+    
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use zip::write::SimpleFileOptions;
+    
+    fn write_jar(path: &Path, entries: &[(&str, &str)]) {
+        let mut writer = ZipWriter::new(File::create(path).unwrap());
+        for (name, content) in entries {
+            writer.start_file(*name, SimpleFileOptions::default()).unwrap();
+            writer.write_all(content.as_bytes()).unwrap();
+        }
+        writer.finish().unwrap();
+    }
+    
+    fn read_entry(path: &Path, name: &str) -> Option<String> {
+        let mut archive = ZipArchive::new(File::open(path).unwrap()).unwrap();
+        let mut entry = archive.by_name(name).ok()?;
+        let mut content = String::new();
+        entry.read_to_string(&mut content).unwrap();
+        Some(content)
+    }
+    
+    #[test]
+    fn later_jar_mods_override_earlier_ones() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path().join("base.jar");
+        let first = dir.path().join("first.zip");
+        let second = dir.path().join("second.zip");
+        let output = dir.path().join("patches").join(PATCHED_JAR_NAME);
+        
+        write_jar(&base, &[("a.class", "base"), ("b.class", "base"), ("META-INF/MOJANG_C.SF", "sig")]);
+        write_jar(&first, &[("a.class", "first"), ("c.class", "first")]);
+        write_jar(&second, &[("a.class", "second")]);
+        
+        build_patched_jar(&base, &[first, second], &output).unwrap();
+        
+        assert_eq!(read_entry(&output, "a.class").as_deref(), Some("second"));
+        assert_eq!(read_entry(&output, "b.class").as_deref(), Some("base"));
+        assert_eq!(read_entry(&output, "c.class").as_deref(), Some("first"));
+        assert_eq!(read_entry(&output, "META-INF/MOJANG_C.SF"), None);
+    }
+    
+    #[test]
+    fn order_file_takes_precedence() {
+        let dir = tempfile::tempdir().unwrap();
+        let jar_mods = dir.path().join(JAR_MODS_DIR);
+        fs::create_dir_all(&jar_mods).unwrap();
+        for name in ["a.zip", "b.zip", "c.jar"] {
+            fs::write(jar_mods.join(name), "").unwrap();
+        }
+        
+        save_jar_mod_order(dir.path(), &["c.jar".to_string(), "gone.zip".to_string(), "a.zip".to_string()]).unwrap();
+        
+        assert_eq!(list_jar_mods(dir.path()), vec!["c.jar", "a.zip", "b.zip"]);
+    }
+    
+    #[test]
+    fn jar_mods_only_apply_to_legacy_versions() {
+        assert!(supports_jar_mods("1.7.10"));
+        assert!(supports_jar_mods("1.12.2"));
+        assert!(supports_jar_mods("b1.7.3"));
+        assert!(supports_jar_mods("17w43a"));
+        assert!(!supports_jar_mods("1.13"));
+        assert!(!supports_jar_mods("1.20.1"));
+        assert!(!supports_jar_mods("24w14a"));
+    }
+}
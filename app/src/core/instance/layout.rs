@@ -0,0 +1,253 @@
+//! Normalization of instance directory layouts.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::fs;
+use std::path::Path;
+
+use crate::core::error::Result;
+
+/// Name of the game directory inside an instance folder
+pub const GAME_DIR_NAME: &str = ".minecraft";
+
+/// Game directory name used by MultiMC-style layouts
+const LEGACY_GAME_DIR_NAME: &str = "minecraft";
+
+/// Folders every instance's game directory is expected to have
+pub const GAME_SUBDIRS: &[&str] = &["mods", "resourcepacks", "saves", "screenshots"];
+
+/// Game files and folders that belong in the game directory, as found at
+/// the root of instances imported or built with a flat layout. Anything else
+/// at the root (instance.json, patches, jar mods, ...) belongs to the launcher.
+const FLAT_LAYOUT_ENTRIES: &[&str] = &[
+    "mods", "coremods", "config", "defaultconfigs", "saves", "resourcepacks", "texturepacks",
+    "shaderpacks", "screenshots", "schematics", "scripts", "kubejs", "journeymap", "crash-reports",
+    "options.txt", "optionsof.txt", "optionsshaders.txt", "servers.dat", "servers.dat_old",
+    "usercache.json", "usernamecache.json",
+];
+
+/// Folders whose presence at the instance root marks a flat layout
+const FLAT_LAYOUT_MARKERS: &[&str] = &["mods", "saves", "config"];
+
+/// Pack metadata kept inside a resource folder (e.g. `mods/.index`). It's
+/// read from the instance root, so it doesn't mark a flat layout and stays
+/// put when one is migrated.
+const METADATA_DIR: &str = ".index";
+
+/// Create an instance's game directory and its standard subfolders
+pub fn create_game_dirs(instance_path: &Path) -> Result<()> {
+    let game_dir = instance_path.join(GAME_DIR_NAME);
+    for subdir in GAME_SUBDIRS {
+        fs::create_dir_all(game_dir.join(subdir))?;
+    }
+    Ok(())
+}
+
+/// Bring an instance folder to the layout `Instance::load` and the launcher
+/// expect, returning a description of each change made.
+///
+/// A `minecraft` game folder from older layouts is renamed to `.minecraft`
+/// when there is no `.minecraft` yet, and missing standard subfolders are
+/// created. Folders without an `instance.json` are left alone.
+pub fn normalize_instance_layout(instance_path: &Path) -> Result<Vec<String>> {
+    let mut changes = Vec::new();
+    if !instance_path.join("instance.json").is_file() {
+        return Ok(changes);
+    }
+    
+    let game_dir = instance_path.join(GAME_DIR_NAME);
+    let legacy_dir = instance_path.join(LEGACY_GAME_DIR_NAME);
+    if !game_dir.exists() && legacy_dir.is_dir() {
+        fs::rename(&legacy_dir, &game_dir)?;
+        changes.push(format!("Renamed {} to {}", LEGACY_GAME_DIR_NAME, GAME_DIR_NAME));
+    }
+    
+    for subdir in GAME_SUBDIRS {
+        let path = game_dir.join(subdir);
+        if !path.is_dir() {
+            fs::create_dir_all(&path)?;
+            changes.push(format!("Created {}/{}", GAME_DIR_NAME, subdir));
+        }
+    }
+    
+    Ok(changes)
+}
+
+/// Whether an instance keeps its game files at the root of the instance
+/// folder instead of in the game directory
+pub fn has_flat_layout(instance_path: &Path) -> bool {
+    FLAT_LAYOUT_MARKERS.iter().any(|name| {
+        fs::read_dir(instance_path.join(name))
+            .map(|entries| entries.flatten().any(|entry| entry.file_name() != METADATA_DIR))
+            .unwrap_or(false)
+    })
+}
+
+/// Move a root folder's game files into the game directory, leaving any
+/// pack metadata behind. Returns how many files were left at `src`.
+fn move_game_files(src: &Path, dst: &Path) -> Result<usize> {
+    if !src.join(METADATA_DIR).is_dir() {
+        return move_merging(src, dst);
+    }
+    
+    fs::create_dir_all(dst)?;
+    let mut kept = 0;
+    for entry in fs::read_dir(src)?.flatten() {
+        if entry.file_name() != METADATA_DIR {
+            kept += move_merging(&entry.path(), &dst.join(entry.file_name()))?;
+        }
+    }
+    Ok(kept)
+}
+
+/// Move `src` to `dst`, merging folders that exist in both places. Files the
+/// destination already has are left at `src`; returns how many.
+fn move_merging(src: &Path, dst: &Path) -> Result<usize> {
+    if !dst.exists() {
+        fs::rename(src, dst)?;
+        return Ok(0);
+    }
+    if !(src.is_dir() && dst.is_dir()) {
+        return Ok(1);
+    }
+    
+    let mut kept = 0;
+    for entry in fs::read_dir(src)?.flatten() {
+        kept += move_merging(&entry.path(), &dst.join(entry.file_name()))?;
+    }
+    if kept == 0 {
+        fs::remove_dir(src)?;
+    }
+    Ok(kept)
+}
+
+/// Move game files from the root of a flat-layout instance into its game
+/// directory, where the launcher runs the game, returning a description of
+/// each change. Folders in both places are merged; a file that already
+/// exists in the game directory is kept there and the root copy is left in
+/// place and reported.
+pub fn migrate_flat_layout(instance_path: &Path) -> Result<Vec<String>> {
+    let mut changes = Vec::new();
+    if !has_flat_layout(instance_path) {
+        return Ok(changes);
+    }
+    
+    let game_dir = instance_path.join(GAME_DIR_NAME);
+    fs::create_dir_all(&game_dir)?;
+    
+    for name in FLAT_LAYOUT_ENTRIES {
+        let src = instance_path.join(name);
+        if fs::symlink_metadata(&src).is_err() {
+            continue;
+        }
+        
+        let kept = move_game_files(&src, &game_dir.join(name))?;
+        if kept == 0 {
+            changes.push(format!("Moved {} to {}/{}", name, GAME_DIR_NAME, name));
+        } else {
+            changes.push(format!(
+                "Left {} file(s) in {} that already exist in {}/{}",
+                kept, name, GAME_DIR_NAME, name
+            ));
+        }
+    }
+    
+    for subdir in GAME_SUBDIRS {
+        fs::create_dir_all(game_dir.join(subdir))?;
+    }
+    
+    Ok(changes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn migrates_legacy_game_dir_and_fills_in_subfolders() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("instance.json"), "{}").unwrap();
+        fs::create_dir_all(dir.path().join("minecraft/mods")).unwrap();
+        fs::write(dir.path().join("minecraft/mods/a.jar"), "a").unwrap();
+        
+        let changes = normalize_instance_layout(dir.path()).unwrap();
+        assert_eq!(changes.len(), 4);
+        assert!(dir.path().join(".minecraft/mods/a.jar").is_file());
+        assert!(dir.path().join(".minecraft/screenshots").is_dir());
+        assert!(!dir.path().join("minecraft").exists());
+        
+        // Already normalized
+        assert!(normalize_instance_layout(dir.path()).unwrap().is_empty());
+    }
+    
+    #[test]
+    fn migrates_flat_layout_into_game_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("instance.json"), "{}").unwrap();
+        fs::create_dir_all(dir.path().join("saves/World")).unwrap();
+        fs::write(dir.path().join("saves/World/level.dat"), "world").unwrap();
+        fs::create_dir_all(dir.path().join("mods")).unwrap();
+        fs::write(dir.path().join("mods/a.jar"), "root").unwrap();
+        fs::write(dir.path().join("mods/b.jar"), "b").unwrap();
+        fs::write(dir.path().join("options.txt"), "lang:en_us").unwrap();
+        fs::create_dir_all(dir.path().join("patches")).unwrap();
+        fs::create_dir_all(dir.path().join(".minecraft/mods")).unwrap();
+        fs::write(dir.path().join(".minecraft/mods/a.jar"), "game").unwrap();
+        
+        assert!(has_flat_layout(dir.path()));
+        let changes = migrate_flat_layout(dir.path()).unwrap();
+        assert_eq!(changes.len(), 3);
+        
+        let game_dir = dir.path().join(GAME_DIR_NAME);
+        assert!(game_dir.join("saves/World/level.dat").is_file());
+        assert!(game_dir.join("options.txt").is_file());
+        assert!(game_dir.join("mods/b.jar").is_file());
+        assert_eq!(fs::read_to_string(game_dir.join("mods/a.jar")).unwrap(), "game");
+        // The conflicting root copy stays where it was
+        assert!(dir.path().join("mods/a.jar").is_file());
+        assert!(!dir.path().join("saves").exists());
+        assert!(dir.path().join("patches").is_dir());
+    }
+    
+    #[test]
+    fn mod_metadata_is_not_a_flat_layout() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("instance.json"), "{}").unwrap();
+        fs::create_dir_all(dir.path().join("mods/.index")).unwrap();
+        fs::write(dir.path().join("mods/.index/sodium.pw.toml"), "name = 'Sodium'").unwrap();
+        
+        assert!(!has_flat_layout(dir.path()));
+        assert!(migrate_flat_layout(dir.path()).unwrap().is_empty());
+        
+        // Mods next to the metadata are still migrated, without it
+        fs::write(dir.path().join("mods/sodium.jar"), "jar").unwrap();
+        assert!(has_flat_layout(dir.path()));
+        migrate_flat_layout(dir.path()).unwrap();
+        assert!(dir.path().join(".minecraft/mods/sodium.jar").is_file());
+        assert!(dir.path().join("mods/.index/sodium.pw.toml").is_file());
+        assert!(!dir.path().join(".minecraft/mods/.index").exists());
+    }
+    
+    #[test]
+    fn ignores_folders_without_instance_metadata() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(normalize_instance_layout(dir.path()).unwrap().is_empty());
+        assert!(!dir.path().join(GAME_DIR_NAME).exists());
+    }
+}
@@ -20,10 +20,28 @@
 
 #![allow(dead_code)] // List management will be used as features are completed
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::collections::HashMap;
 use crate::core::error::Result;
-use super::Instance;
+use super::{normalize_instance_layout, Instance};
+
+/// First-seen spelling of each group, keyed by its lowercase name, so groups
+/// whose names differ only by case are treated as one
+pub fn canonical_groups<'a>(groups: impl IntoIterator<Item = &'a str>) -> HashMap<String, String> {
+    let mut canonical = HashMap::new();
+    for group in groups {
+        canonical.entry(group.to_lowercase()).or_insert_with(|| group.to_string());
+    }
+    canonical
+}
+
+/// The spelling of an existing group that matches `name` ignoring case,
+/// or `name` itself when it's a new group
+pub fn canonical_group_name<'a>(existing: impl IntoIterator<Item = &'a str>, name: &str) -> String {
+    canonical_groups(existing)
+        .remove(&name.to_lowercase())
+        .unwrap_or_else(|| name.to_string())
+}
 
 /// List of all instances
 #[allow(dead_code)] // Methods will be used as features are implemented
@@ -61,6 +79,16 @@ impl InstanceList {
             let path = entry.path();
             
             if path.is_dir() {
+                // Fix up layouts left by older creation paths before loading
+                match normalize_instance_layout(&path) {
+                    Ok(changes) => {
+                        for change in changes {
+                            tracing::info!("Normalized instance layout at {:?}: {}", path, change);
+                        }
+                    }
+                    Err(e) => tracing::warn!("Failed to normalize instance layout at {:?}: {}", path, e),
+                }
+                
                 // Try to load instance from this directory
                 match Instance::load(&path) {
                     Ok(instance) => {
@@ -139,11 +167,12 @@ impl InstanceList {
         }
     }
 
-    /// Get all instances in a group
+    /// Get all instances in a group, matching its name ignoring case
     pub fn get_group(&self, group: &str) -> Vec<&Instance> {
+        let group = group.to_lowercase();
         self.instances
             .iter()
-            .filter(|i| i.group.as_deref() == Some(group))
+            .filter(|i| i.group.as_ref().is_some_and(|g| g.to_lowercase() == group))
             .collect()
     }
 
@@ -155,9 +184,13 @@ impl InstanceList {
             .collect()
     }
 
-    /// Get all unique group names
+    /// Get all unique group names, case variants shown once under the
+    /// spelling the first instance in the list uses
     pub fn get_groups(&self) -> Vec<String> {
-        let mut groups: Vec<_> = self.groups.keys().cloned().collect();
+        let seen = self.instances.iter()
+            .filter_map(|i| i.group.as_deref())
+            .chain(self.groups.keys().map(String::as_str));
+        let mut groups: Vec<_> = canonical_groups(seen).into_values().collect();
         groups.sort();
         groups
     }
@@ -181,8 +214,9 @@ impl InstanceList {
             }
         }
         
+        // When merging into an existing group, that group keeps its state
         if let Some(collapsed) = self.groups.remove(old_name) {
-            self.groups.insert(new_name.to_string(), collapsed);
+            self.groups.entry(new_name.to_string()).or_insert(collapsed);
         }
     }
 
@@ -261,6 +295,21 @@ impl InstanceList {
         Ok(())
     }
 
+    /// Move a group's persisted collapsed state to a new name without loading the instances.
+    /// If the new name already has a state (merging groups), it is kept.
+    pub fn rename_group_state(instances_dir: &Path, old_name: &str, new_name: &str) -> Result<()> {
+        let instances_dir = instances_dir.to_path_buf();
+        let mut list = Self::new();
+        list.load_groups_state(&instances_dir);
+        
+        let Some(collapsed) = list.groups.remove(old_name) else {
+            return Ok(());
+        };
+        list.groups.entry(new_name.to_string()).or_insert(collapsed);
+        
+        list.save_groups_state(&instances_dir)
+    }
+
     /// Find instances by Minecraft version
     pub fn find_by_version(&self, version: &str) -> Vec<&Instance> {
         self.instances
@@ -298,3 +347,56 @@ impl Default for InstanceList {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn write_groups(dir: &Path, groups: &[(&str, bool)]) {
+        let groups: HashMap<_, _> = groups.iter().map(|(g, c)| (g.to_string(), *c)).collect();
+        std::fs::write(dir.join("groups.json"), serde_json::to_string(&groups).unwrap()).unwrap();
+    }
+    
+    fn read_groups(dir: &Path) -> HashMap<String, bool> {
+        serde_json::from_str(&std::fs::read_to_string(dir.join("groups.json")).unwrap()).unwrap()
+    }
+    
+    #[test]
+    fn rename_group_state_moves_collapsed_flag() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        write_groups(dir, &[("Old", true), ("Other", false)]);
+        
+        InstanceList::rename_group_state(dir, "Old", "New").unwrap();
+        
+        let groups = read_groups(dir);
+        assert_eq!(groups.get("New"), Some(&true));
+        assert!(!groups.contains_key("Old"));
+        assert_eq!(groups.get("Other"), Some(&false));
+    }
+    
+    #[test]
+    fn rename_group_state_keeps_target_when_merging() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        write_groups(dir, &[("Old", true), ("Existing", false)]);
+        
+        InstanceList::rename_group_state(dir, "Old", "Existing").unwrap();
+        
+        let groups = read_groups(dir);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups.get("Existing"), Some(&false));
+    }
+    
+    #[test]
+    fn case_variant_groups_resolve_to_first_seen_spelling() {
+        let groups = ["Favorites", "Modded", "favorites", "FAVORITES"];
+        
+        let canonical = canonical_groups(groups);
+        assert_eq!(canonical.len(), 2);
+        assert_eq!(canonical["favorites"], "Favorites");
+        
+        assert_eq!(canonical_group_name(groups, "favorites"), "Favorites");
+        assert_eq!(canonical_group_name(groups, "New"), "New");
+    }
+}
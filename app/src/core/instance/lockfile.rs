@@ -0,0 +1,251 @@
+//! Reproducible lockfiles of an instance's exact files.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha512};
+
+use crate::core::error::Result;
+
+/// Lockfile written to the instance directory
+pub const LOCKFILE_NAME: &str = "oxide.lock";
+
+/// Volatile files left out of a lockfile unless another ignore list is given,
+/// including those the launcher itself rewrites on every launch
+pub const DEFAULT_LOCK_IGNORE: &[&str] = &[
+    "natives",
+    "options.txt",
+    "quickPlay",
+    "logs",
+    "crash-reports",
+    "saves",
+    "screenshots",
+    ".fabric",
+    ".mixin.out",
+    "*.log",
+    "usercache.json",
+    "usernamecache.json",
+];
+
+/// Every file in an instance's game directory with its SHA-512, so a
+/// distributed copy can be checked to be byte-identical
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Lockfile {
+    pub minecraft_version: String,
+    /// Patterns left out when the lockfile was made, also skipped when verifying
+    pub ignore: Vec<String>,
+    /// Game-directory relative path -> SHA-512
+    pub files: BTreeMap<String, String>,
+}
+
+/// Differences between an instance and a lockfile
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LockfileDrift {
+    /// In the lockfile but not on disk
+    pub missing: Vec<String>,
+    /// On disk with a different hash
+    pub changed: Vec<String>,
+    /// On disk but not in the lockfile
+    pub extra: Vec<String>,
+    /// Minecraft version the lockfile was made for, when it isn't the instance's
+    pub minecraft_version: Option<String>,
+}
+
+impl LockfileDrift {
+    /// Whether the instance matches the lockfile exactly
+    pub fn is_exact(&self) -> bool {
+        self.missing.is_empty()
+            && self.changed.is_empty()
+            && self.extra.is_empty()
+            && self.minecraft_version.is_none()
+    }
+}
+
+impl Lockfile {
+    /// Hash every file under `game_dir` that no `ignore` pattern matches
+    pub fn generate(game_dir: &Path, minecraft_version: &str, ignore: Vec<String>) -> Result<Self> {
+        let files = hash_files(game_dir, &ignore)?;
+        Ok(Self {
+            minecraft_version: minecraft_version.to_string(),
+            ignore,
+            files,
+        })
+    }
+    
+    pub fn load(path: &Path) -> Result<Self> {
+        Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+    }
+    
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+    
+    /// Compare `game_dir` against the lockfile, skipping its ignored files
+    pub fn verify(&self, game_dir: &Path, minecraft_version: &str) -> Result<LockfileDrift> {
+        let current = hash_files(game_dir, &self.ignore)?;
+        let mut drift = LockfileDrift::default();
+        
+        for (path, hash) in &self.files {
+            match current.get(path) {
+                Some(current_hash) if current_hash == hash => {}
+                Some(_) => drift.changed.push(path.clone()),
+                None => drift.missing.push(path.clone()),
+            }
+        }
+        drift.extra = current
+            .into_keys()
+            .filter(|path| !self.files.contains_key(path))
+            .collect();
+        
+        if self.minecraft_version != minecraft_version {
+            drift.minecraft_version = Some(self.minecraft_version.clone());
+        }
+        
+        Ok(drift)
+    }
+}
+
+/// Whether an ignore pattern matches a relative path. `*.ext` matches by
+/// extension, a pattern with a slash matches that path and everything under
+/// it, and a plain name matches any file or folder with that name.
+fn is_ignored(relative: &str, ignore: &[String]) -> bool {
+    ignore.iter().any(|pattern| {
+        let pattern = pattern.trim().trim_matches('/');
+        if pattern.is_empty() {
+            false
+        } else if let Some(suffix) = pattern.strip_prefix('*') {
+            relative.ends_with(suffix)
+        } else if pattern.contains('/') {
+            relative == pattern || relative.starts_with(&format!("{}/", pattern))
+        } else {
+            relative.split('/').any(|component| component == pattern)
+        }
+    })
+}
+
+/// SHA-512 of every file under a directory that isn't ignored, keyed by relative path
+fn hash_files(dir: &Path, ignore: &[String]) -> Result<BTreeMap<String, String>> {
+    let mut hashes = BTreeMap::new();
+    
+    if !dir.exists() {
+        return Ok(hashes);
+    }
+    
+    for entry in walkdir::WalkDir::new(dir).follow_links(false) {
+        let entry = entry.map_err(|e| std::io::Error::other(e.to_string()))?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        
+        let Ok(relative) = entry.path().strip_prefix(dir) else {
+            continue;
+        };
+        let relative = relative.to_string_lossy().replace('\\', "/");
+        if is_ignored(&relative, ignore) {
+            continue;
+        }
+        
+        hashes.insert(relative, sha512_file(entry.path())?);
+    }
+    
+    Ok(hashes)
+}
+
+fn sha512_file(path: &Path) -> Result<String> {
+    let mut file = fs::File::open(path)?;
+    let mut hasher = Sha512::new();
+    let mut buffer = [0u8; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(hex::encode(hasher.finalize()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn default_ignore() -> Vec<String> {
+        DEFAULT_LOCK_IGNORE.iter().map(|pattern| pattern.to_string()).collect()
+    }
+    
+    #[test]
+    fn ignore_patterns() {
+        let ignore = vec!["logs".to_string(), "*.log".to_string(), "config/local".to_string()];
+        assert!(is_ignored("logs/latest.log", &ignore));
+        assert!(is_ignored("mods/.cache/debug.log", &ignore));
+        assert!(is_ignored("config/local/a.toml", &ignore));
+        assert!(!is_ignored("config/localized.toml", &ignore));
+        assert!(!is_ignored("mods/logship.jar", &ignore));
+    }
+    
+    #[test]
+    fn verify_reports_drift() {
+        let dir = tempfile::tempdir().unwrap();
+        let game_dir = dir.path();
+        fs::create_dir_all(game_dir.join("mods")).unwrap();
+        fs::create_dir_all(game_dir.join("logs")).unwrap();
+        fs::write(game_dir.join("mods/a.jar"), b"a").unwrap();
+        fs::write(game_dir.join("mods/b.jar"), b"b").unwrap();
+        fs::write(game_dir.join("logs/latest.log"), b"log").unwrap();
+        
+        let lockfile = Lockfile::generate(game_dir, "1.20.1", default_ignore()).unwrap();
+        assert_eq!(lockfile.files.len(), 2);
+        assert!(lockfile.verify(game_dir, "1.20.1").unwrap().is_exact());
+        
+        fs::write(game_dir.join("mods/a.jar"), b"changed").unwrap();
+        fs::remove_file(game_dir.join("mods/b.jar")).unwrap();
+        fs::write(game_dir.join("mods/c.jar"), b"c").unwrap();
+        fs::write(game_dir.join("logs/latest.log"), b"more log").unwrap();
+        
+        let drift = lockfile.verify(game_dir, "1.20.1").unwrap();
+        assert_eq!(drift.changed, vec!["mods/a.jar"]);
+        assert_eq!(drift.missing, vec!["mods/b.jar"]);
+        assert_eq!(drift.extra, vec!["mods/c.jar"]);
+        assert!(!drift.is_exact());
+    }
+    
+    #[test]
+    fn launch_written_files_are_not_drift() {
+        let dir = tempfile::tempdir().unwrap();
+        let game_dir = dir.path();
+        fs::create_dir_all(game_dir.join("mods")).unwrap();
+        fs::write(game_dir.join("mods/a.jar"), b"a").unwrap();
+        
+        let lockfile = Lockfile::generate(game_dir, "1.20.1", default_ignore()).unwrap();
+        
+        fs::create_dir_all(game_dir.join("natives")).unwrap();
+        fs::create_dir_all(game_dir.join("quickPlay/java")).unwrap();
+        fs::write(game_dir.join("natives/liblwjgl.so"), b"native").unwrap();
+        fs::write(game_dir.join("quickPlay/java/log.json"), b"[]").unwrap();
+        fs::write(game_dir.join("options.txt"), b"fullscreen:true").unwrap();
+        
+        assert!(lockfile.verify(game_dir, "1.20.1").unwrap().is_exact());
+    }
+}
@@ -26,10 +26,25 @@ mod components;
 mod transfer;
 mod export;
 mod import;
+mod jarmods;
+mod pack_files;
+mod server_pack;
+mod mod_files;
+mod shared_folders;
+mod naming;
+mod mod_configs;
+mod mod_copy;
+mod mod_scan;
+mod recipe;
+mod layout;
+mod card;
+mod demo_lock;
+mod lockfile;
+mod disk_usage;
 
 pub use types::*;
 #[allow(unused_imports)] // Will be used as features are completed
-pub use list::InstanceList;
+pub use list::{canonical_group_name, canonical_groups, InstanceList};
 #[allow(unused_imports)]
 pub use create::create_instance;
 #[allow(unused_imports)]
@@ -37,4 +52,19 @@ pub use setup::{setup_instance, SetupProgress, install_modloader_for_instance};
 pub use components::*;
 pub use transfer::*;
 pub use export::{export_instance, ExportOptions};
-pub use import::{import_instance, detect_import_type, ImportOptions};
+pub use jarmods::{list_jar_mods, save_jar_mod_order, supports_jar_mods, build_patched_jar, JAR_MODS_DIR, PATCHED_JAR_NAME};
+pub use pack_files::{PackFileIndex, MergeAction, merge_action, pack_file_key, hash_tree, file_hash};
+pub use server_pack::{copy_server_mods, write_start_scripts, ServerLaunch, SERVER_CONFIG_DIRS};
+pub use mod_files::{split_mod_filename, find_mod_file, scan_mod_files, repair_mod_files, clean_mod_metadata, mod_set_fingerprint, find_duplicate_mods, DuplicateMods, ModFileRepair, DISABLED_SUFFIX};
+pub use shared_folders::{share_folder, unshare_folder, SharedFolder};
+pub use naming::{validate_instance_name, NameValidation};
+pub use mod_configs::find_mod_configs;
+pub use mod_copy::{copy_mods, ModCopyResult, ModTarget};
+pub use mod_scan::{scan_instance_files, Blocklist, ScanReport};
+pub use layout::{create_game_dirs, has_flat_layout, migrate_flat_layout, normalize_instance_layout};
+pub use card::{encode_png, render_card, InstanceCard};
+pub use demo_lock::{portable_demo_lock_pin, set_demo_lock};
+pub use lockfile::{Lockfile, LockfileDrift, DEFAULT_LOCK_IGNORE, LOCKFILE_NAME};
+pub use disk_usage::{instance_disk_usage, InstanceDiskUsage};
+pub use recipe::{InstanceRecipe, RecipeModRestore, write_recipe_manifest, read_recipe_manifest, restore_recipe_mods};
+pub use import::{import_instance, detect_import_type, plan_import, ImportOptions, ImportPlan};
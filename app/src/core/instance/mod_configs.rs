@@ -0,0 +1,106 @@
+//! Locating a mod's files in the instance config folder.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Suffixes mods commonly append to their mod ID when splitting configs by side
+const CONFIG_SIDE_SUFFIXES: &[&str] = &["client", "common", "server", "options", "config"];
+
+/// Find config files and folders in `config_dir` that belong to `mod_id`.
+///
+/// Matches `<modid>.<ext>`, `<modid>-client.toml` style side splits and a
+/// `<modid>/` folder. Comparison ignores case, `-` and `_`, so `ferritecore`
+/// also finds `ferrite-core.mixin.properties`. Results are sorted by path.
+pub fn find_mod_configs(config_dir: &Path, mod_id: &str) -> Vec<PathBuf> {
+    let wanted = normalize(mod_id);
+    if wanted.is_empty() {
+        return Vec::new();
+    }
+    
+    let Ok(entries) = fs::read_dir(config_dir) else {
+        return Vec::new();
+    };
+    
+    let mut matches: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+                return false;
+            };
+            
+            // Folders match on their whole name, files on the part before the first '.'
+            let stem = if path.is_dir() { name } else { name.split('.').next().unwrap_or(name) };
+            stem_matches(&normalize(stem), &wanted)
+        })
+        .collect();
+    
+    matches.sort();
+    matches
+}
+
+fn stem_matches(stem: &str, wanted: &str) -> bool {
+    if stem == wanted {
+        return true;
+    }
+    
+    stem.strip_prefix(wanted)
+        .is_some_and(|rest| CONFIG_SIDE_SUFFIXES.contains(&rest))
+}
+
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| *c != '-' && *c != '_')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_finds_files_side_splits_and_folders() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = dir.path();
+        for file in ["sodium-options.json", "sodium-mixins.properties", "jei-client.toml", "jei.toml", "lithium.properties"] {
+            fs::write(config.join(file), "").unwrap();
+        }
+        fs::create_dir(config.join("jei")).unwrap();
+        
+        let jei: Vec<_> = find_mod_configs(config, "jei")
+            .into_iter()
+            .map(|p| p.file_name().unwrap().to_string_lossy().to_string())
+            .collect();
+        assert_eq!(jei, vec!["jei", "jei-client.toml", "jei.toml"]);
+        
+        assert_eq!(find_mod_configs(config, "sodium").len(), 1);
+        assert!(find_mod_configs(config, "iris").is_empty());
+    }
+    
+    #[test]
+    fn test_ignores_case_and_separators() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("Ferrite-Core.mixin.properties"), "").unwrap();
+        
+        assert_eq!(find_mod_configs(dir.path(), "ferritecore").len(), 1);
+    }
+}
@@ -0,0 +1,297 @@
+//! Copying mods between instances.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::Serialize;
+
+use super::mod_files::{find_mod_file, split_mod_filename, DISABLED_SUFFIX, METADATA_SUFFIX};
+use crate::core::error::Result;
+use crate::core::rustwiz::parser::{index_dir, read_mod_toml};
+use crate::core::rustwiz::types::ModTomlExtended;
+
+/// The game version and loader a mods folder belongs to
+#[derive(Debug, Clone)]
+pub struct ModTarget {
+    pub minecraft_version: String,
+    /// Loader name in lowercase (`fabric`, `neoforge`, ...); None for vanilla
+    pub loader: Option<String>,
+}
+
+/// Outcome of copying mods from one instance to another
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ModCopyResult {
+    /// Jar names that were copied
+    pub copied: Vec<String>,
+    /// Jar names already present in the destination
+    pub skipped: Vec<String>,
+    /// Possible loader or game version mismatches; the mods were still copied
+    pub warnings: Vec<String>,
+    /// Requested jar names that aren't in the source instance
+    pub missing: Vec<String>,
+}
+
+/// Copy mod jars along with their `.metadata.json` sidecars and `.pw.toml`
+/// index entries.
+///
+/// `filenames` are jar names with or without the `.disabled` suffix; None
+/// copies every mod. Disabled mods stay disabled. Mods the destination
+/// already has, in either state, are skipped.
+pub fn copy_mods(
+    source_dir: &Path,
+    dest_dir: &Path,
+    filenames: Option<&[String]>,
+    source: &ModTarget,
+    dest: &ModTarget,
+) -> Result<ModCopyResult> {
+    let mut result = ModCopyResult::default();
+    
+    let available = list_mod_jars(source_dir);
+    let selected: Vec<String> = match filenames {
+        Some(names) => {
+            let mut seen = HashSet::new();
+            names.iter()
+                .map(|name| split_mod_filename(name).map_or(name.as_str(), |(base, _, _)| base).to_string())
+                .filter(|base| seen.insert(base.clone()))
+                .filter(|base| {
+                    let found = available.contains(base);
+                    if !found {
+                        result.missing.push(base.clone());
+                    }
+                    found
+                })
+                .collect()
+        }
+        None => available,
+    };
+    
+    if selected.is_empty() {
+        return Ok(result);
+    }
+    
+    if dest.loader.is_none() {
+        result.warnings.push("The destination instance has no mod loader, so these mods won't load".to_string());
+    }
+    
+    fs::create_dir_all(dest_dir)?;
+    let tomls = index_tomls(source_dir);
+    
+    for base in selected {
+        if find_mod_file(dest_dir, &base).is_some() {
+            result.skipped.push(base);
+            continue;
+        }
+        
+        let Some(current) = find_mod_file(source_dir, &base) else {
+            result.missing.push(base);
+            continue;
+        };
+        
+        // Normalize repeated suffixes while keeping the enabled state
+        let enabled = split_mod_filename(&current).is_some_and(|(_, enabled, _)| enabled);
+        let dest_name = if enabled {
+            base.clone()
+        } else {
+            format!("{}{}", base, DISABLED_SUFFIX)
+        };
+        fs::copy(source_dir.join(&current), dest_dir.join(dest_name))?;
+        
+        let metadata = format!("{}{}", base, METADATA_SUFFIX);
+        if source_dir.join(&metadata).is_file() {
+            fs::copy(source_dir.join(&metadata), dest_dir.join(&metadata))?;
+        }
+        
+        let toml = tomls.iter().find(|(_, toml)| toml_jar_name(toml) == Some(base.as_str()));
+        if let Some((path, _)) = toml {
+            let dest_index = index_dir(dest_dir);
+            let dest_toml = dest_index.join(path.file_name().unwrap_or_default());
+            if !dest_toml.exists() {
+                fs::create_dir_all(&dest_index)?;
+                fs::copy(path, dest_toml)?;
+            }
+        }
+        
+        if let Some(warning) = compatibility_warning(&base, toml.map(|(_, toml)| toml), source, dest) {
+            result.warnings.push(warning);
+        }
+        
+        result.copied.push(base);
+    }
+    
+    Ok(result)
+}
+
+/// Explain why a mod may not work in the destination, if it might not.
+///
+/// Uses the versions and loaders recorded in the mod's metadata; mods without
+/// any are assumed to match the source instance.
+fn compatibility_warning(
+    jar: &str,
+    toml: Option<&ModTomlExtended>,
+    source: &ModTarget,
+    dest: &ModTarget,
+) -> Option<String> {
+    let oxide = toml.and_then(|toml| toml.oxide.as_ref());
+    let mc_versions = oxide.map(|o| o.mc_versions.clone())
+        .filter(|versions| !versions.is_empty())
+        .unwrap_or_else(|| vec![source.minecraft_version.clone()]);
+    let loaders = oxide.map(|o| o.loaders.clone())
+        .filter(|loaders| !loaders.is_empty())
+        .unwrap_or_else(|| source.loader.iter().cloned().collect());
+    
+    let mut problems = Vec::new();
+    if !mc_versions.contains(&dest.minecraft_version) {
+        problems.push(format!("Minecraft {}", mc_versions.join(", ")));
+    }
+    if let Some(loader) = &dest.loader {
+        if !loaders.is_empty() && !loaders.iter().any(|l| l.eq_ignore_ascii_case(loader)) {
+            problems.push(loaders.join(", "));
+        }
+    }
+    
+    if problems.is_empty() {
+        None
+    } else {
+        Some(format!("{} is built for {}", jar, problems.join(" on ")))
+    }
+}
+
+/// Jar names in a mods folder, without `.disabled` suffixes
+pub(super) fn list_mod_jars(mods_dir: &Path) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(mods_dir) else {
+        return Vec::new();
+    };
+    
+    let mut jars: Vec<String> = entries
+        .flatten()
+        .filter(|entry| entry.path().is_file())
+        .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+        .filter_map(|name| split_mod_filename(&name).map(|(base, _, _)| base.to_string()))
+        .collect();
+    jars.sort();
+    jars.dedup();
+    jars
+}
+
+/// The jar a `.pw.toml` describes; its filename is relative to the game
+/// directory (`mods/x.jar`)
+pub(super) fn toml_jar_name(toml: &ModTomlExtended) -> Option<&str> {
+    Path::new(&toml.packwiz.filename).file_name().and_then(|name| name.to_str())
+}
+
+/// The readable `.pw.toml` files in a mods folder's index
+pub(super) fn index_tomls(mods_dir: &Path) -> Vec<(PathBuf, ModTomlExtended)> {
+    let Ok(entries) = fs::read_dir(index_dir(mods_dir)) else {
+        return Vec::new();
+    };
+    
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.to_string_lossy().ends_with(".pw.toml"))
+        .filter_map(|path| read_mod_toml(&path).ok().map(|toml| (path, toml)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn target(minecraft_version: &str, loader: &str) -> ModTarget {
+        ModTarget {
+            minecraft_version: minecraft_version.to_string(),
+            loader: Some(loader.to_string()),
+        }
+    }
+    
+    fn write_toml(mods_dir: &Path, name: &str, jar: &str, mc_version: &str, loader: &str) {
+        let index = index_dir(mods_dir);
+        fs::create_dir_all(&index).unwrap();
+        fs::write(index.join(format!("{}.pw.toml", name)), format!(
+            "name = \"{name}\"\nfilename = \"mods/{jar}\"\n\n\
+             [download]\nurl = \"https://example.com/{jar}\"\nhash-format = \"sha1\"\nhash = \"0\"\n\n\
+             [oxide]\nmc_versions = [\"{mc_version}\"]\nloaders = [\"{loader}\"]\n"
+        )).unwrap();
+    }
+    
+    #[test]
+    fn copies_selected_mods_with_metadata_and_state() {
+        let source = tempfile::tempdir().unwrap();
+        let dest = tempfile::tempdir().unwrap();
+        fs::write(source.path().join("a.jar"), "a").unwrap();
+        fs::write(source.path().join("b.jar.disabled"), "b").unwrap();
+        fs::write(source.path().join("c.jar"), "c").unwrap();
+        fs::write(source.path().join("a.jar.metadata.json"), "{}").unwrap();
+        write_toml(source.path(), "bee", "b.jar", "1.20.1", "fabric");
+        
+        let names = vec!["a.jar".to_string(), "b.jar.disabled".to_string(), "x.jar".to_string()];
+        let fabric = target("1.20.1", "fabric");
+        let result = copy_mods(source.path(), dest.path(), Some(&names), &fabric, &fabric).unwrap();
+        
+        assert_eq!(result.copied, vec!["a.jar", "b.jar"]);
+        assert_eq!(result.missing, vec!["x.jar"]);
+        assert!(result.warnings.is_empty());
+        assert!(dest.path().join("a.jar").is_file());
+        assert!(dest.path().join("a.jar.metadata.json").is_file());
+        assert!(dest.path().join("b.jar.disabled").is_file());
+        assert!(index_dir(dest.path()).join("bee.pw.toml").is_file());
+        assert!(!dest.path().join("c.jar").exists());
+    }
+    
+    #[test]
+    fn skips_mods_already_present() {
+        let source = tempfile::tempdir().unwrap();
+        let dest = tempfile::tempdir().unwrap();
+        fs::write(source.path().join("a.jar"), "a").unwrap();
+        fs::write(source.path().join("b.jar"), "b").unwrap();
+        fs::write(dest.path().join("a.jar.disabled"), "old").unwrap();
+        
+        let fabric = target("1.20.1", "fabric");
+        let result = copy_mods(source.path(), dest.path(), None, &fabric, &fabric).unwrap();
+        
+        assert_eq!(result.copied, vec!["b.jar"]);
+        assert_eq!(result.skipped, vec!["a.jar"]);
+        assert_eq!(fs::read_to_string(dest.path().join("a.jar.disabled")).unwrap(), "old");
+    }
+    
+    #[test]
+    fn warns_on_mismatched_loader_or_version() {
+        let source = tempfile::tempdir().unwrap();
+        let dest = tempfile::tempdir().unwrap();
+        fs::write(source.path().join("a.jar"), "a").unwrap();
+        fs::write(source.path().join("b.jar"), "b").unwrap();
+        write_toml(source.path(), "a", "a.jar", "1.21.1", "neoforge");
+        
+        let result = copy_mods(
+            source.path(),
+            dest.path(),
+            None,
+            &target("1.20.1", "fabric"),
+            &target("1.21.1", "fabric"),
+        ).unwrap();
+        
+        assert_eq!(result.copied.len(), 2);
+        assert_eq!(result.warnings, vec![
+            "a.jar is built for neoforge",
+            "b.jar is built for Minecraft 1.20.1",
+        ]);
+    }
+}
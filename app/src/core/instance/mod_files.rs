@@ -0,0 +1,571 @@
+//! Detection and repair of inconsistent mod file states.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use sha1::{Digest, Sha1};
+
+use super::import::files_identical;
+use crate::core::error::Result;
+use crate::core::files;
+use crate::core::modplatform::parse_mod_jar;
+
+/// Suffix appended to a mod jar to disable it.
+///
+/// Disabling a mod only renames `x.jar` to `x.jar.disabled`. Every supported
+/// loader scans the mods folder for `.jar` files, so the renamed file is
+/// ignored, and the launcher never puts the mods folder or a disabled file
+/// on the classpath.
+pub const DISABLED_SUFFIX: &str = ".disabled";
+
+/// Suffix of the legacy per-mod metadata sidecar
+pub(super) const METADATA_SUFFIX: &str = ".metadata.json";
+
+/// Split a mods folder filename into the jar name and whether it is enabled.
+///
+/// Any number of trailing `.disabled` suffixes are stripped, so
+/// `x.jar.disabled.disabled` resolves to (`x.jar`, false). Returns the number
+/// of suffixes as well; anything other than 0 or 1 needs repairing. Returns
+/// None for files that aren't mod jars.
+pub fn split_mod_filename(filename: &str) -> Option<(&str, bool, usize)> {
+    let mut base = filename;
+    let mut suffixes = 0;
+    while let Some(stripped) = base.strip_suffix(DISABLED_SUFFIX) {
+        base = stripped;
+        suffixes += 1;
+    }
+    
+    if base.ends_with(".jar") {
+        Some((base, suffixes == 0, suffixes))
+    } else {
+        None
+    }
+}
+
+/// Find the file currently backing a mod, in whichever state it is in.
+///
+/// Prefers the exact enabled or disabled name; falls back to a file with
+/// repeated `.disabled` suffixes.
+pub fn find_mod_file(mods_dir: &Path, filename: &str) -> Option<String> {
+    let enabled = filename.to_string();
+    let disabled = format!("{}{}", filename, DISABLED_SUFFIX);
+    for candidate in [&enabled, &disabled] {
+        if mods_dir.join(candidate).is_file() {
+            return Some(candidate.clone());
+        }
+    }
+    
+    fs::read_dir(mods_dir).ok()?
+        .flatten()
+        .filter(|entry| entry.path().is_file())
+        .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+        .find(|name| matches!(split_mod_filename(name), Some((base, _, _)) if base == filename))
+}
+
+/// A problem found in an instance's mods folder
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type")]
+pub enum ModFileIssue {
+    /// A jar with more than one `.disabled` suffix
+    ExtraSuffix { file: String, normalized: String },
+    /// The same jar exists both enabled and disabled
+    Duplicate { enabled: String, disabled: String },
+    /// A `.metadata.json` sidecar without a matching jar
+    OrphanMetadata { file: String },
+    /// A sidecar named after the disabled file (`x.jar.disabled.metadata.json`)
+    StaleMetadata { file: String, normalized: String },
+}
+
+/// Result of checking or repairing a mods folder
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ModFileRepair {
+    pub issues: Vec<ModFileIssue>,
+    /// Actions taken (empty for a dry run)
+    pub fixed: Vec<String>,
+    /// Issues that need the user to decide, e.g. differing duplicate copies
+    pub unresolved: Vec<String>,
+}
+
+/// Result of cleaning up metadata sidecars
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MetadataCleanup {
+    /// Sidecars deleted because their jar is gone or a current one exists
+    pub removed: Vec<String>,
+    /// Sidecars named after the disabled file, renamed to match the jar
+    pub renamed: Vec<String>,
+}
+
+/// One copy of a mod in a [`DuplicateMods`] group
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DuplicateModFile {
+    pub filename: String,
+    /// Version from the jar's metadata
+    pub version: String,
+    pub enabled: bool,
+}
+
+/// Jars in a mods folder that declare the same mod ID
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DuplicateMods {
+    pub mod_id: String,
+    /// Display name from the first copy's metadata
+    pub name: String,
+    pub files: Vec<DuplicateModFile>,
+}
+
+/// The files in the mods folder backing one jar
+#[derive(Default)]
+struct ModFileGroup {
+    enabled: Option<String>,
+    disabled: Option<String>,
+    extra_suffixes: Vec<String>,
+}
+
+/// Find mods with repeated `.disabled` suffixes, jars present both enabled
+/// and disabled, and metadata sidecars whose jar is gone.
+pub fn scan_mod_files(mods_dir: &Path) -> Vec<ModFileIssue> {
+    let (groups, metadata) = read_mods_dir(mods_dir);
+    let mut issues = Vec::new();
+    
+    for (base, group) in &groups {
+        for file in &group.extra_suffixes {
+            issues.push(ModFileIssue::ExtraSuffix {
+                file: file.clone(),
+                normalized: format!("{}{}", base, DISABLED_SUFFIX),
+            });
+        }
+        
+        let disabled = group.disabled.as_ref().or(group.extra_suffixes.first());
+        if let (Some(enabled), Some(disabled)) = (&group.enabled, disabled) {
+            issues.push(ModFileIssue::Duplicate {
+                enabled: enabled.clone(),
+                disabled: disabled.clone(),
+            });
+        }
+    }
+    
+    for file in metadata {
+        let jar = file.trim_end_matches(METADATA_SUFFIX);
+        let base = split_mod_filename(jar).map_or(jar, |(base, _, _)| base);
+        if !groups.contains_key(base) {
+            issues.push(ModFileIssue::OrphanMetadata { file });
+        } else if base != jar {
+            let normalized = format!("{}{}", base, METADATA_SUFFIX);
+            issues.push(ModFileIssue::StaleMetadata { file, normalized });
+        }
+    }
+    
+    issues
+}
+
+/// Bring every mod in the folder back to a single enabled or disabled file.
+///
+/// Repeated suffixes are collapsed to one, extra copies identical to the one
+/// being kept are deleted and metadata is cleaned up as in
+/// [`clean_mod_metadata`]. Differing copies
+/// of the same jar are left alone and reported as unresolved.
+pub fn repair_mod_files(mods_dir: &Path, use_recycle_bin: bool) -> Result<ModFileRepair> {
+    let issues = scan_mod_files(mods_dir);
+    let (groups, _) = read_mods_dir(mods_dir);
+    let mut fixed = Vec::new();
+    let mut unresolved = Vec::new();
+    
+    for (base, mut group) in groups {
+        let normalized = format!("{}{}", base, DISABLED_SUFFIX);
+        
+        for file in std::mem::take(&mut group.extra_suffixes) {
+            let path = mods_dir.join(&file);
+            
+            match group.disabled.clone() {
+                None => {
+                    fs::rename(&path, mods_dir.join(&normalized))?;
+                    fixed.push(format!("Renamed {} to {}", file, normalized));
+                    group.disabled = Some(normalized.clone());
+                }
+                Some(existing) if files_identical(&path, &mods_dir.join(&existing))? => {
+                    files::delete_file(&path, use_recycle_bin)?;
+                    fixed.push(format!("Removed duplicate {}", file));
+                }
+                Some(existing) => {
+                    unresolved.push(format!("{} differs from {}", file, existing));
+                }
+            }
+        }
+        
+        if let (Some(enabled), Some(disabled)) = (&group.enabled, &group.disabled) {
+            let disabled_path = mods_dir.join(disabled);
+            if files_identical(&mods_dir.join(enabled), &disabled_path)? {
+                files::delete_file(&disabled_path, use_recycle_bin)?;
+                fixed.push(format!("Removed disabled copy of enabled mod {}", enabled));
+            } else {
+                unresolved.push(format!("{} is both enabled and disabled with different contents", base));
+            }
+        }
+    }
+    
+    let cleanup = clean_mod_metadata(mods_dir, use_recycle_bin)?;
+    fixed.extend(cleanup.removed.iter().map(|file| format!("Removed stale metadata {}", file)));
+    fixed.extend(cleanup.renamed.iter().map(|file| format!("Renamed metadata {}", file)));
+    
+    Ok(ModFileRepair { issues, fixed, unresolved })
+}
+
+/// Remove `.metadata.json` sidecars whose jar no longer exists in any state.
+///
+/// Sidecars named after the disabled file are renamed to the jar's name, or
+/// removed when the jar already has its own sidecar.
+pub fn clean_mod_metadata(mods_dir: &Path, use_recycle_bin: bool) -> Result<MetadataCleanup> {
+    let (_, metadata) = read_mods_dir(mods_dir);
+    let mut cleanup = MetadataCleanup::default();
+    
+    for file in metadata {
+        let jar = file.trim_end_matches(METADATA_SUFFIX);
+        let base = split_mod_filename(jar).map_or(jar, |(base, _, _)| base);
+        let normalized = format!("{}{}", base, METADATA_SUFFIX);
+        
+        if find_mod_file(mods_dir, base).is_none() || (base != jar && mods_dir.join(&normalized).exists()) {
+            files::delete_file(mods_dir.join(&file), use_recycle_bin)?;
+            cleanup.removed.push(file);
+        } else if base != jar {
+            fs::rename(mods_dir.join(&file), mods_dir.join(&normalized))?;
+            cleanup.renamed.push(file);
+        }
+    }
+    
+    Ok(cleanup)
+}
+
+/// Content hash of a jar, valid while its size and modification time match
+struct CachedJarHash {
+    size: u64,
+    modified: Option<SystemTime>,
+    sha1: String,
+}
+
+/// Jar hashes from earlier fingerprints, so unchanged jars aren't re-read
+static JAR_HASHES: Lazy<Mutex<HashMap<PathBuf, CachedJarHash>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// SHA-1 of a jar's contents, reusing the cached hash while the jar's size
+/// and modification time are unchanged
+fn cached_jar_sha1(path: &Path, metadata: &fs::Metadata) -> Option<String> {
+    let size = metadata.len();
+    let modified = metadata.modified().ok();
+    if let Some(cached) = JAR_HASHES.lock().unwrap().get(path) {
+        if cached.size == size && cached.modified == modified {
+            return Some(cached.sha1.clone());
+        }
+    }
+    
+    let mut file = fs::File::open(path).ok()?;
+    let mut hasher = Sha1::new();
+    std::io::copy(&mut file, &mut hasher).ok()?;
+    let sha1 = hex::encode(hasher.finalize());
+    JAR_HASHES.lock().unwrap().insert(path.to_path_buf(), CachedJarHash { size, modified, sha1: sha1.clone() });
+    Some(sha1)
+}
+
+/// Fingerprint of the enabled mod set: a SHA-1 over the enabled jars' names
+/// and content hashes, sorted so it doesn't depend on directory listing order.
+/// Jar hashes are cached by size and modification time, so only new or
+/// changed jars are read.
+pub fn mod_set_fingerprint(mods_dir: &Path) -> String {
+    let mut jars: Vec<(String, String)> = fs::read_dir(mods_dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|entry| {
+                    let name = entry.file_name().to_str()?.to_string();
+                    let metadata = entry.metadata().ok().filter(|m| m.is_file())?;
+                    if !matches!(split_mod_filename(&name), Some((_, true, _))) {
+                        return None;
+                    }
+                    // An unreadable jar still counts, by its size
+                    let hash = cached_jar_sha1(&entry.path(), &metadata)
+                        .unwrap_or_else(|| metadata.len().to_string());
+                    Some((name, hash))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    jars.sort();
+    
+    let mut hasher = Sha1::new();
+    for (name, hash) in &jars {
+        hasher.update(format!("{}\0{}\n", name, hash).as_bytes());
+    }
+    hex::encode(hasher.finalize())
+}
+
+/// Group the mods folder's jars by name and collect metadata sidecars
+fn read_mods_dir(mods_dir: &Path) -> (BTreeMap<String, ModFileGroup>, Vec<String>) {
+    let mut groups: BTreeMap<String, ModFileGroup> = BTreeMap::new();
+    let mut metadata = Vec::new();
+    
+    let Ok(entries) = fs::read_dir(mods_dir) else {
+        return (groups, metadata);
+    };
+    
+    let mut names: Vec<String> = entries
+        .flatten()
+        .filter(|entry| entry.path().is_file())
+        .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+        .collect();
+    names.sort();
+    
+    for name in names {
+        if name.ends_with(METADATA_SUFFIX) {
+            metadata.push(name);
+            continue;
+        }
+        
+        let Some((base, enabled, suffixes)) = split_mod_filename(&name) else {
+            continue;
+        };
+        let group = groups.entry(base.to_string()).or_default();
+        match (enabled, suffixes) {
+            (true, _) => group.enabled = Some(name.clone()),
+            (false, 1) => group.disabled = Some(name.clone()),
+            _ => group.extra_suffixes.push(name.clone()),
+        }
+    }
+    
+    (groups, metadata)
+}
+
+/// Find jars, enabled or disabled, that declare the same mod ID, so the user
+/// can pick which copy to keep. A disabled copy identical to its enabled
+/// twin (`a.jar` and `a.jar.disabled`) is the same file, not a conflict;
+/// [`repair_mod_files`] cleans those up. Jars without readable metadata are
+/// skipped.
+pub fn find_duplicate_mods(mods_dir: &Path) -> Vec<DuplicateMods> {
+    let Ok(entries) = fs::read_dir(mods_dir) else {
+        return Vec::new();
+    };
+    let mut names: Vec<String> = entries
+        .flatten()
+        .filter(|entry| entry.path().is_file())
+        .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+        .filter(|name| split_mod_filename(name).is_some())
+        .collect();
+    names.sort();
+    
+    let mut by_id: BTreeMap<String, (String, Vec<DuplicateModFile>)> = BTreeMap::new();
+    for filename in names {
+        let Some((base, enabled, _)) = split_mod_filename(&filename) else {
+            continue;
+        };
+        let Some(details) = parse_mod_jar(&mods_dir.join(&filename)) else {
+            continue;
+        };
+        
+        let (_, files) = by_id
+            .entry(details.mod_id.clone())
+            .or_insert_with(|| (details.name.clone(), Vec::new()));
+        let twin = !enabled && files.iter().any(|file| {
+            file.enabled
+                && file.filename == base
+                && files_identical(&mods_dir.join(&file.filename), &mods_dir.join(&filename)).unwrap_or(false)
+        });
+        if !twin {
+            files.push(DuplicateModFile {
+                filename: filename.clone(),
+                version: details.version,
+                enabled,
+            });
+        }
+    }
+    
+    by_id
+        .into_iter()
+        .filter(|(_, (_, files))| files.len() > 1)
+        .map(|(mod_id, (name, files))| DuplicateMods { mod_id, name, files })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn write(dir: &Path, name: &str, contents: &str) {
+        fs::write(dir.join(name), contents).unwrap();
+    }
+    
+    #[test]
+    fn splits_repeated_disabled_suffixes() {
+        assert_eq!(split_mod_filename("a.jar"), Some(("a.jar", true, 0)));
+        assert_eq!(split_mod_filename("a.jar.disabled"), Some(("a.jar", false, 1)));
+        assert_eq!(split_mod_filename("a.jar.disabled.disabled"), Some(("a.jar", false, 2)));
+        assert_eq!(split_mod_filename("a.jar.metadata.json"), None);
+    }
+    
+    #[test]
+    fn finds_mod_in_any_state() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "a.jar.disabled.disabled", "a");
+        write(dir.path(), "b.jar", "b");
+        
+        assert_eq!(find_mod_file(dir.path(), "a.jar").as_deref(), Some("a.jar.disabled.disabled"));
+        assert_eq!(find_mod_file(dir.path(), "b.jar").as_deref(), Some("b.jar"));
+        assert_eq!(find_mod_file(dir.path(), "c.jar"), None);
+    }
+    
+    #[test]
+    fn scan_reports_each_kind_of_issue() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "a.jar.disabled.disabled", "a");
+        write(dir.path(), "b.jar", "b");
+        write(dir.path(), "b.jar.disabled", "b");
+        write(dir.path(), "c.jar.metadata.json", "{}");
+        write(dir.path(), "d.jar", "d");
+        write(dir.path(), "d.jar.metadata.json", "{}");
+        
+        let issues = scan_mod_files(dir.path());
+        assert_eq!(issues, vec![
+            ModFileIssue::ExtraSuffix {
+                file: "a.jar.disabled.disabled".to_string(),
+                normalized: "a.jar.disabled".to_string(),
+            },
+            ModFileIssue::Duplicate {
+                enabled: "b.jar".to_string(),
+                disabled: "b.jar.disabled".to_string(),
+            },
+            ModFileIssue::OrphanMetadata { file: "c.jar.metadata.json".to_string() },
+        ]);
+    }
+    
+    #[test]
+    fn repair_normalizes_files() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "a.jar.disabled.disabled", "a");
+        write(dir.path(), "b.jar", "b");
+        write(dir.path(), "b.jar.disabled", "b");
+        write(dir.path(), "c.jar.disabled", "c");
+        write(dir.path(), "c.jar.disabled.disabled", "c");
+        write(dir.path(), "d.jar.metadata.json", "{}");
+        
+        let repair = repair_mod_files(dir.path(), false).unwrap();
+        
+        assert!(repair.unresolved.is_empty());
+        assert!(dir.path().join("a.jar.disabled").exists());
+        assert!(!dir.path().join("a.jar.disabled.disabled").exists());
+        assert!(dir.path().join("b.jar").exists());
+        assert!(!dir.path().join("b.jar.disabled").exists());
+        assert!(dir.path().join("c.jar.disabled").exists());
+        assert!(!dir.path().join("c.jar.disabled.disabled").exists());
+        assert!(!dir.path().join("d.jar.metadata.json").exists());
+        assert!(scan_mod_files(dir.path()).is_empty());
+    }
+    
+    #[test]
+    fn cleans_orphaned_and_stale_metadata() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "a.jar.disabled", "a");
+        write(dir.path(), "a.jar.disabled.metadata.json", "{}");
+        write(dir.path(), "b.jar", "b");
+        write(dir.path(), "b.jar.metadata.json", "{}");
+        write(dir.path(), "b.jar.disabled.metadata.json", "{}");
+        write(dir.path(), "c.jar.metadata.json", "{}");
+        write(dir.path(), "d.jar.disabled.metadata.json", "{}");
+        
+        let cleanup = clean_mod_metadata(dir.path(), false).unwrap();
+        
+        assert_eq!(cleanup.removed, vec![
+            "b.jar.disabled.metadata.json",
+            "c.jar.metadata.json",
+            "d.jar.disabled.metadata.json",
+        ]);
+        assert_eq!(cleanup.renamed, vec!["a.jar.disabled.metadata.json"]);
+        assert!(dir.path().join("a.jar.metadata.json").exists());
+        assert!(dir.path().join("b.jar.metadata.json").exists());
+        assert!(scan_mod_files(dir.path()).is_empty());
+    }
+    
+    #[test]
+    fn fingerprint_tracks_enabled_mods() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "a.jar", "a");
+        write(dir.path(), "b.jar.disabled", "b");
+        write(dir.path(), "notes.txt", "not a mod");
+        let initial = mod_set_fingerprint(dir.path());
+        
+        // Disabled jars and other files don't count
+        write(dir.path(), "c.jar.disabled", "c");
+        write(dir.path(), "a.jar.metadata.json", "{}");
+        assert_eq!(mod_set_fingerprint(dir.path()), initial);
+        
+        write(dir.path(), "a.jar", "a, updated");
+        let updated = mod_set_fingerprint(dir.path());
+        assert_ne!(updated, initial);
+        
+        // Same size, different content
+        write(dir.path(), "a.jar", "b, updated");
+        assert_ne!(mod_set_fingerprint(dir.path()), updated);
+        
+        let empty = tempfile::tempdir().unwrap();
+        assert_ne!(mod_set_fingerprint(empty.path()), initial);
+    }
+    
+    #[test]
+    fn repair_keeps_differing_copies() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "a.jar", "new");
+        write(dir.path(), "a.jar.disabled", "old");
+        
+        let repair = repair_mod_files(dir.path(), false).unwrap();
+        
+        assert_eq!(repair.unresolved.len(), 1);
+        assert!(dir.path().join("a.jar").exists());
+        assert!(dir.path().join("a.jar.disabled").exists());
+    }
+    
+    fn write_mod(dir: &Path, name: &str, id: &str, version: &str) {
+        let mut zip = zip::ZipWriter::new(fs::File::create(dir.join(name)).unwrap());
+        zip.start_file("fabric.mod.json", zip::write::SimpleFileOptions::default()).unwrap();
+        let json = format!(r#"{{"id": "{}", "name": "{}", "version": "{}"}}"#, id, id, version);
+        std::io::Write::write_all(&mut zip, json.as_bytes()).unwrap();
+        zip.finish().unwrap();
+    }
+    
+    #[test]
+    fn finds_duplicate_mod_ids() {
+        let dir = tempfile::tempdir().unwrap();
+        write_mod(dir.path(), "sodium-1.jar", "sodium", "1.0");
+        write_mod(dir.path(), "sodium-2.jar.disabled", "sodium", "2.0");
+        write_mod(dir.path(), "lithium.jar", "lithium", "1.0");
+        // The same file enabled and disabled isn't a conflict
+        write_mod(dir.path(), "iris.jar", "iris", "1.0");
+        fs::copy(dir.path().join("iris.jar"), dir.path().join("iris.jar.disabled")).unwrap();
+        
+        let duplicates = find_duplicate_mods(dir.path());
+        
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates[0].mod_id, "sodium");
+        let files: Vec<_> = duplicates[0].files.iter()
+            .map(|file| (file.filename.as_str(), file.version.as_str(), file.enabled))
+            .collect();
+        assert_eq!(files, vec![("sodium-1.jar", "1.0", true), ("sodium-2.jar.disabled", "2.0", false)]);
+    }
+}
@@ -0,0 +1,290 @@
+//! Known-bad hash detection for mod jars.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+//!
+//! Files are compared by hash against a blocklist of confirmed-malicious
+//! files (such as those spread by fractureiser). This is not a general
+//! antivirus: a file that isn't on the list is not known to be safe.
+
+use std::collections::HashSet;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+use sha2::{Sha256, Sha512};
+
+use super::{split_mod_filename, JAR_MODS_DIR};
+use crate::core::error::Result;
+
+/// Blocklist shipped with the launcher, extended by the configured source
+const BUNDLED_BLOCKLIST: &str = include_str!("known_bad_hashes.json");
+
+/// Game directory folders whose jars are loaded as mods
+const SCANNED_GAME_DIRS: &[&str] = &["mods", "coremods"];
+
+/// A known-bad file, identified by one or more hashes (lowercase hex)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlocklistEntry {
+    #[serde(default)]
+    pub sha1: Option<String>,
+    #[serde(default)]
+    pub sha256: Option<String>,
+    #[serde(default)]
+    pub sha512: Option<String>,
+    /// What the file is, e.g. the infected mod and version
+    pub name: String,
+    /// Link to or summary of the advisory
+    #[serde(default)]
+    pub advisory: Option<String>,
+}
+
+/// A set of known-bad file hashes
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Blocklist {
+    #[serde(default)]
+    pub entries: Vec<BlocklistEntry>,
+}
+
+impl Blocklist {
+    /// The blocklist shipped with the launcher
+    pub fn bundled() -> Self {
+        Self::parse(BUNDLED_BLOCKLIST).unwrap_or_default()
+    }
+    
+    /// Parse a blocklist from its JSON form
+    pub fn parse(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+    
+    /// Add another list's entries
+    pub fn extend(&mut self, other: Blocklist) {
+        self.entries.extend(other.entries);
+    }
+    
+    /// The entry matching a file's hashes, if any
+    fn find(&self, hashes: &FileHashes) -> Option<&BlocklistEntry> {
+        let matches = |expected: &Option<String>, actual: &Option<String>| {
+            matches!((expected, actual), (Some(e), Some(a)) if e.eq_ignore_ascii_case(a))
+        };
+        self.entries.iter().find(|entry| {
+            matches(&entry.sha1, &Some(hashes.sha1.clone()))
+                || matches(&entry.sha256, &hashes.sha256)
+                || matches(&entry.sha512, &hashes.sha512)
+        })
+    }
+}
+
+/// A file's hashes. SHA-256 and SHA-512 are only computed when the
+/// blocklist has entries that use them.
+struct FileHashes {
+    sha1: String,
+    sha256: Option<String>,
+    sha512: Option<String>,
+}
+
+impl FileHashes {
+    fn compute(path: &Path, sha256: bool, sha512: bool) -> Result<Self> {
+        let mut file = std::fs::File::open(path)?;
+        let mut sha1_hasher = Sha1::new();
+        let mut sha256_hasher = sha256.then(Sha256::new);
+        let mut sha512_hasher = sha512.then(Sha512::new);
+        
+        let mut buffer = [0u8; 64 * 1024];
+        loop {
+            let read = file.read(&mut buffer)?;
+            if read == 0 {
+                break;
+            }
+            sha1_hasher.update(&buffer[..read]);
+            if let Some(hasher) = sha256_hasher.as_mut() {
+                hasher.update(&buffer[..read]);
+            }
+            if let Some(hasher) = sha512_hasher.as_mut() {
+                hasher.update(&buffer[..read]);
+            }
+        }
+        
+        Ok(Self {
+            sha1: hex::encode(sha1_hasher.finalize()),
+            sha256: sha256_hasher.map(|hasher| hex::encode(hasher.finalize())),
+            sha512: sha512_hasher.map(|hasher| hex::encode(hasher.finalize())),
+        })
+    }
+    
+    fn all(&self) -> impl Iterator<Item = &String> {
+        std::iter::once(&self.sha1).chain(self.sha256.iter()).chain(self.sha512.iter())
+    }
+}
+
+/// A scanned file that's on the blocklist
+#[derive(Debug, Clone, Serialize)]
+pub struct ScanMatch {
+    /// Path relative to the instance folder
+    pub file: String,
+    pub sha1: String,
+    /// Blocklist entry's description of the file
+    pub name: String,
+    pub advisory: Option<String>,
+}
+
+/// Result of scanning an instance's jars
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ScanReport {
+    /// False when no known-bad hashes were loaded, so no file was checked.
+    /// No matches then says nothing about whether the files are safe.
+    pub checked: bool,
+    /// Number of files hashed
+    pub scanned: usize,
+    /// Number of known-bad hashes checked against
+    pub blocklist_entries: usize,
+    pub matches: Vec<ScanMatch>,
+    /// Blocklisted files skipped because their hash is allowlisted
+    pub allowlisted: Vec<String>,
+    /// Files that couldn't be read, and blocklist sources that couldn't be loaded
+    pub warnings: Vec<String>,
+}
+
+/// Jars an instance loads: mods (including disabled ones and subfolders),
+/// coremods and jar mods
+fn scanned_files(instance_path: &Path, game_dir: &Path) -> Vec<PathBuf> {
+    let roots = SCANNED_GAME_DIRS.iter()
+        .map(|dir| game_dir.join(dir))
+        .chain(std::iter::once(instance_path.join(JAR_MODS_DIR)));
+    
+    roots
+        .flat_map(|root| walkdir::WalkDir::new(root).into_iter().flatten())
+        .filter(|entry| entry.file_type().is_file())
+        .filter(|entry| {
+            let name = entry.file_name().to_string_lossy();
+            split_mod_filename(&name).is_some() || name.ends_with(".zip")
+        })
+        .map(|entry| entry.into_path())
+        .collect()
+}
+
+/// Hash an instance's jars and report the ones on the blocklist. Files whose
+/// hash (any algorithm) is in `allowlist` are reported as allowlisted instead.
+pub fn scan_instance_files(
+    instance_path: &Path,
+    game_dir: &Path,
+    blocklist: &Blocklist,
+    allowlist: &[String],
+) -> ScanReport {
+    let allowlist: HashSet<String> = allowlist.iter().map(|hash| hash.trim().to_lowercase()).collect();
+    let needs_sha256 = blocklist.entries.iter().any(|entry| entry.sha256.is_some());
+    let needs_sha512 = blocklist.entries.iter().any(|entry| entry.sha512.is_some());
+    
+    let mut report = ScanReport {
+        blocklist_entries: blocklist.entries.len(),
+        ..Default::default()
+    };
+    if blocklist.entries.is_empty() {
+        report.warnings.push(
+            "No known-bad hashes are loaded, so no files were checked. Set a blocklist URL or file in the mod settings.".to_string()
+        );
+        return report;
+    }
+    report.checked = true;
+    
+    for path in scanned_files(instance_path, game_dir) {
+        let relative = path.strip_prefix(instance_path).unwrap_or(&path).to_string_lossy().to_string();
+        let hashes = match FileHashes::compute(&path, needs_sha256, needs_sha512) {
+            Ok(hashes) => hashes,
+            Err(e) => {
+                report.warnings.push(format!("Couldn't read {}: {}", relative, e));
+                continue;
+            }
+        };
+        report.scanned += 1;
+        
+        let Some(entry) = blocklist.find(&hashes) else {
+            continue;
+        };
+        if hashes.all().any(|hash| allowlist.contains(hash)) {
+            report.allowlisted.push(relative);
+            continue;
+        }
+        report.matches.push(ScanMatch {
+            file: relative,
+            sha1: hashes.sha1,
+            name: entry.name.clone(),
+            advisory: entry.advisory.clone(),
+        });
+    }
+    
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn reports_blocklisted_jars_unless_allowlisted() {
+        let dir = tempfile::tempdir().unwrap();
+        let instance_path = dir.path();
+        let game_dir = instance_path.join(".minecraft");
+        let mods = game_dir.join("mods");
+        std::fs::create_dir_all(mods.join("1.12.2")).unwrap();
+        std::fs::write(mods.join("clean.jar"), "clean").unwrap();
+        std::fs::write(mods.join("bad.jar.disabled"), "bad").unwrap();
+        std::fs::write(mods.join("1.12.2").join("nested.jar"), "nested").unwrap();
+        std::fs::write(mods.join("notes.txt"), "bad").unwrap();
+        
+        let bad_sha1 = hex::encode(Sha1::digest(b"bad"));
+        let nested_sha256 = hex::encode(Sha256::digest(b"nested"));
+        let blocklist = Blocklist::parse(&format!(
+            r#"{{"entries": [
+                {{"sha1": "{}", "name": "Bad mod", "advisory": "https://example.com/advisory"}},
+                {{"sha256": "{}", "name": "Nested mod"}}
+            ]}}"#,
+            bad_sha1.to_uppercase(), nested_sha256
+        )).unwrap();
+        
+        let report = scan_instance_files(instance_path, &game_dir, &blocklist, &[]);
+        assert!(report.checked);
+        assert_eq!(report.scanned, 3);
+        let mut names: Vec<&str> = report.matches.iter().map(|m| m.name.as_str()).collect();
+        names.sort();
+        assert_eq!(names, vec!["Bad mod", "Nested mod"]);
+        
+        let report = scan_instance_files(instance_path, &game_dir, &blocklist, &[bad_sha1]);
+        assert_eq!(report.matches.len(), 1);
+        assert_eq!(report.allowlisted.len(), 1);
+    }
+    
+    #[test]
+    fn empty_blocklist_is_not_a_clean_result() {
+        let dir = tempfile::tempdir().unwrap();
+        let game_dir = dir.path().join(".minecraft");
+        std::fs::create_dir_all(game_dir.join("mods")).unwrap();
+        std::fs::write(game_dir.join("mods").join("a.jar"), "a").unwrap();
+        
+        let report = scan_instance_files(dir.path(), &game_dir, &Blocklist::default(), &[]);
+        assert!(!report.checked);
+        assert_eq!(report.scanned, 0);
+        assert_eq!(report.warnings.len(), 1);
+    }
+    
+    #[test]
+    fn bundled_blocklist_parses() {
+        assert!(Blocklist::parse(BUNDLED_BLOCKLIST).is_ok());
+    }
+}
@@ -0,0 +1,136 @@
+//! Instance display name validation.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use serde::Serialize;
+
+/// Characters that can't appear in file names on at least one supported platform
+const UNSAFE_NAME_CHARS: &[char] = &['/', '\\', ':', '*', '?', '"', '<', '>', '|'];
+
+/// Result of checking an instance name
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NameValidation {
+    /// Whether the name can be used as-is
+    pub valid: bool,
+    /// Problems with the name, in display order
+    pub issues: Vec<String>,
+    /// A cleaned-up name that doesn't collide with an existing instance,
+    /// present whenever the name isn't valid
+    pub suggested_name: Option<String>,
+}
+
+/// Check a display name for emptiness, characters that are awkward in file
+/// and shortcut names, and collisions with `existing` names (case-insensitive).
+pub fn validate_instance_name<S: AsRef<str>>(name: &str, existing: &[S]) -> NameValidation {
+    let mut issues = Vec::new();
+    let trimmed = name.trim();
+    
+    if trimmed.is_empty() {
+        issues.push("Name can't be empty".to_string());
+    }
+    
+    let unsafe_chars: String = trimmed.chars()
+        .filter(|c| UNSAFE_NAME_CHARS.contains(c) || c.is_control())
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    if !unsafe_chars.is_empty() {
+        issues.push(format!("Name contains characters that can't be used in file names: {}", unsafe_chars));
+    }
+    
+    if trimmed.ends_with('.') {
+        issues.push("Name can't end with a period".to_string());
+    }
+    
+    if !trimmed.is_empty() && name_taken(trimmed, existing) {
+        issues.push(format!("An instance named \"{}\" already exists", trimmed));
+    }
+    
+    let valid = issues.is_empty();
+    let suggested_name = (!valid).then(|| unique_instance_name(&clean_name(name), existing));
+    
+    NameValidation {
+        valid,
+        issues,
+        suggested_name,
+    }
+}
+
+/// Append " (2)", " (3)", ... to `base` until it doesn't collide with `existing`
+pub fn unique_instance_name<S: AsRef<str>>(base: &str, existing: &[S]) -> String {
+    if !name_taken(base, existing) {
+        return base.to_string();
+    }
+    
+    (2..)
+        .map(|n| format!("{} ({})", base, n))
+        .find(|candidate| !name_taken(candidate, existing))
+        .expect("unbounded counter always finds a free name")
+}
+
+/// Remove unsafe characters, collapse whitespace and fall back to "Instance"
+fn clean_name(name: &str) -> String {
+    let cleaned: String = name.chars()
+        .filter(|c| !UNSAFE_NAME_CHARS.contains(c) && !c.is_control())
+        .collect();
+    let cleaned = cleaned.split_whitespace().collect::<Vec<_>>().join(" ");
+    let cleaned = cleaned.trim_end_matches('.').trim_end();
+    
+    if cleaned.is_empty() {
+        "Instance".to_string()
+    } else {
+        cleaned.to_string()
+    }
+}
+
+fn name_taken<S: AsRef<str>>(name: &str, existing: &[S]) -> bool {
+    existing.iter().any(|e| e.as_ref().trim().eq_ignore_ascii_case(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_valid_name_has_no_suggestion() {
+        let result = validate_instance_name("Survival", &["Creative"]);
+        assert!(result.valid);
+        assert!(result.suggested_name.is_none());
+    }
+    
+    #[test]
+    fn test_duplicate_name_suggests_next_free_number() {
+        let result = validate_instance_name("survival", &["Survival", "Survival (2)"]);
+        assert!(!result.valid);
+        assert_eq!(result.suggested_name.as_deref(), Some("survival (3)"));
+    }
+    
+    #[test]
+    fn test_unsafe_characters_are_removed_from_suggestion() {
+        let result = validate_instance_name("Mods: 1.20/Fabric?", &[] as &[&str]);
+        assert!(!result.valid);
+        assert_eq!(result.suggested_name.as_deref(), Some("Mods 1.20Fabric"));
+    }
+    
+    #[test]
+    fn test_empty_name_suggests_default() {
+        let result = validate_instance_name("   ", &["Instance"]);
+        assert!(!result.valid);
+        assert_eq!(result.suggested_name.as_deref(), Some("Instance (2)"));
+    }
+}
@@ -0,0 +1,226 @@
+//! Tracking of modpack-managed files for in-place pack updates.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+
+use crate::core::download::compute_sha1;
+use crate::core::error::Result;
+use super::transfer::FileToDownload;
+
+/// File in the instance directory recording the installed pack files
+pub const PACK_FILES_NAME: &str = "modpack_files.json";
+
+/// Files installed by the instance's modpack, as they were when installed.
+///
+/// This is the common ancestor for updates: a file whose hash still matches
+/// was not touched by the user and can be replaced or removed safely.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PackFileIndex {
+    /// Pack version these files belong to
+    #[serde(default)]
+    pub version_id: String,
+    
+    /// Game-directory relative path -> SHA1 of the file the pack installed
+    #[serde(default)]
+    pub files: BTreeMap<String, String>,
+    
+    /// Pack download key (see `pack_file_key`) -> path it was installed to
+    #[serde(default)]
+    pub sources: BTreeMap<String, String>,
+}
+
+impl PackFileIndex {
+    /// Load the index for an instance (empty if the instance has none)
+    pub fn load(instance_path: &Path) -> Self {
+        fs::read_to_string(instance_path.join(PACK_FILES_NAME))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+    
+    /// Save the index to the instance directory
+    pub fn save(&self, instance_path: &Path) -> Result<()> {
+        fs::write(instance_path.join(PACK_FILES_NAME), serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+    
+    /// Record a downloaded pack file by hashing it where it was installed
+    pub fn record_download(&mut self, game_dir: &Path, key: String, installed_path: &Path) {
+        let Some(relative) = relative_path(game_dir, installed_path) else {
+            return;
+        };
+        
+        if let Ok(hash) = compute_sha1(&installed_path.to_path_buf()) {
+            self.files.insert(relative.clone(), hash);
+        }
+        self.sources.insert(key, relative);
+    }
+}
+
+/// Key identifying a pack download across pack versions: the platform file
+/// for CurseForge files (whose names are only known once resolved), the
+/// install path otherwise
+pub fn pack_file_key(file: &FileToDownload) -> String {
+    match file.platform_info {
+        Some(ref info) if info.platform == "curseforge" => {
+            format!("curseforge:{}:{}", info.project_id, info.file_id)
+        }
+        _ => file.path.clone(),
+    }
+}
+
+/// What to do with a single pack file during an update
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeAction {
+    /// Install the new pack version of the file
+    Write,
+    /// Leave the file as it is
+    Keep,
+    /// Remove the file (dropped from the pack and not edited by the user)
+    Delete,
+    /// Both the user and the pack changed the file; keep the user's copy
+    Conflict,
+}
+
+/// Three-way decision for one file, given SHA1s of the originally installed
+/// version (`base`), what's on disk now (`current`) and the new pack's
+/// version (`new`, `None` when the pack dropped the file)
+pub fn merge_action(base: Option<&str>, current: Option<&str>, new: Option<&str>) -> MergeAction {
+    match (new, current) {
+        // Pack dropped the file
+        (None, None) => MergeAction::Keep,
+        (None, Some(current)) => match base {
+            Some(base) if base == current => MergeAction::Delete,
+            Some(_) => MergeAction::Conflict,
+            None => MergeAction::Keep,
+        },
+        
+        // User deleted a pack file: respect that unless the pack changed it
+        (Some(new), None) => match base {
+            Some(base) if base == new => MergeAction::Keep,
+            _ => MergeAction::Write,
+        },
+        
+        (Some(new), Some(current)) => {
+            if current == new {
+                MergeAction::Keep
+            } else if base == Some(current) {
+                MergeAction::Write
+            } else if base == Some(new) {
+                // Only the user changed it
+                MergeAction::Keep
+            } else {
+                MergeAction::Conflict
+            }
+        }
+    }
+}
+
+/// SHA1 of every file under a directory, keyed by relative path
+pub fn hash_tree(dir: &Path) -> Result<BTreeMap<String, String>> {
+    let mut hashes = BTreeMap::new();
+    
+    if !dir.exists() {
+        return Ok(hashes);
+    }
+    
+    for entry in walkdir::WalkDir::new(dir) {
+        let entry = entry.map_err(|e| std::io::Error::other(e.to_string()))?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        
+        if let Some(relative) = relative_path(dir, entry.path()) {
+            hashes.insert(relative, compute_sha1(&entry.path().to_path_buf())?);
+        }
+    }
+    
+    Ok(hashes)
+}
+
+/// SHA1 of a file, or `None` if it doesn't exist
+pub fn file_hash(path: &PathBuf) -> Option<String> {
+    if path.is_file() {
+        compute_sha1(path).ok()
+    } else {
+        None
+    }
+}
+
+/// Relative path with forward slashes, used as the index key
+fn relative_path(root: &Path, path: &Path) -> Option<String> {
+    path.strip_prefix(root)
+        .ok()
+        .map(|relative| relative.to_string_lossy().replace('\\', "/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn untouched_files_follow_the_pack() {
+        assert_eq!(merge_action(Some("a"), Some("a"), Some("b")), MergeAction::Write);
+        assert_eq!(merge_action(Some("a"), Some("a"), None), MergeAction::Delete);
+        assert_eq!(merge_action(None, None, Some("b")), MergeAction::Write);
+    }
+    
+    #[test]
+    fn user_changes_are_preserved() {
+        // Edited by the user, unchanged in the pack
+        assert_eq!(merge_action(Some("a"), Some("u"), Some("a")), MergeAction::Keep);
+        // Deleted by the user, unchanged in the pack
+        assert_eq!(merge_action(Some("a"), None, Some("a")), MergeAction::Keep);
+        // Added by the user and not part of the pack
+        assert_eq!(merge_action(None, Some("u"), None), MergeAction::Keep);
+    }
+    
+    #[test]
+    fn diverging_changes_conflict() {
+        assert_eq!(merge_action(Some("a"), Some("u"), Some("b")), MergeAction::Conflict);
+        assert_eq!(merge_action(Some("a"), Some("u"), None), MergeAction::Conflict);
+        // Pre-existing file the pack now wants to install
+        assert_eq!(merge_action(None, Some("u"), Some("b")), MergeAction::Conflict);
+        assert_eq!(merge_action(Some("a"), Some("b"), Some("b")), MergeAction::Keep);
+    }
+    
+    #[test]
+    fn index_round_trips_with_downloads() {
+        let dir = tempfile::tempdir().unwrap();
+        let game_dir = dir.path().join(".minecraft");
+        fs::create_dir_all(game_dir.join("mods")).unwrap();
+        fs::write(game_dir.join("mods/a.jar"), "jar").unwrap();
+        
+        let mut index = PackFileIndex {
+            version_id: "v1".to_string(),
+            ..Default::default()
+        };
+        index.record_download(&game_dir, "curseforge:1:2".to_string(), &game_dir.join("mods/a.jar"));
+        index.save(dir.path()).unwrap();
+        
+        let loaded = PackFileIndex::load(dir.path());
+        assert_eq!(loaded.version_id, "v1");
+        assert_eq!(loaded.sources.get("curseforge:1:2").map(String::as_str), Some("mods/a.jar"));
+        assert_eq!(loaded.files.get("mods/a.jar"), file_hash(&game_dir.join("mods/a.jar")).as_ref());
+    }
+}
@@ -0,0 +1,267 @@
+//! Instance recipes: everything needed to rebuild an instance without its files.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use chrono::{DateTime, Utc};
+use futures::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
+
+use super::mod_copy::{index_tomls, list_mod_jars, toml_jar_name};
+use super::mod_files::{find_mod_file, split_mod_filename, DISABLED_SUFFIX};
+use super::{portable_demo_lock_pin, Instance, InstanceSettings, ModLoader};
+use crate::core::download::download_binary_file;
+use crate::core::error::{OxideError, Result};
+use crate::core::rustwiz::parser::{index_dir, mod_toml_filename, verify_hash, write_mod_toml};
+use crate::core::rustwiz::types::ModTomlExtended;
+
+/// Version of the recipe manifest format
+pub const RECIPE_FORMAT_VERSION: u32 = 1;
+
+/// Number of mods downloaded at once when restoring a recipe
+const RESTORE_CONCURRENCY: usize = 4;
+
+/// A list of instance recipes, written as a single JSON file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecipeManifest {
+    pub format_version: u32,
+    pub exported_at: DateTime<Utc>,
+    pub instances: Vec<InstanceRecipe>,
+}
+
+/// An instance's version, loader, settings and tracked mods
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstanceRecipe {
+    pub name: String,
+    pub icon: String,
+    pub group: Option<String>,
+    pub minecraft_version: String,
+    pub mod_loader: Option<ModLoader>,
+    pub settings: InstanceSettings,
+    #[serde(default)]
+    pub notes: String,
+    /// Mods installed from a platform, which can be downloaded again
+    #[serde(default)]
+    pub mods: Vec<RecipeMod>,
+    /// Jars without platform metadata; these can't be restored
+    #[serde(default)]
+    pub untracked_mods: Vec<String>,
+}
+
+/// A tracked mod: its `.pw.toml` metadata and whether it was enabled
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecipeMod {
+    pub enabled: bool,
+    pub metadata: ModTomlExtended,
+}
+
+/// Outcome of downloading a recipe's mods
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RecipeModRestore {
+    /// Jar names that were downloaded
+    pub restored: Vec<String>,
+    /// Jar names that failed, with the reason
+    pub failed: Vec<String>,
+}
+
+impl InstanceRecipe {
+    /// Build a recipe from an instance and the mods in its mods folder
+    pub fn from_instance(instance: &Instance) -> Self {
+        let mods_dir = instance.mods_dir();
+        let tomls = index_tomls(&mods_dir);
+        
+        let mut mods = Vec::new();
+        let mut untracked_mods = Vec::new();
+        for jar in list_mod_jars(&mods_dir) {
+            let toml = tomls.iter().find(|(_, toml)| toml_jar_name(toml) == Some(jar.as_str()));
+            match toml {
+                Some((_, metadata)) => {
+                    let enabled = find_mod_file(&mods_dir, &jar)
+                        .and_then(|file| split_mod_filename(&file).map(|(_, enabled, _)| enabled))
+                        .unwrap_or(true);
+                    mods.push(RecipeMod { enabled, metadata: metadata.clone() });
+                }
+                None => untracked_mods.push(jar),
+            }
+        }
+        
+        // The demo lock PIN has to verify on the instance the recipe creates
+        let mut settings = instance.settings.clone();
+        settings.demo_lock_pin = portable_demo_lock_pin(&instance.settings, &instance.id);
+        
+        Self {
+            name: instance.name.clone(),
+            icon: instance.icon.clone(),
+            group: instance.group.clone(),
+            minecraft_version: instance.minecraft_version.clone(),
+            mod_loader: instance.mod_loader.clone(),
+            settings,
+            notes: instance.notes.clone(),
+            mods,
+            untracked_mods,
+        }
+    }
+    
+    /// Copy the recipe onto a freshly created instance.
+    ///
+    /// A Java path that doesn't exist on this machine is dropped so the
+    /// instance falls back to automatic Java selection.
+    pub fn apply_to(&self, instance: &mut Instance) {
+        instance.icon = self.icon.clone();
+        instance.group = self.group.clone();
+        instance.mod_loader = self.mod_loader.clone();
+        instance.settings = self.settings.clone();
+        instance.notes = self.notes.clone();
+        
+        if instance.settings.java_path.as_ref().is_some_and(|path| !path.exists()) {
+            instance.settings.java_path = None;
+        }
+    }
+}
+
+/// Write a recipe manifest for the given instances
+pub fn write_recipe_manifest(path: &Path, instances: &[Instance]) -> Result<RecipeManifest> {
+    let manifest = RecipeManifest {
+        format_version: RECIPE_FORMAT_VERSION,
+        exported_at: Utc::now(),
+        instances: instances.iter().map(InstanceRecipe::from_instance).collect(),
+    };
+    
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, serde_json::to_string_pretty(&manifest)?)?;
+    
+    Ok(manifest)
+}
+
+/// Read a recipe manifest, rejecting formats newer than this launcher knows
+pub fn read_recipe_manifest(path: &Path) -> Result<RecipeManifest> {
+    let content = fs::read_to_string(path)?;
+    let manifest: RecipeManifest = serde_json::from_str(&content)
+        .map_err(|e| OxideError::Other(format!("Failed to parse recipe manifest: {}", e)))?;
+    
+    if manifest.format_version > RECIPE_FORMAT_VERSION {
+        return Err(OxideError::Other(format!(
+            "Recipe manifest version {} is newer than this launcher supports",
+            manifest.format_version
+        )));
+    }
+    
+    Ok(manifest)
+}
+
+/// Download a recipe's mods into a mods folder and restore their metadata.
+///
+/// Each jar is checked against the hash in its metadata. Failures are
+/// collected rather than aborting the rest of the mods.
+pub async fn restore_recipe_mods(mods_dir: &Path, mods: &[RecipeMod]) -> RecipeModRestore {
+    // Built in a loop rather than with `map` so the future stays `Send` for
+    // the command that awaits it
+    let mut downloads = Vec::with_capacity(mods.len());
+    for recipe_mod in mods {
+        downloads.push(restore_mod(mods_dir, recipe_mod));
+    }
+    let results: Vec<(String, Result<()>)> = stream::iter(downloads)
+        .buffer_unordered(RESTORE_CONCURRENCY)
+        .collect()
+        .await;
+    
+    let mut restore = RecipeModRestore::default();
+    for (jar, result) in results {
+        match result {
+            Ok(()) => restore.restored.push(jar),
+            Err(e) => restore.failed.push(format!("{}: {}", jar, e)),
+        }
+    }
+    restore.restored.sort();
+    restore.failed.sort();
+    
+    restore
+}
+
+async fn restore_mod(mods_dir: &Path, recipe_mod: &RecipeMod) -> (String, Result<()>) {
+    let jar = toml_jar_name(&recipe_mod.metadata).unwrap_or_default().to_string();
+    let result = download_mod(mods_dir, &jar, recipe_mod).await;
+    (jar, result)
+}
+
+async fn download_mod(mods_dir: &Path, jar: &str, recipe_mod: &RecipeMod) -> Result<()> {
+    if jar.is_empty() {
+        return Err(OxideError::Other("Mod metadata has no filename".to_string()));
+    }
+    
+    let download = &recipe_mod.metadata.packwiz.download;
+    if download.url.is_empty() {
+        return Err(OxideError::DownloadBlocked("no download URL recorded".to_string()));
+    }
+    
+    let dest: PathBuf = if recipe_mod.enabled {
+        mods_dir.join(jar)
+    } else {
+        mods_dir.join(format!("{}{}", jar, DISABLED_SUFFIX))
+    };
+    download_binary_file(&download.url, &dest, None).await?;
+    
+    if !verify_hash(&dest, &download.hash, download.hash_format)? {
+        let _ = fs::remove_file(&dest);
+        return Err(OxideError::Other("Downloaded file doesn't match its recorded hash".to_string()));
+    }
+    
+    let index = index_dir(mods_dir);
+    fs::create_dir_all(&index)?;
+    write_mod_toml(&index.join(mod_toml_filename(jar)), &recipe_mod.metadata)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn recipe_tracks_indexed_mods_and_lists_the_rest() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut instance = Instance::new("Pack".to_string(), dir.path().to_path_buf(), "1.20.1".to_string());
+        instance.settings.java_path = Some(dir.path().join("missing-java"));
+        
+        let mods_dir = instance.mods_dir();
+        let index = index_dir(&mods_dir);
+        fs::create_dir_all(&index).unwrap();
+        fs::write(mods_dir.join("tracked.jar.disabled"), "a").unwrap();
+        fs::write(mods_dir.join("local.jar"), "b").unwrap();
+        fs::write(index.join("tracked.pw.toml"),
+            "name = \"Tracked\"\nfilename = \"mods/tracked.jar\"\n\n\
+             [download]\nurl = \"https://example.com/tracked.jar\"\nhash-format = \"sha1\"\nhash = \"0\"\n"
+        ).unwrap();
+        
+        let recipe = InstanceRecipe::from_instance(&instance);
+        assert_eq!(recipe.mods.len(), 1);
+        assert!(!recipe.mods[0].enabled);
+        assert_eq!(recipe.untracked_mods, vec!["local.jar".to_string()]);
+        
+        let manifest_path = dir.path().join("recipes.json");
+        write_recipe_manifest(&manifest_path, std::slice::from_ref(&instance)).unwrap();
+        let manifest = read_recipe_manifest(&manifest_path).unwrap();
+        assert_eq!(manifest.instances.len(), 1);
+        
+        let mut restored = Instance::new("Pack".to_string(), dir.path().join("new"), "1.20.1".to_string());
+        manifest.instances[0].apply_to(&mut restored);
+        assert_eq!(restored.settings.java_path, None);
+    }
+}
@@ -0,0 +1,220 @@
+//! Server pack generation from a client instance.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::fs;
+use std::path::Path;
+
+use crate::core::error::Result;
+use crate::core::modplatform::{mod_side, ModSide};
+
+/// Name of the generated POSIX start script
+pub const START_SCRIPT_SH: &str = "start.sh";
+
+/// Name of the generated Windows start script
+pub const START_SCRIPT_BAT: &str = "start.bat";
+
+/// Instance folders copied into a server pack as-is
+pub const SERVER_CONFIG_DIRS: &[&str] = &["config", "defaultconfigs"];
+
+/// How a generated server pack starts the server
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ServerLaunch {
+    /// Run a server jar directly (vanilla server or Fabric server launcher)
+    Jar(String),
+    /// Run the Forge/NeoForge installer on first start, then the installed server
+    ForgeInstaller {
+        installer: String,
+        /// Prefix of the server jar legacy installers produce (e.g. "forge-")
+        jar_prefix: String,
+    },
+    /// Run the Quilt installer on first start, then the Quilt server launcher
+    QuiltInstaller {
+        installer: String,
+        minecraft_version: String,
+        loader_version: String,
+    },
+}
+
+/// Mods sorted into what goes on the server and what was left out
+#[derive(Debug, Clone, Default)]
+pub struct ServerModSelection {
+    pub included: Vec<String>,
+    pub client_only: Vec<String>,
+    /// Included mods whose jar doesn't say which side they run on
+    pub unknown_side: Vec<String>,
+}
+
+/// Copy enabled mods that can run on a server, skipping client-only ones.
+/// Mods that don't declare a side are copied.
+pub fn copy_server_mods(mods_dir: &Path, target_dir: &Path) -> Result<ServerModSelection> {
+    let mut selection = ServerModSelection::default();
+    
+    let Ok(entries) = fs::read_dir(mods_dir) else {
+        return Ok(selection);
+    };
+    
+    let mut jars: Vec<_> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "jar"))
+        .collect();
+    jars.sort();
+    
+    fs::create_dir_all(target_dir)?;
+    
+    for jar in jars {
+        let filename = jar.file_name().unwrap_or_default().to_string_lossy().to_string();
+        
+        match mod_side(&jar) {
+            ModSide::Client => {
+                selection.client_only.push(filename);
+                continue;
+            }
+            ModSide::Unknown => selection.unknown_side.push(filename.clone()),
+            ModSide::Server | ModSide::Both => {}
+        }
+        
+        fs::copy(&jar, target_dir.join(&filename))?;
+        selection.included.push(filename);
+    }
+    
+    Ok(selection)
+}
+
+/// Render the POSIX start script
+pub fn start_script_sh(launch: &ServerLaunch, min_memory: u32, max_memory: u32) -> String {
+    let memory = format!("-Xms{}M -Xmx{}M", min_memory, max_memory);
+    let body = match launch {
+        ServerLaunch::Jar(jar) => format!("exec java {} -jar {} nogui\n", memory, jar),
+        ServerLaunch::ForgeInstaller { installer, jar_prefix } => format!(
+            "if [ ! -f run.sh ] && [ ! -f .installed ]; then\n\
+             \x20   java -jar {installer} --installServer && touch .installed || exit 1\n\
+             fi\n\
+             \n\
+             if [ -f run.sh ]; then\n\
+             \x20   echo \"{memory}\" > user_jvm_args.txt\n\
+             \x20   exec sh run.sh nogui\n\
+             fi\n\
+             \n\
+             exec java {memory} -jar \"$(ls {jar_prefix}*.jar | grep -v installer | head -n 1)\" nogui\n"
+        ),
+        ServerLaunch::QuiltInstaller { installer, minecraft_version, loader_version } => format!(
+            "if [ ! -f quilt-server-launch.jar ]; then\n\
+             \x20   java -jar {installer} install server {minecraft_version} {loader_version} --download-server --install-dir=. || exit 1\n\
+             fi\n\
+             \n\
+             exec java {memory} -jar quilt-server-launch.jar nogui\n"
+        ),
+    };
+    
+    format!("#!/bin/sh\ncd \"$(dirname \"$0\")\"\n\n{}", body)
+}
+
+/// Render the Windows start script
+pub fn start_script_bat(launch: &ServerLaunch, min_memory: u32, max_memory: u32) -> String {
+    let memory = format!("-Xms{}M -Xmx{}M", min_memory, max_memory);
+    let body = match launch {
+        ServerLaunch::Jar(jar) => format!("java {} -jar {} nogui\r\n", memory, jar),
+        ServerLaunch::ForgeInstaller { installer, jar_prefix } => format!(
+            "if not exist run.bat if not exist .installed (\r\n\
+             \x20   java -jar {installer} --installServer || exit /b 1\r\n\
+             \x20   type nul > .installed\r\n\
+             )\r\n\
+             \r\n\
+             if exist run.bat (\r\n\
+             \x20   echo {memory}> user_jvm_args.txt\r\n\
+             \x20   call run.bat nogui\r\n\
+             \x20   exit /b\r\n\
+             )\r\n\
+             \r\n\
+             for %%f in ({jar_prefix}*.jar) do (\r\n\
+             \x20   echo %%f | find /i \"installer\" > nul || java {memory} -jar %%f nogui\r\n\
+             )\r\n"
+        ),
+        ServerLaunch::QuiltInstaller { installer, minecraft_version, loader_version } => format!(
+            "if not exist quilt-server-launch.jar (\r\n\
+             \x20   java -jar {installer} install server {minecraft_version} {loader_version} --download-server --install-dir=. || exit /b 1\r\n\
+             )\r\n\
+             \r\n\
+             java {memory} -jar quilt-server-launch.jar nogui\r\n"
+        ),
+    };
+    
+    format!("@echo off\r\ncd /d \"%~dp0\"\r\n\r\n{}pause\r\n", body)
+}
+
+/// Write both start scripts, marking the POSIX one executable
+pub fn write_start_scripts(output_dir: &Path, launch: &ServerLaunch, min_memory: u32, max_memory: u32) -> Result<()> {
+    let sh_path = output_dir.join(START_SCRIPT_SH);
+    fs::write(&sh_path, start_script_sh(launch, min_memory, max_memory))?;
+    fs::write(output_dir.join(START_SCRIPT_BAT), start_script_bat(launch, min_memory, max_memory))?;
+    
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&sh_path, fs::Permissions::from_mode(0o755))?;
+    }
+    
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::write::{SimpleFileOptions, ZipWriter};
+    
+    fn write_mod(path: &Path, environment: &str) {
+        let mut writer = ZipWriter::new(fs::File::create(path).unwrap());
+        writer.start_file("fabric.mod.json", SimpleFileOptions::default()).unwrap();
+        write!(writer, r#"{{"id": "test", "version": "1.0", "environment": "{}"}}"#, environment).unwrap();
+        writer.finish().unwrap();
+    }
+    
+    #[test]
+    fn client_only_mods_are_left_out() {
+        let dir = tempfile::tempdir().unwrap();
+        let mods = dir.path().join("mods");
+        fs::create_dir_all(&mods).unwrap();
+        write_mod(&mods.join("both.jar"), "*");
+        write_mod(&mods.join("client.jar"), "client");
+        fs::write(mods.join("disabled.jar.disabled"), "").unwrap();
+        
+        let target = dir.path().join("server").join("mods");
+        let selection = copy_server_mods(&mods, &target).unwrap();
+        
+        assert_eq!(selection.included, vec!["both.jar"]);
+        assert_eq!(selection.client_only, vec!["client.jar"]);
+        assert!(target.join("both.jar").exists());
+        assert!(!target.join("client.jar").exists());
+    }
+    
+    #[test]
+    fn scripts_use_memory_settings() {
+        let launch = ServerLaunch::Jar("fabric-server-launch.jar".to_string());
+        
+        let sh = start_script_sh(&launch, 1024, 4096);
+        assert!(sh.starts_with("#!/bin/sh"));
+        assert!(sh.contains("java -Xms1024M -Xmx4096M -jar fabric-server-launch.jar nogui"));
+        
+        let bat = start_script_bat(&launch, 1024, 4096);
+        assert!(bat.contains("java -Xms1024M -Xmx4096M -jar fabric-server-launch.jar nogui\r\n"));
+    }
+}
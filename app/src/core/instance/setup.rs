@@ -20,34 +20,166 @@
 
 #![allow(dead_code)] // Setup fields will be used as features are completed
 
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 use crate::core::{
-    download::{download_file, download_file_verified, download_files, DownloadTask, DownloadProgress},
+    config::Config,
+    download::{compute_sha1, download_file_verified, download_files, DownloadTask, DownloadProgress},
     error::{OxideError, Result},
     minecraft::{
         version::{fetch_version_manifest, fetch_version_data},
+        local_versions::save_version_json,
         libraries::{get_missing_libraries, get_native_libraries, get_missing_native_libraries},
         assets::get_missing_assets,
     },
 };
 use super::Instance;
 
+/// Unwrap the results of a background download task
+fn join_downloads(
+    joined: std::result::Result<Vec<Result<()>>, tokio::task::JoinError>,
+) -> Result<Vec<Result<()>>> {
+    joined.map_err(|e| OxideError::Download(format!("Download task failed: {}", e)))
+}
+
+/// Download a batch of files with up to `max_concurrent` in flight,
+/// calling `report` with the bytes downloaded across all of them and the
+/// batch's total size. Returns each file's result once all have finished.
+/// Tasks that share a destination (e.g. assets with the same hash) are
+/// downloaded once.
+async fn download_batch(
+    mut tasks: Vec<DownloadTask>,
+    max_concurrent: usize,
+    report: impl Fn(u64, u64),
+) -> Result<Vec<Result<()>>> {
+    /// How often progress is reported while bytes are arriving
+    const REPORT_INTERVAL: Duration = Duration::from_millis(100);
+    
+    let mut destinations = HashSet::new();
+    tasks.retain(|task| destinations.insert(task.dest.clone()));
+    
+    let total_bytes: u64 = tasks.iter().filter_map(|task| task.size).sum();
+    report(0, total_bytes);
+    
+    let (download_tx, mut download_rx) = mpsc::channel(100);
+    let downloads = tokio::spawn(async move {
+        download_files(tasks, max_concurrent.max(1), Some(download_tx)).await
+    });
+    
+    let mut per_file: HashMap<PathBuf, u64> = HashMap::new();
+    let mut downloaded: u64 = 0;
+    let mut last_report = Instant::now();
+    while let Some(progress) = download_rx.recv().await {
+        match progress {
+            DownloadProgress::Progress { dest, downloaded: file_bytes, .. } => {
+                let previous = per_file.insert(dest, file_bytes).unwrap_or(0);
+                downloaded = (downloaded + file_bytes).saturating_sub(previous);
+            }
+            // A retried file starts over
+            DownloadProgress::Retrying { dest, .. } => {
+                downloaded = downloaded.saturating_sub(per_file.remove(&dest).unwrap_or(0));
+            }
+            _ => continue,
+        }
+        // Files without a known size can push the count past the total
+        if last_report.elapsed() >= REPORT_INTERVAL {
+            report(downloaded.min(total_bytes), total_bytes);
+            last_report = Instant::now();
+        }
+    }
+    report(total_bytes, total_bytes);
+    
+    join_downloads(downloads.await)
+}
+
+/// Fail setup on any failed download, so a corrupt or missing JAR is
+/// reported here instead of as a classloader crash at launch
+fn check_downloads(what: &str, results: Vec<Result<()>>) -> Result<()> {
+    let errors: Vec<String> = results
+        .into_iter()
+        .filter_map(|result| result.err())
+        .map(|e| e.to_string())
+        .collect();
+    if errors.is_empty() {
+        return Ok(());
+    }
+    
+    Err(OxideError::Download(format!(
+        "{} {} failed to download:\n{}",
+        errors.len(),
+        what,
+        errors.join("\n")
+    )))
+}
+
 /// Setup progress events
 #[derive(Debug, Clone)]
 pub enum SetupProgress {
     DownloadingVersionManifest,
     DownloadingVersionData,
     DownloadingClientJar { progress: u64, total: Option<u64> },
-    DownloadingLibraries { current: usize, total: usize },
+    DownloadingLibraries { downloaded: u64, total: u64 },
     ExtractingNatives,
     DownloadingAssetIndex,
-    DownloadingAssets { current: usize, total: usize },
+    DownloadingAssets { downloaded: u64, total: u64 },
     InstallingModloader(String),
     Complete,
     Error(String),
 }
 
+impl SetupProgress {
+    /// Short name of the setup step, for progress events
+    pub fn phase(&self) -> &'static str {
+        match self {
+            SetupProgress::DownloadingVersionManifest => "version_manifest",
+            SetupProgress::DownloadingVersionData => "version_data",
+            SetupProgress::DownloadingClientJar { .. } => "client_jar",
+            SetupProgress::DownloadingLibraries { .. } => "libraries",
+            SetupProgress::ExtractingNatives => "natives",
+            SetupProgress::DownloadingAssetIndex => "asset_index",
+            SetupProgress::DownloadingAssets { .. } => "assets",
+            SetupProgress::InstallingModloader(_) => "modloader",
+            SetupProgress::Complete => "complete",
+            SetupProgress::Error(_) => "error",
+        }
+    }
+    
+    /// Progress within the step as (done, total) bytes downloaded
+    pub fn counts(&self) -> Option<(u64, u64)> {
+        match self {
+            SetupProgress::DownloadingClientJar { progress, total: Some(total) } => Some((*progress, *total)),
+            SetupProgress::DownloadingLibraries { downloaded, total }
+            | SetupProgress::DownloadingAssets { downloaded, total } => Some((*downloaded, *total)),
+            _ => None,
+        }
+    }
+    
+    /// Overall setup progress from 0.0 to 1.0. Each step gets a share of
+    /// the bar roughly matching how long it usually takes.
+    pub fn fraction(&self) -> f32 {
+        let (start, end) = match self {
+            SetupProgress::DownloadingVersionManifest => (0.0, 0.02),
+            SetupProgress::DownloadingVersionData => (0.02, 0.05),
+            SetupProgress::DownloadingClientJar { .. } => (0.05, 0.2),
+            SetupProgress::DownloadingLibraries { .. } => (0.2, 0.45),
+            SetupProgress::ExtractingNatives => (0.45, 0.5),
+            SetupProgress::DownloadingAssetIndex => (0.5, 0.52),
+            SetupProgress::DownloadingAssets { .. } => (0.52, 0.9),
+            SetupProgress::InstallingModloader(_) => (0.9, 0.99),
+            SetupProgress::Complete => (1.0, 1.0),
+            SetupProgress::Error(_) => (0.0, 0.0),
+        };
+        
+        let within = match self.counts() {
+            Some((done, total)) if total > 0 => (done as f32 / total as f32).min(1.0),
+            _ => 0.0,
+        };
+        start + (end - start) * within
+    }
+}
+
 /// Setup a newly created instance - download all required files
 pub async fn setup_instance(
     instance: &Instance,
@@ -78,16 +210,23 @@ pub async fn setup_instance(
     // Setup directory structure
     let meta_dir = data_dir.join("meta");
     let versions_dir = meta_dir.join("versions").join(&instance.minecraft_version);
-    let libraries_dir = data_dir.join("libraries");
-    let assets_dir = data_dir.join("assets");
+    let config = Config::load().unwrap_or_default();
+    let libraries_dir = config.libraries_dir();
+    let assets_dir = config.assets_dir();
     let assets_objects_dir = assets_dir.join("objects");
     let assets_indexes_dir = assets_dir.join("indexes");
+    let max_concurrent = config.network.max_concurrent_downloads;
     
     std::fs::create_dir_all(&versions_dir)?;
     std::fs::create_dir_all(&libraries_dir)?;
     std::fs::create_dir_all(&assets_objects_dir)?;
     std::fs::create_dir_all(&assets_indexes_dir)?;
     
+    // Keep the version JSON so what's downloaded can be checked offline
+    if let Err(e) = save_version_json(&meta_dir, &version_data) {
+        tracing::warn!("Failed to save version JSON for {}: {}", instance.minecraft_version, e);
+    }
+    
     // 3. Download client JAR
     let client_jar_path = versions_dir.join(format!("{}.jar", &instance.minecraft_version));
     if !client_jar_path.exists() {
@@ -99,8 +238,8 @@ pub async fn setup_instance(
             let dest = client_jar_path.clone();
             let sha1 = client.sha1.clone();
             
-            tokio::spawn(async move {
-                let _ = download_file_verified(&url, &dest, &sha1, Some(download_tx)).await;
+            let download = tokio::spawn(async move {
+                download_file_verified(&url, &dest, &sha1, Some(download_tx)).await
             });
             
             // Forward progress
@@ -109,14 +248,14 @@ pub async fn setup_instance(
                     send_progress(SetupProgress::DownloadingClientJar { progress: downloaded, total });
                 }
             }
+            
+            check_downloads("client JAR", join_downloads(download.await.map(|result| vec![result]))?)?;
         }
     }
     
     // 4. Download libraries
     let missing_libs = get_missing_libraries(&version_data, &libraries_dir);
     if !missing_libs.is_empty() {
-        send_progress(SetupProgress::DownloadingLibraries { current: 0, total: missing_libs.len() });
-        
         let download_tasks: Vec<DownloadTask> = missing_libs.iter().map(|lib| {
             DownloadTask {
                 url: lib.url.clone(),
@@ -126,25 +265,10 @@ pub async fn setup_instance(
             }
         }).collect();
         
-        let (download_tx, mut download_rx) = mpsc::channel(100);
-        let tasks_clone = download_tasks.clone();
-        
-        // Download in background
-        tokio::spawn(async move {
-            let _ = download_files(tasks_clone, 5, Some(download_tx)).await;
-        });
-        
-        // Track progress
-        let mut completed = 0;
-        while let Some(progress) = download_rx.recv().await {
-            if let DownloadProgress::Completed { .. } = progress {
-                completed += 1;
-                send_progress(SetupProgress::DownloadingLibraries { 
-                    current: completed, 
-                    total: missing_libs.len() 
-                });
-            }
-        }
+        let results = download_batch(download_tasks, max_concurrent, |downloaded, total| {
+            send_progress(SetupProgress::DownloadingLibraries { downloaded, total });
+        }).await?;
+        check_downloads("libraries", results)?;
     }
     
     // 5. Download and extract native libraries
@@ -164,16 +288,8 @@ pub async fn setup_instance(
             }
         }).collect();
         
-        let (download_tx, mut download_rx) = mpsc::channel(100);
-        let tasks_clone = download_tasks.clone();
-        
-        // Download in background
-        tokio::spawn(async move {
-            let _ = download_files(tasks_clone, 5, Some(download_tx)).await;
-        });
-        
-        // Wait for all downloads to complete
-        while download_rx.recv().await.is_some() {}
+        let results = download_batch(download_tasks, max_concurrent, |_, _| {}).await?;
+        check_downloads("native libraries", results)?;
         
         tracing::info!("Native library JARs downloaded");
     }
@@ -196,8 +312,12 @@ pub async fn setup_instance(
     send_progress(SetupProgress::DownloadingAssetIndex);
     let asset_index_path = assets_indexes_dir.join(format!("{}.json", &version_data.assets));
     
-    if !asset_index_path.exists() {
-        download_file(&version_data.asset_index.url, &asset_index_path, None).await?;
+    // An index that doesn't match its hash (e.g. cut off by a crash) is fetched again
+    let asset_index = &version_data.asset_index;
+    let index_valid = asset_index_path.exists()
+        && compute_sha1(&asset_index_path).is_ok_and(|sha1| sha1.eq_ignore_ascii_case(&asset_index.sha1));
+    if !index_valid {
+        download_file_verified(&asset_index.url, &asset_index_path, &asset_index.sha1, None).await?;
     }
     
     // 7. Load asset index and download missing assets
@@ -209,8 +329,6 @@ pub async fn setup_instance(
         let missing_assets = get_missing_assets(&asset_index, &assets_dir);
         
         if !missing_assets.is_empty() {
-            send_progress(SetupProgress::DownloadingAssets { current: 0, total: missing_assets.len() });
-            
             let download_tasks: Vec<DownloadTask> = missing_assets.iter().map(|(_, asset)| {
                 DownloadTask {
                     url: asset.get_url(),
@@ -220,25 +338,12 @@ pub async fn setup_instance(
                 }
             }).collect();
             
-            let (download_tx, mut download_rx) = mpsc::channel(100);
-            let tasks_clone = download_tasks.clone();
-            
-            // Download assets in background
-            tokio::spawn(async move {
-                let _ = download_files(tasks_clone, 10, Some(download_tx)).await;
-            });
-            
-            // Track progress
-            let mut completed = 0;
-            while let Some(progress) = download_rx.recv().await {
-                if let DownloadProgress::Completed { .. } = progress {
-                    completed += 1;
-                    send_progress(SetupProgress::DownloadingAssets { 
-                        current: completed, 
-                        total: missing_assets.len() 
-                    });
-                }
-            }
+            // Assets used a fixed limit of 10; they follow the configured limit
+            // like libraries now, so a slow or metered connection can be throttled
+            let results = download_batch(download_tasks, max_concurrent, |downloaded, total| {
+                send_progress(SetupProgress::DownloadingAssets { downloaded, total });
+            }).await?;
+            check_downloads("assets", results)?;
         }
     }
     
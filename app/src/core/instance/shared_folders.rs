@@ -0,0 +1,332 @@
+//! Sharing an instance's screenshots or saves folder through a symlink.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+
+use crate::core::error::{OxideError, Result};
+
+/// A game folder that can be shared between instances
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SharedFolder {
+    Screenshots,
+    Saves,
+}
+
+impl SharedFolder {
+    /// Folder name inside the game directory
+    pub fn dir_name(&self) -> &'static str {
+        match self {
+            SharedFolder::Screenshots => "screenshots",
+            SharedFolder::Saves => "saves",
+        }
+    }
+    
+    /// Record of what this instance moved into the shared folder
+    fn manifest_path(&self, game_dir: &Path) -> PathBuf {
+        game_dir.join(format!(".shared-{}.json", self.dir_name()))
+    }
+}
+
+/// Entries moved into the shared folder, by their local name
+type Manifest = BTreeMap<String, String>;
+
+fn read_manifest(path: &Path) -> Manifest {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Write the manifest, removing it once nothing is left in it
+fn write_manifest(path: &Path, manifest: &Manifest) -> Result<()> {
+    if manifest.is_empty() {
+        let _ = fs::remove_file(path);
+    } else {
+        fs::write(path, serde_json::to_string_pretty(manifest)?)?;
+    }
+    Ok(())
+}
+
+/// Whether the instance's folder is currently a link to `shared_dir`
+pub fn is_shared(game_dir: &Path, folder: SharedFolder, shared_dir: &Path) -> bool {
+    fs::read_link(game_dir.join(folder.dir_name()))
+        .map(|target| target == shared_dir)
+        .unwrap_or(false)
+}
+
+/// Replace the instance's folder with a symlink to `shared_dir`.
+///
+/// Existing contents are moved into the shared folder first (renamed if the
+/// name is taken) and remembered so `unshare_folder` can give them back.
+/// Each move is recorded as it happens; if one fails, the ones before it are
+/// moved back. If symlinks can't be created nothing is moved. Returns the
+/// names moved.
+pub fn share_folder(game_dir: &Path, folder: SharedFolder, shared_dir: &Path) -> Result<Vec<String>> {
+    if is_shared(game_dir, folder, shared_dir) {
+        return Ok(Vec::new());
+    }
+    
+    fs::create_dir_all(shared_dir)?;
+    let local = game_dir.join(folder.dir_name());
+    
+    // Make sure a link can be created before touching any files
+    let staged_link = game_dir.join(format!(".{}-link", folder.dir_name()));
+    let _ = remove_link(&staged_link);
+    create_dir_link(shared_dir, &staged_link)?;
+    
+    let manifest_path = folder.manifest_path(game_dir);
+    let mut moved = read_manifest(&manifest_path);
+    let newly_moved = if fs::symlink_metadata(&local).map(|m| m.file_type().is_symlink()).unwrap_or(false) {
+        // Linked somewhere else before; just repoint it
+        remove_link(&local)?;
+        Vec::new()
+    } else if local.is_dir() {
+        match move_contents_to_shared(&local, shared_dir, &manifest_path, &mut moved) {
+            Ok(names) => names,
+            Err(e) => {
+                let _ = restore_moved(&local, shared_dir, &manifest_path, &mut moved);
+                let _ = remove_link(&staged_link);
+                return Err(e);
+            }
+        }
+    } else {
+        Vec::new()
+    };
+    
+    fs::rename(&staged_link, &local)?;
+    
+    Ok(newly_moved)
+}
+
+/// Move everything in `local` into `shared_dir`, recording each move in the
+/// manifest as soon as it's done. Returns the names moved.
+fn move_contents_to_shared(local: &Path, shared_dir: &Path, manifest_path: &Path, moved: &mut Manifest) -> Result<Vec<String>> {
+    let mut names = Vec::new();
+    for entry in fs::read_dir(local)? {
+        let name = entry?.file_name().to_string_lossy().to_string();
+        let shared_name = unique_name(shared_dir, &name);
+        move_entry(&local.join(&name), &shared_dir.join(&shared_name))?;
+        moved.insert(name.clone(), shared_name);
+        write_manifest(manifest_path, moved)?;
+        names.push(name);
+    }
+    fs::remove_dir(local)?;
+    Ok(names)
+}
+
+/// Move the recorded entries from `shared_dir` back into `local`, dropping
+/// each from the manifest once it's back. Returns the names restored.
+fn restore_moved(local: &Path, shared_dir: &Path, manifest_path: &Path, moved: &mut Manifest) -> Result<Vec<String>> {
+    fs::create_dir_all(local)?;
+    let mut restored = Vec::new();
+    for (name, shared_name) in moved.clone() {
+        let source = shared_dir.join(&shared_name);
+        if source.exists() {
+            move_entry(&source, &local.join(&name))?;
+            restored.push(name.clone());
+        }
+        moved.remove(&name);
+        write_manifest(manifest_path, moved)?;
+    }
+    Ok(restored)
+}
+
+/// Rename a file or folder, copying it and removing the original when the
+/// destination is on another filesystem
+fn move_entry(source: &Path, dest: &Path) -> Result<()> {
+    match fs::rename(source, dest) {
+        Err(e) if e.kind() == std::io::ErrorKind::CrossesDevices => {
+            if let Err(e) = copy_entry(source, dest) {
+                // Leave the original as the only copy
+                let _ = if dest.is_dir() { fs::remove_dir_all(dest) } else { fs::remove_file(dest) };
+                return Err(e.into());
+            }
+            if source.is_dir() {
+                fs::remove_dir_all(source)?;
+            } else {
+                fs::remove_file(source)?;
+            }
+            Ok(())
+        }
+        result => Ok(result?),
+    }
+}
+
+fn copy_entry(source: &Path, dest: &Path) -> std::io::Result<()> {
+    if !source.is_dir() {
+        return fs::copy(source, dest).map(|_| ());
+    }
+    fs::create_dir_all(dest)?;
+    for entry in fs::read_dir(source)? {
+        let entry = entry?;
+        copy_entry(&entry.path(), &dest.join(entry.file_name()))?;
+    }
+    Ok(())
+}
+
+/// Turn the instance's folder back into a regular folder.
+///
+/// The files the instance brought into the shared folder are moved back;
+/// anything created while sharing stays in the shared folder. A share that
+/// failed partway (the folder isn't a link but some files are recorded as
+/// moved) is rolled back the same way. Returns the names restored.
+pub fn unshare_folder(game_dir: &Path, folder: SharedFolder, shared_dir: &Path) -> Result<Vec<String>> {
+    let local = game_dir.join(folder.dir_name());
+    let manifest_path = folder.manifest_path(game_dir);
+    let is_link = fs::symlink_metadata(&local)
+        .map(|m| m.file_type().is_symlink())
+        .unwrap_or(false);
+    if !is_link && !manifest_path.exists() {
+        return Ok(Vec::new());
+    }
+    
+    if is_link {
+        remove_link(&local)?;
+    }
+    
+    let mut moved = read_manifest(&manifest_path);
+    let restored = restore_moved(&local, shared_dir, &manifest_path, &mut moved)?;
+    let _ = fs::remove_file(manifest_path);
+    
+    Ok(restored)
+}
+
+/// `name`, or `name (2)`, `name (3)`... if it is already taken in `dir`
+fn unique_name(dir: &Path, name: &str) -> String {
+    if !dir.join(name).exists() {
+        return name.to_string();
+    }
+    
+    let (stem, extension) = match name.rsplit_once('.') {
+        Some((stem, ext)) if !stem.is_empty() && dir.join(name).is_file() => (stem, format!(".{}", ext)),
+        _ => (name, String::new()),
+    };
+    
+    (2..)
+        .map(|n| format!("{} ({}){}", stem, n, extension))
+        .find(|candidate| !dir.join(candidate).exists())
+        .unwrap()
+}
+
+#[cfg(unix)]
+fn create_dir_link(target: &Path, link: &Path) -> Result<()> {
+    std::os::unix::fs::symlink(target, link).map_err(|e| link_error(link, e))
+}
+
+#[cfg(windows)]
+fn create_dir_link(target: &Path, link: &Path) -> Result<()> {
+    std::os::windows::fs::symlink_dir(target, link).map_err(|e| link_error(link, e))
+}
+
+fn link_error(link: &Path, e: std::io::Error) -> OxideError {
+    let hint = if cfg!(windows) {
+        " On Windows, creating symlinks requires Developer Mode or running as administrator."
+    } else {
+        ""
+    };
+    OxideError::Instance(format!("Couldn't create a symlink at {:?}: {}.{}", link, e, hint))
+}
+
+/// Remove a symlink without following it
+fn remove_link(link: &Path) -> std::io::Result<()> {
+    // Directory symlinks on Windows are removed like directories
+    fs::remove_file(link).or_else(|_| fs::remove_dir(link))
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn share_moves_contents_and_unshare_restores_them() {
+        let temp = tempfile::tempdir().unwrap();
+        let game_dir = temp.path().join("game");
+        let shared = temp.path().join("shared");
+        fs::create_dir_all(game_dir.join("screenshots")).unwrap();
+        fs::create_dir_all(&shared).unwrap();
+        fs::write(game_dir.join("screenshots/a.png"), "mine").unwrap();
+        fs::write(shared.join("a.png"), "theirs").unwrap();
+        
+        let moved = share_folder(&game_dir, SharedFolder::Screenshots, &shared).unwrap();
+        
+        assert_eq!(moved, vec!["a.png".to_string()]);
+        assert!(is_shared(&game_dir, SharedFolder::Screenshots, &shared));
+        assert_eq!(fs::read_to_string(shared.join("a (2).png")).unwrap(), "mine");
+        assert_eq!(fs::read_to_string(game_dir.join("screenshots/a.png")).unwrap(), "theirs");
+        
+        // Taken while shared; stays in the shared folder
+        fs::write(game_dir.join("screenshots/b.png"), "new").unwrap();
+        
+        let restored = unshare_folder(&game_dir, SharedFolder::Screenshots, &shared).unwrap();
+        
+        assert_eq!(restored, vec!["a.png".to_string()]);
+        assert!(!is_shared(&game_dir, SharedFolder::Screenshots, &shared));
+        assert_eq!(fs::read_to_string(game_dir.join("screenshots/a.png")).unwrap(), "mine");
+        assert!(shared.join("a.png").exists());
+        assert!(shared.join("b.png").exists());
+        assert!(!game_dir.join(".shared-screenshots.json").exists());
+    }
+    
+    #[test]
+    fn sharing_twice_is_a_no_op() {
+        let temp = tempfile::tempdir().unwrap();
+        let game_dir = temp.path().join("game");
+        let shared = temp.path().join("shared");
+        fs::create_dir_all(&game_dir).unwrap();
+        
+        share_folder(&game_dir, SharedFolder::Saves, &shared).unwrap();
+        assert!(share_folder(&game_dir, SharedFolder::Saves, &shared).unwrap().is_empty());
+        assert!(is_shared(&game_dir, SharedFolder::Saves, &shared));
+    }
+    
+    #[test]
+    fn unshare_rolls_back_a_share_that_failed_partway() {
+        let temp = tempfile::tempdir().unwrap();
+        let game_dir = temp.path().join("game");
+        let shared = temp.path().join("shared");
+        fs::create_dir_all(game_dir.join("saves/Left Behind")).unwrap();
+        fs::create_dir_all(shared.join("World")).unwrap();
+        fs::write(shared.join("World/level.dat"), "moved").unwrap();
+        fs::write(game_dir.join(".shared-saves.json"), r#"{"World": "World"}"#).unwrap();
+        
+        let restored = unshare_folder(&game_dir, SharedFolder::Saves, &shared).unwrap();
+        
+        assert_eq!(restored, vec!["World".to_string()]);
+        assert_eq!(fs::read_to_string(game_dir.join("saves/World/level.dat")).unwrap(), "moved");
+        assert!(game_dir.join("saves/Left Behind").is_dir());
+        assert!(!shared.join("World").exists());
+        assert!(!game_dir.join(".shared-saves.json").exists());
+    }
+    
+    #[test]
+    fn copies_folders_for_cross_device_moves() {
+        let temp = tempfile::tempdir().unwrap();
+        fs::create_dir_all(temp.path().join("a/region")).unwrap();
+        fs::write(temp.path().join("a/region/r.0.0.mca"), "chunk").unwrap();
+        
+        copy_entry(&temp.path().join("a"), &temp.path().join("b")).unwrap();
+        
+        assert_eq!(fs::read_to_string(temp.path().join("b/region/r.0.0.mca")).unwrap(), "chunk");
+    }
+}
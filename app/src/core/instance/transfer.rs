@@ -497,6 +497,140 @@ pub struct FTBTarget {
     pub target_type: Option<String>,
 }
 
+// =============================================================================
+// ATLauncher Instance Format (instance.json)
+// =============================================================================
+
+/// ATLauncher instance.json. It extends the Minecraft version JSON, so
+/// `id` is the Minecraft version; launcher data sits under `launcher`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ATLauncherInstanceJson {
+    /// Minecraft version
+    pub id: String,
+    
+    /// Launcher-side instance data
+    pub launcher: ATLauncherInstanceData,
+}
+
+/// The `launcher` block of an ATLauncher instance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ATLauncherInstanceData {
+    /// Instance name
+    pub name: String,
+    
+    /// Name of the pack the instance was installed from
+    #[serde(default)]
+    pub pack: Option<String>,
+    
+    /// Pack version
+    #[serde(default)]
+    pub version: Option<String>,
+    
+    /// Installed mod loader
+    #[serde(default)]
+    pub loader_version: Option<ATLauncherLoaderVersion>,
+    
+    /// Installed mods, resource packs and shader packs
+    #[serde(default)]
+    pub mods: Vec<ATLauncherMod>,
+    
+    /// Minimum memory (MB)
+    #[serde(default)]
+    pub initial_memory: Option<u32>,
+    
+    /// Maximum memory (MB)
+    #[serde(default)]
+    pub maximum_memory: Option<u32>,
+    
+    /// Extra JVM arguments
+    #[serde(default)]
+    pub java_arguments: Option<String>,
+}
+
+/// Mod loader recorded by ATLauncher
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ATLauncherLoaderVersion {
+    /// Loader version
+    pub version: String,
+    
+    /// Loader name ("Forge", "Fabric", "Quilt", "NeoForge")
+    #[serde(rename = "type")]
+    pub loader_type: String,
+}
+
+/// A mod entry in an ATLauncher instance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ATLauncherMod {
+    /// Display name
+    pub name: String,
+    
+    /// File name in its folder
+    pub file: String,
+    
+    /// What the file is ("mods", "resourcepack", "shaderpack", ...)
+    #[serde(rename = "type", default)]
+    pub mod_type: String,
+    
+    /// Disabled mods sit outside the mods folder and aren't restored
+    #[serde(default)]
+    pub disabled: bool,
+    
+    /// CurseForge project ID
+    #[serde(default)]
+    pub curse_forge_project_id: Option<u32>,
+    
+    /// CurseForge file ID
+    #[serde(default)]
+    pub curse_forge_file_id: Option<u32>,
+    
+    /// Modrinth version the file came from
+    #[serde(default)]
+    pub modrinth_version: Option<ATLauncherModrinthVersion>,
+}
+
+/// The Modrinth version recorded for an ATLauncher mod
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ATLauncherModrinthVersion {
+    /// Version ID
+    pub id: String,
+    
+    /// Project ID
+    pub project_id: String,
+    
+    /// Files in the version
+    #[serde(default)]
+    pub files: Vec<ATLauncherModrinthFile>,
+}
+
+/// A file in a Modrinth version recorded by ATLauncher
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ATLauncherModrinthFile {
+    pub url: String,
+    pub filename: String,
+    #[serde(default)]
+    pub primary: bool,
+    #[serde(default)]
+    pub size: u64,
+    #[serde(default)]
+    pub hashes: HashMap<String, String>,
+}
+
+impl ATLauncherMod {
+    /// Folder the file belongs in, relative to the game directory.
+    /// None for types the instance doesn't install as separate files.
+    pub fn target_folder(&self) -> Option<&'static str> {
+        match self.mod_type.as_str() {
+            "mods" | "" => Some("mods"),
+            "resourcepack" => Some("resourcepacks"),
+            "shaderpack" => Some("shaderpacks"),
+            _ => None,
+        }
+    }
+}
+
 // =============================================================================
 // Import Result
 // =============================================================================
@@ -533,6 +667,9 @@ pub struct ImportResult {
     
     /// Original settings
     pub settings: OxideInstanceSettings,
+    
+    /// Problems found while reading the pack, such as files with no known source
+    pub warnings: Vec<String>,
 }
 
 /// A file that needs to be downloaded
@@ -585,11 +722,12 @@ pub enum ImportType {
     Prism,
     /// Technic modpack
     Technic,
-    /// ATLauncher modpack (placeholder for future support)
-    #[allow(dead_code)]
+    /// ATLauncher instance
     ATLauncher,
     /// FTB App instance
     FTBApp,
+    /// A jar with no pack manifest: a pack installer, loader installer or mod
+    InstallerJar,
     /// Unknown format
     Unknown,
 }
@@ -73,6 +73,10 @@ pub struct Instance {
     /// Instance status
     #[serde(default)]
     pub status: InstanceStatus,
+    
+    /// Enabled mod set fingerprint at the last launch (see `mod_set_fingerprint`)
+    #[serde(default)]
+    pub last_launch_mod_set: Option<String>,
 }
 
 #[allow(dead_code)] // Helper methods will be used as features are completed
@@ -98,6 +102,7 @@ impl Instance {
             notes: String::new(),
             managed_pack: None,
             status: InstanceStatus::Ready,
+            last_launch_mod_set: None,
         }
     }
 
@@ -283,6 +288,15 @@ pub struct InstanceSettings {
     /// Override game arguments
     pub game_args: Option<String>,
     
+    /// Name of a shared argument profile whose arguments go before this
+    /// instance's own
+    #[serde(default)]
+    pub argument_profile: Option<String>,
+    
+    /// Include the global extra JVM arguments before this instance's own
+    #[serde(default = "default_true")]
+    pub use_global_jvm_args: bool,
+    
     /// Override minimum memory (MB)
     pub min_memory: Option<u32>,
     
@@ -299,6 +313,15 @@ pub struct InstanceSettings {
     #[serde(default)]
     pub fullscreen: bool,
     
+    /// Monitor to go fullscreen on, as an index into the connected displays
+    #[serde(default)]
+    pub monitor_index: Option<u32>,
+    
+    /// Game language written to options.txt (e.g. `ja_jp`); None leaves the
+    /// game's own choice alone
+    #[serde(default)]
+    pub language: Option<String>,
+    
     /// Pre-launch command
     pub pre_launch_command: Option<String>,
     
@@ -308,6 +331,11 @@ pub struct InstanceSettings {
     /// Wrapper command
     pub wrapper_command: Option<String>,
     
+    /// Environment variables set on the game process. `${NAME}` in a value
+    /// expands to the launcher's own environment; a later duplicate wins.
+    #[serde(default)]
+    pub env_vars: Vec<(String, String)>,
+    
     /// Enable game time recording
     #[serde(default = "default_true")]
     pub record_play_time: bool,
@@ -349,6 +377,26 @@ pub struct InstanceSettings {
     /// Log the full launch command for debugging
     #[serde(default)]
     pub log_launch_command: bool,
+    
+    // === Shared Folders ===
+    
+    /// Link the screenshots folder to the launcher-wide shared screenshots folder
+    #[serde(default)]
+    pub shared_screenshots: bool,
+    
+    /// Link the saves folder to the launcher-wide shared saves folder
+    #[serde(default)]
+    pub shared_saves: bool,
+    
+    // === Demo Lock ===
+    
+    /// Always launch in demo mode, whatever account or launch mode is chosen
+    #[serde(default)]
+    pub demo_locked: bool,
+    
+    /// Salted hash of the PIN needed to lift the demo lock
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub demo_lock_pin: Option<String>,
 }
 
 impl Default for InstanceSettings {
@@ -357,14 +405,19 @@ impl Default for InstanceSettings {
             java_path: None,
             jvm_args: None,
             game_args: None,
+            argument_profile: None,
+            use_global_jvm_args: true,
             min_memory: None,
             max_memory: None,
             window_width: None,
             window_height: None,
             fullscreen: false,
+            monitor_index: None,
+            language: None,
             pre_launch_command: None,
             post_exit_command: None,
             wrapper_command: None,
+            env_vars: Vec::new(),
             record_play_time: true,
             show_console: false,
             auto_close_console: true,
@@ -376,6 +429,10 @@ impl Default for InstanceSettings {
             use_java_console: false,
             disable_create_no_window: false,
             log_launch_command: false,
+            shared_screenshots: false,
+            shared_saves: false,
+            demo_locked: false,
+            demo_lock_pin: None,
         }
     }
 }
@@ -26,6 +26,7 @@ use tokio::time::timeout;
 use serde::{Deserialize, Serialize};
 use tracing::debug;
 use crate::core::java::install::{JavaInstallation, JavaArch, JavaValidationResult};
+use crate::core::java::permissions::ensure_executable;
 use crate::core::java::version::JavaVersion;
 
 /// Timeout for Java checker process (15 seconds like Prism)
@@ -170,6 +171,12 @@ impl JavaChecker {
         args.push("-XshowSettings:all".to_string());
         args.push("-version".to_string());
         
+        // Tell a missing binary apart from one that lost its executable bit
+        if let Err(e) = ensure_executable(&self.path) {
+            result.error = Some(e.to_string());
+            return result;
+        }
+        
         debug!("Running Java checker: {:?} {:?}", self.path, args);
         
         // Spawn the process
@@ -192,6 +199,10 @@ impl JavaChecker {
 
         let child = match process_result {
             Ok(child) => child,
+            Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => {
+                result.error = Some(format!("Java executable is not executable (permission denied): {}", self.path.display()));
+                return result;
+            }
             Err(e) => {
                 result.error = Some(format!("Failed to start Java process: {}", e));
                 return result;
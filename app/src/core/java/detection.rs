@@ -20,9 +20,13 @@
 
 use std::path::PathBuf;
 use std::collections::HashSet;
+use std::sync::Mutex;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
 use tracing::{debug, info};
 use crate::core::java::install::{JavaInstallation, JavaArch};
 use crate::core::java::version::JavaVersion;
+use crate::core::java::download::{get_java_install_dir, list_managed_java};
 
 /// The Java executable name for the current platform
 #[cfg(target_os = "windows")]
@@ -31,6 +35,22 @@ pub const JAVA_EXECUTABLE: &str = "javaw.exe";
 #[cfg(not(target_os = "windows"))]
 pub const JAVA_EXECUTABLE: &str = "java";
 
+/// Installations found by the last detection; cleared when managed Java changes
+static DETECTED_JAVA: Lazy<Mutex<Option<Vec<JavaInstallation>>>> = Lazy::new(|| Mutex::new(None));
+
+/// Java installations from the last detection, detecting them if there wasn't one
+pub fn cached_java_installations() -> Vec<JavaInstallation> {
+    if let Some(installations) = DETECTED_JAVA.lock().unwrap().as_ref() {
+        return installations.clone();
+    }
+    detect_java_installations()
+}
+
+/// Drop the cached detection, after installing or removing a Java
+pub fn forget_detected_java() {
+    *DETECTED_JAVA.lock().unwrap() = None;
+}
+
 /// Detect all Java installations on the system
 pub fn detect_java_installations() -> Vec<JavaInstallation> {
     info!("Detecting Java installations...");
@@ -38,6 +58,8 @@ pub fn detect_java_installations() -> Vec<JavaInstallation> {
     let mut found_paths: HashSet<PathBuf> = HashSet::new();
     let mut installations: Vec<JavaInstallation> = Vec::new();
     
+    let managed_dir = get_java_install_dir().ok();
+    
     // Get all candidate paths
     let candidates = get_all_java_candidates();
     debug!("Found {} candidate Java paths", candidates.len());
@@ -60,8 +82,9 @@ pub fn detect_java_installations() -> Vec<JavaInstallation> {
         debug!("Checking Java at: {:?}", path);
         
         // Try to get version info
-        if let Some(installation) = probe_java(&path) {
+        if let Some(mut installation) = probe_java(&path) {
             info!("Found Java {} at {:?}", installation.version, path);
+            installation.is_managed = managed_dir.as_ref().is_some_and(|dir| path.starts_with(dir));
             found_paths.insert(canonical);
             installations.push(installation);
         }
@@ -71,9 +94,45 @@ pub fn detect_java_installations() -> Vec<JavaInstallation> {
     installations.sort_by(|a, b| b.cmp(a));
     
     info!("Detected {} Java installations", installations.len());
+    *DETECTED_JAVA.lock().unwrap() = Some(installations.clone());
     installations
 }
 
+/// The range of Java major versions a Minecraft version can run on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JavaRequirement {
+    /// Oldest major version that can run the game
+    pub min_major: u32,
+    /// Major version Mojang ships for the game, preferred when choosing or downloading
+    pub recommended_major: u32,
+    /// Newest major version known to run the game
+    pub max_major: u32,
+}
+
+impl JavaRequirement {
+    /// Build the compatible range around a recommended major version
+    pub fn for_major(recommended_major: u32) -> Self {
+        let (min_major, max_major) = match recommended_major {
+            8 => (8, 8), // Minecraft < 1.17 strictly needs Java 8
+            16 => (16, 17), // 1.17 snapshots can use 16 or 17
+            17 => (17, 21), // 1.18-1.20.4 works with 17-21
+            21 => (21, 25), // 1.21+ requires 21+
+            _ => (recommended_major, recommended_major + 4), // Default: allow 4 versions higher
+        };
+        
+        Self {
+            min_major,
+            recommended_major,
+            max_major,
+        }
+    }
+    
+    /// Whether a Java major version falls inside the range
+    pub fn contains(&self, major: u32) -> bool {
+        (self.min_major..=self.max_major).contains(&major)
+    }
+}
+
 /// Find the best Java installation that meets the version requirement
 /// 
 /// Selection priority:
@@ -82,22 +141,21 @@ pub fn detect_java_installations() -> Vec<JavaInstallation> {
 /// 3. 64-bit over 32-bit
 /// 4. Managed installations over system-installed
 pub fn find_java_for_version(required_major: u32) -> Option<JavaInstallation> {
+    find_java_in_range(&JavaRequirement::for_major(required_major))
+}
+
+/// Find the best detected Java whose major version is inside `requirement`
+pub fn find_java_in_range(requirement: &JavaRequirement) -> Option<JavaInstallation> {
     let installations = detect_java_installations();
     
     if installations.is_empty() {
         return None;
     }
     
-    // Get compatible version range based on Minecraft requirements
-    let (min_version, max_version) = get_compatible_java_range(required_major);
-    
     // Filter to compatible installations
     let mut compatible: Vec<_> = installations
         .into_iter()
-        .filter(|java| {
-            let major = java.version.major;
-            major >= min_version && major <= max_version
-        })
+        .filter(|java| requirement.contains(java.version.major))
         .collect();
     
     if compatible.is_empty() {
@@ -105,6 +163,7 @@ pub fn find_java_for_version(required_major: u32) -> Option<JavaInstallation> {
     }
     
     // Score and sort installations
+    let required_major = requirement.recommended_major;
     compatible.sort_by(|a, b| {
         let score_a = score_java_installation(a, required_major);
         let score_b = score_java_installation(b, required_major);
@@ -114,17 +173,6 @@ pub fn find_java_for_version(required_major: u32) -> Option<JavaInstallation> {
     compatible.into_iter().next()
 }
 
-/// Get the compatible Java version range for a required version
-fn get_compatible_java_range(required_major: u32) -> (u32, u32) {
-    match required_major {
-        8 => (8, 8), // Minecraft < 1.17 strictly needs Java 8
-        16 => (16, 17), // 1.17 snapshots can use 16 or 17
-        17 => (17, 21), // 1.18-1.20.4 works with 17-21
-        21 => (21, 25), // 1.21+ requires 21+
-        _ => (required_major, required_major + 4), // Default: allow 4 versions higher
-    }
-}
-
 /// Score a Java installation for selection priority
 /// Higher score = better match
 fn score_java_installation(java: &JavaInstallation, required_major: u32) -> i32 {
@@ -164,9 +212,13 @@ fn score_java_installation(java: &JavaInstallation, required_major: u32) -> i32
 
 /// Find the best Java for a Minecraft version
 pub fn find_java_for_minecraft(minecraft_version: &str) -> Option<JavaInstallation> {
-    // Determine required Java version based on Minecraft version
-    let required = get_required_java_version(minecraft_version);
-    find_java_for_version(required)
+    // Any installation inside the compatible range will do
+    find_java_in_range(&get_java_requirement(minecraft_version))
+}
+
+/// Get the compatible Java range for a Minecraft version
+pub fn get_java_requirement(minecraft_version: &str) -> JavaRequirement {
+    JavaRequirement::for_major(get_required_java_version(minecraft_version))
 }
 
 /// Get the required Java major version for a Minecraft version
@@ -669,7 +721,8 @@ fn get_managed_java_paths() -> Vec<PathBuf> {
     if let Some(data_dir) = dirs::data_dir() {
         let managed_java_dir = data_dir.join("OxideLauncher").join("java");
         if managed_java_dir.exists() {
-            candidates.extend(find_java_in_directory(&managed_java_dir));
+            // Skips downloads that are still being extracted
+            candidates.extend(list_managed_java(&managed_java_dir).into_iter().filter_map(|java| java.java_path));
         }
     }
     
@@ -709,4 +762,18 @@ mod tests {
         assert_eq!(get_required_java_version("1.16.5"), 8);
         assert_eq!(get_required_java_version("1.12.2"), 8);
     }
+    
+    #[test]
+    fn test_java_requirement_range() {
+        let modern = get_java_requirement("1.20.4");
+        assert_eq!((modern.min_major, modern.recommended_major, modern.max_major), (17, 17, 21));
+        assert!(modern.contains(17));
+        assert!(modern.contains(21));
+        assert!(!modern.contains(16));
+        assert!(!modern.contains(22));
+        
+        let legacy = get_java_requirement("1.12.2");
+        assert!(legacy.contains(8));
+        assert!(!legacy.contains(11));
+    }
 }
@@ -22,11 +22,13 @@ use std::path::{Path, PathBuf};
 use tokio::sync::mpsc;
 use tokio::io::AsyncWriteExt;
 use futures::StreamExt;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 use serde::{Deserialize, Serialize};
 use crate::core::java::metadata::{JavaMetadata, DownloadType, get_current_arch, get_current_os};
+use crate::core::java::checker::JavaChecker;
 use crate::core::java::install::JavaInstallation;
-use crate::core::java::detection::JAVA_EXECUTABLE;
+use crate::core::java::detection::{forget_detected_java, JAVA_EXECUTABLE};
+use crate::core::java::permissions::repair_java_permissions;
 use crate::core::error::{OxideError, Result};
 
 /// Progress event for Java downloads
@@ -57,6 +59,30 @@ pub struct AvailableJavaVersion {
     pub is_lts: bool,
 }
 
+/// Suffix of the hidden folder a Java download is extracted into before it's
+/// moved into place
+const PARTIAL_SUFFIX: &str = ".partial";
+
+/// A folder in the managed Java directory
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManagedJava {
+    /// Folder name, e.g. "eclipse-adoptium-21-0"
+    pub name: String,
+    pub dir: PathBuf,
+    /// Major version from the folder name
+    pub major: Option<u32>,
+    /// The Java executable, if the folder has one
+    pub java_path: Option<PathBuf>,
+}
+
+/// A managed Java that is missing its executable or fails `JavaChecker`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BrokenManagedJava {
+    #[serde(flatten)]
+    pub java: ManagedJava,
+    pub error: String,
+}
+
 /// Get the managed Java installation directory
 pub fn get_java_install_dir() -> Result<PathBuf> {
     let data_dir = dirs::data_dir()
@@ -190,6 +216,12 @@ pub async fn download_java(
         metadata.version.minor
     );
     let java_dir = install_dir.join(&java_dir_name);
+    // Extract next to the final folder and rename once complete, so an
+    // interrupted download never leaves a half-extracted Java behind
+    let partial_dir = install_dir.join(format!(".{}{}", java_dir_name, PARTIAL_SUFFIX));
+    if partial_dir.exists() {
+        std::fs::remove_dir_all(&partial_dir)?;
+    }
     
     info!("Downloading Java to {:?}", java_dir);
     
@@ -201,21 +233,47 @@ pub async fn download_java(
         }).await;
     }
     
-    match metadata.download_type {
-        DownloadType::Archive => {
-            download_archive(&metadata.url, &java_dir, &metadata.checksum.hash, progress_tx.clone()).await?
+    let extracted = async {
+        match metadata.download_type {
+            DownloadType::Archive => {
+                download_archive(&metadata.url, &partial_dir, &metadata.checksum.hash, progress_tx.clone()).await?
+            }
+            DownloadType::Manifest => {
+                download_manifest(&metadata.url, &partial_dir, progress_tx.clone()).await?
+            }
+            DownloadType::Unknown => {
+                // Try archive download as default
+                download_archive(&metadata.url, &partial_dir, &metadata.checksum.hash, progress_tx.clone()).await?
+            }
         }
-        DownloadType::Manifest => {
-            download_manifest(&metadata.url, &java_dir, progress_tx.clone()).await?
+        
+        // Find the Java executable
+        let java_path = find_java_in_extracted_dir(&partial_dir)?;
+        
+        // Archives don't always carry Unix modes; without them the binaries can't be spawned
+        let fixed = repair_java_permissions(&java_path)?;
+        if !fixed.is_empty() {
+            info!("Set executable permission on {} Java binaries", fixed.len());
         }
-        DownloadType::Unknown => {
-            // Try archive download as default
-            download_archive(&metadata.url, &java_dir, &metadata.checksum.hash, progress_tx.clone()).await?
+        
+        Ok::<_, OxideError>(java_path)
+    }.await;
+    
+    let extracted_java = match extracted {
+        Ok(path) => path,
+        Err(e) => {
+            let _ = std::fs::remove_dir_all(&partial_dir);
+            return Err(e);
         }
-    }
+    };
     
-    // Find the Java executable
-    let java_path = find_java_in_extracted_dir(&java_dir)?;
+    // Replace any earlier (possibly broken) copy with the complete extraction
+    if java_dir.exists() {
+        std::fs::remove_dir_all(&java_dir)?;
+    }
+    std::fs::rename(&partial_dir, &java_dir)?;
+    forget_detected_java();
+    let java_path = java_dir.join(extracted_java.strip_prefix(&partial_dir).unwrap_or(&extracted_java));
     
     info!("Java installed successfully at {:?}", java_path);
     
@@ -498,6 +556,81 @@ fn compute_sha256(path: &Path) -> Result<String> {
     Ok(format!("{:x}", hash))
 }
 
+/// Major version from a managed Java folder name ("<vendor>-<major>-<minor>")
+pub fn managed_java_major(name: &str) -> Option<u32> {
+    let mut parts = name.rsplitn(3, '-');
+    let _minor = parts.next()?;
+    let major = parts.next()?;
+    parts.next()?;
+    major.parse().ok()
+}
+
+/// List the Java folders in a managed Java directory, skipping downloads
+/// still being extracted
+pub fn list_managed_java(install_dir: &Path) -> Vec<ManagedJava> {
+    let Ok(entries) = std::fs::read_dir(install_dir) else {
+        return Vec::new();
+    };
+    
+    let mut managed: Vec<ManagedJava> = entries
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.starts_with('.') {
+                return None;
+            }
+            let dir = entry.path();
+            Some(ManagedJava {
+                major: managed_java_major(&name),
+                java_path: find_java_in_extracted_dir(&dir).ok(),
+                name,
+                dir,
+            })
+        })
+        .collect();
+    managed.sort_by(|a, b| a.name.cmp(&b.name));
+    managed
+}
+
+/// Run `JavaChecker` on every managed Java and return the ones that fail,
+/// such as folders left half-extracted by an interrupted download
+pub async fn find_broken_managed_java() -> Result<Vec<BrokenManagedJava>> {
+    let mut broken = Vec::new();
+    
+    for java in list_managed_java(&get_java_install_dir()?) {
+        let error = match &java.java_path {
+            None => Some("No Java executable found".to_string()),
+            Some(path) => {
+                let result = JavaChecker::new(path.clone()).check().await;
+                (!result.valid).then(|| result.error.unwrap_or_else(|| "Java validation failed".to_string()))
+            }
+        };
+        if let Some(error) = error {
+            warn!("Managed Java {} is broken: {}", java.name, error);
+            broken.push(BrokenManagedJava { java, error });
+        }
+    }
+    
+    Ok(broken)
+}
+
+/// Delete a folder from the managed Java directory
+pub async fn delete_managed_java_dir(dir: &Path) -> Result<()> {
+    let managed_dir = get_java_install_dir()?;
+    if dir.parent() != Some(managed_dir.as_path()) {
+        return Err(OxideError::Other(
+            "Java installation is not in managed directory".to_string()
+        ));
+    }
+    
+    info!("Deleting managed Java folder {:?}", dir);
+    tokio::fs::remove_dir_all(dir).await?;
+    forget_detected_java();
+    
+    Ok(())
+}
+
 /// Delete a managed Java installation
 pub async fn delete_java_installation(installation: &JavaInstallation) -> Result<()> {
     if !installation.is_managed {
@@ -522,6 +655,32 @@ pub async fn delete_java_installation(installation: &JavaInstallation) -> Result
     
     // Remove the directory
     tokio::fs::remove_dir_all(&java_home).await?;
+    forget_detected_java();
     
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn lists_managed_java_folders() {
+        let dir = tempfile::tempdir().unwrap();
+        let bin = dir.path().join("eclipse-adoptium-21-0").join("jdk-21.0.4+7-jre").join("bin");
+        std::fs::create_dir_all(&bin).unwrap();
+        std::fs::write(bin.join(JAVA_EXECUTABLE), "").unwrap();
+        std::fs::create_dir_all(dir.path().join("azul-zulu-8-0").join("lib")).unwrap();
+        std::fs::create_dir_all(dir.path().join(".eclipse-adoptium-17-0.partial").join("bin")).unwrap();
+        
+        let managed = list_managed_java(dir.path());
+        assert_eq!(managed.len(), 2);
+        assert_eq!(managed[0].name, "azul-zulu-8-0");
+        assert_eq!(managed[0].major, Some(8));
+        assert_eq!(managed[0].java_path, None);
+        assert_eq!(managed[1].major, Some(21));
+        assert_eq!(managed[1].java_path, Some(bin.join(JAVA_EXECUTABLE)));
+        
+        assert_eq!(managed_java_major("custom"), None);
+    }
+}
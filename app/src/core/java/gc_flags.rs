@@ -0,0 +1,136 @@
+//! Aikar's G1 garbage collector flags, scaled to the heap size.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+/// Heaps above this (MB) get the large-heap variant of the flags
+const LARGE_HEAP_MB: u32 = 12 * 1024;
+
+/// Heaps below this (MB) get smaller G1 regions
+const SMALL_HEAP_MB: u32 = 4 * 1024;
+
+/// `-XX` options that pick a garbage collector other than G1; they would
+/// conflict with the generated flags
+const OTHER_GC_OPTIONS: &[&str] = &[
+    "UseZGC", "ZGenerational", "UseShenandoahGC", "UseParallelGC", "UseParallelOldGC",
+    "UseSerialGC", "UseConcMarkSweepGC", "UseEpsilonGC",
+];
+
+/// System properties Aikar's flags set to mark themselves
+const MARKER_PROPERTIES: &[&str] = &["-Dusing.aikars.flags=", "-Daikars.new.flags="];
+
+/// Aikar's flags for a heap of `max_memory_mb`: G1 with its region size and
+/// young generation and reserve sizes bucketed by heap size, following
+/// <https://docs.papermc.io/paper/aikars-flags>.
+pub fn aikars_flags(max_memory_mb: u32) -> Vec<String> {
+    let large = max_memory_mb > LARGE_HEAP_MB;
+    let region_size = if large {
+        "16M"
+    } else if max_memory_mb < SMALL_HEAP_MB {
+        "4M"
+    } else {
+        "8M"
+    };
+    let (new_size, max_new_size, reserve, initiating_occupancy) = if large {
+        (40, 50, 15, 20)
+    } else {
+        (30, 40, 20, 15)
+    };
+    
+    [
+        "-XX:+UseG1GC".to_string(),
+        "-XX:+ParallelRefProcEnabled".to_string(),
+        "-XX:MaxGCPauseMillis=200".to_string(),
+        "-XX:+UnlockExperimentalVMOptions".to_string(),
+        "-XX:+DisableExplicitGC".to_string(),
+        "-XX:+AlwaysPreTouch".to_string(),
+        format!("-XX:G1NewSizePercent={}", new_size),
+        format!("-XX:G1MaxNewSizePercent={}", max_new_size),
+        format!("-XX:G1HeapRegionSize={}", region_size),
+        format!("-XX:G1ReservePercent={}", reserve),
+        "-XX:G1HeapWastePercent=5".to_string(),
+        "-XX:G1MixedGCCountTarget=4".to_string(),
+        format!("-XX:InitiatingHeapOccupancyPercent={}", initiating_occupancy),
+        "-XX:G1MixedGCLiveThresholdPercent=90".to_string(),
+        "-XX:G1RSetUpdatingPauseTimePercent=5".to_string(),
+        "-XX:SurvivorRatio=32".to_string(),
+        "-XX:+PerfDisableSharedMem".to_string(),
+        "-XX:MaxTenuringThreshold=1".to_string(),
+        "-Dusing.aikars.flags=https://mcflags.emc.gs".to_string(),
+        "-Daikars.new.flags=true".to_string(),
+    ].into()
+}
+
+/// Name of a `-XX` option (`-XX:+UseG1GC` and `-XX:G1HeapRegionSize=8M`
+/// give `UseG1GC` and `G1HeapRegionSize`)
+fn xx_option_name(arg: &str) -> Option<&str> {
+    let option = arg.strip_prefix("-XX:")?;
+    let option = option.trim_start_matches(['+', '-']);
+    Some(option.split('=').next().unwrap_or(option))
+}
+
+/// Put Aikar's flags for `max_memory_mb` into a JVM argument string. Flags
+/// the set defines, earlier Aikar's flags and other garbage collector
+/// choices are replaced; every other argument is kept, in order, before the
+/// generated flags.
+pub fn apply_aikars_flags(jvm_args: &str, max_memory_mb: u32) -> String {
+    let flags = aikars_flags(max_memory_mb);
+    let replaced: Vec<&str> = flags.iter()
+        .filter_map(|flag| xx_option_name(flag))
+        .chain(OTHER_GC_OPTIONS.iter().copied())
+        .collect();
+    
+    let mut args: Vec<String> = jvm_args.split_whitespace()
+        .filter(|arg| {
+            let replaced_option = xx_option_name(arg).is_some_and(|name| replaced.contains(&name));
+            let marker = MARKER_PROPERTIES.iter().any(|prefix| arg.starts_with(prefix));
+            !replaced_option && !marker
+        })
+        .map(str::to_string)
+        .collect();
+    args.extend(flags);
+    
+    args.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn region_size_scales_with_heap() {
+        assert!(aikars_flags(2048).contains(&"-XX:G1HeapRegionSize=4M".to_string()));
+        assert!(aikars_flags(8192).contains(&"-XX:G1HeapRegionSize=8M".to_string()));
+        let large = aikars_flags(16384);
+        assert!(large.contains(&"-XX:G1HeapRegionSize=16M".to_string()));
+        assert!(large.contains(&"-XX:G1NewSizePercent=40".to_string()));
+    }
+    
+    #[test]
+    fn replaces_gc_flags_and_keeps_the_rest() {
+        let existing = "-Dfml.ignoreInvalidMinecraftCertificates=true -XX:+UseZGC -XX:G1HeapRegionSize=32M -Daikars.new.flags=true";
+        let applied = apply_aikars_flags(existing, 6144);
+        
+        assert!(applied.starts_with("-Dfml.ignoreInvalidMinecraftCertificates=true -XX:+UseG1GC"));
+        assert!(!applied.contains("UseZGC"));
+        assert!(!applied.contains("32M"));
+        assert_eq!(applied.matches("-Daikars.new.flags=true").count(), 1);
+        // Applying again changes nothing
+        assert_eq!(apply_aikars_flags(&applied, 6144), applied);
+    }
+}
@@ -0,0 +1,194 @@
+//! JVM memory allocation validation against system RAM.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use serde::{Deserialize, Serialize};
+
+/// Smallest heap (MB) that is accepted for min/max memory
+pub const MIN_ALLOCATION_MB: u32 = 256;
+
+/// Largest heap (MB) a 32-bit JVM can reliably reserve
+pub const MAX_32BIT_HEAP_MB: u32 = 1536;
+
+/// Share of physical RAM above which a max heap is likely to cause swapping
+const HIGH_MEMORY_RATIO: f64 = 0.9;
+
+/// Result of validating a min/max memory pair, with values snapped to a valid range
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MemoryValidation {
+    pub min_memory: u32,
+    pub max_memory: u32,
+    /// Physical RAM in MB, if it could be detected
+    pub total_memory_mb: Option<u32>,
+    pub warnings: Vec<String>,
+}
+
+/// Total physical RAM in MB
+pub fn total_physical_memory_mb() -> Option<u32> {
+    let mut system = sysinfo::System::new();
+    system.refresh_memory();
+    
+    match system.total_memory() {
+        0 => None,
+        bytes => Some(u32::try_from(bytes / 1024 / 1024).unwrap_or(u32::MAX)),
+    }
+}
+
+/// Validate min/max memory against this machine's RAM
+pub fn validate_memory(min_memory: u32, max_memory: u32) -> MemoryValidation {
+    validate_memory_with_total(min_memory, max_memory, total_physical_memory_mb())
+}
+
+/// Validate min/max memory (MB) against a known amount of RAM.
+///
+/// Max memory is clamped to physical RAM and min memory to max memory; both
+/// are raised to `MIN_ALLOCATION_MB`. A max above 90% of RAM is kept but warned about.
+pub fn validate_memory_with_total(
+    min_memory: u32,
+    max_memory: u32,
+    total_memory_mb: Option<u32>,
+) -> MemoryValidation {
+    let mut warnings = Vec::new();
+    let mut max = max_memory;
+    let mut min = min_memory;
+    
+    if max < MIN_ALLOCATION_MB {
+        warnings.push(format!("Maximum memory raised to the minimum of {} MB", MIN_ALLOCATION_MB));
+        max = MIN_ALLOCATION_MB;
+    }
+    
+    if let Some(total) = total_memory_mb {
+        if max > total {
+            warnings.push(format!(
+                "Maximum memory ({} MB) exceeds system RAM ({} MB) and was lowered",
+                max, total
+            ));
+            max = total.max(MIN_ALLOCATION_MB);
+        }
+        
+        if f64::from(max) > f64::from(total) * HIGH_MEMORY_RATIO {
+            warnings.push(format!(
+                "Maximum memory ({} MB) is over 90% of system RAM ({} MB) and may cause swapping",
+                max, total
+            ));
+        }
+    }
+    
+    if min < MIN_ALLOCATION_MB {
+        min = MIN_ALLOCATION_MB;
+    }
+    
+    if min > max {
+        warnings.push(format!(
+            "Minimum memory ({} MB) was higher than maximum memory and was lowered to {} MB",
+            min, max
+        ));
+        min = max;
+    }
+    
+    MemoryValidation {
+        min_memory: min,
+        max_memory: max,
+        total_memory_mb,
+        warnings,
+    }
+}
+
+/// Clamp min/max memory (MB) to what the JVM's architecture can address.
+///
+/// 64-bit JVMs are returned unchanged. A 32-bit JVM can't reserve much more
+/// than 1.5 GB of heap and exits before the game starts when asked for more.
+pub fn validate_memory_for_arch(is_64bit: bool, min_memory: u32, max_memory: u32) -> MemoryValidation {
+    let mut warnings = Vec::new();
+    let mut max = max_memory;
+    let mut min = min_memory;
+    
+    if !is_64bit && max > MAX_32BIT_HEAP_MB {
+        warnings.push(format!(
+            "Maximum memory ({} MB) is more than a 32-bit Java can use and was lowered to {} MB. \
+             Install a 64-bit Java to allocate more memory.",
+            max, MAX_32BIT_HEAP_MB
+        ));
+        max = MAX_32BIT_HEAP_MB;
+        min = min.min(max);
+    }
+    
+    MemoryValidation {
+        min_memory: min,
+        max_memory: max,
+        total_memory_mb: None,
+        warnings,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_valid_memory_is_unchanged() {
+        let result = validate_memory_with_total(1024, 4096, Some(16384));
+        assert_eq!(result.min_memory, 1024);
+        assert_eq!(result.max_memory, 4096);
+        assert!(result.warnings.is_empty());
+    }
+    
+    #[test]
+    fn test_max_clamped_to_system_ram() {
+        let result = validate_memory_with_total(512, 32768, Some(8192));
+        assert_eq!(result.max_memory, 8192);
+        assert!(!result.warnings.is_empty());
+    }
+    
+    #[test]
+    fn test_min_clamped_to_max() {
+        let result = validate_memory_with_total(8192, 4096, Some(16384));
+        assert_eq!(result.min_memory, 4096);
+        assert_eq!(result.max_memory, 4096);
+    }
+    
+    #[test]
+    fn test_high_ratio_warns_without_clamping() {
+        let result = validate_memory_with_total(512, 7900, Some(8192));
+        assert_eq!(result.max_memory, 7900);
+        assert_eq!(result.warnings.len(), 1);
+    }
+    
+    #[test]
+    fn test_unknown_ram_only_checks_ordering() {
+        let result = validate_memory_with_total(2048, 1024, None);
+        assert_eq!(result.min_memory, 1024);
+        assert_eq!(result.max_memory, 1024);
+    }
+    
+    #[test]
+    fn test_32bit_max_memory_clamped() {
+        let result = validate_memory_for_arch(false, 2048, 4096);
+        assert_eq!(result.max_memory, MAX_32BIT_HEAP_MB);
+        assert_eq!(result.min_memory, MAX_32BIT_HEAP_MB);
+        assert_eq!(result.warnings.len(), 1);
+    }
+    
+    #[test]
+    fn test_64bit_memory_unchanged() {
+        let result = validate_memory_for_arch(true, 2048, 8192);
+        assert_eq!(result.max_memory, 8192);
+        assert!(result.warnings.is_empty());
+    }
+}
@@ -24,6 +24,9 @@ pub mod install;
 pub mod detection;
 pub mod checker;
 pub mod download;
+pub mod memory;
+pub mod gc_flags;
+pub mod permissions;
 
 // Public API re-exports - may not all be used internally but are part of the public module interface
 #[allow(unused_imports)]
@@ -33,8 +36,13 @@ pub use metadata::{JavaMetadata, DownloadType};
 #[allow(unused_imports)]
 pub use install::{JavaInstallation, JavaArch};
 #[allow(unused_imports)] // Functions used through commands module
-pub use detection::{detect_java_installations, find_java_for_version, get_required_java_version};
+pub use detection::{
+    cached_java_installations, detect_java_installations, find_java_for_version, find_java_in_range,
+    forget_detected_java, get_java_requirement, get_required_java_version, JavaRequirement,
+};
 #[allow(unused_imports)]
 pub use checker::{JavaChecker, JavaCheckResult};
 #[allow(unused_imports)]
 pub use download::{download_java, fetch_adoptium_versions, AvailableJavaVersion, JavaDownloadProgress};
+#[allow(unused_imports)]
+pub use memory::{validate_memory, validate_memory_for_arch, MemoryValidation};
@@ -0,0 +1,145 @@
+//! Executable permission checks and repair for Java binaries.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::path::{Path, PathBuf};
+use tracing::info;
+use crate::core::error::{OxideError, Result};
+
+/// Helper binaries outside `bin/` the JVM runs itself
+/// (jspawnhelper starts child processes on Linux and macOS)
+const LIB_HELPERS: &[&str] = &["jspawnhelper"];
+
+/// Whether a file has any executable bit set. Always true off Unix.
+pub fn is_executable(path: &Path) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::metadata(path)
+            .map(|m| m.permissions().mode() & 0o111 != 0)
+            .unwrap_or(false)
+    }
+    #[cfg(not(unix))]
+    {
+        path.exists()
+    }
+}
+
+/// Make a file executable by everyone who can read it.
+/// Returns whether the permissions had to be changed.
+pub fn ensure_executable(path: &Path) -> Result<bool> {
+    if !path.is_file() {
+        return Err(OxideError::Java(format!("Java executable not found: {}", path.display())));
+    }
+    if is_executable(path) {
+        return Ok(false);
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut permissions = std::fs::metadata(path)?.permissions();
+        // Mirror the read bits onto the execute bits (0o644 -> 0o755)
+        let mode = permissions.mode();
+        permissions.set_mode(mode | ((mode & 0o444) >> 2) | 0o100);
+        std::fs::set_permissions(path, permissions).map_err(|e| {
+            OxideError::Java(format!(
+                "Java executable is not executable and its permissions couldn't be fixed ({}): {}",
+                e,
+                path.display()
+            ))
+        })?;
+        info!("Set executable permission on {:?}", path);
+    }
+
+    Ok(true)
+}
+
+/// Re-apply the executable bit to a Java installation's binaries: everything
+/// in the `bin` folder holding `java_path`, plus the JVM's helper binaries in
+/// `lib`. Returns the files that were fixed.
+pub fn repair_java_permissions(java_path: &Path) -> Result<Vec<PathBuf>> {
+    if !java_path.is_file() {
+        return Err(OxideError::Java(format!("Java executable not found: {}", java_path.display())));
+    }
+
+    let mut fixed = Vec::new();
+    if ensure_executable(java_path)? {
+        fixed.push(java_path.to_path_buf());
+    }
+
+    let Some(bin_dir) = java_path.parent() else {
+        return Ok(fixed);
+    };
+
+    let mut candidates: Vec<PathBuf> = std::fs::read_dir(bin_dir)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && path != java_path)
+        .collect();
+    if let Some(home) = bin_dir.parent() {
+        candidates.extend(LIB_HELPERS.iter().map(|name| home.join("lib").join(name)));
+    }
+
+    for path in candidates.into_iter().filter(|path| path.is_file()) {
+        if ensure_executable(&path)? {
+            fixed.push(path);
+        }
+    }
+
+    Ok(fixed)
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+
+    fn write_file(path: &Path, mode: u32) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, "#!/bin/sh\n").unwrap();
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(mode)).unwrap();
+    }
+
+    #[test]
+    fn repair_sets_the_executable_bit() {
+        let dir = tempfile::tempdir().unwrap();
+        let java = dir.path().join("bin").join("java");
+        let keytool = dir.path().join("bin").join("keytool");
+        let helper = dir.path().join("lib").join("jspawnhelper");
+        write_file(&java, 0o644);
+        write_file(&keytool, 0o755);
+        write_file(&helper, 0o600);
+
+        assert!(!is_executable(&java));
+        let fixed = repair_java_permissions(&java).unwrap();
+
+        assert_eq!(fixed, vec![java.clone(), helper.clone()]);
+        assert_eq!(std::fs::metadata(&java).unwrap().permissions().mode() & 0o777, 0o755);
+        assert_eq!(std::fs::metadata(&helper).unwrap().permissions().mode() & 0o777, 0o700);
+        assert!(repair_java_permissions(&java).unwrap().is_empty());
+    }
+
+    #[test]
+    fn missing_binary_is_reported_as_not_found() {
+        let dir = tempfile::tempdir().unwrap();
+        let err = ensure_executable(&dir.path().join("bin").join("java")).unwrap_err();
+        assert!(err.to_string().contains("not found"));
+    }
+}
@@ -0,0 +1,234 @@
+//! The classpath a launch uses, shared by the launch step and classpath previews.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+use super::LaunchContext;
+use crate::core::instance::DISABLED_SUFFIX;
+use crate::core::minecraft::libraries::{get_required_libraries, library_path};
+use crate::core::minecraft::local_versions::client_jar_path;
+use crate::core::minecraft::version::VersionData;
+use crate::core::modloaders::ModloaderProfile;
+
+/// What put a jar on the classpath
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClasspathSource {
+    /// A library from the mod loader's profile
+    Modloader,
+    /// A library of the Minecraft version
+    Library,
+    /// The Minecraft jar (or the instance's patched jar)
+    ClientJar,
+}
+
+/// A jar on the classpath
+#[derive(Debug, Clone, Serialize)]
+pub struct ClasspathEntry {
+    /// Path as passed to Java
+    pub path: String,
+    pub source: ClasspathSource,
+    /// Whether the file is on disk. Missing libraries are left off the
+    /// launch classpath; a missing client jar fails the launch.
+    pub exists: bool,
+}
+
+/// Whether a path is a disabled mod or library (`x.jar.disabled`).
+/// Loaders only pick up `.jar` files from the mods folder, and these are
+/// also kept off the classpath in case a profile lists one explicitly.
+fn is_disabled_file(path: &Path) -> bool {
+    path.to_string_lossy().ends_with(DISABLED_SUFFIX)
+}
+
+/// Normalize a path to use the OS-native separator
+/// This is needed because maven_to_path uses forward slashes,
+/// but Java on Windows may not handle mixed path separators correctly
+pub(crate) fn normalize_path(path: &Path) -> String {
+    // Use canonicalize if possible, otherwise just convert to string
+    // canonicalize will normalize the path and resolve symlinks
+    if let Ok(canonical) = path.canonicalize() {
+        let path_str = canonical.to_string_lossy().to_string();
+        // On Windows, canonicalize adds \\?\ prefix which Java doesn't understand
+        // Strip it if present
+        #[cfg(windows)]
+        {
+            if let Some(stripped) = path_str.strip_prefix(r"\\?\") {
+                return stripped.to_string();
+            }
+        }
+        path_str
+    } else {
+        // Path doesn't exist or can't be canonicalized, just replace slashes on Windows
+        #[cfg(windows)]
+        {
+            path.to_string_lossy().replace('/', "\\")
+        }
+        #[cfg(not(windows))]
+        {
+            path.to_string_lossy().to_string()
+        }
+    }
+}
+
+/// Check if a library is natives-only (has no main JAR, only native libraries)
+/// These libraries should be skipped when building the classpath
+fn is_natives_only_library(name: &str) -> bool {
+    // Parse the library name to get the artifact
+    // Format: group:artifact:version or group:artifact:version:classifier
+    let parts: Vec<&str> = name.split(':').collect();
+    if parts.len() < 3 {
+        return false;
+    }
+    
+    let artifact = parts[1];
+    
+    // Known natives-only libraries that don't have a main JAR
+    // These are platform-specific native library containers
+    artifact.ends_with("-platform") 
+        || artifact == "twitch-platform" 
+        || artifact == "twitch-external-platform"
+}
+
+/// The jar the game runs: the instance's patched jar if jar mods were
+/// applied, otherwise the version's client jar
+pub fn client_jar(context: &LaunchContext) -> PathBuf {
+    context.client_jar.clone().unwrap_or_else(|| {
+        client_jar_path(&context.config.meta_dir(), &context.instance.minecraft_version)
+    })
+}
+
+/// Every jar a launch puts on the classpath, in order, including ones that
+/// are missing on disk. Mod loader libraries come first, then the version's
+/// libraries and the client jar; a jar listed by both appears once.
+pub fn classpath_entries(
+    context: &LaunchContext,
+    version_data: &VersionData,
+    modloader_profile: Option<&ModloaderProfile>,
+) -> Vec<ClasspathEntry> {
+    let mut entries: Vec<ClasspathEntry> = Vec::new();
+    let mut push = |path: &Path, source: ClasspathSource| {
+        if is_disabled_file(path) {
+            tracing::debug!("Skipping disabled library: {:?}", path);
+            return;
+        }
+        let normalized = normalize_path(path);
+        if !entries.iter().any(|e| e.path == normalized) {
+            entries.push(ClasspathEntry { path: normalized, source, exists: path.exists() });
+        }
+    };
+    
+    // Mod loader libraries need to be before vanilla libs
+    if let Some(profile) = modloader_profile {
+        for lib in &profile.libraries {
+            // Natives-only libraries have no main JAR to add to the classpath
+            if !lib.applies_to_current_os() || is_natives_only_library(&lib.name) {
+                continue;
+            }
+            push(&context.libraries_dir.join(lib.get_path()), ClasspathSource::Modloader);
+        }
+    }
+    
+    for lib in get_required_libraries(version_data) {
+        push(&library_path(lib, &context.libraries_dir), ClasspathSource::Library);
+    }
+    push(&client_jar(context), ClasspathSource::ClientJar);
+    
+    entries
+}
+
+/// The classpath string a launch passes to Java. Libraries that aren't on
+/// disk are left out (and logged); the client jar is always included.
+pub fn build_launch_classpath(
+    context: &LaunchContext,
+    version_data: &VersionData,
+    modloader_profile: Option<&ModloaderProfile>,
+) -> String {
+    let separator = if cfg!(target_os = "windows") { ";" } else { ":" };
+    let entries = classpath_entries(context, version_data, modloader_profile);
+    
+    let missing_modloader: Vec<&ClasspathEntry> = entries.iter()
+        .filter(|e| e.source == ClasspathSource::Modloader && !e.exists)
+        .collect();
+    for entry in &missing_modloader {
+        tracing::warn!("Modloader library not found: {}", entry.path);
+    }
+    if !missing_modloader.is_empty() {
+        tracing::warn!("Some modloader libraries are missing! The game may not launch correctly.");
+    }
+    
+    let paths: Vec<&str> = entries.iter()
+        .filter(|e| e.exists || e.source == ClasspathSource::ClientJar)
+        .map(|e| e.path.as_str())
+        .collect();
+    tracing::debug!("Total classpath entries: {}", paths.len());
+    
+    paths.join(separator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::accounts::AuthSession;
+    use crate::core::config::Config;
+    use crate::core::instance::Instance;
+    
+    #[test]
+    fn lists_missing_jars_once_and_always_the_client_jar() {
+        let dir = tempfile::tempdir().unwrap();
+        let version_data: VersionData = serde_json::from_str(r#"{
+            "id": "1.20.1",
+            "type": "release",
+            "mainClass": "net.minecraft.client.main.Main",
+            "releaseTime": "2023-06-12T13:25:51+00:00",
+            "time": "2023-06-12T13:25:51+00:00",
+            "assets": "5",
+            "assetIndex": { "id": "5", "sha1": "0", "size": 0, "url": "https://example.com/5.json" },
+            "downloads": {},
+            "libraries": [
+                { "name": "org.example:present:1.0" },
+                { "name": "org.example:missing:1.0" },
+                { "name": "org.example:present:1.0" }
+            ]
+        }"#).unwrap();
+        
+        let instance = Instance::new("Test".to_string(), dir.path().join("instances"), "1.20.1".to_string());
+        let mut context = LaunchContext::new(instance, AuthSession::offline("Player"), Config::default());
+        context.libraries_dir = dir.path().join("libraries");
+        context.client_jar = Some(dir.path().join("client.jar"));
+        
+        let present = context.libraries_dir.join("org/example/present/1.0/present-1.0.jar");
+        std::fs::create_dir_all(present.parent().unwrap()).unwrap();
+        std::fs::write(&present, "jar").unwrap();
+        
+        let entries = classpath_entries(&context, &version_data, None);
+        let summary: Vec<(ClasspathSource, bool)> = entries.iter().map(|e| (e.source, e.exists)).collect();
+        assert_eq!(summary, vec![
+            (ClasspathSource::Library, true),
+            (ClasspathSource::Library, false),
+            (ClasspathSource::ClientJar, false),
+        ]);
+        
+        let separator = if cfg!(target_os = "windows") { ";" } else { ":" };
+        let classpath = build_launch_classpath(&context, &version_data, None);
+        assert_eq!(classpath.split(separator).count(), 2);
+        assert!(!classpath.contains("missing"));
+    }
+}
@@ -0,0 +1,255 @@
+//! Effective launch settings after applying instance overrides to global defaults.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use serde::Serialize;
+use super::steps::{java_version_at, select_java};
+use super::LaunchContext;
+use crate::core::java::get_required_java_version;
+
+/// Where an effective setting's value came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SettingSource {
+    /// Set on the instance (including its argument profile)
+    Instance,
+    /// Taken from the launcher-wide settings
+    Global,
+    /// Set nowhere; the launcher decides at launch (e.g. Java auto-detection)
+    Default,
+}
+
+/// A resolved setting and where it came from
+#[derive(Debug, Clone, Serialize)]
+pub struct EffectiveSetting<T> {
+    pub value: T,
+    pub source: SettingSource,
+}
+
+impl<T> EffectiveSetting<T> {
+    fn new(value: T, source: SettingSource) -> Self {
+        Self { value, source }
+    }
+}
+
+/// Resolve an optional instance override against a global fallback
+fn resolve<T: Clone>(instance: &Option<T>, global: T) -> EffectiveSetting<T> {
+    match instance {
+        Some(value) => EffectiveSetting::new(value.clone(), SettingSource::Instance),
+        None => EffectiveSetting::new(global, SettingSource::Global),
+    }
+}
+
+/// The settings a launch of an instance will use
+#[derive(Debug, Clone, Serialize)]
+pub struct EffectiveSettings {
+    /// Java executable; None when it's auto-detected at launch
+    pub java_path: EffectiveSetting<Option<String>>,
+    pub min_memory: EffectiveSetting<u32>,
+    pub max_memory: EffectiveSetting<u32>,
+    /// Argument profile applied before the instance's own arguments
+    pub argument_profile: Option<String>,
+    /// JVM arguments added by the user, in launch order
+    pub jvm_args: EffectiveSetting<Vec<String>>,
+    /// Game arguments added by the user, in launch order
+    pub game_args: EffectiveSetting<Vec<String>>,
+    pub window_width: EffectiveSetting<u32>,
+    pub window_height: EffectiveSetting<u32>,
+    pub fullscreen: EffectiveSetting<bool>,
+    pub wrapper_command: EffectiveSetting<Option<String>>,
+    pub skip_java_compatibility_check: EffectiveSetting<bool>,
+}
+
+impl EffectiveSettings {
+    /// Resolve the settings a launch context will use
+    pub fn from_context(context: &LaunchContext) -> Self {
+        let settings = &context.instance.settings;
+        let config = &context.config;
+
+        let java_path = match (&settings.java_path, &config.java.custom_path) {
+            (Some(path), _) => EffectiveSetting::new(Some(path.to_string_lossy().to_string()), SettingSource::Instance),
+            (None, Some(path)) => EffectiveSetting::new(Some(path.to_string_lossy().to_string()), SettingSource::Global),
+            (None, None) => EffectiveSetting::new(None, SettingSource::Default),
+        };
+
+        // Only an assignment to a profile that still exists contributes arguments
+        let argument_profile = settings.argument_profile.clone()
+            .filter(|name| config.argument_profile(name).is_some());
+
+        let jvm_source = if argument_profile.is_some() || settings.jvm_args.is_some() {
+            SettingSource::Instance
+        } else if settings.use_global_jvm_args && !config.java.extra_args.is_empty() {
+            SettingSource::Global
+        } else {
+            SettingSource::Default
+        };
+        let game_source = if argument_profile.is_some() || settings.game_args.is_some() {
+            SettingSource::Instance
+        } else {
+            SettingSource::Default
+        };
+
+        let fullscreen = if settings.fullscreen {
+            EffectiveSetting::new(true, SettingSource::Instance)
+        } else {
+            EffectiveSetting::new(config.minecraft.launch_maximized, SettingSource::Global)
+        };
+        let skip_java_compatibility_check = if settings.skip_java_compatibility_check {
+            EffectiveSetting::new(true, SettingSource::Instance)
+        } else {
+            EffectiveSetting::new(config.java.skip_compatibility_check, SettingSource::Global)
+        };
+
+        let wrapper_command = match (&settings.wrapper_command, &config.commands.wrapper_command) {
+            (Some(command), _) => EffectiveSetting::new(Some(command.clone()), SettingSource::Instance),
+            (None, Some(command)) => EffectiveSetting::new(Some(command.clone()), SettingSource::Global),
+            (None, None) => EffectiveSetting::new(None, SettingSource::Default),
+        };
+
+        Self {
+            java_path,
+            min_memory: resolve(&settings.min_memory, config.memory.min_memory),
+            max_memory: resolve(&settings.max_memory, config.memory.max_memory),
+            argument_profile,
+            jvm_args: EffectiveSetting::new(context.custom_jvm_args(), jvm_source),
+            game_args: EffectiveSetting::new(context.custom_game_args(), game_source),
+            window_width: resolve(&settings.window_width, config.minecraft.window_width),
+            window_height: resolve(&settings.window_height, config.minecraft.window_height),
+            fullscreen,
+            wrapper_command,
+            skip_java_compatibility_check,
+        }
+    }
+}
+
+/// The Java runtime, memory and JVM arguments a launch would use right now
+#[derive(Debug, Clone, Serialize)]
+pub struct RuntimeConfig {
+    /// Java executable, chosen the way the Check Java step chooses it.
+    /// `Default` means it was auto-detected or found on PATH.
+    pub java_path: EffectiveSetting<Option<String>>,
+    /// Why no Java executable could be chosen
+    pub java_error: Option<String>,
+    /// Version the chosen executable reports
+    pub java_version: Option<String>,
+    /// Java major version the instance's Minecraft version needs
+    pub required_java_version: u32,
+    pub min_memory: EffectiveSetting<u32>,
+    pub max_memory: EffectiveSetting<u32>,
+    /// Every JVM argument the launcher adds, in launch order: memory flags,
+    /// then global, profile and instance arguments
+    pub jvm_args: Vec<String>,
+    /// Where the user-supplied part of `jvm_args` comes from
+    pub jvm_args_source: SettingSource,
+}
+
+impl RuntimeConfig {
+    /// Resolve the runtime a launch context will use. Runs the chosen Java
+    /// to read its version, so this isn't free. A Java that automatic
+    /// downloads would install at launch isn't known yet.
+    pub async fn resolve(context: &LaunchContext) -> Self {
+        let effective = EffectiveSettings::from_context(context);
+        
+        let (java_path, java_error) = match select_java(&context.instance, &context.config) {
+            Ok((path, source)) => (EffectiveSetting::new(Some(path), source), None),
+            Err(e) => (EffectiveSetting::new(None, effective.java_path.source), Some(e)),
+        };
+        let java_version = match &java_path.value {
+            Some(path) => java_version_at(path).await,
+            None => None,
+        };
+        
+        let mut jvm_args = context.memory_args();
+        jvm_args.extend(effective.jvm_args.value);
+        
+        Self {
+            java_path: EffectiveSetting::new(
+                java_path.value.map(|path| path.to_string_lossy().to_string()),
+                java_path.source,
+            ),
+            java_error,
+            java_version,
+            required_java_version: get_required_java_version(&context.instance.minecraft_version),
+            min_memory: effective.min_memory,
+            max_memory: effective.max_memory,
+            jvm_args,
+            jvm_args_source: effective.jvm_args.source,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::accounts::AuthSession;
+    use crate::core::config::Config;
+    use crate::core::instance::Instance;
+    use std::path::PathBuf;
+
+    #[test]
+    fn instance_overrides_win_over_global_defaults() {
+        let mut instance = Instance::new("Test".to_string(), PathBuf::from("instances"), "1.20.1".to_string());
+        instance.settings.max_memory = Some(6144);
+        instance.settings.jvm_args = Some("-XX:+UseZGC".to_string());
+
+        let mut config = Config::default();
+        config.memory.min_memory = 1024;
+        config.memory.max_memory = 4096;
+        config.java.extra_args = vec!["-Dglobal=1".to_string()];
+        config.commands.wrapper_command = Some("gamemoderun".to_string());
+
+        let context = LaunchContext::new(instance, AuthSession::offline("Player"), config);
+        let effective = EffectiveSettings::from_context(&context);
+
+        assert_eq!(effective.min_memory.value, 1024);
+        assert_eq!(effective.min_memory.source, SettingSource::Global);
+        assert_eq!(effective.max_memory.value, 6144);
+        assert_eq!(effective.max_memory.source, SettingSource::Instance);
+        assert_eq!(effective.jvm_args.value, vec!["-Dglobal=1", "-XX:+UseZGC"]);
+        assert_eq!(effective.jvm_args.source, SettingSource::Instance);
+        assert_eq!(effective.java_path.source, SettingSource::Default);
+        assert_eq!(effective.wrapper_command.value.as_deref(), Some("gamemoderun"));
+        assert_eq!(effective.wrapper_command.source, SettingSource::Global);
+        assert_eq!(effective.game_args.source, SettingSource::Default);
+    }
+
+    #[tokio::test]
+    async fn runtime_config_reports_a_missing_instance_java() {
+        let mut instance = Instance::new("Test".to_string(), PathBuf::from("instances"), "1.20.1".to_string());
+        instance.settings.java_path = Some(PathBuf::from("/nonexistent/bin/java"));
+        instance.settings.min_memory = Some(2048);
+        instance.settings.jvm_args = Some("-XX:+UseZGC".to_string());
+
+        let mut config = Config::default();
+        config.memory.max_memory = 4096;
+
+        let context = LaunchContext::new(instance, AuthSession::offline("Player"), config);
+        let runtime = RuntimeConfig::resolve(&context).await;
+
+        assert!(runtime.java_path.value.is_none());
+        assert_eq!(runtime.java_path.source, SettingSource::Instance);
+        assert!(runtime.java_error.is_some());
+        assert!(runtime.java_version.is_none());
+        assert_eq!(runtime.required_java_version, 17);
+        assert_eq!(runtime.min_memory.source, SettingSource::Instance);
+        assert_eq!(runtime.max_memory.source, SettingSource::Global);
+        assert_eq!(runtime.jvm_args, vec!["-Xms2048M", "-Xmx4096M", "-XX:+UseZGC"]);
+        assert_eq!(runtime.jvm_args_source, SettingSource::Instance);
+    }
+}
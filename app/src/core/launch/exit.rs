@@ -0,0 +1,127 @@
+//! Classifying how a game process ended.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// Why the game stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExitReason {
+    /// Exited with code 0
+    Clean,
+    /// Exited with an error and left a crash report
+    Crashed,
+    /// Exited with an error or signal but no crash report
+    Failed,
+    /// Stopped by the launcher
+    Killed,
+}
+
+/// How an instance's most recent game session ended
+#[derive(Debug, Clone, Serialize)]
+pub struct ExitStatus {
+    pub reason: ExitReason,
+    pub exit_code: Option<i32>,
+    /// Crash report or JVM error log written during the session
+    pub crash_report: Option<PathBuf>,
+    pub exited_at: DateTime<Utc>,
+}
+
+impl ExitStatus {
+    /// A session the launcher stopped itself
+    pub fn killed() -> Self {
+        Self {
+            reason: ExitReason::Killed,
+            exit_code: None,
+            crash_report: None,
+            exited_at: Utc::now(),
+        }
+    }
+    
+    /// Classify an exit by its code and whether the game wrote a crash
+    /// report (or JVM error log) in `game_dir` after `started_at`
+    pub fn classify(exit_code: Option<i32>, game_dir: &Path, started_at: SystemTime) -> Self {
+        let (reason, crash_report) = match exit_code {
+            Some(0) => (ExitReason::Clean, None),
+            _ => match find_crash_report(game_dir, started_at) {
+                Some(report) => (ExitReason::Crashed, Some(report)),
+                None => (ExitReason::Failed, None),
+            },
+        };
+        
+        Self {
+            reason,
+            exit_code,
+            crash_report,
+            exited_at: Utc::now(),
+        }
+    }
+}
+
+/// The newest crash report or `hs_err_pid*.log` written since `since`
+fn find_crash_report(game_dir: &Path, since: SystemTime) -> Option<PathBuf> {
+    let reports = std::fs::read_dir(game_dir.join("crash-reports"))
+        .into_iter()
+        .flatten()
+        .chain(std::fs::read_dir(game_dir).into_iter().flatten())
+        .flatten()
+        .filter(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            (name.starts_with("crash-") && name.ends_with(".txt"))
+                || (name.starts_with("hs_err_pid") && name.ends_with(".log"))
+        })
+        .filter_map(|entry| {
+            let modified = entry.metadata().ok()?.modified().ok()?;
+            (modified >= since).then(|| (modified, entry.path()))
+        });
+    
+    reports.max_by_key(|(modified, _)| *modified).map(|(_, path)| path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    
+    #[test]
+    fn crash_needs_a_fresh_report() {
+        let dir = tempfile::tempdir().unwrap();
+        let started_at = SystemTime::now() - Duration::from_secs(60);
+        
+        assert_eq!(ExitStatus::classify(Some(0), dir.path(), started_at).reason, ExitReason::Clean);
+        assert_eq!(ExitStatus::classify(Some(1), dir.path(), started_at).reason, ExitReason::Failed);
+        
+        let reports = dir.path().join("crash-reports");
+        std::fs::create_dir_all(&reports).unwrap();
+        std::fs::write(reports.join("crash-2025-01-01_00.00.00-client.txt"), "crash").unwrap();
+        
+        let status = ExitStatus::classify(Some(-1), dir.path(), started_at);
+        assert_eq!(status.reason, ExitReason::Crashed);
+        assert!(status.crash_report.is_some());
+        
+        // Reports from before the session don't count
+        let later = SystemTime::now() + Duration::from_secs(60);
+        assert_eq!(ExitStatus::classify(Some(-1), dir.path(), later).reason, ExitReason::Failed);
+    }
+}
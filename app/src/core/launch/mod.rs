@@ -18,13 +18,23 @@
 //! You should have received a copy of the GNU General Public License
 //! along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+mod classpath;
+mod effective;
+mod exit;
+mod readiness;
 mod step;
 mod task;
+mod wrapper;
 pub mod steps;
 
+pub use classpath::{build_launch_classpath, classpath_entries, ClasspathEntry};
+pub use effective::{EffectiveSettings, RuntimeConfig, SettingSource};
+pub use exit::ExitStatus;
+pub use readiness::{launch_blockers, check_account, LaunchBlocker};
 pub use step::{LaunchStep, LaunchStepResult};
+pub use wrapper::{find_launch_wrapper, install_launch_wrapper, missing_wrapper_message, passes_token_out_of_band, schedule_token_file_removal, write_access_token_file, wrapper_install_path, wrapper_reads_token_file, ACCESS_TOKEN_PLACEHOLDER};
 #[allow(unused_imports)] // Part of public API
-pub use task::{LaunchTask, LaunchProgress, LaunchState};
+pub use task::{LaunchTask, LaunchProgress, LaunchState, LaunchTiming, StepTiming};
 #[allow(unused_imports)] // Re-exports for convenience
 pub use steps::*;
 
@@ -32,7 +42,7 @@ pub use steps::*;
 use crate::core::error::Result;
 use crate::core::instance::Instance;
 use crate::core::accounts::AuthSession;
-use crate::core::config::Config;
+use crate::core::config::{ArgumentProfile, Config};
 use crate::core::minecraft::version::LaunchFeatures;
 
 /// Context passed to launch steps containing all necessary information
@@ -68,6 +78,9 @@ pub struct LaunchContext {
     /// Path to assets directory
     pub assets_dir: std::path::PathBuf,
     
+    /// Client jar to launch against instead of the vanilla one (set by ApplyJarMods step)
+    pub client_jar: Option<std::path::PathBuf>,
+    
     /// Whether launch was aborted
     #[allow(dead_code)] // Used by abort functionality
     pub aborted: bool,
@@ -75,7 +88,6 @@ pub struct LaunchContext {
 
 impl LaunchContext {
     /// Create a new launch context
-    #[allow(dead_code)]
     pub fn new(instance: Instance, auth_session: AuthSession, config: Config) -> Self {
         Self::with_features(instance, auth_session, config, LaunchFeatures::normal())
     }
@@ -98,9 +110,82 @@ impl LaunchContext {
             natives_dir,
             libraries_dir,
             assets_dir,
+            client_jar: None,
             aborted: false,
         }
     }
+    
+    /// Minimum and maximum heap flags, from the instance or global settings
+    pub fn memory_args(&self) -> Vec<String> {
+        let min_mem = self.instance.settings.min_memory.unwrap_or(self.config.memory.min_memory);
+        let max_mem = self.instance.settings.max_memory.unwrap_or(self.config.memory.max_memory);
+        vec![format!("-Xms{}M", min_mem), format!("-Xmx{}M", max_mem)]
+    }
+    
+    /// Fullscreen flag, or the window size from the instance or global settings
+    pub fn window_args(&self) -> Vec<String> {
+        // Fullscreen/Launch maximized - instance setting takes priority
+        if self.instance.settings.fullscreen || self.config.minecraft.launch_maximized {
+            return vec!["--fullscreen".to_string()];
+        }
+        
+        let width = self.instance.settings.window_width.unwrap_or(self.config.minecraft.window_width);
+        let height = self.instance.settings.window_height.unwrap_or(self.config.minecraft.window_height);
+        vec![
+            "--width".to_string(),
+            width.to_string(),
+            "--height".to_string(),
+            height.to_string(),
+        ]
+    }
+    
+    /// User-supplied JVM arguments: the global extra arguments (unless the
+    /// instance opts out), then the assigned argument profile's, then the
+    /// instance's own.
+    ///
+    /// These come after the memory flags. The JVM takes the last value of a
+    /// repeated option such as `-Xmx`, so instance arguments override profile
+    /// ones, which override global ones, which override the memory settings.
+    pub fn custom_jvm_args(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        if self.instance.settings.use_global_jvm_args {
+            args.extend(self.config.java.extra_args.clone());
+        }
+        if let Some(profile) = self.argument_profile() {
+            if let Some(ref profile_args) = profile.jvm_args {
+                args.extend(profile_args.split_whitespace().map(String::from));
+            }
+        }
+        if let Some(ref custom_args) = self.instance.settings.jvm_args {
+            args.extend(custom_args.split_whitespace().map(String::from));
+        }
+        args
+    }
+    
+    /// User-supplied game arguments: the assigned argument profile's, then the
+    /// instance's own
+    pub fn custom_game_args(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        if let Some(profile) = self.argument_profile() {
+            if let Some(ref profile_args) = profile.game_args {
+                args.extend(profile_args.split_whitespace().map(String::from));
+            }
+        }
+        if let Some(ref custom_args) = self.instance.settings.game_args {
+            args.extend(custom_args.split_whitespace().map(String::from));
+        }
+        args
+    }
+    
+    /// The argument profile assigned to the instance, if it still exists
+    fn argument_profile(&self) -> Option<&ArgumentProfile> {
+        let name = self.instance.settings.argument_profile.as_deref()?;
+        let profile = self.config.argument_profile(name);
+        if profile.is_none() {
+            tracing::warn!("Argument profile '{}' no longer exists; ignoring it", name);
+        }
+        profile
+    }
 }
 
 /// Message levels for log output
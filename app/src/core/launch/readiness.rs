@@ -0,0 +1,195 @@
+//! Pre-launch checks that report why an instance can't be launched.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use serde::Serialize;
+use std::path::Path;
+
+use crate::core::config::Config;
+use crate::core::instance::Instance;
+use crate::core::java;
+use crate::core::modloaders::ModloaderProfile;
+
+/// What blocks a launch. Variants are declared in priority order, most fundamental first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LaunchBlockerKind {
+    /// No signed-in account owning the game
+    Account,
+    /// The Minecraft client jar hasn't been downloaded
+    VersionJar,
+    /// The instance has a mod loader but it isn't installed
+    ModloaderProfile,
+    /// No usable Java installation
+    Java,
+}
+
+/// A problem that would make a launch fail
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LaunchBlocker {
+    pub kind: LaunchBlockerKind,
+    /// Human-readable explanation, suitable for a tooltip
+    pub message: String,
+}
+
+impl LaunchBlocker {
+    fn new(kind: LaunchBlockerKind, message: impl Into<String>) -> Self {
+        Self { kind, message: message.into() }
+    }
+}
+
+/// Run every pre-launch check, returning the blockers most important first.
+/// An empty list means the instance can be launched.
+pub fn launch_blockers(instance: &Instance, config: &Config, ownership_verified: bool) -> Vec<LaunchBlocker> {
+    let mut blockers: Vec<LaunchBlocker> = [
+        check_account(ownership_verified),
+        check_version_jar(instance, config),
+        check_modloader_profile(instance),
+        check_java(instance, config),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    
+    blockers.sort_by_key(|blocker| blocker.kind);
+    blockers
+}
+
+/// The game can only be launched once ownership has been verified with a Microsoft account
+pub fn check_account(ownership_verified: bool) -> Option<LaunchBlocker> {
+    if ownership_verified {
+        return None;
+    }
+    
+    Some(LaunchBlocker::new(
+        LaunchBlockerKind::Account,
+        "You must sign in with a Microsoft account that owns Minecraft before playing. Go to Accounts to sign in and verify game ownership.",
+    ))
+}
+
+/// The client jar (or the instance's replacement jar) must be present
+pub fn check_version_jar(instance: &Instance, config: &Config) -> Option<LaunchBlocker> {
+    let version = &instance.minecraft_version;
+    let vanilla_jar = config.meta_dir()
+        .join("versions")
+        .join(version)
+        .join(format!("{}.jar", version));
+    let custom_jar = instance.path.join("patches").join("custom.jar");
+    
+    if vanilla_jar.exists() || custom_jar.exists() {
+        return None;
+    }
+    
+    Some(LaunchBlocker::new(
+        LaunchBlockerKind::VersionJar,
+        format!("Minecraft {} isn't downloaded. Repair the instance to download it.", version),
+    ))
+}
+
+/// Instances with a mod loader need its installed profile
+pub fn check_modloader_profile(instance: &Instance) -> Option<LaunchBlocker> {
+    let loader = instance.mod_loader.as_ref()?;
+    
+    if ModloaderProfile::load(&instance.path.join("modloader_profile.json")).is_ok() {
+        return None;
+    }
+    
+    Some(LaunchBlocker::new(
+        LaunchBlockerKind::ModloaderProfile,
+        format!("{} isn't installed. Reinstall the mod loader from the Version tab.", loader.loader_type.name()),
+    ))
+}
+
+/// Resolve Java the same way the CheckJava step does, without running it
+pub fn check_java(instance: &Instance, config: &Config) -> Option<LaunchBlocker> {
+    if let Some(ref path) = instance.settings.java_path {
+        return (!java_path_resolves(path)).then(|| LaunchBlocker::new(
+            LaunchBlockerKind::Java,
+            format!("The instance's Java path doesn't exist: {}", path.display()),
+        ));
+    }
+    
+    if let Some(ref path) = config.java.custom_path {
+        return (!java_path_resolves(path)).then(|| LaunchBlocker::new(
+            LaunchBlockerKind::Java,
+            format!("The global Java path doesn't exist: {}", path.display()),
+        ));
+    }
+    
+    // A matching Java gets downloaded at launch
+    if config.java.auto_download {
+        return None;
+    }
+    
+    let required = java::get_required_java_version(&instance.minecraft_version);
+    if config.java.auto_detect && java::find_java_for_version(required).is_some() {
+        return None;
+    }
+    
+    Some(LaunchBlocker::new(
+        LaunchBlockerKind::Java,
+        format!(
+            "Minecraft {} needs Java {}, which isn't installed. Install it or enable automatic Java downloads.",
+            instance.minecraft_version, required
+        ),
+    ))
+}
+
+fn java_path_resolves(path: &Path) -> bool {
+    path.exists() || which::which(path).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::instance::{ModLoader, ModLoaderType};
+    
+    #[test]
+    fn account_blocks_only_without_ownership() {
+        assert!(check_account(true).is_none());
+        assert_eq!(check_account(false).unwrap().kind, LaunchBlockerKind::Account);
+    }
+    
+    #[test]
+    fn modloader_profile_required_for_modded_instances() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut instance = Instance::new("Test".to_string(), dir.path().to_path_buf(), "1.20.1".to_string());
+        assert!(check_modloader_profile(&instance).is_none());
+        
+        instance.mod_loader = Some(ModLoader {
+            loader_type: ModLoaderType::Fabric,
+            version: "0.15.0".to_string(),
+        });
+        let blocker = check_modloader_profile(&instance).unwrap();
+        assert_eq!(blocker.kind, LaunchBlockerKind::ModloaderProfile);
+        assert!(blocker.message.contains("Fabric"));
+    }
+    
+    #[test]
+    fn custom_jar_satisfies_version_check() {
+        let dir = tempfile::tempdir().unwrap();
+        let instance = Instance::new("Test".to_string(), dir.path().to_path_buf(), "0.0-missing".to_string());
+        let config = Config::default();
+        assert_eq!(check_version_jar(&instance, &config).unwrap().kind, LaunchBlockerKind::VersionJar);
+        
+        std::fs::create_dir_all(dir.path().join("patches")).unwrap();
+        std::fs::write(dir.path().join("patches").join("custom.jar"), "jar").unwrap();
+        assert!(check_version_jar(&instance, &config).is_none());
+    }
+}
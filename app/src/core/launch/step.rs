@@ -78,6 +78,11 @@ pub trait LaunchStep: Send + Sync {
         None
     }
     
+    /// Take the messages the step wants shown in the launch log
+    fn take_messages(&mut self) -> Vec<(MessageLevel, String)> {
+        Vec::new()
+    }
+    
     /// Get the launched game process (if this step launches a game)
     fn get_game_process(&self) -> Option<Arc<Mutex<Child>>> {
         None
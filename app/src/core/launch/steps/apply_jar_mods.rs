@@ -0,0 +1,122 @@
+//! Apply jar mods step.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use async_trait::async_trait;
+use tracing::{info, warn};
+
+use crate::core::instance::{build_patched_jar, list_jar_mods, supports_jar_mods, JAR_MODS_DIR, PATCHED_JAR_NAME};
+use crate::core::launch::{LaunchContext, LaunchStep, LaunchStepResult};
+
+/// Step that patches the instance's jar mods into the client jar (legacy versions only)
+pub struct ApplyJarModsStep {
+    status: Option<String>,
+    progress: f32,
+}
+
+impl ApplyJarModsStep {
+    pub fn new() -> Self {
+        Self {
+            status: None,
+            progress: 0.0,
+        }
+    }
+}
+
+#[async_trait]
+impl LaunchStep for ApplyJarModsStep {
+    fn name(&self) -> &'static str {
+        "Apply Jar Mods"
+    }
+    
+    fn description(&self) -> &'static str {
+        "Patches jar mods into the Minecraft jar"
+    }
+    
+    async fn execute(&mut self, context: &mut LaunchContext) -> LaunchStepResult {
+        let instance_path = context.instance.path.clone();
+        let jar_mods = list_jar_mods(&instance_path);
+        
+        if jar_mods.is_empty() {
+            self.progress = 1.0;
+            return LaunchStepResult::Success;
+        }
+        
+        let minecraft_version = context.instance.minecraft_version.clone();
+        if !supports_jar_mods(&minecraft_version) {
+            warn!(
+                "Ignoring {} jar mod(s): Minecraft {} doesn't load jar mods",
+                jar_mods.len(), minecraft_version
+            );
+            self.progress = 1.0;
+            return LaunchStepResult::Success;
+        }
+        
+        self.status = Some(format!("Applying {} jar mod(s)...", jar_mods.len()));
+        
+        // Patch on top of a replaced Minecraft jar if the instance has one
+        let patches_dir = instance_path.join("patches");
+        let custom_jar = patches_dir.join("custom.jar");
+        let base_jar = if custom_jar.exists() {
+            custom_jar
+        } else {
+            context.config.meta_dir()
+                .join("versions")
+                .join(&minecraft_version)
+                .join(format!("{}.jar", minecraft_version))
+        };
+        
+        if !base_jar.exists() {
+            return LaunchStepResult::Failed(format!(
+                "Minecraft jar not found, can't apply jar mods: {:?}", base_jar
+            ));
+        }
+        
+        let mod_paths: Vec<_> = jar_mods.iter()
+            .map(|name| instance_path.join(JAR_MODS_DIR).join(name))
+            .collect();
+        let output = patches_dir.join(PATCHED_JAR_NAME);
+        
+        if let Err(e) = build_patched_jar(&base_jar, &mod_paths, &output) {
+            return LaunchStepResult::Failed(format!("Failed to apply jar mods: {}", e));
+        }
+        
+        info!("Applied jar mods in order: {}", jar_mods.join(", "));
+        context.client_jar = Some(output);
+        
+        self.status = Some("Jar mods applied".to_string());
+        self.progress = 1.0;
+        
+        LaunchStepResult::Success
+    }
+    
+    fn progress(&self) -> f32 {
+        self.progress
+    }
+    
+    fn status(&self) -> Option<String> {
+        self.status.clone()
+    }
+}
+
+impl Default for ApplyJarModsStep {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -56,16 +56,7 @@ impl AutoInstallJavaStep {
             None => return false,
         };
         
-        let required = java::get_required_java_version(minecraft_version);
-        
-        // Check if current Java meets requirement
-        match required {
-            8 => java_major == 8,
-            16 => java_major >= 16 && java_major <= 17,
-            17 => java_major >= 17,
-            21 => java_major >= 21,
-            _ => java_major >= required,
-        }
+        java::get_java_requirement(minecraft_version).contains(java_major)
     }
 }
 
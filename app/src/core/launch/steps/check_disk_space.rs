@@ -0,0 +1,190 @@
+//! Check disk space step.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use async_trait::async_trait;
+use tracing::{info, warn};
+
+use super::extract_natives::natives_size;
+use crate::core::launch::{LaunchContext, LaunchStep, LaunchStepResult, MessageLevel};
+use crate::core::storage::storage_info;
+
+/// Free space below which the launch log gets a warning (1 GiB)
+const LOW_SPACE_WARNING_BYTES: u64 = 1024 * 1024 * 1024;
+
+const MB: u64 = 1024 * 1024;
+
+/// Step that stops a launch before natives extraction could run the data
+/// volume out of space and leave half-extracted natives behind
+pub struct CheckDiskSpaceStep {
+    status: Option<String>,
+    progress: f32,
+    messages: Vec<(MessageLevel, String)>,
+}
+
+impl CheckDiskSpaceStep {
+    pub fn new() -> Self {
+        Self {
+            status: None,
+            progress: 0.0,
+            messages: Vec::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl LaunchStep for CheckDiskSpaceStep {
+    fn name(&self) -> &'static str {
+        "Check Disk Space"
+    }
+    
+    fn description(&self) -> &'static str {
+        "Checks there is room to extract natives"
+    }
+    
+    async fn execute(&mut self, context: &mut LaunchContext) -> LaunchStepResult {
+        self.status = Some("Checking free disk space...".to_string());
+        self.progress = 0.0;
+        
+        let data_dir = context.config.data_dir();
+        let free = match storage_info(&[("data", data_dir.clone())]).first() {
+            Some(volume) => volume.free_bytes,
+            None => {
+                warn!("Couldn't find the volume holding {:?}; skipping the disk space check", data_dir);
+                self.progress = 1.0;
+                return LaunchStepResult::Success;
+            }
+        };
+        self.progress = 0.3;
+        
+        // Without version data (e.g. offline) only the margin is checked
+        let natives = natives_size(context).await.unwrap_or_else(|e| {
+            warn!("Couldn't estimate the size of the natives: {}", e);
+            0
+        });
+        let required = natives + context.config.files.disk_space_margin_mb * MB;
+        self.progress = 0.8;
+        
+        info!(
+            "Free space in {:?}: {} MB (natives need {} MB plus a {} MB margin)",
+            data_dir,
+            free / MB,
+            natives / MB,
+            context.config.files.disk_space_margin_mb
+        );
+        
+        if free < required {
+            let message = format!(
+                "Not enough disk space in {}: {} MB free, but extracting natives needs {} MB including the safety margin. Free up at least {} MB and try again.",
+                data_dir.display(),
+                free / MB,
+                required.div_ceil(MB),
+                (required - free).div_ceil(MB)
+            );
+            self.status = Some("Not enough disk space".to_string());
+            return LaunchStepResult::Failed(message);
+        }
+        
+        if free < LOW_SPACE_WARNING_BYTES {
+            self.messages.push((
+                MessageLevel::Warning,
+                format!("Low disk space in {}: only {} MB free", data_dir.display(), free / MB),
+            ));
+        }
+        
+        self.status = Some("Enough disk space".to_string());
+        self.progress = 1.0;
+        
+        LaunchStepResult::Success
+    }
+    
+    fn progress(&self) -> f32 {
+        self.progress
+    }
+    
+    fn status(&self) -> Option<String> {
+        self.status.clone()
+    }
+    
+    fn take_messages(&mut self) -> Vec<(MessageLevel, String)> {
+        std::mem::take(&mut self.messages)
+    }
+}
+
+impl Default for CheckDiskSpaceStep {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::accounts::AuthSession;
+    use crate::core::config::Config;
+    use crate::core::instance::Instance;
+    use crate::core::minecraft::local_versions::save_version_json;
+    use crate::core::minecraft::version::VersionData;
+    
+    /// Stored version JSON without natives, so the step doesn't fetch the real one
+    const VERSION_JSON: &str = r#"{
+        "id": "1.20.1",
+        "type": "release",
+        "mainClass": "net.minecraft.client.main.Main",
+        "releaseTime": "2023-06-12T13:25:51+00:00",
+        "time": "2023-06-12T13:25:51+00:00",
+        "assets": "5",
+        "assetIndex": { "id": "5", "sha1": "0", "size": 0, "url": "https://example.com/5.json" },
+        "downloads": {},
+        "libraries": []
+    }"#;
+    
+    fn context(dir: &std::path::Path, margin_mb: u64) -> LaunchContext {
+        let mut config = Config::default();
+        config.data_dir = dir.to_path_buf();
+        let version: VersionData = serde_json::from_str(VERSION_JSON).unwrap();
+        save_version_json(&config.meta_dir(), &version).unwrap();
+        config.files.disk_space_margin_mb = margin_mb;
+        let instance = Instance::new("Test".to_string(), dir.join("instances"), "1.20.1".to_string());
+        LaunchContext::new(instance, AuthSession::offline("Player"), config)
+    }
+    
+    #[tokio::test]
+    async fn fails_once_without_room_for_the_margin() {
+        let dir = tempfile::tempdir().unwrap();
+        // More than any volume has, without overflowing the byte count
+        let mut context = context(dir.path(), u64::MAX / (4 * MB));
+        let mut step = CheckDiskSpaceStep::new();
+        
+        let result = step.execute(&mut context).await;
+        assert!(matches!(result, LaunchStepResult::Failed(ref message) if message.starts_with("Not enough disk space")));
+        // The failure itself is logged by the task, so the step adds no copy of it
+        assert!(step.take_messages().is_empty());
+    }
+    
+    #[tokio::test]
+    async fn passes_with_no_margin() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut context = context(dir.path(), 0);
+        let mut step = CheckDiskSpaceStep::new();
+        
+        assert!(matches!(step.execute(&mut context).await, LaunchStepResult::Success));
+        assert_eq!(step.progress(), 1.0);
+    }
+}
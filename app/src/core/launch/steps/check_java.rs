@@ -23,7 +23,9 @@ use std::path::PathBuf;
 use std::process::Command;
 use tracing::{debug, info, warn};
 
-use crate::core::launch::{LaunchContext, LaunchStep, LaunchStepResult};
+use crate::core::config::Config;
+use crate::core::instance::Instance;
+use crate::core::launch::{LaunchContext, LaunchStep, LaunchStepResult, SettingSource};
 use crate::core::java;
 
 /// Step that checks for a valid Java installation
@@ -32,6 +34,81 @@ pub struct CheckJavaStep {
     progress: f32,
 }
 
+/// Pick the Java executable a launch will use: the instance's path, then the
+/// global custom path, then (with auto-detection on) an installation matching
+/// the Minecraft version, then `java` on PATH. Returns where the choice came
+/// from, or the launch error when there is none.
+pub fn select_java(instance: &Instance, config: &Config) -> std::result::Result<(PathBuf, SettingSource), String> {
+    if let Some(ref instance_java) = instance.settings.java_path {
+        return resolve_java_path(&instance_java.to_string_lossy())
+            .map(|path| (path, SettingSource::Instance))
+            .ok_or_else(|| format!(
+                "Instance Java path not found: {:?}\n\
+                 Please set up Java in the launcher's Java settings.",
+                instance_java
+            ));
+    }
+    
+    if let Some(ref custom_path) = config.java.custom_path {
+        return resolve_java_path(&custom_path.to_string_lossy())
+            .map(|path| (path, SettingSource::Global))
+            .ok_or_else(|| format!(
+                "Global Java path not found: {:?}\n\
+                 Please set up Java in the launcher's Java settings.",
+                custom_path
+            ));
+    }
+    
+    let java_exe = if cfg!(target_os = "windows") { "java.exe" } else { "java" };
+    if config.java.auto_detect {
+        // Get required Java version for this Minecraft version
+        let required = java::get_required_java_version(&instance.minecraft_version);
+        if let Some(detected) = java::find_java_for_version(required) {
+            debug!("Auto-detected Java for version {}: {:?}", required, detected.path);
+            return Ok((detected.path, SettingSource::Default));
+        }
+        
+        // Fall back to any Java in PATH
+        return which::which(java_exe)
+            .map(|path| {
+                warn!("Using fallback Java from PATH");
+                (path, SettingSource::Default)
+            })
+            .map_err(|_| "No Java installation found.\n\
+                 Please install Java or configure it in the launcher settings.\n\n\
+                 You can download Java from:\n\
+                 - https://adoptium.net/ (Recommended)\n\
+                 - https://www.azul.com/downloads/".to_string());
+    }
+    
+    which::which(java_exe)
+        .map(|path| (path, SettingSource::Default))
+        .map_err(|_| "No Java installation found in PATH.\n\
+             Please install Java or configure it in the launcher settings.".to_string())
+}
+
+/// Resolve Java executable path from a setting
+pub fn resolve_java_path(path: &str) -> Option<PathBuf> {
+    let path = PathBuf::from(path);
+    
+    // Check if it's already a direct path
+    if path.exists() {
+        return Some(path);
+    }
+    
+    // Try to find it in PATH
+    if let Ok(resolved) = which::which(&path) {
+        return Some(resolved);
+    }
+    
+    None
+}
+
+/// Version string reported by a Java executable, or None when it doesn't run
+pub async fn java_version_at(java_path: &PathBuf) -> Option<String> {
+    CheckJavaStep::new().validate_java(java_path).await.map(|info| info.version)
+}
+
 impl CheckJavaStep {
     pub fn new() -> Self {
         Self {
@@ -40,23 +117,6 @@ impl CheckJavaStep {
         }
     }
     
-    /// Resolve Java executable path from a setting
-    fn resolve_java_path(&self, path: &str) -> Option<PathBuf> {
-        let path = PathBuf::from(path);
-        
-        // Check if it's already a direct path
-        if path.exists() {
-            return Some(path);
-        }
-        
-        // Try to find it in PATH
-        if let Ok(resolved) = which::which(&path) {
-            return Some(resolved);
-        }
-        
-        None
-    }
-    
     /// Validate a Java installation by running it
     async fn validate_java(&self, java_path: &PathBuf) -> Option<JavaInfo> {
         debug!("Validating Java at: {:?}", java_path);
@@ -196,83 +256,26 @@ impl LaunchStep for CheckJavaStep {
         let instance = &context.instance;
         let config = &context.config;
         
-        // Determine Java path to use
-        let java_path: Option<PathBuf>;
-        #[allow(unused_assignments)] // Used for debugging/logging purposes
-        let mut _per_instance: bool = false;
-        
-        // Check instance-specific Java first
-        if let Some(ref instance_java) = instance.settings.java_path {
-            if let Some(resolved) = self.resolve_java_path(&instance_java.to_string_lossy()) {
-                java_path = Some(resolved);
-                _per_instance = true;
-                info!("Using instance-specific Java: {:?}", java_path);
-            } else {
-                return LaunchStepResult::Failed(format!(
-                    "Instance Java path not found: {:?}\n\
-                     Please set up Java in the launcher's Java settings.",
-                    instance_java
-                ));
-            }
-        }
-        // Check global custom Java path
-        else if let Some(ref custom_path) = config.java.custom_path {
-            if let Some(resolved) = self.resolve_java_path(&custom_path.to_string_lossy()) {
-                java_path = Some(resolved);
-                _per_instance = false;
-                info!("Using global custom Java: {:?}", java_path);
-            } else {
-                return LaunchStepResult::Failed(format!(
-                    "Global Java path not found: {:?}\n\
-                     Please set up Java in the launcher's Java settings.",
-                    custom_path
-                ));
-            }
-        }
-        // Auto-detect Java
-        else if config.java.auto_detect {
+        if instance.settings.java_path.is_none() && config.java.custom_path.is_none() && config.java.auto_detect {
             self.status = Some("Auto-detecting Java...".to_string());
-            
-            // Get required Java version for this Minecraft version
-            let required = java::get_required_java_version(&instance.minecraft_version);
-            
-            if let Some(detected) = java::find_java_for_version(required) {
-                java_path = Some(detected.path);
-                _per_instance = false;
-                info!("Auto-detected Java for version {}: {:?}", required, java_path);
-            } else {
-                // Fall back to any Java in PATH
-                let java_exe = if cfg!(target_os = "windows") { "java.exe" } else { "java" };
-                if let Ok(path) = which::which(java_exe) {
-                    java_path = Some(path);
-                    _per_instance = false;
-                    warn!("Using fallback Java from PATH");
-                } else {
-                    return LaunchStepResult::Failed(
-                        "No Java installation found.\n\
-                         Please install Java or configure it in the launcher settings.\n\n\
-                         You can download Java from:\n\
-                         - https://adoptium.net/ (Recommended)\n\
-                         - https://www.azul.com/downloads/".to_string()
-                    );
-                }
-            }
         }
-        // Fall back to PATH
-        else {
-            let java_exe = if cfg!(target_os = "windows") { "java.exe" } else { "java" };
-            if let Ok(path) = which::which(java_exe) {
-                java_path = Some(path);
-                _per_instance = false;
-            } else {
-                return LaunchStepResult::Failed(
-                    "No Java installation found in PATH.\n\
-                     Please install Java or configure it in the launcher settings.".to_string()
-                );
+        
+        let java_path = match select_java(instance, config) {
+            Ok((path, source)) => {
+                match source {
+                    SettingSource::Instance => info!("Using instance-specific Java: {:?}", path),
+                    SettingSource::Global => info!("Using global custom Java: {:?}", path),
+                    SettingSource::Default => info!("Using detected Java: {:?}", path),
+                }
+                path
             }
-        }
+            Err(e) => return LaunchStepResult::Failed(e),
+        };
         
-        let java_path = java_path.unwrap();
+        // A binary without its executable bit fails to spawn with a bare "permission denied"
+        if let Err(e) = java::permissions::ensure_executable(&java_path) {
+            return LaunchStepResult::Failed(e.to_string());
+        }
         
         // Validate the Java installation
         self.status = Some("Validating Java...".to_string());
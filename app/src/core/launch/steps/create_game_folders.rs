@@ -22,6 +22,7 @@ use async_trait::async_trait;
 use std::fs;
 use tracing::{debug, info};
 
+use crate::core::instance::{share_folder, SharedFolder};
 use crate::core::launch::{LaunchContext, LaunchStep, LaunchStepResult};
 
 /// Step that creates required game folders
@@ -87,6 +88,20 @@ impl LaunchStep for CreateGameFoldersStep {
         }
         self.progress = 0.6;
         
+        // Link shared folders before the regular ones are created in their place
+        let shared = [
+            (context.instance.settings.shared_screenshots, SharedFolder::Screenshots, context.config.shared_screenshots_dir()),
+            (context.instance.settings.shared_saves, SharedFolder::Saves, context.config.shared_saves_dir()),
+        ];
+        for (enabled, folder, shared_dir) in shared {
+            if enabled {
+                // Non-fatal - the game still runs with a local folder
+                if let Err(e) = share_folder(&game_dir, folder, &shared_dir) {
+                    tracing::warn!("Couldn't link the shared '{}' folder: {}", folder.dir_name(), e);
+                }
+            }
+        }
+        
         // Create other common directories
         let directories = [
             "mods",
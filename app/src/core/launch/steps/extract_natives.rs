@@ -19,6 +19,7 @@
 //! along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use async_trait::async_trait;
+use serde::Serialize;
 use std::fs::{self, File};
 use std::io;
 use std::path::{Path, PathBuf};
@@ -26,135 +27,259 @@ use tracing::{debug, info, warn};
 use zip::ZipArchive;
 
 use crate::core::launch::{LaunchContext, LaunchStep, LaunchStepResult};
+use crate::core::minecraft::local_versions::load_version_json;
 use crate::core::minecraft::version::{fetch_version_manifest, fetch_version_data};
 
-/// Step that extracts native libraries
-pub struct ExtractNativesStep {
-    status: Option<String>,
-    progress: f32,
+/// Native libraries extracted for a launch
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ExtractedNatives {
+    /// Operating system the natives were picked for ("windows", "osx", "linux")
+    pub os: String,
+    /// Value used for `${arch}` in native classifiers ("64" or "32")
+    pub arch: String,
+    /// Native jars extracted, by file name
+    pub jars: Vec<String>,
+    /// Native libraries written to the natives folder, relative to it
+    pub libraries: Vec<String>,
+    /// Native jars the version needs that aren't downloaded
+    pub missing: Vec<String>,
 }
 
-impl ExtractNativesStep {
-    pub fn new() -> Self {
-        Self {
-            status: None,
-            progress: 0.0,
+/// Native jars a version lists for the current platform
+struct NativeJars {
+    os: &'static str,
+    arch: &'static str,
+    found: Vec<PathBuf>,
+    missing: Vec<PathBuf>,
+}
+
+/// Whether a jar entry is a native library
+fn is_native_library(name: &str) -> bool {
+    name.ends_with(".dll")
+        || name.ends_with(".so")
+        || name.ends_with(".dylib")
+        || name.ends_with(".jnilib")
+}
+
+/// Uncompressed size of the native libraries in a native JAR
+fn native_jar_size(jar_path: &Path) -> io::Result<u64> {
+    let mut archive = ZipArchive::new(File::open(jar_path)?)?;
+    let mut size = 0;
+    for i in 0..archive.len() {
+        let entry = archive.by_index_raw(i)?;
+        if !entry.name().starts_with("META-INF/") && is_native_library(entry.name()) {
+            size += entry.size();
         }
     }
+    Ok(size)
+}
+
+/// Bytes extracting the context's native jars will write, from the
+/// uncompressed sizes in the jars that are downloaded
+pub(super) async fn natives_size(context: &LaunchContext) -> Result<u64, String> {
+    let native_jars = get_native_jars(context).await?;
+    native_jars.found.iter()
+        .map(|jar| native_jar_size(jar).map_err(|e| format!("Failed to read {:?}: {}", jar, e)))
+        .sum()
+}
+
+/// Extract a native JAR to the output directory, returning the libraries written
+fn extract_native_jar(jar_path: &Path, output_dir: &Path, apply_jnilib_hack: bool) -> io::Result<Vec<String>> {
+    let file = File::open(jar_path)?;
+    let mut archive = ZipArchive::new(file)?;
+    let mut extracted = Vec::new();
     
-    /// Extract a native JAR to the output directory
-    fn extract_native_jar(&self, jar_path: &Path, output_dir: &Path, apply_jnilib_hack: bool) -> io::Result<()> {
-        let file = File::open(jar_path)?;
-        let mut archive = ZipArchive::new(file)?;
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i)?;
+        let name = entry.name().to_string();
         
-        for i in 0..archive.len() {
-            let mut entry = archive.by_index(i)?;
-            let name = entry.name().to_string();
-            
-            // Skip non-native files
-            if name.ends_with('/') {
-                continue; // Directory
-            }
-            
-            // Skip META-INF
-            if name.starts_with("META-INF/") {
-                continue;
-            }
-            
-            // Only extract native libraries
-            let is_native = name.ends_with(".dll") 
-                || name.ends_with(".so") 
-                || name.ends_with(".dylib")
-                || name.ends_with(".jnilib");
-            
-            if !is_native {
-                continue;
-            }
-            
-            // Apply .jnilib -> .dylib hack for macOS
-            let output_name = if apply_jnilib_hack && name.ends_with(".jnilib") {
-                name.replace(".jnilib", ".dylib")
-            } else {
-                name.clone()
-            };
-            
-            let output_path = output_dir.join(&output_name);
-            
-            // Create parent directories
-            if let Some(parent) = output_path.parent() {
-                fs::create_dir_all(parent)?;
-            }
-            
-            // Extract file
-            let mut output_file = File::create(&output_path)?;
-            io::copy(&mut entry, &mut output_file)?;
-            
-            debug!("Extracted native: {} -> {:?}", name, output_path);
+        // Skip non-native files
+        if name.ends_with('/') {
+            continue; // Directory
         }
         
-        Ok(())
-    }
-    
-    /// Get list of native JARs for the current platform
-    async fn get_native_jars(&self, context: &LaunchContext) -> Result<Vec<PathBuf>, String> {
-        let manifest = fetch_version_manifest().await
-            .map_err(|e| format!("Failed to fetch version manifest: {}", e))?;
-        
-        let version_info = manifest.get_version(&context.instance.minecraft_version)
-            .ok_or_else(|| format!("Version {} not found", context.instance.minecraft_version))?;
-        
-        let version_data = fetch_version_data(version_info).await
-            .map_err(|e| format!("Failed to fetch version data: {}", e))?;
+        // Skip META-INF
+        if name.starts_with("META-INF/") {
+            continue;
+        }
         
-        let mut native_jars = Vec::new();
-        let libraries_dir = &context.libraries_dir;
+        // Only extract native libraries
+        if !is_native_library(&name) {
+            continue;
+        }
         
-        // Determine current OS
-        let os_name = if cfg!(target_os = "windows") {
-            "windows"
-        } else if cfg!(target_os = "macos") {
-            "osx"
+        // Apply .jnilib -> .dylib hack for macOS
+        let output_name = if apply_jnilib_hack && name.ends_with(".jnilib") {
+            name.replace(".jnilib", ".dylib")
         } else {
-            "linux"
+            name.clone()
         };
         
-        for library in &version_data.libraries {
-            // Check if this library has natives for our OS
-            if let Some(ref natives) = library.natives {
-                let native_key = match os_name {
-                    "windows" => natives.get("windows"),
-                    "osx" => natives.get("osx").or_else(|| natives.get("macos")),
-                    "linux" => natives.get("linux"),
-                    _ => None,
-                };
-                
-                if let Some(classifier) = native_key {
-                    // Build path to native JAR
-                    if let Some(ref downloads) = library.downloads {
-                        if let Some(ref classifiers) = downloads.classifiers {
-                            // Replace ${arch} in classifier
-                            let arch = if cfg!(target_arch = "x86_64") {
-                                "64"
+        let output_path = output_dir.join(&output_name);
+        
+        // Create parent directories
+        if let Some(parent) = output_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        
+        // Extract file
+        let mut output_file = File::create(&output_path)?;
+        io::copy(&mut entry, &mut output_file)?;
+        
+        debug!("Extracted native: {} -> {:?}", name, output_path);
+        extracted.push(output_name);
+    }
+    
+    Ok(extracted)
+}
+
+/// Get list of native JARs for the current platform
+async fn get_native_jars(context: &LaunchContext) -> Result<NativeJars, String> {
+    // The version's JSON is stored once its files are downloaded, so only
+    // a first launch needs the network here
+    let minecraft_version = &context.instance.minecraft_version;
+    let version_data = match load_version_json(&context.config.meta_dir(), minecraft_version) {
+        Some(data) => data,
+        None => {
+            let manifest = fetch_version_manifest().await
+                .map_err(|e| format!("Failed to fetch version manifest: {}", e))?;
+            
+            let version_info = manifest.get_version(minecraft_version)
+                .ok_or_else(|| format!("Version {} not found", minecraft_version))?;
+            
+            fetch_version_data(version_info).await
+                .map_err(|e| format!("Failed to fetch version data: {}", e))?
+        }
+    };
+    
+    let libraries_dir = &context.libraries_dir;
+    
+    // Determine current OS
+    let os_name = if cfg!(target_os = "windows") {
+        "windows"
+    } else if cfg!(target_os = "macos") {
+        "osx"
+    } else {
+        "linux"
+    };
+    
+    // Replace ${arch} in classifier
+    let arch = if cfg!(target_arch = "x86_64") {
+        "64"
+    } else {
+        "32"
+    };
+    
+    let mut native_jars = NativeJars {
+        os: os_name,
+        arch,
+        found: Vec::new(),
+        missing: Vec::new(),
+    };
+    
+    for library in &version_data.libraries {
+        // Check if this library has natives for our OS
+        if let Some(ref natives) = library.natives {
+            let native_key = match os_name {
+                "windows" => natives.get("windows"),
+                "osx" => natives.get("osx").or_else(|| natives.get("macos")),
+                "linux" => natives.get("linux"),
+                _ => None,
+            };
+            
+            if let Some(classifier) = native_key {
+                // Build path to native JAR
+                if let Some(ref downloads) = library.downloads {
+                    if let Some(ref classifiers) = downloads.classifiers {
+                        let actual_classifier = classifier.replace("${arch}", arch);
+                        
+                        if let Some(artifact) = classifiers.get(&actual_classifier) {
+                            let path = libraries_dir.join(&artifact.path);
+                            if path.exists() {
+                                native_jars.found.push(path);
                             } else {
-                                "32"
-                            };
-                            let actual_classifier = classifier.replace("${arch}", arch);
-                            
-                            if let Some(artifact) = classifiers.get(&actual_classifier) {
-                                let path = libraries_dir.join(&artifact.path);
-                                if path.exists() {
-                                    native_jars.push(path);
-                                } else {
-                                    warn!("Native JAR not found: {:?}", path);
-                                }
+                                warn!("Native JAR not found: {:?}", path);
+                                native_jars.missing.push(path);
                             }
                         }
                     }
                 }
             }
         }
-        
-        Ok(native_jars)
+    }
+    
+    Ok(native_jars)
+}
+
+/// Whether `.jnilib` natives should be renamed to `.dylib` (Java 8+)
+fn needs_jnilib_hack(java_version: Option<&str>) -> bool {
+    let java_major = java_version
+        .and_then(|v| {
+            if v.starts_with("1.") {
+                v.split('.').nth(1)?.parse::<u32>().ok()
+            } else {
+                v.split('.').next()?.parse::<u32>().ok()
+            }
+        })
+        .unwrap_or(8);
+    
+    java_major >= 8
+}
+
+/// Clear a launch context's natives folder and extract its version's native
+/// jars into it. Uses `context.java_version` when set. `on_jar` is called
+/// with each jar's index, the number of jars and its path before it's extracted.
+pub async fn extract_natives(
+    context: &LaunchContext,
+    mut on_jar: impl FnMut(usize, usize, &Path),
+) -> Result<ExtractedNatives, String> {
+    let native_jars = get_native_jars(context).await?;
+    let output_dir = &context.natives_dir;
+    
+    // Leftovers are overwritten anyway, so a folder that can't be cleared isn't fatal
+    if output_dir.exists() {
+        if let Err(e) = fs::remove_dir_all(output_dir) {
+            warn!("Failed to clean natives directory: {}", e);
+        }
+    }
+    fs::create_dir_all(output_dir)
+        .map_err(|e| format!("Failed to create natives directory: {}", e))?;
+    
+    let apply_jnilib_hack = needs_jnilib_hack(context.java_version.as_deref());
+    let file_name = |path: &PathBuf| path.file_name().unwrap_or_default().to_string_lossy().to_string();
+    
+    let mut extracted = ExtractedNatives {
+        os: native_jars.os.to_string(),
+        arch: native_jars.arch.to_string(),
+        missing: native_jars.missing.iter().map(file_name).collect(),
+        ..Default::default()
+    };
+    for (i, jar_path) in native_jars.found.iter().enumerate() {
+        on_jar(i, native_jars.found.len(), jar_path);
+        let libraries = extract_native_jar(jar_path, output_dir, apply_jnilib_hack)
+            .map_err(|e| format!(
+                "Couldn't extract native jar '{}' to destination '{:?}': {}",
+                jar_path.display(), output_dir, e
+            ))?;
+        extracted.jars.push(file_name(jar_path));
+        extracted.libraries.extend(libraries);
+    }
+    
+    Ok(extracted)
+}
+
+/// Step that extracts native libraries
+pub struct ExtractNativesStep {
+    status: Option<String>,
+    progress: f32,
+}
+
+impl ExtractNativesStep {
+    pub fn new() -> Self {
+        Self {
+            status: None,
+            progress: 0.0,
+        }
     }
 }
 
@@ -172,63 +297,25 @@ impl LaunchStep for ExtractNativesStep {
         self.status = Some("Finding native libraries...".to_string());
         self.progress = 0.0;
         
-        let native_jars = match self.get_native_jars(context).await {
-            Ok(jars) => jars,
+        let extracted = extract_natives(context, |i, total, jar_path| {
+            self.status = Some(format!(
+                "Extracting {}...",
+                jar_path.file_name().unwrap_or_default().to_string_lossy()
+            ));
+            self.progress = i as f32 / total as f32;
+        }).await;
+        let extracted = match extracted {
+            Ok(extracted) => extracted,
             Err(e) => return LaunchStepResult::Failed(e),
         };
         
-        if native_jars.is_empty() {
+        if extracted.jars.is_empty() {
             info!("No native libraries to extract");
             self.status = Some("No natives to extract".to_string());
-            self.progress = 1.0;
-            return LaunchStepResult::Success;
-        }
-        
-        info!("Extracting {} native JARs", native_jars.len());
-        
-        let output_dir = &context.natives_dir;
-        
-        // Clean existing natives
-        if output_dir.exists() {
-            if let Err(e) = fs::remove_dir_all(output_dir) {
-                warn!("Failed to clean natives directory: {}", e);
-            }
-        }
-        fs::create_dir_all(output_dir).ok();
-        
-        // Check if jnilib hack is needed (Java 8+)
-        let java_major = context.java_version
-            .as_ref()
-            .and_then(|v| {
-                if v.starts_with("1.") {
-                    v.split('.').nth(1)?.parse::<u32>().ok()
-                } else {
-                    v.split('.').next()?.parse::<u32>().ok()
-                }
-            })
-            .unwrap_or(8);
-        
-        let apply_jnilib_hack = java_major >= 8;
-        
-        // Extract each native JAR
-        for (i, jar_path) in native_jars.iter().enumerate() {
-            self.status = Some(format!(
-                "Extracting {}...",
-                jar_path.file_name().unwrap_or_default().to_string_lossy()
-            ));
-            self.progress = i as f32 / native_jars.len() as f32;
-            
-            if let Err(e) = self.extract_native_jar(jar_path, output_dir, apply_jnilib_hack) {
-                let reason = format!(
-                    "Couldn't extract native jar '{}' to destination '{:?}': {}",
-                    jar_path.display(), output_dir, e
-                );
-                return LaunchStepResult::Failed(reason);
-            }
+        } else {
+            info!("Extracted {} native libraries from {} JARs", extracted.libraries.len(), extracted.jars.len());
+            self.status = Some("Natives extracted".to_string());
         }
-        
-        info!("Native libraries extracted successfully");
-        self.status = Some("Natives extracted".to_string());
         self.progress = 1.0;
         
         LaunchStepResult::Success
@@ -24,41 +24,66 @@ use std::process::{Child, Command, Stdio};
 use std::sync::{Arc, Mutex};
 use tracing::{debug, info, warn};
 
-use crate::core::launch::{LaunchContext, LaunchStep, LaunchStepResult};
-use crate::core::minecraft::version::{fetch_version_manifest, fetch_version_data, ArgumentValue, ArgumentValueInner, evaluate_rules_with_features, VersionData};
-use crate::core::minecraft::libraries::build_classpath;
+use crate::core::launch::{build_launch_classpath, find_launch_wrapper, missing_wrapper_message, passes_token_out_of_band, schedule_token_file_removal, write_access_token_file, LaunchContext, LaunchStep, LaunchStepResult, ACCESS_TOKEN_PLACEHOLDER};
+use crate::core::minecraft::version::{fetch_version_manifest, fetch_version_data, ArgumentValue, ArgumentValueInner, evaluate_rules_with_features, supports_quick_play, VersionData};
+use crate::core::instance::Instance;
+use crate::core::minecraft::assets::prepare_legacy_assets;
 use crate::core::modloaders::{ModloaderProfile, LauncherType};
 
-/// The name of the wrapper JAR
-const OXIDE_LAUNCH_JAR: &str = "OxideLaunch.jar";
+/// Where the game writes its quick play log (`--quickPlayPath`)
+fn quick_play_log_path(instance: &Instance) -> PathBuf {
+    instance.game_dir().join("quickPlay").join("java").join("log.json")
+}
 
-/// Normalize a path to use the OS-native separator
-/// This is needed because maven_to_path uses forward slashes,
-/// but Java on Windows may not handle mixed path separators correctly
-fn normalize_path(path: &PathBuf) -> String {
-    // Use canonicalize if possible, otherwise just convert to string
-    // canonicalize will normalize the path and resolve symlinks
-    if let Ok(canonical) = path.canonicalize() {
-        let path_str = canonical.to_string_lossy().to_string();
-        // On Windows, canonicalize adds \\?\ prefix which Java doesn't understand
-        // Strip it if present
-        #[cfg(windows)]
-        {
-            if let Some(stripped) = path_str.strip_prefix(r"\\?\") {
-                return stripped.to_string();
-            }
-        }
-        path_str
-    } else {
-        // Path doesn't exist or can't be canonicalized, just replace slashes on Windows
-        #[cfg(windows)]
-        {
-            path.to_string_lossy().replace('/', "\\")
-        }
-        #[cfg(not(windows))]
-        {
-            path.to_string_lossy().to_string()
+/// Expand `${NAME}` references in an environment variable value. Unknown
+/// names expand to nothing; an unclosed `${` is kept as written.
+fn expand_env_references(value: &str, lookup: impl Fn(&str) -> Option<String>) -> String {
+    let mut expanded = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find("${") {
+        let Some(len) = rest[start + 2..].find('}') else {
+            break;
+        };
+        expanded.push_str(&rest[..start]);
+        expanded.push_str(&lookup(&rest[start + 2..start + 2 + len]).unwrap_or_default());
+        rest = &rest[start + 3 + len..];
+    }
+    expanded.push_str(rest);
+    expanded
+}
+
+/// The instance's environment variables as set on the game process: empty
+/// names skipped, values expanded, and the last of any duplicates kept
+fn game_env_vars(vars: &[(String, String)], lookup: impl Fn(&str) -> Option<String>) -> Vec<(String, String)> {
+    let mut resolved: Vec<(String, String)> = Vec::new();
+    for (name, value) in vars {
+        let name = name.trim();
+        if name.is_empty() {
+            continue;
         }
+        resolved.retain(|(existing, _)| existing != name);
+        resolved.push((name.to_string(), expand_env_references(value, &lookup)));
+    }
+    resolved
+}
+
+/// The Java command line of a launch, after the Java executable
+#[derive(Debug, Clone, Default)]
+pub struct LaunchArguments {
+    /// Memory flags, version and loader JVM arguments, classpath and custom JVM arguments
+    pub jvm_args: Vec<String>,
+    /// The game's main class, or the OxideLaunch wrapper's entry point
+    pub main_class: String,
+    /// Wrapper arguments (when wrapped), then version, loader and custom game arguments
+    pub game_args: Vec<String>,
+}
+
+impl LaunchArguments {
+    fn into_args(self) -> Vec<String> {
+        let mut args = self.jvm_args;
+        args.push(self.main_class);
+        args.extend(self.game_args);
+        args
     }
 }
 
@@ -67,6 +92,10 @@ pub struct LaunchGameStep {
     status: Option<String>,
     progress: f32,
     process: Option<Arc<Mutex<Child>>>,
+    /// Legacy asset layout for `${game_assets}` (virtual or resources folder)
+    game_assets: Option<PathBuf>,
+    /// The access token goes to the wrapper in a file, not on the command line
+    token_out_of_band: bool,
 }
 
 impl LaunchGameStep {
@@ -75,49 +104,39 @@ impl LaunchGameStep {
             status: None,
             progress: 0.0,
             process: None,
+            game_assets: None,
+            token_out_of_band: false,
         }
     }
     
-    /// Get the path to the OxideLaunch wrapper JAR
-    fn get_wrapper_jar_path(&self, context: &LaunchContext) -> Option<std::path::PathBuf> {
-        // Look for the wrapper JAR in the data directory (primary location)
-        let data_dir = &context.config.data_dir;
-        let wrapper_path = std::path::Path::new(data_dir).join("bin").join(OXIDE_LAUNCH_JAR);
+    /// Build the arguments a launch of this context would use, without
+    /// preparing assets or starting anything
+    pub async fn preview_arguments(&mut self, context: &mut LaunchContext) -> Result<LaunchArguments, String> {
+        let modloader_profile = self.load_modloader_profile(context);
         
-        if wrapper_path.exists() {
-            return Some(wrapper_path);
-        }
+        let manifest = fetch_version_manifest().await
+            .map_err(|e| format!("Failed to fetch version manifest: {}", e))?;
+        let version_info = manifest.get_version(&context.instance.minecraft_version)
+            .ok_or_else(|| format!("Version {} not found", context.instance.minecraft_version))?;
+        let version_data = fetch_version_data(version_info).await
+            .map_err(|e| format!("Failed to fetch version data: {}", e))?;
+        context.features.has_quick_plays_support = supports_quick_play(&version_data);
         
-        // Check next to the executable (for bundled builds)
-        if let Ok(exe_path) = std::env::current_exe() {
-            if let Some(exe_dir) = exe_path.parent() {
-                let alt_path = exe_dir.join(OXIDE_LAUNCH_JAR);
-                if alt_path.exists() {
-                    return Some(alt_path);
-                }
-                
-                // Also check in bin/ next to exe
-                let bin_path = exe_dir.join("bin").join(OXIDE_LAUNCH_JAR);
-                if bin_path.exists() {
-                    return Some(bin_path);
-                }
-            }
+        let (launch_args, access_token_file) = self.build_launch_arguments(context, &version_data, modloader_profile.as_ref())?;
+        if let Some(path) = access_token_file {
+            let _ = std::fs::remove_file(path);
         }
+        Ok(launch_args)
+    }
+    
+    /// Get the path to the OxideLaunch wrapper JAR
+    fn get_wrapper_jar_path(&self, context: &LaunchContext) -> Option<std::path::PathBuf> {
+        let wrapper_path = find_launch_wrapper(&context.config.data_dir);
         
-        // Check in target directory (for development builds)
-        // This looks for the JAR in target/debug/bin or target/release/bin
-        if let Ok(exe_path) = std::env::current_exe() {
-            if let Some(exe_dir) = exe_path.parent() {
-                // exe_dir is typically target/debug or target/release
-                let dev_path = exe_dir.join("bin").join(OXIDE_LAUNCH_JAR);
-                if dev_path.exists() {
-                    return Some(dev_path);
-                }
-            }
+        if wrapper_path.is_none() {
+            warn!("OxideLaunch.jar not found in the data directory or launcher bundle");
         }
-
-        warn!("OxideLaunch.jar not found at {:?}", wrapper_path);
-        None
+        wrapper_path
     }
     
     /// Determine the launcher type for vanilla Minecraft (no modloader)
@@ -198,25 +217,6 @@ impl LaunchGameStep {
         }
     }
 
-    /// Check if a library is natives-only (has no main JAR, only native libraries)
-    /// These libraries should be skipped when building the classpath
-    fn is_natives_only_library(&self, name: &str) -> bool {
-        // Parse the library name to get the artifact
-        // Format: group:artifact:version or group:artifact:version:classifier
-        let parts: Vec<&str> = name.split(':').collect();
-        if parts.len() < 3 {
-            return false;
-        }
-        
-        let artifact = parts[1];
-        
-        // Known natives-only libraries that don't have a main JAR
-        // These are platform-specific native library containers
-        artifact.ends_with("-platform") 
-            || artifact == "twitch-platform" 
-            || artifact == "twitch-external-platform"
-    }
-
     /// Build the classpath including modloader libraries
     fn build_full_classpath(
         &self,
@@ -224,67 +224,7 @@ impl LaunchGameStep {
         version_data: &VersionData,
         modloader_profile: Option<&ModloaderProfile>,
     ) -> String {
-        let separator = if cfg!(target_os = "windows") { ";" } else { ":" };
-        let mut paths: Vec<String> = Vec::new();
-
-        // First add modloader libraries (they need to be before vanilla libs)
-        if let Some(profile) = modloader_profile {
-            tracing::debug!("Adding {} modloader libraries to classpath", profile.libraries.len());
-            let mut found_count = 0;
-            let mut missing_count = 0;
-            let mut skipped_natives_only = 0;
-            
-            for lib in &profile.libraries {
-                if !lib.applies_to_current_os() {
-                    tracing::trace!("Skipping library {} (not for current OS)", lib.name);
-                    continue;
-                }
-                
-                // Skip natives-only libraries (they have no main JAR to add to classpath)
-                if self.is_natives_only_library(&lib.name) {
-                    tracing::trace!("Skipping natives-only library: {}", lib.name);
-                    skipped_natives_only += 1;
-                    continue;
-                }
-                
-                let lib_path = context.libraries_dir.join(lib.get_path());
-                if lib_path.exists() {
-                    paths.push(normalize_path(&lib_path));
-                    found_count += 1;
-                } else {
-                    tracing::warn!("Modloader library not found: {} (expected at {:?})", lib.name, lib_path);
-                    missing_count += 1;
-                }
-            }
-            
-            if skipped_natives_only > 0 {
-                tracing::debug!("Skipped {} natives-only libraries from classpath", skipped_natives_only);
-            }
-            tracing::debug!("Modloader libraries: {} found, {} missing", found_count, missing_count);
-            
-            if missing_count > 0 {
-                tracing::warn!("Some modloader libraries are missing! The game may not launch correctly.");
-            }
-        }
-
-        // Then add vanilla libraries and client jar
-        let client_jar = context.config.meta_dir()
-            .join("versions")
-            .join(&context.instance.minecraft_version)
-            .join(format!("{}.jar", &context.instance.minecraft_version));
-
-        let vanilla_classpath = build_classpath(version_data, &context.libraries_dir, &client_jar);
-        
-        // Append vanilla classpath entries (avoiding duplicates)
-        for path in vanilla_classpath.split(separator) {
-            if !paths.contains(&path.to_string()) {
-                paths.push(path.to_string());
-            }
-        }
-        
-        tracing::debug!("Total classpath entries: {}", paths.len());
-
-        paths.join(separator)
+        build_launch_classpath(context, version_data, modloader_profile)
     }
 
     /// Get the main class (modloader overrides vanilla if present)
@@ -312,11 +252,7 @@ impl LaunchGameStep {
         let mut args = Vec::new();
         
         // Memory settings
-        let min_mem = context.instance.settings.min_memory.unwrap_or(context.config.memory.min_memory);
-        let max_mem = context.instance.settings.max_memory.unwrap_or(context.config.memory.max_memory);
-        
-        args.push(format!("-Xms{}M", min_mem));
-        args.push(format!("-Xmx{}M", max_mem));
+        args.extend(context.memory_args());
 
         // Build classpath
         let classpath = self.build_full_classpath(context, version_data, modloader_profile);
@@ -349,13 +285,8 @@ impl LaunchGameStep {
             args.push(classpath);
         }
         
-        // Custom JVM arguments from instance
-        if let Some(ref custom_args) = context.instance.settings.jvm_args {
-            args.extend(custom_args.split_whitespace().map(String::from));
-        }
-        
-        // Extra JVM arguments from global config
-        args.extend(context.config.java.extra_args.clone());
+        // Custom JVM arguments from the global config, argument profile and instance
+        args.extend(context.custom_jvm_args());
         
         args
     }
@@ -395,132 +326,25 @@ impl LaunchGameStep {
             }
         }
         
-        // Window size - use instance settings if set, otherwise global config
-        let width = instance.settings.window_width.unwrap_or(context.config.minecraft.window_width);
-        let height = instance.settings.window_height.unwrap_or(context.config.minecraft.window_height);
+        // Fullscreen or window size
+        args.extend(context.window_args());
         
-        args.push("--width".to_string());
-        args.push(width.to_string());
-        args.push("--height".to_string());
-        args.push(height.to_string());
-        
-        // Fullscreen/Launch maximized - instance setting takes priority
-        if instance.settings.fullscreen || context.config.minecraft.launch_maximized {
-            args.push("--fullscreen".to_string());
-        }
-        
-        // Custom game arguments
-        if let Some(ref custom_args) = instance.settings.game_args {
-            args.extend(custom_args.split_whitespace().map(String::from));
-        }
+        // Custom game arguments from the argument profile and instance
+        args.extend(context.custom_game_args());
         
         args
     }
     
-    /// Substitute JVM argument variables
-    fn substitute_jvm_variable(&self, template: &str, context: &LaunchContext, classpath: &str) -> String {
-        template
-            .replace("${natives_directory}", &context.natives_dir.to_string_lossy())
-            .replace("${classpath}", classpath)
-            .replace("${launcher_name}", "OxideLauncher")
-            .replace("${launcher_version}", env!("CARGO_PKG_VERSION"))
-            .replace("${classpath_separator}", if cfg!(target_os = "windows") { ";" } else { ":" })
-            .replace("${library_directory}", &context.libraries_dir.to_string_lossy())
-            // Forge-specific: ${version_name} is used in ignoreList to exclude the client JAR
-            .replace("${version_name}", &context.instance.minecraft_version)
-    }
-    
-    /// Substitute game argument variables
-    fn substitute_game_variable(&self, template: &str, context: &LaunchContext, version_data: &crate::core::minecraft::version::VersionData) -> String {
-        let instance = &context.instance;
-        let game_dir = instance.game_dir();
-        
-        // For offline accounts, provide placeholder values instead of empty strings
-        // This prevents argument parsing issues where --argName followed by empty value
-        // causes the next argument to be interpreted as the value
-        let access_token = if context.auth_session.access_token.is_empty() { 
-            "0".to_string() 
-        } else { 
-            context.auth_session.access_token.clone() 
-        };
-        let client_id = if context.auth_session.client_id.is_empty() { 
-            "0".to_string() 
-        } else { 
-            context.auth_session.client_id.clone() 
-        };
-        let xuid = if context.auth_session.xuid.is_empty() { 
-            "0".to_string() 
-        } else { 
-            context.auth_session.xuid.clone() 
-        };
-        
-        template
-            .replace("${auth_player_name}", &context.auth_session.username)
-            .replace("${auth_uuid}", &context.auth_session.uuid)
-            .replace("${auth_access_token}", &access_token)
-            .replace("${user_type}", &context.auth_session.user_type)
-            .replace("${version_name}", &instance.minecraft_version)
-            .replace("${game_directory}", &game_dir.to_string_lossy())
-            .replace("${assets_root}", &context.assets_dir.to_string_lossy())
-            .replace("${assets_index_name}", &version_data.assets)
-            .replace("${version_type}", &format!("{:?}", version_data.version_type))
-            .replace("${user_properties}", "{}")
-            // Microsoft/Xbox authentication variables (required for 1.16.4+)
-            .replace("${clientid}", &client_id)
-            .replace("${auth_xuid}", &xuid)
-    }
-}
-
-#[async_trait]
-impl LaunchStep for LaunchGameStep {
-    fn name(&self) -> &'static str {
-        "Launch Game"
-    }
-    
-    fn description(&self) -> &'static str {
-        "Launches the Minecraft process"
-    }
-    
-    async fn execute(&mut self, context: &mut LaunchContext) -> LaunchStepResult {
-        self.status = Some("Preparing to launch...".to_string());
-        self.progress = 0.0;
-        
-        // Get Java path
-        let java_path = match &context.java_path {
-            Some(path) => path.clone(),
-            None => {
-                return LaunchStepResult::Failed("Java path not set. CheckJava step may have failed.".to_string());
-            }
-        };
-        
-        // Load modloader profile if present
-        let modloader_profile = self.load_modloader_profile(context);
-        
-        // Fetch version data
-        self.status = Some("Fetching version data...".to_string());
-        self.progress = 0.1;
-        
-        let manifest = match fetch_version_manifest().await {
-            Ok(m) => m,
-            Err(e) => return LaunchStepResult::Failed(format!("Failed to fetch version manifest: {}", e)),
-        };
-        
-        let version_info = match manifest.get_version(&context.instance.minecraft_version) {
-            Some(v) => v,
-            None => return LaunchStepResult::Failed(format!(
-                "Version {} not found", context.instance.minecraft_version
-            )),
-        };
-        
-        let version_data = match fetch_version_data(version_info).await {
-            Ok(d) => d,
-            Err(e) => return LaunchStepResult::Failed(format!("Failed to fetch version data: {}", e)),
-        };
-        
-        self.progress = 0.3;
-        
+    /// Build the JVM arguments, main class and game arguments for a launch,
+    /// along with the access token file handed to the wrapper, if any
+    fn build_launch_arguments(
+        &mut self,
+        context: &LaunchContext,
+        version_data: &VersionData,
+        modloader_profile: Option<&ModloaderProfile>,
+    ) -> Result<(LaunchArguments, Option<PathBuf>), String> {
         // Determine launcher type
-        let launcher_type = if let Some(ref profile) = modloader_profile {
+        let launcher_type = if let Some(profile) = modloader_profile {
             profile.launcher_type
         } else {
             self.get_vanilla_launcher_type(&context.instance.minecraft_version)
@@ -529,24 +353,27 @@ impl LaunchStep for LaunchGameStep {
         info!("Using launcher type: {:?}", launcher_type);
         
         // Get the main class (modloader overrides vanilla)
-        let main_class = self.get_main_class(&version_data, modloader_profile.as_ref());
+        let main_class = self.get_main_class(version_data, modloader_profile);
         
         // Check for wrapper JAR
         let wrapper_jar = self.get_wrapper_jar_path(context);
-        let use_wrapper = wrapper_jar.is_some() && launcher_type != LauncherType::Standard;
+        // Standard launches also go through the wrapper when it has to read the access token
+        self.token_out_of_band = passes_token_out_of_band(&context.config, &context.auth_session);
+        if context.config.minecraft.access_token_out_of_band
+            && !context.auth_session.access_token.is_empty()
+            && !self.token_out_of_band
+        {
+            warn!("OxideLaunch wrapper is missing or too old to read the token from a file; passing the access token on the command line");
+        }
+        let use_wrapper = wrapper_jar.is_some() && (launcher_type != LauncherType::Standard || self.token_out_of_band);
+        let mut access_token_file = None;
+        let mut launch_args = LaunchArguments::default();
         
+        // Legacy/tweaker launches can't work without the wrapper; fail with a setup error up front
         if launcher_type != LauncherType::Standard && wrapper_jar.is_none() {
-            warn!(
-                "OxideLaunch wrapper JAR not found! Legacy/tweaker launch may fail. \
-                The wrapper JAR should be placed at <data_dir>/bin/OxideLaunch.jar"
-            );
+            return Err(missing_wrapper_message(&context.config.data_dir));
         }
         
-        // Build arguments
-        self.status = Some("Building launch arguments...".to_string());
-        
-        let mut args: Vec<String>;
-        
         if use_wrapper {
             // Use wrapper JAR launch mode
             let wrapper_path = wrapper_jar.unwrap();
@@ -554,19 +381,16 @@ impl LaunchStep for LaunchGameStep {
             
             // Build classpath with wrapper JAR prepended
             let classpath = {
-                let base_classpath = self.build_full_classpath(context, &version_data, modloader_profile.as_ref());
+                let base_classpath = self.build_full_classpath(context, version_data, modloader_profile);
                 let separator = if cfg!(target_os = "windows") { ";" } else { ":" };
                 format!("{}{}{}", wrapper_path.to_string_lossy(), separator, base_classpath)
             };
             
             // Start with JVM arguments
-            args = vec![];
+            let args = &mut launch_args.jvm_args;
             
             // Memory settings
-            let min_mem = context.instance.settings.min_memory.unwrap_or(context.config.memory.min_memory);
-            let max_mem = context.instance.settings.max_memory.unwrap_or(context.config.memory.max_memory);
-            args.push(format!("-Xms{}M", min_mem));
-            args.push(format!("-Xmx{}M", max_mem));
+            args.extend(context.memory_args());
             
             // Native library path
             args.push(format!("-Djava.library.path={}", context.natives_dir.to_string_lossy()));
@@ -599,27 +423,25 @@ impl LaunchStep for LaunchGameStep {
             }
             
             // Add modloader-specific JVM arguments
-            if let Some(ref profile) = modloader_profile {
+            if let Some(profile) = modloader_profile {
                 for arg in &profile.jvm_arguments {
                     let substituted = self.substitute_jvm_variable(arg, context, &classpath);
                     args.push(substituted);
                 }
             }
             
-            // Custom JVM arguments from instance
-            if let Some(ref custom_args) = context.instance.settings.jvm_args {
-                args.extend(custom_args.split_whitespace().map(String::from));
-            }
-            args.extend(context.config.java.extra_args.clone());
+            // Custom JVM arguments from the global config, argument profile and instance
+            args.extend(context.custom_jvm_args());
             
             // Classpath
             args.push("-cp".to_string());
             args.push(classpath);
             
             // Wrapper entry point
-            args.push("dev.oxide.launch.OxideLaunch".to_string());
+            launch_args.main_class = "dev.oxide.launch.OxideLaunch".to_string();
             
             // Wrapper arguments
+            let args = &mut launch_args.game_args;
             args.push("--launcher".to_string());
             args.push(launcher_type.name().to_string());
             
@@ -635,7 +457,7 @@ impl LaunchStep for LaunchGameStep {
             }
             
             args.push("--assetsDir".to_string());
-            args.push(context.assets_dir.to_string_lossy().to_string());
+            args.push(self.game_assets.as_ref().unwrap_or(&context.assets_dir).to_string_lossy().to_string());
             
             args.push("--width".to_string());
             let width = context.instance.settings.window_width.unwrap_or(context.config.minecraft.window_width);
@@ -646,52 +468,68 @@ impl LaunchStep for LaunchGameStep {
             args.push(height.to_string());
             
             // Add tweaker classes for tweaker launcher type
-            if let Some(ref profile) = modloader_profile {
+            if let Some(profile) = modloader_profile {
                 for tweaker in &profile.tweakers {
                     args.push("--tweakClass".to_string());
                     args.push(tweaker.clone());
                 }
             }
             
+            // Access token handed over in a file the wrapper deletes after reading
+            if self.token_out_of_band {
+                match write_access_token_file(&context.config.data_dir, &context.auth_session.access_token) {
+                    Ok(path) => {
+                        args.push("--accessTokenFile".to_string());
+                        args.push(path.to_string_lossy().to_string());
+                        access_token_file = Some(path);
+                    }
+                    Err(e) => {
+                        return Err(format!("Failed to write access token file: {}", e));
+                    }
+                }
+            }
+            
             // Separator between wrapper args and game args
             args.push("--".to_string());
             
             // Game arguments
-            args.extend(self.build_game_args(context, &version_data));
+            args.extend(self.build_game_args(context, version_data));
             
             // Add modloader-specific game arguments (NOT tweakers - those go above)
-            if let Some(ref profile) = modloader_profile {
+            if let Some(profile) = modloader_profile {
                 for arg in &profile.game_arguments {
-                    args.push(self.substitute_game_variable(arg, context, &version_data));
+                    args.push(self.substitute_game_variable(arg, context, version_data));
                 }
             }
             
         } else {
             // Standard direct launch (original code path)
-            args = self.build_jvm_args(context, &version_data, modloader_profile.as_ref());
+            let args = &mut launch_args.jvm_args;
+            args.extend(self.build_jvm_args(context, version_data, modloader_profile));
             
             // Add modloader-specific JVM arguments
-            if let Some(ref profile) = modloader_profile {
+            if let Some(profile) = modloader_profile {
                 for arg in &profile.jvm_arguments {
                     let substituted = self.substitute_jvm_variable(
                         arg, 
                         context, 
-                        &self.build_full_classpath(context, &version_data, Some(profile))
+                        &self.build_full_classpath(context, version_data, Some(profile))
                     );
                     args.push(substituted);
                 }
             }
             
             // Add main class
-            args.push(main_class);
+            launch_args.main_class = main_class;
             
             // Add game arguments
-            args.extend(self.build_game_args(context, &version_data));
+            let args = &mut launch_args.game_args;
+            args.extend(self.build_game_args(context, version_data));
             
             // Add modloader-specific game arguments
-            if let Some(ref profile) = modloader_profile {
+            if let Some(profile) = modloader_profile {
                 for arg in &profile.game_arguments {
-                    args.push(self.substitute_game_variable(arg, context, &version_data));
+                    args.push(self.substitute_game_variable(arg, context, version_data));
                 }
                 
                 // Add tweaker classes (for legacy modloaders like old Forge/LiteLoader)
@@ -704,6 +542,149 @@ impl LaunchStep for LaunchGameStep {
             }
         }
         
+        Ok((launch_args, access_token_file))
+    }
+    
+    /// Substitute JVM argument variables
+    fn substitute_jvm_variable(&self, template: &str, context: &LaunchContext, classpath: &str) -> String {
+        template
+            .replace("${natives_directory}", &context.natives_dir.to_string_lossy())
+            .replace("${classpath}", classpath)
+            .replace("${launcher_name}", "OxideLauncher")
+            .replace("${launcher_version}", env!("CARGO_PKG_VERSION"))
+            .replace("${classpath_separator}", if cfg!(target_os = "windows") { ";" } else { ":" })
+            .replace("${library_directory}", &context.libraries_dir.to_string_lossy())
+            // Forge-specific: ${version_name} is used in ignoreList to exclude the client JAR
+            .replace("${version_name}", &context.instance.minecraft_version)
+    }
+    
+    /// Substitute game argument variables
+    fn substitute_game_variable(&self, template: &str, context: &LaunchContext, version_data: &crate::core::minecraft::version::VersionData) -> String {
+        let instance = &context.instance;
+        let game_dir = instance.game_dir();
+        
+        // For offline accounts, provide placeholder values instead of empty strings
+        // This prevents argument parsing issues where --argName followed by empty value
+        // causes the next argument to be interpreted as the value
+        let access_token = if self.token_out_of_band {
+            ACCESS_TOKEN_PLACEHOLDER.to_string()
+        } else if context.auth_session.access_token.is_empty() { 
+            "0".to_string() 
+        } else { 
+            context.auth_session.access_token.clone() 
+        };
+        let client_id = if context.auth_session.client_id.is_empty() { 
+            "0".to_string() 
+        } else { 
+            context.auth_session.client_id.clone() 
+        };
+        let xuid = if context.auth_session.xuid.is_empty() { 
+            "0".to_string() 
+        } else { 
+            context.auth_session.xuid.clone() 
+        };
+        
+        template
+            .replace("${auth_player_name}", &context.auth_session.username)
+            .replace("${auth_uuid}", &context.auth_session.uuid)
+            .replace("${auth_access_token}", &access_token)
+            .replace("${user_type}", &context.auth_session.user_type)
+            .replace("${version_name}", &instance.minecraft_version)
+            .replace("${game_directory}", &game_dir.to_string_lossy())
+            .replace("${assets_root}", &context.assets_dir.to_string_lossy())
+            .replace("${game_assets}", &self.game_assets.as_ref().unwrap_or(&context.assets_dir).to_string_lossy())
+            .replace("${assets_index_name}", &version_data.assets)
+            .replace("${version_type}", &format!("{:?}", version_data.version_type))
+            .replace("${user_properties}", "{}")
+            // Microsoft/Xbox authentication variables (required for 1.16.4+)
+            .replace("${clientid}", &client_id)
+            .replace("${auth_xuid}", &xuid)
+            // Quick play log (1.20+). The launcher never picks a world, server or realm,
+            // so the other quick play arguments stay off
+            .replace("${quickPlayPath}", &quick_play_log_path(instance).to_string_lossy())
+    }
+}
+
+#[async_trait]
+impl LaunchStep for LaunchGameStep {
+    fn name(&self) -> &'static str {
+        "Launch Game"
+    }
+    
+    fn description(&self) -> &'static str {
+        "Launches the Minecraft process"
+    }
+    
+    async fn execute(&mut self, context: &mut LaunchContext) -> LaunchStepResult {
+        self.status = Some("Preparing to launch...".to_string());
+        self.progress = 0.0;
+        
+        // Get Java path
+        let java_path = match &context.java_path {
+            Some(path) => path.clone(),
+            None => {
+                return LaunchStepResult::Failed("Java path not set. CheckJava step may have failed.".to_string());
+            }
+        };
+        
+        // Load modloader profile if present
+        let modloader_profile = self.load_modloader_profile(context);
+        
+        // Fetch version data
+        self.status = Some("Fetching version data...".to_string());
+        self.progress = 0.1;
+        
+        let manifest = match fetch_version_manifest().await {
+            Ok(m) => m,
+            Err(e) => return LaunchStepResult::Failed(format!("Failed to fetch version manifest: {}", e)),
+        };
+        
+        let version_info = match manifest.get_version(&context.instance.minecraft_version) {
+            Some(v) => v,
+            None => return LaunchStepResult::Failed(format!(
+                "Version {} not found", context.instance.minecraft_version
+            )),
+        };
+        
+        let version_data = match fetch_version_data(version_info).await {
+            Ok(d) => d,
+            Err(e) => return LaunchStepResult::Failed(format!("Failed to fetch version data: {}", e)),
+        };
+        
+        // Legacy versions read assets from a virtual or resources layout
+        self.game_assets = match prepare_legacy_assets(
+            &context.assets_dir,
+            &version_data.assets,
+            &context.instance.game_dir(),
+        ) {
+            Ok(dir) => dir,
+            Err(e) => {
+                warn!("Failed to prepare legacy assets: {}", e);
+                None
+            }
+        };
+        
+        // Only versions that know about quick play (1.20+) get a log path
+        context.features.has_quick_plays_support = supports_quick_play(&version_data);
+        if context.features.has_quick_plays_support {
+            if let Some(dir) = quick_play_log_path(&context.instance).parent() {
+                if let Err(e) = std::fs::create_dir_all(dir) {
+                    warn!("Failed to create quick play log folder: {}", e);
+                }
+            }
+        }
+        
+        self.progress = 0.3;
+        
+        // Build arguments
+        self.status = Some("Building launch arguments...".to_string());
+        
+        let (launch_args, access_token_file) = match self.build_launch_arguments(context, &version_data, modloader_profile.as_ref()) {
+            Ok(built) => built,
+            Err(e) => return LaunchStepResult::Failed(e),
+        };
+        let args = launch_args.into_args();
+        
         self.progress = 0.5;
         
         // Handle wrapper command - use instance-specific if set, otherwise global config
@@ -802,6 +783,12 @@ impl LaunchStep for LaunchGameStep {
             .stdout(Stdio::piped())
             .stderr(Stdio::piped());
         
+        // Custom environment variables from the instance settings
+        for (name, value) in game_env_vars(&context.instance.settings.env_vars, |name| std::env::var(name).ok()) {
+            debug!("Setting environment variable {}", name);
+            command.env(name, value);
+        }
+        
         // On Windows, use CREATE_NO_WINDOW to prevent console window.
         // Note: This works with javaw.exe. If using java.exe, a console may still appear.
         // Can be disabled via debug settings for troubleshooting.
@@ -824,6 +811,9 @@ impl LaunchStep for LaunchGameStep {
         let child = match command.spawn() {
             Ok(child) => child,
             Err(e) => {
+                if let Some(path) = access_token_file {
+                    let _ = std::fs::remove_file(path);
+                }
                 return LaunchStepResult::Failed(format!(
                     "Failed to start Minecraft: {}\n\nCommand: {} {}",
                     e, program, final_args.join(" ")
@@ -833,7 +823,20 @@ impl LaunchStep for LaunchGameStep {
         
         info!("Minecraft process started with PID: {}", child.id());
         
-        self.process = Some(Arc::new(Mutex::new(child)));
+        let process = Arc::new(Mutex::new(child));
+        if let Some(path) = access_token_file {
+            let process = process.clone();
+            schedule_token_file_removal(path, async move {
+                loop {
+                    let running = matches!(process.lock().map(|mut child| child.try_wait()), Ok(Ok(None)));
+                    if !running {
+                        break;
+                    }
+                    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+                }
+            });
+        }
+        self.process = Some(process);
         
         self.status = Some("Minecraft is running".to_string());
         self.progress = 1.0;
@@ -873,3 +876,186 @@ impl Default for LaunchGameStep {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::accounts::AuthSession;
+    use crate::core::config::{ArgumentProfile, Config};
+    use crate::core::instance::Instance;
+    use crate::core::minecraft::version::LaunchFeatures;
+    use crate::core::modloaders::profile::ModloaderLibrary;
+    
+    #[test]
+    fn disabled_mods_never_reach_the_game() {
+        let dir = tempfile::tempdir().unwrap();
+        let instance = Instance::new("Test".to_string(), dir.path().to_path_buf(), "1.12.2".to_string());
+        let mods_dir = instance.mods_dir();
+        std::fs::create_dir_all(&mods_dir).unwrap();
+        std::fs::write(mods_dir.join("enabled.jar"), "a").unwrap();
+        std::fs::write(mods_dir.join("disabled.jar.disabled"), "b").unwrap();
+        
+        let mut context = LaunchContext::with_features(
+            instance.clone(),
+            AuthSession::offline("Player"),
+            Config::default(),
+            LaunchFeatures::normal(),
+        );
+        // Resolve profile libraries from the game directory so a profile can
+        // point straight at the mods folder
+        context.libraries_dir = instance.game_dir();
+        
+        let mut profile = ModloaderProfile::new("net.minecraftforge".to_string(), "14.23.5".to_string(), "1.12.2".to_string());
+        for jar in ["enabled.jar", "disabled.jar.disabled"] {
+            let mut library = ModloaderLibrary::from_maven(&format!("local:{}:1", jar));
+            library.path = Some(format!("mods/{}", jar));
+            profile.libraries.push(library);
+        }
+        
+        let version_data: VersionData = serde_json::from_value(serde_json::json!({
+            "id": "1.12.2",
+            "type": "release",
+            "mainClass": "net.minecraft.launchwrapper.Launch",
+            "releaseTime": "2017-09-18T08:39:46+00:00",
+            "time": "2017-09-18T08:39:46+00:00",
+            "assets": "1.12",
+            "assetIndex": { "id": "1.12", "sha1": "", "size": 0, "url": "" },
+            "downloads": {},
+            "libraries": [],
+            "minecraftArguments": "--gameDir ${game_directory}"
+        })).unwrap();
+        
+        let step = LaunchGameStep::new();
+        let mut args = step.build_jvm_args(&context, &version_data, Some(&profile));
+        args.extend(step.build_game_args(&context, &version_data));
+        
+        assert!(args.iter().any(|arg| arg.contains("enabled.jar")));
+        assert!(!args.iter().any(|arg| arg.contains("disabled.jar")));
+    }
+    
+    #[test]
+    fn argument_profile_comes_before_instance_args() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut instance = Instance::new("Test".to_string(), dir.path().to_path_buf(), "1.20.1".to_string());
+        instance.settings.jvm_args = Some("-Dinstance=1".to_string());
+        instance.settings.game_args = Some("--instance".to_string());
+        instance.settings.argument_profile = Some("Aikar's flags".to_string());
+        
+        let mut config = Config::default();
+        config.argument_profiles.push(ArgumentProfile {
+            name: "Aikar's flags".to_string(),
+            jvm_args: Some("-XX:+UseG1GC  -Dprofile=1".to_string()),
+            game_args: Some("--profile".to_string()),
+        });
+        
+        let context = LaunchContext::with_features(
+            instance.clone(),
+            AuthSession::offline("Player"),
+            config.clone(),
+            LaunchFeatures::normal(),
+        );
+        assert_eq!(context.custom_jvm_args(), vec!["-XX:+UseG1GC", "-Dprofile=1", "-Dinstance=1"]);
+        assert_eq!(context.custom_game_args(), vec!["--profile", "--instance"]);
+        
+        // A deleted profile is skipped rather than failing the launch
+        config.argument_profiles.clear();
+        let context = LaunchContext::with_features(instance, AuthSession::offline("Player"), config, LaunchFeatures::normal());
+        assert_eq!(context.custom_jvm_args(), vec!["-Dinstance=1"]);
+    }
+    
+    #[test]
+    fn global_jvm_args_come_first_so_instance_args_win() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut instance = Instance::new("Test".to_string(), dir.path().to_path_buf(), "1.20.1".to_string());
+        instance.settings.jvm_args = Some("-Xmx6G -Dinstance=1".to_string());
+        instance.settings.min_memory = Some(512);
+        instance.settings.max_memory = Some(4096);
+        instance.settings.argument_profile = Some("GC".to_string());
+        
+        let mut config = Config::default();
+        config.java.extra_args = vec!["-Xmx2G".to_string(), "-Dglobal=1".to_string()];
+        config.argument_profiles.push(ArgumentProfile {
+            name: "GC".to_string(),
+            jvm_args: Some("-XX:+UseG1GC".to_string()),
+            game_args: None,
+        });
+        
+        let context = LaunchContext::with_features(
+            instance.clone(),
+            AuthSession::offline("Player"),
+            config.clone(),
+            LaunchFeatures::normal(),
+        );
+        let mut args = context.memory_args();
+        args.extend(context.custom_jvm_args());
+        assert_eq!(args, vec![
+            "-Xms512M", "-Xmx4096M",
+            "-Xmx2G", "-Dglobal=1",
+            "-XX:+UseG1GC",
+            "-Xmx6G", "-Dinstance=1",
+        ]);
+        
+        instance.settings.use_global_jvm_args = false;
+        let context = LaunchContext::with_features(instance, AuthSession::offline("Player"), config, LaunchFeatures::normal());
+        assert_eq!(context.custom_jvm_args(), vec!["-XX:+UseG1GC", "-Xmx6G", "-Dinstance=1"]);
+    }
+    
+    #[test]
+    fn quick_play_passes_only_the_log_path_on_supporting_versions() {
+        let dir = tempfile::tempdir().unwrap();
+        let instance = Instance::new("Test".to_string(), dir.path().to_path_buf(), "1.20.1".to_string());
+        let version_data: VersionData = serde_json::from_value(serde_json::json!({
+            "id": "1.20.1",
+            "type": "release",
+            "mainClass": "net.minecraft.client.main.Main",
+            "releaseTime": "2023-06-12T13:25:51+00:00",
+            "time": "2023-06-12T13:25:51+00:00",
+            "assets": "5",
+            "assetIndex": { "id": "5", "sha1": "", "size": 0, "url": "" },
+            "downloads": {},
+            "libraries": [],
+            "arguments": {
+                "game": [
+                    "--gameDir", "${game_directory}",
+                    { "rules": [{ "action": "allow", "features": { "has_quick_plays_support": true } }], "value": ["--quickPlayPath", "${quickPlayPath}"] },
+                    { "rules": [{ "action": "allow", "features": { "is_quick_play_singleplayer": true } }], "value": ["--quickPlaySingleplayer", "${quickPlaySingleplayer}"] },
+                    { "rules": [{ "action": "allow", "features": { "is_quick_play_realms": true } }], "value": ["--quickPlayRealms", "${quickPlayRealms}"] }
+                ],
+                "jvm": []
+            }
+        })).unwrap();
+        assert!(supports_quick_play(&version_data));
+        
+        let step = LaunchGameStep::new();
+        
+        let mut features = LaunchFeatures::normal();
+        features.has_quick_plays_support = true;
+        let context = LaunchContext::with_features(instance.clone(), AuthSession::offline("Player"), Config::default(), features);
+        let args = step.build_game_args(&context, &version_data);
+        let path_index = args.iter().position(|arg| arg == "--quickPlayPath").unwrap();
+        assert!(args[path_index + 1].ends_with("log.json"));
+        assert!(!args.iter().any(|arg| arg.contains("Singleplayer") || arg.contains("Realms") || arg.contains("${")));
+        
+        // Versions without quick play get none of it
+        let context = LaunchContext::with_features(instance, AuthSession::offline("Player"), Config::default(), LaunchFeatures::normal());
+        assert!(!step.build_game_args(&context, &version_data).iter().any(|arg| arg.starts_with("--quickPlay")));
+    }
+    
+    #[test]
+    fn env_vars_expand_skip_empty_names_and_keep_the_last_duplicate() {
+        let lookup = |name: &str| (name == "HOME").then(|| "/home/player".to_string());
+        let vars = vec![
+            ("MESA_GL_VERSION_OVERRIDE".to_string(), "4.5".to_string()),
+            (" ".to_string(), "ignored".to_string()),
+            ("CACHE".to_string(), "${HOME}/.cache/${MISSING}gl".to_string()),
+            ("MESA_GL_VERSION_OVERRIDE".to_string(), "4.6".to_string()),
+            ("BROKEN".to_string(), "${HOME".to_string()),
+        ];
+        
+        assert_eq!(game_env_vars(&vars, lookup), vec![
+            ("CACHE".to_string(), "/home/player/.cache/gl".to_string()),
+            ("MESA_GL_VERSION_OVERRIDE".to_string(), "4.6".to_string()),
+            ("BROKEN".to_string(), "${HOME".to_string()),
+        ]);
+    }
+}
@@ -23,40 +23,69 @@ mod verify_java;
 mod auto_install_java;
 mod create_game_folders;
 mod extract_natives;
+mod apply_jar_mods;
 mod pre_launch_command;
 mod post_launch_command;
 mod launch_game;
 mod print_instance_info;
+mod check_disk_space;
 
-pub use check_java::CheckJavaStep;
+pub use check_java::{java_version_at, resolve_java_path, select_java, CheckJavaStep};
 pub use verify_java::VerifyJavaStep;
 pub use auto_install_java::AutoInstallJavaStep;
 pub use create_game_folders::CreateGameFoldersStep;
-pub use extract_natives::ExtractNativesStep;
+pub use extract_natives::{extract_natives, ExtractNativesStep, ExtractedNatives};
+pub use apply_jar_mods::ApplyJarModsStep;
 pub use pre_launch_command::PreLaunchCommandStep;
 pub use post_launch_command::PostLaunchCommandStep;
 pub use launch_game::LaunchGameStep;
 pub use print_instance_info::PrintInstanceInfoStep;
+pub use check_disk_space::CheckDiskSpaceStep;
+
+use tracing::warn;
 
 use super::task::LaunchTask;
 use super::LaunchContext;
+use crate::core::config::LaunchStepOptions;
 
-/// Create a default launch task with all standard steps
+/// Create a default launch task with all standard steps,
+/// honoring the step toggles in `config.debug.launch_steps`
 pub fn create_default_launch_task(context: LaunchContext) -> LaunchTask {
+    let options = context.config.debug.launch_steps.clone();
+    create_launch_task(context, &options)
+}
+
+/// Create a launch task with the given optional steps enabled
+pub fn create_launch_task(context: LaunchContext, options: &LaunchStepOptions) -> LaunchTask {
+    let options = resolve_step_options(&context, options);
     let mut task = LaunchTask::new(context.clone());
     
     // Add steps in order
-    task.append_step(Box::new(PrintInstanceInfoStep::new()));
-    task.append_step(Box::new(CreateGameFoldersStep::new()));
-    task.append_step(Box::new(CheckJavaStep::new()));
-    task.append_step(Box::new(VerifyJavaStep::new()));
+    if options.print_instance_info {
+        task.append_step(Box::new(PrintInstanceInfoStep::new()));
+    }
+    task.append_step(Box::new(CheckDiskSpaceStep::new()));
+    if options.create_game_folders {
+        task.append_step(Box::new(CreateGameFoldersStep::new()));
+    }
+    if options.check_java {
+        task.append_step(Box::new(CheckJavaStep::new()));
+    }
+    if options.verify_java {
+        task.append_step(Box::new(VerifyJavaStep::new()));
+    }
     
     // Auto-install Java if enabled
     if context.config.java.auto_download {
         task.append_step(Box::new(AutoInstallJavaStep::new()));
     }
     
-    task.append_step(Box::new(ExtractNativesStep::new()));
+    if options.extract_natives {
+        task.append_step(Box::new(ExtractNativesStep::new()));
+    }
+    
+    // Patch jar mods into the client jar (no-op without jar mods)
+    task.append_step(Box::new(ApplyJarModsStep::new()));
     
     // Pre-launch command if configured
     if context.instance.settings.pre_launch_command.is_some() {
@@ -73,3 +102,39 @@ pub fn create_default_launch_task(context: LaunchContext) -> LaunchTask {
     
     task
 }
+
+/// Re-enable skipped steps whose output later steps depend on
+fn resolve_step_options(context: &LaunchContext, options: &LaunchStepOptions) -> LaunchStepOptions {
+    let mut resolved = options.clone();
+    
+    // Without CheckJava, only AutoInstallJava can provide the Java path
+    if !resolved.check_java && !context.config.java.auto_download {
+        warn!("CheckJava can't be skipped while Java auto-download is disabled; running it anyway");
+        resolved.check_java = true;
+    }
+    
+    // VerifyJava needs the Java version detected by CheckJava
+    if !resolved.check_java && resolved.verify_java {
+        warn!("Skipping VerifyJava because CheckJava is skipped");
+        resolved.verify_java = false;
+    }
+    
+    // Skipping folder creation only makes sense once the folders exist
+    if !resolved.create_game_folders
+        && (!context.instance.game_dir().is_dir() || !context.natives_dir.is_dir())
+    {
+        warn!("Game folders don't exist yet; running CreateGameFolders anyway");
+        resolved.create_game_folders = true;
+    }
+    
+    // Natives from a previous launch can be reused, but there must be some
+    let has_natives = std::fs::read_dir(&context.natives_dir)
+        .map(|mut entries| entries.next().is_some())
+        .unwrap_or(false);
+    if !resolved.extract_natives && !has_natives {
+        warn!("No extracted natives found; running ExtractNatives anyway");
+        resolved.extract_natives = true;
+    }
+    
+    resolved
+}
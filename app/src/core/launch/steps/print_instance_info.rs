@@ -93,6 +93,9 @@ impl LaunchStep for PrintInstanceInfoStep {
         if instance.settings.fullscreen {
             info!("Fullscreen: enabled");
         }
+        if let Some(monitor) = instance.settings.monitor_index {
+            info!("Monitor: {}", monitor);
+        }
         
         if instance.settings.skip_java_compatibility_check {
             info!("Java Compatibility Check: SKIPPED");
@@ -52,19 +52,25 @@ impl VerifyJavaStep {
         }
     }
     
-    /// Get compatible Java major versions for a Minecraft version
-    fn get_compatible_java_majors(minecraft_version: &str) -> Vec<u32> {
-        let required = java::get_required_java_version(minecraft_version);
+    /// Lower the heap for this launch when a 32-bit Java can't address it
+    fn limit_32bit_memory(context: &mut LaunchContext) {
+        let is_64bit = context.java_architecture.as_deref() != Some("32");
+        let settings = &context.instance.settings;
+        let min_memory = settings.min_memory.unwrap_or(context.config.memory.min_memory);
+        let max_memory = settings.max_memory.unwrap_or(context.config.memory.max_memory);
         
-        // Return a list of compatible versions
-        // Generally newer Java versions are forward compatible
-        match required {
-            8 => vec![8], // Minecraft < 1.17 strictly needs Java 8
-            16 => vec![16, 17], // 1.17 snapshots
-            17 => vec![17, 18, 19, 20, 21], // 1.17-1.20.4
-            21 => vec![21, 22, 23, 24, 25], // 1.20.5+
-            _ => vec![required],
+        let validation = java::validate_memory_for_arch(is_64bit, min_memory, max_memory);
+        if validation.warnings.is_empty() {
+            return;
         }
+        
+        for warning in &validation.warnings {
+            warn!("{}", warning);
+        }
+        
+        // Only the launch context's copy of the instance is changed
+        context.instance.settings.min_memory = Some(validation.min_memory);
+        context.instance.settings.max_memory = Some(validation.max_memory);
     }
 }
 
@@ -82,6 +88,11 @@ impl LaunchStep for VerifyJavaStep {
         self.status = Some("Verifying Java compatibility...".to_string());
         self.progress = 0.0;
         
+        // Check architecture and memory before anything can skip the step,
+        // since a 32-bit JVM exits immediately when the heap is too large
+        Self::limit_32bit_memory(context);
+        self.progress = 0.3;
+        
         let instance = &context.instance;
         
         // Get stored Java version from CheckJava step
@@ -94,8 +105,6 @@ impl LaunchStep for VerifyJavaStep {
             }
         };
         
-        let java_architecture = context.java_architecture.as_deref().unwrap_or("64");
-        
         // Check if user wants to ignore compatibility
         if instance.settings.skip_java_compatibility_check {
             warn!(
@@ -107,19 +116,6 @@ impl LaunchStep for VerifyJavaStep {
             return LaunchStepResult::Success;
         }
         
-        // Check architecture and memory
-        self.progress = 0.3;
-        let max_memory = instance.settings.max_memory.unwrap_or(context.config.memory.max_memory);
-        
-        if java_architecture == "32" && max_memory > 2048 {
-            warn!(
-                "32-bit Java detected with {}MB max memory allocation. \
-                 32-bit Java cannot use more than ~2048MB.",
-                max_memory
-            );
-            // Don't fail, just warn - the game might still run with reduced memory
-        }
-        
         // Parse Java major version
         self.progress = 0.5;
         let java_major = match Self::parse_major_version(&java_version) {
@@ -133,12 +129,12 @@ impl LaunchStep for VerifyJavaStep {
             }
         };
         
-        // Get compatible versions for this Minecraft version
-        let compatible_majors = Self::get_compatible_java_majors(&instance.minecraft_version);
+        // Get the compatible range for this Minecraft version
+        let requirement = java::get_java_requirement(&instance.minecraft_version);
         
         self.progress = 0.8;
         
-        if compatible_majors.contains(&java_major) {
+        if requirement.contains(java_major) {
             info!(
                 "Java {} is compatible with Minecraft {}",
                 java_major, instance.minecraft_version
@@ -163,7 +159,11 @@ impl LaunchStep for VerifyJavaStep {
         }
         
         // Java version is not compatible
-        let required = java::get_required_java_version(&instance.minecraft_version);
+        let required = if requirement.min_major == requirement.max_major {
+            requirement.min_major.to_string()
+        } else {
+            format!("{}-{}", requirement.min_major, requirement.max_major)
+        };
         
         LaunchStepResult::Failed(format!(
             "Java {} is not compatible with Minecraft {}.\n\n\
@@ -20,6 +20,8 @@
 
 use std::process::Child;
 use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use serde::Serialize;
 
 use crate::core::error::Result;
 use super::{
@@ -80,6 +82,30 @@ impl Default for LaunchProgress {
     }
 }
 
+/// How long one launch step took
+#[derive(Debug, Clone, Serialize)]
+pub struct StepTiming {
+    pub step: String,
+    pub duration_ms: u64,
+}
+
+/// Per-step timing of a launch, in the order the steps ran
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LaunchTiming {
+    pub steps: Vec<StepTiming>,
+    pub total_ms: u64,
+}
+
+impl LaunchTiming {
+    /// One-line summary such as `Check Java 120 ms, Launch Game 340 ms (total 460 ms)`
+    pub fn summary(&self) -> String {
+        let steps: Vec<String> = self.steps.iter()
+            .map(|timing| format!("{} {} ms", timing.step, timing.duration_ms))
+            .collect();
+        format!("{} (total {} ms)", steps.join(", "), self.total_ms)
+    }
+}
+
 /// Manages the launch process by executing steps in sequence
 pub struct LaunchTask {
     /// Launch steps to execute
@@ -105,6 +131,9 @@ pub struct LaunchTask {
     
     /// Game process (if launched)
     game_process: Option<Arc<Mutex<Child>>>,
+    
+    /// Time spent in each step that has run
+    timing: LaunchTiming,
 }
 
 impl LaunchTask {
@@ -121,6 +150,7 @@ impl LaunchTask {
             log_receiver: Some(log_receiver),
             abort_requested: false,
             game_process: None,
+            timing: LaunchTiming::default(),
         }
     }
     
@@ -190,6 +220,7 @@ impl LaunchTask {
     pub async fn execute(&mut self) -> Result<()> {
         self.state = LaunchState::Running;
         self.current_step = 0;
+        self.timing = LaunchTiming::default();
         
         let total_steps = self.steps.len();
         self.log(MessageLevel::Launcher, format!(
@@ -217,10 +248,16 @@ impl LaunchTask {
             ));
             
             // Execute step
-            let result = {
+            let started = Instant::now();
+            let (result, messages) = {
                 let step = &mut self.steps[current];
-                step.execute(&mut self.context).await
+                let result = step.execute(&mut self.context).await;
+                (result, step.take_messages())
             };
+            self.record_timing(step_name, started);
+            for (level, message) in messages {
+                let _ = self.log_sender.send(LogMessage::new(level, message, step_name));
+            }
             
             match result {
                 LaunchStepResult::Success => {
@@ -274,6 +311,21 @@ impl LaunchTask {
         Ok(())
     }
     
+    /// Add a finished step's duration to the timing breakdown
+    fn record_timing(&mut self, step_name: &str, started: Instant) {
+        let duration_ms = started.elapsed().as_millis() as u64;
+        self.timing.total_ms += duration_ms;
+        self.timing.steps.push(StepTiming {
+            step: step_name.to_string(),
+            duration_ms,
+        });
+    }
+    
+    /// Time spent in each step so far, including a step that failed
+    pub fn timing(&self) -> &LaunchTiming {
+        &self.timing
+    }
+    
     /// Request abort of the launch process
     #[allow(dead_code)] // Part of public API for abort handling
     pub async fn abort(&mut self) -> bool {
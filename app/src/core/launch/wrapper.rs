@@ -0,0 +1,292 @@
+//! OxideLaunch wrapper JAR discovery and installation.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use crate::core::accounts::AuthSession;
+use crate::core::config::Config;
+use crate::core::error::{OxideError, Result};
+
+/// The name of the wrapper JAR
+pub const OXIDE_LAUNCH_JAR: &str = "OxideLaunch.jar";
+
+/// Game argument value the wrapper replaces with the token from `--accessTokenFile`
+pub const ACCESS_TOKEN_PLACEHOLDER: &str = "${oxide_access_token}";
+
+/// JAR manifest attribute holding the wrapper's protocol version. Wrappers
+/// built before it was added have none and count as version 1.
+const PROTOCOL_ATTRIBUTE: &str = "Oxide-Launch-Protocol";
+
+/// First wrapper protocol version that understands `--accessTokenFile`
+pub const ACCESS_TOKEN_FILE_PROTOCOL: u32 = 2;
+
+/// How long an access token file may stay on disk. The wrapper deletes it as
+/// soon as it has read it; the launcher removes it after this in case the
+/// wrapper never got that far.
+pub const ACCESS_TOKEN_FILE_LIFETIME: Duration = Duration::from_secs(60);
+
+/// Prefix of access token file names in `<data>/temp`
+const ACCESS_TOKEN_FILE_PREFIX: &str = "token-";
+
+/// Where the wrapper is installed inside the data directory (`<data>/bin/OxideLaunch.jar`)
+pub fn wrapper_install_path(data_dir: &Path) -> PathBuf {
+    data_dir.join("bin").join(OXIDE_LAUNCH_JAR)
+}
+
+/// Locations a wrapper JAR shipped with the launcher may be found.
+/// Bundled builds place it next to the executable; development builds
+/// copy it to `target/<profile>/bin` from build.rs.
+fn bundled_wrapper_paths() -> Vec<PathBuf> {
+    let Some(exe_dir) = std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(Path::to_path_buf))
+    else {
+        return Vec::new();
+    };
+    
+    vec![
+        exe_dir.join(OXIDE_LAUNCH_JAR),
+        exe_dir.join("bin").join(OXIDE_LAUNCH_JAR),
+    ]
+}
+
+/// Protocol version of a wrapper JAR, from its manifest. 1 for wrappers
+/// that predate versioning; 0 if the JAR can't be read.
+pub fn wrapper_protocol(path: &Path) -> u32 {
+    let Ok(file) = std::fs::File::open(path) else {
+        return 0;
+    };
+    let Ok(mut archive) = zip::ZipArchive::new(file) else {
+        return 0;
+    };
+    let mut manifest = String::new();
+    if archive.by_name("META-INF/MANIFEST.MF")
+        .and_then(|mut entry| entry.read_to_string(&mut manifest).map_err(Into::into))
+        .is_err()
+    {
+        return 1;
+    }
+    
+    manifest.lines()
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case(PROTOCOL_ATTRIBUTE))
+        .and_then(|(_, value)| value.trim().parse().ok())
+        .unwrap_or(1)
+}
+
+/// The bundled wrapper with the newest protocol, if any
+fn newest_bundled_wrapper() -> Option<(PathBuf, u32)> {
+    bundled_wrapper_paths()
+        .into_iter()
+        .filter(|path| path.is_file())
+        .map(|path| {
+            let protocol = wrapper_protocol(&path);
+            (path, protocol)
+        })
+        .max_by_key(|(_, protocol)| *protocol)
+}
+
+/// Find the wrapper JAR, preferring the copy in the data directory unless
+/// the launcher bundles a newer one (the installed copy is left over from an
+/// older launcher)
+pub fn find_launch_wrapper(data_dir: &Path) -> Option<PathBuf> {
+    let installed = Some(wrapper_install_path(data_dir)).filter(|path| path.is_file());
+    let bundled = newest_bundled_wrapper();
+    
+    match (installed, bundled) {
+        (Some(installed), Some((bundled, protocol))) if protocol > wrapper_protocol(&installed) => Some(bundled),
+        (Some(installed), _) => Some(installed),
+        (None, bundled) => bundled.map(|(path, _)| path),
+    }
+}
+
+/// Whether the wrapper a launch would use can read the access token from a file
+pub fn wrapper_reads_token_file(data_dir: &Path) -> bool {
+    find_launch_wrapper(data_dir)
+        .is_some_and(|path| wrapper_protocol(&path) >= ACCESS_TOKEN_FILE_PROTOCOL)
+}
+
+/// Make sure the wrapper is installed in `<data>/bin`, copying it from the
+/// launcher bundle if it's missing or older than the bundled one. Returns the
+/// installed path.
+pub fn install_launch_wrapper(data_dir: &Path) -> Result<PathBuf> {
+    let install_path = wrapper_install_path(data_dir);
+    let bundled = newest_bundled_wrapper();
+    if install_path.is_file() {
+        let current = bundled.as_ref()
+            .is_none_or(|(_, protocol)| *protocol <= wrapper_protocol(&install_path));
+        if current {
+            return Ok(install_path);
+        }
+    }
+    
+    let (bundled, _) = bundled
+        .ok_or_else(|| OxideError::Launch(missing_wrapper_message(data_dir)))?;
+    
+    if let Some(parent) = install_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::copy(&bundled, &install_path)?;
+    
+    tracing::info!("Installed {} from {:?} to {:?}", OXIDE_LAUNCH_JAR, bundled, install_path);
+    Ok(install_path)
+}
+
+/// Setup error shown when the wrapper can't be found anywhere
+pub fn missing_wrapper_message(data_dir: &Path) -> String {
+    format!(
+        "{} is missing. It is required to launch legacy and modded versions of Minecraft. \
+        Reinstall the launcher or place the wrapper JAR at {}",
+        OXIDE_LAUNCH_JAR,
+        wrapper_install_path(data_dir).display()
+    )
+}
+
+/// Whether a launch with this session hands the access token to the wrapper
+/// out-of-band. Needs the config flag, a real token and a wrapper new enough
+/// to read it; otherwise the token goes on the command line as before.
+pub fn passes_token_out_of_band(config: &Config, session: &AuthSession) -> bool {
+    config.minecraft.access_token_out_of_band
+        && !session.access_token.is_empty()
+        && wrapper_reads_token_file(&config.data_dir)
+}
+
+/// Write the access token to a file only the current user can read, for the
+/// wrapper to read and delete. Lives in `<data>/temp` rather than the system
+/// temp directory, which other users can list. Token files older than
+/// [`ACCESS_TOKEN_FILE_LIFETIME`], left behind if the launcher quit before
+/// removing them, are deleted first.
+pub fn write_access_token_file(data_dir: &Path, token: &str) -> Result<PathBuf> {
+    let dir = data_dir.join("temp");
+    std::fs::create_dir_all(&dir)?;
+    remove_stale_token_files(&dir);
+    let path = dir.join(format!("{}{}", ACCESS_TOKEN_FILE_PREFIX, uuid::Uuid::new_v4()));
+    
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options.open(&path)?.write_all(token.as_bytes())?;
+    
+    Ok(path)
+}
+
+/// Delete an access token file once the game has exited or
+/// [`ACCESS_TOKEN_FILE_LIFETIME`] has passed, whichever comes first
+pub fn schedule_token_file_removal(path: PathBuf, game_exited: impl std::future::Future<Output = ()> + Send + 'static) {
+    tokio::spawn(async move {
+        let _ = tokio::time::timeout(ACCESS_TOKEN_FILE_LIFETIME, game_exited).await;
+        if path.exists() {
+            if let Err(e) = std::fs::remove_file(&path) {
+                tracing::warn!("Failed to remove access token file {:?}: {}", path, e);
+            }
+        }
+    });
+}
+
+/// Remove token files in `dir` older than [`ACCESS_TOKEN_FILE_LIFETIME`]
+fn remove_stale_token_files(dir: &Path) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let is_token = entry.file_name().to_string_lossy().starts_with(ACCESS_TOKEN_FILE_PREFIX);
+        let stale = entry.metadata()
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .is_some_and(|age| age > ACCESS_TOKEN_FILE_LIFETIME);
+        if is_token && stale {
+            let _ = std::fs::remove_file(entry.path());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn token_file_holds_only_the_token() {
+        let dir = TempDir::new().unwrap();
+        let path = write_access_token_file(dir.path(), "secret").unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "secret");
+        
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(std::fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+        }
+    }
+
+    #[test]
+    fn out_of_band_needs_a_token() {
+        let mut config = Config::default();
+        config.minecraft.access_token_out_of_band = true;
+        assert!(!passes_token_out_of_band(&config, &AuthSession::offline("Player")));
+    }
+    
+    fn write_wrapper(path: &Path, manifest: &str) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let mut zip = zip::ZipWriter::new(std::fs::File::create(path).unwrap());
+        zip.start_file("META-INF/MANIFEST.MF", zip::write::SimpleFileOptions::default()).unwrap();
+        zip.write_all(manifest.as_bytes()).unwrap();
+        zip.finish().unwrap();
+    }
+    
+    #[test]
+    fn stale_wrapper_keeps_the_token_on_the_command_line() {
+        let dir = TempDir::new().unwrap();
+        let mut config = Config { data_dir: dir.path().to_path_buf(), ..Default::default() };
+        config.minecraft.access_token_out_of_band = true;
+        let mut session = AuthSession::offline("Player");
+        session.access_token = "secret".to_string();
+        
+        // Installed by an older launcher, before the protocol attribute existed
+        let installed = wrapper_install_path(dir.path());
+        write_wrapper(&installed, "Main-Class: dev.oxide.launch.OxideLaunch\r\n");
+        assert_eq!(wrapper_protocol(&installed), 1);
+        assert!(!passes_token_out_of_band(&config, &session));
+        
+        write_wrapper(&installed, "Main-Class: dev.oxide.launch.OxideLaunch\r\nOxide-Launch-Protocol: 2\r\n");
+        assert_eq!(wrapper_protocol(&installed), ACCESS_TOKEN_FILE_PROTOCOL);
+        assert!(passes_token_out_of_band(&config, &session));
+    }
+    
+    #[test]
+    fn stale_token_files_are_removed() {
+        let dir = TempDir::new().unwrap();
+        let temp = dir.path().join("temp");
+        std::fs::create_dir_all(&temp).unwrap();
+        let stale = temp.join("token-old");
+        std::fs::write(&stale, "secret").unwrap();
+        let old = std::time::SystemTime::now() - ACCESS_TOKEN_FILE_LIFETIME * 2;
+        std::fs::File::options().write(true).open(&stale).unwrap().set_modified(old).unwrap();
+        
+        let fresh = write_access_token_file(dir.path(), "secret").unwrap();
+        assert!(!stale.exists());
+        assert!(fresh.exists());
+    }
+}
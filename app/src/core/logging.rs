@@ -19,8 +19,16 @@
 //! along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{LineWriter, Write};
+use std::path::Path;
+use std::sync::Mutex;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+/// Filename prefix of the game output logs written to an instance's logs folder
+const GAME_LOG_PREFIX: &str = "oxide-game-";
+
 /// Source of a log entry
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -121,6 +129,142 @@ impl LogEntry {
     }
 }
 
+/// In-memory log of a running game, keeping only the newest lines.
+///
+/// Every entry gets a sequence number so a snapshot of the buffer can be
+/// lined up with lines streamed after it.
+#[derive(Debug)]
+pub struct LogBuffer {
+    entries: VecDeque<LogEntry>,
+    capacity: usize,
+    next_seq: u64,
+}
+
+impl LogBuffer {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            capacity: capacity.max(1),
+            next_seq: 0,
+        }
+    }
+    
+    /// Append an entry, dropping the oldest one when full. Returns its sequence number.
+    pub fn push(&mut self, entry: LogEntry) -> u64 {
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+        
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        seq
+    }
+    
+    /// The buffered entries, oldest first
+    pub fn entries(&self) -> Vec<LogEntry> {
+        self.entries.iter().cloned().collect()
+    }
+    
+    /// Sequence number the next entry will get
+    pub fn next_seq(&self) -> u64 {
+        self.next_seq
+    }
+    
+    /// Number of entries dropped to stay within capacity
+    pub fn dropped(&self) -> u64 {
+        self.next_seq - self.entries.len() as u64
+    }
+}
+
+/// Durable copy of a game's stdout/stderr, written line by line as the output streams in
+/// so it survives even if the in-memory log buffer is lost
+pub struct GameLogFile {
+    state: Mutex<GameLogState>,
+    max_bytes: u64,
+}
+
+struct GameLogState {
+    writer: LineWriter<File>,
+    written: u64,
+    truncated: bool,
+}
+
+impl GameLogFile {
+    /// Create `oxide-game-<timestamp>.log` in `logs_dir`, keeping at most `max_files`
+    /// game logs. Output beyond `max_file_size_mb` is dropped with a note in the file.
+    pub fn create(logs_dir: &Path, max_files: u32, max_file_size_mb: u32) -> std::io::Result<Self> {
+        std::fs::create_dir_all(logs_dir)?;
+        
+        let timestamp = chrono::Local::now().format("%Y-%m-%d_%H-%M-%S");
+        let path = logs_dir.join(format!("{}{}.log", GAME_LOG_PREFIX, timestamp));
+        let file = File::create(&path)?;
+        
+        // The new file counts toward the retention limit
+        prune_game_logs(logs_dir, max_files.max(1) as usize);
+        
+        Ok(Self {
+            state: Mutex::new(GameLogState {
+                writer: LineWriter::new(file),
+                written: 0,
+                truncated: false,
+            }),
+            max_bytes: u64::from(max_file_size_mb.max(1)) * 1024 * 1024,
+        })
+    }
+    
+    /// Append a game log entry, marking lines that came from stderr
+    pub fn write_entry(&self, entry: &LogEntry) {
+        let line = match entry.source {
+            LogSource::StdErr => format!("[STDERR] {}\n", entry.content),
+            _ => format!("{}\n", entry.content),
+        };
+        
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        
+        if state.truncated {
+            return;
+        }
+        
+        if state.written + line.len() as u64 > self.max_bytes {
+            state.truncated = true;
+            let _ = state.writer.write_all(b"[Oxide Launcher] Log size limit reached, further output is not saved\n");
+            return;
+        }
+        
+        if state.writer.write_all(line.as_bytes()).is_ok() {
+            state.written += line.len() as u64;
+        }
+    }
+}
+
+/// Remove the oldest game output logs in `logs_dir`, keeping the newest `keep_count`
+pub fn prune_game_logs(logs_dir: &Path, keep_count: usize) {
+    let Ok(entries) = std::fs::read_dir(logs_dir) else {
+        return;
+    };
+    
+    // Timestamps in the filename sort chronologically
+    let mut game_logs: Vec<_> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|n| n.to_str())
+                .map(|n| n.starts_with(GAME_LOG_PREFIX) && n.ends_with(".log"))
+                .unwrap_or(false)
+        })
+        .collect();
+    game_logs.sort();
+    
+    let excess = game_logs.len().saturating_sub(keep_count);
+    for path in game_logs.into_iter().take(excess) {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
 /// Detect the log level from log line content
 /// 
 /// Matches common Minecraft/Java/Forge/NeoForge log patterns:
@@ -184,6 +328,19 @@ pub fn detect_log_level(line: &str) -> LogLevel {
 mod tests {
     use super::*;
     
+    #[test]
+    fn test_log_buffer_drops_oldest_entries() {
+        let mut buffer = LogBuffer::new(2);
+        assert_eq!(buffer.push(LogEntry::game("a")), 0);
+        assert_eq!(buffer.push(LogEntry::game("b")), 1);
+        assert_eq!(buffer.push(LogEntry::game("c")), 2);
+        
+        let contents: Vec<String> = buffer.entries().into_iter().map(|e| e.content).collect();
+        assert_eq!(contents, vec!["b", "c"]);
+        assert_eq!(buffer.next_seq(), 3);
+        assert_eq!(buffer.dropped(), 1);
+    }
+    
     #[test]
     fn test_detect_log_level_minecraft_format() {
         assert_eq!(detect_log_level("[12:34:56] [main/INFO]: Loading..."), LogLevel::Info);
@@ -204,4 +361,36 @@ mod tests {
         assert_eq!(detect_log_level("[FML/INFO]: Forge mod loading..."), LogLevel::Info);
         assert_eq!(detect_log_level("[FML/WARN]: Mod compatibility warning"), LogLevel::Warning);
     }
+    
+    #[test]
+    fn test_prune_game_logs_keeps_newest() {
+        let dir = tempfile::tempdir().unwrap();
+        for name in [
+            "oxide-game-2025-01-01_10-00-00.log",
+            "oxide-game-2025-01-02_10-00-00.log",
+            "oxide-game-2025-01-03_10-00-00.log",
+            "latest.log",
+        ] {
+            std::fs::write(dir.path().join(name), "").unwrap();
+        }
+        
+        prune_game_logs(dir.path(), 2);
+        
+        assert!(!dir.path().join("oxide-game-2025-01-01_10-00-00.log").exists());
+        assert!(dir.path().join("oxide-game-2025-01-02_10-00-00.log").exists());
+        assert!(dir.path().join("oxide-game-2025-01-03_10-00-00.log").exists());
+        assert!(dir.path().join("latest.log").exists());
+    }
+    
+    #[test]
+    fn test_game_log_file_marks_stderr() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = GameLogFile::create(dir.path(), 5, 1).unwrap();
+        log.write_entry(&LogEntry::game("[main/INFO]: Hello"));
+        log.write_entry(&LogEntry::stderr("Something broke"));
+        
+        let path = std::fs::read_dir(dir.path()).unwrap().next().unwrap().unwrap().path();
+        let content = std::fs::read_to_string(path).unwrap();
+        assert_eq!(content, "[main/INFO]: Hello\n[STDERR] Something broke\n");
+    }
 }
@@ -111,34 +111,6 @@ impl MetaClient {
         Ok(index.versions)
     }
     
-    /// Get Minecraft versions filtered by type.
-    pub async fn get_minecraft_versions_filtered(
-        &self,
-        show_releases: bool,
-        show_snapshots: bool,
-        show_betas: bool,
-        show_alphas: bool,
-        show_experimental: bool,
-    ) -> Result<Vec<VersionEntry>> {
-        let versions = self.get_minecraft_versions().await?;
-        
-        Ok(versions
-            .into_iter()
-            .filter(|v| {
-                let version_type = v.version_type.as_deref().unwrap_or("release");
-                match version_type {
-                    "release" => show_releases,
-                    "snapshot" => show_snapshots,
-                    "experiment" => show_experimental,
-                    "old_beta" => show_betas,
-                    "old_alpha" => show_alphas,
-                    "old_snapshot" => show_alphas, // Old snapshots are early pre-release, group with alphas
-                    _ => show_releases, // Unknown types default to release behavior
-                }
-            })
-            .collect())
-    }
-    
     // =========================================================================
     // Modloader Versions
     // =========================================================================
@@ -230,6 +202,32 @@ impl MetaClient {
     }
 }
 
+/// Filter Minecraft versions by type
+pub fn filter_minecraft_versions(
+    versions: Vec<VersionEntry>,
+    show_releases: bool,
+    show_snapshots: bool,
+    show_betas: bool,
+    show_alphas: bool,
+    show_experimental: bool,
+) -> Vec<VersionEntry> {
+    versions
+        .into_iter()
+        .filter(|v| {
+            let version_type = v.version_type.as_deref().unwrap_or("release");
+            match version_type {
+                "release" => show_releases,
+                "snapshot" => show_snapshots,
+                "experiment" => show_experimental,
+                "old_beta" => show_betas,
+                "old_alpha" => show_alphas,
+                "old_snapshot" => show_alphas, // Old snapshots are early pre-release, group with alphas
+                _ => show_releases, // Unknown types default to release behavior
+            }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -21,4 +21,5 @@
 mod types;
 mod client;
 
-pub use client::MetaClient;
+pub use client::{filter_minecraft_versions, MetaClient};
+pub use types::{uids, VersionEntry};
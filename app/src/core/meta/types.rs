@@ -198,6 +198,21 @@ impl VersionEntry {
             Some("old_alpha") | Some("old_beta") | Some("old_snapshot")
         )
     }
+    
+    /// Check if the version string carries a pre-release tag: a `-` separated
+    /// part that isn't a version number (e.g. "0.20.0-beta.9", "21.0.0-beta").
+    /// This is the fallback for loaders whose metadata has no stable flag;
+    /// recommended builds are always treated as stable.
+    pub fn is_prerelease(&self) -> bool {
+        if self.recommended {
+            return false;
+        }
+        
+        self.version
+            .split('-')
+            .skip(1)
+            .any(|part| part.starts_with(|c: char| c.is_ascii_alphabetic()))
+    }
 }
 
 impl PackageIndex {
@@ -296,4 +311,23 @@ mod tests {
         assert!(test_entry(vec![], Some("old_beta")).is_old());
         assert!(test_entry(vec![], Some("old_snapshot")).is_old());
     }
+    
+    #[test]
+    fn test_prerelease_detection() {
+        let entry = |version: &str, recommended: bool| VersionEntry {
+            version: version.to_string(),
+            recommended,
+            ..test_entry(vec![], None)
+        };
+        
+        assert!(entry("0.20.0-beta.9", false).is_prerelease());
+        assert!(entry("21.0.0-beta", false).is_prerelease());
+        assert!(entry("0.14.0-rc.1", false).is_prerelease());
+        assert!(entry("1.20.1-47.1.0-pre1", false).is_prerelease());
+        assert!(!entry("0.16.9", false).is_prerelease());
+        assert!(!entry("1.20.1-47.1.0", false).is_prerelease());
+        assert!(!entry("47.3.0", true).is_prerelease());
+        // A recommended build is stable whatever its tag says
+        assert!(!entry("0.26.0-beta.1", true).is_prerelease());
+    }
 }
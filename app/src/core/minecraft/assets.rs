@@ -22,7 +22,8 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::fs;
+use std::path::{Path, PathBuf};
 use crate::core::error::Result;
 
 /// Asset index containing all game assets
@@ -87,3 +88,149 @@ pub fn get_missing_assets<'a>(
         .map(|(name, asset)| (name.as_str(), asset))
         .collect()
 }
+
+/// Directory legacy versions read their assets from, populated from the shared objects store.
+///
+/// Pre-1.6 indexes (`map_to_resources`) expect the files under the instance's
+/// `resources` folder; 1.6-1.7.2 indexes (`virtual`) expect them under
+/// `assets/virtual/<index>`, which is shared across instances. Returns None for
+/// modern indexes, which read the objects store directly, or if the index
+/// hasn't been downloaded.
+pub fn prepare_legacy_assets(
+    assets_dir: &Path,
+    index_name: &str,
+    game_dir: &Path,
+) -> Result<Option<PathBuf>> {
+    let index_path = assets_dir.join("indexes").join(format!("{}.json", index_name));
+    if !index_path.exists() {
+        return Ok(None);
+    }
+    
+    let index: AssetIndexData = serde_json::from_str(&fs::read_to_string(&index_path)?)?;
+    let target = if index.map_to_resources {
+        game_dir.join("resources")
+    } else if index.r#virtual {
+        assets_dir.join("virtual").join(index_name)
+    } else {
+        return Ok(None);
+    };
+    
+    let placed = reconstruct_assets(&index, &assets_dir.join("objects"), &target)?;
+    if placed > 0 {
+        tracing::info!("Placed {} legacy assets in {:?}", placed, target);
+    }
+    
+    Ok(Some(target))
+}
+
+/// Lay out the index's objects under their names in `target`.
+///
+/// Files are hard-linked from the objects store where the filesystem allows
+/// it and copied otherwise. Files already present with the right size are
+/// left alone. Returns the number of files placed.
+pub fn reconstruct_assets(index: &AssetIndexData, objects_dir: &Path, target: &Path) -> Result<usize> {
+    let mut placed = 0;
+    
+    for (name, asset) in &index.objects {
+        let source = objects_dir.join(asset.get_path());
+        let dest = target.join(name);
+        
+        if fs::metadata(&dest).map(|m| m.len() == asset.size).unwrap_or(false) {
+            continue;
+        }
+        if !source.exists() {
+            tracing::warn!("Asset object missing for {}: {:?}", name, source);
+            continue;
+        }
+        
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        link_or_copy(&source, &dest)?;
+        placed += 1;
+    }
+    
+    Ok(placed)
+}
+
+/// Hard-link `source` to `dest`, falling back to a copy (e.g. across filesystems)
+pub(super) fn link_or_copy(source: &Path, dest: &Path) -> std::io::Result<()> {
+    if dest.exists() {
+        fs::remove_file(dest)?;
+    }
+    
+    if fs::hard_link(source, dest).is_err() {
+        fs::copy(source, dest)?;
+    }
+    
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const HASH: &str = "bdf48ef6b5d0d23bbb02e17d04865216179f510a";
+    
+    fn write_index(assets_dir: &Path, name: &str, flags: &str) {
+        let index = format!(
+            r#"{{ {} "objects": {{ "sound/step/grass1.ogg": {{ "hash": "{}", "size": 5 }} }} }}"#,
+            flags, HASH
+        );
+        fs::create_dir_all(assets_dir.join("indexes")).unwrap();
+        fs::write(assets_dir.join("indexes").join(format!("{}.json", name)), index).unwrap();
+        
+        let object = assets_dir.join("objects").join(&HASH[..2]).join(HASH);
+        fs::create_dir_all(object.parent().unwrap()).unwrap();
+        fs::write(object, "hello").unwrap();
+    }
+    
+    #[test]
+    fn virtual_assets_are_linked_into_shared_folder() {
+        let dir = tempfile::tempdir().unwrap();
+        let assets_dir = dir.path().join("assets");
+        let game_dir = dir.path().join("game");
+        write_index(&assets_dir, "legacy", r#""virtual": true,"#);
+        
+        let target = prepare_legacy_assets(&assets_dir, "legacy", &game_dir).unwrap().unwrap();
+        
+        assert_eq!(target, assets_dir.join("virtual").join("legacy"));
+        let placed = target.join("sound/step/grass1.ogg");
+        assert_eq!(fs::read_to_string(&placed).unwrap(), "hello");
+        
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+            assert_eq!(fs::metadata(&placed).unwrap().nlink(), 2);
+        }
+        
+        // Already in place, nothing to do the second time
+        let index: AssetIndexData = serde_json::from_str(
+            &fs::read_to_string(assets_dir.join("indexes/legacy.json")).unwrap()
+        ).unwrap();
+        assert_eq!(reconstruct_assets(&index, &assets_dir.join("objects"), &target).unwrap(), 0);
+    }
+    
+    #[test]
+    fn pre_1_6_assets_go_to_instance_resources() {
+        let dir = tempfile::tempdir().unwrap();
+        let assets_dir = dir.path().join("assets");
+        let game_dir = dir.path().join("game");
+        write_index(&assets_dir, "pre-1.6", r#""map_to_resources": true,"#);
+        
+        let target = prepare_legacy_assets(&assets_dir, "pre-1.6", &game_dir).unwrap().unwrap();
+        
+        assert_eq!(target, game_dir.join("resources"));
+        assert!(target.join("sound/step/grass1.ogg").exists());
+    }
+    
+    #[test]
+    fn modern_assets_use_objects_store() {
+        let dir = tempfile::tempdir().unwrap();
+        let assets_dir = dir.path().join("assets");
+        write_index(&assets_dir, "17", "");
+        
+        assert_eq!(prepare_legacy_assets(&assets_dir, "17", dir.path()).unwrap(), None);
+        assert!(!assets_dir.join("virtual").exists());
+    }
+}
@@ -0,0 +1,160 @@
+//! Mojang's blocked-servers list.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+
+use crate::core::config::NetworkConfig;
+use crate::core::download::http_client;
+use crate::core::error::Result;
+
+/// Mojang's list of SHA-1 hashes of blocked server addresses, one per line
+pub const BLOCKED_SERVERS_URL: &str = "https://sessionserver.mojang.com/blockedservers";
+
+/// File the list is cached in, under the cache folder
+pub const BLOCKED_SERVERS_CACHE: &str = "blocked_servers.json";
+
+/// How long a cached list is used before it's fetched again
+const CACHE_MAX_AGE_HOURS: i64 = 24;
+
+/// The blocked-servers hash list, as fetched at `fetched_at`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockedServers {
+    pub hashes: HashSet<String>,
+    pub fetched_at: DateTime<Utc>,
+}
+
+impl BlockedServers {
+    /// Fetch the current list from Mojang, through the configured proxy and timeout
+    pub async fn fetch(network: &NetworkConfig) -> Result<Self> {
+        let body = http_client(network)?
+            .get(BLOCKED_SERVERS_URL)
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+        
+        Ok(Self::parse(&body))
+    }
+    
+    fn parse(body: &str) -> Self {
+        Self {
+            hashes: body
+                .lines()
+                .map(|line| line.trim().to_ascii_lowercase())
+                .filter(|line| !line.is_empty())
+                .collect(),
+            fetched_at: Utc::now(),
+        }
+    }
+    
+    /// Read a cached list, if there is one
+    pub fn load(path: &Path) -> Option<Self> {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+    }
+    
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string(self)?)?;
+        Ok(())
+    }
+    
+    /// Whether the list is old enough to fetch again
+    pub fn is_stale(&self) -> bool {
+        Utc::now() - self.fetched_at > Duration::hours(CACHE_MAX_AGE_HOURS)
+    }
+    
+    /// The pattern that blocks `address`, if any (e.g. `*.example.com`)
+    pub fn blocked_by(&self, address: &str) -> Option<String> {
+        blocked_server_patterns(address)
+            .into_iter()
+            .find(|pattern| self.hashes.contains(&sha1_hex(pattern)))
+    }
+}
+
+/// The patterns Mojang checks an address against: the host itself, then a
+/// wildcard for each parent domain (`*.example.com`, `*.com`), or for IPv4
+/// addresses each leading group of octets (`1.2.3.*`, `1.2.*`, `1.*`).
+/// The port is ignored and hosts are compared in lower case.
+pub fn blocked_server_patterns(address: &str) -> Vec<String> {
+    let host = address.trim().to_ascii_lowercase();
+    let host = match host.rsplit_once(':') {
+        Some((host, port)) if !host.contains(':') && port.chars().all(|c| c.is_ascii_digit()) => host.to_string(),
+        _ => host,
+    };
+    let host = host.trim_end_matches('.');
+    if host.is_empty() {
+        return Vec::new();
+    }
+    
+    let parts: Vec<&str> = host.split('.').collect();
+    let is_ipv4 = parts.len() == 4 && parts.iter().all(|part| part.parse::<u8>().is_ok());
+    
+    let mut patterns = vec![host.to_string()];
+    if is_ipv4 {
+        for end in (1..parts.len()).rev() {
+            patterns.push(format!("{}.*", parts[..end].join(".")));
+        }
+    } else {
+        for start in 1..parts.len() {
+            patterns.push(format!("*.{}", parts[start..].join(".")));
+        }
+    }
+    patterns
+}
+
+fn sha1_hex(value: &str) -> String {
+    hex::encode(Sha1::digest(value.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn patterns_for_hosts_and_ips() {
+        assert_eq!(
+            blocked_server_patterns("Play.Example.com:25565"),
+            vec!["play.example.com", "*.example.com", "*.com"]
+        );
+        assert_eq!(
+            blocked_server_patterns("10.0.0.1"),
+            vec!["10.0.0.1", "10.0.0.*", "10.0.*", "10.*"]
+        );
+    }
+    
+    #[test]
+    fn wildcard_entries_block_subdomains() {
+        let list = BlockedServers::parse(&format!("{}\n", sha1_hex("*.example.com").to_uppercase()));
+        assert_eq!(list.blocked_by("mc.example.com"), Some("*.example.com".to_string()));
+        assert_eq!(list.blocked_by("example.com"), None);
+        assert_eq!(list.blocked_by("example.org"), None);
+    }
+}
@@ -0,0 +1,150 @@
+//! Age-based cleanup of screenshots and world backups.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime};
+use serde::Serialize;
+use std::path::Path;
+use tracing::info;
+use crate::core::error::Result;
+use crate::core::files::delete_file;
+
+/// Name formats Minecraft stamps captures with, in local time:
+/// screenshots are `2024-01-15_18.30.45.png` (or `..._2.png` for the same
+/// second), world backups `2024-01-15_18-30-45_World Name.zip`
+const NAME_TIME_FORMATS: &[&str] = &["%Y-%m-%d_%H.%M.%S", "%Y-%m-%d_%H-%M-%S"];
+
+/// Length of a name timestamp
+const NAME_TIME_LEN: usize = 19;
+
+/// What a cleanup removed
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PruneResult {
+    pub deleted: usize,
+    pub bytes_freed: u64,
+}
+
+/// Parse a cleanup cutoff: an RFC 3339 timestamp, or a `YYYY-MM-DD` date
+/// meaning the start of that day in local time
+pub fn parse_cutoff(date: &str) -> Option<NaiveDateTime> {
+    let date = date.trim();
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(date) {
+        return Some(timestamp.with_timezone(&Local).naive_local());
+    }
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .ok()
+        .and_then(|day| day.and_hms_opt(0, 0, 0))
+}
+
+/// When a screenshot or backup was taken: the timestamp in its name when it
+/// has one, since copying a file resets its modification time; otherwise the
+/// modification time
+pub fn capture_time(path: &Path) -> Option<NaiveDateTime> {
+    let from_name = path.file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.get(..NAME_TIME_LEN))
+        .and_then(|stamp| {
+            NAME_TIME_FORMATS.iter()
+                .find_map(|format| NaiveDateTime::parse_from_str(stamp, format).ok())
+        });
+
+    from_name.or_else(|| {
+        let modified = std::fs::metadata(path).and_then(|m| m.modified()).ok()?;
+        Some(DateTime::<Local>::from(modified).naive_local())
+    })
+}
+
+/// Delete the files in `dir` with the given extension that were taken before
+/// `cutoff`. A missing folder counts as nothing to delete.
+pub fn prune_before(
+    dir: &Path,
+    extension: &str,
+    cutoff: NaiveDateTime,
+    use_recycle_bin: bool,
+) -> Result<PruneResult> {
+    let mut result = PruneResult::default();
+    if !dir.is_dir() {
+        return Ok(result);
+    }
+
+    for entry in std::fs::read_dir(dir)?.flatten() {
+        let path = entry.path();
+        let matches_extension = path.extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.eq_ignore_ascii_case(extension))
+            .unwrap_or(false);
+        if !path.is_file() || !matches_extension {
+            continue;
+        }
+        if !capture_time(&path).map(|taken| taken < cutoff).unwrap_or(false) {
+            continue;
+        }
+
+        let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+        delete_file(&path, use_recycle_bin)?;
+        result.deleted += 1;
+        result.bytes_freed += size;
+    }
+
+    info!("Removed {} files ({} bytes) from {:?}", result.deleted, result.bytes_freed, dir);
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(stamp: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(stamp, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn capture_time_prefers_the_name() {
+        assert_eq!(capture_time(Path::new("2023-05-01_12.30.45.png")), Some(at("2023-05-01 12:30:45")));
+        assert_eq!(capture_time(Path::new("2023-05-01_12.30.45_2.png")), Some(at("2023-05-01 12:30:45")));
+        assert_eq!(capture_time(Path::new("2023-05-01_12-30-45_My World.zip")), Some(at("2023-05-01 12:30:45")));
+    }
+
+    #[test]
+    fn cutoff_accepts_dates_and_timestamps() {
+        assert_eq!(parse_cutoff("2024-02-03"), Some(at("2024-02-03 00:00:00")));
+        assert!(parse_cutoff("2024-02-03T10:00:00Z").is_some());
+        assert!(parse_cutoff("last week").is_none());
+    }
+
+    #[test]
+    fn prunes_only_older_captures() {
+        let dir = tempfile::tempdir().unwrap();
+        // Freshly written, so its modification time is now, but the name dates it to 2020
+        std::fs::write(dir.path().join("2020-01-01_10.00.00.png"), [0u8; 10]).unwrap();
+        std::fs::write(dir.path().join("2099-01-01_10.00.00.png"), [0u8; 5]).unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "keep").unwrap();
+        // No name timestamp; its modification time (now) keeps it
+        std::fs::write(dir.path().join("custom.png"), [0u8; 3]).unwrap();
+
+        let result = prune_before(dir.path(), "png", at("2024-01-01 00:00:00"), false).unwrap();
+
+        assert_eq!(result.deleted, 1);
+        assert_eq!(result.bytes_freed, 10);
+        assert!(!dir.path().join("2020-01-01_10.00.00.png").exists());
+        assert!(dir.path().join("2099-01-01_10.00.00.png").exists());
+        assert!(dir.path().join("custom.png").exists());
+        assert!(dir.path().join("notes.txt").exists());
+    }
+}
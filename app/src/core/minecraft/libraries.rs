@@ -18,34 +18,10 @@
 //! You should have received a copy of the GNU General Public License
 //! along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use crate::core::minecraft::version::{Library, VersionData};
 use tracing::debug;
 
-/// Normalize a path to use OS-native separators
-fn normalize_path(path: &PathBuf) -> String {
-    if let Ok(canonical) = path.canonicalize() {
-        let path_str = canonical.to_string_lossy().to_string();
-        // On Windows, canonicalize adds \\?\ prefix which Java doesn't understand
-        #[cfg(windows)]
-        {
-            if let Some(stripped) = path_str.strip_prefix(r"\\?\") {
-                return stripped.to_string();
-            }
-        }
-        path_str
-    } else {
-        #[cfg(windows)]
-        {
-            path.to_string_lossy().replace('/', "\\")
-        }
-        #[cfg(not(windows))]
-        {
-            path.to_string_lossy().to_string()
-        }
-    }
-}
-
 /// Get all libraries needed for a version
 pub fn get_required_libraries(version: &VersionData) -> Vec<&Library> {
     version.libraries
@@ -54,39 +30,12 @@ pub fn get_required_libraries(version: &VersionData) -> Vec<&Library> {
         .collect()
 }
 
-/// Get the classpath for launching
-pub fn build_classpath(
-    version: &VersionData,
-    libraries_dir: &PathBuf,
-    client_jar: &PathBuf,
-) -> String {
-    let separator = if cfg!(target_os = "windows") { ";" } else { ":" };
-    
-    let mut paths: Vec<String> = get_required_libraries(version)
-        .iter()
-        .filter_map(|lib| {
-            let path = if let Some(downloads) = &lib.downloads {
-                if let Some(artifact) = &downloads.artifact {
-                    libraries_dir.join(&artifact.path)
-                } else {
-                    libraries_dir.join(lib.artifact_path())
-                }
-            } else {
-                libraries_dir.join(lib.artifact_path())
-            };
-            
-            if path.exists() {
-                Some(normalize_path(&path))
-            } else {
-                None
-            }
-        })
-        .collect();
-    
-    // Add client jar at the end
-    paths.push(normalize_path(client_jar));
-    
-    paths.join(separator)
+/// Path of a library's jar in the libraries folder
+pub fn library_path(lib: &Library, libraries_dir: &Path) -> PathBuf {
+    match lib.downloads.as_ref().and_then(|downloads| downloads.artifact.as_ref()) {
+        Some(artifact) => libraries_dir.join(&artifact.path),
+        None => libraries_dir.join(lib.artifact_path()),
+    }
 }
 
 /// Get libraries that need to be downloaded
@@ -0,0 +1,143 @@
+//! Which Minecraft versions are downloaded to the launcher's version store.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::core::error::Result;
+use super::version::VersionData;
+
+/// How much of a Minecraft version is stored locally
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LocalVersion {
+    pub id: String,
+    /// The version's JSON (libraries, arguments, asset index) is saved
+    pub has_version_json: bool,
+    /// The client jar is downloaded
+    pub has_client_jar: bool,
+    /// The asset index named by the version JSON is downloaded
+    pub has_asset_index: bool,
+    /// Everything needed to set up an instance offline is present
+    pub complete: bool,
+}
+
+/// Path of a version's saved JSON, next to its client jar
+pub fn version_json_path(meta_dir: &Path, version: &str) -> PathBuf {
+    meta_dir.join("versions").join(version).join(format!("{}.json", version))
+}
+
+/// Path of a version's client jar
+pub fn client_jar_path(meta_dir: &Path, version: &str) -> PathBuf {
+    meta_dir.join("versions").join(version).join(format!("{}.jar", version))
+}
+
+/// Save a version's JSON so the version's files can be checked without the network
+pub fn save_version_json(meta_dir: &Path, data: &VersionData) -> Result<()> {
+    let path = version_json_path(meta_dir, &data.id);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, serde_json::to_string(data)?)?;
+    Ok(())
+}
+
+/// Read a version's saved JSON, if it's stored and parses
+pub fn load_version_json(meta_dir: &Path, version: &str) -> Option<VersionData> {
+    fs::read_to_string(version_json_path(meta_dir, version))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+}
+
+/// Check what's stored locally for one version
+pub fn local_version(meta_dir: &Path, assets_dir: &Path, version: &str) -> LocalVersion {
+    let version_data = load_version_json(meta_dir, version);
+    
+    let has_client_jar = client_jar_path(meta_dir, version).is_file();
+    let has_asset_index = version_data.as_ref()
+        .map(|data| assets_dir.join("indexes").join(format!("{}.json", data.assets)).is_file())
+        .unwrap_or(false);
+    let has_version_json = version_data.is_some();
+    
+    LocalVersion {
+        id: version.to_string(),
+        has_version_json,
+        has_client_jar,
+        has_asset_index,
+        complete: has_version_json && has_client_jar && has_asset_index,
+    }
+}
+
+/// Every version with files in the version store, sorted by ID
+pub fn local_versions(meta_dir: &Path, assets_dir: &Path) -> Vec<LocalVersion> {
+    let mut versions: Vec<LocalVersion> = fs::read_dir(meta_dir.join("versions"))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+        .map(|id| local_version(meta_dir, assets_dir, &id))
+        .filter(|version| version.has_version_json || version.has_client_jar)
+        .collect();
+    versions.sort_by(|a, b| a.id.cmp(&b.id));
+    versions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const VERSION_JSON: &str = r#"{
+        "id": "1.20.1",
+        "type": "release",
+        "mainClass": "net.minecraft.client.main.Main",
+        "releaseTime": "2023-06-12T13:25:51+00:00",
+        "time": "2023-06-12T13:25:51+00:00",
+        "assets": "5",
+        "assetIndex": { "id": "5", "sha1": "0", "size": 0, "url": "https://example.com/5.json" },
+        "downloads": {},
+        "libraries": []
+    }"#;
+    
+    #[test]
+    fn complete_only_with_json_jar_and_asset_index() {
+        let root = tempfile::tempdir().unwrap();
+        let meta_dir = root.path().join("meta");
+        let assets_dir = root.path().join("assets");
+        
+        let data: VersionData = serde_json::from_str(VERSION_JSON).unwrap();
+        save_version_json(&meta_dir, &data).unwrap();
+        fs::write(client_jar_path(&meta_dir, "1.20.1"), "jar").unwrap();
+        fs::create_dir_all(meta_dir.join("versions").join("1.19.4")).unwrap();
+        fs::write(client_jar_path(&meta_dir, "1.19.4"), "jar").unwrap();
+        fs::create_dir_all(meta_dir.join("versions").join("empty")).unwrap();
+        
+        let versions = local_versions(&meta_dir, &assets_dir);
+        assert_eq!(versions.len(), 2);
+        assert!(!versions.iter().any(|v| v.complete));
+        
+        fs::create_dir_all(assets_dir.join("indexes")).unwrap();
+        fs::write(assets_dir.join("indexes").join("5.json"), "{}").unwrap();
+        
+        let versions = local_versions(&meta_dir, &assets_dir);
+        assert_eq!(versions[0].id, "1.19.4");
+        assert!(!versions[0].complete);
+        assert!(versions[1].complete);
+    }
+}
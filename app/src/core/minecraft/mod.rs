@@ -22,3 +22,9 @@ pub mod version;
 pub mod assets;
 pub mod libraries;
 pub mod world;
+pub mod official;
+pub mod options;
+pub mod shaders;
+pub mod captures;
+pub mod local_versions;
+pub mod blocked_servers;
@@ -0,0 +1,217 @@
+//! Reading an official Minecraft Launcher installation.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::Serialize;
+use walkdir::WalkDir;
+
+use super::assets::link_or_copy;
+use crate::core::download::compute_sha1;
+use crate::core::error::Result;
+
+/// What an official `.minecraft` folder contains
+#[derive(Debug, Clone, Serialize)]
+pub struct OfficialInstall {
+    pub path: PathBuf,
+    /// Version IDs with a version JSON
+    pub versions: Vec<String>,
+    /// Number of library jars
+    pub library_count: usize,
+    /// Asset index names
+    pub asset_indexes: Vec<String>,
+    /// Number of files in the shared objects store
+    pub asset_object_count: usize,
+    /// Total size of the objects store in bytes
+    pub asset_objects_size: u64,
+}
+
+/// Result of importing the official objects store
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AssetImport {
+    /// Asset indexes copied
+    pub indexes: usize,
+    /// Objects linked or copied
+    pub objects: usize,
+    /// Objects skipped because they were already present
+    pub existing: usize,
+    /// Objects skipped because their contents didn't match their hash
+    pub corrupt: usize,
+}
+
+/// The official launcher's default game directory, if it exists
+pub fn official_minecraft_dir() -> Option<PathBuf> {
+    #[cfg(target_os = "windows")]
+    let dir = dirs::data_dir()?.join(".minecraft");
+    
+    #[cfg(target_os = "macos")]
+    let dir = dirs::data_dir()?.join("minecraft");
+    
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    let dir = dirs::home_dir()?.join(".minecraft");
+    
+    dir.is_dir().then_some(dir)
+}
+
+/// Summarize an official installation without modifying it
+pub fn scan_official_install(dir: &Path) -> OfficialInstall {
+    let mut versions: Vec<String> = fs::read_dir(dir.join("versions"))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| {
+            let id = entry.file_name().to_str()?.to_string();
+            entry.path().join(format!("{}.json", id)).is_file().then_some(id)
+        })
+        .collect();
+    versions.sort();
+    
+    let library_count = WalkDir::new(dir.join("libraries"))
+        .into_iter()
+        .flatten()
+        .filter(|entry| entry.file_type().is_file() && entry.path().extension().is_some_and(|e| e == "jar"))
+        .count();
+    
+    let mut asset_indexes: Vec<String> = fs::read_dir(dir.join("assets").join("indexes"))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| entry.file_name().to_str()?.strip_suffix(".json").map(str::to_string))
+        .collect();
+    asset_indexes.sort();
+    
+    let (asset_object_count, asset_objects_size) = WalkDir::new(dir.join("assets").join("objects"))
+        .into_iter()
+        .flatten()
+        .filter(|entry| entry.file_type().is_file())
+        .fold((0, 0), |(count, size), entry| {
+            (count + 1, size + entry.metadata().map(|m| m.len()).unwrap_or(0))
+        });
+    
+    OfficialInstall {
+        path: dir.to_path_buf(),
+        versions,
+        library_count,
+        asset_indexes,
+        asset_object_count,
+        asset_objects_size,
+    }
+}
+
+/// Bring the official assets into `assets_dir` so they don't need downloading.
+///
+/// Indexes are copied and objects hard-linked (or copied across filesystems).
+/// Nothing in `official_dir` is written to. Objects whose contents don't match
+/// their hash are skipped, so a later re-download never writes through a hard
+/// link into the official store.
+pub fn import_official_assets(official_dir: &Path, assets_dir: &Path) -> Result<AssetImport> {
+    let source = official_dir.join("assets");
+    let mut result = AssetImport::default();
+    
+    let indexes_dir = assets_dir.join("indexes");
+    fs::create_dir_all(&indexes_dir)?;
+    for entry in fs::read_dir(source.join("indexes")).into_iter().flatten().flatten() {
+        let dest = indexes_dir.join(entry.file_name());
+        if entry.path().is_file() && !dest.exists() {
+            fs::copy(entry.path(), &dest)?;
+            result.indexes += 1;
+        }
+    }
+    
+    let objects_source = source.join("objects");
+    let objects_dir = assets_dir.join("objects");
+    for entry in WalkDir::new(&objects_source).min_depth(2).max_depth(2).into_iter().flatten() {
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        
+        let Ok(relative) = entry.path().strip_prefix(&objects_source) else {
+            continue;
+        };
+        let dest = objects_dir.join(relative);
+        if dest.exists() {
+            result.existing += 1;
+            continue;
+        }
+        
+        let hash = entry.file_name().to_string_lossy().to_lowercase();
+        if compute_sha1(&entry.path().to_path_buf())? != hash {
+            result.corrupt += 1;
+            continue;
+        }
+        
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        link_or_copy(entry.path(), &dest)?;
+        result.objects += 1;
+    }
+    
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const HASH: &str = "aaf4c61ddcc5e8a2dabede0f3b482cd9aea9434d";
+    
+    fn official_fixture(root: &Path) {
+        let assets = root.join("assets");
+        fs::create_dir_all(assets.join("indexes")).unwrap();
+        fs::write(assets.join("indexes").join("17.json"), r#"{"objects":{}}"#).unwrap();
+        
+        let objects = assets.join("objects");
+        fs::create_dir_all(objects.join("aa")).unwrap();
+        fs::write(objects.join("aa").join(HASH), "hello").unwrap();
+        fs::create_dir_all(objects.join("00")).unwrap();
+        fs::write(objects.join("00").join("0000000000000000000000000000000000000000"), "corrupt").unwrap();
+        
+        fs::create_dir_all(root.join("versions").join("1.21")).unwrap();
+        fs::write(root.join("versions").join("1.21").join("1.21.json"), "{}").unwrap();
+        fs::create_dir_all(root.join("versions").join("broken")).unwrap();
+    }
+    
+    #[test]
+    fn test_scan_reports_versions_and_assets() {
+        let dir = tempfile::tempdir().unwrap();
+        official_fixture(dir.path());
+        
+        let install = scan_official_install(dir.path());
+        assert_eq!(install.versions, vec!["1.21"]);
+        assert_eq!(install.asset_indexes, vec!["17"]);
+        assert_eq!(install.asset_object_count, 2);
+        assert_eq!(install.library_count, 0);
+    }
+    
+    #[test]
+    fn test_import_skips_corrupt_and_existing_objects() {
+        let official = tempfile::tempdir().unwrap();
+        let assets = tempfile::tempdir().unwrap();
+        official_fixture(official.path());
+        
+        let first = import_official_assets(official.path(), assets.path()).unwrap();
+        assert_eq!((first.indexes, first.objects, first.corrupt), (1, 1, 1));
+        assert!(assets.path().join("objects").join("aa").join(HASH).is_file());
+        
+        let second = import_official_assets(official.path(), assets.path()).unwrap();
+        assert_eq!((second.indexes, second.objects, second.existing), (0, 0, 1));
+    }
+}
@@ -0,0 +1,228 @@
+//! Reading and updating the game's options.txt.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::Local;
+use serde::Serialize;
+
+use crate::core::error::Result;
+
+/// Name of the game's settings file in the game directory
+pub const OPTIONS_FILE: &str = "options.txt";
+
+/// Settings files OptiFine and shader mods keep next to options.txt
+pub const EXTRA_OPTIONS_FILES: &[&str] = &["optionsof.txt", "optionsshaders.txt"];
+
+/// Language codes the game ships with. Mods and resource packs can add more.
+const KNOWN_LANGUAGES: &[&str] = &[
+    "af_za", "ar_sa", "ast_es", "az_az", "ba_ru", "bar", "be_by", "bg_bg", "br_fr", "brb",
+    "bs_ba", "ca_es", "cs_cz", "cy_gb", "da_dk", "de_at", "de_ch", "de_de", "el_gr", "en_au",
+    "en_ca", "en_gb", "en_nz", "en_pt", "en_ud", "en_us", "enp", "enws", "eo_uy", "es_ar",
+    "es_cl", "es_ec", "es_es", "es_mx", "es_uy", "es_ve", "esan", "et_ee", "eu_es", "fa_ir",
+    "fi_fi", "fil_ph", "fo_fo", "fr_ca", "fr_fr", "fra_de", "fur_it", "fy_nl", "ga_ie", "gd_gb",
+    "gl_es", "haw_us", "he_il", "hi_in", "hr_hr", "hu_hu", "hy_am", "id_id", "ig_ng", "io_en",
+    "is_is", "isv", "it_it", "ja_jp", "jbo_en", "ka_ge", "kk_kz", "kn_in", "ko_kr", "ksh",
+    "kw_gb", "la_la", "lb_lu", "li_li", "lmo", "lo_la", "lol_us", "lt_lt", "lv_lv", "lzh",
+    "mk_mk", "mn_mn", "ms_my", "mt_mt", "nah", "nb_no", "nds_de", "nl_be", "nl_nl", "nn_no",
+    "no_no", "oc_fr", "ovd", "pl_pl", "pt_br", "pt_pt", "qya_aa", "ro_ro", "rpr", "ru_ru",
+    "ry_ua", "sah_sah", "se_no", "sk_sk", "sl_si", "so_so", "sq_al", "sr_cs", "sr_sp", "sv_se",
+    "sxu", "szl", "ta_in", "th_th", "tl_ph", "tlh_aa", "tok", "tr_tr", "tt_ru", "uk_ua",
+    "val_es", "vec_it", "vi_vn", "yi_de", "yo_ng", "zh_cn", "zh_hk", "zh_tw", "zlm_arab",
+];
+
+/// A settings file moved aside by a reset
+#[derive(Debug, Clone, Serialize)]
+pub struct ResetOptionsFile {
+    /// File name in the game directory
+    pub file: String,
+    /// Where the old file was kept
+    pub backup: PathBuf,
+}
+
+/// Set `key:value` lines in an options.txt, keeping every other line as is.
+///
+/// Keys that aren't in the file yet are appended; a missing file is created.
+pub fn set_options(path: &Path, values: &[(&str, String)]) -> Result<()> {
+    let existing = fs::read_to_string(path).unwrap_or_default();
+    let mut remaining: Vec<&(&str, String)> = values.iter().collect();
+    
+    let mut lines: Vec<String> = existing
+        .lines()
+        .map(|line| {
+            let key = line.split_once(':').map(|(key, _)| key);
+            match remaining.iter().position(|(k, _)| Some(*k) == key) {
+                Some(index) => {
+                    let (key, value) = remaining.remove(index);
+                    format!("{}:{}", key, value)
+                }
+                None => line.to_string(),
+            }
+        })
+        .collect();
+    lines.extend(remaining.iter().map(|(key, value)| format!("{}:{}", key, value)));
+    
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, lines.join("\n") + "\n")?;
+    Ok(())
+}
+
+/// The options.txt values for the instance's display settings.
+///
+/// `fullscreen` is only written when the instance asks for it, so otherwise
+/// the player's own choice (F11) is kept between sessions. The game has no
+/// setting for which monitor to use, so a chosen monitor is applied as the
+/// fullscreen video mode matching its resolution.
+pub fn display_options(fullscreen: bool, monitor_size: Option<(u32, u32)>) -> Vec<(&'static str, String)> {
+    if !fullscreen {
+        return Vec::new();
+    }
+    let mut values = vec![("fullscreen", "true".to_string())];
+    if let Some((width, height)) = monitor_size {
+        values.push(("fullscreenResolution", format!("{}x{}", width, height)));
+    }
+    values
+}
+
+/// Put a language code in the form options.txt uses (`ja_jp`).
+/// Returns None for something that can't be a language code.
+pub fn normalize_language(code: &str) -> Option<String> {
+    let code = code.trim().to_lowercase();
+    let valid = !code.is_empty()
+        && code.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    valid.then_some(code)
+}
+
+/// The `lang` value a Minecraft version reads. Codes are stored lowercase,
+/// as 1.11 and later use them (`ja_jp`); older versions only recognize the
+/// region in capitals (`ja_JP`).
+pub fn language_for_version(code: &str, minecraft_version: &str) -> String {
+    let parts: Vec<&str> = minecraft_version.split('.').collect();
+    let major: Option<u32> = parts.first().and_then(|s| s.parse().ok());
+    let minor: u32 = parts.get(1).and_then(|s| s.parse().ok()).unwrap_or(0);
+    if major != Some(1) || minor >= 11 {
+        return code.to_string();
+    }
+    
+    match code.split_once('_') {
+        Some((language, region)) => format!("{}_{}", language, region.to_uppercase()),
+        None => code.to_string(),
+    }
+}
+
+/// Whether a (normalized) language code is one the game ships with
+pub fn is_known_language(code: &str) -> bool {
+    KNOWN_LANGUAGES.contains(&code)
+}
+
+/// Reset the game's settings by moving options.txt (and, with `include_extra`,
+/// OptiFine's and shader mods' settings files) to a timestamped `.bak` next to
+/// it. The game writes fresh defaults on its next launch. Files that don't
+/// exist are skipped.
+pub fn reset_options(game_dir: &Path, include_extra: bool) -> Result<Vec<ResetOptionsFile>> {
+    let stamp = Local::now().format("%Y-%m-%d_%H-%M-%S");
+    let extra = if include_extra { EXTRA_OPTIONS_FILES } else { &[] };
+    
+    let mut reset = Vec::new();
+    for name in std::iter::once(&OPTIONS_FILE).chain(extra) {
+        let path = game_dir.join(name);
+        if !path.is_file() {
+            continue;
+        }
+        
+        let backup = game_dir.join(format!("{}.{}.bak", name, stamp));
+        fs::rename(&path, &backup)?;
+        reset.push(ResetOptionsFile { file: name.to_string(), backup });
+    }
+    
+    Ok(reset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn normalizes_and_recognizes_language_codes() {
+        assert_eq!(normalize_language(" ja_JP ").as_deref(), Some("ja_jp"));
+        assert!(normalize_language("").is_none());
+        assert!(normalize_language("en us").is_none());
+        assert!(is_known_language("ja_jp"));
+        assert!(!is_known_language("xx_modded"));
+    }
+    
+    #[test]
+    fn old_versions_get_capitalized_regions() {
+        assert_eq!(language_for_version("ja_jp", "1.10.2"), "ja_JP");
+        assert_eq!(language_for_version("lol_us", "1.8.9"), "lol_US");
+        assert_eq!(language_for_version("ja_jp", "1.11"), "ja_jp");
+        assert_eq!(language_for_version("ja_jp", "1.20.1"), "ja_jp");
+        assert_eq!(language_for_version("ja_jp", "24w14a"), "ja_jp");
+    }
+    
+    #[test]
+    fn replaces_existing_keys_and_appends_new_ones() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(OPTIONS_FILE);
+        fs::write(&path, "version:3465\nfullscreen:false\nfov:0.5\n").unwrap();
+        
+        set_options(&path, &[("fullscreen", "true".to_string()), ("lang", "ja_jp".to_string())]).unwrap();
+        
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "version:3465\nfullscreen:true\nfov:0.5\nlang:ja_jp\n"
+        );
+    }
+    
+    #[test]
+    fn creates_missing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".minecraft").join(OPTIONS_FILE);
+        
+        set_options(&path, &display_options(true, Some((2560, 1440)))).unwrap();
+        
+        assert_eq!(fs::read_to_string(&path).unwrap(), "fullscreen:true\nfullscreenResolution:2560x1440\n");
+    }
+    
+    #[test]
+    fn windowed_leaves_fullscreen_to_the_player() {
+        assert!(display_options(false, Some((1920, 1080))).is_empty());
+    }
+    
+    #[test]
+    fn reset_moves_settings_aside() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join(OPTIONS_FILE), "fov:0.5\n").unwrap();
+        fs::write(dir.path().join("optionsof.txt"), "ofFastRender:true\n").unwrap();
+        
+        let reset = reset_options(dir.path(), false).unwrap();
+        assert_eq!(reset.len(), 1);
+        assert!(!dir.path().join(OPTIONS_FILE).exists());
+        assert_eq!(fs::read_to_string(&reset[0].backup).unwrap(), "fov:0.5\n");
+        assert!(dir.path().join("optionsof.txt").exists());
+        
+        let reset = reset_options(dir.path(), true).unwrap();
+        assert_eq!(reset.len(), 1);
+        assert_eq!(reset[0].file, "optionsof.txt");
+    }
+}
@@ -0,0 +1,243 @@
+//! Active shader pack settings for Iris, Oculus and OptiFine.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::core::error::Result;
+use crate::core::modplatform::mod_parser::parse_mod_jar;
+
+/// OptiFine's shader settings file in the game directory
+const OPTIFINE_SHADERS_FILE: &str = "optionsshaders.txt";
+
+/// Value OptiFine writes for "no shader pack"
+const OPTIFINE_SHADERS_OFF: &str = "OFF";
+
+/// The mod that loads shader packs, and with it the file that names the active one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ShaderLoader {
+    /// Iris, `config/iris.properties`
+    Iris,
+    /// Oculus (Iris for Forge), `config/oculus.properties`
+    Oculus,
+    /// OptiFine, `optionsshaders.txt`
+    OptiFine,
+}
+
+impl ShaderLoader {
+    /// Settings file holding the active shader pack
+    pub fn config_path(&self, game_dir: &Path) -> PathBuf {
+        match self {
+            Self::Iris => game_dir.join("config").join("iris.properties"),
+            Self::Oculus => game_dir.join("config").join("oculus.properties"),
+            Self::OptiFine => game_dir.join(OPTIFINE_SHADERS_FILE),
+        }
+    }
+    
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Iris => "Iris",
+            Self::Oculus => "Oculus",
+            Self::OptiFine => "OptiFine",
+        }
+    }
+}
+
+/// Find the shader loader among an instance's enabled mods.
+/// Iris and Oculus are matched by mod ID; OptiFine has no mod metadata on
+/// Fabric and is matched by file name.
+pub fn detect_shader_loader(mods_dir: &Path) -> Option<ShaderLoader> {
+    let entries = fs::read_dir(mods_dir).ok()?;
+    let mut optifine = false;
+    
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let filename = entry.file_name().to_string_lossy().to_lowercase();
+        if !filename.ends_with(".jar") {
+            continue;
+        }
+        
+        match parse_mod_jar(&path).map(|details| details.mod_id) {
+            Some(id) if id == "iris" => return Some(ShaderLoader::Iris),
+            Some(id) if id == "oculus" => return Some(ShaderLoader::Oculus),
+            _ => optifine |= filename.contains("optifine"),
+        }
+    }
+    
+    optifine.then_some(ShaderLoader::OptiFine)
+}
+
+/// The shader pack the loader has active, or None when shaders are off
+pub fn active_shader_pack(game_dir: &Path, loader: ShaderLoader) -> Option<String> {
+    let content = fs::read_to_string(loader.config_path(game_dir)).ok()?;
+    
+    match loader {
+        ShaderLoader::Iris | ShaderLoader::Oculus => {
+            let values = read_properties(&content);
+            let enabled = values.iter()
+                .find(|(key, _)| key == "enableShaders")
+                .is_none_or(|(_, value)| value == "true");
+            let pack = values.into_iter()
+                .find(|(key, _)| key == "shaderPack")
+                .map(|(_, value)| value)?;
+            (enabled && !pack.is_empty()).then_some(pack)
+        }
+        ShaderLoader::OptiFine => {
+            let pack = content.lines()
+                .find_map(|line| line.strip_prefix("shaderPack="))?
+                .trim()
+                .to_string();
+            (!pack.is_empty() && pack != OPTIFINE_SHADERS_OFF && pack != "(internal)").then_some(pack)
+        }
+    }
+}
+
+/// Make `pack` (a file or folder name in `shaderpacks`) the active shader
+/// pack, or turn shaders off with None. Other settings in the file are kept.
+pub fn set_active_shader_pack(game_dir: &Path, loader: ShaderLoader, pack: Option<&str>) -> Result<()> {
+    let path = loader.config_path(game_dir);
+    let existing = fs::read_to_string(&path).unwrap_or_default();
+    
+    let content = match loader {
+        ShaderLoader::Iris | ShaderLoader::Oculus => set_lines(&existing, "=", &[
+            ("enableShaders", pack.is_some().to_string()),
+            ("shaderPack", escape_property(pack.unwrap_or_default())),
+        ]),
+        ShaderLoader::OptiFine => set_lines(&existing, "=", &[
+            ("shaderPack", pack.unwrap_or(OPTIFINE_SHADERS_OFF).to_string()),
+        ]),
+    };
+    
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, content)?;
+    Ok(())
+}
+
+/// Set `key<separator>value` lines, keeping every other line as is and
+/// appending keys that aren't in the file yet
+fn set_lines(existing: &str, separator: &str, values: &[(&str, String)]) -> String {
+    let mut remaining: Vec<&(&str, String)> = values.iter().collect();
+    
+    let mut lines: Vec<String> = existing
+        .lines()
+        .map(|line| {
+            let key = line.split_once(separator).map(|(key, _)| key.trim());
+            match remaining.iter().position(|(k, _)| Some(*k) == key) {
+                Some(index) => {
+                    let (key, value) = remaining.remove(index);
+                    format!("{}{}{}", key, separator, value)
+                }
+                None => line.to_string(),
+            }
+        })
+        .collect();
+    lines.extend(remaining.iter().map(|(key, value)| format!("{}{}{}", key, separator, value)));
+    
+    lines.join("\n") + "\n"
+}
+
+/// Key/value pairs of a Java properties file, with escapes undone
+fn read_properties(content: &str) -> Vec<(String, String)> {
+    content
+        .lines()
+        .map(str::trim_start)
+        .filter(|line| !line.is_empty() && !line.starts_with('#') && !line.starts_with('!'))
+        .filter_map(|line| {
+            let (key, value) = line.split_once('=')?;
+            Some((key.trim().to_string(), unescape_property(value.trim_start())))
+        })
+        .collect()
+}
+
+/// Undo the escaping Java's `Properties.store` applies to a value
+fn unescape_property(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            if let Some(next) = chars.next() {
+                result.push(next);
+            }
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// Escape a value the way Java's `Properties.store` does for the characters
+/// that can appear in a shader pack name
+fn escape_property(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    for (i, c) in value.chars().enumerate() {
+        match c {
+            '\\' | ':' | '=' | '#' | '!' => {
+                result.push('\\');
+                result.push(c);
+            }
+            ' ' if i == 0 => result.push_str("\\ "),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn iris_pack_round_trips_and_keeps_other_settings() {
+        let temp = tempfile::tempdir().unwrap();
+        let game_dir = temp.path();
+        let config = ShaderLoader::Iris.config_path(game_dir);
+        fs::create_dir_all(config.parent().unwrap()).unwrap();
+        fs::write(&config, "#Iris settings\nmaxShadowRenderDistance=32\nenableShaders=false\n").unwrap();
+        assert_eq!(active_shader_pack(game_dir, ShaderLoader::Iris), None);
+        
+        set_active_shader_pack(game_dir, ShaderLoader::Iris, Some("BSL v8.2: Extra.zip")).unwrap();
+        let content = fs::read_to_string(&config).unwrap();
+        assert!(content.contains("maxShadowRenderDistance=32"));
+        assert!(content.contains("shaderPack=BSL v8.2\\: Extra.zip"));
+        assert_eq!(active_shader_pack(game_dir, ShaderLoader::Iris).as_deref(), Some("BSL v8.2: Extra.zip"));
+        
+        set_active_shader_pack(game_dir, ShaderLoader::Iris, None).unwrap();
+        assert_eq!(active_shader_pack(game_dir, ShaderLoader::Iris), None);
+    }
+    
+    #[test]
+    fn optifine_uses_off_for_no_pack() {
+        let temp = tempfile::tempdir().unwrap();
+        let game_dir = temp.path();
+        
+        set_active_shader_pack(game_dir, ShaderLoader::OptiFine, Some("Sildurs.zip")).unwrap();
+        assert_eq!(active_shader_pack(game_dir, ShaderLoader::OptiFine).as_deref(), Some("Sildurs.zip"));
+        
+        set_active_shader_pack(game_dir, ShaderLoader::OptiFine, None).unwrap();
+        let content = fs::read_to_string(game_dir.join(OPTIFINE_SHADERS_FILE)).unwrap();
+        assert_eq!(content, "shaderPack=OFF\n");
+        assert_eq!(active_shader_pack(game_dir, ShaderLoader::OptiFine), None);
+    }
+}
@@ -25,7 +25,7 @@ use chrono::{DateTime, Utc};
 use crate::core::error::Result;
 
 /// URL for the Minecraft version manifest
-const VERSION_MANIFEST_URL: &str = "https://piston-meta.mojang.com/mc/game/version_manifest_v2.json";
+pub(crate) const VERSION_MANIFEST_URL: &str = "https://piston-meta.mojang.com/mc/game/version_manifest_v2.json";
 
 /// Minecraft version manifest
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -394,6 +394,32 @@ impl LaunchFeatures {
     }
 }
 
+/// Whether a version's arguments support quick play (MC 1.20+),
+/// read from its `has_quick_plays_support` argument rule
+pub fn supports_quick_play(version_data: &VersionData) -> bool {
+    has_feature_rule(version_data, "has_quick_plays_support")
+}
+
+/// Whether a version's arguments support demo mode (MC 1.13+), read from
+/// its `is_demo_user` argument rule. Older versions have no way to be told.
+pub fn supports_demo(version_data: &VersionData) -> bool {
+    has_feature_rule(version_data, "is_demo_user")
+}
+
+/// Whether any game argument is gated on the given launcher feature
+fn has_feature_rule(version_data: &VersionData, feature: &str) -> bool {
+    let Some(arguments) = &version_data.arguments else {
+        return false;
+    };
+    
+    arguments.game.iter().any(|arg| match arg {
+        ArgumentValue::Conditional { rules, .. } => rules.iter().any(|rule| {
+            rule.features.as_ref().is_some_and(|features| features.contains_key(feature))
+        }),
+        ArgumentValue::Simple(_) => false,
+    })
+}
+
 /// Evaluate rules to determine if something applies (OS-only, no features)
 pub fn evaluate_rules(rules: &[Rule]) -> bool {
     evaluate_rules_with_features(rules, &LaunchFeatures::default())
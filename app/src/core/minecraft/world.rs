@@ -26,6 +26,7 @@ use tracing::{debug, info, warn};
 
 use crate::core::error::{OxideError, Result};
 use crate::core::files;
+use crate::core::minecraft::captures::{prune_before, PruneResult};
 
 /// Represents a Minecraft game type/mode
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -62,6 +63,76 @@ impl std::fmt::Display for GameType {
     }
 }
 
+/// Folders, relative to the game directory, that mods such as Global Packs,
+/// Open Loader and Paxi load data packs from for every world
+pub const GLOBAL_DATAPACK_DIRS: &[&str] = &[
+    "global_packs/required_data",
+    "global_packs/optional_data",
+    "openloader/data",
+    "config/openloader/data",
+    "config/paxi/datapacks",
+];
+
+/// Name of the summary written next to the world in an export
+const EXPORT_README: &str = "README.txt";
+
+/// Lock file Minecraft holds open while a world is loaded; never archived
+const SESSION_LOCK: &str = "session.lock";
+
+/// Timestamp format of backup file names; sorts chronologically and is
+/// valid on every filesystem
+const BACKUP_TIMESTAMP_FORMAT: &str = "%Y-%m-%d_%H-%M-%S";
+
+/// A backup of a world in the backups folder: the launcher's own at
+/// `<world folder>/<timestamp>.zip`, or Minecraft's at
+/// `<timestamp>_<world folder>.zip`
+#[derive(Debug, Clone, Serialize)]
+pub struct WorldBackup {
+    /// Folder name of the world the backup was made from
+    pub world: String,
+    /// Path of the backup within the backups folder, `/`-separated, e.g.
+    /// `New World/2025-01-31_18-04-12.zip`
+    pub file_name: String,
+    pub path: PathBuf,
+    /// When the backup was made, from its file name
+    pub created: Option<chrono::NaiveDateTime>,
+    /// Size of the zip in bytes
+    pub size: u64,
+}
+
+impl WorldBackup {
+    /// Read a backup in `backups_dir`; None when the file isn't a zip
+    /// holding a world folder
+    fn from_path(backups_dir: &Path, path: &Path) -> Option<Self> {
+        let file_name = path.strip_prefix(backups_dir).ok()?
+            .components()
+            .map(|c| c.as_os_str().to_str())
+            .collect::<Option<Vec<_>>>()?
+            .join("/");
+        let stem = path.file_name()?.to_str()?.strip_suffix(".zip")?;
+        let timestamp = stem.get(..19).unwrap_or(stem);
+        // The file name may be suffixed to keep it unique; the zip's top
+        // folder is the world's folder name
+        let mut archive = zip::ZipArchive::new(fs::File::open(path).ok()?).ok()?;
+        let world = backup_world_folder(&mut archive)?;
+        
+        Some(Self {
+            world,
+            created: chrono::NaiveDateTime::parse_from_str(timestamp, BACKUP_TIMESTAMP_FORMAT).ok(),
+            size: fs::metadata(path).map(|m| m.len()).unwrap_or(0),
+            path: path.to_path_buf(),
+            file_name,
+        })
+    }
+}
+
+/// What a world export contained beyond the world folder
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct WorldExport {
+    /// Global data packs copied into the world's `datapacks` folder
+    pub global_datapacks: Vec<String>,
+}
+
 /// Represents a Minecraft world/save
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct World {
@@ -180,6 +251,18 @@ pub fn list_worlds(saves_dir: &Path) -> Vec<World> {
     worlds
 }
 
+/// Count worlds in a saves directory without reading their metadata
+pub fn count_worlds(saves_dir: &Path) -> usize {
+    let Ok(entries) = fs::read_dir(saves_dir) else {
+        return 0;
+    };
+    
+    entries
+        .flatten()
+        .filter(|entry| entry.path().join("level.dat").exists())
+        .count()
+}
+
 /// Delete a world by folder name
 pub fn delete_world(saves_dir: &Path, folder_name: &str, use_recycle_bin: bool) -> Result<()> {
     let world_path = saves_dir.join(folder_name);
@@ -217,8 +300,19 @@ pub fn delete_world(saves_dir: &Path, folder_name: &str, use_recycle_bin: bool)
     Ok(())
 }
 
-/// Export a world to a ZIP file
-pub fn export_world(saves_dir: &Path, folder_name: &str, output_path: &Path) -> Result<()> {
+/// Export a world to a ZIP file, with a README summarizing its seed, game
+/// rules and data packs.
+///
+/// Data packs in the world's own `datapacks` folder are part of the world.
+/// With `game_dir`, global data packs (see [`GLOBAL_DATAPACK_DIRS`]) the
+/// world has enabled are copied into its `datapacks` folder in the archive,
+/// so it loads the same without the mod that provided them.
+pub fn export_world(
+    saves_dir: &Path,
+    folder_name: &str,
+    output_path: &Path,
+    game_dir: Option<&Path>,
+) -> Result<WorldExport> {
     let world_path = saves_dir.join(folder_name);
     
     if !world_path.exists() {
@@ -230,6 +324,9 @@ pub fn export_world(saves_dir: &Path, folder_name: &str, output_path: &Path) ->
     
     info!("Exporting world {:?} to {:?}", world_path, output_path);
     
+    let level_data = read_level_dat(&world_path.join("level.dat")).unwrap_or_default();
+    let enabled_datapacks = find_nbt_string_list(&level_data, b"DataPacks", b"Enabled");
+    
     let file = fs::File::create(output_path)?;
     let mut zip = zip::ZipWriter::new(file);
     let options = zip::write::SimpleFileOptions::default()
@@ -238,10 +335,277 @@ pub fn export_world(saves_dir: &Path, folder_name: &str, output_path: &Path) ->
     // Add all files from the world directory
     add_directory_to_zip(&mut zip, &world_path, folder_name, options)?;
     
+    let mut export = WorldExport::default();
+    if let Some(game_dir) = game_dir {
+        for pack in referenced_global_datapacks(game_dir, &enabled_datapacks) {
+            let name = pack.file_name().unwrap_or_default().to_string_lossy().to_string();
+            if world_path.join("datapacks").join(&name).exists() {
+                continue;
+            }
+            
+            let zip_path = format!("{}/datapacks/{}", folder_name, name);
+            if pack.is_dir() {
+                add_directory_to_zip(&mut zip, &pack, &zip_path, options)?;
+            } else {
+                zip.start_file(zip_path, options)?;
+                std::io::copy(&mut fs::File::open(&pack)?, &mut zip)?;
+            }
+            export.global_datapacks.push(name);
+        }
+    }
+    
+    let readme = export_readme(folder_name, &level_data, &enabled_datapacks, &export.global_datapacks);
+    zip.start_file(EXPORT_README, options)?;
+    std::io::Write::write_all(&mut zip, readme.as_bytes())?;
+    
     zip.finish()?;
-    info!("World exported successfully");
+    info!("World exported successfully ({} global data packs bundled)", export.global_datapacks.len());
     
-    Ok(())
+    Ok(export)
+}
+
+/// Zip a world into `backups_dir/<folder_name>/<timestamp>.zip`, so each
+/// world keeps its own backup history. The world folder is stored under its
+/// own name in the zip so the backup restores as-is.
+pub fn backup_world(saves_dir: &Path, folder_name: &str, backups_dir: &Path) -> Result<WorldBackup> {
+    let world_path = saves_dir.join(folder_name);
+    if !world_path.join("level.dat").is_file() {
+        return Err(OxideError::Io(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            format!("World '{}' not found", folder_name),
+        )));
+    }
+    
+    let world_backups_dir = backups_dir.join(folder_name);
+    fs::create_dir_all(&world_backups_dir)?;
+    
+    let timestamp = chrono::Local::now().format(BACKUP_TIMESTAMP_FORMAT).to_string();
+    let mut backup_path = world_backups_dir.join(format!("{}.zip", timestamp));
+    let mut counter = 2;
+    while backup_path.exists() {
+        backup_path = world_backups_dir.join(format!("{} ({}).zip", timestamp, counter));
+        counter += 1;
+    }
+    
+    info!("Backing up world {:?} to {:?}", world_path, backup_path);
+    
+    let written = (|| -> Result<()> {
+        let mut zip = zip::ZipWriter::new(fs::File::create(&backup_path)?);
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated);
+        add_directory_to_zip(&mut zip, &world_path, folder_name, options)?;
+        zip.finish()?;
+        Ok(())
+    })();
+    if let Err(e) = written {
+        let _ = fs::remove_file(&backup_path);
+        return Err(e);
+    }
+    
+    WorldBackup::from_path(backups_dir, &backup_path)
+        .ok_or_else(|| OxideError::Other(format!("Invalid backup {}", backup_path.display())))
+}
+
+/// Zip files directly in `backups_dir` (Minecraft's backups) and in its
+/// per-world folders (the launcher's)
+fn backup_files(backups_dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(backups_dir) else {
+        return Vec::new();
+    };
+    
+    let mut paths = Vec::new();
+    for path in entries.flatten().map(|entry| entry.path()) {
+        if path.is_dir() {
+            if let Ok(world_entries) = fs::read_dir(&path) {
+                paths.extend(world_entries.flatten().map(|entry| entry.path()).filter(|p| p.is_file()));
+            }
+        } else if path.is_file() {
+            paths.push(path);
+        }
+    }
+    paths
+}
+
+/// Resolve a backup's `file_name` (as listed) to its path in `backups_dir`.
+/// None unless it names a file directly in the folder or in a world folder.
+pub fn backup_path(backups_dir: &Path, file_name: &str) -> Option<PathBuf> {
+    let parts: Vec<&str> = file_name.split('/').collect();
+    let valid = parts.len() <= 2 && parts.iter().all(|part| {
+        !part.is_empty() && Path::new(part).file_name() == Some(std::ffi::OsStr::new(part))
+    });
+    if !valid {
+        return None;
+    }
+    
+    let path = parts.iter().fold(backups_dir.to_path_buf(), |path, part| path.join(part));
+    path.is_file().then_some(path)
+}
+
+/// Every world backup in `backups_dir`, the launcher's and Minecraft's own,
+/// including those of worlds that have since been deleted; newest first
+pub fn list_world_backups(backups_dir: &Path) -> Vec<WorldBackup> {
+    let mut backups: Vec<(std::time::SystemTime, WorldBackup)> = backup_files(backups_dir)
+        .into_iter()
+        .filter_map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+            WorldBackup::from_path(backups_dir, &path).map(|backup| (modified, backup))
+        })
+        .collect();
+    // Suffixed names were made later within the same second
+    backups.sort_by(|(a_time, a), (b_time, b)| {
+        (b.created, b_time, b.file_name.len(), &b.file_name)
+            .cmp(&(a.created, a_time, a.file_name.len(), &a.file_name))
+    });
+    
+    backups.into_iter().map(|(_, backup)| backup).collect()
+}
+
+/// Delete the backups in `backups_dir` and its per-world folders made
+/// before `cutoff`, removing world folders left empty
+pub fn prune_world_backups(
+    backups_dir: &Path,
+    cutoff: chrono::NaiveDateTime,
+    use_recycle_bin: bool,
+) -> Result<PruneResult> {
+    let mut result = prune_before(backups_dir, "zip", cutoff, use_recycle_bin)?;
+    let Ok(entries) = fs::read_dir(backups_dir) else {
+        return Ok(result);
+    };
+    
+    for world_dir in entries.flatten().map(|entry| entry.path()).filter(|p| p.is_dir()) {
+        let pruned = prune_before(&world_dir, "zip", cutoff, use_recycle_bin)?;
+        result.deleted += pruned.deleted;
+        result.bytes_freed += pruned.bytes_freed;
+        // Only succeeds once the folder is empty
+        let _ = fs::remove_dir(&world_dir);
+    }
+    Ok(result)
+}
+
+/// Unzip a world backup into `saves_dir`. Returns the folder name used.
+///
+/// If the world still exists it is replaced when `overwrite` is set;
+/// otherwise the backup is restored next to it under a new name, so nothing
+/// is lost. The backup is extracted to a staging folder first, so a failed
+/// restore leaves the existing world untouched.
+pub fn restore_world_backup(
+    backup_path: &Path,
+    saves_dir: &Path,
+    overwrite: bool,
+    use_recycle_bin: bool,
+) -> Result<String> {
+    let mut archive = zip::ZipArchive::new(fs::File::open(backup_path)?)?;
+    let folder_name = backup_world_folder(&mut archive)
+        .ok_or_else(|| OxideError::Other(format!("{} is not a world backup", backup_path.display())))?;
+    
+    let staging = saves_dir.join(format!(".{}.restoring", folder_name));
+    if staging.exists() {
+        fs::remove_dir_all(&staging)?;
+    }
+    
+    info!("Restoring world backup {:?} into {:?}", backup_path, saves_dir);
+    let extracted = (|| -> Result<()> {
+        for i in 0..archive.len() {
+            let mut file = archive.by_index(i)?;
+            let Some(relative) = file.enclosed_name()
+                .and_then(|path| path.strip_prefix(&folder_name).ok().map(Path::to_path_buf))
+            else {
+                continue;
+            };
+            
+            let out_path = staging.join(relative);
+            if file.is_dir() {
+                fs::create_dir_all(&out_path)?;
+            } else {
+                if let Some(parent) = out_path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                std::io::copy(&mut file, &mut fs::File::create(&out_path)?)?;
+            }
+        }
+        
+        if !staging.join("level.dat").is_file() {
+            return Err(OxideError::Other(format!("{} is not a world backup (no level.dat)", backup_path.display())));
+        }
+        Ok(())
+    })();
+    if let Err(e) = extracted {
+        let _ = fs::remove_dir_all(&staging);
+        return Err(e);
+    }
+    
+    let target_name = if saves_dir.join(&folder_name).exists() {
+        if overwrite {
+            info!("Replacing world {} with backup", folder_name);
+            files::delete_directory(saves_dir.join(&folder_name), use_recycle_bin)?;
+            folder_name
+        } else {
+            unique_folder_name(saves_dir, &folder_name)
+        }
+    } else {
+        folder_name
+    };
+    fs::rename(&staging, saves_dir.join(&target_name))?;
+    
+    Ok(target_name)
+}
+
+/// Global data packs a world has enabled. Loader mods name these packs
+/// differently, so a pack counts as enabled when an entry of the world's
+/// enabled list ends with its file name.
+fn referenced_global_datapacks(game_dir: &Path, enabled: &[String]) -> Vec<PathBuf> {
+    let enabled_names: Vec<&str> = enabled.iter()
+        .filter_map(|entry| entry.rsplit(['/', ':']).next())
+        .collect();
+    
+    GLOBAL_DATAPACK_DIRS.iter()
+        .filter_map(|dir| fs::read_dir(game_dir.join(dir)).ok())
+        .flat_map(|entries| entries.flatten().map(|entry| entry.path()))
+        .filter(|path| {
+            path.file_name()
+                .map(|name| enabled_names.contains(&&*name.to_string_lossy()))
+                .unwrap_or(false)
+        })
+        .collect()
+}
+
+/// The README written next to an exported world, for setting up a server
+/// or checking what the world expects
+fn export_readme(folder_name: &str, level_data: &[u8], enabled: &[String], bundled: &[String]) -> String {
+    let (name, seed, game_type, hardcore, _) = parse_nbt_world_data(level_data);
+    let game_rules = find_nbt_string_compound(level_data, b"GameRules");
+    
+    let mut readme = format!(
+        "World: {}\nFolder: {}\nSeed: {}\nGame mode: {}{}\nExported by Oxide Launcher {} on {}\n",
+        name,
+        folder_name,
+        seed.map(|s| s.to_string()).unwrap_or_else(|| "Unknown".to_string()),
+        game_type,
+        if hardcore { " (hardcore)" } else { "" },
+        env!("CARGO_PKG_VERSION"),
+        chrono::Local::now().format("%Y-%m-%d %H:%M"),
+    );
+    
+    if !game_rules.is_empty() {
+        readme.push_str("\nGame rules:\n");
+        for (rule, value) in &game_rules {
+            readme.push_str(&format!("  {} = {}\n", rule, value));
+        }
+    }
+    if !enabled.is_empty() {
+        readme.push_str("\nEnabled data packs:\n");
+        for pack in enabled {
+            readme.push_str(&format!("  {}\n", pack));
+        }
+    }
+    if !bundled.is_empty() {
+        readme.push_str("\nGlobal data packs copied into the world's datapacks folder:\n");
+        for pack in bundled {
+            readme.push_str(&format!("  {}\n", pack));
+        }
+    }
+    
+    readme
 }
 
 /// Copy/duplicate a world
@@ -289,13 +653,105 @@ pub fn get_world_icon(saves_dir: &Path, folder_name: &str) -> Option<String> {
     }
 }
 
+/// What a world's level.dat records about the game that last saved it
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorldVersion {
+    /// Version name such as "1.20.1", from `Data.Version.Name` (1.9+)
+    pub name: Option<String>,
+    /// Data version number, from `Data.DataVersion`
+    pub data_version: Option<i32>,
+    pub snapshot: bool,
+    /// Whether the world was ever opened with a modified game
+    pub was_modded: bool,
+    /// Brands that have opened the world, such as "vanilla", "fabric" or "forge"
+    pub brands: Vec<String>,
+}
+
+impl WorldVersion {
+    /// Whether anything other than the vanilla game has opened the world
+    pub fn is_modded(&self) -> bool {
+        self.was_modded || self.brands.iter().any(|brand| brand != "vanilla")
+    }
+}
+
+/// Read the version information from a world folder's level.dat
+pub fn read_world_version(world_path: &Path) -> Result<WorldVersion> {
+    let data = read_level_dat(&world_path.join("level.dat"))?;
+    Ok(parse_world_version(&data))
+}
+
+fn parse_world_version(data: &[u8]) -> WorldVersion {
+    const TAG_BYTE: u8 = 1;
+    const TAG_INT: u8 = 3;
+    const TAG_STRING: u8 = 8;
+    const TAG_COMPOUND: u8 = 10;
+    
+    let read_byte = |data: &[u8], name: &[u8]| {
+        find_typed_tag(data, TAG_BYTE, name).and_then(|pos| data.get(pos)).is_some_and(|b| *b != 0)
+    };
+    
+    let mut version = WorldVersion {
+        data_version: find_typed_tag(data, TAG_INT, b"DataVersion")
+            .and_then(|pos| data.get(pos..pos + 4))
+            .map(|b| i32::from_be_bytes([b[0], b[1], b[2], b[3]])),
+        was_modded: read_byte(data, b"WasModded"),
+        brands: find_nbt_string_list(data, b"Data", b"ServerBrands"),
+        ..Default::default()
+    };
+    
+    if let Some(pos) = find_typed_tag(data, TAG_COMPOUND, b"Version") {
+        let compound = &data[pos..];
+        version.name = find_typed_tag(compound, TAG_STRING, b"Name")
+            .and_then(|name_pos| read_nbt_string_at(compound, name_pos))
+            .map(|(name, _)| name);
+        version.snapshot = read_byte(compound, b"Snapshot");
+    }
+    
+    version
+}
+
+/// Copy a world folder from anywhere into `saves_dir`, renaming it if a world
+/// with the same folder name exists. Returns the folder name used.
+pub fn import_world(world_path: &Path, saves_dir: &Path) -> Result<String> {
+    if !world_path.join("level.dat").is_file() {
+        return Err(OxideError::Other(format!("{} is not a world folder (no level.dat)", world_path.display())));
+    }
+    
+    let base = world_path.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| "World".to_string());
+    let folder_name = unique_folder_name(saves_dir, &base);
+    
+    info!("Importing world {:?} into {:?} as {}", world_path, saves_dir, folder_name);
+    copy_dir_recursive(world_path, &saves_dir.join(&folder_name))?;
+    
+    Ok(folder_name)
+}
+
 // ============================================================================
 // Helper functions
 // ============================================================================
 
-/// Parse level.dat NBT file for world metadata
-/// Returns (name, seed, game_type, hardcore, last_played)
-fn parse_level_dat(path: &Path) -> Result<(String, Option<i64>, GameType, bool, Option<i64>)> {
+/// The world folder a backup zip holds: the top folder of its entries
+fn backup_world_folder<R: std::io::Read + std::io::Seek>(archive: &mut zip::ZipArchive<R>) -> Option<String> {
+    (0..archive.len())
+        .filter_map(|i| archive.by_index(i).ok()?.enclosed_name())
+        .find_map(|path| path.components().next().map(|c| c.as_os_str().to_string_lossy().to_string()))
+}
+
+/// `base`, or `base (2)`, `base (3)`, ... if a world with that name exists
+fn unique_folder_name(saves_dir: &Path, base: &str) -> String {
+    let mut folder_name = base.to_string();
+    let mut counter = 2;
+    while saves_dir.join(&folder_name).exists() {
+        folder_name = format!("{} ({})", base, counter);
+        counter += 1;
+    }
+    folder_name
+}
+
+/// Read a level.dat's uncompressed NBT data
+fn read_level_dat(path: &Path) -> Result<Vec<u8>> {
     let mut file = fs::File::open(path)?;
     let mut compressed_data = Vec::new();
     file.read_to_end(&mut compressed_data)?;
@@ -305,6 +761,14 @@ fn parse_level_dat(path: &Path) -> Result<(String, Option<i64>, GameType, bool,
     let mut nbt_data = Vec::new();
     decoder.read_to_end(&mut nbt_data)?;
     
+    Ok(nbt_data)
+}
+
+/// Parse level.dat NBT file for world metadata
+/// Returns (name, seed, game_type, hardcore, last_played)
+fn parse_level_dat(path: &Path) -> Result<(String, Option<i64>, GameType, bool, Option<i64>)> {
+    let nbt_data = read_level_dat(path)?;
+    
     // Parse NBT - we'll use a simple approach since we just need a few values
     // The structure is: Compound "Data" containing the world info
     let (name, seed, game_type, hardcore, last_played) = parse_nbt_world_data(&nbt_data);
@@ -398,6 +862,79 @@ fn find_tag_position(data: &[u8], tag_name: &[u8]) -> Option<usize> {
     Some(pos + tag_name.len())
 }
 
+/// Find the position after a tag's header, matching the tag type and name
+/// length as well so that `Version` doesn't match inside `DataVersion`
+fn find_typed_tag(data: &[u8], tag_type: u8, tag_name: &[u8]) -> Option<usize> {
+    let mut header = vec![tag_type];
+    header.extend((tag_name.len() as u16).to_be_bytes());
+    header.extend(tag_name);
+    find_tag_position(data, &header)
+}
+
+/// Read a 2-byte length-prefixed NBT string at `pos`, returning it and the
+/// position after it
+fn read_nbt_string_at(data: &[u8], pos: usize) -> Option<(String, usize)> {
+    let length = u16::from_be_bytes([*data.get(pos)?, *data.get(pos + 1)?]) as usize;
+    let bytes = data.get(pos + 2..pos + 2 + length)?;
+    Some((String::from_utf8_lossy(bytes).to_string(), pos + 2 + length))
+}
+
+/// Read the string tags of an NBT compound, such as `GameRules`, as
+/// (name, value) pairs. Stops at the first tag that isn't a string.
+fn find_nbt_string_compound(data: &[u8], tag_name: &[u8]) -> Vec<(String, String)> {
+    const TAG_STRING: u8 = 8;
+    
+    let mut values = Vec::new();
+    let Some(mut pos) = find_tag_position(data, tag_name) else {
+        return values;
+    };
+    
+    while data.get(pos) == Some(&TAG_STRING) {
+        let Some((name, after_name)) = read_nbt_string_at(data, pos + 1) else {
+            break;
+        };
+        let Some((value, after_value)) = read_nbt_string_at(data, after_name) else {
+            break;
+        };
+        values.push((name, value));
+        pos = after_value;
+    }
+    
+    values
+}
+
+/// Read a list of strings named `list_name` inside the compound `parent`,
+/// such as `DataPacks.Enabled`
+fn find_nbt_string_list(data: &[u8], parent: &[u8], list_name: &[u8]) -> Vec<String> {
+    const TAG_STRING: u8 = 8;
+    
+    let Some(parent_pos) = find_tag_position(data, parent) else {
+        return Vec::new();
+    };
+    let Some(pos) = find_tag_position(&data[parent_pos..], list_name).map(|pos| parent_pos + pos) else {
+        return Vec::new();
+    };
+    if data.get(pos) != Some(&TAG_STRING) {
+        return Vec::new();
+    }
+    let Some(count) = data.get(pos + 1..pos + 5) else {
+        return Vec::new();
+    };
+    let count = i32::from_be_bytes([count[0], count[1], count[2], count[3]]).max(0);
+    
+    let mut values = Vec::new();
+    let mut pos = pos + 5;
+    for _ in 0..count {
+        let Some((value, next)) = read_nbt_string_at(data, pos) else {
+            break;
+        };
+        values.push(value);
+        pos = next;
+    }
+    
+    values
+}
+
 /// Calculate the total size of a directory
 fn calculate_dir_size(path: &Path) -> Result<u64> {
     let mut total = 0u64;
@@ -447,11 +984,12 @@ fn copy_dir_recursive(src: &Path, dst: &Path) -> Result<()> {
     Ok(())
 }
 
-/// Add a directory to a ZIP file recursively
+/// Add a directory to a ZIP file recursively, under `prefix`. Skips
+/// `session.lock`, which Minecraft keeps locked while the world is open.
 fn add_directory_to_zip<W: std::io::Write + std::io::Seek>(
     zip: &mut zip::ZipWriter<W>,
     dir_path: &Path,
-    _prefix: &str, // Reserved for future use with custom prefixes
+    prefix: &str,
     options: zip::write::SimpleFileOptions,
 ) -> Result<()> {
     for entry in walkdir::WalkDir::new(dir_path) {
@@ -461,12 +999,14 @@ fn add_directory_to_zip<W: std::io::Write + std::io::Seek>(
         )))?;
         
         let path = entry.path();
-        let relative_path = path.strip_prefix(dir_path.parent().unwrap_or(dir_path))
-            .unwrap_or(path);
-        let name = relative_path.to_string_lossy();
+        let relative_path = path.strip_prefix(dir_path).unwrap_or(path);
+        let name = format!("{}/{}", prefix, relative_path.to_string_lossy().replace('\\', "/"));
         
         if path.is_file() {
-            zip.start_file(name.to_string(), options)?;
+            if path.parent() == Some(dir_path) && path.file_name() == Some(std::ffi::OsStr::new(SESSION_LOCK)) {
+                continue;
+            }
+            zip.start_file(name, options)?;
             let mut file = fs::File::open(path)?;
             std::io::copy(&mut file, zip)?;
         } else if path.is_dir() && path != dir_path {
@@ -476,3 +1016,178 @@ fn add_directory_to_zip<W: std::io::Write + std::io::Seek>(
     
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    
+    fn nbt_name(out: &mut Vec<u8>, tag: u8, name: &str) {
+        out.push(tag);
+        out.extend((name.len() as u16).to_be_bytes());
+        out.extend(name.as_bytes());
+    }
+    
+    fn nbt_string(out: &mut Vec<u8>, value: &str) {
+        out.extend((value.len() as u16).to_be_bytes());
+        out.extend(value.as_bytes());
+    }
+    
+    /// A gzipped level.dat with a name, seed, game rules and enabled data packs
+    fn write_level_dat(path: &Path, enabled: &[&str]) {
+        let mut nbt = Vec::new();
+        nbt_name(&mut nbt, 10, "");
+        nbt_name(&mut nbt, 10, "Data");
+        nbt_name(&mut nbt, 8, "LevelName");
+        nbt_string(&mut nbt, "Test World");
+        nbt_name(&mut nbt, 4, "RandomSeed");
+        nbt.extend(42i64.to_be_bytes());
+        nbt_name(&mut nbt, 10, "GameRules");
+        nbt_name(&mut nbt, 8, "keepInventory");
+        nbt_string(&mut nbt, "true");
+        nbt_name(&mut nbt, 8, "doDaylightCycle");
+        nbt_string(&mut nbt, "false");
+        nbt.push(0);
+        nbt_name(&mut nbt, 10, "DataPacks");
+        nbt_name(&mut nbt, 9, "Enabled");
+        nbt.push(8);
+        nbt.extend((enabled.len() as i32).to_be_bytes());
+        for pack in enabled {
+            nbt_string(&mut nbt, pack);
+        }
+        nbt.extend([0, 0, 0]);
+        
+        let mut encoder = flate2::write::GzEncoder::new(fs::File::create(path).unwrap(), flate2::Compression::default());
+        encoder.write_all(&nbt).unwrap();
+        encoder.finish().unwrap();
+    }
+    
+    #[test]
+    fn reads_version_and_imports_world() {
+        let mut nbt = Vec::new();
+        nbt_name(&mut nbt, 10, "");
+        nbt_name(&mut nbt, 10, "Data");
+        nbt_name(&mut nbt, 3, "DataVersion");
+        nbt.extend(3465i32.to_be_bytes());
+        nbt_name(&mut nbt, 1, "WasModded");
+        nbt.push(1);
+        nbt_name(&mut nbt, 9, "ServerBrands");
+        nbt.push(8);
+        nbt.extend(1i32.to_be_bytes());
+        nbt_string(&mut nbt, "fabric");
+        nbt_name(&mut nbt, 10, "Version");
+        nbt_name(&mut nbt, 3, "Id");
+        nbt.extend(3465i32.to_be_bytes());
+        nbt_name(&mut nbt, 8, "Name");
+        nbt_string(&mut nbt, "1.20.1");
+        nbt_name(&mut nbt, 1, "Snapshot");
+        nbt.push(0);
+        nbt.extend([0, 0, 0]);
+        
+        let dir = tempfile::tempdir().unwrap();
+        let world = dir.path().join("Old World");
+        fs::create_dir_all(world.join("region")).unwrap();
+        fs::write(world.join("region").join("r.0.0.mca"), "chunks").unwrap();
+        let mut encoder = flate2::write::GzEncoder::new(fs::File::create(world.join("level.dat")).unwrap(), flate2::Compression::default());
+        encoder.write_all(&nbt).unwrap();
+        encoder.finish().unwrap();
+        
+        let version = read_world_version(&world).unwrap();
+        assert_eq!(version.name.as_deref(), Some("1.20.1"));
+        assert_eq!(version.data_version, Some(3465));
+        assert!(!version.snapshot);
+        assert_eq!(version.brands, vec!["fabric"]);
+        assert!(version.is_modded());
+        
+        let saves = dir.path().join("saves");
+        fs::create_dir_all(saves.join("Old World")).unwrap();
+        assert_eq!(import_world(&world, &saves).unwrap(), "Old World (2)");
+        assert!(saves.join("Old World (2)").join("region").join("r.0.0.mca").is_file());
+    }
+    
+    #[test]
+    fn export_bundles_enabled_global_datapacks_and_readme() {
+        let dir = tempfile::tempdir().unwrap();
+        let game_dir = dir.path();
+        let world = game_dir.join("saves").join("World");
+        fs::create_dir_all(world.join("datapacks")).unwrap();
+        write_level_dat(&world.join("level.dat"), &["vanilla", "file/local.zip", "global:shared.zip"]);
+        fs::write(world.join("datapacks").join("local.zip"), "local").unwrap();
+        
+        let global = game_dir.join("global_packs").join("required_data");
+        fs::create_dir_all(&global).unwrap();
+        fs::write(global.join("shared.zip"), "shared").unwrap();
+        fs::write(global.join("unused.zip"), "unused").unwrap();
+        
+        let output = dir.path().join("export.zip");
+        let export = export_world(&game_dir.join("saves"), "World", &output, Some(game_dir)).unwrap();
+        assert_eq!(export.global_datapacks, vec!["shared.zip"]);
+        
+        let mut archive = zip::ZipArchive::new(fs::File::open(&output).unwrap()).unwrap();
+        assert!(archive.by_name("World/level.dat").is_ok());
+        assert!(archive.by_name("World/datapacks/local.zip").is_ok());
+        assert!(archive.by_name("World/datapacks/shared.zip").is_ok());
+        assert!(archive.by_name("World/datapacks/unused.zip").is_err());
+        
+        let mut readme = String::new();
+        archive.by_name(EXPORT_README).unwrap().read_to_string(&mut readme).unwrap();
+        assert!(readme.contains("World: Test World"));
+        assert!(readme.contains("Seed: 42"));
+        assert!(readme.contains("keepInventory = true"));
+        assert!(readme.contains("doDaylightCycle = false"));
+        assert!(readme.contains("file/local.zip"));
+    }
+    
+    #[test]
+    fn backs_up_and_restores_worlds() {
+        let dir = tempfile::tempdir().unwrap();
+        let saves = dir.path().join("saves");
+        let backups = dir.path().join("backups");
+        let world = saves.join("World");
+        fs::create_dir_all(world.join("region")).unwrap();
+        write_level_dat(&world.join("level.dat"), &[]);
+        fs::write(world.join("region").join("r.0.0.mca"), "before").unwrap();
+        fs::write(world.join(SESSION_LOCK), "").unwrap();
+        
+        let first = backup_world(&saves, "World", &backups).unwrap();
+        let second = backup_world(&saves, "World", &backups).unwrap();
+        assert_ne!(first.file_name, second.file_name);
+        // Each world keeps its backups in its own folder
+        assert_eq!(first.path.parent(), Some(backups.join("World").as_path()));
+        assert!(first.file_name.starts_with("World/"));
+        assert!(first.created.is_some());
+        assert_eq!(backup_path(&backups, &first.file_name), Some(first.path.clone()));
+        assert_eq!(backup_path(&backups, "../saves/World/level.dat"), None);
+        assert_eq!(backup_path(&backups, "World/../World"), None);
+        fs::write(backups.join("notes.txt"), "not a backup").unwrap();
+        // Minecraft's own backups sit directly in the backups folder
+        fs::copy(&first.path, backups.join("2020-01-01_00-00-00_World.zip")).unwrap();
+        let listed = list_world_backups(&backups);
+        assert_eq!(listed.len(), 3);
+        assert_eq!(listed[0].file_name, second.file_name);
+        assert_eq!(listed[1].world, "World");
+        assert_eq!(listed[2].file_name, "2020-01-01_00-00-00_World.zip");
+        
+        // A deleted world's backups are still listed
+        fs::rename(&world, saves.join("Moved")).unwrap();
+        assert_eq!(list_world_backups(&backups).len(), 3);
+        fs::rename(saves.join("Moved"), &world).unwrap();
+        
+        fs::write(world.join("region").join("r.0.0.mca"), "after").unwrap();
+        
+        // The original still exists, so the backup is restored beside it
+        assert_eq!(restore_world_backup(&first.path, &saves, false, false).unwrap(), "World (2)");
+        assert_eq!(fs::read_to_string(saves.join("World (2)").join("region").join("r.0.0.mca")).unwrap(), "before");
+        assert!(!saves.join("World (2)").join(SESSION_LOCK).exists());
+        assert_eq!(fs::read_to_string(world.join("region").join("r.0.0.mca")).unwrap(), "after");
+        
+        assert_eq!(restore_world_backup(&first.path, &saves, true, false).unwrap(), "World");
+        assert_eq!(fs::read_to_string(world.join("region").join("r.0.0.mca")).unwrap(), "before");
+        assert!(!saves.join(".World.restoring").exists());
+        
+        let cutoff = chrono::Local::now().naive_local() + chrono::Duration::days(1);
+        let pruned = prune_world_backups(&backups, cutoff, false).unwrap();
+        assert_eq!(pruned.deleted, 3);
+        assert!(!backups.join("World").exists());
+    }
+}
@@ -32,3 +32,6 @@ pub mod rustwiz;
 pub mod logging;
 pub mod meta;
 pub mod files;
+pub mod connectivity;
+pub mod storage;
+pub mod profiles;
@@ -138,41 +138,14 @@ impl FabricInstaller {
         let profile: FabricLoaderResponse = response.json().await?;
         Ok(profile)
     }
-}
-
-impl Default for FabricInstaller {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-#[async_trait]
-impl ModloaderInstaller for FabricInstaller {
-    fn loader_type(&self) -> ModLoaderType {
-        ModLoaderType::Fabric
-    }
-
-    async fn get_versions(&self, minecraft_version: &str) -> Result<Vec<String>> {
-        let versions = get_fabric_versions(minecraft_version).await?;
-        Ok(versions.into_iter().map(|v| v.version).collect())
-    }
-
-    async fn install(
-        &self,
-        minecraft_version: &str,
-        loader_version: &str,
-        libraries_dir: &PathBuf,
-        progress: Option<ProgressCallback>,
-    ) -> Result<ModloaderProfile> {
-        if let Some(ref callback) = progress {
-            callback(InstallProgress::FetchingMetadata);
-        }
 
+    /// Build the Fabric profile without downloading any libraries
+    async fn resolve_profile(&self, minecraft_version: &str, loader_version: &str) -> Result<ModloaderProfile> {
         // Fetch the Fabric profile
         let fabric_profile = self.fetch_profile(minecraft_version, loader_version).await?;
         
         info!(
-            "Installing Fabric {} for Minecraft {}",
+            "Resolving Fabric {} for Minecraft {}",
             loader_version, minecraft_version
         );
         debug!("Fabric main class: {}", fabric_profile.launcher_meta.main_class.client());
@@ -250,6 +223,40 @@ impl ModloaderInstaller for FabricInstaller {
             });
         }
 
+        Ok(profile)
+    }
+}
+
+impl Default for FabricInstaller {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ModloaderInstaller for FabricInstaller {
+    fn loader_type(&self) -> ModLoaderType {
+        ModLoaderType::Fabric
+    }
+
+    async fn get_versions(&self, minecraft_version: &str) -> Result<Vec<String>> {
+        let versions = get_fabric_versions(minecraft_version).await?;
+        Ok(versions.into_iter().map(|v| v.version).collect())
+    }
+
+    async fn install(
+        &self,
+        minecraft_version: &str,
+        loader_version: &str,
+        libraries_dir: &PathBuf,
+        progress: Option<ProgressCallback>,
+    ) -> Result<ModloaderProfile> {
+        if let Some(ref callback) = progress {
+            callback(InstallProgress::FetchingMetadata);
+        }
+
+        let profile = self.resolve_profile(minecraft_version, loader_version).await?;
+
         // Download all libraries
         download_modloader_libraries(&profile, libraries_dir, progress.as_ref()).await?;
 
@@ -261,6 +268,10 @@ impl ModloaderInstaller for FabricInstaller {
         Ok(profile)
     }
 
+    async fn resolve_libraries(&self, minecraft_version: &str, loader_version: &str) -> Result<Vec<ModloaderLibrary>> {
+        Ok(self.resolve_profile(minecraft_version, loader_version).await?.libraries)
+    }
+
     fn is_installed(&self, _minecraft_version: &str, loader_version: &str, libraries_dir: &PathBuf) -> bool {
         // Check if the main Fabric loader library exists
         let loader_path = format!(
@@ -301,6 +312,42 @@ pub async fn get_fabric_versions(minecraft_version: &str) -> Result<Vec<FabricVe
     Ok(fabric_versions)
 }
 
+/// Fabric installer release, used to build the server launcher URL
+#[derive(Debug, Deserialize)]
+struct FabricInstallerInfo {
+    version: String,
+    stable: bool,
+}
+
+/// Get the download URL of the executable Fabric server launcher
+/// (it fetches the vanilla server itself on first start)
+pub async fn get_server_launcher_url(minecraft_version: &str, loader_version: &str) -> Result<String> {
+    let client = reqwest::Client::new();
+    let response = client
+        .get(format!("{}/versions/installer", FABRIC_META_URL))
+        .header("User-Agent", format!("OxideLauncher/{}", env!("CARGO_PKG_VERSION")))
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        return Err(OxideError::Modloader(format!(
+            "Failed to fetch Fabric installer versions: HTTP {}",
+            response.status()
+        )));
+    }
+
+    let installers: Vec<FabricInstallerInfo> = response.json().await?;
+    let installer = installers.iter()
+        .find(|i| i.stable)
+        .or_else(|| installers.first())
+        .ok_or_else(|| OxideError::Modloader("No Fabric installer versions available".to_string()))?;
+    
+    Ok(format!(
+        "{}/versions/loader/{}/{}/{}/server/jar",
+        FABRIC_META_URL, minecraft_version, loader_version, installer.version
+    ))
+}
+
 /// Get the latest stable Fabric version for a Minecraft version
 #[allow(dead_code)] // Utility function for future auto-select feature
 pub async fn get_recommended_fabric(minecraft_version: &str) -> Result<Option<String>> {
@@ -34,6 +34,7 @@ use super::processor::{run_processors, Processor, ProcessorContext, ProcessorDat
 
 const FORGE_MAVEN_METADATA: &str = "https://files.minecraftforge.net/net/minecraftforge/forge/maven-metadata.json";
 const FORGE_MAVEN_URL: &str = "https://maven.minecraftforge.net";
+const FORGE_PROMOTIONS_URL: &str = "https://files.minecraftforge.net/net/minecraftforge/forge/promotions_slim.json";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ForgeVersion {
@@ -43,6 +44,19 @@ pub struct ForgeVersion {
     pub latest: bool,
 }
 
+/// The builds Forge promotes for a Minecraft version. A Minecraft version
+/// without a recommended build is still in beta on Forge's side.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ForgePromotions {
+    pub recommended: Option<String>,
+    pub latest: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ForgePromotionsResponse {
+    promos: std::collections::HashMap<String, String>,
+}
+
 // Forge version JSON structures (from installer JAR)
 #[derive(Debug, Deserialize)]
 struct ForgeVersionJson {
@@ -156,7 +170,7 @@ impl ForgeInstaller {
     }
 
     /// Get the installer URL for a Forge version
-    fn get_installer_url(&self, minecraft_version: &str, forge_version: &str) -> String {
+    pub fn get_installer_url(&self, minecraft_version: &str, forge_version: &str) -> String {
         // Forge version format can be:
         // - Just the forge version: "47.2.0"
         // - MC-Forge format: "1.20.1-47.2.0"
@@ -326,6 +340,34 @@ impl ForgeInstaller {
     }
 }
 
+/// Convert the libraries needed by the install processors into modloader libraries
+fn processor_libraries(libs: &[ForgeLibrary]) -> Vec<ModloaderLibrary> {
+    libs.iter().map(|lib| {
+        let mut modloader_lib = ModloaderLibrary {
+            name: lib.name.clone(),
+            url: None,
+            sha1: None,
+            size: None,
+            path: None,
+            natives: None,
+            rules: Vec::new(),
+        };
+        
+        if let Some(ref downloads) = lib.downloads {
+            if let Some(ref artifact) = downloads.artifact {
+                modloader_lib.url = Some(artifact.url.clone());
+                modloader_lib.sha1 = artifact.sha1.clone();
+                modloader_lib.size = artifact.size;
+                modloader_lib.path = Some(artifact.path.clone());
+            }
+        } else if let Some(ref url) = lib.url {
+            modloader_lib.url = Some(format!("{}{}", url, maven_to_path(&lib.name)));
+        }
+        
+        modloader_lib
+    }).collect()
+}
+
 impl Default for ForgeInstaller {
     fn default() -> Self {
         Self::new()
@@ -441,30 +483,7 @@ impl ModloaderInstaller for ForgeInstaller {
                     minecraft_version: minecraft_version.to_string(),
                     main_class: String::new(),
                     launcher_type: super::profile::LauncherType::Standard,
-                    libraries: proc_libs.iter().map(|lib| {
-                        let mut modloader_lib = ModloaderLibrary {
-                            name: lib.name.clone(),
-                            url: None,
-                            sha1: None,
-                            size: None,
-                            path: None,
-                            natives: None,
-                            rules: Vec::new(),
-                        };
-                        
-                        if let Some(ref downloads) = lib.downloads {
-                            if let Some(ref artifact) = downloads.artifact {
-                                modloader_lib.url = Some(artifact.url.clone());
-                                modloader_lib.sha1 = artifact.sha1.clone();
-                                modloader_lib.size = artifact.size;
-                                modloader_lib.path = Some(artifact.path.clone());
-                            }
-                        } else if let Some(ref url) = lib.url {
-                            modloader_lib.url = Some(format!("{}{}", url, maven_to_path(&lib.name)));
-                        }
-                        
-                        modloader_lib
-                    }).collect(),
+                    libraries: processor_libraries(proc_libs),
                     jvm_arguments: Vec::new(),
                     game_arguments: Vec::new(),
                     tweakers: Vec::new(),
@@ -552,6 +571,30 @@ impl ModloaderInstaller for ForgeInstaller {
         Ok(profile)
     }
 
+    async fn resolve_libraries(&self, minecraft_version: &str, loader_version: &str) -> Result<Vec<ModloaderLibrary>> {
+        // Only the installer JAR is fetched; it holds the version and install profiles
+        // A fresh directory per call, so concurrent plans don't share the installer
+        let temp_dir = tempfile::tempdir()?;
+        let installer_path = temp_dir.path().join("forge-installer.jar");
+
+        download_file(&self.get_installer_url(minecraft_version, loader_version), &installer_path, None).await?;
+
+        let version_json = self.extract_version_json(&installer_path);
+        let install_profile = self.extract_install_profile(&installer_path);
+        drop(temp_dir);
+
+        let mut libraries = self.build_profile(minecraft_version, loader_version, &version_json?).libraries;
+        if let Some(proc_libs) = install_profile?.and_then(|p| p.libraries) {
+            libraries.extend(processor_libraries(&proc_libs));
+        }
+
+        Ok(libraries)
+    }
+
+    fn installer_url(&self, minecraft_version: &str, loader_version: &str) -> Option<String> {
+        Some(self.get_installer_url(minecraft_version, loader_version))
+    }
+
     fn is_installed(&self, minecraft_version: &str, loader_version: &str, libraries_dir: &PathBuf) -> bool {
         // Check if the main Forge library exists
         let full_version = if loader_version.contains('-') {
@@ -585,16 +628,23 @@ pub async fn get_forge_versions(minecraft_version: &str) -> Result<Vec<ForgeVers
     }
 
     let metadata: std::collections::HashMap<String, Vec<String>> = response.json().await?;
+    let promotions = get_forge_promotions(minecraft_version).await.unwrap_or_default();
+    // Maven versions carry the Minecraft version prefix, promotions don't
+    let is_promoted = |forge_ver: &str, promoted: &Option<String>| {
+        promoted.as_deref().is_some_and(|p| {
+            forge_ver == p || forge_ver == format!("{}-{}", minecraft_version, p)
+        })
+    };
     
     let mut versions = Vec::new();
     
     if let Some(mc_versions) = metadata.get(minecraft_version) {
-        for (idx, forge_ver) in mc_versions.iter().enumerate() {
+        for forge_ver in mc_versions {
             versions.push(ForgeVersion {
                 version: forge_ver.clone(),
                 minecraft_version: minecraft_version.to_string(),
-                recommended: idx == 0,
-                latest: idx == 0,
+                recommended: is_promoted(forge_ver, &promotions.recommended),
+                latest: is_promoted(forge_ver, &promotions.latest),
             });
         }
     }
@@ -602,6 +652,30 @@ pub async fn get_forge_versions(minecraft_version: &str) -> Result<Vec<ForgeVers
     Ok(versions)
 }
 
+/// Fetch Forge's recommended and latest builds for a Minecraft version
+pub async fn get_forge_promotions(minecraft_version: &str) -> Result<ForgePromotions> {
+    let client = reqwest::Client::new();
+    let response = client
+        .get(FORGE_PROMOTIONS_URL)
+        .header("User-Agent", format!("OxideLauncher/{}", env!("CARGO_PKG_VERSION")))
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        return Err(OxideError::Modloader(format!(
+            "Failed to fetch Forge promotions: HTTP {}",
+            response.status()
+        )));
+    }
+
+    let mut promotions: ForgePromotionsResponse = response.json().await?;
+    
+    Ok(ForgePromotions {
+        recommended: promotions.promos.remove(&format!("{}-recommended", minecraft_version)),
+        latest: promotions.promos.remove(&format!("{}-latest", minecraft_version)),
+    })
+}
+
 /// Get the recommended Forge version for a Minecraft version
 #[allow(dead_code)] // Utility function for future auto-select feature
 pub async fn get_recommended_forge(minecraft_version: &str) -> Result<Option<String>> {
@@ -18,15 +18,17 @@
 //! You should have received a copy of the GNU General Public License
 //! along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use async_trait::async_trait;
+use futures::StreamExt;
+use serde::Serialize;
 use tracing::{info, debug, warn};
 
 use crate::core::config::Config;
 use crate::core::error::{Result};
-use crate::core::download::{download_file, download_file_verified};
+use crate::core::download::{download_file, download_file_verified, fetch_content_length};
 use crate::core::instance::ModLoaderType;
 use super::profile::{ModloaderProfile, ModloaderLibrary};
 
@@ -68,11 +70,58 @@ pub trait ModloaderInstaller: Send + Sync {
         progress: Option<ProgressCallback>,
     ) -> Result<ModloaderProfile>;
 
+    /// Resolve the libraries an install would need without downloading them
+    async fn resolve_libraries(&self, minecraft_version: &str, loader_version: &str) -> Result<Vec<ModloaderLibrary>>;
+
+    /// URL of an installer JAR the install downloads besides the libraries
+    fn installer_url(&self, _minecraft_version: &str, _loader_version: &str) -> Option<String> {
+        None
+    }
+
     /// Check if a version is installed
     #[allow(dead_code)] // Part of trait interface for future use
     fn is_installed(&self, minecraft_version: &str, loader_version: &str, libraries_dir: &PathBuf) -> bool;
 }
 
+/// Maven repositories the launcher already knows how to reach
+const STANDARD_REPOSITORIES: &[&str] = &[
+    "https://maven.fabricmc.net/",
+    "https://maven.quiltmc.org/",
+    "https://maven.minecraftforge.net/",
+    "https://maven.neoforged.net/",
+    "https://repo1.maven.org/maven2/",
+    "https://libraries.minecraft.net/",
+];
+
+/// A library an install would need, as resolved by [`plan_modloader_install`]
+#[derive(Debug, Clone, Serialize)]
+pub struct PlannedLibrary {
+    pub name: String,
+    pub url: String,
+    /// Download size in bytes, if the metadata or server reported one
+    pub size: Option<u64>,
+    /// Already present in the libraries directory
+    pub installed: bool,
+    /// Hosted outside the standard Maven repositories
+    pub non_standard_repo: bool,
+}
+
+/// What installing a modloader would download, resolved without downloading it
+#[derive(Debug, Clone, Serialize)]
+pub struct InstallPlan {
+    pub loader_type: ModLoaderType,
+    pub minecraft_version: String,
+    pub loader_version: String,
+    pub libraries: Vec<PlannedLibrary>,
+    /// Total bytes still to download, counting the installer JAR (Forge and
+    /// NeoForge) and only libraries with a known size
+    pub download_size: u64,
+    /// Number of missing libraries, or the installer JAR, whose size could not be determined
+    pub unknown_sizes: usize,
+    /// Libraries that must come from non-standard Maven repositories
+    pub non_standard_repos: Vec<String>,
+}
+
 /// Check if a library is a natives-only library (has no main JAR)
 /// These are libraries like lwjgl-platform, jinput-platform that only contain native files
 fn is_natives_only_library(name: &str) -> bool {
@@ -286,6 +335,90 @@ pub async fn install_modloader(
     Ok(profile)
 }
 
+/// Resolve the libraries and sizes a modloader install would need, without installing it
+pub async fn plan_modloader_install(
+    loader_type: ModLoaderType,
+    minecraft_version: &str,
+    loader_version: &str,
+    libraries_dir: &Path,
+) -> Result<InstallPlan> {
+    let installer = get_installer(loader_type);
+
+    info!(
+        "Planning {} {} for Minecraft {}",
+        loader_type.name(),
+        loader_version,
+        minecraft_version
+    );
+
+    let libraries = installer
+        .resolve_libraries(minecraft_version, loader_version)
+        .await?;
+
+    // HEAD probes share the download limit so a large loader doesn't flood the server
+    let max_concurrent = Config::load().unwrap_or_default().network.max_concurrent_downloads.max(1);
+    let planned: Vec<_> = libraries
+        .into_iter()
+        .filter(|lib| lib.applies_to_current_os() && !is_natives_only_library(&lib.name))
+        .map(|lib| {
+            let url = resolve_library_url(&lib);
+            let installed = libraries_dir.join(lib.get_path()).exists();
+            async move {
+                // Ask the server for sizes the metadata didn't include
+                let size = match lib.size {
+                    Some(size) => Some(size),
+                    None if !installed => fetch_content_length(&url).await,
+                    None => None,
+                };
+                PlannedLibrary {
+                    name: lib.name,
+                    non_standard_repo: !is_standard_repository(&url),
+                    url,
+                    size,
+                    installed,
+                }
+            }
+        })
+        .collect();
+    let planned: Vec<PlannedLibrary> = futures::stream::iter(planned)
+        .buffered(max_concurrent)
+        .collect()
+        .await;
+
+    // Installing downloads the installer again, so it counts like a missing library
+    let installer_size = match installer.installer_url(minecraft_version, loader_version) {
+        Some(url) => Some(fetch_content_length(&url).await),
+        None => None,
+    };
+
+    let missing = planned.iter().filter(|lib| !lib.installed);
+    let download_size = missing.clone().filter_map(|lib| lib.size).sum::<u64>()
+        + installer_size.flatten().unwrap_or(0);
+    let unknown_sizes = missing.filter(|lib| lib.size.is_none()).count()
+        + usize::from(matches!(installer_size, Some(None)));
+    let non_standard_repos = planned
+        .iter()
+        .filter(|lib| lib.non_standard_repo)
+        .map(|lib| lib.name.clone())
+        .collect();
+
+    Ok(InstallPlan {
+        loader_type,
+        minecraft_version: minecraft_version.to_string(),
+        loader_version: loader_version.to_string(),
+        libraries: planned,
+        download_size,
+        unknown_sizes,
+        non_standard_repos,
+    })
+}
+
+/// Check whether a resolved library URL points at one of the standard repositories
+fn is_standard_repository(url: &str) -> bool {
+    let url = url.replacen("http://", "https://", 1);
+    STANDARD_REPOSITORIES.iter().any(|repo| url.starts_with(repo))
+}
+
 /// Check if all libraries for a profile are downloaded
 #[allow(dead_code)] // Utility for future pre-launch validation
 pub fn check_libraries_installed(profile: &ModloaderProfile, libraries_dir: &PathBuf) -> bool {
@@ -316,3 +449,36 @@ pub fn get_missing_libraries<'a>(profile: &'a ModloaderProfile, libraries_dir: &
         })
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn library(name: &str, url: Option<&str>) -> ModloaderLibrary {
+        ModloaderLibrary {
+            name: name.to_string(),
+            url: url.map(str::to_string),
+            sha1: None,
+            size: None,
+            path: None,
+            natives: None,
+            rules: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn default_urls_are_standard_repositories() {
+        for name in ["net.fabricmc:fabric-loader:0.15.0", "com.mojang:brigadier:1.0.18", "org.ow2.asm:asm:9.6"] {
+            assert!(is_standard_repository(&resolve_library_url(&library(name, None))));
+        }
+    }
+
+    #[test]
+    fn flags_non_standard_repositories() {
+        let lib = library("com.mumfrey:liteloader:1.12.2", Some("http://dl.liteloader.com/versions/"));
+        assert!(!is_standard_repository(&resolve_library_url(&lib)));
+        
+        let lib = library("org.spongepowered:mixin:0.8.5", Some("http://repo1.maven.org/maven2/"));
+        assert!(is_standard_repository(&resolve_library_url(&lib)));
+    }
+}
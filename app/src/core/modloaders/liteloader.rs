@@ -86,36 +86,9 @@ impl LiteLoaderInstaller {
             client: reqwest::Client::new(),
         }
     }
-}
-
-impl Default for LiteLoaderInstaller {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-#[async_trait]
-impl ModloaderInstaller for LiteLoaderInstaller {
-    fn loader_type(&self) -> ModLoaderType {
-        ModLoaderType::LiteLoader
-    }
-
-    async fn get_versions(&self, minecraft_version: &str) -> Result<Vec<String>> {
-        let versions = get_liteloader_versions(minecraft_version).await?;
-        Ok(versions.into_iter().map(|v| v.version).collect())
-    }
-
-    async fn install(
-        &self,
-        minecraft_version: &str,
-        loader_version: &str,
-        libraries_dir: &PathBuf,
-        progress: Option<ProgressCallback>,
-    ) -> Result<ModloaderProfile> {
-        if let Some(ref callback) = progress {
-            callback(InstallProgress::FetchingMetadata);
-        }
 
+    /// Build the LiteLoader profile without downloading any libraries
+    async fn resolve_profile(&self, minecraft_version: &str, loader_version: &str) -> Result<ModloaderProfile> {
         warn!("LiteLoader is a legacy modloader and may not work correctly");
 
         // Fetch LiteLoader metadata
@@ -190,6 +163,40 @@ impl ModloaderInstaller for LiteLoaderInstaller {
             }
         }
 
+        Ok(profile)
+    }
+}
+
+impl Default for LiteLoaderInstaller {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ModloaderInstaller for LiteLoaderInstaller {
+    fn loader_type(&self) -> ModLoaderType {
+        ModLoaderType::LiteLoader
+    }
+
+    async fn get_versions(&self, minecraft_version: &str) -> Result<Vec<String>> {
+        let versions = get_liteloader_versions(minecraft_version).await?;
+        Ok(versions.into_iter().map(|v| v.version).collect())
+    }
+
+    async fn install(
+        &self,
+        minecraft_version: &str,
+        loader_version: &str,
+        libraries_dir: &PathBuf,
+        progress: Option<ProgressCallback>,
+    ) -> Result<ModloaderProfile> {
+        if let Some(ref callback) = progress {
+            callback(InstallProgress::FetchingMetadata);
+        }
+
+        let profile = self.resolve_profile(minecraft_version, loader_version).await?;
+
         // Download libraries
         download_modloader_libraries(&profile, libraries_dir, progress.as_ref()).await?;
 
@@ -201,6 +208,10 @@ impl ModloaderInstaller for LiteLoaderInstaller {
         Ok(profile)
     }
 
+    async fn resolve_libraries(&self, minecraft_version: &str, loader_version: &str) -> Result<Vec<ModloaderLibrary>> {
+        Ok(self.resolve_profile(minecraft_version, loader_version).await?.libraries)
+    }
+
     fn is_installed(&self, minecraft_version: &str, loader_version: &str, libraries_dir: &PathBuf) -> bool {
         let loader_path = format!(
             "com/mumfrey/liteloader/{}/liteloader-{}.jar",
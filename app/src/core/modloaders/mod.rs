@@ -26,9 +26,10 @@ pub mod liteloader;
 pub mod profile;
 pub mod installer;
 pub mod processor;
+pub mod version_range;
 
 // Re-export commonly used items
 // Note: Version listing is now handled by meta server (commands/versions.rs)
 // The get_*_versions functions in each module are used internally by installers
 pub use profile::{ModloaderProfile, LauncherType};
-pub use installer::{InstallProgress, install_modloader, get_installer};
+pub use installer::{InstallProgress, InstallPlan, install_modloader, plan_modloader_install, get_installer};
@@ -136,7 +136,7 @@ impl NeoForgeInstaller {
     }
 
     /// Get the installer URL for a NeoForge version
-    fn get_installer_url(&self, version: &str) -> String {
+    pub fn get_installer_url(&self, version: &str) -> String {
         format!(
             "{}/net/neoforged/neoforge/{}/neoforge-{}-installer.jar",
             NEOFORGE_MAVEN_BASE, version, version
@@ -243,6 +243,34 @@ impl NeoForgeInstaller {
     }
 }
 
+/// Convert the libraries needed by the install processors into modloader libraries
+fn processor_libraries(libs: &[NeoForgeLibrary]) -> Vec<ModloaderLibrary> {
+    libs.iter().map(|lib| {
+        let mut modloader_lib = ModloaderLibrary {
+            name: lib.name.clone(),
+            url: None,
+            sha1: None,
+            size: None,
+            path: None,
+            natives: None,
+            rules: Vec::new(),
+        };
+        
+        if let Some(ref downloads) = lib.downloads {
+            if let Some(ref artifact) = downloads.artifact {
+                modloader_lib.url = Some(artifact.url.clone());
+                modloader_lib.sha1 = artifact.sha1.clone();
+                modloader_lib.size = artifact.size;
+                modloader_lib.path = Some(artifact.path.clone());
+            }
+        } else if let Some(ref url) = lib.url {
+            modloader_lib.url = Some(format!("{}{}", url, maven_to_path(&lib.name)));
+        }
+        
+        modloader_lib
+    }).collect()
+}
+
 impl Default for NeoForgeInstaller {
     fn default() -> Self {
         Self::new()
@@ -342,30 +370,7 @@ impl ModloaderInstaller for NeoForgeInstaller {
                     minecraft_version: minecraft_version.to_string(),
                     main_class: String::new(),
                     launcher_type: super::profile::LauncherType::Standard,
-                    libraries: proc_libs.iter().map(|lib| {
-                        let mut modloader_lib = ModloaderLibrary {
-                            name: lib.name.clone(),
-                            url: None,
-                            sha1: None,
-                            size: None,
-                            path: None,
-                            natives: None,
-                            rules: Vec::new(),
-                        };
-                        
-                        if let Some(ref downloads) = lib.downloads {
-                            if let Some(ref artifact) = downloads.artifact {
-                                modloader_lib.url = Some(artifact.url.clone());
-                                modloader_lib.sha1 = artifact.sha1.clone();
-                                modloader_lib.size = artifact.size;
-                                modloader_lib.path = Some(artifact.path.clone());
-                            }
-                        } else if let Some(ref url) = lib.url {
-                            modloader_lib.url = Some(format!("{}{}", url, maven_to_path(&lib.name)));
-                        }
-                        
-                        modloader_lib
-                    }).collect(),
+                    libraries: processor_libraries(proc_libs),
                     jvm_arguments: Vec::new(),
                     game_arguments: Vec::new(),
                     tweakers: Vec::new(),
@@ -453,6 +458,30 @@ impl ModloaderInstaller for NeoForgeInstaller {
         Ok(profile)
     }
 
+    async fn resolve_libraries(&self, minecraft_version: &str, loader_version: &str) -> Result<Vec<ModloaderLibrary>> {
+        // Only the installer JAR is fetched; it holds the version and install profiles
+        // A fresh directory per call, so concurrent plans don't share the installer
+        let temp_dir = tempfile::tempdir()?;
+        let installer_path = temp_dir.path().join("neoforge-installer.jar");
+
+        download_file(&self.get_installer_url(loader_version), &installer_path, None).await?;
+
+        let version_json = self.extract_version_json(&installer_path);
+        let install_profile = self.extract_install_profile(&installer_path);
+        drop(temp_dir);
+
+        let mut libraries = self.build_profile(minecraft_version, loader_version, &version_json?).libraries;
+        if let Some(proc_libs) = install_profile?.and_then(|p| p.libraries) {
+            libraries.extend(processor_libraries(&proc_libs));
+        }
+
+        Ok(libraries)
+    }
+
+    fn installer_url(&self, _minecraft_version: &str, loader_version: &str) -> Option<String> {
+        Some(self.get_installer_url(loader_version))
+    }
+
     fn is_installed(&self, _minecraft_version: &str, loader_version: &str, libraries_dir: &PathBuf) -> bool {
         // Check if the main NeoForge library exists
         let loader_path = format!(
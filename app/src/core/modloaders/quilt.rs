@@ -30,6 +30,9 @@ use super::installer::{ModloaderInstaller, InstallProgress, ProgressCallback, do
 
 const QUILT_META_URL: &str = "https://meta.quiltmc.org/v3";
 
+/// Latest universal Quilt installer, used for server installs
+pub const QUILT_INSTALLER_URL: &str = "https://quiltmc.org/api/v1/download-latest-installer/java-universal";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QuiltVersion {
     pub version: String,
@@ -136,41 +139,14 @@ impl QuiltInstaller {
         let profile: QuiltLoaderResponse = response.json().await?;
         Ok(profile)
     }
-}
-
-impl Default for QuiltInstaller {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-#[async_trait]
-impl ModloaderInstaller for QuiltInstaller {
-    fn loader_type(&self) -> ModLoaderType {
-        ModLoaderType::Quilt
-    }
-
-    async fn get_versions(&self, minecraft_version: &str) -> Result<Vec<String>> {
-        let versions = get_quilt_versions(minecraft_version).await?;
-        Ok(versions.into_iter().map(|v| v.version).collect())
-    }
-
-    async fn install(
-        &self,
-        minecraft_version: &str,
-        loader_version: &str,
-        libraries_dir: &PathBuf,
-        progress: Option<ProgressCallback>,
-    ) -> Result<ModloaderProfile> {
-        if let Some(ref callback) = progress {
-            callback(InstallProgress::FetchingMetadata);
-        }
 
+    /// Build the Quilt profile without downloading any libraries
+    async fn resolve_profile(&self, minecraft_version: &str, loader_version: &str) -> Result<ModloaderProfile> {
         // Fetch the Quilt profile
         let quilt_profile = self.fetch_profile(minecraft_version, loader_version).await?;
         
         info!(
-            "Installing Quilt {} for Minecraft {}",
+            "Resolving Quilt {} for Minecraft {}",
             loader_version, minecraft_version
         );
         debug!("Quilt main class: {}", quilt_profile.launcher_meta.main_class.client());
@@ -264,6 +240,40 @@ impl ModloaderInstaller for QuiltInstaller {
 
         debug!("Total libraries in profile: {}", profile.libraries.len());
 
+        Ok(profile)
+    }
+}
+
+impl Default for QuiltInstaller {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ModloaderInstaller for QuiltInstaller {
+    fn loader_type(&self) -> ModLoaderType {
+        ModLoaderType::Quilt
+    }
+
+    async fn get_versions(&self, minecraft_version: &str) -> Result<Vec<String>> {
+        let versions = get_quilt_versions(minecraft_version).await?;
+        Ok(versions.into_iter().map(|v| v.version).collect())
+    }
+
+    async fn install(
+        &self,
+        minecraft_version: &str,
+        loader_version: &str,
+        libraries_dir: &PathBuf,
+        progress: Option<ProgressCallback>,
+    ) -> Result<ModloaderProfile> {
+        if let Some(ref callback) = progress {
+            callback(InstallProgress::FetchingMetadata);
+        }
+
+        let profile = self.resolve_profile(minecraft_version, loader_version).await?;
+
         // Download all libraries
         download_modloader_libraries(&profile, libraries_dir, progress.as_ref()).await?;
 
@@ -275,6 +285,10 @@ impl ModloaderInstaller for QuiltInstaller {
         Ok(profile)
     }
 
+    async fn resolve_libraries(&self, minecraft_version: &str, loader_version: &str) -> Result<Vec<ModloaderLibrary>> {
+        Ok(self.resolve_profile(minecraft_version, loader_version).await?.libraries)
+    }
+
     fn is_installed(&self, _minecraft_version: &str, loader_version: &str, libraries_dir: &PathBuf) -> bool {
         // Check if the main Quilt loader library exists
         let loader_path = format!(
@@ -0,0 +1,300 @@
+//! Loader version ranges declared by mods, and picking a loader version that satisfies them.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::cmp::Ordering;
+use serde::Serialize;
+
+/// A mod's declared loader requirement
+#[derive(Debug, Clone)]
+pub struct LoaderRequirement {
+    /// Mod name shown in conflict messages
+    pub mod_name: String,
+    /// Range as declared in the mod's metadata
+    pub range: String,
+}
+
+/// Recommended loader version for a set of mods
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LoaderRecommendation {
+    /// Best version satisfying every mod, or None when no single version does
+    pub version: Option<String>,
+    /// Whether `version` is the loader's recommended build
+    pub recommended: bool,
+    /// Mods that rule out the closest candidate when there is a conflict
+    pub conflicts: Vec<String>,
+}
+
+/// Compare two loader versions segment by segment.
+///
+/// Numeric segments compare as numbers. When one version is a prefix of the
+/// other, a trailing pre-release tag (`0.26.0-beta.1`) sorts before the
+/// plain release while extra numbers (`47.1.1`) sort after it.
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+    let a_parts = version_segments(a);
+    let b_parts = version_segments(b);
+    
+    for (x, y) in a_parts.iter().zip(&b_parts) {
+        let ordering = match (x.parse::<u64>(), y.parse::<u64>()) {
+            (Ok(x), Ok(y)) => x.cmp(&y),
+            (Ok(_), Err(_)) => Ordering::Greater,
+            (Err(_), Ok(_)) => Ordering::Less,
+            (Err(_), Err(_)) => x.cmp(y),
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+    
+    let extra_rank = |rest: &[&str]| match rest.first() {
+        None => Ordering::Equal,
+        Some(part) if part.parse::<u64>().is_ok() => Ordering::Greater,
+        Some(_) => Ordering::Less,
+    };
+    match a_parts.len().cmp(&b_parts.len()) {
+        Ordering::Greater => extra_rank(&a_parts[b_parts.len()..]),
+        Ordering::Less => extra_rank(&b_parts[a_parts.len()..]).reverse(),
+        Ordering::Equal => Ordering::Equal,
+    }
+}
+
+fn version_segments(version: &str) -> Vec<&str> {
+    version.trim()
+        .split(['.', '-', '+'])
+        .filter(|part| !part.is_empty())
+        .collect()
+}
+
+/// Whether a loader version satisfies a declared range.
+///
+/// Understands Maven ranges as used by Forge and NeoForge (`[47.1,)`,
+/// `[1,2),[3,4)`), and Fabric/Quilt predicates (`>=0.14`, `~0.15.1`,
+/// `^0.14`, `0.14.x`, space-separated conjunctions). Alternatives may be
+/// joined with ` || `. A bare Maven version is a soft requirement, treated as
+/// a minimum. Ranges that can't be parsed are assumed to be satisfied.
+pub fn version_satisfies(version: &str, range: &str) -> bool {
+    range.split("||").any(|alternative| {
+        let alternative = alternative.trim();
+        if alternative.starts_with('[') || alternative.starts_with('(') {
+            maven_range_satisfies(version, alternative)
+        } else {
+            alternative.split_whitespace().all(|predicate| predicate_satisfies(version, predicate))
+        }
+    })
+}
+
+/// Match a Maven version range, which may be a union of intervals
+fn maven_range_satisfies(version: &str, range: &str) -> bool {
+    let mut intervals = Vec::new();
+    let mut start = 0;
+    for (i, c) in range.char_indices() {
+        if c == ']' || c == ')' {
+            intervals.push(range[start..=i].trim_start_matches(',').trim());
+            start = i + 1;
+        }
+    }
+    if intervals.is_empty() {
+        return true;
+    }
+    
+    intervals.iter().any(|interval| {
+        let (Some(open), Some(close)) = (interval.chars().next(), interval.chars().last()) else {
+            return true;
+        };
+        if interval.len() < 2 || !matches!(open, '[' | '(') {
+            return true;
+        }
+        let inner = &interval[1..interval.len() - 1];
+        let (lower, upper) = match inner.split_once(',') {
+            Some((lower, upper)) => (lower.trim(), upper.trim()),
+            // `[1.0]` pins an exact version
+            None => (inner.trim(), inner.trim()),
+        };
+        
+        let above = lower.is_empty() || match compare_versions(version, lower) {
+            Ordering::Greater => true,
+            Ordering::Equal => open == '[',
+            Ordering::Less => false,
+        };
+        let below = upper.is_empty() || match compare_versions(version, upper) {
+            Ordering::Less => true,
+            Ordering::Equal => close == ']',
+            Ordering::Greater => false,
+        };
+        above && below
+    })
+}
+
+/// Match a single Fabric-style predicate
+fn predicate_satisfies(version: &str, predicate: &str) -> bool {
+    if predicate == "*" || predicate.is_empty() {
+        return true;
+    }
+    
+    let (operator, target) = ["<=", ">=", "<", ">", "=", "~", "^"]
+        .iter()
+        .find_map(|op| predicate.strip_prefix(op).map(|target| (*op, target)))
+        .unwrap_or(("", predicate));
+    
+    // `0.14.x` matches everything starting with 0.14
+    if let Some(prefix) = target.strip_suffix(".x").or_else(|| target.strip_suffix(".*")) {
+        return version_segments(version).starts_with(&version_segments(prefix));
+    }
+    
+    let ordering = compare_versions(version, target);
+    match operator {
+        ">=" => ordering != Ordering::Less,
+        "<=" => ordering != Ordering::Greater,
+        ">" => ordering == Ordering::Greater,
+        "<" => ordering == Ordering::Less,
+        "~" | "^" => {
+            let segments = version_segments(target);
+            // ~ allows patch updates within the minor version, ^ within the major
+            let keep = if operator == "~" { 2 } else { 1 };
+            ordering != Ordering::Less
+                && version_segments(version).starts_with(&segments[..keep.min(segments.len())])
+        }
+        _ => ordering == Ordering::Equal,
+    }
+}
+
+/// Whether `version` is one of the versions passed when moving from `from`
+/// to `to`: newer than the older end, up to and including the newer one.
+/// Works the same for downgrades, with the ends swapped.
+pub fn in_version_range(version: &str, from: &str, to: &str) -> bool {
+    let (lower, upper) = if compare_versions(from, to) == Ordering::Greater {
+        (to, from)
+    } else {
+        (from, to)
+    };
+    
+    compare_versions(version, lower) == Ordering::Greater
+        && compare_versions(version, upper) != Ordering::Greater
+}
+
+/// Pick the loader version to use for a set of mods.
+///
+/// `versions` is the loader's version list, newest first, with each entry's
+/// recommended flag. The loader's recommended build wins if it satisfies
+/// every mod; otherwise the newest version that does. When none does, the
+/// version satisfying the most mods is used to report which mods conflict.
+pub fn recommend_loader_version(
+    versions: &[(String, bool)],
+    requirements: &[LoaderRequirement],
+) -> LoaderRecommendation {
+    let rejecting = |version: &str| -> Vec<&LoaderRequirement> {
+        requirements.iter().filter(|req| !version_satisfies(version, &req.range)).collect()
+    };
+    
+    let compatible: Vec<&(String, bool)> = versions.iter()
+        .filter(|(version, _)| rejecting(version).is_empty())
+        .collect();
+    
+    if let Some((version, recommended)) = compatible.iter()
+        .find(|(_, recommended)| *recommended)
+        .or_else(|| compatible.first())
+    {
+        return LoaderRecommendation {
+            version: Some(version.clone()),
+            recommended: *recommended,
+            conflicts: Vec::new(),
+        };
+    }
+    
+    let closest = versions.iter()
+        .map(|(version, _)| (version, rejecting(version)))
+        .min_by_key(|(_, rejected)| rejected.len());
+    
+    let conflicts = match closest {
+        Some((version, rejected)) => rejected.iter()
+            .map(|req| format!("{} requires {} (closest match is {})", req.mod_name, req.range, version))
+            .collect(),
+        None => vec!["No loader versions are available for this Minecraft version".to_string()],
+    };
+    
+    LoaderRecommendation {
+        version: None,
+        recommended: false,
+        conflicts,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn requirement(mod_name: &str, range: &str) -> LoaderRequirement {
+        LoaderRequirement { mod_name: mod_name.to_string(), range: range.to_string() }
+    }
+    
+    #[test]
+    fn test_compare_versions() {
+        assert_eq!(compare_versions("0.15.11", "0.15.2"), Ordering::Greater);
+        assert_eq!(compare_versions("47.1", "47.1.0"), Ordering::Less);
+        assert_eq!(compare_versions("0.26.0-beta.1", "0.26.0"), Ordering::Less);
+        assert_eq!(compare_versions("20.4.237", "20.4.237"), Ordering::Equal);
+    }
+    
+    #[test]
+    fn test_range_formats() {
+        assert!(version_satisfies("47.2.0", "[47.1,)"));
+        assert!(!version_satisfies("46.0.1", "[47.1,)"));
+        assert!(!version_satisfies("48.0.0", "[47,48)"));
+        assert!(version_satisfies("3.5", "[1,2),[3,4)"));
+        assert!(version_satisfies("0.15.11", ">=0.14.21"));
+        assert!(version_satisfies("0.14.9", "~0.14.2"));
+        assert!(!version_satisfies("0.15.0", "~0.14.2"));
+        assert!(version_satisfies("0.14.22", "0.14.x"));
+        assert!(!version_satisfies("0.16.0", ">=0.14 <0.16"));
+        assert!(version_satisfies("0.16.0", "<0.15 || >=0.16"));
+        assert!(version_satisfies("1.0", "*"));
+    }
+    
+    #[test]
+    fn test_recommendation_prefers_recommended_then_newest() {
+        let versions = vec![
+            ("0.16.0".to_string(), false),
+            ("0.15.11".to_string(), true),
+            ("0.14.21".to_string(), false),
+        ];
+        
+        let pick = recommend_loader_version(&versions, &[requirement("A", ">=0.14")]);
+        assert_eq!(pick.version.as_deref(), Some("0.15.11"));
+        assert!(pick.recommended);
+        
+        let pick = recommend_loader_version(&versions, &[requirement("A", ">=0.16")]);
+        assert_eq!(pick.version.as_deref(), Some("0.16.0"));
+        
+        let pick = recommend_loader_version(&versions, &[requirement("A", ">=0.16"), requirement("B", "<0.15")]);
+        assert_eq!(pick.version, None);
+        assert_eq!(pick.conflicts.len(), 1);
+    }
+    
+    #[test]
+    fn test_version_range_excludes_current_and_includes_target() {
+        assert!(in_version_range("47.1.3", "47.1.0", "47.2.0"));
+        assert!(in_version_range("47.2.0", "47.1.0", "47.2.0"));
+        assert!(!in_version_range("47.1.0", "47.1.0", "47.2.0"));
+        assert!(!in_version_range("47.2.1", "47.1.0", "47.2.0"));
+        
+        // A downgrade lists the versions being left behind
+        assert!(in_version_range("0.16.0", "0.16.0", "0.15.11"));
+        assert!(!in_version_range("0.15.11", "0.16.0", "0.15.11"));
+    }
+}
@@ -20,12 +20,17 @@
 
 #![allow(dead_code)] // API client will be used as features are completed
 
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use once_cell::sync::Lazy;
 use serde::Deserialize;
 use crate::core::error::{OxideError, Result};
 use crate::core::config::Config;
 use super::types::*;
+use super::modrinth::Category;
 
-const CURSEFORGE_API_URL: &str = "https://api.curseforge.com/v1";
+pub(crate) const CURSEFORGE_API_URL: &str = "https://api.curseforge.com/v1";
 const MINECRAFT_GAME_ID: u32 = 432;
 
 /// CurseForge mod class IDs
@@ -37,6 +42,27 @@ mod class_ids {
     pub const SHADERS: u32 = 6552;
 }
 
+/// How long fetched category lists are reused; they rarely change
+const CATEGORY_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// A class's categories as (id, category) pairs and when they were fetched
+type CachedCategories = (Instant, Vec<(u32, Category)>);
+
+/// Category lists keyed by class ID
+static CATEGORY_CACHE: Lazy<Mutex<HashMap<u32, CachedCategories>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Get the CurseForge class ID that holds a resource type
+fn class_id_for(resource_type: Option<ResourceType>) -> u32 {
+    match resource_type {
+        Some(ResourceType::Mod) => class_ids::MODS,
+        Some(ResourceType::Modpack) => class_ids::MODPACKS,
+        Some(ResourceType::ResourcePack) => class_ids::RESOURCE_PACKS,
+        Some(ResourceType::ShaderPack) => class_ids::SHADERS,
+        _ => class_ids::MODS,
+    }
+}
+
 /// CurseForge API client
 pub struct CurseForgeClient {
     client: reqwest::Client,
@@ -71,13 +97,7 @@ impl CurseForgeClient {
 
     /// Search for mods
     pub async fn search(&self, query: &SearchQuery) -> Result<SearchResults> {
-        let class_id = match query.resource_type {
-            Some(ResourceType::Mod) => class_ids::MODS,
-            Some(ResourceType::Modpack) => class_ids::MODPACKS,
-            Some(ResourceType::ResourcePack) => class_ids::RESOURCE_PACKS,
-            Some(ResourceType::ShaderPack) => class_ids::SHADERS,
-            _ => class_ids::MODS,
-        };
+        let class_id = class_id_for(query.resource_type);
         
         // CurseForge API max pageSize is 50
         let page_size = query.limit.min(50);
@@ -106,9 +126,7 @@ impl CurseForgeClient {
         
         // Add category filter - convert category names to CurseForge category IDs
         if !query.categories.is_empty() {
-            let category_ids: Vec<u32> = query.categories.iter()
-                .filter_map(|cat| get_mod_category_id(cat))
-                .collect();
+            let category_ids = self.category_ids(class_id, &query.categories).await;
             if !category_ids.is_empty() {
                 // CurseForge API supports categoryIds parameter for multiple categories
                 let ids_str = format!("[{}]", category_ids.iter()
@@ -176,6 +194,28 @@ impl CurseForgeClient {
         Ok(response.data.into_iter().map(|f| f.into_version(mod_id)).collect())
     }
 
+    /// Get one page of a mod's files for every game version and loader.
+    /// CurseForge returns at most 50 files per page.
+    pub async fn get_files_page(&self, mod_id: u32, offset: u32, limit: u32) -> Result<VersionPage> {
+        let limit = limit.clamp(1, 50);
+        let params = [("index", offset.to_string()), ("pageSize", limit.to_string())];
+        
+        let response: CurseForgeFilesResponse = self.request(reqwest::Method::GET, &format!("/mods/{}/files", mod_id))?
+            .query(&params)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        
+        let versions: Vec<ProjectVersion> = response.data.into_iter().map(|f| f.into_version(mod_id)).collect();
+        let total = response.pagination
+            .map(|p| p.total_count)
+            .unwrap_or(offset + versions.len() as u32);
+        
+        Ok(VersionPage { versions, total, offset, limit })
+    }
+
     /// Get a specific file
     pub async fn get_file(&self, mod_id: u32, file_id: u32) -> Result<ProjectVersion> {
         let response: CurseForgeFileResponse = self.request(reqwest::Method::GET, &format!("/mods/{}/files/{}", mod_id, file_id))?
@@ -187,15 +227,20 @@ impl CurseForgeClient {
         Ok(response.data.into_version(mod_id))
     }
 
-    /// Get download URL for a file
+    /// Get download URL for a file. An empty URL means the author has
+    /// disabled third-party downloads and the file must be fetched manually.
     pub async fn get_download_url(&self, mod_id: u32, file_id: u32) -> Result<String> {
-        let response: CurseForgeDownloadUrlResponse = self.request(reqwest::Method::GET, &format!("/mods/{}/files/{}/download-url", mod_id, file_id))?
+        let response = self.request(reqwest::Method::GET, &format!("/mods/{}/files/{}/download-url", mod_id, file_id))?
             .send()
-            .await?
+            .await?;
+        if response.status() == reqwest::StatusCode::FORBIDDEN {
+            return Ok(String::new());
+        }
+        let response: CurseForgeDownloadUrlResponse = response.error_for_status()?
             .json()
             .await?;
         
-        Ok(response.data)
+        Ok(response.data.unwrap_or_default())
     }
 
     /// Get mod description (full HTML body)
@@ -209,6 +254,75 @@ impl CurseForgeClient {
         Ok(response.data)
     }
 
+    /// Get the categories of a resource type
+    pub async fn get_categories(&self, resource_type: ResourceType) -> Result<Vec<Category>> {
+        let categories = self.class_categories(class_id_for(Some(resource_type))).await?;
+        Ok(categories.into_iter().map(|(_, category)| category).collect())
+    }
+
+    /// Get a class's categories with their IDs, reusing a recent result when available
+    async fn class_categories(&self, class_id: u32) -> Result<Vec<(u32, Category)>> {
+        {
+            let cache = CATEGORY_CACHE.lock().unwrap();
+            if let Some((fetched_at, categories)) = cache.get(&class_id) {
+                if fetched_at.elapsed() < CATEGORY_TTL {
+                    return Ok(categories.clone());
+                }
+            }
+        }
+        
+        let response: CurseForgeCategoriesResponse = self.request(reqwest::Method::GET, "/categories")?
+            .query(&[
+                ("gameId", MINECRAFT_GAME_ID.to_string()),
+                ("classId", class_id.to_string()),
+            ])
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        
+        let project_type = match class_id {
+            class_ids::MODPACKS => "modpack",
+            class_ids::RESOURCE_PACKS => "resourcepack",
+            class_ids::SHADERS => "shader",
+            _ => "mod",
+        };
+        let categories: Vec<(u32, Category)> = response.data.into_iter()
+            .filter(|c| !c.is_class.unwrap_or(false))
+            .map(|c| (c.id, Category {
+                name: c.name,
+                icon: c.icon_url.unwrap_or_default(),
+                project_type: project_type.to_string(),
+            }))
+            .collect();
+        
+        CATEGORY_CACHE.lock().unwrap().insert(class_id, (Instant::now(), categories.clone()));
+        Ok(categories)
+    }
+
+    /// Translate category names into CurseForge category IDs for a class.
+    /// Names come from the fetched category list; the built-in mod table
+    /// covers the case where that list can't be fetched.
+    async fn category_ids(&self, class_id: u32, names: &[String]) -> Vec<u32> {
+        let known = match self.class_categories(class_id).await {
+            Ok(categories) => categories,
+            Err(e) => {
+                tracing::warn!("Failed to fetch CurseForge categories: {}", e);
+                Vec::new()
+            }
+        };
+        
+        names.iter()
+            .filter_map(|name| {
+                known.iter()
+                    .find(|(_, c)| c.name.eq_ignore_ascii_case(name))
+                    .map(|(id, _)| *id)
+                    .or_else(|| (class_id == class_ids::MODS).then(|| get_mod_category_id(name)).flatten())
+            })
+            .collect()
+    }
+
     /// Get multiple mods by their IDs (batch request)
     /// Returns a map of mod_id -> class_id for routing files to correct folders
     pub async fn get_mods_class_ids(&self, mod_ids: &[u32]) -> Result<std::collections::HashMap<u32, u32>> {
@@ -361,6 +475,21 @@ struct CurseForgeCategory {
     slug: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct CurseForgeCategoriesResponse {
+    data: Vec<CurseForgeCategoryEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CurseForgeCategoryEntry {
+    id: u32,
+    name: String,
+    #[serde(rename = "iconUrl")]
+    icon_url: Option<String>,
+    #[serde(rename = "isClass")]
+    is_class: Option<bool>,
+}
+
 #[derive(Debug, Deserialize)]
 struct CurseForgeFileIndex {
     #[serde(rename = "gameVersion")]
@@ -494,6 +623,8 @@ impl From<CurseForgeMod> for Project {
 #[derive(Debug, Deserialize)]
 struct CurseForgeFilesResponse {
     data: Vec<CurseForgeFile>,
+    #[serde(default)]
+    pagination: Option<CurseForgePagination>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -600,7 +731,7 @@ impl CurseForgeFile {
 
 #[derive(Debug, Deserialize)]
 struct CurseForgeDownloadUrlResponse {
-    data: String,
+    data: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -22,8 +22,10 @@ pub mod modrinth;
 pub mod curseforge;
 pub mod types;
 pub mod mod_parser;
+pub mod suggestions;
 
 #[allow(unused_imports)] // Types will be used as features are completed
 pub use types::*;
 #[allow(unused_imports)] // Public API for mod parsing
-pub use mod_parser::{parse_mod_jar, ModDetails};
+pub use mod_parser::{mod_side, parse_mod_jar, ModDetails, ModSide};
+pub use suggestions::suggestions_for_loader;
@@ -49,6 +49,47 @@ pub struct ModDetails {
     pub icon_path: Option<String>,
     /// Detected mod loader type
     pub loader_type: Option<String>,
+    /// Side the mod declares it runs on ("client", "server" or "*"), if any
+    pub environment: Option<String>,
+    /// Loader the version range below applies to (`fabricloader`,
+    /// `quilt_loader`, `forge` or `neoforge`)
+    #[serde(default)]
+    pub loader_dependency: Option<String>,
+    /// Loader versions the mod accepts, as declared in its metadata. Fabric
+    /// and Quilt use predicates (`>=0.14.21`); Forge and NeoForge use Maven
+    /// ranges (`[47.1,)`). Alternatives are joined with ` || `.
+    #[serde(default)]
+    pub loader_version_range: Option<String>,
+}
+
+/// Which side a mod runs on, as declared by its jar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ModSide {
+    Client,
+    Server,
+    /// Needed on both the client and the server
+    Both,
+    /// The jar doesn't say; callers should assume it may be needed anywhere
+    Unknown,
+}
+
+impl ModDetails {
+    /// Side the mod declares it runs on
+    pub fn side(&self) -> ModSide {
+        match self.environment.as_deref() {
+            Some("client") => ModSide::Client,
+            // Quilt calls the server side "dedicated_server"
+            Some("server" | "dedicated_server") => ModSide::Server,
+            Some("*") => ModSide::Both,
+            _ => ModSide::Unknown,
+        }
+    }
+}
+
+/// Side of a mod jar; Unknown when it can't be read or declares none
+pub fn mod_side(path: &Path) -> ModSide {
+    parse_mod_jar(path).map(|details| details.side()).unwrap_or(ModSide::Unknown)
 }
 
 /// Parse mod details from a JAR file
@@ -109,6 +150,10 @@ fn try_parse_fabric<R: Read + std::io::Seek>(archive: &mut ZipArchive<R>) -> Opt
         }),
         icon_path: json.icon,
         loader_type: Some("Fabric".to_string()),
+        environment: json.environment,
+        loader_dependency: Some("fabricloader".to_string()),
+        loader_version_range: json.depends
+            .and_then(|depends| depends.get("fabricloader").and_then(version_predicates)),
     })
 }
 
@@ -144,12 +189,20 @@ fn try_parse_quilt<R: Read + std::io::Seek>(archive: &mut ZipArchive<R>) -> Opti
         }),
         icon_path: metadata.icon,
         loader_type: Some("Quilt".to_string()),
+        environment: json.minecraft.and_then(|m| m.environment),
+        loader_dependency: Some("quilt_loader".to_string()),
+        loader_version_range: loader.depends.iter()
+            .filter(|dep| dep.get("id").and_then(|id| id.as_str()) == Some("quilt_loader"))
+            .find_map(|dep| dep.get("versions").and_then(version_predicates)),
     })
 }
 
-/// Try to parse META-INF/mods.toml (modern Forge)
+/// Try to parse META-INF/mods.toml (modern Forge) or META-INF/neoforge.mods.toml
 fn try_parse_forge_toml<R: Read + std::io::Seek>(archive: &mut ZipArchive<R>) -> Option<ModDetails> {
-    let mut file = archive.by_name("META-INF/mods.toml").ok()?;
+    let (path, loader_type) = [("META-INF/mods.toml", "Forge"), ("META-INF/neoforge.mods.toml", "NeoForge")]
+        .into_iter()
+        .find(|(path, _)| archive.file_names().any(|name| name == *path))?;
+    let mut file = archive.by_name(path).ok()?;
     let mut contents = String::new();
     file.read_to_string(&mut contents).ok()?;
     
@@ -208,6 +261,37 @@ fn try_parse_forge_toml<R: Read + std::io::Seek>(archive: &mut ZipArchive<R>) ->
         .and_then(|v| v.as_str())
         .map(|s| s.to_string());
     
+    // mods.toml has no environment field. Newer Forge has `clientSideOnly`;
+    // otherwise `displayTest` says whether the server needs the mod
+    // (IGNORE_SERVER_VERSION: client-only, MATCH_VERSION: both sides).
+    // IGNORE_ALL_VERSION fits both server-only and optional mods, and a
+    // dependency's `side` only says where that dependency is needed, so
+    // neither says anything about the mod itself.
+    let client_side_only = toml.get("clientSideOnly").and_then(|v| v.as_bool()) == Some(true);
+    let display_test = first_mod.get("displayTest").and_then(|v| v.as_str());
+    let environment = if client_side_only || display_test == Some("IGNORE_SERVER_VERSION") {
+        Some("client".to_string())
+    } else if display_test == Some("MATCH_VERSION") {
+        Some("*".to_string())
+    } else {
+        None
+    };
+    
+    let loader_dep = toml.get("dependencies")
+        .and_then(|deps| deps.get(&mod_id))
+        .and_then(|deps| deps.as_array())
+        .and_then(|deps| deps.iter().find(|d| {
+            matches!(d.get("modId").and_then(|id| id.as_str()), Some("forge" | "neoforge"))
+        }));
+    let loader_dependency = loader_dep
+        .and_then(|d| d.get("modId"))
+        .and_then(|id| id.as_str())
+        .map(|id| id.to_string());
+    let loader_version_range = loader_dep
+        .and_then(|d| d.get("versionRange"))
+        .and_then(|range| range.as_str())
+        .map(|range| range.to_string());
+    
     Some(ModDetails {
         mod_id,
         name,
@@ -219,7 +303,10 @@ fn try_parse_forge_toml<R: Read + std::io::Seek>(archive: &mut ZipArchive<R>) ->
         source_url: None,
         license,
         icon_path,
-        loader_type: Some("Forge".to_string()),
+        loader_type: Some(loader_type.to_string()),
+        environment,
+        loader_dependency,
+        loader_version_range,
     })
 }
 
@@ -286,6 +373,9 @@ fn try_parse_mcmod_info<R: Read + std::io::Seek>(archive: &mut ZipArchive<R>) ->
         license: None,
         icon_path,
         loader_type: Some("Forge".to_string()),
+        environment: None,
+        loader_dependency: None,
+        loader_version_range: None,
     })
 }
 
@@ -328,9 +418,26 @@ fn try_parse_litemod<R: Read + std::io::Seek>(archive: &mut ZipArchive<R>) -> Op
         license: None,
         icon_path: None,
         loader_type: Some("LiteLoader".to_string()),
+        // LiteLoader only exists on the client
+        environment: Some("client".to_string()),
+        loader_dependency: None,
+        loader_version_range: None,
     })
 }
 
+/// Read a Fabric/Quilt version requirement, which is either one predicate
+/// string or an array of alternatives
+fn version_predicates(value: &serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::String(predicate) => Some(predicate.clone()),
+        serde_json::Value::Array(alternatives) => {
+            let predicates: Vec<&str> = alternatives.iter().filter_map(|v| v.as_str()).collect();
+            (!predicates.is_empty()).then(|| predicates.join(" || "))
+        }
+        _ => None,
+    }
+}
+
 // JSON structures for parsing
 
 #[derive(Debug, Deserialize)]
@@ -343,6 +450,8 @@ struct FabricModJson {
     contact: Option<ContactInfo>,
     license: Option<LicenseEntry>,
     icon: Option<String>,
+    environment: Option<String>,
+    depends: Option<std::collections::HashMap<String, serde_json::Value>>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -373,6 +482,12 @@ enum LicenseEntry {
 #[derive(Debug, Deserialize)]
 struct QuiltModJson {
     quilt_loader: QuiltLoader,
+    minecraft: Option<QuiltMinecraft>,
+}
+
+#[derive(Debug, Deserialize)]
+struct QuiltMinecraft {
+    environment: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -380,6 +495,8 @@ struct QuiltLoader {
     id: String,
     version: String,
     metadata: Option<QuiltMetadata>,
+    #[serde(default)]
+    depends: Vec<serde_json::Value>,
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -503,3 +620,45 @@ fn try_read_icon_from_archive<R: Read + std::io::Seek>(
     
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    
+    /// Write a jar holding a single metadata file
+    fn write_jar(path: &Path, name: &str, contents: &str) {
+        let mut zip = zip::ZipWriter::new(std::fs::File::create(path).unwrap());
+        zip.start_file(name, zip::write::SimpleFileOptions::default()).unwrap();
+        zip.write_all(contents.as_bytes()).unwrap();
+        zip.finish().unwrap();
+    }
+    
+    #[test]
+    fn reads_declared_sides() {
+        let dir = tempfile::tempdir().unwrap();
+        let jar = |name: &str| dir.path().join(name);
+        
+        write_jar(&jar("fabric.jar"), "fabric.mod.json", r#"{"id": "a", "version": "1", "environment": "client"}"#);
+        write_jar(&jar("any.jar"), "fabric.mod.json", r#"{"id": "b", "version": "1", "environment": "*"}"#);
+        write_jar(&jar("quilt.jar"), "quilt.mod.json",
+            r#"{"quilt_loader": {"id": "c", "version": "1"}, "minecraft": {"environment": "dedicated_server"}}"#);
+        write_jar(&jar("forge.jar"), "META-INF/mods.toml",
+            "modLoader=\"javafml\"\n[[mods]]\nmodId=\"d\"\ndisplayTest=\"IGNORE_SERVER_VERSION\"\n");
+        write_jar(&jar("neoforge.jar"), "META-INF/neoforge.mods.toml",
+            "modLoader=\"javafml\"\n[[mods]]\nmodId=\"e\"\ndisplayTest=\"MATCH_VERSION\"\n");
+        write_jar(&jar("silent.jar"), "META-INF/mods.toml", "modLoader=\"javafml\"\n[[mods]]\nmodId=\"f\"\n");
+        write_jar(&jar("client-deps.jar"), "META-INF/mods.toml",
+            "modLoader=\"javafml\"\n[[mods]]\nmodId=\"g\"\n[[dependencies.g]]\nmodId=\"jei\"\nside=\"CLIENT\"\n");
+        
+        assert_eq!(mod_side(&jar("fabric.jar")), ModSide::Client);
+        assert_eq!(mod_side(&jar("any.jar")), ModSide::Both);
+        assert_eq!(mod_side(&jar("quilt.jar")), ModSide::Server);
+        assert_eq!(mod_side(&jar("forge.jar")), ModSide::Client);
+        assert_eq!(mod_side(&jar("neoforge.jar")), ModSide::Both);
+        assert_eq!(mod_side(&jar("silent.jar")), ModSide::Unknown);
+        // Client-side dependencies don't make the mod itself client-only
+        assert_eq!(mod_side(&jar("client-deps.jar")), ModSide::Unknown);
+        assert_eq!(mod_side(&jar("missing.jar")), ModSide::Unknown);
+    }
+}
@@ -20,12 +20,25 @@
 
 #![allow(dead_code)] // API client will be used as features are completed
 
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use once_cell::sync::Lazy;
 use serde::Deserialize;
 use crate::core::error::Result;
 use crate::core::config::Config;
 use super::types::*;
 
-const MODRINTH_API_URL: &str = "https://api.modrinth.com/v2";
+pub(crate) const MODRINTH_API_URL: &str = "https://api.modrinth.com/v2";
+
+/// How long the fetched category list is reused; it rarely changes
+const CATEGORY_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// The full category list and when it was fetched
+type CachedCategories = Option<(Instant, Vec<Category>)>;
+
+static CATEGORY_CACHE: Lazy<Mutex<CachedCategories>> =
+    Lazy::new(|| Mutex::new(None));
 
 /// Modrinth API client
 pub struct ModrinthClient {
@@ -173,6 +186,19 @@ impl ModrinthClient {
         Ok(response.into_iter().map(|v| v.into()).collect())
     }
 
+    /// Get one page of a project's versions for every game version and loader.
+    /// Modrinth returns the whole list at once, so the page is cut locally.
+    pub async fn get_versions_page(&self, project_id: &str, offset: u32, limit: u32) -> Result<VersionPage> {
+        let versions = self.get_versions(project_id, None, None).await?;
+        let total = versions.len() as u32;
+        let versions = versions.into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect();
+        
+        Ok(VersionPage { versions, total, offset, limit })
+    }
+
     /// Get a specific version
     pub async fn get_version(&self, version_id: &str) -> Result<ProjectVersion> {
         let response: ModrinthVersion = self.request(reqwest::Method::GET, &format!("/version/{}", version_id))
@@ -184,19 +210,62 @@ impl ModrinthClient {
         Ok(response.into())
     }
 
-    /// Get categories
+    /// Find the version a file belongs to by its SHA1 hash
+    pub async fn get_version_from_hash(&self, sha1: &str) -> Result<ProjectVersion> {
+        let response: ModrinthVersion = self.request(reqwest::Method::GET, &format!("/version_file/{}", sha1))
+            .query(&[("algorithm", "sha1")])
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        
+        Ok(response.into())
+    }
+
+    /// Find the versions of many files at once by their SHA1 hashes. Hashes
+    /// Modrinth doesn't know are missing from the result.
+    pub async fn get_versions_from_hashes(&self, sha1s: &[String]) -> Result<HashMap<String, ProjectVersion>> {
+        if sha1s.is_empty() {
+            return Ok(HashMap::new());
+        }
+        
+        let response: HashMap<String, ModrinthVersion> = self.request(reqwest::Method::POST, "/version_files")
+            .json(&serde_json::json!({ "hashes": sha1s, "algorithm": "sha1" }))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        
+        Ok(response.into_iter()
+            .map(|(sha1, version)| (sha1.to_ascii_lowercase(), version.into()))
+            .collect())
+    }
+
+    /// Get categories, reusing a recent result when available
     pub async fn get_categories(&self) -> Result<Vec<Category>> {
+        if let Some((fetched_at, categories)) = CATEGORY_CACHE.lock().unwrap().as_ref() {
+            if fetched_at.elapsed() < CATEGORY_TTL {
+                return Ok(categories.clone());
+            }
+        }
+        
         let response: Vec<ModrinthCategory> = self.request(reqwest::Method::GET, "/tag/category")
             .send()
             .await?
+            .error_for_status()?
             .json()
             .await?;
         
-        Ok(response.into_iter().map(|c| Category {
+        let categories: Vec<Category> = response.into_iter().map(|c| Category {
             name: c.name,
             icon: c.icon,
             project_type: c.project_type,
-        }).collect())
+        }).collect();
+        
+        *CATEGORY_CACHE.lock().unwrap() = Some((Instant::now(), categories.clone()));
+        Ok(categories)
     }
 }
 
@@ -0,0 +1,50 @@
+//! Curated mod suggestions for new instances.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::core::config::SuggestedMod;
+
+/// Suggestions from `mods` that apply to `loader`, in list order
+pub fn suggestions_for_loader<'a>(mods: &'a [SuggestedMod], loader: &str) -> Vec<&'a SuggestedMod> {
+    mods.iter().filter(|m| m.supports_loader(loader)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::default_suggested_mods;
+    
+    #[test]
+    fn test_fabric_suggestions_start_with_fabric_api() {
+        let mods = default_suggested_mods();
+        let fabric = suggestions_for_loader(&mods, "fabric");
+        assert_eq!(fabric[0].name, "Fabric API");
+        assert!(fabric.iter().all(|m| m.project_id != "qvIfYCYJ"));
+    }
+    
+    #[test]
+    fn test_loader_match_ignores_case() {
+        let mods = default_suggested_mods();
+        assert_eq!(
+            suggestions_for_loader(&mods, "NeoForge").len(),
+            suggestions_for_loader(&mods, "neoforge").len()
+        );
+        assert!(suggestions_for_loader(&mods, "vanilla").is_empty());
+    }
+}
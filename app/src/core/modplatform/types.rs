@@ -178,6 +178,16 @@ pub struct ProjectVersion {
     pub dependencies: Vec<Dependency>,
 }
 
+/// One page of a project's versions, newest first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VersionPage {
+    pub versions: Vec<ProjectVersion>,
+    /// Versions the project has in total
+    pub total: u32,
+    pub offset: u32,
+    pub limit: u32,
+}
+
 /// Version release type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum VersionType {
@@ -0,0 +1,168 @@
+//! Named data profiles, each with its own config, instances and accounts.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+use crate::core::error::{OxideError, Result};
+
+/// Name of the profile that uses the launcher's original config and data folders
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Top-level file recording the active profile. It lives beside the default
+/// profile's config and is shared by every profile.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProfilesFile {
+    /// Active profile; None for the default profile
+    #[serde(default)]
+    pub active: Option<String>,
+}
+
+/// The active profile, read from the profiles file on first use
+static ACTIVE_PROFILE: Lazy<Mutex<Option<String>>> =
+    Lazy::new(|| Mutex::new(load_profiles_file().active));
+
+/// The launcher's folder in the OS config directory
+pub fn launcher_config_dir() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("OxideLauncher")
+}
+
+/// The launcher's folder in the OS data directory
+pub fn launcher_data_dir() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("OxideLauncher")
+}
+
+fn profiles_file_path() -> PathBuf {
+    launcher_config_dir().join("profiles.json")
+}
+
+/// Folder holding the named (non-default) profiles
+pub fn profiles_root() -> PathBuf {
+    launcher_data_dir().join("profiles")
+}
+
+/// Folder holding a named profile's config and default data directory
+pub fn profile_dir(name: &str) -> PathBuf {
+    profiles_root().join(name)
+}
+
+fn load_profiles_file() -> ProfilesFile {
+    std::fs::read_to_string(profiles_file_path())
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// The active named profile, or None for the default profile
+pub fn active_profile() -> Option<String> {
+    ACTIVE_PROFILE.lock().unwrap().clone()
+}
+
+/// The active profile's name, including the default profile
+pub fn active_profile_name() -> String {
+    active_profile().unwrap_or_else(|| DEFAULT_PROFILE.to_string())
+}
+
+/// Make `name` the active profile and persist the choice. The profile's
+/// folder is created if it doesn't exist.
+pub fn set_active_profile(name: &str) -> Result<()> {
+    validate_profile_name(name)?;
+    let active = (name != DEFAULT_PROFILE).then(|| name.to_string());
+    
+    if let Some(name) = &active {
+        std::fs::create_dir_all(profile_dir(name))?;
+    }
+    
+    let path = profiles_file_path();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let content = serde_json::to_string_pretty(&ProfilesFile { active: active.clone() })?;
+    std::fs::write(&path, content)?;
+    
+    *ACTIVE_PROFILE.lock().unwrap() = active;
+    Ok(())
+}
+
+/// Check that a profile name is usable as a folder name
+pub fn validate_profile_name(name: &str) -> Result<()> {
+    let valid = !name.is_empty()
+        && name.len() <= 64
+        && name.trim() == name
+        && name.chars().all(|c| c.is_alphanumeric() || c == '-' || c == '_' || c == ' ');
+    if valid {
+        Ok(())
+    } else {
+        Err(OxideError::Other(format!(
+            "Invalid profile name '{}': use letters, numbers, spaces, '-' or '_'",
+            name
+        )))
+    }
+}
+
+/// All profiles: the default profile followed by the named ones
+pub fn list_profiles() -> Vec<String> {
+    list_profiles_in(&profiles_root())
+}
+
+fn list_profiles_in(root: &Path) -> Vec<String> {
+    let mut named: Vec<String> = std::fs::read_dir(root)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .filter(|name| name != DEFAULT_PROFILE && validate_profile_name(name).is_ok())
+        .collect();
+    named.sort_by_key(|name| name.to_lowercase());
+    
+    let mut profiles = vec![DEFAULT_PROFILE.to_string()];
+    profiles.extend(named);
+    profiles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn lists_and_validates_profiles() {
+        let dir = tempfile::tempdir().unwrap();
+        for name in ["Videos", "personal", ".hidden"] {
+            std::fs::create_dir_all(dir.path().join(name)).unwrap();
+        }
+        std::fs::write(dir.path().join("notes.txt"), "").unwrap();
+        
+        assert_eq!(list_profiles_in(dir.path()), vec!["default", "personal", "Videos"]);
+        assert_eq!(list_profiles_in(&dir.path().join("missing")), vec!["default"]);
+        
+        assert!(validate_profile_name("Content creation").is_ok());
+        assert!(validate_profile_name("").is_err());
+        assert!(validate_profile_name("../escape").is_err());
+        assert!(validate_profile_name(" padded").is_err());
+    }
+}
@@ -22,6 +22,7 @@ pub mod types;
 pub mod parser;
 pub mod update_check;
 pub mod export;
+pub mod verify;
 
 // Re-export commonly used types
 pub use types::{
@@ -45,7 +46,9 @@ pub use parser::initialize_packwiz as initialize_pack;
 pub use parser::has_packwiz as has_pack;
 
 #[allow(unused_imports)] // check_instance_updates kept for backwards compatibility
-pub use update_check::{check_instance_updates, check_instance_updates_with_info};
+pub use update_check::{check_instance_updates, check_instance_updates_with_info, pinned_mods, set_mod_pinned, PinnedMods};
+
+pub use verify::{check_mod_files, HashCheck, ModFileCheck};
 
 pub use export::{
     ExportOptions,
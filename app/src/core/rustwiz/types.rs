@@ -192,6 +192,10 @@ pub struct ModToml {
     /// Update sources for automatic updates
     #[serde(skip_serializing_if = "Option::is_none")]
     pub update: Option<ModUpdate>,
+    
+    /// Pinned at the current version; update checks and modpack updates leave it alone
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub pin: bool,
 }
 
 /// Download information for a mod
@@ -450,6 +454,9 @@ pub struct BatchUpdateResult {
     /// Mods that couldn't be checked (no update info)
     pub unchecked: Vec<String>,
     
+    /// Mods skipped because they are pinned
+    pub pinned: Vec<String>,
+    
     /// Errors encountered during checking
     pub errors: Vec<String>,
 }
@@ -537,6 +544,7 @@ impl ModToml {
             },
             option: None,
             update: None,
+            pin: false,
         }
     }
     
@@ -18,13 +18,16 @@
 //! You should have received a copy of the GNU General Public License
 //! along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 
-use crate::core::error::Result;
+use crate::core::error::{OxideError, Result};
+use crate::core::instance::DISABLED_SUFFIX;
 use crate::core::modplatform::modrinth::ModrinthClient;
 use crate::core::modplatform::curseforge::CurseForgeClient;
 use super::types::*;
-use super::parser::{find_mod_tomls, read_mod_toml, read_pack_toml};
+use super::parser::{find_mod_tomls, read_mod_toml, read_pack_toml, write_mod_toml};
+use super::verify::index_mod_tomls;
 
 // =============================================================================
 // Update Checking
@@ -94,6 +97,8 @@ pub async fn check_instance_updates(
 /// 
 /// This allows checking for updates without requiring pack.toml by passing
 /// the minecraft version and mod loader directly from the instance metadata.
+/// `instance_path` is the folder holding `mods/`: the instance's game
+/// directory, where downloads and pins write their metadata.
 pub async fn check_instance_updates_with_info(
     instance_path: &Path,
     minecraft_version: Option<&str>,
@@ -103,6 +108,7 @@ pub async fn check_instance_updates_with_info(
         updates_available: Vec::new(),
         up_to_date: Vec::new(),
         unchecked: Vec::new(),
+        pinned: Vec::new(),
         errors: Vec::new(),
     };
     
@@ -146,6 +152,11 @@ pub async fn check_instance_updates_with_info(
             }
         };
         
+        if mod_toml.packwiz.pin {
+            result.pinned.push(mod_toml.packwiz.filename.clone());
+            continue;
+        }
+        
         // Check if we have update info
         if mod_toml.packwiz.update.is_none() {
             result.unchecked.push(mod_toml.packwiz.filename.clone());
@@ -301,6 +312,77 @@ async fn check_curseforge_update(
     }
 }
 
+// =============================================================================
+// Pinned Mods
+// =============================================================================
+
+/// Mods in a mods folder that are pinned at their current version
+#[derive(Debug, Default)]
+pub struct PinnedMods {
+    /// Jar names of the pinned mods
+    pub files: HashSet<String>,
+    
+    /// Jar names keyed by Modrinth or CurseForge project ID
+    pub projects: HashMap<String, String>,
+}
+
+impl PinnedMods {
+    /// Whether a game-directory relative path is one of the pinned jars
+    pub fn contains_path(&self, relative: &str) -> bool {
+        relative
+            .strip_prefix("mods/")
+            .map(|name| name.trim_end_matches(DISABLED_SUFFIX))
+            .is_some_and(|name| self.files.contains(name))
+    }
+    
+    /// The pinned jar for a Modrinth or CurseForge project, if the project is pinned
+    pub fn project_jar(&self, project_id: &str) -> Option<&str> {
+        self.projects.get(project_id).map(String::as_str)
+    }
+}
+
+/// Pin or unpin the mod backed by `jar` in its `.index` metadata, the same
+/// metadata [`check_instance_updates_with_info`] reads. Returns whether
+/// anything changed; mods without metadata can't be pinned.
+pub fn set_mod_pinned(mods_dir: &Path, jar: &str, pinned: bool) -> Result<bool> {
+    let (toml_path, mut mod_toml) = index_mod_tomls(mods_dir)
+        .remove(jar)
+        .ok_or_else(|| OxideError::Other(format!(
+            "{} has no update metadata; only mods installed from Modrinth or CurseForge can be pinned",
+            jar
+        )))?;
+    
+    if mod_toml.packwiz.pin == pinned {
+        return Ok(false);
+    }
+    mod_toml.packwiz.pin = pinned;
+    write_mod_toml(&toml_path, &mod_toml)?;
+    
+    Ok(true)
+}
+
+/// Collect the pinned mods from the metadata in a mods folder's `.index`
+pub fn pinned_mods(mods_dir: &Path) -> PinnedMods {
+    let mut pinned = PinnedMods::default();
+    
+    for (jar, (_, toml)) in index_mod_tomls(mods_dir) {
+        if !toml.packwiz.pin {
+            continue;
+        }
+        if let Some(ref update) = toml.packwiz.update {
+            if let Some(ref modrinth) = update.modrinth {
+                pinned.projects.insert(modrinth.mod_id.clone(), jar.clone());
+            }
+            if let Some(ref curseforge) = update.curseforge {
+                pinned.projects.insert(curseforge.project_id.to_string(), jar.clone());
+            }
+        }
+        pinned.files.insert(jar);
+    }
+    
+    pinned
+}
+
 // =============================================================================
 // Utilities
 // =============================================================================
@@ -360,4 +442,64 @@ mod tests {
             "19.0.0.7"
         );
     }
+    
+    #[test]
+    fn collects_pinned_mods() {
+        use crate::core::rustwiz::parser::{index_dir, write_mod_toml};
+        
+        let dir = tempfile::tempdir().unwrap();
+        let mods = dir.path();
+        for (jar, pin) in [("sodium.jar", true), ("iris.jar", false)] {
+            let mut toml = ModToml::new(
+                jar.to_string(),
+                format!("mods/{}", jar),
+                format!("https://example.invalid/{}", jar),
+                String::new(),
+                HashFormat::Sha1,
+            )
+            .with_modrinth_update(format!("{}-id", jar), "v1".to_string());
+            toml.pin = pin;
+            let path = index_dir(mods).join(format!("{}.pw.toml", jar));
+            write_mod_toml(&path, &ModTomlExtended::from_packwiz(toml)).unwrap();
+        }
+        
+        let pinned = pinned_mods(mods);
+        
+        assert!(pinned.contains_path("mods/sodium.jar"));
+        assert!(pinned.contains_path("mods/sodium.jar.disabled"));
+        assert!(!pinned.contains_path("mods/iris.jar"));
+        assert!(!pinned.contains_path("config/sodium.jar"));
+        assert_eq!(pinned.project_jar("sodium.jar-id"), Some("sodium.jar"));
+        assert_eq!(pinned.project_jar("iris.jar-id"), None);
+    }
+    
+    #[tokio::test]
+    async fn update_check_skips_mods_pinned_in_the_instance() {
+        use crate::core::instance::Instance;
+        use crate::core::rustwiz::parser::{index_dir, write_mod_toml};
+        
+        let dir = tempfile::tempdir().unwrap();
+        let instance = Instance::new("Test".to_string(), dir.path().join("test"), "1.20.1".to_string());
+        let mods_dir = instance.mods_dir();
+        let toml = ModToml::new(
+            "sodium.jar".to_string(),
+            "mods/sodium.jar".to_string(),
+            "https://example.invalid/sodium.jar".to_string(),
+            String::new(),
+            HashFormat::Sha1,
+        )
+        .with_modrinth_update("sodium-id".to_string(), "v1".to_string());
+        write_mod_toml(&index_dir(&mods_dir).join("sodium.jar.pw.toml"), &ModTomlExtended::from_packwiz(toml)).unwrap();
+        
+        assert!(set_mod_pinned(&mods_dir, "sodium.jar", true).unwrap());
+        assert!(!set_mod_pinned(&mods_dir, "sodium.jar", true).unwrap());
+        assert!(set_mod_pinned(&mods_dir, "iris.jar", true).is_err());
+        
+        let result = check_instance_updates_with_info(&instance.game_dir(), Some("1.20.1"), Some("fabric"))
+            .await
+            .unwrap();
+        assert_eq!(result.pinned, vec!["mods/sodium.jar"]);
+        assert!(result.updates_available.is_empty());
+        assert!(result.errors.is_empty());
+    }
 }
@@ -0,0 +1,167 @@
+//! Checking installed mod jars against the hashes stored in their metadata.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::core::error::Result;
+use crate::core::instance::split_mod_filename;
+use super::parser::{compute_file_hash, index_dir, read_mod_toml};
+use super::types::ModTomlExtended;
+
+/// Outcome of comparing a jar with its stored hash
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HashCheck {
+    /// The jar matches its stored hash
+    Intact,
+    /// The jar differs from its stored hash
+    Mismatch { expected: String, actual: String },
+    /// No metadata, or metadata without a usable hash
+    NoHash,
+    /// The jar couldn't be read to hash it
+    Unreadable(String),
+}
+
+/// Hash check for one installed mod
+#[derive(Debug, Clone)]
+pub struct ModFileCheck {
+    /// File name on disk, including any `.disabled` suffix
+    pub file: String,
+    /// Jar name without the `.disabled` suffix, as recorded in metadata
+    pub base: String,
+    /// The `.pw.toml` describing this jar and its path
+    pub metadata: Option<(PathBuf, ModTomlExtended)>,
+    pub check: HashCheck,
+}
+
+/// Map each jar name in the mods folder's `.index` to the `.pw.toml` describing it.
+/// Unreadable metadata files are skipped.
+pub fn index_mod_tomls(mods_dir: &Path) -> HashMap<String, (PathBuf, ModTomlExtended)> {
+    let mut tomls = HashMap::new();
+    let Ok(entries) = fs::read_dir(index_dir(mods_dir)) else {
+        return tomls;
+    };
+    
+    for path in entries.flatten().map(|entry| entry.path()) {
+        if !path.to_string_lossy().ends_with(".pw.toml") {
+            continue;
+        }
+        let Ok(toml) = read_mod_toml(&path) else {
+            tracing::warn!("Skipping unreadable mod metadata {:?}", path);
+            continue;
+        };
+        let jar = Path::new(&toml.packwiz.filename)
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        if !jar.is_empty() {
+            tomls.insert(jar, (path, toml));
+        }
+    }
+    
+    tomls
+}
+
+/// Recompute the hash of every mod jar (enabled or disabled) that has stored
+/// metadata and compare it with the recorded one. A jar that can't be read is
+/// reported as unreadable rather than failing the whole check.
+pub fn check_mod_files(mods_dir: &Path) -> Result<Vec<ModFileCheck>> {
+    let mut tomls = index_mod_tomls(mods_dir);
+    let mut checks = Vec::new();
+    
+    if !mods_dir.is_dir() {
+        return Ok(checks);
+    }
+    
+    let mut files: Vec<String> = fs::read_dir(mods_dir)?
+        .flatten()
+        .filter(|entry| entry.path().is_file())
+        .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+        .collect();
+    files.sort();
+    
+    for file in files {
+        let Some((base, _, _)) = split_mod_filename(&file) else {
+            continue;
+        };
+        let base = base.to_string();
+        let metadata = tomls.remove(&base);
+        
+        let check = match &metadata {
+            Some((_, toml)) if !toml.packwiz.download.hash.is_empty() => {
+                let download = &toml.packwiz.download;
+                match compute_file_hash(&mods_dir.join(&file), download.hash_format) {
+                    Ok(actual) if actual.eq_ignore_ascii_case(&download.hash) => HashCheck::Intact,
+                    Ok(actual) => HashCheck::Mismatch { expected: download.hash.clone(), actual },
+                    Err(e) => {
+                        tracing::warn!("Couldn't read mod {} to check it: {}", file, e);
+                        HashCheck::Unreadable(e.to_string())
+                    }
+                }
+            }
+            _ => HashCheck::NoHash,
+        };
+        
+        checks.push(ModFileCheck { file, base, metadata, check });
+    }
+    
+    Ok(checks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::rustwiz::types::{HashFormat, ModToml};
+    use crate::core::rustwiz::parser::{compute_hash, write_mod_toml};
+    
+    fn write_metadata(mods_dir: &Path, jar: &str, hash: String) {
+        let toml = ModToml::new(
+            jar.to_string(),
+            format!("mods/{}", jar),
+            format!("https://example.invalid/{}", jar),
+            hash,
+            HashFormat::Sha512,
+        );
+        let path = index_dir(mods_dir).join(format!("{}.pw.toml", jar));
+        write_mod_toml(&path, &ModTomlExtended::from_packwiz(toml)).unwrap();
+    }
+    
+    #[test]
+    fn compares_jars_with_stored_hashes() {
+        let dir = tempfile::tempdir().unwrap();
+        let mods = dir.path();
+        fs::write(mods.join("good.jar"), b"good").unwrap();
+        fs::write(mods.join("bad.jar.disabled"), b"truncated").unwrap();
+        fs::write(mods.join("local.jar"), b"local").unwrap();
+        write_metadata(mods, "good.jar", compute_hash(b"good", HashFormat::Sha512));
+        write_metadata(mods, "bad.jar", compute_hash(b"complete", HashFormat::Sha512));
+        
+        let checks = check_mod_files(mods).unwrap();
+        let status: Vec<_> = checks.iter().map(|c| (c.file.as_str(), &c.check)).collect();
+        
+        assert_eq!(status.len(), 3);
+        assert!(matches!(status[0], ("bad.jar.disabled", HashCheck::Mismatch { .. })));
+        assert_eq!(checks[0].base, "bad.jar");
+        assert_eq!(status[1], ("good.jar", &HashCheck::Intact));
+        assert_eq!(status[2], ("local.jar", &HashCheck::NoHash));
+        assert!(checks[2].metadata.is_none());
+    }
+}
@@ -0,0 +1,143 @@
+//! Free and total space on the volumes holding the launcher's folders.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+/// Free space below which a volume is reported as low (2 GiB)
+pub const LOW_SPACE_BYTES: u64 = 2 * 1024 * 1024 * 1024;
+
+/// A volume and the launcher folders stored on it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct VolumeInfo {
+    pub mount_point: PathBuf,
+    pub free_bytes: u64,
+    pub total_bytes: u64,
+    /// Free space is under `LOW_SPACE_BYTES`
+    pub low: bool,
+    /// Folders on this volume, such as "data" or "instances"
+    pub folders: Vec<String>,
+}
+
+/// A mounted volume: mount point, free bytes, total bytes
+type Mount = (PathBuf, u64, u64);
+
+/// Free and total space for the volumes holding `folders`, given as
+/// (label, path) pairs. Folders sharing a volume are grouped into one entry.
+pub fn storage_info(folders: &[(&str, PathBuf)]) -> Vec<VolumeInfo> {
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    let mounts: Vec<Mount> = disks.list()
+        .iter()
+        .map(|disk| (disk.mount_point().to_path_buf(), disk.available_space(), disk.total_space()))
+        .collect();
+    group_by_volume(folders, &mounts)
+}
+
+fn group_by_volume(folders: &[(&str, PathBuf)], mounts: &[Mount]) -> Vec<VolumeInfo> {
+    let mut volumes: Vec<VolumeInfo> = Vec::new();
+    
+    for (label, path) in folders {
+        let path = existing_ancestor(path);
+        // The deepest mount point containing the folder
+        let Some((mount_point, free, total)) = mounts.iter()
+            .filter(|(mount_point, _, _)| path.starts_with(mount_point))
+            .max_by_key(|(mount_point, _, _)| mount_point.components().count())
+        else {
+            tracing::debug!("No volume found for {:?}", path);
+            continue;
+        };
+        
+        match volumes.iter_mut().find(|v| &v.mount_point == mount_point) {
+            Some(volume) => volume.folders.push(label.to_string()),
+            None => volumes.push(VolumeInfo {
+                mount_point: mount_point.clone(),
+                free_bytes: *free,
+                total_bytes: *total,
+                low: *free < LOW_SPACE_BYTES,
+                folders: vec![label.to_string()],
+            }),
+        }
+    }
+    
+    volumes
+}
+
+/// The folder itself, resolved through symlinks, or its nearest existing parent
+/// when it hasn't been created yet
+fn existing_ancestor(path: &Path) -> PathBuf {
+    path.ancestors()
+        .find_map(|ancestor| ancestor.canonicalize().ok())
+        .map(|canonical| strip_verbatim_prefix(&canonical))
+        .unwrap_or_else(|| path.to_path_buf())
+}
+
+/// On Windows, canonicalize adds a \\?\ prefix that mount points don't have
+fn strip_verbatim_prefix(path: &Path) -> PathBuf {
+    match path.to_string_lossy().strip_prefix(r"\\?\") {
+        Some(stripped) => PathBuf::from(stripped),
+        None => path.to_path_buf(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn groups_folders_by_deepest_mount() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        let games = root.join("games");
+        std::fs::create_dir_all(&games).unwrap();
+        
+        let mounts = vec![
+            (PathBuf::from("/"), 100, 1000),
+            (root.clone(), 10 * LOW_SPACE_BYTES, 20 * LOW_SPACE_BYTES),
+            (games.clone(), 1, 2),
+        ];
+        let folders = [
+            ("data", root.clone()),
+            ("instances", games.join("instances")),
+            ("libraries", root.join("libraries")),
+        ];
+        
+        let volumes = group_by_volume(&folders, &mounts);
+        assert_eq!(volumes.len(), 2);
+        assert_eq!(volumes[0].mount_point, root);
+        assert_eq!(volumes[0].folders, vec!["data", "libraries"]);
+        assert!(!volumes[0].low);
+        assert_eq!(volumes[1].mount_point, games);
+        assert_eq!(volumes[1].folders, vec!["instances"]);
+        assert!(volumes[1].low);
+    }
+    
+    #[test]
+    fn strips_verbatim_prefix() {
+        assert_eq!(
+            strip_verbatim_prefix(Path::new(r"\\?\C:\Games\Oxide")),
+            PathBuf::from(r"C:\Games\Oxide")
+        );
+        assert_eq!(
+            strip_verbatim_prefix(Path::new("/home/player/.oxide")),
+            PathBuf::from("/home/player/.oxide")
+        );
+    }
+}
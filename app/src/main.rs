@@ -23,11 +23,13 @@
 
 mod commands;
 mod core;
+mod tray;
 
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter, Layer};
 use std::sync::Arc;
 use parking_lot::RwLock;
 use chrono::Local;
+use tauri::Manager;
 
 /// Global logging state to allow runtime reconfiguration
 static LOGGING_STATE: once_cell::sync::Lazy<Arc<RwLock<LoggingState>>> = 
@@ -135,37 +137,85 @@ fn main() {
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_dialog::init())
         .manage(commands::AppState::new())
+        .setup(|app| {
+            tray::setup_tray(app)?;
+            tray::show_on_startup(app);
+            
+            // Warm caches in the background once the window is up
+            let warm_up = app.state::<commands::AppState>().config.lock().unwrap().ui.warm_up_on_startup;
+            if warm_up {
+                let handle = app.handle().clone();
+                tauri::async_runtime::spawn(commands::startup::warm_up(handle));
+            }
+            Ok(())
+        })
+        .on_window_event(tray::handle_window_event)
         .invoke_handler(tauri::generate_handler![
             // Instance commands
             commands::instances::get_instances,
             commands::instances::get_instance_details,
             commands::instances::create_instance,
+            commands::instances::create_from_world,
             commands::instances::delete_instance,
             commands::instances::launch_instance,
+            commands::instances::launch_instance_with_console,
+            commands::instances::can_launch,
             commands::instances::get_instance_logs,
+            commands::instances::subscribe_instance_logs,
+            commands::instances::unsubscribe_instance_logs,
             commands::instances::is_instance_running,
             commands::instances::get_instance_status,
             commands::instances::rename_instance,
             commands::instances::change_instance_icon,
             commands::instances::copy_instance,
+            commands::instances::export_all_recipes,
+            commands::instances::import_all_recipes,
+            commands::instances::generate_lockfile,
+            commands::instances::verify_against_lockfile,
             commands::instances::change_instance_group,
+            commands::instances::rename_group,
+            commands::instances::merge_groups,
+            commands::instances::validate_instance_name,
             commands::instances::open_instance_folder,
+            commands::instances::normalize_instance_layouts,
+            commands::instances::migrate_instance_layout,
+            commands::instances::get_instance_size_on_disk,
             commands::instances::open_instance_logs_folder,
             commands::instances::export_instance,
             commands::instances::kill_instance,
+            commands::instances::clear_running_state,
+            commands::instances::get_launch_timing,
+            commands::instances::get_last_exit_status,
+            commands::instances::reextract_natives,
             commands::instances::get_instance_settings,
             commands::instances::update_instance_settings,
+            commands::instances::apply_settings_to_group,
+            commands::instances::preview_launch_arguments,
+            commands::instances::get_classpath,
+            commands::instances::set_instance_language,
+            commands::instances::set_instance_demo_lock,
+            commands::instances::apply_aikars_gc_flags,
+            commands::instances::get_effective_settings,
+            commands::instances::get_instance_runtime_config,
+            commands::instances::reset_game_options,
+            commands::instances::set_shared_folder,
+            commands::instances::get_instance_summary,
+            commands::instances::get_mod_set_fingerprint,
+            commands::instances::render_instance_card,
             // Component management commands
             commands::instances::get_instance_components,
             commands::instances::remove_instance_component,
             commands::instances::change_component_version,
             commands::instances::install_mod_loader,
+            commands::instances::plan_mod_loader_install,
             commands::instances::open_minecraft_folder,
             commands::instances::open_libraries_folder,
+            commands::instances::open_mod_config,
             // Jar mods and agents
             commands::instances::add_jar_mod,
             commands::instances::get_jar_mods,
             commands::instances::remove_jar_mod,
+            commands::instances::reorder_jar_mods,
             commands::instances::add_java_agent,
             commands::instances::get_java_agents,
             commands::instances::remove_java_agent,
@@ -183,6 +233,12 @@ fn main() {
             commands::instances::detect_import_format,
             commands::instances::import_instance_from_file,
             commands::instances::import_instance_from_url,
+            commands::instances::import_into_instance,
+            commands::instances::cancel_import,
+            commands::instances::install_modpack,
+            commands::instances::plan_modpack_install,
+            commands::instances::update_modpack,
+            commands::instances::generate_server_pack,
             // Blocked mods commands
             commands::instances::resolve_blocked_files,
             commands::instances::get_blocked_mods_info,
@@ -203,10 +259,12 @@ fn main() {
             commands::accounts::cancel_microsoft_login,
             commands::accounts::refresh_account,
             commands::accounts::set_active_account,
+            commands::accounts::reorder_accounts,
             commands::accounts::remove_account,
             commands::accounts::get_account_for_launch,
             commands::accounts::is_microsoft_configured,
             commands::accounts::has_verified_ownership,
+            commands::accounts::get_account_token_status,
             // Skin management commands
             commands::accounts::get_player_profile,
             commands::accounts::change_skin_url,
@@ -216,8 +274,10 @@ fn main() {
             commands::accounts::hide_cape,
             commands::accounts::fetch_skin_from_username,
             commands::accounts::import_skin_from_username,
+            commands::accounts::import_skin_from_file,
             commands::accounts::open_skins_folder,
             commands::accounts::set_default_account,
+            commands::accounts::deduplicate_accounts,
             commands::accounts::download_skin_image,
             commands::accounts::cache_skin_image,
             commands::accounts::get_cached_skin_path,
@@ -225,6 +285,21 @@ fn main() {
             // Config commands
             commands::config::get_config,
             commands::config::update_config,
+            commands::config::set_shared_directory,
+            commands::config::detect_official_minecraft,
+            commands::config::import_official_assets,
+            commands::config::check_connectivity,
+            commands::config::get_storage_info,
+            commands::config::find_dangling_references,
+            commands::config::validate_memory_settings,
+            commands::config::get_instance_template,
+            commands::config::set_instance_template,
+            commands::config::get_argument_profiles,
+            commands::config::save_argument_profile,
+            commands::config::delete_argument_profile,
+            commands::config::set_global_jvm_args,
+            commands::config::export_settings,
+            commands::config::import_settings,
             commands::config::get_logs_directory,
             commands::config::open_logs_directory,
             commands::config::open_data_directory,
@@ -232,19 +307,34 @@ fn main() {
             commands::config::open_external_url,
             commands::config::get_window_state,
             commands::config::save_window_state,
+            commands::config::get_instance_tab,
+            commands::config::set_instance_tab,
             commands::config::is_window_position_memory_enabled,
+            commands::config::is_minimize_to_tray_enabled,
             // Version commands
             commands::versions::get_minecraft_versions,
+            commands::versions::get_downloaded_versions,
+            commands::versions::get_latest_release,
+            commands::versions::get_latest_snapshot,
+            commands::versions::get_recent_releases,
             commands::versions::get_forge_versions,
             commands::versions::get_neoforge_versions,
+            commands::versions::recommend_loader_version,
+            commands::versions::get_loader_changelog_range,
             commands::versions::get_fabric_versions,
             commands::versions::get_quilt_versions,
+            commands::versions::detect_snapshot_loader,
             commands::versions::get_liteloader_versions,
+            commands::versions::get_supported_loaders,
             // Mod commands
             commands::mods::search_mods,
             commands::mods::download_mod,
             commands::mods::get_installed_mods,
             commands::mods::toggle_mod,
+            commands::mods::set_mod_pinned,
+            commands::mods::repair_mod_files,
+            commands::mods::clean_mod_metadata,
+            commands::mods::find_duplicate_mods,
             commands::mods::delete_mod,
             commands::mods::delete_mods,
             commands::mods::enable_mods,
@@ -257,23 +347,42 @@ fn main() {
             commands::mods::search_mods_detailed,
             commands::mods::get_mod_details,
             commands::mods::get_mod_versions,
+            commands::mods::get_all_mod_versions,
             commands::mods::download_mod_version,
             commands::mods::download_mods_batch,
-            commands::mods::get_mod_categories,
+            commands::mods::get_download_size,
+            commands::mods::get_suggested_mods,
+            commands::mods::install_suggested_mods,
+            commands::mods::copy_mods_from_instance,
+            commands::mods::get_categories,
+            commands::mods::scan_mods_for_malware,
+            commands::mods::get_mod_side,
+            commands::mods::verify_mods,
             // Java commands
             commands::java::detect_java,
             commands::java::find_java_for_minecraft,
             commands::java::get_required_java,
+            commands::java::get_java_requirement,
             commands::java::validate_java,
             commands::java::fetch_available_java_versions,
             commands::java::download_java,
+            commands::java::check_managed_java,
+            commands::java::repair_managed_java,
             commands::java::get_java_install_dir,
+            commands::java::check_launch_wrapper,
+            commands::java::install_launch_wrapper,
             commands::java::delete_java,
+            commands::java::repair_java_permissions,
             commands::java::check_java_compatibility,
+            commands::java::check_java_memory,
             commands::java::find_best_java_for_instance,
             // World commands
             commands::worlds::list_worlds,
             commands::worlds::delete_world,
+            commands::worlds::delete_world_backups_before,
+            commands::worlds::backup_world,
+            commands::worlds::list_world_backups,
+            commands::worlds::restore_world_backup,
             commands::worlds::export_world,
             commands::worlds::copy_world,
             commands::worlds::get_world_icon,
@@ -291,6 +400,8 @@ fn main() {
             commands::resources::add_local_resource_pack_from_bytes,
             // Shader pack commands
             commands::resources::list_shader_packs,
+            commands::resources::get_active_shader_pack,
+            commands::resources::set_active_shader_pack,
             commands::resources::delete_shader_pack,
             commands::resources::open_shaderpacks_folder,
             commands::resources::search_shader_packs,
@@ -303,6 +414,7 @@ fn main() {
             // Screenshot commands
             commands::screenshots::list_screenshots,
             commands::screenshots::delete_screenshot,
+            commands::screenshots::delete_screenshots_before,
             commands::screenshots::open_screenshots_folder,
             // Shortcut commands
             commands::shortcuts::create_instance_shortcut,
@@ -317,6 +429,14 @@ fn main() {
             commands::rustwiz::export_rustwiz_format,
             // News commands
             commands::news::get_news,
+            // Profile commands
+            commands::profiles::list_profiles,
+            commands::profiles::switch_profile,
+            // Startup commands
+            commands::startup::warm_up_caches,
+            // Server list commands
+            commands::servers::get_blocked_servers,
+            commands::servers::check_server_blocked,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
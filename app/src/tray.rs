@@ -0,0 +1,100 @@
+//! System tray icon and minimize-to-tray window handling.
+//!
+//! Oxide Launcher — A Rust-based Minecraft launcher
+//! Copyright (C) 2025 Oxide Launcher contributors
+//!
+//! This file is part of Oxide Launcher.
+//!
+//! Oxide Launcher is free software: you can redistribute it and/or modify
+//! it under the terms of the GNU General Public License as published by
+//! the Free Software Foundation, either version 3 of the License, or
+//! (at your option) any later version.
+//!
+//! Oxide Launcher is distributed in the hope that it will be useful,
+//! but WITHOUT ANY WARRANTY; without even the implied warranty of
+//! MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+//! GNU General Public License for more details.
+//!
+//! You should have received a copy of the GNU General Public License
+//! along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use tauri::menu::{Menu, MenuItem};
+use tauri::tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent};
+use tauri::{App, AppHandle, Manager, Window, WindowEvent};
+
+use crate::commands::AppState;
+
+/// Label of the launcher's main window
+const MAIN_WINDOW: &str = "main";
+
+/// Create the tray icon. Clicking it shows the launcher; its menu can show or quit.
+///
+/// "Quit" exits the app directly, so it works regardless of `minimize_to_tray`.
+pub fn setup_tray(app: &App) -> tauri::Result<()> {
+    let show = MenuItem::with_id(app, "show", "Show Oxide Launcher", true, None::<&str>)?;
+    let quit = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
+    let menu = Menu::with_items(app, &[&show, &quit])?;
+    
+    let mut builder = TrayIconBuilder::with_id("main")
+        .tooltip("Oxide Launcher")
+        .menu(&menu)
+        .show_menu_on_left_click(false)
+        .on_menu_event(|app, event| match event.id.as_ref() {
+            "show" => show_main_window(app),
+            "quit" => app.exit(0),
+            _ => {}
+        })
+        .on_tray_icon_event(|tray, event| {
+            if let TrayIconEvent::Click {
+                button: MouseButton::Left,
+                button_state: MouseButtonState::Up,
+                ..
+            } = event
+            {
+                show_main_window(tray.app_handle());
+            }
+        });
+    
+    if let Some(icon) = app.default_window_icon() {
+        builder = builder.icon(icon.clone());
+    }
+    
+    builder.build(app)?;
+    Ok(())
+}
+
+/// Show the main window unless the launcher is configured to start minimized
+pub fn show_on_startup(app: &App) {
+    let start_minimized = app.state::<AppState>().config.lock().unwrap().ui.start_minimized;
+    
+    if start_minimized {
+        tracing::info!("Starting minimized to the tray");
+    } else {
+        show_main_window(app.handle());
+    }
+}
+
+/// Bring the main window back from the tray or taskbar
+pub fn show_main_window(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window(MAIN_WINDOW) {
+        let _ = window.show();
+        let _ = window.unminimize();
+        let _ = window.set_focus();
+    }
+}
+
+/// Hide the main window instead of closing it when `minimize_to_tray` is enabled
+pub fn handle_window_event(window: &Window, event: &WindowEvent) {
+    let WindowEvent::CloseRequested { api, .. } = event else {
+        return;
+    };
+    if window.label() != MAIN_WINDOW {
+        return;
+    }
+    
+    let minimize_to_tray = window.state::<AppState>().config.lock().unwrap().ui.minimize_to_tray;
+    if minimize_to_tray {
+        api.prevent_close();
+        let _ = window.hide();
+    }
+}
@@ -33,6 +33,8 @@ if %ERRORLEVEL% neq 0 (
 
 REM Create manifest
 echo Main-Class: dev.oxide.launch.OxideLaunch> "%OUT_DIR%\MANIFEST.MF"
+REM Parenthesized so "2>>" isn't read as a stderr redirect
+(echo Oxide-Launch-Protocol: 2)>> "%OUT_DIR%\MANIFEST.MF"
 echo.>> "%OUT_DIR%\MANIFEST.MF"
 
 REM Create JAR
@@ -30,6 +30,7 @@ fi
 
 # Create manifest
 echo "Main-Class: dev.oxide.launch.OxideLaunch" > "$OUT_DIR/MANIFEST.MF"
+echo "Oxide-Launch-Protocol: 2" >> "$OUT_DIR/MANIFEST.MF"
 echo "" >> "$OUT_DIR/MANIFEST.MF"
 
 # Create JAR
@@ -22,6 +22,11 @@
 
 package dev.oxide.launch;
 
+import java.io.IOException;
+import java.nio.charset.StandardCharsets;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.nio.file.Paths;
 import java.util.ArrayList;
 import java.util.List;
 
@@ -32,6 +37,9 @@ import java.util.List;
  */
 public final class LaunchConfig {
     
+    /** Game argument value the launcher substitutes for an access token passed out-of-band */
+    public static final String ACCESS_TOKEN_PLACEHOLDER = "${oxide_access_token}";
+    
     private LauncherType launcherType = LauncherType.STANDARD;
     private String mainClass;
     private String gameDir;
@@ -41,6 +49,7 @@ public final class LaunchConfig {
     private boolean maximize = false;
     private final List<String> tweakClasses = new ArrayList<>();
     private final List<String> gameArgs = new ArrayList<>();
+    private String accessTokenFile;
     
     private LaunchConfig() {}
     
@@ -124,6 +133,12 @@ public final class LaunchConfig {
                     }
                     break;
                     
+                case "--accessTokenFile":
+                    if (i + 1 < args.length) {
+                        config.accessTokenFile = args[++i];
+                    }
+                    break;
+                    
                 default:
                     // Unknown argument - could be a game argument without separator
                     // For safety, just skip it
@@ -138,9 +153,43 @@ public final class LaunchConfig {
             return null;
         }
         
+        if (config.accessTokenFile != null && !config.readAccessToken()) {
+            return null;
+        }
+        
         return config;
     }
     
+    /**
+     * Read the access token from the file the launcher wrote, delete the file,
+     * and put the token in place of the placeholder in the game arguments.
+     * 
+     * @return false if the token file could not be read
+     */
+    private boolean readAccessToken() {
+        Path path = Paths.get(accessTokenFile);
+        String token;
+        try {
+            token = new String(Files.readAllBytes(path), StandardCharsets.UTF_8).trim();
+        } catch (IOException e) {
+            System.err.println("Error: could not read access token file: " + e.getMessage());
+            return false;
+        } finally {
+            try {
+                Files.deleteIfExists(path);
+            } catch (IOException e) {
+                OxideLaunch.debug("Could not delete access token file: " + e.getMessage());
+            }
+        }
+        
+        for (int i = 0; i < gameArgs.size(); i++) {
+            if (ACCESS_TOKEN_PLACEHOLDER.equals(gameArgs.get(i))) {
+                gameArgs.set(i, token);
+            }
+        }
+        return true;
+    }
+    
     // Getters
     
     public LauncherType getLauncherType() {
@@ -187,11 +236,14 @@ public final class LaunchConfig {
     public String[] buildGameArgs() {
         List<String> args = new ArrayList<>(gameArgs);
         
-        // Add window dimensions (most modern versions support these)
-        args.add("--width");
-        args.add(String.valueOf(width));
-        args.add("--height");
-        args.add(String.valueOf(height));
+        // Add window dimensions (most modern versions support these),
+        // unless the launcher already passed them as game arguments
+        if (!gameArgs.contains("--width") && !gameArgs.contains("--fullscreen")) {
+            args.add("--width");
+            args.add(String.valueOf(width));
+            args.add("--height");
+            args.add(String.valueOf(height));
+        }
         
         return args.toArray(new String[0]);
     }
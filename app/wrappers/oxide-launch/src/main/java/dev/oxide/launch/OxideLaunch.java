@@ -50,6 +50,12 @@ public final class OxideLaunch {
     
     public static final String VERSION = "1.0.0";
     
+    /**
+     * Version of the launcher-facing arguments, also written to the JAR manifest
+     * as Oxide-Launch-Protocol. 2 added --accessTokenFile.
+     */
+    public static final int PROTOCOL_VERSION = 2;
+    
     public static void main(String[] args) {
         try {
             // Handle --help and --version before anything else
@@ -60,7 +66,7 @@ public final class OxideLaunch {
                     return;
                 }
                 if ("--version".equals(first) || "-v".equals(first)) {
-                    System.out.println("OxideLaunch v" + VERSION);
+                    System.out.println("OxideLaunch v" + VERSION + " (protocol " + PROTOCOL_VERSION + ")");
                     return;
                 }
             }
@@ -125,6 +131,7 @@ public final class OxideLaunch {
         System.out.println("  --width <pixels>      Window width (default: 854)");
         System.out.println("  --height <pixels>     Window height (default: 480)");
         System.out.println("  --maximize            Start maximized");
+        System.out.println("  --accessTokenFile <path>  Read the access token from a file (deleted after reading)");
         System.out.println("  --help, -h            Show this help message");
         System.out.println("  --version, -v         Show version");
         System.out.println("  --                    Separator between wrapper args and game args");
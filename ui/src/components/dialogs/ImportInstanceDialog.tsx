@@ -18,7 +18,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-import { useState } from "react";
+import { useRef, useState } from "react";
 import { invoke } from "@tauri-apps/api/core";
 import { open as openFileDialog } from "@tauri-apps/plugin-dialog";
 import {
@@ -90,6 +90,9 @@ export function ImportInstanceDialog({
   const [status, setStatus] = useState("");
   const [error, setError] = useState<string | null>(null);
   const [result, setResult] = useState<ImportResult | null>(null);
+  const [cancelling, setCancelling] = useState(false);
+  // ID of the running import, so it can be cancelled
+  const importIdRef = useRef<string | null>(null);
 
   const handleSelectFile = async () => {
     try {
@@ -132,6 +135,9 @@ export function ImportInstanceDialog({
   const handleImport = async () => {
     if (!selectedFile) return;
 
+    const importId = crypto.randomUUID();
+    importIdRef.current = importId;
+
     try {
       setError(null);
       setImporting(true);
@@ -141,6 +147,7 @@ export function ImportInstanceDialog({
       const importResult = await invoke<ImportResult>("import_instance_from_file", {
         archivePath: selectedFile,
         nameOverride: nameOverride || null,
+        importId,
       });
 
       setProgress(100);
@@ -158,6 +165,22 @@ export function ImportInstanceDialog({
       setImporting(false);
       setProgress(0);
       setStatus("");
+    } finally {
+      importIdRef.current = null;
+      setCancelling(false);
+    }
+  };
+
+  const handleCancelImport = async () => {
+    if (!importIdRef.current) return;
+
+    setCancelling(true);
+    setStatus("Cancelling import...");
+    try {
+      await invoke<boolean>("cancel_import", { importId: importIdRef.current });
+    } catch (err) {
+      console.error("Failed to cancel import:", err);
+      setCancelling(false);
     }
   };
 
@@ -345,8 +368,12 @@ export function ImportInstanceDialog({
         <DialogFooter>
           {!result && (
             <>
-              <Button variant="outline" onClick={handleClose} disabled={importing}>
-                Cancel
+              <Button
+                variant="outline"
+                onClick={importing ? handleCancelImport : handleClose}
+                disabled={cancelling}
+              >
+                {cancelling ? "Cancelling..." : "Cancel"}
               </Button>
               <Button 
                 onClick={handleImport} 
@@ -50,6 +50,8 @@ import {
   AccordionTrigger,
 } from "@/components/ui/accordion";
 import { cn } from "@/lib/utils";
+import { useBlockedModDownloads } from "@/hooks/useBlockedModDownloads";
+import { BlockedModsDialog } from "./BlockedModsDialog";
 
 export interface UpdateCheckResult {
   filename: string;
@@ -65,6 +67,7 @@ export interface BatchUpdateResult {
   updates_available: UpdateCheckResult[];
   up_to_date: string[];
   unchecked: string[];
+  pinned: string[];
   errors: string[];
 }
 
@@ -89,6 +92,7 @@ export function ModUpdatesDialog({
   const [selectedMods, setSelectedMods] = useState<Set<string>>(new Set());
   const [updateProgress, setUpdateProgress] = useState<Map<string, "pending" | "updating" | "success" | "error">>(new Map());
   const [error, setError] = useState<string | null>(null);
+  const blockedDownloads = useBlockedModDownloads(open ? instanceId : "");
 
   const checkForUpdates = async () => {
     setChecking(true);
@@ -263,6 +267,11 @@ export function ModUpdatesDialog({
                     {results.unchecked.length} unchecked
                   </Badge>
                 )}
+                {results.pinned.length > 0 && (
+                  <Badge variant="outline" className="flex items-center gap-1">
+                    {results.pinned.length} pinned
+                  </Badge>
+                )}
                 {results.errors.length > 0 && (
                   <Badge variant="destructive" className="flex items-center gap-1">
                     <AlertCircle className="h-3 w-3" />
@@ -433,6 +442,19 @@ export function ModUpdatesDialog({
             Close
           </Button>
         </DialogFooter>
+
+        {/* Updates whose CurseForge download has to be done manually */}
+        <BlockedModsDialog
+          open={blockedDownloads.dialogOpen}
+          onOpenChange={blockedDownloads.setDialogOpen}
+          blockedMods={blockedDownloads.blockedMods}
+          instanceId={instanceId}
+          onContinue={() => {
+            blockedDownloads.clear();
+            onModsUpdated?.();
+          }}
+          onSkip={blockedDownloads.clear}
+        />
       </DialogContent>
     </Dialog>
   );
@@ -19,6 +19,8 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 export { useJavaManagement } from './useJavaManagement';
+export { useBlockedModDownloads } from './useBlockedModDownloads';
+export type { UseBlockedModDownloadsReturn } from './useBlockedModDownloads';
 export type { UseJavaManagementReturn } from './useJavaManagement';
 export { useTheme, ThemeProvider } from './useTheme';
 export { useConfig, ConfigProvider } from './useConfig';
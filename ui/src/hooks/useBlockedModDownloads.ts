@@ -0,0 +1,72 @@
+// Hook collecting CurseForge mod downloads that must be done manually.
+//
+// Oxide Launcher — A Rust-based Minecraft launcher
+// Copyright (C) 2025 Oxide Launcher contributors
+//
+// This file is part of Oxide Launcher.
+//
+// Oxide Launcher is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Oxide Launcher is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+import { useState, useEffect, useCallback } from "react";
+import { listen } from "@tauri-apps/api/event";
+import type { BlockedMod } from "@/components/dialogs/BlockedModsDialog";
+
+interface ModDownloadBlocked {
+  instance_id: string;
+  blocked_mods: BlockedMod[];
+}
+
+export interface UseBlockedModDownloadsReturn {
+  blockedMods: BlockedMod[];
+  dialogOpen: boolean;
+  setDialogOpen: (open: boolean) => void;
+  // Close the dialog and forget the collected mods
+  clear: () => void;
+}
+
+/**
+ * Collect the mods refused by single-mod downloads into an instance
+ * (the backend's "mod-download-blocked" event) so they can be shown in
+ * the blocked mods dialog.
+ */
+export function useBlockedModDownloads(instanceId: string): UseBlockedModDownloadsReturn {
+  const [blockedMods, setBlockedMods] = useState<BlockedMod[]>([]);
+  const [dialogOpen, setDialogOpen] = useState(false);
+
+  useEffect(() => {
+    if (!instanceId) return;
+
+    const unlisten = listen<ModDownloadBlocked>("mod-download-blocked", (event) => {
+      if (event.payload.instance_id !== instanceId) return;
+
+      const incoming = event.payload.blocked_mods;
+      setBlockedMods((prev) => [
+        ...prev.filter((mod) => !incoming.some((m) => m.file_id === mod.file_id)),
+        ...incoming,
+      ]);
+      setDialogOpen(true);
+    });
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [instanceId]);
+
+  const clear = useCallback(() => {
+    setDialogOpen(false);
+    setBlockedMods([]);
+  }, []);
+
+  return { blockedMods, dialogOpen, setDialogOpen, clear };
+}
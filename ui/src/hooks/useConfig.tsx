@@ -53,6 +53,7 @@ export interface NetworkConfig {
   user_agent: string;
   downloads_dir: string | null;
   downloads_dir_watch_recursive: boolean;
+  check_blocked_servers: boolean;
 }
 
 export interface ProxyConfig {
@@ -75,6 +76,9 @@ export interface UiConfig {
   last_instance: string | null;
   rust_mode: boolean;
   open_instance_after_install: boolean;
+  minimize_to_tray: boolean;
+  start_minimized: boolean;
+  warm_up_on_startup: boolean;
 }
 
 export interface MinecraftConfig {
@@ -87,6 +91,7 @@ export interface MinecraftConfig {
   show_console_on_error: boolean;
   record_game_time: boolean;
   show_game_time: boolean;
+  access_token_out_of_band: boolean;
 }
 
 export interface CustomCommands {
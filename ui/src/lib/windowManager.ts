@@ -332,9 +332,15 @@ export async function setupMainWindowCloseHandler(): Promise<void> {
     }
     
     // Listen for close request on main window
-    mainWindowCloseListener = await mainWindow.onCloseRequested(async () => {
+    mainWindowCloseListener = await mainWindow.onCloseRequested(async (event) => {
       // Close any open dialog windows first
       await closeAllDialogWindows();
+      
+      // Hide to the tray instead of quitting; the tray's "Quit" still exits
+      if (await isMinimizeToTrayEnabled()) {
+        event.preventDefault();
+        await mainWindow.hide();
+      }
     });
   } catch (error) {
     console.error("Failed to setup main window close handler:", error);
@@ -437,6 +443,18 @@ export async function isWindowPositionMemoryEnabled(windowType: string): Promise
   }
 }
 
+/**
+ * Check if closing the main window should hide it to the tray
+ */
+export async function isMinimizeToTrayEnabled(): Promise<boolean> {
+  try {
+    return await invoke<boolean>("is_minimize_to_tray_enabled");
+  } catch (error) {
+    console.error("Failed to check minimize to tray:", error);
+    return false;
+  }
+}
+
 /**
  * Get current window position and size
  */
@@ -526,12 +544,17 @@ export async function setupMainWindowPositionMemory(): Promise<() => void> {
   const unlistenResize = mainWindow.onResized(debouncedSave);
   
   // Save final position when closing
-  const unlistenClose = mainWindow.onCloseRequested(async () => {
+  const unlistenClose = mainWindow.onCloseRequested(async (event) => {
     const isEnabled = await isWindowPositionMemoryEnabled("main");
     if (isEnabled) {
       const state = await getCurrentWindowState(mainWindow);
       await saveWindowState("main", state);
     }
+    
+    // Every close listener has to prevent the close, or the window is destroyed
+    if (await isMinimizeToTrayEnabled()) {
+      event.preventDefault();
+    }
   });
   
   // Return cleanup function
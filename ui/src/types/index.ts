@@ -97,6 +97,13 @@ export interface ShaderPackInfo {
   filename: string;
   name: string;
   size: string;
+  active: boolean;
+}
+
+export interface ActiveShaderPack {
+  loader: "iris" | "oculus" | "optifine" | null;
+  pack: string | null;
+  message: string | null;
 }
 
 export interface ScreenshotInfo {
@@ -230,3 +237,117 @@ export interface LogEntry {
   level: LogLevel;
   content: string;
 }
+
+/** Payload of the `instance-log-line` event */
+export interface InstanceLogLine extends LogEntry {
+  instance_id: string;
+  seq: number;
+}
+
+/** Buffered log returned by `subscribe_instance_logs` */
+export interface InstanceLogSnapshot {
+  entries: LogEntry[];
+  next_seq: number;
+  dropped: number;
+}
+
+export interface DanglingReference {
+  // null for the global Java setting
+  instance_id: string | null;
+  instance_name: string | null;
+  kind: "java";
+  value: string;
+}
+
+export interface Lockfile {
+  minecraft_version: string;
+  ignore: string[];
+  files: Record<string, string>;
+}
+
+export interface LockfileDrift {
+  missing: string[];
+  changed: string[];
+  extra: string[];
+  minecraft_version: string | null;
+}
+
+export interface BlockedServerList {
+  hashes: string[];
+  fetched_at: string;
+}
+
+export interface BlockedServerCheck {
+  address: string;
+  blocked: boolean;
+  matched: string | null;
+}
+
+export interface GroupSettingsUpdate {
+  java_path?: string | null;
+  java_args?: string | null;
+  min_memory?: number | null;
+  max_memory?: number | null;
+  window_width?: number | null;
+  window_height?: number | null;
+  fullscreen?: boolean | null;
+}
+
+export interface GroupSettingsResult {
+  instance_id: string;
+  name: string;
+  error: string | null;
+}
+
+export interface ExitStatus {
+  reason: "clean" | "crashed" | "failed" | "killed";
+  exit_code: number | null;
+  crash_report: string | null;
+  exited_at: string;
+}
+
+export interface WarmUpReport {
+  versions_fetched: boolean;
+  java_installations: number;
+  instance_summaries: number;
+}
+
+export interface PlannedLibrary {
+  name: string;
+  url: string;
+  size: number | null;
+  installed: boolean;
+  non_standard_repo: boolean;
+}
+
+export interface InstallPlan {
+  loader_type: "Forge" | "NeoForge" | "Fabric" | "Quilt" | "LiteLoader";
+  minecraft_version: string;
+  loader_version: string;
+  libraries: PlannedLibrary[];
+  download_size: number;
+  unknown_sizes: number;
+  non_standard_repos: string[];
+}
+
+export interface InstanceDiskUsage {
+  total: number;
+  mods: number;
+  saves: number;
+  resourcepacks: number;
+  shaderpacks: number;
+  libraries: number;
+  other: number;
+}
+
+export interface DuplicateModFile {
+  filename: string;
+  version: string;
+  enabled: boolean;
+}
+
+export interface DuplicateMods {
+  mod_id: string;
+  name: string;
+  files: DuplicateModFile[];
+}
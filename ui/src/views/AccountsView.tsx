@@ -31,6 +31,8 @@ import {
   ExternalLink,
   AlertCircle,
   Palette,
+  ArrowUp,
+  ArrowDown,
 } from "lucide-react";
 import { Button } from "@/components/ui/button";
 import { Card, CardContent } from "@/components/ui/card";
@@ -75,6 +77,7 @@ export function AccountsView() {
   const [refreshingAccount, setRefreshingAccount] = useState<string | null>(null);
   const [isMsaConfigured, setIsMsaConfigured] = useState(false);
   const [ownershipVerified, setOwnershipVerified] = useState(false);
+  const [tokenStatus, setTokenStatus] = useState<Record<string, "valid" | "expired" | "needs_relogin">>({});
 
   // Microsoft login state
   const [deviceCode, setDeviceCode] = useState<DeviceCodeInfo | null>(null);
@@ -126,12 +129,33 @@ export function AccountsView() {
     try {
       const data = await invoke<AccountInfo[]>("get_accounts");
       setAccounts(data);
+      const statuses = await invoke<{ account_id: string; status: "valid" | "expired" | "needs_relogin" }[]>(
+        "get_account_token_status"
+      );
+      setTokenStatus(Object.fromEntries(statuses.map(s => [s.account_id, s.status])));
     } catch (error) {
       console.error("Failed to load accounts:", error);
       setError(String(error));
     }
   };
 
+  const moveAccount = async (index: number, offset: number) => {
+    const target = index + offset;
+    if (target < 0 || target >= accounts.length) return;
+
+    const reordered = [...accounts];
+    [reordered[index], reordered[target]] = [reordered[target], reordered[index]];
+    setAccounts(reordered);
+
+    try {
+      await invoke("reorder_accounts", { orderedIds: reordered.map(a => a.id) });
+    } catch (error) {
+      console.error("Failed to reorder accounts:", error);
+      setError(String(error));
+      loadAccounts();
+    }
+  };
+
   const checkMsaConfigured = async () => {
     try {
       const configured = await invoke<boolean>("is_microsoft_configured");
@@ -553,7 +577,7 @@ export function AccountsView() {
         </div>
       ) : (
         <div className="space-y-4">
-          {accounts.map((account) => (
+          {accounts.map((account, index) => (
             <Card
               key={account.id}
               className={`transition-all ${
@@ -580,7 +604,11 @@ export function AccountsView() {
                       {account.is_active && (
                         <Badge className="bg-emerald-500/10 text-emerald-600 dark:text-emerald-400 border-emerald-500/30 hover:bg-emerald-500/20">Active</Badge>
                       )}
-                      {account.account_type === "Microsoft" && !account.is_valid && (
+                      {tokenStatus[account.id] === "needs_relogin" ? (
+                        <Badge variant="destructive" title="The saved login is too old to renew. Remove the account and log in again.">
+                          Needs Re-login
+                        </Badge>
+                      ) : account.account_type === "Microsoft" && !account.is_valid && (
                         <Badge variant="destructive">Expired</Badge>
                       )}
                       {account.account_type === "Microsoft" &&
@@ -602,6 +630,24 @@ export function AccountsView() {
                   </div>
                 </div>
                 <div className="flex gap-2">
+                  <Button
+                    variant="ghost"
+                    size="sm"
+                    onClick={() => moveAccount(index, -1)}
+                    disabled={index === 0}
+                    title="Move up"
+                  >
+                    <ArrowUp className="h-4 w-4" />
+                  </Button>
+                  <Button
+                    variant="ghost"
+                    size="sm"
+                    onClick={() => moveAccount(index, 1)}
+                    disabled={index === accounts.length - 1}
+                    title="Move down"
+                  >
+                    <ArrowDown className="h-4 w-4" />
+                  </Button>
                   {account.account_type === "Microsoft" && (
                     <>
                       <Button
@@ -18,12 +18,15 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-import { useState, useEffect } from "react";
+import { useState, useEffect, useRef } from "react";
 import { useNavigate, useSearchParams } from "react-router-dom";
 import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
 import { Package } from "lucide-react";
 import { Button } from "@/components/ui/button";
+import { Progress } from "@/components/ui/progress";
 import { cn } from "@/lib/utils";
+import { formatFileSize } from "./instance-details/utils";
 import { 
   ImportTab, 
   CustomTab,
@@ -32,6 +35,56 @@ import {
 } from "./create-instance";
 import { openDialogWindow, WINDOW_LABELS, setupDialogEventListeners } from "@/lib/windowManager";
 
+/** A service's entry in the `check_connectivity` result */
+interface ServiceStatus {
+  id: string;
+  name: string;
+  reachable: boolean;
+  error: string | null;
+}
+
+/** A volume's entry in the `get_storage_info` result */
+interface VolumeInfo {
+  mount_point: string;
+  free_bytes: number;
+  total_bytes: number;
+  low: boolean;
+  folders: string[];
+}
+
+/** Payload of the `instance-setup-progress` event */
+interface InstanceSetupProgress {
+  instance_id: string;
+  phase: string;
+  // Bytes downloaded so far and in total for the current phase
+  current: number | null;
+  total: number | null;
+  percent: number;
+}
+
+/** Payload of the `instance-setup-complete` and `instance-setup-failed` events */
+interface InstanceSetupResult {
+  instance_id: string;
+  error: string | null;
+}
+
+/** A setup event, tagged with which of the events it came from */
+type SetupEvent =
+  | { kind: "progress"; payload: InstanceSetupProgress }
+  | { kind: "complete" | "failed"; payload: InstanceSetupResult };
+
+const SETUP_PHASE_LABELS: Record<string, string> = {
+  version_manifest: "Fetching version list",
+  version_data: "Fetching version data",
+  client_jar: "Downloading Minecraft",
+  libraries: "Downloading libraries",
+  natives: "Extracting natives",
+  asset_index: "Fetching asset index",
+  assets: "Downloading assets",
+  modloader: "Installing mod loader",
+  complete: "Finishing up",
+};
+
 export function CreateInstanceView() {
   const navigate = useNavigate();
   const [searchParams] = useSearchParams();
@@ -76,12 +129,93 @@ export function CreateInstanceView() {
   const [loaderVersion, setLoaderVersion] = useState("");
   const [creating, setCreating] = useState(false);
   const [group, setGroup] = useState("");
+  // Instance whose game files are being set up, and how far along it is
+  const [setupInstanceId, setSetupInstanceId] = useState<string | null>(null);
+  const [setupProgress, setSetupProgress] = useState<InstanceSetupProgress | null>(null);
+  const [setupError, setSetupError] = useState<string | null>(null);
+  // Services that didn't answer the pre-flight check
+  const [unreachable, setUnreachable] = useState<ServiceStatus[]>([]);
+  // Volumes for the instance, library and asset folders that are low on space
+  const [lowVolumes, setLowVolumes] = useState<VolumeInfo[]>([]);
+
+  useEffect(() => {
+    invoke<ServiceStatus[]>("check_connectivity")
+      .then((statuses) => setUnreachable(statuses.filter((status) => !status.reachable)))
+      .catch((error) => console.error("Failed to check connectivity:", error));
+    invoke<VolumeInfo[]>("get_storage_info")
+      .then((volumes) => setLowVolumes(volumes.filter((volume) => volume.low)))
+      .catch((error) => console.error("Failed to get storage info:", error));
+  }, []);
+
+  // Setup can finish or fail before create_instance returns the id, so
+  // events are listened for from the start and held until the id is known
+  const setupIdRef = useRef<string | null>(null);
+  // Non-null while create_instance is running
+  const pendingSetupEvents = useRef<SetupEvent[] | null>(null);
+  const handleSetupEventRef = useRef<(event: SetupEvent) => void>(() => {});
+
+  handleSetupEventRef.current = (event: SetupEvent) => {
+    if (event.payload.instance_id !== setupIdRef.current) return;
+    switch (event.kind) {
+      case "progress":
+        setSetupProgress(event.payload);
+        break;
+      case "complete":
+        navigate("/");
+        break;
+      case "failed":
+        setSetupError(event.payload.error || "Unknown error");
+        setCreating(false);
+        break;
+    }
+  };
+
+  useEffect(() => {
+    const receive = (event: SetupEvent) => {
+      if (pendingSetupEvents.current) {
+        pendingSetupEvents.current.push(event);
+      } else {
+        handleSetupEventRef.current(event);
+      }
+    };
+
+    const unlisteners = [
+      listen<InstanceSetupProgress>("instance-setup-progress", (event) => {
+        receive({ kind: "progress", payload: event.payload });
+      }),
+      listen<InstanceSetupResult>("instance-setup-complete", (event) => {
+        receive({ kind: "complete", payload: event.payload });
+      }),
+      listen<InstanceSetupResult>("instance-setup-failed", (event) => {
+        receive({ kind: "failed", payload: event.payload });
+      }),
+    ];
+
+    return () => {
+      unlisteners.forEach((promise) => promise.then((unlisten) => unlisten()));
+    };
+  }, []);
+
+  // Pre-select the loader from the new instance template
+  useEffect(() => {
+    invoke<{ mod_loader: string | null }>("get_instance_template")
+      .then((template) => {
+        if (template.mod_loader) {
+          setModLoader(template.mod_loader);
+        }
+      })
+      .catch((error) => console.error("Failed to load instance template:", error));
+  }, []);
 
   const handleCreateInstance = async () => {
     setCreating(true);
+    setSetupProgress(null);
+    setSetupError(null);
+    setupIdRef.current = null;
+    pendingSetupEvents.current = [];
 
     try {
-      await invoke("create_instance", {
+      const instanceId = await invoke<string>("create_instance", {
         request: {
           name,
           minecraft_version: version,
@@ -90,11 +224,16 @@ export function CreateInstanceView() {
           group: group || null,
         },
       });
-      navigate("/");
+      // Stay here until the game files are downloaded
+      setupIdRef.current = instanceId;
+      setSetupInstanceId(instanceId);
+      const pending = pendingSetupEvents.current ?? [];
+      pendingSetupEvents.current = null;
+      pending.forEach((event) => handleSetupEventRef.current(event));
     } catch (error) {
       console.error("Failed to create instance:", error);
+      pendingSetupEvents.current = null;
       alert("Failed to create instance: " + error);
-    } finally {
       setCreating(false);
     }
   };
@@ -175,9 +314,38 @@ export function CreateInstanceView() {
 
       {/* Bottom Actions - Only shown for custom tab */}
       {showBottomActions && (
-        <div className="flex flex-wrap justify-end gap-2 md:gap-3 pt-3 md:pt-4 mt-3 md:mt-4 border-t">
+        <div className="flex flex-wrap items-center justify-end gap-2 md:gap-3 pt-3 md:pt-4 mt-3 md:mt-4 border-t">
+          {creating && setupInstanceId && (
+            <div className="flex flex-1 items-center gap-3 min-w-[12rem]">
+              <span className="text-sm text-muted-foreground whitespace-nowrap">
+                {SETUP_PHASE_LABELS[setupProgress?.phase ?? ""] ?? "Setting up"}
+                {setupProgress?.total
+                  ? ` (${formatFileSize(setupProgress.current ?? 0)} / ${formatFileSize(setupProgress.total)})`
+                  : ""}
+              </span>
+              <Progress value={setupProgress?.percent ?? 0} className="h-2" />
+            </div>
+          )}
+          {setupError && (
+            <span className="flex-1 text-sm text-destructive">
+              Instance created, but setup failed: {setupError}
+            </span>
+          )}
+          {!creating && !setupError && unreachable.length > 0 && (
+            <span className="flex-1 text-sm text-yellow-600 dark:text-yellow-500">
+              {unreachable.map((status) => status.name).join(", ")}{" "}
+              {unreachable.length === 1 ? "is" : "are"} unreachable; downloads may fail
+            </span>
+          )}
+          {!creating && !setupError && lowVolumes.length > 0 && (
+            <span className="flex-1 text-sm text-yellow-600 dark:text-yellow-500">
+              Low disk space on {lowVolumes.map((volume) =>
+                `${volume.mount_point} (${(volume.free_bytes / 1024 ** 3).toFixed(1)} GB free)`
+              ).join(", ")}
+            </span>
+          )}
           <Button type="button" variant="outline" size="sm" className="md:size-default" onClick={() => navigate("/")}>
-            Cancel
+            {creating && setupInstanceId ? "Continue in background" : "Cancel"}
           </Button>
           <Button 
             type="button" 
@@ -18,9 +18,10 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-import { useEffect, useState, useRef } from "react";
+import { useEffect, useState, useRef, useCallback } from "react";
 import { useParams, useNavigate, useSearchParams } from "react-router-dom";
 import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
 import { ArrowLeft, Save, Play, Square, ChevronDown, X } from "lucide-react";
 import { Button } from "@/components/ui/button";
 import { Badge } from "@/components/ui/badge";
@@ -31,9 +32,15 @@ import {
   DropdownMenuItem,
   DropdownMenuTrigger,
 } from "@/components/ui/dropdown-menu";
+import {
+  Tooltip,
+  TooltipContent,
+  TooltipProvider,
+  TooltipTrigger,
+} from "@/components/ui/tooltip";
 import { cn } from "@/lib/utils";
 import { useConfig } from "@/hooks/useConfig";
-import type { LogEntry } from "@/types";
+import type { LogEntry, InstanceLogLine, InstanceLogSnapshot } from "@/types";
 
 // Import modular tab components
 import {
@@ -51,6 +58,12 @@ import {
   SettingsTab,
 } from "./instance-details";
 
+/** A problem that would make launching fail, as reported by `can_launch` */
+interface LaunchBlocker {
+  kind: "account" | "version_jar" | "modloader_profile" | "java";
+  message: string;
+}
+
 export function InstanceDetailsView() {
   const { id } = useParams<{ id: string }>();
   const navigate = useNavigate();
@@ -58,12 +71,13 @@ export function InstanceDetailsView() {
   const { config } = useConfig();
   
   // Get initial tab from URL parameter, default to "log"
-  const initialTab = (searchParams.get("tab") as TabType) || "log";
-  const [activeTab, setActiveTab] = useState<TabType>(initialTab);
+  const urlTab = searchParams.get("tab") as TabType | null;
+  const [activeTab, setActiveTab] = useState<TabType>(urlTab || "log");
   const [instance, setInstance] = useState<InstanceInfo | null>(null);
   const [loading, setLoading] = useState(true);
   const [launching, setLaunching] = useState(false);
   const [isRunning, setIsRunning] = useState(false);
+  const [launchBlockers, setLaunchBlockers] = useState<LaunchBlocker[]>([]);
   
   // Track previous running state for detecting game exit
   const wasRunningRef = useRef(false);
@@ -71,6 +85,8 @@ export function InstanceDetailsView() {
 
   // Log state
   const [logContent, setLogContent] = useState<LogEntry[]>([]);
+  // Sequence number of the next streamed line we haven't seen
+  const nextLogSeqRef = useRef(0);
   const [searchTerm, setSearchTerm] = useState("");
   const [autoScroll, setAutoScroll] = useState(true);
   const [wrapLines, setWrapLines] = useState(false);
@@ -112,14 +128,6 @@ export function InstanceDetailsView() {
         }
         
         wasRunningRef.current = status.running;
-
-        // Fetch logs
-        const logs = await invoke<LogEntry[]>("get_instance_logs", {
-          instanceId: id,
-        });
-        if (logs.length > 0) {
-          setLogContent(logs);
-        }
       } catch (error) {
         console.error("Failed to fetch instance status:", error);
       }
@@ -128,6 +136,69 @@ export function InstanceDetailsView() {
     return () => clearInterval(interval);
   }, [id, config?.minecraft.auto_close_console, config?.minecraft.show_console_on_error, navigate]);
 
+  // Subscribe to the log while the page is open. The snapshot holds everything
+  // buffered so far; later lines arrive as instance-log-line events.
+  const loadLogSnapshot = useCallback(async () => {
+    if (!id) return;
+    try {
+      const snapshot = await invoke<InstanceLogSnapshot>("subscribe_instance_logs", { instanceId: id });
+      nextLogSeqRef.current = snapshot.next_seq;
+      if (snapshot.entries.length > 0) {
+        setLogContent(snapshot.entries);
+      }
+    } catch (error) {
+      console.error("Failed to subscribe to instance logs:", error);
+    }
+  }, [id]);
+
+  useEffect(() => {
+    if (!id) return;
+
+    const unlistenPromise = listen<InstanceLogLine>("instance-log-line", (event) => {
+      const { instance_id, seq, ...entry } = event.payload;
+      if (instance_id !== id || seq < nextLogSeqRef.current) return;
+      nextLogSeqRef.current = seq + 1;
+      setLogContent(prev => [...prev, entry]);
+    });
+
+    return () => {
+      unlistenPromise.then(unlisten => unlisten());
+      invoke("unsubscribe_instance_logs", { instanceId: id }).catch(() => {});
+    };
+  }, [id]);
+
+  // Sequence numbers restart with each launch, so take a fresh snapshot then
+  useEffect(() => {
+    loadLogSnapshot();
+  }, [loadLogSnapshot, isRunning]);
+
+  // Re-check launch readiness whenever the game stops or the instance changes
+  useEffect(() => {
+    if (!id || isRunning) return;
+    invoke<LaunchBlocker[]>("can_launch", { instanceId: id })
+      .then(setLaunchBlockers)
+      .catch((error) => console.error("Failed to check launch readiness:", error));
+  }, [id, isRunning, instance]);
+
+  // Without a tab in the URL, reopen on the tab last used
+  useEffect(() => {
+    if (!id || urlTab) return;
+    invoke<string | null>("get_instance_tab", { instanceId: id })
+      .then((tab) => {
+        if (tab && TABS.some((t) => t.id === tab)) {
+          setActiveTab(tab as TabType);
+        }
+      })
+      .catch((error) => console.error("Failed to load last instance tab:", error));
+  }, [id]);
+
+  const changeTab = (tab: TabType) => {
+    setActiveTab(tab);
+    invoke("set_instance_tab", { instanceId: id, tab }).catch((error) =>
+      console.error("Failed to save instance tab:", error)
+    );
+  };
+
   useEffect(() => {
     if (activeTab === "notes") {
       loadNotes();
@@ -209,6 +280,17 @@ export function InstanceDetailsView() {
     }
   };
 
+  // For when the game is gone but the launcher still thinks it's running
+  const clearRunningState = async () => {
+    if (!id) return;
+    try {
+      await invoke("clear_running_state", { instanceId: id });
+      setIsRunning(false);
+    } catch (error) {
+      console.error("Failed to clear running state:", error);
+    }
+  };
+
   if (loading) {
     return (
       <div className="flex items-center justify-center h-full">
@@ -253,6 +335,11 @@ export function InstanceDetailsView() {
             <div className="flex flex-wrap items-center gap-1 sm:gap-2 mt-1">
               <Badge className="text-xs bg-emerald-500/10 text-emerald-600 dark:text-emerald-400 border-emerald-500/30 hover:bg-emerald-500/20">{instance.minecraft_version}</Badge>
               <Badge variant="outline" className={cn("text-xs", getLoaderBadgeClass(instance.mod_loader))}>{instance.mod_loader}</Badge>
+              {instance.demo_locked && (
+                <Badge variant="outline" className="text-xs bg-amber-500/10 text-amber-600 dark:text-amber-400 border-amber-500/30">
+                  Demo locked
+                </Badge>
+              )}
               {instance.mod_loader_version && (
                 <span className="text-xs text-muted-foreground hidden sm:inline">
                   v{instance.mod_loader_version}
@@ -265,37 +352,66 @@ export function InstanceDetailsView() {
         {/* Action buttons */}
         <div className="flex items-center gap-2 sm:ml-auto">
           {isRunning ? (
-            <Button variant="destructive" size="sm" onClick={killGame}>
-              <Square className="h-4 w-4 sm:mr-2" />
-              <span className="hidden sm:inline">Kill</span>
-            </Button>
-          ) : (
-            <div className="flex">
-              <Button 
-                className="rounded-r-none" 
+            <>
+              <Button variant="destructive" size="sm" onClick={killGame}>
+                <Square className="h-4 w-4 sm:mr-2" />
+                <span className="hidden sm:inline">Kill</span>
+              </Button>
+              <Button
+                variant="ghost"
                 size="sm"
-                onClick={() => launchGame("normal")}
-                disabled={launching}
+                onClick={clearRunningState}
+                title="Mark as not running without stopping the game"
               >
-                <Play className="h-4 w-4 sm:mr-2" />
-                <span className="hidden sm:inline">{launching ? "Launching..." : "Launch"}</span>
+                <X className="h-4 w-4" />
               </Button>
+            </>
+          ) : (
+            <div className="flex">
+              <TooltipProvider delayDuration={200}>
+                <Tooltip>
+                  <TooltipTrigger asChild>
+                    {/* Disabled buttons don't receive hover events, so the span carries the tooltip */}
+                    <span tabIndex={launchBlockers.length > 0 ? 0 : undefined}>
+                      <Button 
+                        className="rounded-r-none" 
+                        size="sm"
+                        onClick={() => launchGame("normal")}
+                        disabled={launching || launchBlockers.length > 0}
+                      >
+                        <Play className="h-4 w-4 sm:mr-2" />
+                        <span className="hidden sm:inline">
+                          {launching ? "Launching..." : instance.demo_locked ? "Launch Demo" : "Launch"}
+                        </span>
+                      </Button>
+                    </span>
+                  </TooltipTrigger>
+                  {launchBlockers.length > 0 && (
+                    <TooltipContent side="bottom" className="max-w-xs">
+                      {launchBlockers.map((blocker) => (
+                        <p key={blocker.kind} className="text-sm">{blocker.message}</p>
+                      ))}
+                    </TooltipContent>
+                  )}
+                </Tooltip>
+              </TooltipProvider>
               <DropdownMenu>
                 <DropdownMenuTrigger asChild>
                   <Button 
                     className="rounded-l-none border-l border-primary-foreground/20 px-2"
                     size="sm"
-                    disabled={launching}
+                    disabled={launching || launchBlockers.length > 0}
                   >
                     <ChevronDown className="h-4 w-4" />
                   </Button>
                 </DropdownMenuTrigger>
                 <DropdownMenuContent align="end">
-                  <DropdownMenuItem onClick={() => launchGame("normal")}>
+                  {/* A demo-locked instance launches as a demo whatever mode is picked */}
+                  <DropdownMenuItem onClick={() => launchGame("normal")} disabled={instance.demo_locked}>
                     <Play className="h-4 w-4 mr-2" />
                     Launch
                   </DropdownMenuItem>
-                  <DropdownMenuItem onClick={() => launchGame("offline")}>
+                  <DropdownMenuItem onClick={() => launchGame("offline")} disabled={instance.demo_locked}>
                     Launch Offline
                   </DropdownMenuItem>
                   <DropdownMenuItem onClick={() => launchGame("demo")}>
@@ -314,7 +430,7 @@ export function InstanceDetailsView() {
       {/* Tabs */}
       <Tabs
         value={activeTab}
-        onValueChange={(v) => setActiveTab(v as TabType)}
+        onValueChange={(v) => changeTab(v as TabType)}
         className="flex-1 flex flex-col overflow-hidden"
       >
         <TabsList className="w-full justify-start gap-0.5 rounded-none border-b bg-transparent px-2 sm:px-4 h-auto py-1 overflow-x-auto flex-shrink-0">
@@ -395,7 +511,7 @@ export function InstanceDetailsView() {
 
           {/* Settings Tab */}
           <TabsContent value="settings" className="h-full m-0">
-            <SettingsTab instanceId={id} instance={instance} />
+            <SettingsTab instanceId={id} instance={instance} onInstanceChanged={loadInstance} />
           </TabsContent>
         </div>
       </Tabs>
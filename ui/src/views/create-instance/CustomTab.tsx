@@ -20,7 +20,7 @@
 
 import { useState, useEffect } from "react";
 import { invoke } from "@tauri-apps/api/core";
-import { RefreshCw, Star } from "lucide-react";
+import { HardDrive, RefreshCw, Star } from "lucide-react";
 import { Button } from "@/components/ui/button";
 import { Card, CardContent, CardHeader, CardTitle } from "@/components/ui/card";
 import { Input } from "@/components/ui/input";
@@ -44,6 +44,19 @@ interface CustomTabProps {
   setLoaderVersion: (version: string) => void;
 }
 
+// Entry of get_downloaded_versions
+interface LocalVersion {
+  id: string;
+  complete: boolean;
+}
+
+// Result of validate_instance_name
+interface NameValidation {
+  valid: boolean;
+  issues: string[];
+  suggested_name: string | null;
+}
+
 export function CustomTab({
   name,
   setName,
@@ -56,11 +69,21 @@ export function CustomTab({
   loaderVersion,
   setLoaderVersion,
 }: CustomTabProps) {
+  const [nameValidation, setNameValidation] = useState<NameValidation | null>(null);
+
   // Version list state
   const [versions, setVersions] = useState<MinecraftVersion[]>([]);
   const [filteredVersions, setFilteredVersions] = useState<MinecraftVersion[]>([]);
   const [loadingVersions, setLoadingVersions] = useState(true);
   const [versionSearch, setVersionSearch] = useState("");
+  // Versions whose game files are already downloaded
+  const [cachedVersions, setCachedVersions] = useState<Set<string>>(new Set());
+
+  useEffect(() => {
+    invoke<LocalVersion[]>("get_downloaded_versions")
+      .then((local) => setCachedVersions(new Set(local.filter((v) => v.complete).map((v) => v.id))))
+      .catch((error) => console.error("Failed to load downloaded versions:", error));
+  }, []);
   
   // Version filters
   const [showReleases, setShowReleases] = useState(true);
@@ -69,9 +92,14 @@ export function CustomTab({
   const [showBetas, setShowBetas] = useState(false);
   const [showAlphas, setShowAlphas] = useState(false);
 
+  // Loaders with versions for the selected Minecraft version; null until
+  // loaded or if the metadata couldn't be fetched
+  const [supportedLoaders, setSupportedLoaders] = useState<string[] | null>(null);
+
   // Loader version state
   const [loaderVersions, setLoaderVersions] = useState<LoaderVersion[]>([]);
   const [loadingLoaderVersions, setLoadingLoaderVersions] = useState(false);
+  const [showPrereleaseLoaders, setShowPrereleaseLoaders] = useState(false);
 
   // Load Minecraft versions on mount and when filters change
   useEffect(() => {
@@ -91,6 +119,40 @@ export function CustomTab({
     }
   }, [versionSearch, versions]);
 
+  // Ask which loaders have versions for the selected Minecraft version
+  useEffect(() => {
+    if (!version) {
+      setSupportedLoaders(null);
+      return;
+    }
+
+    let cancelled = false;
+    setSupportedLoaders(null);
+    invoke<string[]>("get_supported_loaders", { minecraftVersion: version })
+      .then((loaders) => {
+        if (cancelled) return;
+        setSupportedLoaders(loaders);
+        if (modLoader !== "None" && !loaders.includes(modLoader)) {
+          setModLoader("None");
+        }
+      })
+      .catch((error) => console.error("Failed to load supported loaders:", error));
+
+    return () => {
+      cancelled = true;
+    };
+  }, [version]);
+
+  // Without the metadata, fall back to the known version ranges
+  const loaderCompatibility = (loader: string) => {
+    if (loader === "None" || !supportedLoaders) {
+      return isLoaderCompatible(loader, version);
+    }
+    return supportedLoaders.includes(loader)
+      ? { compatible: true }
+      : { compatible: false, reason: `No ${loader} versions for ${version}` };
+  };
+
   // Load loader versions when MC version or loader type changes
   useEffect(() => {
     if (version && modLoader !== "None") {
@@ -99,7 +161,7 @@ export function CustomTab({
       setLoaderVersions([]);
       setLoaderVersion("");
     }
-  }, [version, modLoader]);
+  }, [version, modLoader, showPrereleaseLoaders, showSnapshots]);
 
   // Auto-set name from version when version changes
   useEffect(() => {
@@ -146,26 +208,33 @@ export function CustomTab({
         case "Forge":
           data = await invoke<LoaderVersion[]>("get_forge_versions", {
             minecraftVersion: version,
+            includePrerelease: showPrereleaseLoaders,
           });
           break;
         case "NeoForge":
           data = await invoke<LoaderVersion[]>("get_neoforge_versions", {
             minecraftVersion: version,
+            includePrerelease: showPrereleaseLoaders,
           });
           break;
         case "Fabric":
           data = await invoke<LoaderVersion[]>("get_fabric_versions", {
             minecraftVersion: version,
+            includePrerelease: showPrereleaseLoaders,
+            includeSnapshots: showSnapshots,
           });
           break;
         case "Quilt":
           data = await invoke<LoaderVersion[]>("get_quilt_versions", {
             minecraftVersion: version,
+            includePrerelease: showPrereleaseLoaders,
+            includeSnapshots: showSnapshots,
           });
           break;
         case "LiteLoader":
           data = await invoke<LoaderVersion[]>("get_liteloader_versions", {
             minecraftVersion: version,
+            includePrerelease: showPrereleaseLoaders,
           });
           break;
       }
@@ -186,6 +255,24 @@ export function CustomTab({
     }
   };
 
+  // Validate the name shortly after typing stops
+  useEffect(() => {
+    if (!name) {
+      setNameValidation(null);
+      return;
+    }
+
+    const timeout = window.setTimeout(async () => {
+      try {
+        setNameValidation(await invoke<NameValidation>("validate_instance_name", { name }));
+      } catch (error) {
+        console.error("Failed to validate instance name:", error);
+      }
+    }, 300);
+
+    return () => window.clearTimeout(timeout);
+  }, [name]);
+
   return (
     <div className="flex-1 flex flex-col gap-3 overflow-hidden">
       {/* Name and Group inputs */}
@@ -199,6 +286,23 @@ export function CustomTab({
             placeholder="Instance name"
             className="h-9"
           />
+          {nameValidation && !nameValidation.valid && (
+            <p className="text-xs text-destructive mt-1">
+              {nameValidation.issues.join(". ")}
+              {nameValidation.suggested_name && (
+                <>
+                  {" "}
+                  <button
+                    type="button"
+                    className="underline"
+                    onClick={() => setName(nameValidation.suggested_name!)}
+                  >
+                    Use "{nameValidation.suggested_name}"
+                  </button>
+                </>
+              )}
+            </p>
+          )}
         </div>
         <div className="w-full sm:w-40">
           <Label htmlFor="group" className="text-sm">Group</Label>
@@ -250,6 +354,11 @@ export function CustomTab({
                     <span className="flex items-center gap-1.5 truncate">
                       {index === 0 && <Star className="h-3 w-3 text-yellow-500 fill-yellow-500 flex-shrink-0" />}
                       {v.id}
+                      {cachedVersions.has(v.id) && (
+                        <span title="Downloaded; no game files to fetch">
+                          <HardDrive className="h-3 w-3 flex-shrink-0 opacity-60" />
+                        </span>
+                      )}
                     </span>
                     <span className={cn(
                       "text-xs flex-shrink-0 hidden sm:inline",
@@ -310,7 +419,7 @@ export function CustomTab({
             </CardHeader>
             <CardContent className="px-3 pb-2 pt-0 flex flex-wrap gap-x-3 gap-y-1">
               {["None", "NeoForge", "Forge", "Fabric", "Quilt", "LiteLoader"].map((loader) => {
-                const compat = isLoaderCompatible(loader, version);
+                const compat = loaderCompatibility(loader);
                 const loaderColor = getLoaderColor(loader);
                 return (
                   <div key={loader} className="flex items-center space-x-1.5">
@@ -351,6 +460,17 @@ export function CustomTab({
                 </Button>
               </CardHeader>
               <CardContent className="px-3 pb-2 pt-0 flex-1 min-h-0 overflow-hidden">
+                <div className="flex items-center space-x-1.5 mb-1.5">
+                  <Checkbox
+                    id="prerelease-loaders"
+                    checked={showPrereleaseLoaders}
+                    onCheckedChange={(val) => setShowPrereleaseLoaders(val as boolean)}
+                    className="h-3.5 w-3.5"
+                  />
+                  <Label htmlFor="prerelease-loaders" className="text-xs font-normal cursor-pointer">
+                    Show betas
+                  </Label>
+                </div>
                 {loadingLoaderVersions ? (
                   <p className="text-xs text-muted-foreground">Loading...</p>
                 ) : loaderVersions.length === 0 ? (
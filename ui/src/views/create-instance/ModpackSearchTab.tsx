@@ -459,6 +459,8 @@ export function ModpackSearchTab({
       await invoke("import_instance_from_url", {
         url: selectedModpackVer.download_url,
         nameOverride: name || null,
+        packId: selectedModpackVer.project_id,
+        packVersionId: selectedModpackVer.id,
       });
       
       setInstallProgress(100);
@@ -75,6 +75,8 @@ import {
 import { Separator } from "@/components/ui/separator";
 import { cn } from "@/lib/utils";
 import { DialogWindowHeader } from "@/components/common/DialogWindowHeader";
+import { BlockedModsDialog } from "@/components/dialogs/BlockedModsDialog";
+import { useBlockedModDownloads } from "@/hooks/useBlockedModDownloads";
 
 // Import custom platform logos
 import modrinthLogo from "../../../art/modrinth.svg";
@@ -188,6 +190,9 @@ export function ModBrowserPage() {
   const minecraftVersion = searchParams.get("minecraftVersion") || "";
   const modLoader = searchParams.get("modLoader") || "Fabric";
 
+  // CurseForge files that have to be downloaded manually
+  const blockedDownloads = useBlockedModDownloads(instanceId);
+
   // Platform state
   const [platform, setPlatform] = useState<"modrinth" | "curseforge">("modrinth");
   
@@ -1387,6 +1392,18 @@ export function ModBrowserPage() {
           </AlertDialogFooter>
         </AlertDialogContent>
       </AlertDialog>
+
+      <BlockedModsDialog
+        open={blockedDownloads.dialogOpen}
+        onOpenChange={blockedDownloads.setDialogOpen}
+        blockedMods={blockedDownloads.blockedMods}
+        instanceId={instanceId}
+        onContinue={async () => {
+          blockedDownloads.clear();
+          await emit("mods-changed", { instanceId });
+        }}
+        onSkip={blockedDownloads.clear}
+      />
     </div>
   );
 }
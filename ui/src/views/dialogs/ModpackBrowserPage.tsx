@@ -56,6 +56,16 @@ import {
 import { Separator } from "@/components/ui/separator";
 import { Label } from "@/components/ui/label";
 import { Progress } from "@/components/ui/progress";
+import {
+  AlertDialog,
+  AlertDialogAction,
+  AlertDialogCancel,
+  AlertDialogContent,
+  AlertDialogDescription,
+  AlertDialogFooter,
+  AlertDialogHeader,
+  AlertDialogTitle,
+} from "@/components/ui/alert-dialog";
 import { cn } from "@/lib/utils";
 import { DialogWindowHeader } from "@/components/common/DialogWindowHeader";
 import { BlockedModsDialog, BlockedMod } from "@/components/dialogs/BlockedModsDialog";
@@ -150,6 +160,21 @@ interface ImportResultInfo {
   blocked_files: BlockedFileInfo[];
 }
 
+// Pre-install report from plan_modpack_install
+interface ModpackInstallPlan {
+  name: string;
+  version_id: string;
+  version_number: string;
+  minecraft_version: string;
+  mod_loader_type: string | null;
+  mod_loader_version: string | null;
+  total_mods: number;
+  total_files: number;
+  download_size: number;
+  blocked_files: BlockedFileInfo[];
+  warnings: string[];
+}
+
 // Simple blocked file info from import result
 interface BlockedFileInfo {
   project_id: string;
@@ -163,6 +188,7 @@ interface ModpackDownloadProgress {
   total: number;
   bytes_downloaded: number;
   speed_bps: number;
+  total_bytes: number | null;
   current_file: string | null;
   phase?: "preparing" | "resolving" | "downloading";
 }
@@ -226,6 +252,8 @@ export function ModpackBrowserPage() {
 
   // Blocked mods dialog state
   const [showBlockedModsDialog, setShowBlockedModsDialog] = useState(false);
+  const [installPlan, setInstallPlan] = useState<ModpackInstallPlan | null>(null);
+  const [isPlanning, setIsPlanning] = useState(false);
   const [currentBlockedMods, setCurrentBlockedMods] = useState<BlockedMod[]>([]);
   const [currentInstanceId, setCurrentInstanceId] = useState<string>("");
 
@@ -482,6 +510,27 @@ export function ModpackBrowserPage() {
     searchModpacks(searchQuery, sortBy, [], 1);
   };
 
+  // Show what the install involves before starting it
+  const reviewInstall = async () => {
+    if (!selectedModpack || !selectedVersion) return;
+
+    setIsPlanning(true);
+    setInstallError(null);
+    try {
+      const plan = await invoke<ModpackInstallPlan>("plan_modpack_install", {
+        platform,
+        projectId: selectedVersion.project_id,
+        versionId: selectedVersion.id,
+      });
+      setInstallPlan(plan);
+    } catch (error) {
+      console.error("Failed to plan modpack install:", error);
+      setInstallError(`${error}`);
+    } finally {
+      setIsPlanning(false);
+    }
+  };
+
   // Install selected modpack directly
   const installModpack = async () => {
     if (!selectedModpack || !selectedVersion) return;
@@ -501,6 +550,8 @@ export function ModpackBrowserPage() {
         url: selectedVersion.download_url,
         nameOverride: instanceName || selectedModpack.name,
         iconUrl: modpackDetails?.icon_url || selectedModpack.icon_url || null,
+        packId: selectedVersion.project_id,
+        packVersionId: selectedVersion.id,
       });
 
       // Check if there are blocked files that need manual download
@@ -1126,7 +1177,11 @@ export function ModpackBrowserPage() {
                         {/* Progress bar - real or indeterminate */}
                         {downloadProgress && downloadProgress.total > 0 ? (
                           <Progress 
-                            value={(downloadProgress.downloaded / downloadProgress.total) * 100} 
+                            value={
+                              downloadProgress.total_bytes
+                                ? Math.min(100, (downloadProgress.bytes_downloaded / downloadProgress.total_bytes) * 100)
+                                : (downloadProgress.downloaded / downloadProgress.total) * 100
+                            } 
                             className="h-2"
                           />
                         ) : (
@@ -1147,13 +1202,13 @@ export function ModpackBrowserPage() {
 
                     <Button
                       className="w-full"
-                      onClick={installModpack}
-                      disabled={isInstalling || !selectedVersion}
+                      onClick={reviewInstall}
+                      disabled={isInstalling || isPlanning || !selectedVersion}
                     >
-                      {isInstalling ? (
+                      {isInstalling || isPlanning ? (
                         <>
                           <Loader2 className="mr-2 h-4 w-4 animate-spin" />
-                          Installing...
+                          {isPlanning ? "Checking..." : "Installing..."}
                         </>
                       ) : (
                         <>
@@ -1181,6 +1236,59 @@ export function ModpackBrowserPage() {
         </div>
       </div>
 
+      {/* Install Confirmation Dialog */}
+      <AlertDialog open={!!installPlan} onOpenChange={(open) => !open && setInstallPlan(null)}>
+        <AlertDialogContent>
+          <AlertDialogHeader>
+            <AlertDialogTitle>Install {installPlan?.name}?</AlertDialogTitle>
+            <AlertDialogDescription>
+              Version {installPlan?.version_number} for Minecraft {installPlan?.minecraft_version}
+              {installPlan?.mod_loader_type &&
+                ` with ${installPlan.mod_loader_type} ${installPlan.mod_loader_version ?? ""}`}
+            </AlertDialogDescription>
+          </AlertDialogHeader>
+          {installPlan && (
+            <div className="space-y-2 text-sm">
+              <div className="flex justify-between">
+                <span className="text-muted-foreground">Mods</span>
+                <span>{installPlan.total_mods}</span>
+              </div>
+              <div className="flex justify-between">
+                <span className="text-muted-foreground">Files to download</span>
+                <span>{installPlan.total_files}</span>
+              </div>
+              <div className="flex justify-between">
+                <span className="text-muted-foreground">Download size</span>
+                <span>{formatFileSize(installPlan.download_size)}</span>
+              </div>
+              {installPlan.blocked_files.length > 0 && (
+                <div className="flex items-start gap-2 p-2 bg-yellow-500/10 rounded-md text-yellow-600 dark:text-yellow-500">
+                  <AlertCircle className="h-4 w-4 mt-0.5 shrink-0" />
+                  <span>
+                    {installPlan.blocked_files.length} file(s) must be downloaded manually during install:{" "}
+                    {installPlan.blocked_files.map(f => f.filename).join(", ")}
+                  </span>
+                </div>
+              )}
+              {installPlan.warnings.map((warning) => (
+                <p key={warning} className="text-muted-foreground">{warning}</p>
+              ))}
+            </div>
+          )}
+          <AlertDialogFooter>
+            <AlertDialogCancel>Cancel</AlertDialogCancel>
+            <AlertDialogAction
+              onClick={() => {
+                setInstallPlan(null);
+                installModpack();
+              }}
+            >
+              Install
+            </AlertDialogAction>
+          </AlertDialogFooter>
+        </AlertDialogContent>
+      </AlertDialog>
+
       {/* Blocked Mods Dialog */}
       <BlockedModsDialog
         open={showBlockedModsDialog}
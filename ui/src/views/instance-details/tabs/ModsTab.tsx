@@ -42,6 +42,9 @@ import {
   ArrowDown,
   ArrowUpDown,
   ArrowUpCircle,
+  Sparkles,
+  X,
+  Copy,
 } from "lucide-react";
 import { Button } from "@/components/ui/button";
 import { Badge } from "@/components/ui/badge";
@@ -67,6 +70,13 @@ import {
   TableHeader,
   TableRow,
 } from "@/components/ui/table";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
 import {
   ContextMenu,
   ContextMenuContent,
@@ -83,6 +93,22 @@ import { ModUpdatesDialog } from "@/components/dialogs";
 import type { InstanceInfo, InstalledMod } from "../types";
 import { formatFileSize } from "../utils";
 
+// Essential mod suggestion from get_suggested_mods
+interface SuggestedMod {
+  project_id: string;
+  name: string;
+  reason: string;
+  installed: boolean;
+}
+
+// Result of copy_mods_from_instance
+interface ModCopyResult {
+  copied: string[];
+  skipped: string[];
+  warnings: string[];
+  missing: string[];
+}
+
 // Sort column types
 type SortColumn = "name" | "version" | "modified" | "provider" | "size";
 type SortDirection = "asc" | "desc";
@@ -101,6 +127,12 @@ export function ModsTab({ instanceId, instance }: ModsTabProps) {
   const [isDragging, setIsDragging] = useState(false);
   const [isLoadingMods, setIsLoadingMods] = useState(true);
   const [showUpdatesDialog, setShowUpdatesDialog] = useState(false);
+  const [suggestedMods, setSuggestedMods] = useState<SuggestedMod[]>([]);
+  const [suggestionsDismissed, setSuggestionsDismissed] = useState(false);
+  const [installingSuggestions, setInstallingSuggestions] = useState(false);
+  const [copyDialogOpen, setCopyDialogOpen] = useState(false);
+  const [otherInstances, setOtherInstances] = useState<InstanceInfo[]>([]);
+  const [copySourceId, setCopySourceId] = useState("");
   
   // Sorting state
   const [sortColumn, setSortColumn] = useState<SortColumn>("name");
@@ -195,6 +227,34 @@ export function ModsTab({ instanceId, instance }: ModsTabProps) {
     } finally {
       setIsLoadingMods(false);
     }
+    loadSuggestedMods();
+  };
+
+  const loadSuggestedMods = async () => {
+    try {
+      const suggestions = await invoke<SuggestedMod[]>("get_suggested_mods", { instanceId });
+      setSuggestedMods(suggestions.filter(s => !s.installed));
+    } catch (error) {
+      console.error("Failed to load suggested mods:", error);
+    }
+  };
+
+  const installSuggestedMods = async () => {
+    setInstallingSuggestions(true);
+    try {
+      const result = await invoke<{ installed: string[]; errors: string[] }>("install_suggested_mods", {
+        instanceId,
+        projectIds: suggestedMods.map(s => s.project_id),
+      });
+      if (result.errors.length > 0) {
+        console.error("Failed to install some suggested mods:", result.errors);
+      }
+      await loadInstalledMods();
+    } catch (error) {
+      console.error("Failed to install suggested mods:", error);
+    } finally {
+      setInstallingSuggestions(false);
+    }
   };
 
   const toggleMod = async (filename: string, currentEnabled: boolean) => {
@@ -231,6 +291,41 @@ export function ModsTab({ instanceId, instance }: ModsTabProps) {
     }
   };
 
+  const openCopyDialog = async () => {
+    try {
+      const instances = await invoke<InstanceInfo[]>("get_instances");
+      setOtherInstances(instances.filter(i => i.id !== instanceId));
+      setCopySourceId("");
+      setCopyDialogOpen(true);
+    } catch (error) {
+      console.error("Failed to load instances:", error);
+    }
+  };
+
+  const copyModsFromInstance = async () => {
+    if (!copySourceId) return;
+    try {
+      const result = await invoke<ModCopyResult>("copy_mods_from_instance", {
+        sourceId: copySourceId,
+        destId: instanceId,
+        filenames: null,
+      });
+      await loadInstalledMods();
+      const summary = [`Copied ${result.copied.length} mod(s)`];
+      if (result.skipped.length > 0) {
+        summary.push(`${result.skipped.length} already installed`);
+      }
+      if (result.warnings.length > 0) {
+        alert(`${summary.join(", ")}.\n\nWarnings:\n${result.warnings.join("\n")}`);
+      }
+    } catch (error) {
+      console.error("Failed to copy mods:", error);
+      alert("Failed to copy mods: " + error);
+    } finally {
+      setCopyDialogOpen(false);
+    }
+  };
+
   const deleteSelectedMods = async () => {
     if (selectedMods.size === 0) return;
     try {
@@ -292,6 +387,29 @@ export function ModsTab({ instanceId, instance }: ModsTabProps) {
     }
   };
 
+  const openModConfig = async (filename: string) => {
+    try {
+      const result = await invoke<{ mod_id: string; candidates: string[]; opened: boolean }>("open_mod_config", {
+        instanceId,
+        modIdOrFilename: filename,
+      });
+      if (!result.opened) {
+        // Several matches - let the user pick one by name
+        const names = result.candidates.map(path => path.split(/[\\/]/).pop() ?? path);
+        const choice = window.prompt(
+          `Several config files match ${result.mod_id}. Enter one to open:\n${names.join("\n")}`,
+          names[0],
+        );
+        if (choice) {
+          await invoke("open_mod_config", { instanceId, modIdOrFilename: choice });
+        }
+      }
+    } catch (error) {
+      console.error("Failed to open mod config:", error);
+      alert(String(error));
+    }
+  };
+
   const handleFileSelect = async () => {
     try {
       const selected = await open({
@@ -492,6 +610,14 @@ export function ModsTab({ instanceId, instance }: ModsTabProps) {
           <ArrowUpCircle className="mr-2 h-4 w-4" />
           Check Updates
         </Button>
+        <Button 
+          variant="outline" 
+          size="sm" 
+          onClick={openCopyDialog}
+        >
+          <Copy className="mr-2 h-4 w-4" />
+          Copy From...
+        </Button>
         
         <div className="h-6 w-px bg-border mx-1" />
         
@@ -547,6 +673,40 @@ export function ModsTab({ instanceId, instance }: ModsTabProps) {
         </div>
       </div>
 
+      {/* Essential mod suggestions */}
+      {!suggestionsDismissed && suggestedMods.length > 0 && (
+        <div className="mb-3 rounded-md border border-dashed p-3">
+          <div className="flex items-center gap-2">
+            <Sparkles className="h-4 w-4 text-primary" />
+            <span className="text-sm font-medium">Suggested mods</span>
+            <Badge variant="secondary">Suggestion</Badge>
+            <div className="flex-1" />
+            <Button
+              size="sm"
+              onClick={installSuggestedMods}
+              disabled={installingSuggestions}
+            >
+              <Download className="mr-2 h-4 w-4" />
+              {installingSuggestions ? "Installing..." : "Install All"}
+            </Button>
+            <Button
+              variant="ghost"
+              size="sm"
+              onClick={() => setSuggestionsDismissed(true)}
+            >
+              <X className="h-4 w-4" />
+            </Button>
+          </div>
+          <ul className="mt-2 space-y-1 text-sm text-muted-foreground">
+            {suggestedMods.map(mod => (
+              <li key={mod.project_id}>
+                <span className="text-foreground">{mod.name}</span> — {mod.reason}
+              </li>
+            ))}
+          </ul>
+        </div>
+      )}
+
       {/* Mods Table */}
       <div 
         className={cn(
@@ -745,6 +905,10 @@ export function ModsTab({ instanceId, instance }: ModsTabProps) {
                         {mod.enabled ? <XCircle className="mr-2 h-4 w-4" /> : <Check className="mr-2 h-4 w-4" />}
                         {mod.enabled ? "Disable" : "Enable"}
                       </ContextMenuItem>
+                      <ContextMenuItem onClick={() => openModConfig(mod.filename)}>
+                        <FileText className="mr-2 h-4 w-4" />
+                        Open Config
+                      </ContextMenuItem>
                       <ContextMenuItem 
                         onClick={() => setDeleteModDialog(mod.filename)}
                         className="text-destructive focus:text-destructive"
@@ -830,6 +994,37 @@ export function ModsTab({ instanceId, instance }: ModsTabProps) {
         </AlertDialogContent>
       </AlertDialog>
 
+      {/* Copy Mods Dialog */}
+      <AlertDialog open={copyDialogOpen} onOpenChange={setCopyDialogOpen}>
+        <AlertDialogContent>
+          <AlertDialogHeader>
+            <AlertDialogTitle>Copy Mods From Instance</AlertDialogTitle>
+            <AlertDialogDescription>
+              Copies every mod from the chosen instance. Mods already installed here are skipped,
+              and disabled mods stay disabled.
+            </AlertDialogDescription>
+          </AlertDialogHeader>
+          <Select value={copySourceId} onValueChange={setCopySourceId}>
+            <SelectTrigger>
+              <SelectValue placeholder="Select an instance" />
+            </SelectTrigger>
+            <SelectContent>
+              {otherInstances.map((other) => (
+                <SelectItem key={other.id} value={other.id}>
+                  {other.name} ({other.minecraft_version}{other.mod_loader ? `, ${other.mod_loader}` : ""})
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+          <AlertDialogFooter>
+            <AlertDialogCancel>Cancel</AlertDialogCancel>
+            <AlertDialogAction onClick={copyModsFromInstance} disabled={!copySourceId}>
+              Copy Mods
+            </AlertDialogAction>
+          </AlertDialogFooter>
+        </AlertDialogContent>
+      </AlertDialog>
+
       {/* Mod Updates Dialog */}
       <ModUpdatesDialog
         open={showUpdatesDialog}
@@ -20,6 +20,7 @@
 
 import { useState, useEffect, useCallback, useRef } from "react";
 import { invoke } from "@tauri-apps/api/core";
+import { availableMonitors, Monitor } from "@tauri-apps/api/window";
 import { RefreshCw, Download, AlertCircle, Check, AlertTriangle, Sparkles, Zap, HelpCircle } from "lucide-react";
 import { Button } from "@/components/ui/button";
 import { Input } from "@/components/ui/input";
@@ -89,9 +90,11 @@ interface JavaCompatibilityResult {
 interface SettingsTabProps {
   instanceId: string;
   instance: InstanceInfo;
+  // Called when a change shows outside the tab, such as the demo lock
+  onInstanceChanged?: () => void;
 }
 
-export function SettingsTab({ instanceId, instance }: SettingsTabProps) {
+export function SettingsTab({ instanceId, instance, onInstanceChanged }: SettingsTabProps) {
   const [settings, setSettings] = useState<InstanceSettings>({
     java_path: null,
     memory_min_mb: 512,
@@ -102,6 +105,8 @@ export function SettingsTab({ instanceId, instance }: SettingsTabProps) {
     window_height: 480,
     start_maximized: false,
     fullscreen: false,
+    monitor_index: null,
+    language: null,
     console_mode: "on_error",
     pre_launch_hook: null,
     post_exit_hook: null,
@@ -110,10 +115,17 @@ export function SettingsTab({ instanceId, instance }: SettingsTabProps) {
     game_dir_override: null,
     skip_java_compatibility_check: false,
     wrapper_command: null,
+    env_vars: [],
     // Debug settings
     use_java_console: false,
     disable_create_no_window: false,
     log_launch_command: false,
+    // Shared folders
+    shared_screenshots: false,
+    shared_saves: false,
+    // Demo lock
+    demo_locked: false,
+    demo_lock_has_pin: false,
   });
 
   const [detectedJavas, setDetectedJavas] = useState<JavaInfo[]>([]);
@@ -122,6 +134,13 @@ export function SettingsTab({ instanceId, instance }: SettingsTabProps) {
   const [downloadingJava, setDownloadingJava] = useState<number | null>(null);
   const [findingBestJava, setFindingBestJava] = useState(false);
   const [saveStatus, setSaveStatus] = useState<"idle" | "saving" | "saved">("idle");
+  const [sharedFolderError, setSharedFolderError] = useState<string | null>(null);
+  const [demoPin, setDemoPin] = useState("");
+  const [demoLockError, setDemoLockError] = useState<string | null>(null);
+  const [languageInput, setLanguageInput] = useState("");
+  const [languageNotice, setLanguageNotice] = useState<{ error: boolean; message: string } | null>(null);
+  const [memoryWarnings, setMemoryWarnings] = useState<string[]>([]);
+  const [envVarsInput, setEnvVarsInput] = useState("");
   
   // Debounce timer ref
   const saveTimeoutRef = useRef<number | null>(null);
@@ -149,10 +168,14 @@ export function SettingsTab({ instanceId, instance }: SettingsTabProps) {
     
     saveTimeoutRef.current = window.setTimeout(async () => {
       try {
-        await invoke("update_instance_settings", {
-          instanceId,
-          settings: newSettings,
-        });
+        const saved = await invoke<{ language_warning: string | null; memory_warnings: string[] }>(
+          "update_instance_settings",
+          { instanceId, settings: newSettings },
+        );
+        if (saved.language_warning) {
+          setLanguageNotice({ error: false, message: saved.language_warning });
+        }
+        setMemoryWarnings(saved.memory_warnings);
         setSaveStatus("saved");
         // Reset to idle after showing "saved" briefly
         setTimeout(() => setSaveStatus("idle"), 1500);
@@ -163,10 +186,20 @@ export function SettingsTab({ instanceId, instance }: SettingsTabProps) {
     }, 300); // 300ms debounce
   }, [instanceId]);
 
+  const [monitors, setMonitors] = useState<Monitor[]>([]);
+
+  useEffect(() => {
+    availableMonitors()
+      .then(setMonitors)
+      .catch((error) => console.error("Failed to list monitors:", error));
+  }, []);
+
   const loadSettings = async () => {
     try {
       const loadedSettings = await invoke<InstanceSettings>("get_instance_settings", { instanceId });
       setSettings(loadedSettings);
+      setLanguageInput(loadedSettings.language ?? "");
+      setEnvVarsInput(loadedSettings.env_vars.map(([name, value]) => `${name}=${value}`).join("\n"));
     } catch (error) {
       console.error("Failed to load instance settings:", error);
     }
@@ -183,10 +216,75 @@ export function SettingsTab({ instanceId, instance }: SettingsTabProps) {
     });
   }, [saveSettings]);
 
-  const detectJavas = async () => {
+  // Shared folders are linked on disk immediately rather than through the debounced save
+  const toggleSharedFolder = async (folder: "screenshots" | "saves", enabled: boolean) => {
+    setSharedFolderError(null);
+    try {
+      await invoke<string[]>("set_shared_folder", { instanceId, folder, enabled });
+      setSettings(prev => ({ ...prev, [`shared_${folder}`]: enabled }));
+    } catch (error) {
+      console.error("Failed to update shared folder:", error);
+      setSharedFolderError(String(error));
+    }
+  };
+
+  // The PIN field sets the PIN when locking and is checked when unlocking
+  const toggleDemoLock = async (locked: boolean) => {
+    setDemoLockError(null);
+    try {
+      await invoke("set_instance_demo_lock", {
+        instanceId,
+        locked,
+        pin: demoPin || null,
+        newPin: demoPin || null,
+      });
+      setSettings(prev => ({
+        ...prev,
+        demo_locked: locked,
+        demo_lock_has_pin: locked && demoPin.trim() !== "",
+      }));
+      setDemoPin("");
+      onInstanceChanged?.();
+    } catch (error) {
+      console.error("Failed to update demo lock:", error);
+      setDemoLockError(String(error));
+    }
+  };
+
+  // The language is written to options.txt straight away, so it's saved when the field loses focus
+  const saveLanguage = async () => {
+    if (languageInput.trim() === (settings.language ?? "")) return;
+    setLanguageNotice(null);
+    try {
+      const warning = await invoke<string | null>("set_instance_language", {
+        instanceId,
+        language: languageInput,
+      });
+      const language = languageInput.trim().toLowerCase() || null;
+      setSettings(prev => ({ ...prev, language }));
+      setLanguageInput(language ?? "");
+      if (warning) {
+        setLanguageNotice({ error: false, message: warning });
+      }
+    } catch (error) {
+      console.error("Failed to set language:", error);
+      setLanguageNotice({ error: true, message: String(error) });
+    }
+  };
+
+  const applyAikarsFlags = async () => {
+    try {
+      const javaArgs = await invoke<string>("apply_aikars_gc_flags", { instanceId });
+      setSettings(prev => ({ ...prev, java_args: javaArgs }));
+    } catch (error) {
+      console.error("Failed to apply Aikar's flags:", error);
+    }
+  };
+
+  const detectJavas = async (refresh = false) => {
     setDetectingJava(true);
     try {
-      const javas = await invoke<JavaInfo[]>("detect_java");
+      const javas = await invoke<JavaInfo[]>("detect_java", { refresh });
       const mappedJavas = javas.map((j: any) => ({
         path: j.path,
         version: j.version,
@@ -427,7 +525,7 @@ export function SettingsTab({ instanceId, instance }: SettingsTabProps) {
                     <Button
                       variant="ghost"
                       size="sm"
-                      onClick={detectJavas}
+                      onClick={() => detectJavas(true)}
                       disabled={detectingJava}
                     >
                       <RefreshCw className={cn("h-4 w-4 mr-2", detectingJava && "animate-spin")} />
@@ -652,6 +750,20 @@ export function SettingsTab({ instanceId, instance }: SettingsTabProps) {
                   </div>
                 </div>
 
+                {memoryWarnings.length > 0 && (
+                  <Alert>
+                    <AlertTriangle className="h-4 w-4" />
+                    <AlertTitle>Memory settings adjusted</AlertTitle>
+                    <AlertDescription>
+                      <ul className="list-disc list-inside text-sm">
+                        {memoryWarnings.map((warning, i) => (
+                          <li key={i}>{warning}</li>
+                        ))}
+                      </ul>
+                    </AlertDescription>
+                  </Alert>
+                )}
+
                 <Separator />
 
                 <div className="text-sm text-muted-foreground space-y-2">
@@ -708,6 +820,70 @@ export function SettingsTab({ instanceId, instance }: SettingsTabProps) {
                     onCheckedChange={(v) => updateSetting("start_maximized", v)}
                   />
                 </div>
+                <div className="flex items-center justify-between">
+                  <div className="space-y-0.5">
+                    <Label>Fullscreen</Label>
+                    <p className="text-sm text-muted-foreground">
+                      Launch the game in fullscreen; the window size is ignored
+                    </p>
+                  </div>
+                  <Switch
+                    checked={settings.fullscreen}
+                    onCheckedChange={(v) => updateSetting("fullscreen", v)}
+                  />
+                </div>
+                {settings.fullscreen && monitors.length > 1 && (
+                  <div className="grid gap-2">
+                    <Label>Monitor</Label>
+                    <Select
+                      value={settings.monitor_index != null && settings.monitor_index >= 0
+                        ? String(settings.monitor_index)
+                        : "default"}
+                      onValueChange={(v) => updateSetting("monitor_index", v === "default" ? -1 : parseInt(v))}
+                    >
+                      <SelectTrigger>
+                        <SelectValue />
+                      </SelectTrigger>
+                      <SelectContent>
+                        <SelectItem value="default">Default display</SelectItem>
+                        {monitors.map((monitor, index) => (
+                          <SelectItem key={index} value={String(index)}>
+                            {monitor.name || `Display ${index + 1}`} ({monitor.size.width}x{monitor.size.height})
+                          </SelectItem>
+                        ))}
+                      </SelectContent>
+                    </Select>
+                  </div>
+                )}
+              </CardContent>
+            </Card>
+
+            <Card>
+              <CardHeader>
+                <CardTitle>Language</CardTitle>
+                <CardDescription>Set the game language for this instance</CardDescription>
+              </CardHeader>
+              <CardContent className="space-y-4">
+                <div className="grid gap-2">
+                  <Label>Language Code</Label>
+                  <Input
+                    value={languageInput}
+                    onChange={(e) => setLanguageInput(e.target.value)}
+                    onBlur={saveLanguage}
+                    placeholder="en_us"
+                    className="font-mono text-sm"
+                  />
+                  <p className="text-xs text-muted-foreground">
+                    Written to options.txt when set and on every launch. Leave empty to keep the in-game choice.
+                  </p>
+                </div>
+                {languageNotice && (
+                  <Alert variant={languageNotice.error ? "destructive" : "default"}>
+                    {languageNotice.error ? <AlertCircle className="h-4 w-4" /> : <AlertTriangle className="h-4 w-4" />}
+                    <AlertTitle>{languageNotice.error ? "Could not set language" : "Unknown language"}</AlertTitle>
+                    <AlertDescription>{languageNotice.message}</AlertDescription>
+                  </Alert>
+                )}
               </CardContent>
             </Card>
 
@@ -752,6 +928,89 @@ export function SettingsTab({ instanceId, instance }: SettingsTabProps) {
                 </div>
               </CardContent>
             </Card>
+
+            <Card>
+              <CardHeader>
+                <CardTitle>Shared Folders</CardTitle>
+                <CardDescription>Share screenshots and worlds with other instances</CardDescription>
+              </CardHeader>
+              <CardContent className="space-y-4">
+                <div className="flex items-center justify-between">
+                  <div className="space-y-0.5">
+                    <Label>Shared Screenshots</Label>
+                    <p className="text-sm text-muted-foreground">
+                      Link the screenshots folder to the launcher's shared screenshots folder.
+                      Existing screenshots are moved there.
+                    </p>
+                  </div>
+                  <Switch
+                    checked={settings.shared_screenshots}
+                    onCheckedChange={(v) => toggleSharedFolder("screenshots", v)}
+                  />
+                </div>
+                <Separator />
+                <div className="flex items-center justify-between">
+                  <div className="space-y-0.5">
+                    <Label>Shared Saves</Label>
+                    <p className="text-sm text-muted-foreground">
+                      Link the saves folder to the launcher's shared saves folder.
+                      Existing worlds are moved there.
+                    </p>
+                  </div>
+                  <Switch
+                    checked={settings.shared_saves}
+                    onCheckedChange={(v) => toggleSharedFolder("saves", v)}
+                  />
+                </div>
+                {sharedFolderError && (
+                  <Alert variant="destructive">
+                    <AlertCircle className="h-4 w-4" />
+                    <AlertTitle>Could not update shared folder</AlertTitle>
+                    <AlertDescription>{sharedFolderError}</AlertDescription>
+                  </Alert>
+                )}
+              </CardContent>
+            </Card>
+
+            <Card>
+              <CardHeader>
+                <CardTitle>Demo Lock</CardTitle>
+                <CardDescription>Always launch this instance as a demo, whatever account is selected</CardDescription>
+              </CardHeader>
+              <CardContent className="space-y-4">
+                <div className="flex items-center justify-between">
+                  <div className="space-y-0.5">
+                    <Label>Demo Locked</Label>
+                    <p className="text-sm text-muted-foreground">
+                      {settings.demo_locked
+                        ? settings.demo_lock_has_pin
+                          ? "Locked to demo mode. Enter the PIN to unlock."
+                          : "Locked to demo mode."
+                        : "Needs Minecraft 1.13 or newer. An optional PIN is needed to unlock it again."}
+                    </p>
+                  </div>
+                  <Switch
+                    checked={settings.demo_locked}
+                    onCheckedChange={toggleDemoLock}
+                  />
+                </div>
+                {(!settings.demo_locked || settings.demo_lock_has_pin) && (
+                  <Input
+                    type="password"
+                    value={demoPin}
+                    onChange={(e) => setDemoPin(e.target.value)}
+                    placeholder={settings.demo_locked ? "PIN" : "PIN (optional)"}
+                  />
+                )}
+                {demoLockError && (
+                  <Alert variant="destructive">
+                    <AlertCircle className="h-4 w-4" />
+                    <AlertTitle>Could not update demo lock</AlertTitle>
+                    <AlertDescription>{demoLockError}</AlertDescription>
+                  </Alert>
+                )}
+              </CardContent>
+            </Card>
           </TabsContent>
 
           {/* Launch Tab */}
@@ -820,6 +1079,42 @@ export function SettingsTab({ instanceId, instance }: SettingsTabProps) {
                 </div>
               </CardContent>
             </Card>
+
+            <Card>
+              <CardHeader>
+                <CardTitle>Environment Variables</CardTitle>
+                <CardDescription>Set environment variables for the game process</CardDescription>
+              </CardHeader>
+              <CardContent>
+                <div className="grid gap-2">
+                  <div className="flex items-center">
+                    <Label>Variables</Label>
+                    <SettingTooltip>
+                      One NAME=value per line. Use ${"${NAME}"} to include a variable from the launcher's environment. Useful for forcing GPU selection or driver options on Linux.
+                    </SettingTooltip>
+                  </div>
+                  <Textarea
+                    value={envVarsInput}
+                    onChange={(e) => setEnvVarsInput(e.target.value)}
+                    onBlur={() =>
+                      updateSetting(
+                        "env_vars",
+                        envVarsInput
+                          .split("\n")
+                          .filter((line) => line.includes("="))
+                          .map((line): [string, string] => {
+                            const index = line.indexOf("=");
+                            return [line.slice(0, index).trim(), line.slice(index + 1)];
+                          })
+                          .filter(([name]) => name.length > 0)
+                      )
+                    }
+                    placeholder={"MESA_GL_VERSION_OVERRIDE=4.6\n__GL_THREADED_OPTIMIZATIONS=1"}
+                    className="font-mono text-sm min-h-[80px]"
+                  />
+                </div>
+              </CardContent>
+            </Card>
           </TabsContent>
 
           {/* Advanced Tab */}
@@ -847,6 +1142,15 @@ export function SettingsTab({ instanceId, instance }: SettingsTabProps) {
                   <p className="text-xs text-muted-foreground">
                     Additional arguments passed to the Java Virtual Machine. Use with caution.
                   </p>
+                  <div className="flex items-center gap-2">
+                    <Button variant="outline" size="sm" onClick={applyAikarsFlags}>
+                      <Zap className="h-4 w-4 mr-2" />
+                      Apply Aikar's Flags
+                    </Button>
+                    <span className="text-xs text-muted-foreground">
+                      G1 garbage collector tuning for {settings.memory_max_mb} MB; replaces other GC flags
+                    </span>
+                  </div>
                 </div>
               </CardContent>
             </Card>
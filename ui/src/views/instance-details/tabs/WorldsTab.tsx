@@ -20,7 +20,7 @@
 
 import { useState, useEffect } from "react";
 import { invoke } from "@tauri-apps/api/core";
-import { Copy, Trash2, RefreshCw, FolderOpen } from "lucide-react";
+import { Copy, Trash2, RefreshCw, FolderOpen, Archive, History } from "lucide-react";
 import { Button } from "@/components/ui/button";
 import { Badge } from "@/components/ui/badge";
 import { ScrollArea } from "@/components/ui/scroll-area";
@@ -44,7 +44,8 @@ import {
 } from "@/components/ui/table";
 import { Card, CardContent, CardDescription, CardHeader, CardTitle } from "@/components/ui/card";
 import { cn } from "@/lib/utils";
-import type { WorldInfo } from "../types";
+import { formatFileSize } from "../utils";
+import type { WorldBackup, WorldInfo } from "../types";
 
 interface WorldsTabProps {
   instanceId: string;
@@ -55,6 +56,11 @@ export function WorldsTab({ instanceId }: WorldsTabProps) {
   const [loading, setLoading] = useState(false);
   const [deleteDialog, setDeleteDialog] = useState<string | null>(null);
   const [copyingWorld, setCopyingWorld] = useState<string | null>(null);
+  const [backingUpWorld, setBackingUpWorld] = useState<string | null>(null);
+  // World whose backups are shown, or null for every world's
+  const [restoreDialog, setRestoreDialog] = useState<{ world: string | null } | null>(null);
+  const [backups, setBackups] = useState<WorldBackup[]>([]);
+  const [restoring, setRestoring] = useState(false);
 
   useEffect(() => {
     loadWorlds();
@@ -99,6 +105,48 @@ export function WorldsTab({ instanceId }: WorldsTabProps) {
     }
   };
 
+  const backupWorld = async (folderName: string) => {
+    setBackingUpWorld(folderName);
+    try {
+      await invoke<WorldBackup>("backup_world", { instanceId, folderName });
+    } catch (error) {
+      console.error("Failed to back up world:", error);
+      alert("Failed to back up world: " + error);
+    } finally {
+      setBackingUpWorld(null);
+    }
+  };
+
+  const openRestoreDialog = async (folderName: string | null) => {
+    try {
+      const list = await invoke<WorldBackup[]>("list_world_backups", { instanceId, folderName });
+      setBackups(list);
+      setRestoreDialog({ world: folderName });
+    } catch (error) {
+      console.error("Failed to list backups:", error);
+      alert("Failed to list backups: " + error);
+    }
+  };
+
+  const restoreBackup = async (fileName: string, overwrite: boolean) => {
+    if (!restoreDialog) return;
+    setRestoring(true);
+    try {
+      await invoke<string>("restore_world_backup", {
+        instanceId,
+        fileName,
+        overwrite,
+      });
+      setRestoreDialog(null);
+      await loadWorlds();
+    } catch (error) {
+      console.error("Failed to restore backup:", error);
+      alert("Failed to restore backup: " + error);
+    } finally {
+      setRestoring(false);
+    }
+  };
+
   const openFolder = async () => {
     try {
       await invoke("open_saves_folder", { instanceId });
@@ -117,6 +165,10 @@ export function WorldsTab({ instanceId }: WorldsTabProps) {
             <CardDescription>Manage your saved worlds</CardDescription>
           </div>
           <div className="flex gap-2">
+            <Button variant="outline" size="sm" onClick={() => openRestoreDialog(null)}>
+              <History className="h-4 w-4 mr-2" />
+              Backups
+            </Button>
             <Button variant="outline" size="sm" onClick={openFolder}>
               <FolderOpen className="h-4 w-4 mr-2" />
               Open Folder
@@ -179,6 +231,27 @@ export function WorldsTab({ instanceId }: WorldsTabProps) {
                             <Copy className="h-4 w-4" />
                           )}
                         </Button>
+                        <Button
+                          variant="ghost"
+                          size="icon"
+                          onClick={() => backupWorld(world.folder_name)}
+                          disabled={backingUpWorld === world.folder_name}
+                          title="Back up world"
+                        >
+                          {backingUpWorld === world.folder_name ? (
+                            <RefreshCw className="h-4 w-4 animate-spin" />
+                          ) : (
+                            <Archive className="h-4 w-4" />
+                          )}
+                        </Button>
+                        <Button
+                          variant="ghost"
+                          size="icon"
+                          onClick={() => openRestoreDialog(world.folder_name)}
+                          title="Restore a backup"
+                        >
+                          <History className="h-4 w-4" />
+                        </Button>
                         <Button
                           variant="ghost"
                           size="icon"
@@ -217,6 +290,80 @@ export function WorldsTab({ instanceId }: WorldsTabProps) {
           </AlertDialogFooter>
         </AlertDialogContent>
       </AlertDialog>
+
+      <AlertDialog open={!!restoreDialog} onOpenChange={() => setRestoreDialog(null)}>
+        <AlertDialogContent>
+          <AlertDialogHeader>
+            <AlertDialogTitle>Restore Backup</AlertDialogTitle>
+            <AlertDialogDescription>
+              "Restore as copy" keeps the current world and restores next to it.
+              "Replace" overwrites the current world with the backup. Backups of
+              deleted worlds are restored as they were.
+            </AlertDialogDescription>
+          </AlertDialogHeader>
+          {backups.length === 0 ? (
+            <div className="text-sm text-muted-foreground">
+              {restoreDialog?.world ? "No backups of this world yet." : "No world backups yet."}
+            </div>
+          ) : (
+            <ScrollArea className="max-h-[300px]">
+              <div className="space-y-2">
+                {backups.map((backup) => {
+                  const exists = worlds.some((world) => world.folder_name === backup.world);
+                  return (
+                    <div key={backup.file_name} className="flex items-center justify-between gap-2">
+                      <div className="text-sm">
+                        {!restoreDialog?.world && (
+                          <div className="flex items-center gap-2 font-medium">
+                            <span>{backup.world}</span>
+                            {!exists && <Badge variant="secondary" className="text-xs">Deleted</Badge>}
+                          </div>
+                        )}
+                        <div>{backup.created ? new Date(backup.created).toLocaleString() : backup.file_name}</div>
+                        <div className="text-muted-foreground">{formatFileSize(backup.size)}</div>
+                      </div>
+                      <div className="flex gap-1">
+                        {exists ? (
+                          <>
+                            <Button
+                              variant="outline"
+                              size="sm"
+                              disabled={restoring}
+                              onClick={() => restoreBackup(backup.file_name, false)}
+                            >
+                              Restore as copy
+                            </Button>
+                            <Button
+                              variant="destructive"
+                              size="sm"
+                              disabled={restoring}
+                              onClick={() => restoreBackup(backup.file_name, true)}
+                            >
+                              Replace
+                            </Button>
+                          </>
+                        ) : (
+                          <Button
+                            variant="outline"
+                            size="sm"
+                            disabled={restoring}
+                            onClick={() => restoreBackup(backup.file_name, false)}
+                          >
+                            Restore
+                          </Button>
+                        )}
+                      </div>
+                    </div>
+                  );
+                })}
+              </div>
+            </ScrollArea>
+          )}
+          <AlertDialogFooter>
+            <AlertDialogCancel>Close</AlertDialogCancel>
+          </AlertDialogFooter>
+        </AlertDialogContent>
+      </AlertDialog>
     </Card>
   );
 }
@@ -49,6 +49,8 @@ export interface InstanceInfo {
   minecraft_version: string;
   mod_loader: string;
   mod_loader_version: string | null;
+  // Launches always run in demo mode
+  demo_locked: boolean;
 }
 
 export interface ModSearchResult {
@@ -74,6 +76,7 @@ export interface InstalledMod {
   homepage: string | null;
   issues_url: string | null;
   source_url: string | null;
+  pinned: boolean;
 }
 
 export interface JavaInstallation {
@@ -103,6 +106,14 @@ export interface WorldInfo {
   has_icon: boolean;
 }
 
+export interface WorldBackup {
+  world: string;
+  file_name: string;
+  path: string;
+  created: string | null;
+  size: number;
+}
+
 export interface ResourcePackInfo {
   filename: string;
   name: string;
@@ -153,10 +164,18 @@ export interface InstanceSettings {
   memory_max_mb: number;
   java_args: string;
   game_args: string;
+  // Shared argument profile name; empty clears it when saving
+  argument_profile: string | null;
+  // Whether the global extra JVM arguments are added before this instance's
+  use_global_jvm_args: boolean;
   window_width: number;
   window_height: number;
   start_maximized: boolean;
   fullscreen: boolean;
+  // Index into the connected displays; -1 clears it when saving
+  monitor_index: number | null;
+  // Game language code written to options.txt, e.g. "ja_jp"
+  language: string | null;
   console_mode: "always" | "on_error" | "never";
   pre_launch_hook: string | null;
   post_exit_hook: string | null;
@@ -165,10 +184,18 @@ export interface InstanceSettings {
   game_dir_override: string | null;
   skip_java_compatibility_check: boolean;
   wrapper_command: string | null;
+  // [name, value] pairs; ${NAME} in a value expands from the launcher's environment
+  env_vars: [string, string][];
   // Debug settings
   use_java_console: boolean;
   disable_create_no_window: boolean;
   log_launch_command: boolean;
+  // Shared folders
+  shared_screenshots: boolean;
+  shared_saves: boolean;
+  // Demo lock
+  demo_locked: boolean;
+  demo_lock_has_pin: boolean;
 }
 
 export interface JavaInfo {
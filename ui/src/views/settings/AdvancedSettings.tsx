@@ -18,8 +18,10 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+import { useState } from "react";
 import { invoke } from "@tauri-apps/api/core";
-import { FolderOpen, AlertTriangle } from "lucide-react";
+import { open as openDialog, save as saveDialog } from "@tauri-apps/plugin-dialog";
+import { FolderOpen, AlertTriangle, Download, Upload } from "lucide-react";
 import { Card, CardContent, CardDescription, CardHeader, CardTitle } from "@/components/ui/card";
 import { Input } from "@/components/ui/input";
 import { Label } from "@/components/ui/label";
@@ -344,6 +346,112 @@ function NetworkSettings() {
   );
 }
 
+interface RecipeImportResult {
+  name: string;
+  instance_id: string | null;
+  mods_restored: number;
+  mods_failed: string[];
+  untracked_mods: string[];
+  error: string | null;
+}
+
+// Library backup card: exports every instance's recipe to one manifest
+function LibraryBackup() {
+  const [busy, setBusy] = useState(false);
+  const [message, setMessage] = useState<string | null>(null);
+  const [results, setResults] = useState<RecipeImportResult[]>([]);
+
+  const exportRecipes = async () => {
+    const outputPath = await saveDialog({
+      defaultPath: "oxide-instances.json",
+      filters: [{ name: "Instance recipes", extensions: ["json"] }],
+    });
+    if (!outputPath) return;
+
+    setBusy(true);
+    setResults([]);
+    try {
+      const count = await invoke<number>("export_all_recipes", { outputPath });
+      setMessage(`Exported ${count} instance${count === 1 ? "" : "s"}.`);
+    } catch (error) {
+      setMessage(`Export failed: ${error}`);
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  const importRecipes = async () => {
+    const inputPath = await openDialog({
+      multiple: false,
+      filters: [{ name: "Instance recipes", extensions: ["json"] }],
+    });
+    if (!inputPath || Array.isArray(inputPath)) return;
+
+    setBusy(true);
+    setMessage("Importing instances and downloading mods...");
+    try {
+      const imported = await invoke<RecipeImportResult[]>("import_all_recipes", { inputPath });
+      setResults(imported);
+      const created = imported.filter((r) => r.instance_id).length;
+      setMessage(`Imported ${created} of ${imported.length} instances.`);
+    } catch (error) {
+      setMessage(`Import failed: ${error}`);
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>Library Backup</CardTitle>
+        <CardDescription>
+          Save every instance's version, loader, settings and mod list to one small file.
+          Importing it recreates the instances and downloads their mods again; worlds and
+          other files are not included.
+        </CardDescription>
+      </CardHeader>
+      <CardContent className="space-y-4">
+        <div className="flex gap-2">
+          <Button variant="outline" onClick={exportRecipes} disabled={busy}>
+            <Upload className="h-4 w-4 mr-2" />
+            Export Instances
+          </Button>
+          <Button variant="outline" onClick={importRecipes} disabled={busy}>
+            <Download className="h-4 w-4 mr-2" />
+            Import Instances
+          </Button>
+        </div>
+
+        {message && <p className="text-sm text-muted-foreground">{message}</p>}
+
+        {results.length > 0 && (
+          <div className="space-y-2 text-sm">
+            {results.map((result, index) => (
+              <div key={index} className="rounded-md border p-2">
+                <p className="font-medium">{result.name}</p>
+                {result.error ? (
+                  <p className="text-destructive">{result.error}</p>
+                ) : (
+                  <p className="text-muted-foreground">
+                    {result.mods_restored} mods downloaded
+                    {result.mods_failed.length > 0 && `, ${result.mods_failed.length} failed`}
+                    {result.untracked_mods.length > 0 &&
+                      `, ${result.untracked_mods.length} not restorable`}
+                  </p>
+                )}
+                {result.mods_failed.map((failure) => (
+                  <p key={failure} className="text-xs text-destructive">{failure}</p>
+                ))}
+              </div>
+            ))}
+          </div>
+        )}
+      </CardContent>
+    </Card>
+  );
+}
+
 // Files Settings Sub-tab
 function FilesSettings() {
   const { config, setConfig } = useSettings();
@@ -392,6 +500,8 @@ function FilesSettings() {
           )}
         </CardContent>
       </Card>
+
+      <LibraryBackup />
     </div>
   );
 }
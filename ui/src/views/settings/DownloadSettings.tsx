@@ -281,6 +281,44 @@ export function DownloadSettings() {
         </CardContent>
       </Card>
 
+      {/* Blocked Servers */}
+      <Card>
+        <CardHeader>
+          <CardTitle>Blocked Servers</CardTitle>
+          <CardDescription>
+            Warn about saved servers that Mojang has blocked.
+          </CardDescription>
+        </CardHeader>
+        <CardContent>
+          <div className="flex items-center justify-between">
+            <div className="space-y-0.5">
+              <Label htmlFor="checkBlockedServers" className="inline-flex items-center">
+                Check Blocked Servers
+                <SettingTooltip>
+                  Downloads Mojang's list of blocked server addresses once a day and checks servers against it.
+                </SettingTooltip>
+              </Label>
+              <p className="text-sm text-muted-foreground">
+                Fetch Mojang's blocked-servers list.
+              </p>
+            </div>
+            <Switch
+              id="checkBlockedServers"
+              checked={config.network.check_blocked_servers}
+              onCheckedChange={(checked) =>
+                setConfig({
+                  ...config,
+                  network: {
+                    ...config.network,
+                    check_blocked_servers: checked,
+                  },
+                })
+              }
+            />
+          </div>
+        </CardContent>
+      </Card>
+
       {/* Timeout Settings */}
       <Card>
         <CardHeader>
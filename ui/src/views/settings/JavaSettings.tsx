@@ -20,7 +20,7 @@
 
 import { useState, useEffect } from "react";
 import { invoke } from "@tauri-apps/api/core";
-import { RefreshCw, Download, Trash2, Check, X, HelpCircle } from "lucide-react";
+import { RefreshCw, Download, Trash2, Check, X, HelpCircle, AlertTriangle, Wrench } from "lucide-react";
 import { Button } from "@/components/ui/button";
 import { Card, CardContent, CardDescription, CardHeader, CardTitle } from "@/components/ui/card";
 import { Input } from "@/components/ui/input";
@@ -28,6 +28,7 @@ import { Label } from "@/components/ui/label";
 import { Badge } from "@/components/ui/badge";
 import { ScrollArea } from "@/components/ui/scroll-area";
 import { Switch } from "@/components/ui/switch";
+import { Alert, AlertDescription } from "@/components/ui/alert";
 import {
   AlertDialog,
   AlertDialogAction,
@@ -45,7 +46,7 @@ import {
   TooltipTrigger,
 } from "@/components/ui/tooltip";
 import { useSettings, extraArgsToString, stringToExtraArgs } from "./context";
-import type { JavaInstallation, AvailableJavaVersion } from "./types";
+import type { JavaInstallation, AvailableJavaVersion, BrokenManagedJava } from "./types";
 
 // Tooltip helper for settings
 function SettingTooltip({ children }: { children: React.ReactNode }) {
@@ -64,7 +65,7 @@ function SettingTooltip({ children }: { children: React.ReactNode }) {
 }
 
 export function JavaSettings() {
-  const { config, setConfig } = useSettings();
+  const { config, setConfig, memoryWarnings } = useSettings();
   
   const [javaInstallations, setJavaInstallations] = useState<JavaInstallation[]>([]);
   const [detectingJava, setDetectingJava] = useState(false);
@@ -73,16 +74,44 @@ export function JavaSettings() {
   const [downloadProgress, setDownloadProgress] = useState<string>("");
   const [showDeleteDialog, setShowDeleteDialog] = useState(false);
   const [javaToDelete, setJavaToDelete] = useState<JavaInstallation | null>(null);
+  const [brokenJava, setBrokenJava] = useState<BrokenManagedJava[]>([]);
+  const [repairingJava, setRepairingJava] = useState<string | null>(null);
 
   useEffect(() => {
     detectJavaInstallations();
     fetchAvailableJavaVersions();
+    checkManagedJava();
   }, []);
 
-  const detectJavaInstallations = async () => {
+  const checkManagedJava = async () => {
+    try {
+      const broken = await invoke<BrokenManagedJava[]>("check_managed_java");
+      setBrokenJava(broken);
+    } catch (error) {
+      console.error("Failed to check managed Java:", error);
+    }
+  };
+
+  // Deletes and re-downloads a managed Java; `majorOrId` is a managed folder
+  // name or a path inside it
+  const repairJava = async (majorOrId: string) => {
+    setRepairingJava(majorOrId);
+    try {
+      await invoke<JavaInstallation>("repair_managed_java", { majorOrId });
+      await detectJavaInstallations(true);
+      await checkManagedJava();
+    } catch (error) {
+      console.error("Failed to repair Java:", error);
+      alert(`Failed to repair Java: ${error}`);
+    } finally {
+      setRepairingJava(null);
+    }
+  };
+
+  const detectJavaInstallations = async (refresh = false) => {
     setDetectingJava(true);
     try {
-      const installations = await invoke<JavaInstallation[]>("detect_java");
+      const installations = await invoke<JavaInstallation[]>("detect_java", { refresh });
       setJavaInstallations(installations);
     } catch (error) {
       console.error("Failed to detect Java:", error);
@@ -159,7 +188,10 @@ export function JavaSettings() {
             <Button
               variant="outline"
               size="sm"
-              onClick={detectJavaInstallations}
+              onClick={() => {
+                detectJavaInstallations(true);
+                checkManagedJava();
+              }}
               disabled={detectingJava}
               className="w-full sm:w-auto"
             >
@@ -169,6 +201,37 @@ export function JavaSettings() {
           </div>
         </CardHeader>
         <CardContent className="space-y-4 pt-0">
+          {brokenJava.length > 0 && (
+            <Alert variant="destructive">
+              <AlertTriangle className="h-4 w-4" />
+              <AlertDescription className="space-y-2">
+                <p className="text-sm">
+                  Some downloaded Java installations are broken and can't be used to launch.
+                </p>
+                {brokenJava.map((broken) => (
+                  <div key={broken.dir} className="flex items-center justify-between gap-2">
+                    <div className="min-w-0">
+                      <p className="text-sm font-medium truncate">{broken.name}</p>
+                      <p className="text-xs truncate">{broken.error}</p>
+                    </div>
+                    <Button
+                      variant="outline"
+                      size="sm"
+                      onClick={() => repairJava(broken.name)}
+                      disabled={repairingJava !== null || broken.major === null}
+                      className="flex-shrink-0"
+                    >
+                      <Wrench className={`h-4 w-4 sm:mr-2 ${repairingJava === broken.name ? 'animate-pulse' : ''}`} />
+                      <span className="hidden sm:inline">
+                        {repairingJava === broken.name ? "Repairing..." : "Repair"}
+                      </span>
+                    </Button>
+                  </div>
+                ))}
+              </AlertDescription>
+            </Alert>
+          )}
+
           {javaInstallations.length > 0 ? (
             <div className="space-y-2">
               <Label className="text-sm">Detected Installations</Label>
@@ -211,6 +274,18 @@ export function JavaSettings() {
                               <Check className="h-4 w-4" />
                             </Button>
                           )}
+                          {java.is_managed && (
+                            <Button
+                              variant="ghost"
+                              size="sm"
+                              className="h-7 w-7 p-0"
+                              title="Re-download this Java"
+                              onClick={() => repairJava(java.path)}
+                              disabled={repairingJava !== null}
+                            >
+                              <Wrench className={`h-4 w-4 ${repairingJava === java.path ? 'animate-pulse' : ''}`} />
+                            </Button>
+                          )}
                           {java.is_managed && (
                             <Button
                               variant="ghost"
@@ -411,6 +486,18 @@ export function JavaSettings() {
           <p className="text-xs text-muted-foreground">
             Recommended: Set max to half of your system RAM.
           </p>
+          {memoryWarnings.length > 0 && (
+            <Alert>
+              <AlertTriangle className="h-4 w-4" />
+              <AlertDescription>
+                <ul className="list-disc list-inside text-xs">
+                  {memoryWarnings.map((warning, i) => (
+                    <li key={i}>{warning}</li>
+                  ))}
+                </ul>
+              </AlertDescription>
+            </Alert>
+          )}
         </CardContent>
       </Card>
 
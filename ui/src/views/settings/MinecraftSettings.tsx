@@ -23,7 +23,17 @@ import { Input } from "@/components/ui/input";
 import { Label } from "@/components/ui/label";
 import { Switch } from "@/components/ui/switch";
 import { Tabs, TabsContent, TabsList, TabsTrigger } from "@/components/ui/tabs";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
 import { useSettings } from "./context";
+import type { InstanceTemplate } from "./types";
+
+const TEMPLATE_LOADERS = ["None", "Forge", "NeoForge", "Fabric", "Quilt"];
 
 // Window Settings Sub-tab
 function WindowSettings() {
@@ -268,6 +278,130 @@ function GameTimeSettings() {
           </div>
         </CardContent>
       </Card>
+
+      <Card>
+        <CardHeader>
+          <CardTitle>Account Security</CardTitle>
+          <CardDescription>
+            Control how your session reaches the game.
+          </CardDescription>
+        </CardHeader>
+        <CardContent className="space-y-4">
+          <div className="flex items-center justify-between">
+            <div className="space-y-0.5">
+              <Label htmlFor="accessTokenOutOfBand">Hide Access Token From Process List</Label>
+              <p className="text-sm text-muted-foreground">
+                Pass the access token to the OxideLaunch wrapper in a file instead of on the
+                command line, where other users on this computer could see it.
+              </p>
+            </div>
+            <Switch
+              id="accessTokenOutOfBand"
+              checked={config.minecraft.access_token_out_of_band}
+              onCheckedChange={(checked) =>
+                setConfig({
+                  ...config,
+                  minecraft: { ...config.minecraft, access_token_out_of_band: checked },
+                })
+              }
+            />
+          </div>
+        </CardContent>
+      </Card>
+    </div>
+  );
+}
+
+// New instance template sub-tab
+function TemplateSettings() {
+  const { config, setConfig } = useSettings();
+  if (!config) return null;
+
+  const template: InstanceTemplate = config.instance_template ?? {
+    min_memory: null,
+    max_memory: null,
+    jvm_args: null,
+    mod_loader: null,
+    java_path: null,
+  };
+  const update = (changes: Partial<InstanceTemplate>) =>
+    setConfig({ ...config, instance_template: { ...template, ...changes } });
+  const parseMemory = (value: string) => (value ? parseInt(value) || null : null);
+
+  return (
+    <div className="space-y-6">
+      <Card>
+        <CardHeader>
+          <CardTitle>New Instance Template</CardTitle>
+          <CardDescription>
+            Defaults applied to every new instance. Leave a field empty to use the
+            launcher-wide setting; choices made while creating an instance take precedence.
+          </CardDescription>
+        </CardHeader>
+        <CardContent className="space-y-4">
+          <div className="space-y-2">
+            <Label>Mod Loader</Label>
+            <Select
+              value={template.mod_loader ?? "None"}
+              onValueChange={(value) => update({ mod_loader: value === "None" ? null : value })}
+            >
+              <SelectTrigger>
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent>
+                {TEMPLATE_LOADERS.map((loader) => (
+                  <SelectItem key={loader} value={loader}>
+                    {loader === "None" ? "Vanilla" : loader}
+                  </SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+          </div>
+
+          <div className="grid grid-cols-2 gap-4">
+            <div className="space-y-2">
+              <Label htmlFor="templateMinMemory">Minimum Memory (MB)</Label>
+              <Input
+                id="templateMinMemory"
+                type="number"
+                value={template.min_memory ?? ""}
+                onChange={(e) => update({ min_memory: parseMemory(e.target.value) })}
+                min="256"
+              />
+            </div>
+            <div className="space-y-2">
+              <Label htmlFor="templateMaxMemory">Maximum Memory (MB)</Label>
+              <Input
+                id="templateMaxMemory"
+                type="number"
+                value={template.max_memory ?? ""}
+                onChange={(e) => update({ max_memory: parseMemory(e.target.value) })}
+                min="512"
+              />
+            </div>
+          </div>
+
+          <div className="space-y-2">
+            <Label htmlFor="templateJvmArgs">JVM Arguments</Label>
+            <Input
+              id="templateJvmArgs"
+              value={template.jvm_args ?? ""}
+              onChange={(e) => update({ jvm_args: e.target.value || null })}
+              placeholder="-XX:+UseG1GC"
+            />
+          </div>
+
+          <div className="space-y-2">
+            <Label htmlFor="templateJavaPath">Java Path</Label>
+            <Input
+              id="templateJavaPath"
+              value={template.java_path ?? ""}
+              onChange={(e) => update({ java_path: e.target.value || null })}
+              placeholder="Automatic"
+            />
+          </div>
+        </CardContent>
+      </Card>
     </div>
   );
 }
@@ -280,6 +414,7 @@ export function MinecraftSettings() {
         <TabsTrigger value="window">Window</TabsTrigger>
         <TabsTrigger value="console">Console</TabsTrigger>
         <TabsTrigger value="gametime">Game Time</TabsTrigger>
+        <TabsTrigger value="template">New Instances</TabsTrigger>
       </TabsList>
 
       <TabsContent value="window">
@@ -293,6 +428,10 @@ export function MinecraftSettings() {
       <TabsContent value="gametime">
         <GameTimeSettings />
       </TabsContent>
+
+      <TabsContent value="template">
+        <TemplateSettings />
+      </TabsContent>
     </Tabs>
   );
 }
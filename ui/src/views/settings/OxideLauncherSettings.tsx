@@ -190,6 +190,60 @@ function GeneralSettings() {
               }
             />
           </div>
+          <div className="flex items-center justify-between">
+            <div className="space-y-0.5">
+              <Label htmlFor="minimizeToTray">Minimize to Tray</Label>
+              <p className="text-sm text-muted-foreground">
+                Closing the window hides the launcher to the system tray. Use Quit from the tray menu to exit.
+              </p>
+            </div>
+            <Switch
+              id="minimizeToTray"
+              checked={config.ui.minimize_to_tray}
+              onCheckedChange={(checked) =>
+                setConfig({
+                  ...config,
+                  ui: { ...config.ui, minimize_to_tray: checked },
+                })
+              }
+            />
+          </div>
+          <div className="flex items-center justify-between">
+            <div className="space-y-0.5">
+              <Label htmlFor="startMinimized">Start Minimized</Label>
+              <p className="text-sm text-muted-foreground">
+                Keep the launcher hidden at startup until the tray icon is clicked.
+              </p>
+            </div>
+            <Switch
+              id="startMinimized"
+              checked={config.ui.start_minimized}
+              onCheckedChange={(checked) =>
+                setConfig({
+                  ...config,
+                  ui: { ...config.ui, start_minimized: checked },
+                })
+              }
+            />
+          </div>
+          <div className="flex items-center justify-between">
+            <div className="space-y-0.5">
+              <Label htmlFor="warmUpOnStartup">Preload on Startup</Label>
+              <p className="text-sm text-muted-foreground">
+                Fetch version lists, detect Java and load instance details in the background after starting.
+              </p>
+            </div>
+            <Switch
+              id="warmUpOnStartup"
+              checked={config.ui.warm_up_on_startup}
+              onCheckedChange={(checked) =>
+                setConfig({
+                  ...config,
+                  ui: { ...config.ui, warm_up_on_startup: checked },
+                })
+              }
+            />
+          </div>
         </CardContent>
       </Card>
     </div>
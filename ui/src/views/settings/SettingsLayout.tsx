@@ -99,6 +99,7 @@ export function SettingsLayout() {
   const [loading, setLoading] = useState(true);
   const [activeTab, setActiveTab] = useState<SettingsTab>("launcher");
   const saveTimeoutRef = useRef<number | null>(null);
+  const [memoryWarnings, setMemoryWarnings] = useState<string[]>([]);
   const { setConfig: setGlobalConfig } = useConfig();
 
   useEffect(() => {
@@ -124,7 +125,8 @@ export function SettingsLayout() {
     
     saveTimeoutRef.current = window.setTimeout(async () => {
       try {
-        await invoke("update_config", { config: newConfig });
+        const warnings = await invoke<string[]>("update_config", { config: newConfig });
+        setMemoryWarnings(warnings);
         // Sync to global config provider so Layout and other components update
         setGlobalConfig(newConfig as import("@/hooks/useConfig").Config);
       } catch (error) {
@@ -157,7 +159,7 @@ export function SettingsLayout() {
   }
 
   return (
-    <SettingsContext.Provider value={{ config, setConfig: setConfigWithSave, saveConfig: async () => {}, loading }}>
+    <SettingsContext.Provider value={{ config, setConfig: setConfigWithSave, saveConfig: async () => {}, loading, memoryWarnings }}>
       <div className="flex flex-col md:flex-row h-full">
         {/* Sidebar Navigation */}
         <div className="w-full md:w-56 lg:w-64 border-b md:border-b-0 md:border-r flex flex-col flex-shrink-0">
@@ -50,6 +50,15 @@ export interface AvailableJavaVersion {
   is_lts: boolean;
 }
 
+// A managed Java that is missing its executable or fails validation
+export interface BrokenManagedJava {
+  name: string;
+  dir: string;
+  major: number | null;
+  java_path: string | null;
+  error: string;
+}
+
 // Proxy types
 export type ProxyType = "Http" | "Socks5";
 
@@ -79,6 +88,7 @@ export interface Config {
   api_keys: ApiKeys;
   debug: DebugConfig;
   files: FilesConfig;
+  instance_template: InstanceTemplate;
 }
 
 export interface JavaConfig {
@@ -98,6 +108,7 @@ export interface NetworkConfig {
   user_agent: string;
   downloads_dir: string | null;
   downloads_dir_watch_recursive: boolean;
+  check_blocked_servers: boolean;
 }
 
 export interface UiConfig {
@@ -116,6 +127,11 @@ export interface UiConfig {
   main_window_state: WindowState;
   dialog_window_states: Record<string, WindowState>;
   open_instance_after_install: boolean;
+  minimize_to_tray: boolean;
+  start_minimized: boolean;
+  warm_up_on_startup: boolean;
+  last_instance_tab: string | null;
+  instance_tabs: Record<string, string>;
 }
 
 export interface MinecraftConfig {
@@ -128,6 +144,7 @@ export interface MinecraftConfig {
   show_console_on_error: boolean;
   record_game_time: boolean;
   show_game_time: boolean;
+  access_token_out_of_band: boolean;
 }
 
 export interface CustomCommands {
@@ -168,10 +185,21 @@ export interface FilesConfig {
   use_recycle_bin: boolean;
 }
 
+export interface InstanceTemplate {
+  min_memory: number | null;
+  max_memory: number | null;
+  jvm_args: string | null;
+  /** Loader preselected in the create-instance wizard, e.g. "Fabric" */
+  mod_loader: string | null;
+  java_path: string | null;
+}
+
 // Settings context type
 export interface SettingsContextType {
   config: Config | null;
   setConfig: React.Dispatch<React.SetStateAction<Config | null>>;
   saveConfig: () => Promise<void>;
   loading: boolean;
+  /** Warnings about the memory settings from the last save */
+  memoryWarnings: string[];
 }